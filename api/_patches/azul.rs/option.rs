@@ -173,6 +173,7 @@
     impl_option!(AzSystemClipboard, AzOptionSystemClipboard, copy = false,  clone = false, [Debug]);
     impl_option!(AzFileTypeList, AzOptionFileTypeList, copy = false, [Debug, Clone]);
     impl_option!(AzWindowState, AzOptionWindowState, copy = false, [Debug, Clone]);
+    impl_option!(AzFocusTarget, AzOptionFocusTarget, copy = false, [Debug, Clone]);
     impl_option!(AzKeyboardState, AzOptionKeyboardState, copy = false, [Debug, Clone]);
     impl_option!(AzMouseState, AzOptionMouseState, [Debug, Clone]);
     impl_option!(AzNodeGraphOnNodeAdded, AzOptionNodeGraphOnNodeAdded, [Debug, Copy, Clone]);
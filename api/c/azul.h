@@ -72,6 +72,10 @@ typedef AzTimerCallbackReturn (*AzTimerCallbackType)(AzRefAny* restrict A, AzTim
 
 typedef AzUpdate (*AzWriteBackCallbackType)(AzRefAny* restrict A, AzRefAny* restrict B, AzCallbackInfo* restrict C);
 
+union AzOptionImageRef;
+typedef union AzOptionImageRef AzOptionImageRef;
+typedef AzOptionImageRef (*AzImageLoaderCallbackType)(AzRefAny* restrict A);
+
 struct AzThreadSender;
 typedef struct AzThreadSender AzThreadSender;
 struct AzThreadReceiver;
@@ -422,6 +426,10 @@ struct AzStringPairVec;
 typedef struct AzStringPairVec AzStringPairVec;
 typedef void (*AzStringPairVecDestructorType)(AzStringPairVec* restrict A);
 
+struct AzFontWarmupVec;
+typedef struct AzFontWarmupVec AzFontWarmupVec;
+typedef void (*AzFontWarmupVecDestructorType)(AzFontWarmupVec* restrict A);
+
 struct AzNormalizedLinearColorStopVec;
 typedef struct AzNormalizedLinearColorStopVec AzNormalizedLinearColorStopVec;
 typedef void (*AzNormalizedLinearColorStopVecDestructorType)(AzNormalizedLinearColorStopVec* restrict A);
@@ -461,6 +469,35 @@ struct AzApp {
 };
 typedef struct AzApp AzApp;
 
+enum AzGpuPreference {
+   AzGpuPreference_Default,
+   AzGpuPreference_HighPerformance,
+   AzGpuPreference_PowerSaving,
+};
+typedef enum AzGpuPreference AzGpuPreference;
+
+struct AzGpuMemoryBudget {
+    size_t max_bytes;
+    uint32_t check_interval_ms;
+};
+typedef struct AzGpuMemoryBudget AzGpuMemoryBudget;
+
+enum AzEventLoopModeTag {
+   AzEventLoopModeTag_Blocking,
+   AzEventLoopModeTag_Polling,
+};
+typedef enum AzEventLoopModeTag AzEventLoopModeTag;
+
+struct AzEventLoopModeVariant_Blocking { AzEventLoopModeTag tag; };
+typedef struct AzEventLoopModeVariant_Blocking AzEventLoopModeVariant_Blocking;
+struct AzEventLoopModeVariant_Polling { AzEventLoopModeTag tag; uint32_t payload; };
+typedef struct AzEventLoopModeVariant_Polling AzEventLoopModeVariant_Polling;
+union AzEventLoopMode {
+    AzEventLoopModeVariant_Blocking Blocking;
+    AzEventLoopModeVariant_Polling Polling;
+};
+typedef union AzEventLoopMode AzEventLoopMode;
+
 enum AzAppLogLevel {
    AzAppLogLevel_Off,
    AzAppLogLevel_Error,
@@ -476,6 +513,39 @@ enum AzLayoutSolver {
 };
 typedef enum AzLayoutSolver AzLayoutSolver;
 
+enum AzGlContextProfile {
+   AzGlContextProfile_Core,
+   AzGlContextProfile_Compatibility,
+};
+typedef enum AzGlContextProfile AzGlContextProfile;
+
+enum AzGlBuffering {
+   AzGlBuffering_DoubleBuffered,
+   AzGlBuffering_SingleBufferedFlush,
+   AzGlBuffering_SingleBufferedFinish,
+};
+typedef enum AzGlBuffering AzGlBuffering;
+
+enum AzPresentModeTag {
+   AzPresentModeTag_Immediate,
+   AzPresentModeTag_Fifo,
+   AzPresentModeTag_FrameCapped,
+};
+typedef enum AzPresentModeTag AzPresentModeTag;
+
+struct AzPresentModeVariant_Immediate { AzPresentModeTag tag; };
+typedef struct AzPresentModeVariant_Immediate AzPresentModeVariant_Immediate;
+struct AzPresentModeVariant_Fifo { AzPresentModeTag tag; };
+typedef struct AzPresentModeVariant_Fifo AzPresentModeVariant_Fifo;
+struct AzPresentModeVariant_FrameCapped { AzPresentModeTag tag; uint32_t payload; };
+typedef struct AzPresentModeVariant_FrameCapped AzPresentModeVariant_FrameCapped;
+union AzPresentMode {
+    AzPresentModeVariant_Immediate Immediate;
+    AzPresentModeVariant_Fifo Fifo;
+    AzPresentModeVariant_FrameCapped FrameCapped;
+};
+typedef union AzPresentMode AzPresentMode;
+
 enum AzVsync {
    AzVsync_Enabled,
    AzVsync_Disabled,
@@ -483,6 +553,12 @@ enum AzVsync {
 };
 typedef enum AzVsync AzVsync;
 
+enum AzRedrawPolicy {
+   AzRedrawPolicy_OnDemand,
+   AzRedrawPolicy_Continuous,
+};
+typedef enum AzRedrawPolicy AzRedrawPolicy;
+
 enum AzSrgb {
    AzSrgb_Enabled,
    AzSrgb_Disabled,
@@ -848,6 +924,14 @@ enum AzMouseCursorType {
 };
 typedef enum AzMouseCursorType AzMouseCursorType;
 
+enum AzWindowCornerPreference {
+   AzWindowCornerPreference_Default,
+   AzWindowCornerPreference_DoNotRound,
+   AzWindowCornerPreference_Round,
+   AzWindowCornerPreference_RoundSmall,
+};
+typedef enum AzWindowCornerPreference AzWindowCornerPreference;
+
 enum AzRendererType {
    AzRendererType_Hardware,
    AzRendererType_Software,
@@ -878,6 +962,12 @@ enum AzWindowTheme {
 };
 typedef enum AzWindowTheme AzWindowTheme;
 
+struct AzAccessibilitySettings {
+    bool  high_contrast;
+    bool  prefers_reduced_motion;
+};
+typedef struct AzAccessibilitySettings AzAccessibilitySettings;
+
 struct AzTouchState {
     uint8_t unused;
 };
@@ -977,12 +1067,28 @@ struct AzThreadCallback {
 };
 typedef struct AzThreadCallback AzThreadCallback;
 
+struct AzImageLoaderCallback {
+    AzImageLoaderCallbackType cb;
+};
+typedef struct AzImageLoaderCallback AzImageLoaderCallback;
+
 struct AzRefCount {
     void* ptr;
     bool  run_destructor;
 };
 typedef struct AzRefCount AzRefCount;
 
+struct AzNativeChildWindow {
+    void* restrict handle;
+};
+typedef struct AzNativeChildWindow AzNativeChildWindow;
+
+enum AzTitleBarRegion {
+   AzTitleBarRegion_Drag,
+   AzTitleBarRegion_NoDrag,
+};
+typedef enum AzTitleBarRegion AzTitleBarRegion;
+
 enum AzOn {
    AzOn_MouseOver,
    AzOn_MouseDown,
@@ -2203,6 +2309,12 @@ struct AzTimerId {
 };
 typedef struct AzTimerId AzTimerId;
 
+enum AzTimerMissedTickPolicy {
+   AzTimerMissedTickPolicy_Coalesce,
+   AzTimerMissedTickPolicy_CatchUp,
+};
+typedef enum AzTimerMissedTickPolicy AzTimerMissedTickPolicy;
+
 enum AzTerminateTimer {
    AzTerminateTimer_Terminate,
    AzTerminateTimer_Continue,
@@ -3422,6 +3534,26 @@ union AzStringPairVecDestructor {
 };
 typedef union AzStringPairVecDestructor AzStringPairVecDestructor;
 
+enum AzFontWarmupVecDestructorTag {
+   AzFontWarmupVecDestructorTag_DefaultRust,
+   AzFontWarmupVecDestructorTag_NoDestructor,
+   AzFontWarmupVecDestructorTag_External,
+};
+typedef enum AzFontWarmupVecDestructorTag AzFontWarmupVecDestructorTag;
+
+struct AzFontWarmupVecDestructorVariant_DefaultRust { AzFontWarmupVecDestructorTag tag; };
+typedef struct AzFontWarmupVecDestructorVariant_DefaultRust AzFontWarmupVecDestructorVariant_DefaultRust;
+struct AzFontWarmupVecDestructorVariant_NoDestructor { AzFontWarmupVecDestructorTag tag; };
+typedef struct AzFontWarmupVecDestructorVariant_NoDestructor AzFontWarmupVecDestructorVariant_NoDestructor;
+struct AzFontWarmupVecDestructorVariant_External { AzFontWarmupVecDestructorTag tag; AzFontWarmupVecDestructorType payload; };
+typedef struct AzFontWarmupVecDestructorVariant_External AzFontWarmupVecDestructorVariant_External;
+union AzFontWarmupVecDestructor {
+    AzFontWarmupVecDestructorVariant_DefaultRust DefaultRust;
+    AzFontWarmupVecDestructorVariant_NoDestructor NoDestructor;
+    AzFontWarmupVecDestructorVariant_External External;
+};
+typedef union AzFontWarmupVecDestructor AzFontWarmupVecDestructor;
+
 enum AzNormalizedLinearColorStopVecDestructorTag {
    AzNormalizedLinearColorStopVecDestructorTag_DefaultRust,
    AzNormalizedLinearColorStopVecDestructorTag_NoDestructor,
@@ -3732,16 +3864,33 @@ struct AzSvgParseErrorPosition {
 };
 typedef struct AzSvgParseErrorPosition AzSvgParseErrorPosition;
 
+struct AzHitTestTolerance {
+    AzFloatValue mouse;
+    AzFloatValue pen;
+    AzFloatValue touch;
+};
+typedef struct AzHitTestTolerance AzHitTestTolerance;
+
 struct AzSystemCallbacks {
     AzCreateThreadFn create_thread_fn;
     AzGetSystemTimeFn get_system_time_fn;
 };
 typedef struct AzSystemCallbacks AzSystemCallbacks;
 
+struct AzGlContextOptions {
+    uint8_t major_version;
+    uint8_t minor_version;
+    AzGlContextProfile profile;
+    bool  debug;
+    AzGlBuffering buffering;
+};
+typedef struct AzGlContextOptions AzGlContextOptions;
+
 struct AzRendererOptions {
     AzVsync vsync;
     AzSrgb srgb;
     AzHwAcceleration hw_accel;
+    AzPresentMode present_mode;
 };
 typedef struct AzRendererOptions AzRendererOptions;
 
@@ -3825,6 +3974,14 @@ union AzAcceleratorKey {
 };
 typedef union AzAcceleratorKey AzAcceleratorKey;
 
+struct AzLetterbox {
+    float scale;
+    AzLogicalRect content;
+    AzLogicalRect leading_bar;
+    AzLogicalRect trailing_bar;
+};
+typedef struct AzLetterbox AzLetterbox;
+
 struct AzWindowFlags {
     AzWindowFrame frame;
     bool  is_about_to_close;
@@ -3837,6 +3994,9 @@ struct AzWindowFlags {
     bool  has_blur_behind_window;
     bool  smooth_scroll_enabled;
     bool  autotab_enabled;
+    bool  zoom_shortcuts_enabled;
+    bool  ctrl_wheel_zoom_enabled;
+    bool  is_shown_in_taskbar;
 };
 typedef struct AzWindowFlags AzWindowFlags;
 
@@ -3962,6 +4122,15 @@ struct AzInlineTextHit {
 };
 typedef struct AzInlineTextHit AzInlineTextHit;
 
+struct AzTextConstraints {
+    AzOptionF32 max_width;
+    AzOptionF32 line_height;
+    AzOptionF32 letter_spacing;
+    AzOptionF32 word_spacing;
+    AzOptionF32 tab_width;
+};
+typedef struct AzTextConstraints AzTextConstraints;
+
 struct AzIFrameCallbackInfo {
     void* system_fonts;
     void* image_cache;
@@ -7586,6 +7755,22 @@ union AzOptionSvgDashPattern {
 };
 typedef union AzOptionSvgDashPattern AzOptionSvgDashPattern;
 
+enum AzOptionLogicalRectTag {
+   AzOptionLogicalRectTag_None,
+   AzOptionLogicalRectTag_Some,
+};
+typedef enum AzOptionLogicalRectTag AzOptionLogicalRectTag;
+
+struct AzOptionLogicalRectVariant_None { AzOptionLogicalRectTag tag; };
+typedef struct AzOptionLogicalRectVariant_None AzOptionLogicalRectVariant_None;
+struct AzOptionLogicalRectVariant_Some { AzOptionLogicalRectTag tag; AzLogicalRect payload; };
+typedef struct AzOptionLogicalRectVariant_Some AzOptionLogicalRectVariant_Some;
+union AzOptionLogicalRect {
+    AzOptionLogicalRectVariant_None None;
+    AzOptionLogicalRectVariant_Some Some;
+};
+typedef union AzOptionLogicalRect AzOptionLogicalRect;
+
 enum AzOptionLogicalPositionTag {
    AzOptionLogicalPositionTag_None,
    AzOptionLogicalPositionTag_Some,
@@ -7810,16 +7995,6 @@ struct AzInvalidSpaceError {
 };
 typedef struct AzInvalidSpaceError AzInvalidSpaceError;
 
-struct AzAppConfig {
-    AzLayoutSolver layout_solver;
-    AzAppLogLevel log_level;
-    bool  enable_visual_panic_hook;
-    bool  enable_logging_on_panic;
-    bool  enable_tab_navigation;
-    AzSystemCallbacks system_callbacks;
-};
-typedef struct AzAppConfig AzAppConfig;
-
 struct AzSmallWindowIconBytes {
     AzIconKey key;
     AzU8Vec rgba_bytes;
@@ -7857,8 +8032,11 @@ typedef struct AzTaskBarIcon AzTaskBarIcon;
 struct AzWindowSize {
     AzLogicalSize dimensions;
     uint32_t dpi;
+    float content_scale;
     AzOptionLogicalSize min_dimensions;
     AzOptionLogicalSize max_dimensions;
+    AzOptionLogicalSize fixed_logical_size;
+    AzLetterbox letterbox;
 };
 typedef struct AzWindowSize AzWindowSize;
 
@@ -7882,6 +8060,12 @@ struct AzMouseState {
 };
 typedef struct AzMouseState AzMouseState;
 
+struct AzTextCaret {
+    AzOptionLogicalRect caret_rect;
+    AzLogicalRectVec selection_rects;
+};
+typedef struct AzTextCaret AzTextCaret;
+
 struct AzMarshaledLayoutCallback {
     AzRefAny marshal_data;
     AzMarshaledLayoutCallbackInner cb;
@@ -8840,6 +9024,15 @@ struct AzWindowsWindowOptions {
     AzOptionWindowIcon window_icon;
     AzOptionTaskBarIcon taskbar_icon;
     AzOptionHwndHandle parent_window;
+    AzOptionF32 alpha_hit_test_threshold;
+    bool  alpha_hit_test_pass_through;
+    AzWindowCornerPreference corner_preference;
+    AzOptionString app_user_model_id;
+    bool  animate_frame_changes;
+    AzOptionHwndHandle shared_gl_context;
+    bool  embed_in_parent;
+    bool  skip_rendering_when_occluded;
+    bool  borderless_shadow;
 };
 typedef struct AzWindowsWindowOptions AzWindowsWindowOptions;
 
@@ -9227,6 +9420,8 @@ struct AzTextInputState {
     size_t max_len;
     AzOptionTextInputSelection selection;
     size_t cursor_pos;
+    bool  read_only;
+    bool  disabled;
 };
 typedef struct AzTextInputState AzTextInputState;
 
@@ -9411,6 +9606,8 @@ struct AzTimer {
     AzOptionDuration delay;
     AzOptionDuration interval;
     AzOptionDuration timeout;
+    AzTimerMissedTickPolicy missed_tick_policy;
+    bool  runs_while_hidden;
     AzTimerCallback callback;
 };
 typedef struct AzTimer AzTimer;
@@ -9725,6 +9922,13 @@ union AzXmlStreamError {
 };
 typedef union AzXmlStreamError AzXmlStreamError;
 
+struct AzFontWarmup {
+    AzStyleFontFamilyVec font;
+    AzStyleFontSize size;
+    AzString characters;
+};
+typedef struct AzFontWarmup AzFontWarmup;
+
 struct AzLinuxWindowOptions {
     AzOptionX11Visual x11_visual;
     AzOptionI32 x11_screen;
@@ -10322,6 +10526,14 @@ struct AzSvgSimpleNodeVec {
 };
 typedef struct AzSvgSimpleNodeVec AzSvgSimpleNodeVec;
 
+struct AzFontWarmupVec {
+    AzFontWarmup* ptr;
+    size_t len;
+    size_t cap;
+    AzFontWarmupVecDestructor destructor;
+};
+typedef struct AzFontWarmupVec AzFontWarmupVec;
+
 enum AzOptionCssPropertyTag {
    AzOptionCssPropertyTag_None,
    AzOptionCssPropertyTag_Some,
@@ -10344,6 +10556,26 @@ struct AzXmlTextError {
 };
 typedef struct AzXmlTextError AzXmlTextError;
 
+struct AzAppConfig {
+    AzLayoutSolver layout_solver;
+    AzAppLogLevel log_level;
+    bool  enable_visual_panic_hook;
+    bool  enable_logging_on_panic;
+    bool  enable_tab_navigation;
+    AzSystemCallbacks system_callbacks;
+    AzEventLoopMode event_loop_mode;
+    AzGpuPreference gpu_preference;
+    AzString window_class_name;
+    AzGpuMemoryBudget gpu_memory_budget;
+    AzOptionString app_user_model_id;
+    AzOptionString shader_cache_dir;
+    AzHitTestTolerance hit_test_tolerance;
+    AzFontWarmupVec font_warmup;
+    bool  async_scene_building;
+    AzOptionDuration frame_budget;
+};
+typedef struct AzAppConfig AzAppConfig;
+
 struct AzPlatformSpecificOptions {
     AzWindowsWindowOptions windows_options;
     AzLinuxWindowOptions linux_options;
@@ -10363,9 +10595,12 @@ struct AzWindowState {
     AzMouseState mouse_state;
     AzTouchState touch_state;
     AzImePosition ime_position;
+    AzTextCaret text_caret;
+    AzAccessibilitySettings accessibility;
     AzMonitor monitor;
     AzPlatformSpecificOptions platform_specific_options;
     AzRendererOptions renderer_options;
+    AzRedrawPolicy redraw_policy;
     AzColorU background_color;
     AzLayoutCallback layout_callback;
     AzOptionCallback close_callback;
@@ -10607,16 +10842,6 @@ union AzXmlParseError {
 };
 typedef union AzXmlParseError AzXmlParseError;
 
-struct AzWindowCreateOptions {
-    AzWindowState state;
-    bool  size_to_content;
-    AzOptionRendererOptions renderer_type;
-    AzOptionWindowTheme theme;
-    AzOptionCallback create_callback;
-    bool  hot_reload;
-};
-typedef struct AzWindowCreateOptions AzWindowCreateOptions;
-
 enum AzFocusTargetTag {
    AzFocusTargetTag_Id,
    AzFocusTargetTag_Path,
@@ -10763,6 +10988,22 @@ struct AzNodeDataVec {
 };
 typedef struct AzNodeDataVec AzNodeDataVec;
 
+enum AzOptionFocusTargetTag {
+   AzOptionFocusTargetTag_None,
+   AzOptionFocusTargetTag_Some,
+};
+typedef enum AzOptionFocusTargetTag AzOptionFocusTargetTag;
+
+struct AzOptionFocusTargetVariant_None { AzOptionFocusTargetTag tag; };
+typedef struct AzOptionFocusTargetVariant_None AzOptionFocusTargetVariant_None;
+struct AzOptionFocusTargetVariant_Some { AzOptionFocusTargetTag tag; AzFocusTarget payload; };
+typedef struct AzOptionFocusTargetVariant_Some AzOptionFocusTargetVariant_Some;
+union AzOptionFocusTarget {
+    AzOptionFocusTargetVariant_None None;
+    AzOptionFocusTargetVariant_Some Some;
+};
+typedef union AzOptionFocusTarget AzOptionFocusTarget;
+
 enum AzXmlErrorTag {
    AzXmlErrorTag_NoParserAvailable,
    AzXmlErrorTag_InvalidXmlPrefixUri,
@@ -10839,6 +11080,21 @@ union AzXmlError {
 };
 typedef union AzXmlError AzXmlError;
 
+struct AzWindowCreateOptions {
+    AzWindowState state;
+    bool  size_to_content;
+    AzOptionRendererOptions renderer_type;
+    AzOptionWindowTheme theme;
+    AzOptionCallback create_callback;
+    bool  hot_reload;
+    bool  center_on_show;
+    AzGlContextOptions gl_context;
+    bool  show_after_first_frame;
+    AzOptionCallback first_frame_callback;
+    AzOptionFocusTarget initial_focus;
+};
+typedef struct AzWindowCreateOptions AzWindowCreateOptions;
+
 struct AzDom {
     AzNodeData root;
     AzDomVec children;
@@ -11024,6 +11280,11 @@ struct AzCss {
 };
 typedef struct AzCss AzCss;
 
+#define AzEventLoopMode_Blocking { .Blocking = { .tag = AzEventLoopModeTag_Blocking } }
+#define AzEventLoopMode_Polling(v) { .Polling = { .tag = AzEventLoopModeTag_Polling, .payload = v } }
+#define AzPresentMode_Immediate { .Immediate = { .tag = AzPresentModeTag_Immediate } }
+#define AzPresentMode_Fifo { .Fifo = { .tag = AzPresentModeTag_Fifo } }
+#define AzPresentMode_FrameCapped(v) { .FrameCapped = { .tag = AzPresentModeTag_FrameCapped, .payload = v } }
 #define AzAnimationRepeatCount_Times(v) { .Times = { .tag = AzAnimationRepeatCountTag_Times, .payload = v } }
 #define AzAnimationRepeatCount_Infinite { .Infinite = { .tag = AzAnimationRepeatCountTag_Infinite } }
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
@@ -11207,6 +11468,9 @@ typedef struct AzCss AzCss;
 #define AzStringPairVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStringPairVecDestructorTag_DefaultRust } }
 #define AzStringPairVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStringPairVecDestructorTag_NoDestructor } }
 #define AzStringPairVecDestructor_External(v) { .External = { .tag = AzStringPairVecDestructorTag_External, .payload = v } }
+#define AzFontWarmupVecDestructor_DefaultRust { .DefaultRust = { .tag = AzFontWarmupVecDestructorTag_DefaultRust } }
+#define AzFontWarmupVecDestructor_NoDestructor { .NoDestructor = { .tag = AzFontWarmupVecDestructorTag_NoDestructor } }
+#define AzFontWarmupVecDestructor_External(v) { .External = { .tag = AzFontWarmupVecDestructorTag_External, .payload = v } }
 #define AzNormalizedLinearColorStopVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNormalizedLinearColorStopVecDestructorTag_DefaultRust } }
 #define AzNormalizedLinearColorStopVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNormalizedLinearColorStopVecDestructorTag_NoDestructor } }
 #define AzNormalizedLinearColorStopVecDestructor_External(v) { .External = { .tag = AzNormalizedLinearColorStopVecDestructorTag_External, .payload = v } }
@@ -11704,6 +11968,8 @@ typedef struct AzCss AzCss;
 #define AzOptionColorU_Some(v) { .Some = { .tag = AzOptionColorUTag_Some, .payload = v } }
 #define AzOptionSvgDashPattern_None { .None = { .tag = AzOptionSvgDashPatternTag_None } }
 #define AzOptionSvgDashPattern_Some(v) { .Some = { .tag = AzOptionSvgDashPatternTag_Some, .payload = v } }
+#define AzOptionLogicalRect_None { .None = { .tag = AzOptionLogicalRectTag_None } }
+#define AzOptionLogicalRect_Some(v) { .Some = { .tag = AzOptionLogicalRectTag_Some, .payload = v } }
 #define AzOptionLogicalPosition_None { .None = { .tag = AzOptionLogicalPositionTag_None } }
 #define AzOptionLogicalPosition_Some(v) { .Some = { .tag = AzOptionLogicalPositionTag_Some, .payload = v } }
 #define AzOptionPhysicalPositionI32_None { .None = { .tag = AzOptionPhysicalPositionI32Tag_None } }
@@ -12051,6 +12317,8 @@ typedef struct AzCss AzCss;
 #define AzFocusTarget_NoFocus { .NoFocus = { .tag = AzFocusTargetTag_NoFocus } }
 #define AzCssDeclaration_Static(v) { .Static = { .tag = AzCssDeclarationTag_Static, .payload = v } }
 #define AzCssDeclaration_Dynamic(v) { .Dynamic = { .tag = AzCssDeclarationTag_Dynamic, .payload = v } }
+#define AzOptionFocusTarget_None { .None = { .tag = AzOptionFocusTargetTag_None } }
+#define AzOptionFocusTarget_Some(v) { .Some = { .tag = AzOptionFocusTargetTag_Some, .payload = v } }
 #define AzXmlError_NoParserAvailable { .NoParserAvailable = { .tag = AzXmlErrorTag_NoParserAvailable } }
 #define AzXmlError_InvalidXmlPrefixUri(v) { .InvalidXmlPrefixUri = { .tag = AzXmlErrorTag_InvalidXmlPrefixUri, .payload = v } }
 #define AzXmlError_UnexpectedXmlUri(v) { .UnexpectedXmlUri = { .tag = AzXmlErrorTag_UnexpectedXmlUri, .payload = v } }
@@ -12310,6 +12578,10 @@ AzStringPair AzStringPairVecArray[] = {};
 #define AzStringPairVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStringPair), .cap = sizeof(v) / sizeof(AzStringPair), .destructor = { .NoDestructor = { .tag = AzStringPairVecDestructorTag_NoDestructor, }, }, }
 #define AzStringPairVec_empty { .ptr = &AzStringPairVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStringPairVecDestructorTag_NoDestructor, }, }, }
 
+AzFontWarmup AzFontWarmupVecArray[] = {};
+#define AzFontWarmupVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzFontWarmup), .cap = sizeof(v) / sizeof(AzFontWarmup), .destructor = { .NoDestructor = { .tag = AzFontWarmupVecDestructorTag_NoDestructor, }, }, }
+#define AzFontWarmupVec_empty { .ptr = &AzFontWarmupVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzFontWarmupVecDestructorTag_NoDestructor, }, }, }
+
 AzNormalizedLinearColorStop AzNormalizedLinearColorStopVecArray[] = {};
 #define AzNormalizedLinearColorStopVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNormalizedLinearColorStop), .cap = sizeof(v) / sizeof(AzNormalizedLinearColorStop), .destructor = { .NoDestructor = { .tag = AzNormalizedLinearColorStopVecDestructorTag_NoDestructor, }, }, }
 #define AzNormalizedLinearColorStopVec_empty { .ptr = &AzNormalizedLinearColorStopVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNormalizedLinearColorStopVecDestructorTag_NoDestructor, }, }, }
@@ -12348,10 +12620,18 @@ extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
 extern DLLIMPORT void AzApp_addWindow(AzApp* restrict app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_addImage(AzApp* restrict app, AzString  id, AzImageRef  image);
 extern DLLIMPORT AzMonitorVec AzApp_getMonitors(const AzApp* app);
-extern DLLIMPORT void AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
+extern DLLIMPORT bool  AzApp_pumpEvents(const AzApp* app);
+extern DLLIMPORT int32_t AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_delete(AzApp* restrict instance);
 extern DLLIMPORT AzApp AzApp_deepCopy(AzApp* const instance);
 extern DLLIMPORT AzAppConfig AzAppConfig_new(AzLayoutSolver  layout_solver);
+extern DLLIMPORT void AzAppConfig_delete(AzAppConfig* restrict instance);
+extern DLLIMPORT AzGpuMemoryBudget AzGpuMemoryBudget_unlimited();
+extern DLLIMPORT AzGpuMemoryBudget AzGpuMemoryBudget_new(size_t max_bytes);
+extern DLLIMPORT AzHitTestTolerance AzHitTestTolerance_none();
+extern DLLIMPORT AzHitTestTolerance AzHitTestTolerance_new(float mouse, float pen, float touch);
+extern DLLIMPORT AzFontWarmup AzFontWarmup_new(AzStyleFontFamilyVec  font, AzStyleFontSize  size, AzString  characters);
+extern DLLIMPORT void AzFontWarmup_delete(AzFontWarmup* restrict instance);
 extern DLLIMPORT AzSystemCallbacks AzSystemCallbacks_libraryInternal();
 extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT void AzWindowCreateOptions_delete(AzWindowCreateOptions* restrict instance);
@@ -12375,6 +12655,7 @@ extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* rest
 extern DLLIMPORT void AzWaylandTheme_delete(AzWaylandTheme* restrict instance);
 extern DLLIMPORT void AzStringPair_delete(AzStringPair* restrict instance);
 extern DLLIMPORT void AzLinuxWindowOptions_delete(AzLinuxWindowOptions* restrict instance);
+extern DLLIMPORT void AzTextCaret_delete(AzTextCaret* restrict instance);
 extern DLLIMPORT void AzMonitor_delete(AzMonitor* restrict instance);
 extern DLLIMPORT AzWindowState AzWindowState_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT AzWindowState AzWindowState_default();
@@ -12401,6 +12682,7 @@ extern DLLIMPORT AzOptionInlineText AzCallbackInfo_getInlineText(const AzCallbac
 extern DLLIMPORT AzOptionFontRef AzCallbackInfo_getFontRef(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionResolvedTextLayoutOptions AzCallbackInfo_getTextLayoutOptions(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionInlineText AzCallbackInfo_shapeText(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id, AzString  text);
+extern DLLIMPORT AzOptionInlineText AzCallbackInfo_measureText(const AzCallbackInfo* callbackinfo, AzStyleFontFamilyVec  font, AzStyleFontSize  font_size, AzString  text, AzTextConstraints  constraints);
 extern DLLIMPORT size_t AzCallbackInfo_getIndexInParent(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getParent(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getPreviousSibling(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
@@ -12421,7 +12703,13 @@ extern DLLIMPORT AzOptionImageRef AzCallbackInfo_getImage(const AzCallbackInfo*
 extern DLLIMPORT void AzCallbackInfo_updateImage(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageRef  new_image, AzUpdateImageType  image_type);
 extern DLLIMPORT void AzCallbackInfo_deleteImage(AzCallbackInfo* restrict callbackinfo, AzString  id);
 extern DLLIMPORT void AzCallbackInfo_updateImageMask(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
+extern DLLIMPORT AzOptionThreadId AzCallbackInfo_loadImageAsync(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzRefAny  data, AzImageLoaderCallbackType  loader, AzImageRef  fallback);
 extern DLLIMPORT void AzCallbackInfo_stopPropagation(AzCallbackInfo* restrict callbackinfo);
+extern DLLIMPORT void AzCallbackInfo_forceFrame(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT bool  AzCallbackInfo_confineToMonitor(const AzCallbackInfo* callbackinfo, size_t monitor_id);
+extern DLLIMPORT void AzCallbackInfo_releaseFromMonitor(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT bool  AzCallbackInfo_lockAspectRatio(const AzCallbackInfo* callbackinfo, float ratio);
+extern DLLIMPORT void AzCallbackInfo_unlockAspectRatio(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
 extern DLLIMPORT AzTimerId AzCallbackInfo_startTimer(AzCallbackInfo* restrict callbackinfo, AzTimer  timer);
 extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
@@ -12445,6 +12733,7 @@ extern DLLIMPORT void AzFocusTarget_delete(AzFocusTarget* restrict instance);
 extern DLLIMPORT void AzFocusTargetPath_delete(AzFocusTargetPath* restrict instance);
 extern DLLIMPORT AzResolvedTextLayoutOptions AzResolvedTextLayoutOptions_default();
 extern DLLIMPORT void AzResolvedTextLayoutOptions_delete(AzResolvedTextLayoutOptions* restrict instance);
+extern DLLIMPORT AzTextConstraints AzTextConstraints_default();
 extern DLLIMPORT void AzAnimation_delete(AzAnimation* restrict instance);
 extern DLLIMPORT void AzIFrameCallbackReturn_delete(AzIFrameCallbackReturn* restrict instance);
 extern DLLIMPORT AzOptionGl AzRenderImageCallbackInfo_getGlContext(const AzRenderImageCallbackInfo* renderimagecallbackinfo);
@@ -12475,6 +12764,7 @@ extern DLLIMPORT AzRefAny AzRefAny_deepCopy(AzRefAny* const instance);
 extern DLLIMPORT AzOptionGl AzLayoutCallbackInfo_getGlContext(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzStringPairVec AzLayoutCallbackInfo_getSystemFonts(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzOptionImageRef AzLayoutCallbackInfo_getImage(const AzLayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+extern DLLIMPORT AzOptionInlineText AzLayoutCallbackInfo_measureText(const AzLayoutCallbackInfo* layoutcallbackinfo, AzStyleFontFamilyVec  font, AzStyleFontSize  font_size, AzString  text, AzTextConstraints  constraints);
 extern DLLIMPORT void AzLayoutCallbackInfo_delete(AzLayoutCallbackInfo* restrict instance);
 extern DLLIMPORT AzDom AzDom_new(AzNodeType  node_type);
 extern DLLIMPORT AzDom AzDom_body();
@@ -12483,6 +12773,7 @@ extern DLLIMPORT AzDom AzDom_br();
 extern DLLIMPORT AzDom AzDom_text(AzString  string);
 extern DLLIMPORT AzDom AzDom_image(AzImageRef  image);
 extern DLLIMPORT AzDom AzDom_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+extern DLLIMPORT AzDom AzDom_nativeChildWindow(AzNativeChildWindow  window);
 extern DLLIMPORT void AzDom_setNodeType(AzDom* restrict dom, AzNodeType  node_type);
 extern DLLIMPORT AzDom AzDom_withNodeType(AzDom* restrict dom, AzNodeType  node_type);
 extern DLLIMPORT void AzDom_setDataset(AzDom* restrict dom, AzRefAny  dataset);
@@ -12529,6 +12820,8 @@ extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT AzDom AzDom_withMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT void AzDom_setContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT AzDom AzDom_withContextMenu(AzDom* restrict dom, AzMenu  context_menu);
+extern DLLIMPORT void AzDom_setTitleBarRegion(AzDom* restrict dom, AzTitleBarRegion  region);
+extern DLLIMPORT AzDom AzDom_withTitleBarRegion(AzDom* restrict dom, AzTitleBarRegion  region);
 extern DLLIMPORT uint64_t AzDom_hash(const AzDom* dom);
 extern DLLIMPORT size_t AzDom_nodeCount(const AzDom* dom);
 extern DLLIMPORT AzString AzDom_getHtmlString(AzDom* restrict dom);
@@ -12569,6 +12862,8 @@ extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTa
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
+extern DLLIMPORT void AzNodeData_setNativeChildWindow(AzNodeData* restrict nodedata, AzNativeChildWindow  window);
+extern DLLIMPORT void AzNodeData_setTitleBarRegion(AzNodeData* restrict nodedata, AzTitleBarRegion  region);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
 extern DLLIMPORT void AzNodeData_delete(AzNodeData* restrict instance);
 extern DLLIMPORT void AzNodeType_delete(AzNodeType* restrict instance);
@@ -12667,6 +12962,10 @@ extern DLLIMPORT void AzTextInput_setText(AzTextInput* restrict textinput, AzStr
 extern DLLIMPORT AzTextInput AzTextInput_withText(AzTextInput* restrict textinput, AzString  text);
 extern DLLIMPORT void AzTextInput_setPlaceholder(AzTextInput* restrict textinput, AzString  text);
 extern DLLIMPORT AzTextInput AzTextInput_withPlaceholder(AzTextInput* restrict textinput, AzString  text);
+extern DLLIMPORT void AzTextInput_setReadOnly(AzTextInput* restrict textinput, bool  read_only);
+extern DLLIMPORT AzTextInput AzTextInput_withReadOnly(AzTextInput* restrict textinput, bool  read_only);
+extern DLLIMPORT void AzTextInput_setDisabled(AzTextInput* restrict textinput, bool  disabled);
+extern DLLIMPORT AzTextInput AzTextInput_withDisabled(AzTextInput* restrict textinput, bool  disabled);
 extern DLLIMPORT void AzTextInput_setOnTextInput(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT AzTextInput AzTextInput_withOnTextInput(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT void AzTextInput_setOnVirtualKeyDown(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
@@ -12964,6 +13263,7 @@ extern DLLIMPORT ssize_t AzGl_getVertexAttribPointerV(const AzGl* gl, uint32_t i
 extern DLLIMPORT int32_t AzGl_getBufferParameterIv(const AzGl* gl, uint32_t target, uint32_t pname);
 extern DLLIMPORT AzString AzGl_getShaderInfoLog(const AzGl* gl, uint32_t shader);
 extern DLLIMPORT AzString AzGl_getString(const AzGl* gl, uint32_t which);
+extern DLLIMPORT AzString AzGl_getRendererName(const AzGl* gl);
 extern DLLIMPORT AzString AzGl_getStringI(const AzGl* gl, uint32_t which, uint32_t index);
 extern DLLIMPORT void AzGl_getShaderIv(const AzGl* gl, uint32_t shader, uint32_t pname, AzGLintVecRefMut  result);
 extern DLLIMPORT AzGlShaderPrecisionFormatReturn AzGl_getShaderPrecisionFormat(const AzGl* gl, uint32_t shader_type, uint32_t precision_type);
@@ -13042,6 +13342,7 @@ extern DLLIMPORT AzTextureFlags AzTextureFlags_default();
 extern DLLIMPORT AzImageRef AzImageRef_invalid(size_t width, size_t height, AzRawImageFormat  format);
 extern DLLIMPORT AzImageRef AzImageRef_rawImage(AzRawImage  data);
 extern DLLIMPORT AzImageRef AzImageRef_glTexture(AzTexture  texture);
+extern DLLIMPORT AzImageRef AzImageRef_solidColor(size_t width, size_t height, AzColorU  color);
 extern DLLIMPORT AzImageRef AzImageRef_callback(AzRefAny  data, AzRenderImageCallbackType  callback);
 extern DLLIMPORT AzImageRef AzImageRef_cloneBytes(const AzImageRef* imageref);
 extern DLLIMPORT bool  AzImageRef_isInvalid(const AzImageRef* imageref);
@@ -13241,6 +13542,8 @@ extern DLLIMPORT AzTimer AzTimer_new(AzRefAny  timer_data, AzTimerCallbackType
 extern DLLIMPORT AzTimer AzTimer_withDelay(const AzTimer* timer, AzDuration  delay);
 extern DLLIMPORT AzTimer AzTimer_withInterval(const AzTimer* timer, AzDuration  interval);
 extern DLLIMPORT AzTimer AzTimer_withTimeout(const AzTimer* timer, AzDuration  timeout);
+extern DLLIMPORT AzTimer AzTimer_withMissedTickPolicy(const AzTimer* timer, AzTimerMissedTickPolicy  missed_tick_policy);
+extern DLLIMPORT AzTimer AzTimer_withRunsWhileHidden(const AzTimer* timer, bool  runs_while_hidden);
 extern DLLIMPORT void AzTimer_delete(AzTimer* restrict instance);
 extern DLLIMPORT void AzThread_delete(AzThread* restrict instance);
 extern DLLIMPORT AzThread AzThread_deepCopy(AzThread* const instance);
@@ -13320,6 +13623,7 @@ extern DLLIMPORT void AzGLuintVec_delete(AzGLuintVec* restrict instance);
 extern DLLIMPORT void AzGLintVec_delete(AzGLintVec* restrict instance);
 extern DLLIMPORT void AzStringVec_delete(AzStringVec* restrict instance);
 extern DLLIMPORT void AzStringPairVec_delete(AzStringPairVec* restrict instance);
+extern DLLIMPORT void AzFontWarmupVec_delete(AzFontWarmupVec* restrict instance);
 extern DLLIMPORT void AzNormalizedLinearColorStopVec_delete(AzNormalizedLinearColorStopVec* restrict instance);
 extern DLLIMPORT void AzNormalizedRadialColorStopVec_delete(AzNormalizedRadialColorStopVec* restrict instance);
 extern DLLIMPORT void AzNodeIdVec_delete(AzNodeIdVec* restrict instance);
@@ -13369,6 +13673,7 @@ extern DLLIMPORT void AzOptionThreadReceiveMsg_delete(AzOptionThreadReceiveMsg*
 extern DLLIMPORT void AzOptionThreadSendMsg_delete(AzOptionThreadSendMsg* restrict instance);
 extern DLLIMPORT void AzOptionRefAny_delete(AzOptionRefAny* restrict instance);
 extern DLLIMPORT void AzOptionInlineText_delete(AzOptionInlineText* restrict instance);
+extern DLLIMPORT void AzOptionFocusTarget_delete(AzOptionFocusTarget* restrict instance);
 extern DLLIMPORT void AzOptionRawImage_delete(AzOptionRawImage* restrict instance);
 extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict instance);
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
@@ -14836,6 +15141,34 @@ extern DLLIMPORT void AzInvalidStringError_delete(AzInvalidStringError* restrict
 #define AzGl_ZOOM_X 0x0D16
 #define AzGl_ZOOM_Y 0x0D17
 
+bool AzEventLoopMode_matchRefPolling(const AzEventLoopMode* value, const Azu32** restrict out) {
+    const AzEventLoopModeVariant_Polling* casted = (const AzEventLoopModeVariant_Polling*)value;
+    bool valid = casted->tag == AzEventLoopModeTag_Polling;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzEventLoopMode_matchMutPolling(AzEventLoopMode* restrict value, Azu32* restrict * restrict out) {
+    AzEventLoopModeVariant_Polling* restrict casted = (AzEventLoopModeVariant_Polling* restrict)value;
+    bool valid = casted->tag == AzEventLoopModeTag_Polling;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzPresentMode_matchRefFrameCapped(const AzPresentMode* value, const Azu32** restrict out) {
+    const AzPresentModeVariant_FrameCapped* casted = (const AzPresentModeVariant_FrameCapped*)value;
+    bool valid = casted->tag == AzPresentModeTag_FrameCapped;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzPresentMode_matchMutFrameCapped(AzPresentMode* restrict value, Azu32* restrict * restrict out) {
+    AzPresentModeVariant_FrameCapped* restrict casted = (AzPresentModeVariant_FrameCapped* restrict)value;
+    bool valid = casted->tag == AzPresentModeTag_FrameCapped;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzRawWindowHandle_matchRefIOS(const AzRawWindowHandle* value, const AzIOSHandle** restrict out) {
     const AzRawWindowHandleVariant_IOS* casted = (const AzRawWindowHandleVariant_IOS*)value;
     bool valid = casted->tag == AzRawWindowHandleTag_IOS;
@@ -19750,6 +20083,20 @@ bool AzStringPairVecDestructor_matchMutExternal(AzStringPairVecDestructor* restr
     return valid;
 }
 
+bool AzFontWarmupVecDestructor_matchRefExternal(const AzFontWarmupVecDestructor* value, const AzFontWarmupVecDestructorType** restrict out) {
+    const AzFontWarmupVecDestructorVariant_External* casted = (const AzFontWarmupVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzFontWarmupVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFontWarmupVecDestructor_matchMutExternal(AzFontWarmupVecDestructor* restrict value, AzFontWarmupVecDestructorType* restrict * restrict out) {
+    AzFontWarmupVecDestructorVariant_External* restrict casted = (AzFontWarmupVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzFontWarmupVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzNormalizedLinearColorStopVecDestructor_matchRefExternal(const AzNormalizedLinearColorStopVecDestructor* value, const AzNormalizedLinearColorStopVecDestructorType** restrict out) {
     const AzNormalizedLinearColorStopVecDestructorVariant_External* casted = (const AzNormalizedLinearColorStopVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzNormalizedLinearColorStopVecDestructorTag_External;
@@ -20646,6 +20993,20 @@ bool AzOptionInlineText_matchMutSome(AzOptionInlineText* restrict value, AzInlin
     return valid;
 }
 
+bool AzOptionFocusTarget_matchRefSome(const AzOptionFocusTarget* value, const AzFocusTarget** restrict out) {
+    const AzOptionFocusTargetVariant_Some* casted = (const AzOptionFocusTargetVariant_Some*)value;
+    bool valid = casted->tag == AzOptionFocusTargetTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionFocusTarget_matchMutSome(AzOptionFocusTarget* restrict value, AzFocusTarget* restrict * restrict out) {
+    AzOptionFocusTargetVariant_Some* restrict casted = (AzOptionFocusTargetVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionFocusTargetTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionLayoutPoint_matchRefSome(const AzOptionLayoutPoint* value, const AzLayoutPoint** restrict out) {
     const AzOptionLayoutPointVariant_Some* casted = (const AzOptionLayoutPointVariant_Some*)value;
     bool valid = casted->tag == AzOptionLayoutPointTag_Some;
@@ -20800,6 +21161,20 @@ bool AzOptionHwndHandle_matchMutSome(AzOptionHwndHandle* restrict value, Az*mut
     return valid;
 }
 
+bool AzOptionLogicalRect_matchRefSome(const AzOptionLogicalRect* value, const AzLogicalRect** restrict out) {
+    const AzOptionLogicalRectVariant_Some* casted = (const AzOptionLogicalRectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionLogicalRect_matchMutSome(AzOptionLogicalRect* restrict value, AzLogicalRect* restrict * restrict out) {
+    AzOptionLogicalRectVariant_Some* restrict casted = (AzOptionLogicalRectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionLogicalPosition_matchRefSome(const AzOptionLogicalPosition* value, const AzLogicalPosition** restrict out) {
     const AzOptionLogicalPositionVariant_Some* casted = (const AzOptionLogicalPositionVariant_Some*)value;
     bool valid = casted->tag == AzOptionLogicalPositionTag_Some;
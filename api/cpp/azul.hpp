@@ -31,6 +31,9 @@ namespace dll {
     
     using WriteBackCallbackType = Update(*)(RefAny* restrict, RefAny* restrict, CallbackInfo* restrict);
     
+    union OptionImageRef;
+    using ImageLoaderCallbackType = OptionImageRef(*)(RefAny* restrict);
+    
     struct ThreadSender;
     struct ThreadReceiver;
     using ThreadCallbackType = void(*)(RefAny, ThreadSender, ThreadReceiver);
@@ -300,6 +303,9 @@ namespace dll {
     struct StringPairVec;
     using StringPairVecDestructorType = void(*)(StringPairVec* restrict);
     
+    struct FontWarmupVec;
+    using FontWarmupVecDestructorType = void(*)(FontWarmupVec* restrict);
+    
     struct NormalizedLinearColorStopVec;
     using NormalizedLinearColorStopVecDestructorType = void(*)(NormalizedLinearColorStopVec* restrict);
     
@@ -333,6 +339,32 @@ namespace dll {
         App() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class GpuPreference {
+       Default,
+       HighPerformance,
+       PowerSaving,
+    };
+    
+    struct GpuMemoryBudget {
+        size_t max_bytes;
+        uint32_t check_interval_ms;
+        GpuMemoryBudget& operator=(const GpuMemoryBudget&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GpuMemoryBudget() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class EventLoopModeTag {
+       Blocking,
+       Polling,
+    };
+    
+    struct EventLoopModeVariant_Blocking { EventLoopModeTag tag; };
+    struct EventLoopModeVariant_Polling { EventLoopModeTag tag; uint32_t payload; };
+    union EventLoopMode {
+        EventLoopModeVariant_Blocking Blocking;
+        EventLoopModeVariant_Polling Polling;
+    };
+    
+    
     enum class AppLogLevel {
        Off,
        Error,
@@ -346,12 +378,44 @@ namespace dll {
        Default,
     };
     
+    enum class GlContextProfile {
+       Core,
+       Compatibility,
+    };
+    
+    enum class GlBuffering {
+       DoubleBuffered,
+       SingleBufferedFlush,
+       SingleBufferedFinish,
+    };
+    
+    enum class PresentModeTag {
+       Immediate,
+       Fifo,
+       FrameCapped,
+    };
+    
+    struct PresentModeVariant_Immediate { PresentModeTag tag; };
+    struct PresentModeVariant_Fifo { PresentModeTag tag; };
+    struct PresentModeVariant_FrameCapped { PresentModeTag tag; uint32_t payload; };
+    union PresentMode {
+        PresentModeVariant_Immediate Immediate;
+        PresentModeVariant_Fifo Fifo;
+        PresentModeVariant_FrameCapped FrameCapped;
+    };
+    
+    
     enum class Vsync {
        Enabled,
        Disabled,
        DontCare,
     };
     
+    enum class RedrawPolicy {
+       OnDemand,
+       Continuous,
+    };
+    
     enum class Srgb {
        Enabled,
        Disabled,
@@ -727,6 +791,13 @@ namespace dll {
        RowResize,
     };
     
+    enum class WindowCornerPreference {
+       Default,
+       DoNotRound,
+       Round,
+       RoundSmall,
+    };
+    
     enum class RendererType {
        Hardware,
        Software,
@@ -756,6 +827,13 @@ namespace dll {
        LightMode,
     };
     
+    struct AccessibilitySettings {
+        bool  high_contrast;
+        bool  prefers_reduced_motion;
+        AccessibilitySettings& operator=(const AccessibilitySettings&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilitySettings() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TouchState {
         uint8_t unused;
         TouchState& operator=(const TouchState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -864,6 +942,13 @@ namespace dll {
         ThreadCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ImageLoaderCallback {
+        ImageLoaderCallbackType cb;
+        ImageLoaderCallback& operator=(const ImageLoaderCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ImageLoaderCallback(const ImageLoaderCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ImageLoaderCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct RefCount {
         void* ptr;
         bool  run_destructor;
@@ -872,6 +957,17 @@ namespace dll {
         RefCount() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NativeChildWindow {
+        void* restrict handle;
+        NativeChildWindow& operator=(const NativeChildWindow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NativeChildWindow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TitleBarRegion {
+       Drag,
+       NoDrag,
+    };
+    
     enum class On {
        MouseOver,
        MouseDown,
@@ -2155,6 +2251,11 @@ namespace dll {
         TimerId() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TimerMissedTickPolicy {
+       Coalesce,
+       CatchUp,
+    };
+    
     enum class TerminateTimer {
        Terminate,
        Continue,
@@ -3172,6 +3273,22 @@ namespace dll {
     };
     
     
+    enum class FontWarmupVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct FontWarmupVecDestructorVariant_DefaultRust { FontWarmupVecDestructorTag tag; };
+    struct FontWarmupVecDestructorVariant_NoDestructor { FontWarmupVecDestructorTag tag; };
+    struct FontWarmupVecDestructorVariant_External { FontWarmupVecDestructorTag tag; FontWarmupVecDestructorType payload; };
+    union FontWarmupVecDestructor {
+        FontWarmupVecDestructorVariant_DefaultRust DefaultRust;
+        FontWarmupVecDestructorVariant_NoDestructor NoDestructor;
+        FontWarmupVecDestructorVariant_External External;
+    };
+    
+    
     enum class NormalizedLinearColorStopVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3424,6 +3541,14 @@ namespace dll {
         SvgParseErrorPosition() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct HitTestTolerance {
+        FloatValue mouse;
+        FloatValue pen;
+        FloatValue touch;
+        HitTestTolerance& operator=(const HitTestTolerance&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        HitTestTolerance() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SystemCallbacks {
         CreateThreadFn create_thread_fn;
         GetSystemTimeFn get_system_time_fn;
@@ -3432,10 +3557,21 @@ namespace dll {
         SystemCallbacks() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct GlContextOptions {
+        uint8_t major_version;
+        uint8_t minor_version;
+        GlContextProfile profile;
+        bool  debug;
+        GlBuffering buffering;
+        GlContextOptions& operator=(const GlContextOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GlContextOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct RendererOptions {
         Vsync vsync;
         Srgb srgb;
         HwAcceleration hw_accel;
+        PresentMode present_mode;
         RendererOptions& operator=(const RendererOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         RendererOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -3507,6 +3643,15 @@ namespace dll {
     };
     
     
+    struct Letterbox {
+        float scale;
+        LogicalRect content;
+        LogicalRect leading_bar;
+        LogicalRect trailing_bar;
+        Letterbox& operator=(const Letterbox&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Letterbox() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowFlags {
         WindowFrame frame;
         bool  is_about_to_close;
@@ -3519,6 +3664,9 @@ namespace dll {
         bool  has_blur_behind_window;
         bool  smooth_scroll_enabled;
         bool  autotab_enabled;
+        bool  zoom_shortcuts_enabled;
+        bool  ctrl_wheel_zoom_enabled;
+        bool  is_shown_in_taskbar;
         WindowFlags& operator=(const WindowFlags&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowFlags() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -3637,6 +3785,16 @@ namespace dll {
         InlineTextHit() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextConstraints {
+        OptionF32 max_width;
+        OptionF32 line_height;
+        OptionF32 letter_spacing;
+        OptionF32 word_spacing;
+        OptionF32 tab_width;
+        TextConstraints& operator=(const TextConstraints&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextConstraints() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct IFrameCallbackInfo {
         void* system_fonts;
         void* image_cache;
@@ -6914,6 +7072,19 @@ namespace dll {
     };
     
     
+    enum class OptionLogicalRectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionLogicalRectVariant_None { OptionLogicalRectTag tag; };
+    struct OptionLogicalRectVariant_Some { OptionLogicalRectTag tag; LogicalRect payload; };
+    union OptionLogicalRect {
+        OptionLogicalRectVariant_None None;
+        OptionLogicalRectVariant_Some Some;
+    };
+    
+    
     enum class OptionLogicalPositionTag {
        None,
        Some,
@@ -7108,18 +7279,6 @@ namespace dll {
         InvalidSpaceError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct AppConfig {
-        LayoutSolver layout_solver;
-        AppLogLevel log_level;
-        bool  enable_visual_panic_hook;
-        bool  enable_logging_on_panic;
-        bool  enable_tab_navigation;
-        SystemCallbacks system_callbacks;
-        AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
-        AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct SmallWindowIconBytes {
         IconKey key;
         U8Vec rgba_bytes;
@@ -7160,8 +7319,11 @@ namespace dll {
     struct WindowSize {
         LogicalSize dimensions;
         uint32_t dpi;
+        float content_scale;
         OptionLogicalSize min_dimensions;
         OptionLogicalSize max_dimensions;
+        OptionLogicalSize fixed_logical_size;
+        Letterbox letterbox;
         WindowSize& operator=(const WindowSize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowSize() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -7190,6 +7352,14 @@ namespace dll {
         MouseState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextCaret {
+        OptionLogicalRect caret_rect;
+        LogicalRectVec selection_rects;
+        TextCaret& operator=(const TextCaret&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextCaret(const TextCaret&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextCaret() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct MarshaledLayoutCallback {
         RefAny marshal_data;
         MarshaledLayoutCallbackInner cb;
@@ -8106,6 +8276,15 @@ namespace dll {
         OptionWindowIcon window_icon;
         OptionTaskBarIcon taskbar_icon;
         OptionHwndHandle parent_window;
+        OptionF32 alpha_hit_test_threshold;
+        bool  alpha_hit_test_pass_through;
+        WindowCornerPreference corner_preference;
+        OptionString app_user_model_id;
+        bool  animate_frame_changes;
+        OptionHwndHandle shared_gl_context;
+        bool  embed_in_parent;
+        bool  skip_rendering_when_occluded;
+        bool  borderless_shadow;
         WindowsWindowOptions& operator=(const WindowsWindowOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowsWindowOptions(const WindowsWindowOptions&) = delete; /* disable copy constructor, use explicit .clone() */
         WindowsWindowOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8461,6 +8640,8 @@ namespace dll {
         size_t max_len;
         OptionTextInputSelection selection;
         size_t cursor_pos;
+        bool  read_only;
+        bool  disabled;
         TextInputState& operator=(const TextInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         TextInputState(const TextInputState&) = delete; /* disable copy constructor, use explicit .clone() */
         TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8670,6 +8851,8 @@ namespace dll {
         OptionDuration delay;
         OptionDuration interval;
         OptionDuration timeout;
+        TimerMissedTickPolicy missed_tick_policy;
+        bool  runs_while_hidden;
         TimerCallback callback;
         Timer& operator=(const Timer&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         Timer(const Timer&) = delete; /* disable copy constructor, use explicit .clone() */
@@ -8967,6 +9150,15 @@ namespace dll {
     };
     
     
+    struct FontWarmup {
+        StyleFontFamilyVec font;
+        StyleFontSize size;
+        String characters;
+        FontWarmup& operator=(const FontWarmup&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FontWarmup(const FontWarmup&) = delete; /* disable copy constructor, use explicit .clone() */
+        FontWarmup() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinuxWindowOptions {
         OptionX11Visual x11_visual;
         OptionI32 x11_screen;
@@ -9508,6 +9700,16 @@ namespace dll {
         SvgSimpleNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct FontWarmupVec {
+        FontWarmup* ptr;
+        size_t len;
+        size_t cap;
+        FontWarmupVecDestructor destructor;
+        FontWarmupVec& operator=(const FontWarmupVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FontWarmupVec(const FontWarmupVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        FontWarmupVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionCssPropertyTag {
        None,
        Some,
@@ -9529,6 +9731,28 @@ namespace dll {
         XmlTextError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AppConfig {
+        LayoutSolver layout_solver;
+        AppLogLevel log_level;
+        bool  enable_visual_panic_hook;
+        bool  enable_logging_on_panic;
+        bool  enable_tab_navigation;
+        SystemCallbacks system_callbacks;
+        EventLoopMode event_loop_mode;
+        GpuPreference gpu_preference;
+        String window_class_name;
+        GpuMemoryBudget gpu_memory_budget;
+        OptionString app_user_model_id;
+        OptionString shader_cache_dir;
+        HitTestTolerance hit_test_tolerance;
+        FontWarmupVec font_warmup;
+        bool  async_scene_building;
+        OptionDuration frame_budget;
+        AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
+        AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PlatformSpecificOptions {
         WindowsWindowOptions windows_options;
         LinuxWindowOptions linux_options;
@@ -9550,9 +9774,12 @@ namespace dll {
         MouseState mouse_state;
         TouchState touch_state;
         ImePosition ime_position;
+        TextCaret text_caret;
+        AccessibilitySettings accessibility;
         Monitor monitor;
         PlatformSpecificOptions platform_specific_options;
         RendererOptions renderer_options;
+        RedrawPolicy redraw_policy;
         ColorU background_color;
         LayoutCallback layout_callback;
         OptionCallback close_callback;
@@ -9785,18 +10012,6 @@ namespace dll {
     };
     
     
-    struct WindowCreateOptions {
-        WindowState state;
-        bool  size_to_content;
-        OptionRendererOptions renderer_type;
-        OptionWindowTheme theme;
-        OptionCallback create_callback;
-        bool  hot_reload;
-        WindowCreateOptions& operator=(const WindowCreateOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        WindowCreateOptions(const WindowCreateOptions&) = delete; /* disable copy constructor, use explicit .clone() */
-        WindowCreateOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     enum class FocusTargetTag {
        Id,
        Path,
@@ -9956,6 +10171,19 @@ namespace dll {
         NodeDataVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class OptionFocusTargetTag {
+       None,
+       Some,
+    };
+    
+    struct OptionFocusTargetVariant_None { OptionFocusTargetTag tag; };
+    struct OptionFocusTargetVariant_Some { OptionFocusTargetTag tag; FocusTarget payload; };
+    union OptionFocusTarget {
+        OptionFocusTargetVariant_None None;
+        OptionFocusTargetVariant_Some Some;
+    };
+    
+    
     enum class XmlErrorTag {
        NoParserAvailable,
        InvalidXmlPrefixUri,
@@ -10014,6 +10242,23 @@ namespace dll {
     };
     
     
+    struct WindowCreateOptions {
+        WindowState state;
+        bool  size_to_content;
+        OptionRendererOptions renderer_type;
+        OptionWindowTheme theme;
+        OptionCallback create_callback;
+        bool  hot_reload;
+        bool  center_on_show;
+        GlContextOptions gl_context;
+        bool  show_after_first_frame;
+        OptionCallback first_frame_callback;
+        OptionFocusTarget initial_focus;
+        WindowCreateOptions& operator=(const WindowCreateOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        WindowCreateOptions(const WindowCreateOptions&) = delete; /* disable copy constructor, use explicit .clone() */
+        WindowCreateOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Dom {
         NodeData root;
         DomVec children;
@@ -10209,10 +10454,18 @@ namespace dll {
         void App_addWindow(App* restrict app, AzWindowCreateOptions  window);
         void App_addImage(App* restrict app, AzString  id, AzImageRef  image);
         MonitorVec App_getMonitors(const App* app);
-        void App_run(const App* app, AzWindowCreateOptions  window);
+        bool  App_pumpEvents(const App* app);
+        int32_t App_run(const App* app, AzWindowCreateOptions  window);
         void App_delete(App* restrict instance);
         App App_deepCopy(App* const instance);
         AppConfig AppConfig_new(AzLayoutSolver  layout_solver);
+        void AppConfig_delete(AppConfig* restrict instance);
+        GpuMemoryBudget GpuMemoryBudget_unlimited();
+        GpuMemoryBudget GpuMemoryBudget_new(size_t max_bytes);
+        HitTestTolerance HitTestTolerance_none();
+        HitTestTolerance HitTestTolerance_new(float mouse, float pen, float touch);
+        FontWarmup FontWarmup_new(AzStyleFontFamilyVec  font, AzStyleFontSize  size, AzString  characters);
+        void FontWarmup_delete(FontWarmup* restrict instance);
         SystemCallbacks SystemCallbacks_libraryInternal();
         WindowCreateOptions WindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
         void WindowCreateOptions_delete(WindowCreateOptions* restrict instance);
@@ -10236,6 +10489,7 @@ namespace dll {
         void WaylandTheme_delete(WaylandTheme* restrict instance);
         void StringPair_delete(StringPair* restrict instance);
         void LinuxWindowOptions_delete(LinuxWindowOptions* restrict instance);
+        void TextCaret_delete(TextCaret* restrict instance);
         void Monitor_delete(Monitor* restrict instance);
         WindowState WindowState_new(AzLayoutCallbackType  layout_callback);
         WindowState WindowState_default();
@@ -10262,6 +10516,7 @@ namespace dll {
         OptionFontRef CallbackInfo_getFontRef(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
         OptionResolvedTextLayoutOptions CallbackInfo_getTextLayoutOptions(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
         OptionInlineText CallbackInfo_shapeText(const CallbackInfo* callbackinfo, AzDomNodeId  node_id, AzString  text);
+        OptionInlineText CallbackInfo_measureText(const CallbackInfo* callbackinfo, AzStyleFontFamilyVec  font, AzStyleFontSize  font_size, AzString  text, AzTextConstraints  constraints);
         size_t CallbackInfo_getIndexInParent(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionDomNodeId CallbackInfo_getParent(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionDomNodeId CallbackInfo_getPreviousSibling(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
@@ -10282,7 +10537,13 @@ namespace dll {
         void CallbackInfo_updateImage(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageRef  new_image, AzUpdateImageType  image_type);
         void CallbackInfo_deleteImage(CallbackInfo* restrict callbackinfo, AzString  id);
         void CallbackInfo_updateImageMask(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
+        OptionThreadId CallbackInfo_loadImageAsync(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzRefAny  data, AzImageLoaderCallbackType  loader, AzImageRef  fallback);
         void CallbackInfo_stopPropagation(CallbackInfo* restrict callbackinfo);
+        void CallbackInfo_forceFrame(const CallbackInfo* callbackinfo);
+        bool  CallbackInfo_confineToMonitor(const CallbackInfo* callbackinfo, size_t monitor_id);
+        void CallbackInfo_releaseFromMonitor(const CallbackInfo* callbackinfo);
+        bool  CallbackInfo_lockAspectRatio(const CallbackInfo* callbackinfo, float ratio);
+        void CallbackInfo_unlockAspectRatio(const CallbackInfo* callbackinfo);
         void CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
         TimerId CallbackInfo_startTimer(CallbackInfo* restrict callbackinfo, AzTimer  timer);
         OptionTimerId CallbackInfo_startAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
@@ -10306,6 +10567,7 @@ namespace dll {
         void FocusTargetPath_delete(FocusTargetPath* restrict instance);
         ResolvedTextLayoutOptions ResolvedTextLayoutOptions_default();
         void ResolvedTextLayoutOptions_delete(ResolvedTextLayoutOptions* restrict instance);
+        TextConstraints TextConstraints_default();
         void Animation_delete(Animation* restrict instance);
         void IFrameCallbackReturn_delete(IFrameCallbackReturn* restrict instance);
         OptionGl RenderImageCallbackInfo_getGlContext(const RenderImageCallbackInfo* renderimagecallbackinfo);
@@ -10336,6 +10598,7 @@ namespace dll {
         OptionGl LayoutCallbackInfo_getGlContext(const LayoutCallbackInfo* layoutcallbackinfo);
         StringPairVec LayoutCallbackInfo_getSystemFonts(const LayoutCallbackInfo* layoutcallbackinfo);
         OptionImageRef LayoutCallbackInfo_getImage(const LayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+        OptionInlineText LayoutCallbackInfo_measureText(const LayoutCallbackInfo* layoutcallbackinfo, AzStyleFontFamilyVec  font, AzStyleFontSize  font_size, AzString  text, AzTextConstraints  constraints);
         void LayoutCallbackInfo_delete(LayoutCallbackInfo* restrict instance);
         Dom Dom_new(AzNodeType  node_type);
         Dom Dom_body();
@@ -10344,6 +10607,7 @@ namespace dll {
         Dom Dom_text(AzString  string);
         Dom Dom_image(AzImageRef  image);
         Dom Dom_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+        Dom Dom_nativeChildWindow(AzNativeChildWindow  window);
         void Dom_setNodeType(Dom* restrict dom, AzNodeType  node_type);
         Dom Dom_withNodeType(Dom* restrict dom, AzNodeType  node_type);
         void Dom_setDataset(Dom* restrict dom, AzRefAny  dataset);
@@ -10390,6 +10654,8 @@ namespace dll {
         Dom Dom_withMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         void Dom_setContextMenu(Dom* restrict dom, AzMenu  context_menu);
         Dom Dom_withContextMenu(Dom* restrict dom, AzMenu  context_menu);
+        void Dom_setTitleBarRegion(Dom* restrict dom, AzTitleBarRegion  region);
+        Dom Dom_withTitleBarRegion(Dom* restrict dom, AzTitleBarRegion  region);
         uint64_t Dom_hash(const Dom* dom);
         size_t Dom_nodeCount(const Dom* dom);
         String Dom_getHtmlString(Dom* restrict dom);
@@ -10430,6 +10696,8 @@ namespace dll {
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
+        void NodeData_setNativeChildWindow(NodeData* restrict nodedata, AzNativeChildWindow  window);
+        void NodeData_setTitleBarRegion(NodeData* restrict nodedata, AzTitleBarRegion  region);
         uint64_t NodeData_hash(const NodeData* nodedata);
         void NodeData_delete(NodeData* restrict instance);
         void NodeType_delete(NodeType* restrict instance);
@@ -10528,6 +10796,10 @@ namespace dll {
         TextInput TextInput_withText(TextInput* restrict textinput, AzString  text);
         void TextInput_setPlaceholder(TextInput* restrict textinput, AzString  text);
         TextInput TextInput_withPlaceholder(TextInput* restrict textinput, AzString  text);
+        void TextInput_setReadOnly(TextInput* restrict textinput, bool  read_only);
+        TextInput TextInput_withReadOnly(TextInput* restrict textinput, bool  read_only);
+        void TextInput_setDisabled(TextInput* restrict textinput, bool  disabled);
+        TextInput TextInput_withDisabled(TextInput* restrict textinput, bool  disabled);
         void TextInput_setOnTextInput(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         TextInput TextInput_withOnTextInput(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         void TextInput_setOnVirtualKeyDown(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
@@ -10825,6 +11097,7 @@ namespace dll {
         int32_t Gl_getBufferParameterIv(const Gl* gl, uint32_t target, uint32_t pname);
         String Gl_getShaderInfoLog(const Gl* gl, uint32_t shader);
         String Gl_getString(const Gl* gl, uint32_t which);
+        String Gl_getRendererName(const Gl* gl);
         String Gl_getStringI(const Gl* gl, uint32_t which, uint32_t index);
         void Gl_getShaderIv(const Gl* gl, uint32_t shader, uint32_t pname, AzGLintVecRefMut  result);
         GlShaderPrecisionFormatReturn Gl_getShaderPrecisionFormat(const Gl* gl, uint32_t shader_type, uint32_t precision_type);
@@ -10903,6 +11176,7 @@ namespace dll {
         ImageRef ImageRef_invalid(size_t width, size_t height, AzRawImageFormat  format);
         ImageRef ImageRef_rawImage(AzRawImage  data);
         ImageRef ImageRef_glTexture(AzTexture  texture);
+        ImageRef ImageRef_solidColor(size_t width, size_t height, AzColorU  color);
         ImageRef ImageRef_callback(AzRefAny  data, AzRenderImageCallbackType  callback);
         ImageRef ImageRef_cloneBytes(const ImageRef* imageref);
         bool  ImageRef_isInvalid(const ImageRef* imageref);
@@ -11102,6 +11376,8 @@ namespace dll {
         Timer Timer_withDelay(const Timer* timer, AzDuration  delay);
         Timer Timer_withInterval(const Timer* timer, AzDuration  interval);
         Timer Timer_withTimeout(const Timer* timer, AzDuration  timeout);
+        Timer Timer_withMissedTickPolicy(const Timer* timer, AzTimerMissedTickPolicy  missed_tick_policy);
+        Timer Timer_withRunsWhileHidden(const Timer* timer, bool  runs_while_hidden);
         void Timer_delete(Timer* restrict instance);
         void Thread_delete(Thread* restrict instance);
         Thread Thread_deepCopy(Thread* const instance);
@@ -11181,6 +11457,7 @@ namespace dll {
         void GLintVec_delete(GLintVec* restrict instance);
         void StringVec_delete(StringVec* restrict instance);
         void StringPairVec_delete(StringPairVec* restrict instance);
+        void FontWarmupVec_delete(FontWarmupVec* restrict instance);
         void NormalizedLinearColorStopVec_delete(NormalizedLinearColorStopVec* restrict instance);
         void NormalizedRadialColorStopVec_delete(NormalizedRadialColorStopVec* restrict instance);
         void NodeIdVec_delete(NodeIdVec* restrict instance);
@@ -11230,6 +11507,7 @@ namespace dll {
         void OptionThreadSendMsg_delete(OptionThreadSendMsg* restrict instance);
         void OptionRefAny_delete(OptionRefAny* restrict instance);
         void OptionInlineText_delete(OptionInlineText* restrict instance);
+        void OptionFocusTarget_delete(OptionFocusTarget* restrict instance);
         void OptionRawImage_delete(OptionRawImage* restrict instance);
         void OptionWaylandTheme_delete(OptionWaylandTheme* restrict instance);
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
@@ -691,6 +691,40 @@ mod dll {
             pub run_destructor: bool,
         }
 
+        /// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzGpuPreference {
+            Default,
+            HighPerformance,
+            PowerSaving,
+        }
+
+        /// Limits the GPU memory (texture cache, render targets, images) used by the renderer of a window: every `check_interval_ms`, the memory usage is compared against `max_bytes` and if it is exceeded, the renderer evicts its caches
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGpuMemoryBudget {
+            pub max_bytes: usize,
+            pub check_interval_ms: u32,
+        }
+
+        /// Strategy of the event loop
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzEventLoopMode {
+            Blocking,
+            Polling(u32),
+        }
+
         /// Configuration to set which messages should be logged.
         #[repr(C)]
         #[derive(Debug)]
@@ -718,6 +752,42 @@ mod dll {
             Default,
         }
 
+        /// OpenGL context profile
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzGlContextProfile {
+            Core,
+            Compatibility,
+        }
+
+        /// Buffering of the OpenGL surface of a window: double-buffered surfaces never show partially drawn frames, but may wait for the next vsync / compositor cycle; single-buffered surfaces render directly into the visible buffer (lower latency for overlays), at the cost of possible tearing and flickering
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzGlBuffering {
+            DoubleBuffered,
+            SingleBufferedFlush,
+            SingleBufferedFinish,
+        }
+
+        /// How rendered frames are presented to the screen: controls vsync (swap interval), tearing and frame pacing
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub enum AzPresentMode {
+            Immediate,
+            Fifo,
+            FrameCapped(u32),
+        }
+
         /// Whether the renderer has VSync enabled
         #[repr(C)]
         #[derive(Debug)]
@@ -731,6 +801,18 @@ mod dll {
             DontCare,
         }
 
+        /// Whether a window only renders when its content changed or on every frame
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub enum AzRedrawPolicy {
+            OnDemand,
+            Continuous,
+        }
+
         /// Does the renderer render in SRGB color space? By default, azul tries to set it to `Enabled` and falls back to `Disabled` if the OpenGL context can't be initialized properly
         #[repr(C)]
         #[derive(Debug)]
@@ -1219,6 +1301,19 @@ mod dll {
             RowResize,
         }
 
+        /// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzWindowCornerPreference {
+            Default,
+            DoNotRound,
+            Round,
+            RoundSmall,
+        }
+
         /// Renderer type of the current windows OpenGL context
         #[repr(C)]
         #[derive(Debug)]
@@ -1276,6 +1371,18 @@ mod dll {
             LightMode,
         }
 
+        /// Accessibility preferences of the user, set by the operating system
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub struct AzAccessibilitySettings {
+            pub high_contrast: bool,
+            pub prefers_reduced_motion: bool,
+        }
+
         /// Current state of touch devices / touch inputs
         #[repr(C)]
         #[derive(Debug)]
@@ -1451,6 +1558,16 @@ mod dll {
             pub cb: AzThreadCallbackType,
         }
 
+        /// Re-export of rust-allocated (stack based) `ImageLoaderCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzImageLoaderCallback {
+            pub cb: AzImageLoaderCallbackType,
+        }
+
+        /// `AzImageLoaderCallbackType` struct
+        pub type AzImageLoaderCallbackType = extern "C" fn(&mut AzRefAny) -> AzOptionImageRef;
+
         /// `AzThreadCallbackType` struct
         pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
@@ -1466,6 +1583,27 @@ mod dll {
             pub run_destructor: bool,
         }
 
+        /// Handle of a native child window (`HWND` on Windows) that is embedded into the layout, see `NodeData::set_native_child_window`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzNativeChildWindow {
+            pub handle: *mut c_void,
+        }
+
+        /// Role of a node in a title bar that is drawn by the application (similar to the CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzTitleBarRegion {
+            Drag,
+            NoDrag,
+        }
+
         /// When to call a callback action - `On::MouseOver`, `On::MouseOut`, etc.
         #[repr(C)]
         #[derive(Debug)]
@@ -3320,6 +3458,17 @@ mod dll {
             pub id: usize,
         }
 
+        /// How a timer with an interval should handle ticks that were missed because the application was busy or in the background
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzTimerMissedTickPolicy {
+            Coalesce,
+            CatchUp,
+        }
+
         /// Should a timer terminate or not - used to remove active timers
         #[repr(C)]
         #[derive(Debug)]
@@ -4209,6 +4358,19 @@ mod dll {
         /// `AzStringPairVecDestructorType` struct
         pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
 
+        /// Re-export of rust-allocated (stack based) `FontWarmupVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzFontWarmupVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzFontWarmupVecDestructorType),
+        }
+
+        /// `AzFontWarmupVecDestructorType` struct
+        pub type AzFontWarmupVecDestructorType = extern "C" fn(&mut AzFontWarmupVec);
+
         /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4420,6 +4582,18 @@ mod dll {
             pub col: u32,
         }
 
+        /// Radius (in logical pixels) by which the hit-testable area of every node is expanded when hit-testing a pointer position, so that small targets can be hit with imprecise input devices: if the pointer misses all nodes, the nearest node within the radius is hit instead
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzHitTestTolerance {
+            pub mouse: AzFloatValue,
+            pub pen: AzFloatValue,
+            pub touch: AzFloatValue,
+        }
+
         /// External system callbacks to get the system time or create / manage threads
         #[repr(C)]
         #[derive(Debug)]
@@ -4430,6 +4604,20 @@ mod dll {
             pub get_system_time_fn: AzGetSystemTimeFn,
         }
 
+        /// OpenGL context version, profile and flags requested for a window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGlContextOptions {
+            pub major_version: u8,
+            pub minor_version: u8,
+            pub profile: AzGlContextProfile,
+            pub debug: bool,
+            pub buffering: AzGlBuffering,
+        }
+
         /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
         #[repr(C)]
         #[derive(Debug)]
@@ -4441,6 +4629,7 @@ mod dll {
             pub vsync: AzVsync,
             pub srgb: AzSrgb,
             pub hw_accel: AzHwAcceleration,
+            pub present_mode: AzPresentMode,
         }
 
         /// Represents a rectangle in physical pixels (integer units)
@@ -4499,6 +4688,20 @@ mod dll {
             Key(AzVirtualKeyCode),
         }
 
+        /// Placement of a window with a fixed logical size inside the actual window: all rects are in logical units of the window (physical pixels / DPI factor)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub struct AzLetterbox {
+            pub scale: f32,
+            pub content: AzLogicalRect,
+            pub leading_bar: AzLogicalRect,
+            pub trailing_bar: AzLogicalRect,
+        }
+
         /// Boolean flags relating to the current window state
         #[repr(C)]
         #[derive(Debug)]
@@ -4518,6 +4721,9 @@ mod dll {
             pub has_blur_behind_window: bool,
             pub smooth_scroll_enabled: bool,
             pub autotab_enabled: bool,
+            pub zoom_shortcuts_enabled: bool,
+            pub ctrl_wheel_zoom_enabled: bool,
+            pub is_shown_in_taskbar: bool,
         }
 
         /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -4640,6 +4846,20 @@ mod dll {
             pub char_index_relative_to_word: usize,
         }
 
+        /// Constraints for measuring a text outside of the DOM (`measure_text`), the values are the resolved values of the CSS properties of a text node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTextConstraints {
+            pub max_width: AzOptionF32,
+            pub line_height: AzOptionF32,
+            pub letter_spacing: AzOptionF32,
+            pub word_spacing: AzOptionF32,
+            pub tab_width: AzOptionF32,
+        }
+
         /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -7592,6 +7812,17 @@ mod dll {
             Some(AzSvgDashPattern),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionLogicalRect {
+            None,
+            Some(AzLogicalRect),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7778,20 +8009,6 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
-        /// Configuration for optional features, such as whether to enable logging or panic hooks
-        #[repr(C)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzAppConfig {
-            pub layout_solver: AzLayoutSolver,
-            pub log_level: AzAppLogLevel,
-            pub enable_visual_panic_hook: bool,
-            pub enable_logging_on_panic: bool,
-            pub enable_tab_navigation: bool,
-            pub system_callbacks: AzSystemCallbacks,
-        }
-
         /// Small (16x16x4) window icon, usually shown in the window titlebar
         #[repr(C)]
         #[derive(Debug)]
@@ -7842,8 +8059,11 @@ mod dll {
         pub struct AzWindowSize {
             pub dimensions: AzLogicalSize,
             pub dpi: u32,
+            pub content_scale: f32,
             pub min_dimensions: AzOptionLogicalSize,
             pub max_dimensions: AzOptionLogicalSize,
+            pub fixed_logical_size: AzOptionLogicalSize,
+            pub letterbox: AzLetterbox,
         }
 
         /// Current keyboard state, stores what keys / characters have been pressed
@@ -7874,6 +8094,16 @@ mod dll {
             pub scroll_y: AzOptionF32,
         }
 
+        /// Caret and selection of the currently focused text input, in logical coordinates relative to the top left of the window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTextCaret {
+            pub caret_rect: AzOptionLogicalRect,
+            pub selection_rects: AzLogicalRectVec,
+        }
+
         /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
         #[repr(C)]
         #[derive(Debug)]
@@ -8684,6 +8914,15 @@ mod dll {
             pub window_icon: AzOptionWindowIcon,
             pub taskbar_icon: AzOptionTaskBarIcon,
             pub parent_window: AzOptionHwndHandle,
+            pub alpha_hit_test_threshold: AzOptionF32,
+            pub alpha_hit_test_pass_through: bool,
+            pub corner_preference: AzWindowCornerPreference,
+            pub app_user_model_id: AzOptionString,
+            pub animate_frame_changes: bool,
+            pub shared_gl_context: AzOptionHwndHandle,
+            pub embed_in_parent: bool,
+            pub skip_rendering_when_occluded: bool,
+            pub borderless_shadow: bool,
         }
 
         /// CSD theme of the window title / button controls
@@ -8984,6 +9223,8 @@ mod dll {
             pub max_len: usize,
             pub selection: AzOptionTextInputSelection,
             pub cursor_pos: usize,
+            pub read_only: bool,
+            pub disabled: bool,
         }
 
         /// Re-export of rust-allocated (stack based) `TabHeader` struct
@@ -9219,6 +9460,8 @@ mod dll {
             pub delay: AzOptionDuration,
             pub interval: AzOptionDuration,
             pub timeout: AzOptionDuration,
+            pub missed_tick_policy: AzTimerMissedTickPolicy,
+            pub runs_while_hidden: bool,
             pub callback: AzTimerCallback,
         }
 
@@ -9434,6 +9677,17 @@ mod dll {
             InvalidCharacterData,
         }
 
+        /// Font that is loaded at a given size when a window is created, together with the characters that are rasterized up front (see `AppConfig::font_warmup`)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzFontWarmup {
+            pub font: AzStyleFontFamilyVec,
+            pub size: AzStyleFontSize,
+            pub characters: AzString,
+        }
+
         /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9817,6 +10071,15 @@ mod dll {
             pub destructor: AzSvgSimpleNodeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `FontWarmupVec`
+        #[repr(C)]
+        pub struct AzFontWarmupVec {
+            pub(crate) ptr: *const AzFontWarmup,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzFontWarmupVecDestructor,
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9837,6 +10100,30 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
+        /// Configuration for optional features, such as whether to enable logging or panic hooks
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzAppConfig {
+            pub layout_solver: AzLayoutSolver,
+            pub log_level: AzAppLogLevel,
+            pub enable_visual_panic_hook: bool,
+            pub enable_logging_on_panic: bool,
+            pub enable_tab_navigation: bool,
+            pub system_callbacks: AzSystemCallbacks,
+            pub event_loop_mode: AzEventLoopMode,
+            pub gpu_preference: AzGpuPreference,
+            pub window_class_name: AzString,
+            pub gpu_memory_budget: AzGpuMemoryBudget,
+            pub app_user_model_id: AzOptionString,
+            pub shader_cache_dir: AzOptionString,
+            pub hit_test_tolerance: AzHitTestTolerance,
+            pub font_warmup: AzFontWarmupVec,
+            pub async_scene_building: bool,
+            pub frame_budget: AzOptionDuration,
+        }
+
         /// Platform-specific window configuration, i.e. WM options that are not cross-platform
         #[repr(C)]
         #[derive(Debug)]
@@ -9865,9 +10152,12 @@ mod dll {
             pub mouse_state: AzMouseState,
             pub touch_state: AzTouchState,
             pub ime_position: AzImePosition,
+            pub text_caret: AzTextCaret,
+            pub accessibility: AzAccessibilitySettings,
             pub monitor: AzMonitor,
             pub platform_specific_options: AzPlatformSpecificOptions,
             pub renderer_options: AzRendererOptions,
+            pub redraw_policy: AzRedrawPolicy,
             pub background_color: AzColorU,
             pub layout_callback: AzLayoutCallback,
             pub close_callback: AzOptionCallback,
@@ -10069,20 +10359,6 @@ mod dll {
             UnknownToken(AzSvgParseErrorPosition),
         }
 
-        /// Options on how to initially create the window
-        #[repr(C)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzWindowCreateOptions {
-            pub state: AzWindowState,
-            pub size_to_content: bool,
-            pub renderer_type: AzOptionRendererOptions,
-            pub theme: AzOptionWindowTheme,
-            pub create_callback: AzOptionCallback,
-            pub hot_reload: bool,
-        }
-
         /// Defines the keyboard input focus target
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10241,6 +10517,16 @@ mod dll {
             pub destructor: AzNodeDataVecDestructor,
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionFocusTarget` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionFocusTarget {
+            None,
+            Some(AzFocusTarget),
+        }
+
         /// Re-export of rust-allocated (stack based) `XmlError` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10266,6 +10552,25 @@ mod dll {
             ParserError(AzXmlParseError),
         }
 
+        /// Options on how to initially create the window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzWindowCreateOptions {
+            pub state: AzWindowState,
+            pub size_to_content: bool,
+            pub renderer_type: AzOptionRendererOptions,
+            pub theme: AzOptionWindowTheme,
+            pub create_callback: AzOptionCallback,
+            pub hot_reload: bool,
+            pub center_on_show: bool,
+            pub gl_context: AzGlContextOptions,
+            pub show_after_first_frame: bool,
+            pub first_frame_callback: AzOptionCallback,
+            pub initial_focus: AzOptionFocusTarget,
+        }
+
         /// Re-export of rust-allocated (stack based) `Dom` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10460,10 +10765,16 @@ mod dll {
         pub(crate) fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_addWindow(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzApp_addImage(transmute(app), transmute(id), transmute(image))) } }
         pub(crate) fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { unsafe { transmute(azul::AzApp_getMonitors(transmute(app))) } }
-        pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
+        pub(crate) fn AzApp_pumpEvents(app: &AzApp) -> bool { unsafe { transmute(azul::AzApp_pumpEvents(transmute(app))) } }
+        pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) -> i32 { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_delete(object: &mut AzApp) { unsafe { transmute(azul::AzApp_delete(transmute(object))) } }
         pub(crate) fn AzApp_deepCopy(object: &AzApp) -> AzApp { unsafe { transmute(azul::AzApp_deepCopy(transmute(object))) } }
         pub(crate) fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { unsafe { transmute(azul::AzAppConfig_new(transmute(layout_solver))) } }
+        pub(crate) fn AzGpuMemoryBudget_unlimited() -> AzGpuMemoryBudget { unsafe { transmute(azul::AzGpuMemoryBudget_unlimited()) } }
+        pub(crate) fn AzGpuMemoryBudget_new(max_bytes: usize) -> AzGpuMemoryBudget { unsafe { transmute(azul::AzGpuMemoryBudget_new(transmute(max_bytes))) } }
+        pub(crate) fn AzHitTestTolerance_none() -> AzHitTestTolerance { unsafe { transmute(azul::AzHitTestTolerance_none()) } }
+        pub(crate) fn AzHitTestTolerance_new(mouse: f32, pen: f32, touch: f32) -> AzHitTestTolerance { unsafe { transmute(azul::AzHitTestTolerance_new(transmute(mouse), transmute(pen), transmute(touch))) } }
+        pub(crate) fn AzFontWarmup_new(font: AzStyleFontFamilyVec, size: AzStyleFontSize, characters: AzString) -> AzFontWarmup { unsafe { transmute(azul::AzFontWarmup_new(transmute(font), transmute(size), transmute(characters))) } }
         pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { unsafe { transmute(azul::AzSystemCallbacks_libraryInternal()) } }
         pub(crate) fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_new(transmute(layout_callback))) } }
         pub(crate) fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_new(transmute(x), transmute(y))) } }
@@ -10498,6 +10809,7 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getFontRef(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionFontRef { unsafe { transmute(azul::AzCallbackInfo_getFontRef(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getTextLayoutOptions(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionResolvedTextLayoutOptions { unsafe { transmute(azul::AzCallbackInfo_getTextLayoutOptions(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_shapeText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId, text: AzString) -> AzOptionInlineText { unsafe { transmute(azul::AzCallbackInfo_shapeText(transmute(callbackinfo), transmute(node_id), transmute(text))) } }
+        pub(crate) fn AzCallbackInfo_measureText(callbackinfo: &AzCallbackInfo, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: AzString, constraints: AzTextConstraints) -> AzOptionInlineText { unsafe { transmute(azul::AzCallbackInfo_measureText(transmute(callbackinfo), transmute(font), transmute(font_size), transmute(text), transmute(constraints))) } }
         pub(crate) fn AzCallbackInfo_getIndexInParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> usize { unsafe { transmute(azul::AzCallbackInfo_getIndexInParent(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getParent(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getPreviousSibling(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getPreviousSibling(transmute(callbackinfo), transmute(node_id))) } }
@@ -10518,7 +10830,13 @@ mod dll {
         pub(crate) fn AzCallbackInfo_updateImage(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_image: AzImageRef, image_type: AzUpdateImageType) { unsafe { transmute(azul::AzCallbackInfo_updateImage(transmute(callbackinfo), transmute(node_id), transmute(new_image), transmute(image_type))) } }
         pub(crate) fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { unsafe { transmute(azul::AzCallbackInfo_deleteImage(transmute(callbackinfo), transmute(id))) } }
         pub(crate) fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { unsafe { transmute(azul::AzCallbackInfo_updateImageMask(transmute(callbackinfo), transmute(node_id), transmute(new_mask))) } }
+        pub(crate) fn AzCallbackInfo_loadImageAsync(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, data: AzRefAny, loader: AzImageLoaderCallbackType, fallback: AzImageRef) -> AzOptionThreadId { unsafe { transmute(azul::AzCallbackInfo_loadImageAsync(transmute(callbackinfo), transmute(node_id), transmute(data), transmute(loader), transmute(fallback))) } }
         pub(crate) fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_stopPropagation(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_forceFrame(callbackinfo: &AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_forceFrame(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_confineToMonitor(callbackinfo: &AzCallbackInfo, monitor_id: usize) -> bool { unsafe { transmute(azul::AzCallbackInfo_confineToMonitor(transmute(callbackinfo), transmute(monitor_id))) } }
+        pub(crate) fn AzCallbackInfo_releaseFromMonitor(callbackinfo: &AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_releaseFromMonitor(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_lockAspectRatio(callbackinfo: &AzCallbackInfo, ratio: f32) -> bool { unsafe { transmute(azul::AzCallbackInfo_lockAspectRatio(transmute(callbackinfo), transmute(ratio))) } }
+        pub(crate) fn AzCallbackInfo_unlockAspectRatio(callbackinfo: &AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_unlockAspectRatio(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { unsafe { transmute(azul::AzCallbackInfo_createWindow(transmute(callbackinfo), transmute(new_window))) } }
         pub(crate) fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { unsafe { transmute(azul::AzCallbackInfo_startTimer(transmute(callbackinfo), transmute(timer))) } }
         pub(crate) fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { unsafe { transmute(azul::AzCallbackInfo_startAnimation(transmute(callbackinfo), transmute(node), transmute(animation))) } }
@@ -10534,6 +10852,7 @@ mod dll {
         pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> f32 { unsafe { transmute(azul::AzHidpiAdjustedBounds_getHidpiFactor(transmute(hidpiadjustedbounds))) } }
         pub(crate) fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { unsafe { transmute(azul::AzInlineText_hitTest(transmute(inlinetext), transmute(position))) } }
         pub(crate) fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions { unsafe { transmute(azul::AzResolvedTextLayoutOptions_default()) } }
+        pub(crate) fn AzTextConstraints_default() -> AzTextConstraints { unsafe { transmute(azul::AzTextConstraints_default()) } }
         pub(crate) fn AzRenderImageCallbackInfo_getGlContext(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzRenderImageCallbackInfo_getGlContext(transmute(renderimagecallbackinfo))) } }
         pub(crate) fn AzRenderImageCallbackInfo_getBounds(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds { unsafe { transmute(azul::AzRenderImageCallbackInfo_getBounds(transmute(renderimagecallbackinfo))) } }
         pub(crate) fn AzRenderImageCallbackInfo_getCallbackNodeId(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzDomNodeId { unsafe { transmute(azul::AzRenderImageCallbackInfo_getCallbackNodeId(transmute(renderimagecallbackinfo))) } }
@@ -10560,6 +10879,7 @@ mod dll {
         pub(crate) fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzLayoutCallbackInfo_getGlContext(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { unsafe { transmute(azul::AzLayoutCallbackInfo_getSystemFonts(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { unsafe { transmute(azul::AzLayoutCallbackInfo_getImage(transmute(layoutcallbackinfo), transmute(id))) } }
+        pub(crate) fn AzLayoutCallbackInfo_measureText(layoutcallbackinfo: &AzLayoutCallbackInfo, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: AzString, constraints: AzTextConstraints) -> AzOptionInlineText { unsafe { transmute(azul::AzLayoutCallbackInfo_measureText(transmute(layoutcallbackinfo), transmute(font), transmute(font_size), transmute(text), transmute(constraints))) } }
         pub(crate) fn AzDom_new(node_type: AzNodeType) -> AzDom { unsafe { transmute(azul::AzDom_new(transmute(node_type))) } }
         pub(crate) fn AzDom_body() -> AzDom { unsafe { transmute(azul::AzDom_body()) } }
         pub(crate) fn AzDom_div() -> AzDom { unsafe { transmute(azul::AzDom_div()) } }
@@ -10567,6 +10887,7 @@ mod dll {
         pub(crate) fn AzDom_text(string: AzString) -> AzDom { unsafe { transmute(azul::AzDom_text(transmute(string))) } }
         pub(crate) fn AzDom_image(image: AzImageRef) -> AzDom { unsafe { transmute(azul::AzDom_image(transmute(image))) } }
         pub(crate) fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { unsafe { transmute(azul::AzDom_iframe(transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDom_nativeChildWindow(window: AzNativeChildWindow) -> AzDom { unsafe { transmute(azul::AzDom_nativeChildWindow(transmute(window))) } }
         pub(crate) fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { unsafe { transmute(azul::AzDom_setNodeType(transmute(dom), transmute(node_type))) } }
         pub(crate) fn AzDom_withNodeType(dom: &mut AzDom, node_type: AzNodeType) -> AzDom { unsafe { transmute(azul::AzDom_withNodeType(transmute(dom), transmute(node_type))) } }
         pub(crate) fn AzDom_setDataset(dom: &mut AzDom, dataset: AzRefAny) { unsafe { transmute(azul::AzDom_setDataset(transmute(dom), transmute(dataset))) } }
@@ -10613,6 +10934,8 @@ mod dll {
         pub(crate) fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withMenuBar(transmute(dom), transmute(menu_bar))) } }
         pub(crate) fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { unsafe { transmute(azul::AzDom_setContextMenu(transmute(dom), transmute(context_menu))) } }
         pub(crate) fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withContextMenu(transmute(dom), transmute(context_menu))) } }
+        pub(crate) fn AzDom_setTitleBarRegion(dom: &mut AzDom, region: AzTitleBarRegion) { unsafe { transmute(azul::AzDom_setTitleBarRegion(transmute(dom), transmute(region))) } }
+        pub(crate) fn AzDom_withTitleBarRegion(dom: &mut AzDom, region: AzTitleBarRegion) -> AzDom { unsafe { transmute(azul::AzDom_withTitleBarRegion(transmute(dom), transmute(region))) } }
         pub(crate) fn AzDom_hash(dom: &AzDom) -> u64 { unsafe { transmute(azul::AzDom_hash(transmute(dom))) } }
         pub(crate) fn AzDom_nodeCount(dom: &AzDom) -> usize { unsafe { transmute(azul::AzDom_nodeCount(transmute(dom))) } }
         pub(crate) fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlString(transmute(dom))) } }
@@ -10650,6 +10973,8 @@ mod dll {
         pub(crate) fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { unsafe { transmute(azul::AzNodeData_setAccessibilityInfo(transmute(nodedata), transmute(accessibility_info))) } }
         pub(crate) fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { unsafe { transmute(azul::AzNodeData_setMenuBar(transmute(nodedata), transmute(menu_bar))) } }
         pub(crate) fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { unsafe { transmute(azul::AzNodeData_setContextMenu(transmute(nodedata), transmute(context_menu))) } }
+        pub(crate) fn AzNodeData_setNativeChildWindow(nodedata: &mut AzNodeData, window: AzNativeChildWindow) { unsafe { transmute(azul::AzNodeData_setNativeChildWindow(transmute(nodedata), transmute(window))) } }
+        pub(crate) fn AzNodeData_setTitleBarRegion(nodedata: &mut AzNodeData, region: AzTitleBarRegion) { unsafe { transmute(azul::AzNodeData_setTitleBarRegion(transmute(nodedata), transmute(region))) } }
         pub(crate) fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { unsafe { transmute(azul::AzNodeData_hash(transmute(nodedata))) } }
         pub(crate) fn AzOn_intoEventFilter(on: AzOn) -> AzEventFilter { unsafe { transmute(azul::AzOn_intoEventFilter(transmute(on))) } }
         pub(crate) fn AzMenu_new(items: AzMenuItemVec) -> AzMenu { unsafe { transmute(azul::AzMenu_new(transmute(items))) } }
@@ -10701,6 +11026,10 @@ mod dll {
         pub(crate) fn AzTextInput_withText(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withText(transmute(textinput), transmute(text))) } }
         pub(crate) fn AzTextInput_setPlaceholder(textinput: &mut AzTextInput, text: AzString) { unsafe { transmute(azul::AzTextInput_setPlaceholder(transmute(textinput), transmute(text))) } }
         pub(crate) fn AzTextInput_withPlaceholder(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withPlaceholder(transmute(textinput), transmute(text))) } }
+        pub(crate) fn AzTextInput_setReadOnly(textinput: &mut AzTextInput, read_only: bool) { unsafe { transmute(azul::AzTextInput_setReadOnly(transmute(textinput), transmute(read_only))) } }
+        pub(crate) fn AzTextInput_withReadOnly(textinput: &mut AzTextInput, read_only: bool) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withReadOnly(transmute(textinput), transmute(read_only))) } }
+        pub(crate) fn AzTextInput_setDisabled(textinput: &mut AzTextInput, disabled: bool) { unsafe { transmute(azul::AzTextInput_setDisabled(transmute(textinput), transmute(disabled))) } }
+        pub(crate) fn AzTextInput_withDisabled(textinput: &mut AzTextInput, disabled: bool) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withDisabled(transmute(textinput), transmute(disabled))) } }
         pub(crate) fn AzTextInput_setOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { unsafe { transmute(azul::AzTextInput_setOnTextInput(transmute(textinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzTextInput_withOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withOnTextInput(transmute(textinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzTextInput_setOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { unsafe { transmute(azul::AzTextInput_setOnVirtualKeyDown(transmute(textinput), transmute(data), transmute(callback))) } }
@@ -10951,6 +11280,7 @@ mod dll {
         pub(crate) fn AzGl_getBufferParameterIv(gl: &AzGl, target: u32, pname: u32) -> i32 { unsafe { transmute(azul::AzGl_getBufferParameterIv(transmute(gl), transmute(target), transmute(pname))) } }
         pub(crate) fn AzGl_getShaderInfoLog(gl: &AzGl, shader: u32) -> AzString { unsafe { transmute(azul::AzGl_getShaderInfoLog(transmute(gl), transmute(shader))) } }
         pub(crate) fn AzGl_getString(gl: &AzGl, which: u32) -> AzString { unsafe { transmute(azul::AzGl_getString(transmute(gl), transmute(which))) } }
+        pub(crate) fn AzGl_getRendererName(gl: &AzGl) -> AzString { unsafe { transmute(azul::AzGl_getRendererName(transmute(gl))) } }
         pub(crate) fn AzGl_getStringI(gl: &AzGl, which: u32, index: u32) -> AzString { unsafe { transmute(azul::AzGl_getStringI(transmute(gl), transmute(which), transmute(index))) } }
         pub(crate) fn AzGl_getShaderIv(gl: &AzGl, shader: u32, pname: u32, result: AzGLintVecRefMut) { unsafe { transmute(azul::AzGl_getShaderIv(transmute(gl), transmute(shader), transmute(pname), transmute(result))) } }
         pub(crate) fn AzGl_getShaderPrecisionFormat(gl: &AzGl, shader_type: u32, precision_type: u32) -> AzGlShaderPrecisionFormatReturn { unsafe { transmute(azul::AzGl_getShaderPrecisionFormat(transmute(gl), transmute(shader_type), transmute(precision_type))) } }
@@ -11023,6 +11353,7 @@ mod dll {
         pub(crate) fn AzImageRef_invalid(width: usize, height: usize, format: AzRawImageFormat) -> AzImageRef { unsafe { transmute(azul::AzImageRef_invalid(transmute(width), transmute(height), transmute(format))) } }
         pub(crate) fn AzImageRef_rawImage(data: AzRawImage) -> AzOptionImageRef { unsafe { transmute(azul::AzImageRef_rawImage(transmute(data))) } }
         pub(crate) fn AzImageRef_glTexture(texture: AzTexture) -> AzImageRef { unsafe { transmute(azul::AzImageRef_glTexture(transmute(texture))) } }
+        pub(crate) fn AzImageRef_solidColor(width: usize, height: usize, color: AzColorU) -> AzImageRef { unsafe { transmute(azul::AzImageRef_solidColor(transmute(width), transmute(height), transmute(color))) } }
         pub(crate) fn AzImageRef_callback(data: AzRefAny, callback: AzRenderImageCallbackType) -> AzImageRef { unsafe { transmute(azul::AzImageRef_callback(transmute(data), transmute(callback))) } }
         pub(crate) fn AzImageRef_cloneBytes(imageref: &AzImageRef) -> AzImageRef { unsafe { transmute(azul::AzImageRef_cloneBytes(transmute(imageref))) } }
         pub(crate) fn AzImageRef_isInvalid(imageref: &AzImageRef) -> bool { unsafe { transmute(azul::AzImageRef_isInvalid(transmute(imageref))) } }
@@ -11205,6 +11536,8 @@ mod dll {
         pub(crate) fn AzTimer_withDelay(timer: &AzTimer, delay: AzDuration) -> AzTimer { unsafe { transmute(azul::AzTimer_withDelay(transmute(timer), transmute(delay))) } }
         pub(crate) fn AzTimer_withInterval(timer: &AzTimer, interval: AzDuration) -> AzTimer { unsafe { transmute(azul::AzTimer_withInterval(transmute(timer), transmute(interval))) } }
         pub(crate) fn AzTimer_withTimeout(timer: &AzTimer, timeout: AzDuration) -> AzTimer { unsafe { transmute(azul::AzTimer_withTimeout(transmute(timer), transmute(timeout))) } }
+        pub(crate) fn AzTimer_withMissedTickPolicy(timer: &AzTimer, missed_tick_policy: AzTimerMissedTickPolicy) -> AzTimer { unsafe { transmute(azul::AzTimer_withMissedTickPolicy(transmute(timer), transmute(missed_tick_policy))) } }
+        pub(crate) fn AzTimer_withRunsWhileHidden(timer: &AzTimer, runs_while_hidden: bool) -> AzTimer { unsafe { transmute(azul::AzTimer_withRunsWhileHidden(transmute(timer), transmute(runs_while_hidden))) } }
         pub(crate) fn AzThread_delete(object: &mut AzThread) { unsafe { transmute(azul::AzThread_delete(transmute(object))) } }
         pub(crate) fn AzThread_deepCopy(object: &AzThread) -> AzThread { unsafe { transmute(azul::AzThread_deepCopy(transmute(object))) } }
         pub(crate) fn AzThreadSender_send(threadsender: &mut AzThreadSender, msg: AzThreadReceiveMsg) -> bool { unsafe { transmute(azul::AzThreadSender_send(transmute(threadsender), transmute(msg))) } }
@@ -11277,6 +11610,7 @@ mod dll {
         pub(crate) fn AzGLintVec_delete(object: &mut AzGLintVec) { unsafe { transmute(azul::AzGLintVec_delete(transmute(object))) } }
         pub(crate) fn AzStringVec_delete(object: &mut AzStringVec) { unsafe { transmute(azul::AzStringVec_delete(transmute(object))) } }
         pub(crate) fn AzStringPairVec_delete(object: &mut AzStringPairVec) { unsafe { transmute(azul::AzStringPairVec_delete(transmute(object))) } }
+        pub(crate) fn AzFontWarmupVec_delete(object: &mut AzFontWarmupVec) { unsafe { transmute(azul::AzFontWarmupVec_delete(transmute(object))) } }
         pub(crate) fn AzNormalizedLinearColorStopVec_delete(object: &mut AzNormalizedLinearColorStopVec) { unsafe { transmute(azul::AzNormalizedLinearColorStopVec_delete(transmute(object))) } }
         pub(crate) fn AzNormalizedRadialColorStopVec_delete(object: &mut AzNormalizedRadialColorStopVec) { unsafe { transmute(azul::AzNormalizedRadialColorStopVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeIdVec_delete(object: &mut AzNodeIdVec) { unsafe { transmute(azul::AzNodeIdVec_delete(transmute(object))) } }
@@ -11300,10 +11634,16 @@ mod dll {
             pub(crate) fn AzApp_addWindow(_:  &mut AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_addImage(_:  &mut AzApp, _:  AzString, _:  AzImageRef);
             pub(crate) fn AzApp_getMonitors(_:  &AzApp) -> AzMonitorVec;
-            pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions);
+            pub(crate) fn AzApp_pumpEvents(_:  &AzApp) -> bool;
+            pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions) -> i32;
             pub(crate) fn AzApp_delete(_:  &mut AzApp);
             pub(crate) fn AzApp_deepCopy(_:  &AzApp) -> AzApp;
             pub(crate) fn AzAppConfig_new(_:  AzLayoutSolver) -> AzAppConfig;
+            pub(crate) fn AzGpuMemoryBudget_unlimited() -> AzGpuMemoryBudget;
+            pub(crate) fn AzGpuMemoryBudget_new(_:  usize) -> AzGpuMemoryBudget;
+            pub(crate) fn AzHitTestTolerance_none() -> AzHitTestTolerance;
+            pub(crate) fn AzHitTestTolerance_new(_:  f32, _:  f32, _:  f32) -> AzHitTestTolerance;
+            pub(crate) fn AzFontWarmup_new(_:  AzStyleFontFamilyVec, _:  AzStyleFontSize, _:  AzString) -> AzFontWarmup;
             pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks;
             pub(crate) fn AzWindowCreateOptions_new(_:  AzLayoutCallbackType) -> AzWindowCreateOptions;
             pub(crate) fn AzLogicalPosition_new(_:  f32, _:  f32) -> AzLogicalPosition;
@@ -11338,6 +11678,7 @@ mod dll {
             pub(crate) fn AzCallbackInfo_getFontRef(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionFontRef;
             pub(crate) fn AzCallbackInfo_getTextLayoutOptions(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionResolvedTextLayoutOptions;
             pub(crate) fn AzCallbackInfo_shapeText(_:  &AzCallbackInfo, _:  AzDomNodeId, _:  AzString) -> AzOptionInlineText;
+            pub(crate) fn AzCallbackInfo_measureText(_:  &AzCallbackInfo, _:  AzStyleFontFamilyVec, _:  AzStyleFontSize, _:  AzString, _:  AzTextConstraints) -> AzOptionInlineText;
             pub(crate) fn AzCallbackInfo_getIndexInParent(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> usize;
             pub(crate) fn AzCallbackInfo_getParent(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionDomNodeId;
             pub(crate) fn AzCallbackInfo_getPreviousSibling(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionDomNodeId;
@@ -11358,7 +11699,13 @@ mod dll {
             pub(crate) fn AzCallbackInfo_updateImage(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageRef, _:  AzUpdateImageType);
             pub(crate) fn AzCallbackInfo_deleteImage(_:  &mut AzCallbackInfo, _:  AzString);
             pub(crate) fn AzCallbackInfo_updateImageMask(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageMask);
+            pub(crate) fn AzCallbackInfo_loadImageAsync(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzRefAny, _:  AzImageLoaderCallbackType, _:  AzImageRef) -> AzOptionThreadId;
             pub(crate) fn AzCallbackInfo_stopPropagation(_:  &mut AzCallbackInfo);
+            pub(crate) fn AzCallbackInfo_forceFrame(_:  &AzCallbackInfo);
+            pub(crate) fn AzCallbackInfo_confineToMonitor(_:  &AzCallbackInfo, _:  usize) -> bool;
+            pub(crate) fn AzCallbackInfo_releaseFromMonitor(_:  &AzCallbackInfo);
+            pub(crate) fn AzCallbackInfo_lockAspectRatio(_:  &AzCallbackInfo, _:  f32) -> bool;
+            pub(crate) fn AzCallbackInfo_unlockAspectRatio(_:  &AzCallbackInfo);
             pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions);
             pub(crate) fn AzCallbackInfo_startTimer(_:  &mut AzCallbackInfo, _:  AzTimer) -> AzTimerId;
             pub(crate) fn AzCallbackInfo_startAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzAnimation) -> AzOptionTimerId;
@@ -11374,6 +11721,7 @@ mod dll {
             pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(_:  &AzHidpiAdjustedBounds) -> f32;
             pub(crate) fn AzInlineText_hitTest(_:  &AzInlineText, _:  AzLogicalPosition) -> AzInlineTextHitVec;
             pub(crate) fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions;
+            pub(crate) fn AzTextConstraints_default() -> AzTextConstraints;
             pub(crate) fn AzRenderImageCallbackInfo_getGlContext(_:  &AzRenderImageCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzRenderImageCallbackInfo_getBounds(_:  &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds;
             pub(crate) fn AzRenderImageCallbackInfo_getCallbackNodeId(_:  &AzRenderImageCallbackInfo) -> AzDomNodeId;
@@ -11400,6 +11748,7 @@ mod dll {
             pub(crate) fn AzLayoutCallbackInfo_getGlContext(_:  &AzLayoutCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(_:  &AzLayoutCallbackInfo) -> AzStringPairVec;
             pub(crate) fn AzLayoutCallbackInfo_getImage(_:  &AzLayoutCallbackInfo, _:  AzString) -> AzOptionImageRef;
+            pub(crate) fn AzLayoutCallbackInfo_measureText(_:  &AzLayoutCallbackInfo, _:  AzStyleFontFamilyVec, _:  AzStyleFontSize, _:  AzString, _:  AzTextConstraints) -> AzOptionInlineText;
             pub(crate) fn AzDom_new(_:  AzNodeType) -> AzDom;
             pub(crate) fn AzDom_body() -> AzDom;
             pub(crate) fn AzDom_div() -> AzDom;
@@ -11407,6 +11756,7 @@ mod dll {
             pub(crate) fn AzDom_text(_:  AzString) -> AzDom;
             pub(crate) fn AzDom_image(_:  AzImageRef) -> AzDom;
             pub(crate) fn AzDom_iframe(_:  AzRefAny, _:  AzIFrameCallbackType) -> AzDom;
+            pub(crate) fn AzDom_nativeChildWindow(_:  AzNativeChildWindow) -> AzDom;
             pub(crate) fn AzDom_setNodeType(_:  &mut AzDom, _:  AzNodeType);
             pub(crate) fn AzDom_withNodeType(_:  &mut AzDom, _:  AzNodeType) -> AzDom;
             pub(crate) fn AzDom_setDataset(_:  &mut AzDom, _:  AzRefAny);
@@ -11453,6 +11803,8 @@ mod dll {
             pub(crate) fn AzDom_withMenuBar(_:  &mut AzDom, _:  AzMenu) -> AzDom;
             pub(crate) fn AzDom_setContextMenu(_:  &mut AzDom, _:  AzMenu);
             pub(crate) fn AzDom_withContextMenu(_:  &mut AzDom, _:  AzMenu) -> AzDom;
+            pub(crate) fn AzDom_setTitleBarRegion(_:  &mut AzDom, _:  AzTitleBarRegion);
+            pub(crate) fn AzDom_withTitleBarRegion(_:  &mut AzDom, _:  AzTitleBarRegion) -> AzDom;
             pub(crate) fn AzDom_hash(_:  &AzDom) -> u64;
             pub(crate) fn AzDom_nodeCount(_:  &AzDom) -> usize;
             pub(crate) fn AzDom_getHtmlString(_:  &mut AzDom) -> AzString;
@@ -11490,6 +11842,8 @@ mod dll {
             pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
             pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
             pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
+            pub(crate) fn AzNodeData_setNativeChildWindow(_:  &mut AzNodeData, _:  AzNativeChildWindow);
+            pub(crate) fn AzNodeData_setTitleBarRegion(_:  &mut AzNodeData, _:  AzTitleBarRegion);
             pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
             pub(crate) fn AzOn_intoEventFilter(_:  AzOn) -> AzEventFilter;
             pub(crate) fn AzMenu_new(_:  AzMenuItemVec) -> AzMenu;
@@ -11541,6 +11895,10 @@ mod dll {
             pub(crate) fn AzTextInput_withText(_:  &mut AzTextInput, _:  AzString) -> AzTextInput;
             pub(crate) fn AzTextInput_setPlaceholder(_:  &mut AzTextInput, _:  AzString);
             pub(crate) fn AzTextInput_withPlaceholder(_:  &mut AzTextInput, _:  AzString) -> AzTextInput;
+            pub(crate) fn AzTextInput_setReadOnly(_:  &mut AzTextInput, _:  bool);
+            pub(crate) fn AzTextInput_withReadOnly(_:  &mut AzTextInput, _:  bool) -> AzTextInput;
+            pub(crate) fn AzTextInput_setDisabled(_:  &mut AzTextInput, _:  bool);
+            pub(crate) fn AzTextInput_withDisabled(_:  &mut AzTextInput, _:  bool) -> AzTextInput;
             pub(crate) fn AzTextInput_setOnTextInput(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType);
             pub(crate) fn AzTextInput_withOnTextInput(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType) -> AzTextInput;
             pub(crate) fn AzTextInput_setOnVirtualKeyDown(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnVirtualKeyDownCallbackType);
@@ -11791,6 +12149,7 @@ mod dll {
             pub(crate) fn AzGl_getBufferParameterIv(_:  &AzGl, _:  u32, _:  u32) -> i32;
             pub(crate) fn AzGl_getShaderInfoLog(_:  &AzGl, _:  u32) -> AzString;
             pub(crate) fn AzGl_getString(_:  &AzGl, _:  u32) -> AzString;
+            pub(crate) fn AzGl_getRendererName(_:  &AzGl) -> AzString;
             pub(crate) fn AzGl_getStringI(_:  &AzGl, _:  u32, _:  u32) -> AzString;
             pub(crate) fn AzGl_getShaderIv(_:  &AzGl, _:  u32, _:  u32, _:  AzGLintVecRefMut);
             pub(crate) fn AzGl_getShaderPrecisionFormat(_:  &AzGl, _:  u32, _:  u32) -> AzGlShaderPrecisionFormatReturn;
@@ -11863,6 +12222,7 @@ mod dll {
             pub(crate) fn AzImageRef_invalid(_:  usize, _:  usize, _:  AzRawImageFormat) -> AzImageRef;
             pub(crate) fn AzImageRef_rawImage(_:  AzRawImage) -> AzOptionImageRef;
             pub(crate) fn AzImageRef_glTexture(_:  AzTexture) -> AzImageRef;
+            pub(crate) fn AzImageRef_solidColor(_:  usize, _:  usize, _:  AzColorU) -> AzImageRef;
             pub(crate) fn AzImageRef_callback(_:  AzRefAny, _:  AzRenderImageCallbackType) -> AzImageRef;
             pub(crate) fn AzImageRef_cloneBytes(_:  &AzImageRef) -> AzImageRef;
             pub(crate) fn AzImageRef_isInvalid(_:  &AzImageRef) -> bool;
@@ -12045,6 +12405,8 @@ mod dll {
             pub(crate) fn AzTimer_withDelay(_:  &AzTimer, _:  AzDuration) -> AzTimer;
             pub(crate) fn AzTimer_withInterval(_:  &AzTimer, _:  AzDuration) -> AzTimer;
             pub(crate) fn AzTimer_withTimeout(_:  &AzTimer, _:  AzDuration) -> AzTimer;
+            pub(crate) fn AzTimer_withMissedTickPolicy(_:  &AzTimer, _:  AzTimerMissedTickPolicy) -> AzTimer;
+            pub(crate) fn AzTimer_withRunsWhileHidden(_:  &AzTimer, _:  bool) -> AzTimer;
             pub(crate) fn AzThread_delete(_:  &mut AzThread);
             pub(crate) fn AzThread_deepCopy(_:  &AzThread) -> AzThread;
             pub(crate) fn AzThreadSender_send(_:  &mut AzThreadSender, _:  AzThreadReceiveMsg) -> bool;
@@ -12117,6 +12479,7 @@ mod dll {
             pub(crate) fn AzGLintVec_delete(_:  &mut AzGLintVec);
             pub(crate) fn AzStringVec_delete(_:  &mut AzStringVec);
             pub(crate) fn AzStringPairVec_delete(_:  &mut AzStringPairVec);
+            pub(crate) fn AzFontWarmupVec_delete(_:  &mut AzFontWarmupVec);
             pub(crate) fn AzNormalizedLinearColorStopVec_delete(_:  &mut AzNormalizedLinearColorStopVec);
             pub(crate) fn AzNormalizedRadialColorStopVec_delete(_:  &mut AzNormalizedRadialColorStopVec);
             pub(crate) fn AzNodeIdVec_delete(_:  &mut AzNodeIdVec);
@@ -12142,6 +12505,8 @@ pub mod app {
     use crate::window::WindowCreateOptions;
     use crate::str::String;
     use crate::image::ImageRef;
+    use crate::vec::StyleFontFamilyVec;
+    use crate::css::StyleFontSize;
     /// Main application class
     
     #[doc(inline)] pub use crate::dll::AzApp as App;
@@ -12155,8 +12520,10 @@ pub mod app {
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzApp_addImage(self, id.into(), image.into()) } }
         /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
         pub fn get_monitors(&self)  -> crate::vec::MonitorVec { unsafe { crate::dll::AzApp_getMonitors(self) } }
-        /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
-        pub fn run<_1: Into<WindowCreateOptions>>(&self, window: _1)  { unsafe { crate::dll::AzApp_run(self, window.into()) } }
+        /// Processes all pending events of the application without blocking (for embedding it into an existing event loop, instead of calling `run()`) and returns whether any window is still open. The first call opens the windows added with `add_window()`, the first one being the root window. Currently only implemented on Windows.
+        pub fn pump_events(&self)  -> bool { unsafe { crate::dll::AzApp_pumpEvents(self) } }
+        /// Runs the application until the event loop stops (the last window was closed or the application quit)
+        pub fn run<_1: Into<WindowCreateOptions>>(&self, window: _1)  -> i32 { unsafe { crate::dll::AzApp_run(self, window.into()) } }
     }
 
     impl Clone for App { fn clone(&self) -> Self { unsafe { crate::dll::AzApp_deepCopy(self) } } }
@@ -12170,6 +12537,43 @@ pub mod app {
         pub fn new<_1: Into<LayoutSolver>>(layout_solver: _1) -> Self { unsafe { crate::dll::AzAppConfig_new(layout_solver.into()) } }
     }
 
+    /// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+    
+    #[doc(inline)] pub use crate::dll::AzGpuPreference as GpuPreference;
+    /// Limits the GPU memory (texture cache, render targets, images) used by the renderer of a window: every `check_interval_ms`, the memory usage is compared against `max_bytes` and if it is exceeded, the renderer evicts its caches
+    
+    #[doc(inline)] pub use crate::dll::AzGpuMemoryBudget as GpuMemoryBudget;
+    impl GpuMemoryBudget {
+
+        /// No limit (default)
+        pub fn unlimited() -> Self { unsafe { crate::dll::AzGpuMemoryBudget_unlimited() } }
+        /// Limits the GPU memory to `max_bytes`, checked every 5 seconds
+        pub fn new(max_bytes: usize) -> Self { unsafe { crate::dll::AzGpuMemoryBudget_new(max_bytes) } }
+    }
+
+    /// Radius (in logical pixels) by which the hit-testable area of every node is expanded when hit-testing a pointer position, so that small targets can be hit with imprecise input devices: if the pointer misses all nodes, the nearest node within the radius is hit instead
+    
+    #[doc(inline)] pub use crate::dll::AzHitTestTolerance as HitTestTolerance;
+    impl HitTestTolerance {
+
+        /// Only exact hits, for all input devices
+        pub fn none() -> Self { unsafe { crate::dll::AzHitTestTolerance_none() } }
+        /// Creates a new `HitTestTolerance` from the tolerances (in logical pixels) for mouse, pen and touch input
+        pub fn new(mouse: f32, pen: f32, touch: f32) -> Self { unsafe { crate::dll::AzHitTestTolerance_new(mouse, pen, touch) } }
+    }
+
+    /// Font that is loaded at a given size when a window is created, together with the characters that are rasterized up front (see `AppConfig::font_warmup`)
+    
+    #[doc(inline)] pub use crate::dll::AzFontWarmup as FontWarmup;
+    impl FontWarmup {
+
+        /// Creates a new `FontWarmup` from the font families, the font size and the characters to rasterize
+        pub fn new<_1: Into<StyleFontFamilyVec>, _2: Into<StyleFontSize>, _3: Into<String>>(font: _1, size: _2, characters: _3) -> Self { unsafe { crate::dll::AzFontWarmup_new(font.into(), size.into(), characters.into()) } }
+    }
+
+    /// Strategy of the event loop
+    
+    #[doc(inline)] pub use crate::dll::AzEventLoopMode as EventLoopMode;
     /// Configuration to set which messages should be logged.
     
     #[doc(inline)] pub use crate::dll::AzAppLogLevel as AppLogLevel;
@@ -12285,12 +12689,27 @@ pub mod window {
         pub fn new(layout_callback: LayoutCallbackType) -> Self { unsafe { crate::dll::AzWindowCreateOptions_new(layout_callback) } }
     }
 
+    /// OpenGL context version, profile and flags requested for a window
+    
+    #[doc(inline)] pub use crate::dll::AzGlContextOptions as GlContextOptions;
+    /// OpenGL context profile
+    
+    #[doc(inline)] pub use crate::dll::AzGlContextProfile as GlContextProfile;
+    /// Buffering of the OpenGL surface of a window: double-buffered surfaces never show partially drawn frames, but may wait for the next vsync / compositor cycle; single-buffered surfaces render directly into the visible buffer (lower latency for overlays), at the cost of possible tearing and flickering
+    
+    #[doc(inline)] pub use crate::dll::AzGlBuffering as GlBuffering;
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
     
     #[doc(inline)] pub use crate::dll::AzRendererOptions as RendererOptions;
+    /// How rendered frames are presented to the screen: controls vsync (swap interval), tearing and frame pacing
+    
+    #[doc(inline)] pub use crate::dll::AzPresentMode as PresentMode;
     /// Whether the renderer has VSync enabled
     
     #[doc(inline)] pub use crate::dll::AzVsync as Vsync;
+    /// Whether a window only renders when its content changed or on every frame
+    
+    #[doc(inline)] pub use crate::dll::AzRedrawPolicy as RedrawPolicy;
     /// Does the renderer render in SRGB color space? By default, azul tries to set it to `Enabled` and falls back to `Disabled` if the OpenGL context can't be initialized properly
     
     #[doc(inline)] pub use crate::dll::AzSrgb as Srgb;
@@ -12386,6 +12805,9 @@ pub mod window {
     /// Symbolic accelerator key (ctrl, alt, shift)
     
     #[doc(inline)] pub use crate::dll::AzAcceleratorKey as AcceleratorKey;
+    /// Placement of a window with a fixed logical size inside the actual window: all rects are in logical units of the window (physical pixels / DPI factor)
+    
+    #[doc(inline)] pub use crate::dll::AzLetterbox as Letterbox;
     /// Minimum / maximum / current size of the window in logical dimensions
     
     #[doc(inline)] pub use crate::dll::AzWindowSize as WindowSize;
@@ -12442,6 +12864,9 @@ pub mod window {
     /// Window configuration specific to Win32
     
     #[doc(inline)] pub use crate::dll::AzWindowsWindowOptions as WindowsWindowOptions;
+    /// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+    
+    #[doc(inline)] pub use crate::dll::AzWindowCornerPreference as WindowCornerPreference;
     /// CSD theme of the window title / button controls
     
     #[doc(inline)] pub use crate::dll::AzWaylandTheme as WaylandTheme;
@@ -12469,6 +12894,12 @@ pub mod window {
     /// Position of the top left corner of the window relative to the top left of the monitor
     
     #[doc(inline)] pub use crate::dll::AzWindowPosition as WindowPosition;
+    /// Caret and selection of the currently focused text input, in logical coordinates relative to the top left of the window
+    
+    #[doc(inline)] pub use crate::dll::AzTextCaret as TextCaret;
+    /// Accessibility preferences of the user, set by the operating system
+    
+    #[doc(inline)] pub use crate::dll::AzAccessibilitySettings as AccessibilitySettings;
     /// Position of the virtual keyboard necessary to insert CJK characters
     
     #[doc(inline)] pub use crate::dll::AzImePosition as ImePosition;
@@ -12636,7 +13067,8 @@ pub mod callbacks {
             struct_as_bytes.into_iter().enumerate().map(|(s_pos, s)| ((*s as u64) << s_pos)).sum()
         }
     }    use crate::str::String;
-    use crate::css::{CssProperty, CssPropertyType};
+    use crate::vec::StyleFontFamilyVec;
+    use crate::css::{CssProperty, CssPropertyType, StyleFontSize};
     use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
     use crate::image::{ImageMask, ImageRef};
     use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
@@ -12709,6 +13141,8 @@ pub mod callbacks {
         pub fn get_text_layout_options<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionResolvedTextLayoutOptions { unsafe { crate::dll::AzCallbackInfo_getTextLayoutOptions(self, node_id.into()) } }
         /// Similar to `get_inline_text()`: If the node is a `Text` node, shape the `text` string with the same parameters as the current text and return the calculated InlineTextLayout. Necessary to calculate text cursor offsets and to detect when a line overflows content.
         pub fn shape_text<_1: Into<DomNodeId>, _2: Into<String>>(&self, node_id: _1, text: _2)  -> crate::option::OptionInlineText { unsafe { crate::dll::AzCallbackInfo_shapeText(self, node_id.into(), text.into()) } }
+        /// Measures a text without building a DOM: loads the first available font of `font` and returns the lines, per-glyph positions and the total size of the text, exactly as the layout engine would lay out a text node with the same font, font size and constraints. Returns `None` if no font could be loaded.
+        pub fn measure_text<_1: Into<StyleFontFamilyVec>, _2: Into<StyleFontSize>, _3: Into<String>, _4: Into<TextConstraints>>(&self, font: _1, font_size: _2, text: _3, constraints: _4)  -> crate::option::OptionInlineText { unsafe { crate::dll::AzCallbackInfo_measureText(self, font.into(), font_size.into(), text.into(), constraints.into()) } }
         /// Returns the index of the node relative to the parent node.
         pub fn get_index_in_parent<_1: Into<DomNodeId>>(&mut self, node_id: _1)  -> usize { unsafe { crate::dll::AzCallbackInfo_getIndexInParent(self, node_id.into()) } }
         /// Returns the parent `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
@@ -12749,8 +13183,20 @@ pub mod callbacks {
         pub fn delete_image<_1: Into<String>>(&mut self, id: _1)  { unsafe { crate::dll::AzCallbackInfo_deleteImage(self, id.into()) } }
         /// If the node has an `ImageMask`, exchanges the current mask for the new mask
         pub fn update_image_mask<_1: Into<DomNodeId>, _2: Into<ImageMask>>(&mut self, node_id: _1, new_mask: _2)  { unsafe { crate::dll::AzCallbackInfo_updateImageMask(self, node_id.into(), new_mask.into()) } }
+        /// Decodes an image on a background thread. The node keeps showing its current (placeholder) image until the `loader` returns, then the decoded image - or the `fallback` if the loader returned `None` - is swapped in and the node is repainted. The layout is not recomputed, so the node should have a fixed size.
+        pub fn load_image_async<_1: Into<DomNodeId>, _2: Into<RefAny>, _4: Into<ImageRef>>(&mut self, node_id: _1, data: _2, loader: ImageLoaderCallbackType, fallback: _4)  -> crate::option::OptionThreadId { unsafe { crate::dll::AzCallbackInfo_loadImageAsync(self, node_id.into(), data.into(), loader, fallback.into()) } }
         /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
         pub fn stop_propagation(&mut self)  { unsafe { crate::dll::AzCallbackInfo_stopPropagation(self) } }
+        /// Runs all due timers, re-layouts and renders exactly one frame of the current window as soon as the callback returns, bypassing the message loop timing. Intended for automated UI tests. Only implemented on Windows.
+        pub fn force_frame(&self)  { unsafe { crate::dll::AzCallbackInfo_forceFrame(self) } }
+        /// Moves the current window onto the monitor with the given `Monitor::id`, shrinking it if necessary, and keeps it there: the user can't drag or resize the window past the monitor bounds anymore. Returns `false` if the monitor does not exist. Only implemented on Windows.
+        pub fn confine_to_monitor(&self, monitor_id: usize)  -> bool { unsafe { crate::dll::AzCallbackInfo_confineToMonitor(self, monitor_id) } }
+        /// Allows the current window to be moved freely again after `confine_to_monitor`
+        pub fn release_from_monitor(&self)  { unsafe { crate::dll::AzCallbackInfo_releaseFromMonitor(self) } }
+        /// Locks the aspect ratio (width / height) of the client area of the current window: when the user resizes the window, the opposite edge follows so that the client area keeps the ratio. Returns `false` if the ratio is not a positive number. Only implemented on Windows.
+        pub fn lock_aspect_ratio(&self, ratio: f32)  -> bool { unsafe { crate::dll::AzCallbackInfo_lockAspectRatio(self, ratio) } }
+        /// Allows the current window to be resized freely again after `lock_aspect_ratio`
+        pub fn unlock_aspect_ratio(&self)  { unsafe { crate::dll::AzCallbackInfo_unlockAspectRatio(self) } }
         /// Spawns a new window with the given `WindowCreateOptions`.
         pub fn create_window<_1: Into<WindowCreateOptions>>(&mut self, new_window: _1)  { unsafe { crate::dll::AzCallbackInfo_createWindow(self, new_window.into()) } }
         /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
@@ -12850,6 +13296,15 @@ pub mod callbacks {
         pub fn default() -> Self { unsafe { crate::dll::AzResolvedTextLayoutOptions_default() } }
     }
 
+    /// Constraints for measuring a text outside of the DOM (`measure_text`), the values are the resolved values of the CSS properties of a text node
+    
+    #[doc(inline)] pub use crate::dll::AzTextConstraints as TextConstraints;
+    impl TextConstraints {
+
+        /// Creates a new `TextConstraints` instance.
+        pub fn default() -> Self { unsafe { crate::dll::AzTextConstraints_default() } }
+    }
+
     /// Animation struct to start a new animation
     
     #[doc(inline)] pub use crate::dll::AzAnimation as Animation;
@@ -12928,6 +13383,12 @@ pub mod callbacks {
     /// `ThreadCallback` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadCallback as ThreadCallback;
+    /// `ImageLoaderCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzImageLoaderCallback as ImageLoaderCallback;
+    /// `ImageLoaderCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzImageLoaderCallbackType as ImageLoaderCallbackType;
     /// `ThreadCallbackType` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadCallbackType as ThreadCallbackType;
@@ -12981,6 +13442,8 @@ pub mod callbacks {
         pub fn get_system_fonts(&self)  -> crate::vec::StringPairVec { unsafe { crate::dll::AzLayoutCallbackInfo_getSystemFonts(self) } }
         /// Returns an `ImageRef` referenced by a CSS ID
         pub fn get_image<_1: Into<String>>(&self, id: _1)  -> crate::option::OptionImageRef { unsafe { crate::dll::AzLayoutCallbackInfo_getImage(self, id.into()) } }
+        /// Measures a text without building a DOM: loads the first available font of `font` and returns the lines, per-glyph positions and the total size of the text, exactly as the layout engine would lay out a text node with the same font, font size and constraints. Returns `None` if no font could be loaded.
+        pub fn measure_text<_1: Into<StyleFontFamilyVec>, _2: Into<StyleFontSize>, _3: Into<String>, _4: Into<TextConstraints>>(&self, font: _1, font_size: _2, text: _3, constraints: _4)  -> crate::option::OptionInlineText { unsafe { crate::dll::AzLayoutCallbackInfo_measureText(self, font.into(), font_size.into(), text.into(), constraints.into()) } }
     }
 
 }
@@ -13142,6 +13605,8 @@ pub mod dom {
         pub fn image<_1: Into<ImageRef>>(image: _1) -> Self { unsafe { crate::dll::AzDom_image(image.into()) } }
         /// Creates a new `Dom` instance.
         pub fn iframe<_1: Into<RefAny>>(data: _1, callback: IFrameCallbackType) -> Self { unsafe { crate::dll::AzDom_iframe(data.into(), callback) } }
+        /// Creates a div that hosts a native child window (i.e. the `HWND` of a WebView2), see `NodeData::set_native_child_window`
+        pub fn native_child_window<_1: Into<NativeChildWindow>>(window: _1) -> Self { unsafe { crate::dll::AzDom_nativeChildWindow(window.into()) } }
         /// Calls the `Dom::set_node_type` function.
         pub fn set_node_type<_1: Into<NodeType>>(&mut self, node_type: _1)  { unsafe { crate::dll::AzDom_setNodeType(self, node_type.into()) } }
         /// Calls the `Dom::with_node_type` function.
@@ -13234,6 +13699,10 @@ pub mod dom {
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzDom_setContextMenu(self, context_menu.into()) } }
         /// Same as set_context_menu, but as a builder method
         pub fn with_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withContextMenu(self, context_menu.into()) } }
+        /// Marks the DOM root node as part of the title bar of the window. See `NodeData::set_title_bar_region` for more information.
+        pub fn set_title_bar_region<_1: Into<TitleBarRegion>>(&mut self, region: _1)  { unsafe { crate::dll::AzDom_setTitleBarRegion(self, region.into()) } }
+        /// Same as set_title_bar_region, but as a builder method
+        pub fn with_title_bar_region<_1: Into<TitleBarRegion>>(&mut self, region: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withTitleBarRegion(self, region.into()) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzDom_hash(self) } }
         /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -13321,10 +13790,20 @@ pub mod dom {
         pub fn set_menu_bar<_1: Into<Menu>>(&mut self, menu_bar: _1)  { unsafe { crate::dll::AzNodeData_setMenuBar(self, menu_bar.into()) } }
         /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzNodeData_setContextMenu(self, context_menu.into()) } }
+        /// Attaches a native child window (i.e. the `HWND` of a WebView2 or a video player) to this node: the window is moved to the visible rect of the node every frame, clipped and scrolled with the layout, and hidden while the node is out of view
+        pub fn set_native_child_window<_1: Into<NativeChildWindow>>(&mut self, window: _1)  { unsafe { crate::dll::AzNodeData_setNativeChildWindow(self, window.into()) } }
+        /// Marks the node as part of the title bar of the window: dragging a `TitleBarRegion::Drag` node moves the window, `TitleBarRegion::NoDrag` nodes inside of it stay interactive. Only nodes of the root DOM are taken into account
+        pub fn set_title_bar_region<_1: Into<TitleBarRegion>>(&mut self, region: _1)  { unsafe { crate::dll::AzNodeData_setTitleBarRegion(self, region.into()) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzNodeData_hash(self) } }
     }
 
+    /// Handle of a native child window (`HWND` on Windows) that is embedded into the layout, see `NodeData::set_native_child_window`
+    
+    #[doc(inline)] pub use crate::dll::AzNativeChildWindow as NativeChildWindow;
+    /// Role of a node in a title bar that is drawn by the application (similar to the CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+    
+    #[doc(inline)] pub use crate::dll::AzTitleBarRegion as TitleBarRegion;
     /// List of core DOM node types built-into by `azul`
     
     #[doc(inline)] pub use crate::dll::AzNodeType as NodeType;
@@ -14872,6 +15351,14 @@ pub mod widgets {
         pub fn set_placeholder<_1: Into<String>>(&mut self, text: _1)  { unsafe { crate::dll::AzTextInput_setPlaceholder(self, text.into()) } }
         /// Calls the `TextInput::with_placeholder` function.
         pub fn with_placeholder<_1: Into<String>>(&mut self, text: _1)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withPlaceholder(self, text.into()) } }
+        /// Calls the `TextInput::set_read_only` function.
+        pub fn set_read_only(&mut self, read_only: bool)  { unsafe { crate::dll::AzTextInput_setReadOnly(self, read_only) } }
+        /// Calls the `TextInput::with_read_only` function.
+        pub fn with_read_only(&mut self, read_only: bool)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withReadOnly(self, read_only) } }
+        /// Calls the `TextInput::set_disabled` function.
+        pub fn set_disabled(&mut self, disabled: bool)  { unsafe { crate::dll::AzTextInput_setDisabled(self, disabled) } }
+        /// Calls the `TextInput::with_disabled` function.
+        pub fn with_disabled(&mut self, disabled: bool)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withDisabled(self, disabled) } }
         /// Calls the `TextInput::set_on_text_input` function.
         pub fn set_on_text_input<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnTextInputCallbackType)  { unsafe { crate::dll::AzTextInput_setOnTextInput(self, data.into(), callback) } }
         /// Calls the `TextInput::with_on_text_input` function.
@@ -17476,6 +17963,8 @@ pub mod gl {
         pub fn get_shader_info_log(&self, shader: u32)  -> crate::str::String { unsafe { crate::dll::AzGl_getShaderInfoLog(self, shader) } }
         /// Calls the `Gl::get_string` function.
         pub fn get_string(&self, which: u32)  -> crate::str::String { unsafe { crate::dll::AzGl_getString(self, which) } }
+        /// Returns the name of the GPU / driver the context was created on (`glGetString(GL_RENDERER)`)
+        pub fn get_renderer_name(&self)  -> crate::str::String { unsafe { crate::dll::AzGl_getRendererName(self) } }
         /// Calls the `Gl::get_string_i` function.
         pub fn get_string_i(&self, which: u32, index: u32)  -> crate::str::String { unsafe { crate::dll::AzGl_getStringI(self, which, index) } }
         /// Calls the `Gl::get_shader_iv` function.
@@ -17706,6 +18195,7 @@ pub mod image {
     use crate::dll::*;
     use core::ffi::c_void;
     use crate::gl::{Texture, U8VecRef};
+    use crate::css::ColorU;
     use crate::callbacks::{RefAny, RenderImageCallbackType};
     use crate::window::LayoutSize;
     use crate::svg::{SvgNode, SvgStyle};
@@ -17720,6 +18210,8 @@ pub mod image {
         pub fn raw_image<_1: Into<RawImage>>(data: _1) ->  crate::option::OptionImageRef { unsafe { crate::dll::AzImageRef_rawImage(data.into()) } }
         /// Creates an image reference from an OpenGL texture
         pub fn gl_texture<_1: Into<Texture>>(texture: _1) -> Self { unsafe { crate::dll::AzImageRef_glTexture(texture.into()) } }
+        /// Creates an image filled with a single color, i.e. a placeholder to show while the real image is still loading
+        pub fn solid_color<_3: Into<ColorU>>(width: usize, height: usize, color: _3) -> Self { unsafe { crate::dll::AzImageRef_solidColor(width, height, color.into()) } }
         /// Creates an image reference from a callback that is going to be rendered with the given nodes computed size
         pub fn callback<_1: Into<RefAny>>(data: _1, callback: RenderImageCallbackType) -> Self { unsafe { crate::dll::AzImageRef_callback(data.into(), callback) } }
         /// Creates a new copy of the image bytes instead of shallow-copying the reference
@@ -18476,8 +18968,15 @@ pub mod task {
         pub fn with_interval<_1: Into<Duration>>(&self, interval: _1)  -> crate::task::Timer { unsafe { crate::dll::AzTimer_withInterval(self, interval.into()) } }
         /// Calls the `Timer::with_timeout` function.
         pub fn with_timeout<_1: Into<Duration>>(&self, timeout: _1)  -> crate::task::Timer { unsafe { crate::dll::AzTimer_withTimeout(self, timeout.into()) } }
+        /// Sets how intervals that were missed while the application was busy are handled, the default is to coalesce them into one run
+        pub fn with_missed_tick_policy<_1: Into<TimerMissedTickPolicy>>(&self, missed_tick_policy: _1)  -> crate::task::Timer { unsafe { crate::dll::AzTimer_withMissedTickPolicy(self, missed_tick_policy.into()) } }
+        /// Exempts the timer from being paused while its window is minimized or cloaked, i.e. for timers that do work that is not related to animations
+        pub fn with_runs_while_hidden(&self, runs_while_hidden: bool)  -> crate::task::Timer { unsafe { crate::dll::AzTimer_withRunsWhileHidden(self, runs_while_hidden) } }
     }
 
+    /// How a timer with an interval should handle ticks that were missed because the application was busy or in the background
+    
+    #[doc(inline)] pub use crate::dll::AzTimerMissedTickPolicy as TimerMissedTickPolicy;
     /// Should a timer terminate or not - used to remove active timers
     
     #[doc(inline)] pub use crate::dll::AzTerminateTimer as TerminateTimer;
@@ -19291,6 +19790,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `StringPairVec`
     
     #[doc(inline)] pub use crate::dll::AzStringPairVec as StringPairVec;
+    /// Wrapper over a Rust-allocated `FontWarmupVec`
+    
+    #[doc(inline)] pub use crate::dll::AzFontWarmupVec as FontWarmupVec;
     /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
     
     #[doc(inline)] pub use crate::dll::AzNormalizedLinearColorStopVec as NormalizedLinearColorStopVec;
@@ -19657,6 +20159,12 @@ pub mod vec {
     /// `StringPairVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzStringPairVecDestructorType as StringPairVecDestructorType;
+    /// `FontWarmupVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzFontWarmupVecDestructor as FontWarmupVecDestructor;
+    /// `FontWarmupVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzFontWarmupVecDestructorType as FontWarmupVecDestructorType;
     /// `NormalizedLinearColorStopVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzNormalizedLinearColorStopVecDestructor as NormalizedLinearColorStopVecDestructor;
@@ -19887,6 +20395,7 @@ pub mod option {
     impl_option!(AzSystemClipboard, AzOptionSystemClipboard, copy = false,  clone = false, [Debug]);
     impl_option!(AzFileTypeList, AzOptionFileTypeList, copy = false, [Debug, Clone]);
     impl_option!(AzWindowState, AzOptionWindowState, copy = false, [Debug, Clone]);
+    impl_option!(AzFocusTarget, AzOptionFocusTarget, copy = false, [Debug, Clone]);
     impl_option!(AzKeyboardState, AzOptionKeyboardState, copy = false, [Debug, Clone]);
     impl_option!(AzMouseState, AzOptionMouseState, [Debug, Clone]);
     impl_option!(AzNodeGraphOnNodeAdded, AzOptionNodeGraphOnNodeAdded, [Debug, Copy, Clone]);
@@ -20068,6 +20577,9 @@ pub mod option {
     /// `OptionInlineText` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionInlineText as OptionInlineText;
+    /// `OptionFocusTarget` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionFocusTarget as OptionFocusTarget;
     /// `OptionLayoutPoint` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLayoutPoint as OptionLayoutPoint;
@@ -20101,6 +20613,9 @@ pub mod option {
     /// `OptionHwndHandle` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionHwndHandle as OptionHwndHandle;
+    /// `OptionLogicalRect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionLogicalRect as OptionLogicalRect;
     /// `OptionLogicalPosition` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLogicalPosition as OptionLogicalPosition;
@@ -10,7 +10,7 @@ use crate::{
     styled_dom::{
         DomId, NodeHierarchyItemId, StyleFontFamiliesHash, StyleFontFamilyHash, StyledDom,
     },
-    task::ExternalSystemCallbacks,
+    task::{ExternalSystemCallbacks, OptionDuration},
     ui_solver::LayoutResult,
     ui_solver::{InlineTextLayout, InlineTextLine, ResolvedTextLayoutOptions},
     window::{LogicalPosition, LogicalRect, LogicalSize, OptionChar},
@@ -23,7 +23,7 @@ use alloc::vec::Vec;
 pub use azul_css::FontMetrics;
 use azul_css::{
     AzString, ColorU, F32Vec, FontRef, LayoutRect, LayoutSize, OptionI32, StyleFontFamily,
-    StyleFontFamilyVec, StyleFontSize, U16Vec, U32Vec, U8Vec, FloatValue,
+    StyleFontFamilyVec, StyleFontSize, U16Vec, U32Vec, U8Vec, FloatValue, OptionAzString,
 };
 use core::{
     fmt,
@@ -60,6 +60,47 @@ pub struct AppConfig {
     pub enable_tab_navigation: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
+    /// Whether the event loop blocks until the next event (default) or
+    /// continuously polls for events and renders
+    pub event_loop_mode: EventLoopMode,
+    /// Which GPU to use on systems with hybrid graphics (default: let the OS decide)
+    pub gpu_preference: GpuPreference,
+    /// Name of the window class registered for the windows of this app
+    /// (default: "AzulApplicationClass"). Override it to avoid collisions
+    /// with other azul apps or DLLs in the same process.
+    pub window_class_name: AzString,
+    /// Opt-in limit for the GPU memory used by the renderer of each window
+    /// (default: unlimited)
+    pub gpu_memory_budget: GpuMemoryBudget,
+    /// Windows only: explicit AppUserModelID of the process, used for taskbar
+    /// grouping, jump lists and toast notifications (default: None = derived
+    /// from the executable path by the OS). Set at startup, before the first
+    /// window is shown.
+    pub app_user_model_id: OptionAzString,
+    /// Directory in which the compiled shader programs of the renderer are
+    /// cached between runs, to speed up the creation of the first window
+    /// (default: None = no disk cache). Caches of other renderer versions
+    /// or GPU drivers are ignored.
+    pub shader_cache_dir: OptionAzString,
+    /// How far (in logical pixels) a mouse, pen or touch input may miss a
+    /// hit-testable node and still hit it (default: 0px for the mouse,
+    /// 4px for pens, 12px for touch input)
+    pub hit_test_tolerance: HitTestTolerance,
+    /// Fonts, sizes and characters that are loaded and rasterized when a
+    /// window is created, so that the first text that uses them doesn't
+    /// stall (default: empty = no warm-up)
+    pub font_warmup: FontWarmupVec,
+    /// Whether webrender builds the scenes of all windows on its scene builder
+    /// thread (default: true). Small UIs usually have lower latency with
+    /// synchronous scene building (the scene is built right after the display
+    /// list is submitted), large UIs benefit from building asynchronously while
+    /// the event loop continues. The scene build time of a window is reported in
+    /// its `DisplayListStats::scene_build_time`.
+    pub async_scene_building: bool,
+    /// Target time of a frame (i.e. 16ms for 60 FPS): if handling the events,
+    /// building the display list, rendering and presenting a frame takes longer,
+    /// a warning with the time of each phase is logged (default: None = no check)
+    pub frame_budget: OptionDuration,
 }
 
 impl AppConfig {
@@ -71,6 +112,130 @@ impl AppConfig {
             enable_logging_on_panic: true,
             enable_tab_navigation: true,
             system_callbacks: ExternalSystemCallbacks::rust_internal(),
+            event_loop_mode: EventLoopMode::Blocking,
+            gpu_preference: GpuPreference::Default,
+            window_class_name: AzString::from_const_str("AzulApplicationClass"),
+            gpu_memory_budget: GpuMemoryBudget::default(),
+            app_user_model_id: OptionAzString::None,
+            shader_cache_dir: OptionAzString::None,
+            hit_test_tolerance: HitTestTolerance::default(),
+            font_warmup: FontWarmupVec::from_const_slice(&[]),
+            async_scene_building: true,
+            frame_budget: OptionDuration::None,
+        }
+    }
+}
+
+/// Font that is loaded at a given size when a window is created, together with
+/// the characters that are rasterized up front (see `AppConfig::font_warmup`)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct FontWarmup {
+    /// Font families in fallback order, same as the CSS `font-family`
+    pub font: StyleFontFamilyVec,
+    /// Font size, same as the CSS `font-size`
+    pub size: StyleFontSize,
+    /// Characters to rasterize, i.e. "abcdefghijklmnopqrstuvwxyz0123456789"
+    pub characters: AzString,
+}
+
+impl FontWarmup {
+    pub fn new(font: StyleFontFamilyVec, size: StyleFontSize, characters: AzString) -> Self {
+        Self {
+            font,
+            size,
+            characters,
+        }
+    }
+}
+
+impl_vec!(FontWarmup, FontWarmupVec, FontWarmupVecDestructor);
+impl_vec_debug!(FontWarmup, FontWarmupVec);
+impl_vec_clone!(FontWarmup, FontWarmupVec, FontWarmupVecDestructor);
+impl_vec_partialeq!(FontWarmup, FontWarmupVec);
+impl_vec_eq!(FontWarmup, FontWarmupVec);
+impl_vec_partialord!(FontWarmup, FontWarmupVec);
+impl_vec_ord!(FontWarmup, FontWarmupVec);
+impl_vec_hash!(FontWarmup, FontWarmupVec);
+
+/// Limits the GPU memory (texture cache, render targets, images) used by the
+/// renderer of a window: every `check_interval_ms`, the memory usage is compared
+/// against `max_bytes` and if it is exceeded, the renderer evicts its caches.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GpuMemoryBudget {
+    /// Maximum GPU memory in bytes (0 = unlimited, default)
+    pub max_bytes: usize,
+    /// How often the memory usage is checked, in milliseconds
+    pub check_interval_ms: u32,
+}
+
+impl GpuMemoryBudget {
+    pub const fn unlimited() -> Self {
+        Self {
+            max_bytes: 0,
+            check_interval_ms: 5000,
+        }
+    }
+
+    pub const fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            check_interval_ms: 5000,
+        }
+    }
+
+    pub const fn is_unlimited(&self) -> bool {
+        self.max_bytes == 0
+    }
+}
+
+impl Default for GpuMemoryBudget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Radius (in logical pixels) by which the hit-testable area of every node is
+/// expanded when hit-testing a pointer position, so that small targets can be hit
+/// with imprecise input devices: if the pointer misses all nodes, the nearest node
+/// within the radius is hit instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct HitTestTolerance {
+    /// Tolerance for mouse input
+    pub mouse: FloatValue,
+    /// Tolerance for pen input
+    pub pen: FloatValue,
+    /// Tolerance for touch input
+    pub touch: FloatValue,
+}
+
+impl HitTestTolerance {
+    /// Only exact hits, for all input devices
+    pub const fn none() -> Self {
+        Self {
+            mouse: FloatValue::const_new(0),
+            pen: FloatValue::const_new(0),
+            touch: FloatValue::const_new(0),
+        }
+    }
+
+    pub fn new(mouse: f32, pen: f32, touch: f32) -> Self {
+        Self {
+            mouse: FloatValue::new(mouse.max(0.0)),
+            pen: FloatValue::new(pen.max(0.0)),
+            touch: FloatValue::new(touch.max(0.0)),
+        }
+    }
+}
+
+impl Default for HitTestTolerance {
+    fn default() -> Self {
+        Self {
+            mouse: FloatValue::const_new(0),
+            pen: FloatValue::const_new(4),
+            touch: FloatValue::const_new(12),
         }
     }
 }
@@ -82,6 +247,43 @@ pub enum LayoutSolverVersion {
     Default,
 }
 
+/// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum GpuPreference {
+    /// Let the OS / driver decide which GPU to use
+    Default,
+    /// Request the dedicated, high-performance GPU
+    HighPerformance,
+    /// Request the integrated, power-saving GPU
+    PowerSaving,
+}
+
+impl Default for GpuPreference {
+    fn default() -> Self {
+        GpuPreference::Default
+    }
+}
+
+/// Strategy of the event loop
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum EventLoopMode {
+    /// Sleep until the next event (input, timer, thread message) arrives and only
+    /// redraw on demand - best for document-style apps (default)
+    Blocking,
+    /// Never sleep: process all pending events in a loop, limited to the given number
+    /// of iterations per second (0 = unlimited). Windows still only render when they
+    /// changed, use `RedrawPolicy::Continuous` for windows that render every frame - best for games
+    Polling(u32),
+}
+
+impl Default for EventLoopMode {
+    fn default() -> Self {
+        EventLoopMode::Blocking
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum AppLogLevel {
@@ -189,6 +391,21 @@ pub enum RawImageFormat {
     RGBAF32,
 }
 
+impl RawImageFormat {
+    pub const fn get_bytes_per_pixel(&self) -> usize {
+        match self {
+            RawImageFormat::R8 => 1,
+            RawImageFormat::RG8 | RawImageFormat::R16 => 2,
+            RawImageFormat::RGB8 | RawImageFormat::BGR8 => 3,
+            RawImageFormat::RGBA8 | RawImageFormat::BGRA8 | RawImageFormat::RG16 => 4,
+            RawImageFormat::RGB16 => 6,
+            RawImageFormat::RGBA16 => 8,
+            RawImageFormat::RGBF32 => 12,
+            RawImageFormat::RGBAF32 => 16,
+        }
+    }
+}
+
 static IMAGE_KEY: AtomicU32 = AtomicU32::new(1); // NOTE: starts at 1 (0 = DUMMY)
 static FONT_KEY: AtomicU32 = AtomicU32::new(0);
 static FONT_INSTANCE_KEY: AtomicU32 = AtomicU32::new(0);
@@ -445,6 +662,20 @@ impl ImageRef {
         }))
     }
 
+    /// Creates an image filled with a single color, i.e. a placeholder to
+    /// show while the real image is still loading
+    pub fn solid_color(width: usize, height: usize, color: ColorU) -> Self {
+        let raw_image = RawImage {
+            pixels: RawImageData::U8([color.r, color.g, color.b, color.a].repeat(width * height).into()),
+            width,
+            height,
+            premultiplied_alpha: false,
+            data_format: RawImageFormat::RGBA8,
+        };
+        Self::new_rawimage(raw_image)
+            .unwrap_or_else(|| Self::invalid(width, height, RawImageFormat::RGBA8))
+    }
+
     pub fn new_rawimage(image_data: RawImage) -> Option<Self> {
         let (image_data, image_descriptor) = image_data.into_loaded_image_source()?;
         Some(Self::new(DecodedImage::Raw((image_descriptor, image_data))))
@@ -655,6 +886,15 @@ impl RendererResources {
         self.currently_registered_images.get(hash)
     }
 
+    /// Returns the (uncompressed) size in bytes of all images
+    /// that are currently registered in the RenderApi
+    pub fn get_registered_image_bytes(&self) -> usize {
+        self.currently_registered_images
+            .values()
+            .map(|i| i.descriptor.width * i.descriptor.height * i.descriptor.format.get_bytes_per_pixel())
+            .sum()
+    }
+
     pub fn get_font_family(
         &self,
         style_font_families_hash: &StyleFontFamiliesHash,
@@ -3114,6 +3354,69 @@ pub fn build_add_font_resource_updates(
     resource_updates
 }
 
+/// Loads the fonts of the `AppConfig::font_warmup` list and adds a font instance
+/// for every size, the same way as if a node with this font had been added to
+/// the DOM. Returns the font key, font instance key and the characters to
+/// rasterize for every entry whose font could be loaded.
+pub fn add_font_warmup_resources(
+    renderer_resources: &mut RendererResources,
+    dpi: DpiScaleFactor,
+    fc_cache: &FcFontCache,
+    id_namespace: IdNamespace,
+    all_resource_updates: &mut Vec<ResourceUpdate>,
+    font_warmup: &[FontWarmup],
+    font_source_load_fn: LoadFontFn,
+    parse_font_fn: ParseFontFn,
+) -> Vec<(FontKey, FontInstanceKey, AzString)> {
+    let mut fonts = FastHashMap::default();
+    for warmup in font_warmup {
+        fonts
+            .entry(ImmediateFontId::Unresolved(warmup.font.clone()))
+            .or_insert_with(|| FastBTreeSet::new())
+            .insert(font_size_to_au(warmup.size));
+    }
+
+    let add_font_resource_updates = build_add_font_resource_updates(
+        renderer_resources,
+        dpi,
+        fc_cache,
+        id_namespace,
+        &fonts,
+        font_source_load_fn,
+        parse_font_fn,
+    );
+
+    add_resources(
+        renderer_resources,
+        all_resource_updates,
+        add_font_resource_updates,
+        Vec::new(),
+    );
+
+    font_warmup
+        .iter()
+        .filter_map(|warmup| {
+            // if one of the families was already loaded before,
+            // there is no entry for the list of families
+            let font_families_hash = StyleFontFamiliesHash::new(warmup.font.as_ref());
+            let font_family_hash = renderer_resources
+                .get_font_family(&font_families_hash)
+                .copied()
+                .or_else(|| {
+                    warmup
+                        .font
+                        .iter()
+                        .map(StyleFontFamilyHash::new)
+                        .find(|h| renderer_resources.font_id_map.contains_key(h))
+                })?;
+            let font_key = *renderer_resources.get_font_key(&font_family_hash)?;
+            let (_, font_instances) = renderer_resources.get_registered_font(&font_key)?;
+            let font_instance_key = *font_instances.get(&(font_size_to_au(warmup.size), dpi))?;
+            Some((font_key, font_instance_key, warmup.characters.clone()))
+        })
+        .collect()
+}
+
 /// Given the images of the current frame, returns `AddImage`s of
 /// which image keys are currently not in the `current_registered_images` and
 /// need to be added.
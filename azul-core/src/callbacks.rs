@@ -4,7 +4,7 @@ use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{
         FontInstanceKey, IdNamespace, ImageCache, ImageMask, ImageRef, LayoutedGlyphs,
-        RendererResources, ShapedWords, WordPositions, Words,
+        OptionImageRef, RendererResources, ShapedWords, WordPositions, Words,
     },
     id_tree::{NodeDataContainer, NodeId},
     styled_dom::{CssPropertyCache, StyledDom, StyledNode},
@@ -12,7 +12,8 @@ use crate::{
     task::{
         CreateThreadCallback, Duration as AzDuration, ExternalSystemCallbacks,
         GetSystemTimeCallback, Instant as AzInstant, Instant, TerminateTimer, Thread, ThreadId,
-        ThreadReceiver, ThreadSendMsg, ThreadSender, Timer, TimerId,
+        ThreadReceiveMsg, ThreadReceiver, ThreadSendMsg, ThreadSender, ThreadWriteBackMsg, Timer,
+        TimerId,
     },
     ui_solver::{
         LayoutResult, OverflowingScrollNode, PositionInfo, PositionedRectangle,
@@ -1290,6 +1291,9 @@ impl CallbackInfo {
     fn internal_get_system_fonts<'a>(&'a mut self) -> &'a mut FcFontCache {
         unsafe { &mut *self.system_fonts }
     }
+    fn internal_get_system_fonts_ref<'a>(&'a self) -> &'a FcFontCache {
+        unsafe { &*self.system_fonts }
+    }
     fn internal_get_timers<'a>(&'a mut self) -> &'a mut FastHashMap<TimerId, Timer> {
         unsafe { &mut *self.timers }
     }
@@ -1623,6 +1627,11 @@ impl CallbackInfo {
         ))
     }
 
+    /// Font cache that the layout engine resolves font families with
+    pub fn get_fc_cache(&self) -> &FcFontCache {
+        self.internal_get_system_fonts_ref()
+    }
+
     /// Returns the FontRef for the given NodeId
     pub fn get_font_ref(&self, node_id: DomNodeId) -> Option<FontRef> {
         use crate::styled_dom::StyleFontFamiliesHash;
@@ -1749,7 +1758,7 @@ impl CallbackInfo {
         dom_node_id: DomNodeId,
         animation: Animation,
     ) -> Option<TimerId> {
-        use crate::task::SystemTimeDiff;
+        use crate::task::{SystemTimeDiff, TimerMissedTickPolicy};
 
         let layout_result = self
             .internal_get_layout_results()
@@ -1777,6 +1786,12 @@ impl CallbackInfo {
             return None;
         }
 
+        // the user turned off animations: jump to the end state immediately
+        let mut animation = animation;
+        if self.internal_get_current_window_state().accessibility.prefers_reduced_motion {
+            animation.from = animation.to.clone();
+        }
+
         let timer_id = TimerId::unique();
 
         let now = self.get_current_time();
@@ -1808,6 +1823,8 @@ impl CallbackInfo {
             delay: None.into(),
             interval: Some(AzDuration::System(SystemTimeDiff::from_millis(10))).into(),
             timeout: timer_duration.into(),
+            missed_tick_policy: TimerMissedTickPolicy::Coalesce,
+            runs_while_hidden: false,
             callback: TimerCallback {
                 cb: drive_animation_func,
             },
@@ -1883,6 +1900,79 @@ impl CallbackInfo {
                 .insert(nid, new_image_mask);
         }
     }
+
+    /// Decodes an image on a background thread. The node keeps showing its current
+    /// image (the placeholder, see `ImageRef::solid_color`) until `loader` returns,
+    /// then the decoded image - or `fallback` if the loader returned `None` - is
+    /// swapped into the same texture and the node is repainted.
+    ///
+    /// NOTE: the layout is not recomputed, so the node should have a fixed size.
+    pub fn load_image_async(
+        &mut self,
+        node_id: DomNodeId,
+        data: RefAny,
+        loader: ImageLoaderCallbackType,
+        fallback: ImageRef,
+    ) -> Option<ThreadId> {
+        let load_data = RefAny::new(ImageLoadThreadData {
+            data,
+            loader: ImageLoaderCallback { cb: loader },
+        });
+        let writeback_data = RefAny::new(ImageLoadWriteBackData { node_id, fallback });
+        self.start_thread(load_data, writeback_data, load_image_thread)
+    }
+}
+
+struct ImageLoadThreadData {
+    data: RefAny,
+    loader: ImageLoaderCallback,
+}
+
+struct ImageLoadWriteBackData {
+    node_id: DomNodeId,
+    fallback: ImageRef,
+}
+
+// runs on the background thread started by load_image_async
+extern "C" fn load_image_thread(
+    mut load_data: RefAny,
+    mut sender: ThreadSender,
+    _receiver: ThreadReceiver,
+) {
+    let image = match load_data.downcast_mut::<ImageLoadThreadData>() {
+        Some(mut s) => {
+            let s = &mut *s;
+            (s.loader.cb)(&mut s.data)
+        }
+        None => OptionImageRef::None,
+    };
+
+    let _ = sender.send(ThreadReceiveMsg::WriteBack(ThreadWriteBackMsg::new(
+        image_loaded,
+        RefAny::new(image),
+    )));
+}
+
+// runs on the main thread once the image is decoded: swaps the placeholder
+// for the decoded image or the fallback
+extern "C" fn image_loaded(
+    writeback_data: &mut RefAny,
+    image: &mut RefAny,
+    info: &mut CallbackInfo,
+) -> Update {
+    let writeback_data = match writeback_data.downcast_ref::<ImageLoadWriteBackData>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let image = image
+        .downcast_ref::<OptionImageRef>()
+        .and_then(|i| i.into_option())
+        .unwrap_or_else(|| writeback_data.fallback.clone());
+
+    info.update_image(writeback_data.node_id, image, UpdateImageType::Content);
+
+    Update::DoNothing
 }
 
 impl Clone for CallbackInfo {
@@ -2416,6 +2506,17 @@ pub struct ThreadCallback {
 }
 impl_callback!(ThreadCallback);
 
+// -- image loader callback
+
+/// Decodes an image on a background thread, returns `None` if the image could not be loaded
+pub type ImageLoaderCallbackType = extern "C" fn(&mut RefAny) -> OptionImageRef;
+
+#[repr(C)]
+pub struct ImageLoaderCallback {
+    pub cb: ImageLoaderCallbackType,
+}
+impl_callback!(ImageLoaderCallback);
+
 // -- timer callback
 
 /// Callback that can runs on every frame on the main thread - can modify the app data model
@@ -2554,6 +2655,11 @@ impl LayoutCallbackInfo {
         self.internal_get_gl_context().clone()
     }
 
+    /// Font cache that the layout engine resolves font families with
+    pub fn get_fc_cache(&self) -> &FcFontCache {
+        self.internal_get_system_fonts()
+    }
+
     pub fn get_system_fonts(&self) -> Vec<AzStringPair> {
         self.internal_get_system_fonts()
             .list()
@@ -2622,6 +2728,13 @@ pub enum FocusTarget {
     NoFocus,
 }
 
+impl_option!(
+    FocusTarget,
+    OptionFocusTarget,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct FocusTargetPath {
@@ -2637,103 +2750,12 @@ impl FocusTarget {
     ) -> Result<Option<DomNodeId>, UpdateFocusWarning> {
         use crate::callbacks::FocusTarget::*;
         use crate::style::matches_html_element;
+        use crate::window::get_tab_order;
 
         if layout_results.is_empty() {
             return Ok(None);
         }
 
-        macro_rules! search_for_focusable_node_id {
-            ($layout_results:expr, $start_dom_id:expr, $start_node_id:expr, $get_next_node_fn:ident) => {{
-                let mut start_dom_id = $start_dom_id;
-                let mut start_node_id = $start_node_id;
-
-                let min_dom_id = DomId::ROOT_ID;
-                let max_dom_id = DomId {
-                    inner: layout_results.len() - 1,
-                };
-
-                // iterate through all DOMs
-                loop {
-                    // 'outer_dom_iter
-
-                    let layout_result = $layout_results
-                        .get(start_dom_id.inner)
-                        .ok_or(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone()))?;
-
-                    let node_id_valid = layout_result
-                        .styled_dom
-                        .node_data
-                        .as_container()
-                        .get(start_node_id)
-                        .is_some();
-
-                    if !node_id_valid {
-                        return Err(UpdateFocusWarning::FocusInvalidNodeId(
-                            NodeHierarchyItemId::from_crate_internal(Some(start_node_id.clone())),
-                        ));
-                    }
-
-                    if layout_result.styled_dom.node_data.is_empty() {
-                        return Err(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone()));
-                        // ???
-                    }
-
-                    let max_node_id = NodeId::new(layout_result.styled_dom.node_data.len() - 1);
-                    let min_node_id = NodeId::ZERO;
-
-                    // iterate through nodes in DOM
-                    loop {
-                        let current_node_id =
-                            NodeId::new(start_node_id.index().$get_next_node_fn(1))
-                                .max(min_node_id)
-                                .min(max_node_id);
-
-                        if layout_result.styled_dom.node_data.as_container()[current_node_id]
-                            .is_focusable()
-                        {
-                            return Ok(Some(DomNodeId {
-                                dom: start_dom_id,
-                                node: NodeHierarchyItemId::from_crate_internal(Some(
-                                    current_node_id,
-                                )),
-                            }));
-                        }
-
-                        if current_node_id == min_node_id && current_node_id < start_node_id {
-                            // going in decreasing (previous) direction
-                            if start_dom_id == min_dom_id {
-                                // root node / root dom encountered
-                                return Ok(None);
-                            } else {
-                                start_dom_id.inner -= 1;
-                                start_node_id = NodeId::new(
-                                    $layout_results[start_dom_id.inner]
-                                        .styled_dom
-                                        .node_data
-                                        .len()
-                                        - 1,
-                                );
-                                break; // continue 'outer_dom_iter
-                            }
-                        } else if current_node_id == max_node_id && current_node_id > start_node_id
-                        {
-                            // going in increasing (next) direction
-                            if start_dom_id == max_dom_id {
-                                // last dom / last node encountered
-                                return Ok(None);
-                            } else {
-                                start_dom_id.inner += 1;
-                                start_node_id = NodeId::ZERO;
-                                break; // continue 'outer_dom_iter
-                            }
-                        } else {
-                            start_node_id = current_node_id;
-                        }
-                    }
-                }
-            }};
-        }
-
         match self {
             Path(FocusTargetPath { dom, css_path }) => {
                 let layout_result = layout_results
@@ -2786,109 +2808,22 @@ impl FocusTarget {
                     Ok(Some(dom_node_id.clone()))
                 }
             }
-            Previous => {
-                let last_layout_dom_id = DomId {
-                    inner: layout_results.len() - 1,
-                };
-
-                // select the previous focusable element or `None`
-                // if this was the first focusable element in the DOM
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if let Some(layout_result) = layout_results.get(s.dom.inner) {
-                                (
-                                    s.dom,
-                                    NodeId::new(layout_result.styled_dom.node_data.len() - 1),
-                                )
-                            } else {
-                                (
-                                    last_layout_dom_id,
-                                    NodeId::new(
-                                        layout_results[last_layout_dom_id.inner]
-                                            .styled_dom
-                                            .node_data
-                                            .len()
-                                            - 1,
-                                    ),
-                                )
-                            }
-                        }
-                    },
-                    None => (
-                        last_layout_dom_id,
-                        NodeId::new(
-                            layout_results[last_layout_dom_id.inner]
-                                .styled_dom
-                                .node_data
-                                .len()
-                                - 1,
-                        ),
-                    ),
-                };
-
-                search_for_focusable_node_id!(
-                    layout_results,
-                    current_focus_dom,
-                    current_focus_node_id,
-                    saturating_sub
-                );
-            }
-            Next => {
-                // select the previous focusable element or `None`
-                // if this was the first focusable element in the DOM, select the first focusable element
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if layout_results.get(s.dom.inner).is_some() {
-                                (s.dom, NodeId::ZERO)
-                            } else {
-                                (DomId::ROOT_ID, NodeId::ZERO)
-                            }
-                        }
-                    },
-                    None => (DomId::ROOT_ID, NodeId::ZERO),
-                };
-
-                search_for_focusable_node_id!(
-                    layout_results,
-                    current_focus_dom,
-                    current_focus_node_id,
-                    saturating_add
-                );
-            }
-            First => {
-                let (current_focus_dom, current_focus_node_id) = (DomId::ROOT_ID, NodeId::ZERO);
-                search_for_focusable_node_id!(
-                    layout_results,
-                    current_focus_dom,
-                    current_focus_node_id,
-                    saturating_add
-                );
-            }
-            Last => {
-                let last_layout_dom_id = DomId {
-                    inner: layout_results.len() - 1,
+            Previous | Next => {
+                // select the previous / next focusable element in tab order or `None`
+                // if this was the first / last focusable element in the window
+                let tab_order = get_tab_order(layout_results);
+                let current_index = current_focus
+                    .and_then(|f| tab_order.iter().position(|n| *n == f));
+                let next_index = match (current_index, self) {
+                    (None, Previous) => tab_order.len().checked_sub(1),
+                    (None, _) => Some(0),
+                    (Some(i), Previous) => i.checked_sub(1),
+                    (Some(i), _) => Some(i + 1),
                 };
-                let (current_focus_dom, current_focus_node_id) = (
-                    last_layout_dom_id,
-                    NodeId::new(
-                        layout_results[last_layout_dom_id.inner]
-                            .styled_dom
-                            .node_data
-                            .len()
-                            - 1,
-                    ),
-                );
-                search_for_focusable_node_id!(
-                    layout_results,
-                    current_focus_dom,
-                    current_focus_node_id,
-                    saturating_add
-                );
+                Ok(next_index.and_then(|i| tab_order.get(i).copied()))
             }
+            First => Ok(get_tab_order(layout_results).first().copied()),
+            Last => Ok(get_tab_order(layout_results).last().copied()),
             NoFocus => Ok(None),
         }
     }
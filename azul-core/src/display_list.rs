@@ -25,6 +25,7 @@ use azul_css::{
     StyleMixBlendMode,
 };
 use core::fmt;
+use core::time::Duration;
 use rust_fontconfig::FcFontCache;
 
 pub type GlyphIndex = u32;
@@ -80,6 +81,34 @@ impl CachedDisplayList {
     }
 }
 
+/// Display lists of the iframes (separate webrender pipelines) submitted in the
+/// last frame: iframes whose display list didn't change are not re-submitted
+#[derive(Debug, Default, Clone)]
+pub struct RetainedDisplayLists {
+    pub iframes: BTreeMap<PipelineId, CachedDisplayList>,
+    /// Statistics of the last display list rebuild
+    pub stats: DisplayListStats,
+    /// Set if a new display list was submitted with async scene building
+    /// and the renderer hasn't waited for the scene yet
+    pub scene_build_pending: bool,
+    /// Time spent building and submitting display lists since the last
+    /// frame was presented, reset by the windowing system on every present
+    pub build_time_since_present: Duration,
+}
+
+/// How many display list nodes (frames / scroll frames) were rebuilt
+/// or reused (retained by webrender) in the last display list rebuild
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplayListStats {
+    pub nodes_rebuilt: usize,
+    pub nodes_reused: usize,
+    /// How long building the webrender scene of the last display list took.
+    /// With synchronous scene building, this is the full build time; with
+    /// async scene building, only the time that rendering had to wait for the
+    /// scene builder thread (`None` if no scene was built yet)
+    pub scene_build_time: Option<Duration>,
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum DisplayListMsg {
     // nested display list
@@ -106,6 +135,46 @@ impl DisplayListMsg {
         }
     }
 
+    // warning: recursive function!
+    /// Whether any frame, scroll frame or iframe has a NaN or infinite size or
+    /// position (i.e. from a division by zero in a layout callback), which
+    /// webrender can't render
+    pub fn has_non_finite_geometry(&self) -> bool {
+        use self::DisplayListMsg::*;
+
+        fn frame_is_non_finite(f: &DisplayListFrame) -> bool {
+            let offset = f.position.get_relative_offset();
+            !(f.size.width.is_finite() && f.size.height.is_finite() &&
+              offset.x.is_finite() && offset.y.is_finite()) ||
+            f.children.iter().any(|c| c.has_non_finite_geometry())
+        }
+
+        match self {
+            Frame(f) => frame_is_non_finite(f),
+            ScrollFrame(sf) => {
+                let r = sf.content_rect;
+                !(r.origin.x.is_finite() && r.origin.y.is_finite() &&
+                  r.size.width.is_finite() && r.size.height.is_finite()) ||
+                frame_is_non_finite(&sf.frame)
+            },
+            IFrame(_, size, _, dl) => {
+                !(size.width.is_finite() && size.height.is_finite()) ||
+                dl.root.has_non_finite_geometry()
+            },
+        }
+    }
+
+    // warning: recursive function!
+    /// Number of frames and scroll frames in this display list (including iframes)
+    pub fn node_count(&self) -> usize {
+        use self::DisplayListMsg::*;
+        match self {
+            Frame(f) => 1 + f.children.iter().map(|c| c.node_count()).sum::<usize>(),
+            ScrollFrame(sf) => 1 + sf.frame.children.iter().map(|c| c.node_count()).sum::<usize>(),
+            IFrame(_, _, _, dl) => dl.root.node_count(),
+        }
+    }
+
     pub fn get_transform_key(&self) -> Option<&(TransformKey, ComputedTransform3D)> {
         use self::DisplayListMsg::*;
         match self {
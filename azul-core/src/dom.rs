@@ -15,6 +15,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use azul_css::{AzString, Css, CssProperty, FontRef, NodeTypeTag, OptionAzString};
 use core::{
+    ffi::c_void,
     fmt,
     hash::{Hash, Hasher},
     iter::FromIterator,
@@ -781,6 +782,12 @@ impl Hash for NodeData {
             if let Some(c) = ext.context_menu.as_ref() {
                 c.hash(state);
             }
+            if let Some(c) = ext.native_child_window.as_ref() {
+                c.hash(state);
+            }
+            if let Some(c) = ext.title_bar_region.as_ref() {
+                c.hash(state);
+            }
         }
     }
 }
@@ -798,9 +805,41 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Native child window that is positioned at the rect of this node
+    pub(crate) native_child_window: Option<NativeChildWindow>,
+    /// Whether the node acts as the title bar of the window, see `NodeData::set_title_bar_region`
+    pub(crate) title_bar_region: Option<TitleBarRegion>,
     // ... insert further API extensions here...
 }
 
+/// Role of a node in a title bar that is drawn by the application (similar to the
+/// CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TitleBarRegion {
+    /// Dragging the node moves the window, double-clicking it maximizes / restores
+    /// the window - the node behaves like the native caption and gets no mouse events
+    Drag,
+    /// Interactive control inside of a `Drag` region (i.e. a close button),
+    /// receives mouse events as usual. Wins over overlapping `Drag` regions.
+    NoDrag,
+}
+
+/// Handle of a native child window (`HWND` on Windows) that is embedded
+/// into the layout, see `NodeData::set_native_child_window`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct NativeChildWindow {
+    pub handle: *mut c_void,
+}
+
+impl NativeChildWindow {
+    #[inline]
+    pub const fn new(handle: *mut c_void) -> Self {
+        Self { handle }
+    }
+}
+
 /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[repr(C)]
@@ -1240,6 +1279,14 @@ impl NodeData {
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> {
         self.extra.as_ref().and_then(|e| e.context_menu.as_ref())
     }
+    #[inline]
+    pub fn get_native_child_window(&self) -> Option<NativeChildWindow> {
+        self.extra.as_ref().and_then(|e| e.native_child_window)
+    }
+    #[inline]
+    pub fn get_title_bar_region(&self) -> Option<TitleBarRegion> {
+        self.extra.as_ref().and_then(|e| e.title_bar_region)
+    }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) {
@@ -1296,6 +1343,30 @@ impl NodeData {
         self
     }
 
+    /// Attaches a native child window (i.e. the `HWND` of a WebView2 or a video player)
+    /// to this node: the node reserves the space in the layout and the window is moved
+    /// to the visible rect of the node every frame, clipped and scrolled with the
+    /// layout. The window is hidden while the node is scrolled out of view or removed
+    /// from the DOM.
+    #[inline]
+    pub fn set_native_child_window(&mut self, window: NativeChildWindow) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .native_child_window = Some(window);
+    }
+
+    /// Marks the node as part of the title bar of the window: the user can move
+    /// the window by dragging a `TitleBarRegion::Drag` node (i.e. a custom title bar,
+    /// see `WindowFlags::has_decorations`), `TitleBarRegion::NoDrag` nodes inside of it
+    /// (buttons, tabs, search fields) stay interactive. Only nodes of the root DOM
+    /// are taken into account.
+    #[inline]
+    pub fn set_title_bar_region(&mut self, region: TitleBarRegion) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .title_bar_region = Some(region);
+    }
+
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
         let mut v: CallbackDataVec = Vec::new().into();
@@ -1529,6 +1600,13 @@ impl Dom {
             data,
         }))
     }
+    /// Div that hosts a native child window, see `NodeData::set_native_child_window`
+    #[inline]
+    pub fn native_child_window(window: NativeChildWindow) -> Self {
+        let mut dom = Self::div();
+        dom.root.set_native_child_window(window);
+        dom
+    }
 
     // Swaps `self` with a default DOM, necessary for builder methods
     #[inline(always)]
@@ -1633,6 +1711,12 @@ impl Dom {
         self
     }
 
+    #[inline]
+    pub fn with_title_bar_region(mut self, region: TitleBarRegion) -> Self {
+        self.root.set_title_bar_region(region);
+        self
+    }
+
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
             self.estimated_total_children = 0;
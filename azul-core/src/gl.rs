@@ -1969,6 +1969,10 @@ impl GlContextPtr {
     pub fn get_shader_info_log(&self, shader: GLuint) -> AzString {
         self.get().get_shader_info_log(shader).into()
     }
+    /// Returns the name of the GPU / driver the context was created on (`glGetString(GL_RENDERER)`)
+    pub fn get_renderer_name(&self) -> AzString {
+        self.get_string(gl::RENDERER)
+    }
     pub fn get_string(&self, which: GLenum) -> AzString {
         self.get().get_string(which).into()
     }
@@ -2836,6 +2836,24 @@ impl StyledDom {
         self.tag_ids_to_node_ids = new_tag_ids.into();
     }
 
+    /// Replaces the stylesheet of an already styled DOM: unlike `restyle()`, all
+    /// styles of the previous stylesheet are removed first. Inline styles and
+    /// properties that were overridden in callbacks are kept.
+    pub fn replace_css(&mut self, css: CssApiWrapper) {
+        {
+            let cache = self.css_property_cache.downcast_mut();
+            cache.css_normal_props.clear();
+            cache.css_hover_props.clear();
+            cache.css_active_props.clear();
+            cache.css_focus_props.clear();
+            cache.cascaded_normal_props.clear();
+            cache.cascaded_hover_props.clear();
+            cache.cascaded_active_props.clear();
+            cache.cascaded_focus_props.clear();
+        }
+        self.restyle(css);
+    }
+
     /// Inserts default On::Scroll and On::Tab handle for scroll-able
     /// and tabindex-able nodes.
     #[inline]
@@ -3908,3 +3926,56 @@ fn recursive_get_last_child(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dom::IdOrClass::Class;
+    use azul_css::StyleOpacity;
+
+    fn get_test_dom() -> StyledDom {
+        Dom::body()
+        .with_children(vec![
+            Dom::div().with_ids_and_classes(vec![Class("a".into())].into())
+        ].into())
+        .style(CssApiWrapper::from_string("body { width: 10px; } .a { height: 20px; }".into()))
+    }
+
+    fn has_css_prop(styled_dom: &StyledDom, node_id: usize, prop: CssPropertyType) -> bool {
+        styled_dom.css_property_cache.ptr.css_normal_props
+            .get(&NodeId::new(node_id))
+            .map(|props| props.contains_key(&prop))
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_replace_css_removes_previous_styles() {
+        let mut styled_dom = get_test_dom();
+        assert!(has_css_prop(&styled_dom, 0, CssPropertyType::Width));
+        assert!(has_css_prop(&styled_dom, 1, CssPropertyType::Height));
+
+        styled_dom.replace_css(CssApiWrapper::from_string(".a { width: 30px; }".into()));
+
+        assert!(!has_css_prop(&styled_dom, 0, CssPropertyType::Width));
+        assert!(!has_css_prop(&styled_dom, 1, CssPropertyType::Height));
+        assert!(has_css_prop(&styled_dom, 1, CssPropertyType::Width));
+    }
+
+    #[test]
+    fn test_replace_css_keeps_overridden_properties() {
+        let mut styled_dom = get_test_dom();
+        styled_dom.css_property_cache.downcast_mut().user_overridden_properties
+            .entry(NodeId::new(1))
+            .or_insert_with(|| BTreeMap::new())
+            .insert(CssPropertyType::Opacity, CssProperty::const_opacity(StyleOpacity::const_new(50)));
+
+        styled_dom.replace_css(CssApiWrapper::empty());
+
+        let overridden = styled_dom.css_property_cache.ptr.user_overridden_properties
+            .get(&NodeId::new(1))
+            .and_then(|props| props.get(&CssPropertyType::Opacity))
+            .cloned();
+        assert_eq!(overridden, Some(CssProperty::const_opacity(StyleOpacity::const_new(50))));
+    }
+}
@@ -49,7 +49,25 @@ pub enum TerminateTimer {
     Continue,
 }
 
-static MAX_TIMER_ID: AtomicUsize = AtomicUsize::new(5);
+/// How a timer with an interval should handle ticks that were missed because
+/// the application was busy or in the background
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TimerMissedTickPolicy {
+    /// Run the callback once, no matter how many intervals were missed (default)
+    Coalesce,
+    /// Run the callback once for every interval that elapsed since the last run
+    CatchUp,
+}
+
+impl Default for TimerMissedTickPolicy {
+    fn default() -> Self {
+        TimerMissedTickPolicy::Coalesce
+    }
+}
+
+// IDs 1 - 6 are reserved for the internal WM_TIMER ticks of the win32 shell
+static MAX_TIMER_ID: AtomicUsize = AtomicUsize::new(7);
 
 /// ID for uniquely identifying a timer
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -486,6 +504,45 @@ impl Duration {
             }
         }
     }
+
+    /// Returns how many whole `interval`s fit into this duration
+    /// (returns 0 for a zero-length interval)
+    pub fn whole_intervals(&self, interval: &Self) -> u64 {
+        use self::Duration::*;
+        match (self, interval) {
+            (System(s), System(i)) => {
+                let i = i.as_nanos();
+                if i == 0 {
+                    0
+                } else {
+                    (s.as_nanos() / i).min(u64::MAX as u128) as u64
+                }
+            }
+            (Tick(t), Tick(i)) => t.tick_diff.checked_div(i.tick_diff).unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    /// Multiplies the duration by `n`, saturating at `Duration::max()`
+    pub fn saturating_mul(&self, n: u64) -> Self {
+        match self {
+            Duration::System(s) => {
+                let nanos = s.as_nanos().saturating_mul(n as u128);
+                let secs = nanos / (NANOS_PER_SEC as u128);
+                if secs > u64::MAX as u128 {
+                    Duration::max()
+                } else {
+                    Duration::System(SystemTimeDiff {
+                        secs: secs as u64,
+                        nanos: (nanos % (NANOS_PER_SEC as u128)) as u32,
+                    })
+                }
+            }
+            Duration::Tick(t) => Duration::Tick(SystemTickDiff {
+                tick_diff: t.tick_diff.saturating_mul(n),
+            }),
+        }
+    }
 }
 
 /// Represents a difference in ticks for systems that
@@ -518,6 +575,9 @@ impl SystemTimeDiff {
     fn as_secs_f64(&self) -> f64 {
         (self.secs as f64) + ((self.nanos as f64) / (NANOS_PER_SEC as f64))
     }
+    fn as_nanos(&self) -> u128 {
+        (self.secs as u128) * (NANOS_PER_SEC as u128) + (self.nanos as u128)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -629,6 +689,12 @@ pub struct Timer {
     /// When to stop the timer (for example, you can stop the
     /// execution after 5s using `Some(Duration::from_secs(5))`).
     pub timeout: OptionDuration,
+    /// Whether intervals that were missed (because the application was busy)
+    /// are coalesced into one run or caught up by running the callback once per interval
+    pub missed_tick_policy: TimerMissedTickPolicy,
+    /// Whether the timer keeps running while its window is minimized or cloaked
+    /// (default: false, the timer is paused until the window is visible again)
+    pub runs_while_hidden: bool,
     /// Callback to be called for this timer
     pub callback: TimerCallback,
 }
@@ -649,6 +715,8 @@ impl Timer {
             delay: OptionDuration::None,
             interval: OptionDuration::None,
             timeout: OptionDuration::None,
+            missed_tick_policy: TimerMissedTickPolicy::Coalesce,
+            runs_while_hidden: false,
             callback: TimerCallback { cb: callback },
         }
     }
@@ -688,6 +756,66 @@ impl Timer {
             .add_optional_duration(self.interval.as_ref())
     }
 
+    /// Returns the instant from which the elapsed intervals are counted: the last
+    /// scheduled run or, if the timer hasn't run yet, the end of the initial delay
+    fn schedule_start(&self) -> Instant {
+        match self.last_run.as_ref() {
+            Some(s) => s.clone(),
+            None => self.created.add_optional_duration(self.delay.as_ref()),
+        }
+    }
+
+    /// Returns how many whole intervals have elapsed since the last scheduled run,
+    /// measured with the (monotonic) `instant_now` instead of the cadence in which
+    /// the timer happens to be polled. Timers without an interval are always due once.
+    pub fn elapsed_intervals(&self, instant_now: &Instant) -> usize {
+        let interval = match self.interval.as_ref() {
+            Some(s) => s,
+            None => return 1,
+        };
+
+        let start = self.schedule_start();
+        if *instant_now < start {
+            return 0;
+        }
+
+        let elapsed = instant_now.duration_since(&start);
+        if elapsed.smaller_than(interval) {
+            0
+        } else {
+            (elapsed.whole_intervals(interval).min(usize::MAX as u64) as usize).max(1)
+        }
+    }
+
+    /// Returns how often the callback has to be invoked at `instant_now`,
+    /// depending on the `missed_tick_policy` of the timer
+    pub fn pending_invocations(&self, instant_now: &Instant) -> usize {
+        let elapsed_intervals = self.elapsed_intervals(instant_now);
+        match self.missed_tick_policy {
+            TimerMissedTickPolicy::Coalesce => elapsed_intervals.min(1),
+            TimerMissedTickPolicy::CatchUp => elapsed_intervals,
+        }
+    }
+
+    /// Updates `last_run` and `run_count` after an invocation: timers with an interval stay
+    /// aligned to their schedule, so that one late tick doesn't delay all following ticks
+    fn record_run(&mut self, instant_now: &Instant, elapsed_intervals: usize) {
+        let last_run = match self.interval.as_ref() {
+            Some(interval) => {
+                let intervals = match self.missed_tick_policy {
+                    TimerMissedTickPolicy::Coalesce => elapsed_intervals,
+                    TimerMissedTickPolicy::CatchUp => 1,
+                };
+                self.schedule_start()
+                    .add_optional_duration(Some(&interval.saturating_mul(intervals as u64)))
+            }
+            None => instant_now.clone(),
+        };
+
+        self.last_run = OptionInstant::Some(last_run);
+        self.run_count += 1;
+    }
+
     /// Delays the timer to not start immediately but rather
     /// start after a certain time frame has elapsed.
     #[inline]
@@ -712,6 +840,22 @@ impl Timer {
         self
     }
 
+    /// Sets how intervals that were missed while the application was busy are handled
+    /// (see `TimerMissedTickPolicy`), the default is to coalesce them into one run.
+    #[inline]
+    pub fn with_missed_tick_policy(mut self, missed_tick_policy: TimerMissedTickPolicy) -> Self {
+        self.missed_tick_policy = missed_tick_policy;
+        self
+    }
+
+    /// Exempts the timer from being paused while its window is minimized or
+    /// cloaked, i.e. for timers that do work that is not related to animations
+    #[inline]
+    pub fn with_runs_while_hidden(mut self, runs_while_hidden: bool) -> Self {
+        self.runs_while_hidden = runs_while_hidden;
+        self
+    }
+
     /// Crate-internal: Invokes the timer if the timer should run. Otherwise returns `Update::DoNothing`
     pub fn invoke(
         &mut self,
@@ -720,22 +864,13 @@ impl Timer {
         get_system_time_fn: GetSystemTimeCallback,
     ) -> TimerCallbackReturn {
         let instant_now = (get_system_time_fn.cb)();
+        let elapsed_intervals = self.elapsed_intervals(&instant_now);
 
-        if let OptionDuration::Some(interval) = self.interval {
-            let last_run = match self.last_run.as_ref() {
-                Some(s) => s.clone(),
-                None => self.created.add_optional_duration(self.delay.as_ref()),
+        if elapsed_intervals == 0 {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Continue,
             };
-
-            if instant_now
-                .duration_since(&last_run)
-                .smaller_than(&interval)
-            {
-                return TimerCallbackReturn {
-                    should_update: Update::DoNothing,
-                    should_terminate: TerminateTimer::Continue,
-                };
-            }
         }
 
         let run_count = self.run_count;
@@ -756,8 +891,7 @@ impl Timer {
             res.should_terminate = TerminateTimer::Terminate;
         }
 
-        self.last_run = OptionInstant::Some(instant_now);
-        self.run_count += 1;
+        self.record_run(&instant_now, elapsed_intervals);
 
         res
     }
@@ -1339,3 +1473,124 @@ extern "C" fn thread_sender_drop(_: *mut ThreadSenderInner) {}
 
 #[cfg(feature = "std")]
 extern "C" fn thread_receiver_drop(_: *mut ThreadReceiverInner) {}
+
+#[test]
+fn test_timer_missed_ticks_stalled_then_resumed() {
+    extern "C" fn tick_zero() -> Instant {
+        Instant::Tick(SystemTick::new(0))
+    }
+    extern "C" fn do_nothing(_: &mut RefAny, _: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+        TimerCallbackReturn {
+            should_update: Update::DoNothing,
+            should_terminate: TerminateTimer::Continue,
+        }
+    }
+
+    // runs the timer like the event loop would at the given tick,
+    // returns how often the callback was invoked
+    fn poll(timer: &mut Timer, tick: u64) -> usize {
+        let instant_now = Instant::Tick(SystemTick::new(tick));
+        let invocations = timer.pending_invocations(&instant_now);
+        for _ in 0..invocations {
+            let elapsed_intervals = timer.elapsed_intervals(&instant_now);
+            timer.record_run(&instant_now, elapsed_intervals);
+        }
+        invocations
+    }
+
+    let timer = Timer::new(
+        RefAny::new(()),
+        do_nothing,
+        GetSystemTimeCallback { cb: tick_zero },
+    )
+    .with_interval(Duration::Tick(SystemTickDiff { tick_diff: 10 }));
+
+    let mut coalesce = timer.clone();
+    let mut catch_up = timer.with_missed_tick_policy(TimerMissedTickPolicy::CatchUp);
+
+    for timer in [&mut coalesce, &mut catch_up] {
+        assert_eq!(poll(timer, 5), 0);
+        assert_eq!(poll(timer, 10), 1);
+        assert_eq!(poll(timer, 21), 1);
+        assert_eq!(poll(timer, 29), 0);
+    }
+
+    // application stalls from tick 29 to tick 75: the intervals at 30, 40, 50, 60, 70 are missed
+    assert_eq!(poll(&mut coalesce, 75), 1);
+    assert_eq!(poll(&mut catch_up, 75), 5);
+    assert_eq!(coalesce.run_count, 3);
+    assert_eq!(catch_up.run_count, 7);
+
+    // both timers resume on the original schedule instead of drifting to 85
+    for timer in [&mut coalesce, &mut catch_up] {
+        assert_eq!(
+            timer.last_run,
+            OptionInstant::Some(Instant::Tick(SystemTick::new(70)))
+        );
+        assert_eq!(poll(timer, 79), 0);
+        assert_eq!(poll(timer, 80), 1);
+        assert_eq!(poll(timer, 90), 1);
+    }
+}
+
+#[test]
+fn test_timer_pending_invocations_delay_and_no_interval() {
+    extern "C" fn tick_zero() -> Instant {
+        Instant::Tick(SystemTick::new(0))
+    }
+    extern "C" fn do_nothing(_: &mut RefAny, _: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+        TimerCallbackReturn {
+            should_update: Update::DoNothing,
+            should_terminate: TerminateTimer::Continue,
+        }
+    }
+
+    let tick = |t: u64| Instant::Tick(SystemTick::new(t));
+
+    // the initial delay is not counted as missed intervals
+    let delayed = Timer::new(
+        RefAny::new(()),
+        do_nothing,
+        GetSystemTimeCallback { cb: tick_zero },
+    )
+    .with_delay(Duration::Tick(SystemTickDiff { tick_diff: 50 }))
+    .with_interval(Duration::Tick(SystemTickDiff { tick_diff: 10 }))
+    .with_missed_tick_policy(TimerMissedTickPolicy::CatchUp);
+
+    assert_eq!(delayed.pending_invocations(&tick(0)), 0);
+    assert_eq!(delayed.pending_invocations(&tick(55)), 0);
+    assert_eq!(delayed.pending_invocations(&tick(60)), 1);
+    assert_eq!(delayed.pending_invocations(&tick(85)), 3);
+
+    // a timer without an interval is due once on every poll
+    let mut no_interval = Timer::new(
+        RefAny::new(()),
+        do_nothing,
+        GetSystemTimeCallback { cb: tick_zero },
+    )
+    .with_missed_tick_policy(TimerMissedTickPolicy::CatchUp);
+
+    assert_eq!(no_interval.pending_invocations(&tick(0)), 1);
+    no_interval.record_run(&tick(100), 1);
+    assert_eq!(no_interval.pending_invocations(&tick(1000)), 1);
+    assert_eq!(
+        no_interval.last_run,
+        OptionInstant::Some(tick(100))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_duration_whole_intervals() {
+    let interval = Duration::System(SystemTimeDiff::from_millis(16));
+    let elapsed = Duration::System(SystemTimeDiff::from_millis(100));
+    assert_eq!(elapsed.whole_intervals(&interval), 6);
+    assert_eq!(
+        interval.saturating_mul(6),
+        Duration::System(SystemTimeDiff::from_millis(96))
+    );
+    assert_eq!(
+        elapsed.whole_intervals(&Duration::System(SystemTimeDiff::from_secs(0))),
+        0
+    );
+}
@@ -20,6 +20,7 @@ use crate::{
 };
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use azul_css::{
     ColorU as StyleColorU, CssPropertyValue, LayoutBorderBottomWidth, LayoutBorderLeftWidth,
@@ -690,6 +691,187 @@ impl LayoutResult {
         LayoutRect::new(self.root_position, self.root_size)
     }
 
+    /// Hit-test tolerance for imprecise pointers: if `cursor` misses all hit-testable
+    /// nodes, but lies within `tolerance` logical pixels of one of them, returns the
+    /// point inside of the nearest such node that the hit test should use instead.
+    ///
+    /// Nodes that contain another candidate are skipped, so that a hit container
+    /// does not shadow the small targets inside of it. On a tie, the node that is
+    /// painted last wins. Returns `None` if a target is hit directly or if no target
+    /// is within the tolerance. Scroll offsets and transforms are not taken into account.
+    pub fn get_hit_test_position_with_tolerance(
+        &self,
+        cursor: LogicalPosition,
+        tolerance: f32,
+    ) -> Option<LogicalPosition> {
+        let rects = self.rects.as_ref();
+        let targets = self
+            .styled_dom
+            .tag_ids_to_node_ids
+            .iter()
+            .filter_map(|t| {
+                let node_id = t.node_id.into_crate_internal()?;
+                let r = rects.get(node_id)?;
+                let rect = LogicalRect::new(r.position.get_static_offset(), r.size);
+                Some((node_id, rect, t.parent_node_ids.as_ref()))
+            });
+
+        get_hit_test_position_with_tolerance(cursor, tolerance, targets)
+    }
+
+    /// Returns the visible part of the rect of a node, relative to the top left of
+    /// the DOM: the layout rect, moved by the scroll offsets of the scroll frames
+    /// that the node is in and clipped to them and to `overflow: hidden` ancestors.
+    /// Returns `None` if the node is scrolled / clipped out of view entirely.
+    pub fn get_visible_node_rect(&self, node_id: NodeId, scroll_states: &ScrollStates) -> Option<LogicalRect> {
+
+        let rects = self.rects.as_ref();
+        let node_hierarchy = self.styled_dom.node_hierarchy.as_container();
+
+        let r = rects.get(node_id)?;
+        let mut rect = LogicalRect::new(r.position.get_static_offset(), r.size);
+        let mut clips: Vec<LogicalRect> = Vec::new();
+
+        let mut current = node_hierarchy[node_id].parent_id();
+        while let Some(parent) = current {
+
+            let parent_hierarchy_id = NodeHierarchyItemId::from_crate_internal(Some(parent));
+
+            if let Some(scroll_node) = self.scrollable_nodes.overflowing_nodes.get(&parent_hierarchy_id) {
+                // the scroll frame moves everything inside of it, but not its own clip
+                let offset = scroll_states.0
+                    .get(&scroll_node.parent_external_scroll_id)
+                    .map(|s| s.get())
+                    .unwrap_or_else(LogicalPosition::zero);
+
+                rect.origin.x -= offset.x;
+                rect.origin.y -= offset.y;
+                for clip in clips.iter_mut() {
+                    clip.origin.x -= offset.x;
+                    clip.origin.y -= offset.y;
+                }
+
+                clips.push(scroll_node.parent_rect);
+            } else if let Some(clip_size) = self.scrollable_nodes.clip_nodes.get(&parent) {
+                if let Some(p) = rects.get(parent) {
+                    clips.push(LogicalRect::new(p.position.get_static_offset(), *clip_size));
+                }
+            }
+
+            current = node_hierarchy[parent].parent_id();
+        }
+
+        clips.iter().try_fold(rect, |rect, clip| rect.intersection(clip))
+    }
+
+    /// Returns a human-readable dump of the solved layout tree: one line per
+    /// node (indented by depth) containing the node id, tag, ids / classes,
+    /// the computed rect, the display / position values, the resolved
+    /// margin / padding / border widths and the index of the node in the
+    /// rendering order (z-order). Used for debugging layout issues.
+    #[cfg(feature = "multithreading")]
+    pub fn get_layout_tree_string(&self) -> String {
+        use crate::dom::IdOrClass;
+        use crate::styled_dom::ContentGroup;
+        use core::fmt::Write;
+
+        fn collect_rendering_order(group: &ContentGroup, order: &mut BTreeMap<NodeId, usize>) {
+            if let Some(root) = group.root.into_crate_internal() {
+                let next = order.len();
+                order.entry(root).or_insert(next);
+            }
+            for child in group.children.as_ref().iter() {
+                collect_rendering_order(child, order);
+            }
+        }
+
+        fn fmt_offsets(o: &ResolvedOffsets) -> String {
+            let mut s = String::new();
+            let _ = write!(s, "{} {} {} {}", o.top, o.right, o.bottom, o.left);
+            s
+        }
+
+        let mut rendering_order = BTreeMap::new();
+        collect_rendering_order(
+            &self.styled_dom.get_rects_in_rendering_order(),
+            &mut rendering_order,
+        );
+
+        let node_hierarchy = self.styled_dom.node_hierarchy.as_container();
+        let node_data = self.styled_dom.node_data.as_container();
+        let rects = self.rects.as_ref();
+        let layout_displays = self.layout_displays.as_ref();
+        let layout_positions = self.layout_positions.as_ref();
+
+        let mut output = String::new();
+        let _ = writeln!(
+            output,
+            "dom {} (parent: {}) @ {:?} {:?}",
+            self.dom_id.inner,
+            self.parent_dom_id
+                .map(|p| p.inner.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            self.root_position,
+            self.root_size,
+        );
+
+        for node_id in node_hierarchy.linear_iter() {
+            let mut depth = 0;
+            let mut cur = node_hierarchy[node_id].parent_id();
+            while let Some(p) = cur {
+                depth += 1;
+                cur = node_hierarchy[p].parent_id();
+            }
+
+            let nd = &node_data[node_id];
+            let mut selector = nd.get_node_type().get_path().to_string();
+            for ioc in nd.get_ids_and_classes().as_ref().iter() {
+                let _ = match ioc {
+                    IdOrClass::Id(s) => write!(selector, "#{}", s.as_str()),
+                    IdOrClass::Class(s) => write!(selector, ".{}", s.as_str()),
+                };
+            }
+
+            let rect = &rects[node_id];
+            let origin = rect.position.get_static_offset();
+            let display = layout_displays[node_id]
+                .get_property()
+                .copied()
+                .unwrap_or_default();
+
+            let _ = write!(
+                output,
+                "{:indent$}#{} <{}> [{}, {} {}x{}] display: {:?}, position: {:?}, z: {}, \
+                 margin: [{}], padding: [{}], border: [{}]",
+                "",
+                node_id.index(),
+                selector,
+                origin.x,
+                origin.y,
+                rect.size.width,
+                rect.size.height,
+                display,
+                layout_positions[node_id],
+                rendering_order
+                    .get(&node_id)
+                    .map(|z| z.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                fmt_offsets(&rect.margin),
+                fmt_offsets(&rect.padding),
+                fmt_offsets(&rect.border_widths),
+                indent = depth * 4,
+            );
+
+            if let Some(child_dom) = self.iframe_mapping.get(&node_id) {
+                let _ = write!(output, " -> iframe dom {}", child_dom.inner);
+            }
+
+            output.push('\n');
+        }
+
+        output
+    }
+
     #[cfg(feature = "multithreading")]
     pub fn get_cached_display_list(
         document_id: &DocumentId,
@@ -998,6 +1180,67 @@ impl LayoutResult {
     }
 }
 
+// Geometry of `LayoutResult::get_hit_test_position_with_tolerance`: `targets` are
+// the hit-testable nodes with their rect and the ids of their parent nodes
+fn get_hit_test_position_with_tolerance<'a, I>(
+    cursor: LogicalPosition,
+    tolerance: f32,
+    targets: I,
+) -> Option<LogicalPosition>
+where
+    I: Iterator<Item = (NodeId, LogicalRect, &'a [NodeHierarchyItemId])>,
+{
+    use alloc::collections::btree_set::BTreeSet;
+
+    if !(tolerance > 0.0) {
+        return None;
+    }
+
+    let candidates = targets
+        .filter_map(|(node_id, rect, parents)| {
+            let distance = rect.distance_to(&cursor);
+            if distance <= tolerance {
+                Some((node_id, rect, distance, parents))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let ancestors = candidates
+        .iter()
+        .flat_map(|(_, _, _, parents)| parents.iter())
+        .filter_map(|p| p.into_crate_internal())
+        .collect::<BTreeSet<_>>();
+
+    let mut nearest: Option<(LogicalRect, f32)> = None;
+    for (node_id, rect, distance, _) in candidates {
+        if ancestors.contains(&node_id) {
+            continue;
+        }
+        match nearest {
+            Some((_, d)) if d < distance => {}
+            _ => nearest = Some((rect, distance)),
+        }
+    }
+
+    let (rect, distance) = nearest?;
+    if distance == 0.0 {
+        return None;
+    }
+
+    // clamp the cursor into the rect, slightly inset so that
+    // the point is not ambiguous due to rounding at the edge
+    let inset_x = (rect.size.width / 2.0).min(0.5);
+    let inset_y = (rect.size.height / 2.0).min(0.5);
+    let x = cursor.x.max(rect.min_x() + inset_x);
+    let y = cursor.y.max(rect.min_y() + inset_y);
+    Some(LogicalPosition::new(
+        x.min(rect.max_x() - inset_x),
+        y.min(rect.max_y() - inset_y),
+    ))
+}
+
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
 pub struct GpuValueCache {
     pub transform_keys: BTreeMap<NodeId, TransformKey>,
@@ -1311,6 +1554,40 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd]
 );
 
+/// Constraints for measuring a text outside of the DOM (`measure_text`), the
+/// values are the resolved values of the CSS properties of a text node
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextConstraints {
+    /// Maximum width of the text (in pixels) before lines are broken, `None` = no line breaks
+    pub max_width: OptionF32,
+    /// Multiplier for the line height (`line-height`), default to 1.0
+    pub line_height: OptionF32,
+    /// Additional spacing between glyphs in pixels (`letter-spacing`)
+    pub letter_spacing: OptionF32,
+    /// Additional spacing between words in pixels (`word-spacing`)
+    pub word_spacing: OptionF32,
+    /// How many spaces a tab character emulates (`tab-width`)
+    pub tab_width: OptionF32,
+}
+
+impl TextConstraints {
+    /// Returns the options that the layout engine would use
+    /// for a text node with these properties
+    pub fn to_text_layout_options(&self, font_size_px: f32) -> ResolvedTextLayoutOptions {
+        ResolvedTextLayoutOptions {
+            font_size_px,
+            line_height: self.line_height,
+            letter_spacing: self.letter_spacing,
+            word_spacing: self.word_spacing,
+            tab_width: self.tab_width,
+            max_horizontal_width: self.max_width,
+            leading: None.into(),
+            holes: Vec::new().into(),
+        }
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct ResolvedOffsets {
@@ -2470,3 +2747,66 @@ impl ComputedTransform3D {
         pre_transform.then(&rotate_transform).then(&post_transform)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn target(id: usize, x: f32, y: f32, w: f32, h: f32) -> (NodeId, LogicalRect) {
+        (NodeId::new(id), LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(w, h)))
+    }
+
+    fn hit_test(
+        cursor: LogicalPosition,
+        tolerance: f32,
+        targets: &[(NodeId, LogicalRect, Vec<NodeHierarchyItemId>)],
+    ) -> Option<LogicalPosition> {
+        get_hit_test_position_with_tolerance(
+            cursor,
+            tolerance,
+            targets.iter().map(|(id, rect, parents)| (*id, *rect, parents.as_slice())),
+        )
+    }
+
+    #[test]
+    fn test_tolerance_snaps_to_nearby_target() {
+        let (id, rect) = target(1, 10.0, 10.0, 10.0, 10.0);
+        let targets = vec![(id, rect, Vec::new())];
+        let pos = hit_test(LogicalPosition::new(5.0, 15.0), 8.0, &targets).unwrap();
+        assert!(rect.hit_test(&pos).is_some());
+        assert_eq!(pos.y, 15.0);
+    }
+
+    #[test]
+    fn test_tolerance_ignores_far_targets_and_direct_hits() {
+        let (id, rect) = target(1, 10.0, 10.0, 10.0, 10.0);
+        let targets = vec![(id, rect, Vec::new())];
+        assert_eq!(hit_test(LogicalPosition::new(0.0, 0.0), 4.0, &targets), None);
+        assert_eq!(hit_test(LogicalPosition::new(15.0, 15.0), 4.0, &targets), None);
+        assert_eq!(hit_test(LogicalPosition::new(5.0, 15.0), 0.0, &targets), None);
+    }
+
+    #[test]
+    fn test_tolerance_picks_nearest_target() {
+        let (id1, rect1) = target(1, 0.0, 0.0, 10.0, 10.0);
+        let (id2, rect2) = target(2, 20.0, 0.0, 10.0, 10.0);
+        let targets = vec![(id1, rect1, Vec::new()), (id2, rect2, Vec::new())];
+        let pos = hit_test(LogicalPosition::new(17.0, 5.0), 8.0, &targets).unwrap();
+        assert!(rect2.hit_test(&pos).is_some());
+    }
+
+    #[test]
+    fn test_tolerance_skips_containers_of_candidates() {
+        // the container is hit directly, but the button inside of it is nearby:
+        // the container must not shadow the button
+        let (container_id, container_rect) = target(1, 0.0, 0.0, 100.0, 100.0);
+        let (button_id, button_rect) = target(2, 10.0, 10.0, 10.0, 10.0);
+        let targets = vec![
+            (container_id, container_rect, Vec::new()),
+            (button_id, button_rect, vec![NodeHierarchyItemId::from_crate_internal(Some(container_id))]),
+        ];
+        let pos = hit_test(LogicalPosition::new(22.0, 15.0), 4.0, &targets).unwrap();
+        assert!(button_rect.hit_test(&pos).is_some());
+    }
+}
@@ -7,12 +7,12 @@ use crate::{
     callbacks::{Callback, HitTestItem, UpdateImageType},
     callbacks::{
         CallbackType, DocumentId, DomNodeId, LayoutCallback, LayoutCallbackType, OptionCallback,
-        PipelineId, RefAny, ScrollPosition, Update,
+        OptionFocusTarget, PipelineId, RefAny, ScrollPosition, Update,
     },
-    display_list::RenderCallbacks,
-    dom::NodeHierarchy,
+    display_list::{RenderCallbacks, RetainedDisplayLists},
+    dom::{NodeData, NodeHierarchy, TabIndex},
     id_tree::NodeId,
-    styled_dom::{DomId, NodeHierarchyItemId},
+    styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
     task::{ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
     ui_solver::{
         ExternalScrollId, HitTest, LayoutResult, OverflowingScrollNode, QuickResizeResult,
@@ -25,7 +25,7 @@ use alloc::collections::btree_map::BTreeMap;
 use alloc::collections::btree_set::BTreeSet;
 use alloc::vec::Vec;
 use azul_css::{
-    AzString, ColorU, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, OptionAzString,
+    AzString, ColorU, Css, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, OptionAzString,
     OptionF32, OptionI32, U8Vec, FloatValue,
 };
 use core::{
@@ -82,6 +82,7 @@ pub struct RendererOptions {
     pub vsync: Vsync,
     pub srgb: Srgb,
     pub hw_accel: HwAcceleration,
+    pub present_mode: PresentMode,
 }
 
 impl_option!(
@@ -96,6 +97,7 @@ impl Default for RendererOptions {
             vsync: Vsync::Enabled,
             srgb: Srgb::Disabled,
             hw_accel: HwAcceleration::Enabled,
+            present_mode: PresentMode::Fifo,
         }
     }
 }
@@ -106,10 +108,71 @@ impl RendererOptions {
             vsync,
             srgb,
             hw_accel,
+            present_mode: PresentMode::from_vsync(vsync),
         }
     }
 }
 
+/// How rendered frames are presented to the screen: controls vsync
+/// (swap interval), tearing and frame pacing in one place.
+///
+/// Changing the present mode of a window at runtime takes effect on the next frame.
+#[repr(C, u8)]
+#[derive(PartialEq, Copy, Clone, Debug, PartialOrd, Ord, Eq, Hash)]
+pub enum PresentMode {
+    /// Present frames as soon as they are rendered (no vsync, may tear)
+    Immediate,
+    /// Wait for the vertical blank before presenting (vsync, no tearing)
+    Fifo,
+    /// Present without waiting for the vertical blank, but limit the number of
+    /// presented frames per second (low latency, may tear)
+    FrameCapped(u32),
+}
+
+impl PresentMode {
+    pub const fn from_vsync(vsync: Vsync) -> Self {
+        match vsync {
+            Vsync::Disabled => PresentMode::Immediate,
+            Vsync::Enabled | Vsync::DontCare => PresentMode::Fifo,
+        }
+    }
+
+    /// Returns the swap interval (`wglSwapIntervalEXT` / `glXSwapIntervalEXT`)
+    /// that corresponds to this present mode
+    pub const fn get_swap_interval(&self) -> i32 {
+        match self {
+            PresentMode::Fifo => 1,
+            PresentMode::Immediate | PresentMode::FrameCapped(_) => 0,
+        }
+    }
+
+    /// Returns the minimum duration between two presented frames in
+    /// milliseconds, if the present mode limits the frame rate
+    pub fn get_min_frame_time_ms(&self) -> Option<f32> {
+        match self {
+            PresentMode::FrameCapped(fps) if *fps > 0 => Some(1000.0 / *fps as f32),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a window only renders when its content changed or on every frame
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug, PartialOrd, Ord, Eq, Hash)]
+pub enum RedrawPolicy {
+    /// Render a new frame only when the window is invalidated (default)
+    OnDemand,
+    /// Render every frame unconditionally (live dashboards, games), paced by
+    /// the `PresentMode` of the window (vsync or the frame cap)
+    Continuous,
+}
+
+impl Default for RedrawPolicy {
+    fn default() -> Self {
+        RedrawPolicy::OnDemand
+    }
+}
+
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug, PartialOrd, Ord, Eq, Hash)]
 pub enum Vsync {
@@ -666,6 +729,7 @@ pub fn update_full_window_state(
     full_window_state.keyboard_state = window_state.keyboard_state.clone();
     full_window_state.mouse_state = window_state.mouse_state;
     full_window_state.ime_position = window_state.ime_position.into();
+    full_window_state.text_caret = window_state.text_caret.clone();
     full_window_state.platform_specific_options = window_state.platform_specific_options.clone();
 }
 
@@ -696,12 +760,232 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Display lists of the iframes submitted in the last frame (see `rebuild_display_list`)
+    pub retained_display_lists: RetainedDisplayLists,
+    /// Stylesheet that replaces the stylesheet of the root DOM (see `set_css`),
+    /// re-applied every time the layout callback is invoked
+    pub css_override: Option<Css>,
 }
 
 impl WindowInternal {
     pub fn get_dpi_scale_factor(&self) -> DpiScaleFactor {
         DpiScaleFactor { inner: FloatValue::new(self.current_window_state.size.get_hidpi_factor()) }
     }
+
+    /// Returns a human-readable dump of the current layout of all DOMs
+    /// (root DOM + iframes), see `LayoutResult::get_layout_tree_string`.
+    ///
+    /// Does not require a renderer or a native window, so it can also be used
+    /// to inspect the layout of a headless `WindowInternal`.
+    #[cfg(feature = "multithreading")]
+    pub fn get_layout_tree_string(&self) -> alloc::string::String {
+        let mut output = alloc::string::String::new();
+        for layout_result in self.layout_results.iter() {
+            output.push_str(&layout_result.get_layout_tree_string());
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Returns all nodes that can be reached via keyboard (Tab) navigation, in tab order,
+    /// see `get_tab_order`.
+    pub fn get_tab_order(&self) -> Vec<DomNodeId> {
+        get_tab_order(&self.layout_results)
+    }
+
+    /// Returns the node that should be focused when the user presses Tab
+    /// (or Shift + Tab if `reverse` is set), wrapping around at the end of the tab order.
+    ///
+    /// Returns `None` if there are no keyboard-focusable nodes in the window.
+    pub fn get_next_tab_focus(&self, reverse: bool) -> Option<DomNodeId> {
+        let tab_order = self.get_tab_order();
+
+        if tab_order.is_empty() {
+            return None;
+        }
+
+        let current_index = self
+            .current_window_state
+            .focused_node
+            .and_then(|f| tab_order.iter().position(|n| *n == f));
+
+        let next_index = get_next_tab_index(current_index, tab_order.len(), reverse);
+
+        tab_order.get(next_index).copied()
+    }
+
+    /// Returns whether moving the focus with Tab (Shift + Tab if `reverse` is set)
+    /// would wrap around, i.e. the focused node is the last (first) node in tab order
+    /// or the window has no focusable nodes. Windows that are embedded in each other
+    /// hand the focus to the next window instead of wrapping around.
+    pub fn tab_focus_wraps(&self, reverse: bool) -> bool {
+        let tab_order = self.get_tab_order();
+
+        let edge = if reverse { tab_order.first() } else { tab_order.last() };
+
+        match (edge, self.current_window_state.focused_node) {
+            (None, _) => true,
+            (Some(edge), Some(focused)) => *edge == focused,
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Adjusts the scroll positions of all scroll containers that contain `node`
+    /// so that the node is fully visible (as far as possible).
+    ///
+    /// Returns whether any scroll position was changed.
+    pub fn scroll_node_into_view(&mut self, node: DomNodeId) -> bool {
+        let layout_result = match self.layout_results.get(node.dom.inner) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let node_id = match node.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let node_rect = match layout_result.rects.as_ref().get(node_id) {
+            Some(r) => LogicalRect::new(r.position.get_static_offset(), r.size),
+            None => return false,
+        };
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let mut scrolled = false;
+        let mut current_parent = node_hierarchy.get(node_id).and_then(|n| n.parent_id());
+
+        while let Some(parent_id) = current_parent {
+            if let Some(scroll_node) = layout_result
+                .scrollable_nodes
+                .overflowing_nodes
+                .get(&NodeHierarchyItemId::from_crate_internal(Some(parent_id)))
+            {
+                let current_scroll = self
+                    .scroll_states
+                    .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                    .unwrap_or(LogicalPosition::zero());
+
+                let visible = &scroll_node.parent_rect;
+                let mut new_scroll = current_scroll;
+
+                if node_rect.origin.x < visible.origin.x + current_scroll.x {
+                    new_scroll.x = node_rect.origin.x - visible.origin.x;
+                } else if node_rect.max_x() > visible.max_x() + current_scroll.x {
+                    new_scroll.x = node_rect.max_x() - visible.max_x();
+                }
+
+                if node_rect.origin.y < visible.origin.y + current_scroll.y {
+                    new_scroll.y = node_rect.origin.y - visible.origin.y;
+                } else if node_rect.max_y() > visible.max_y() + current_scroll.y {
+                    new_scroll.y = node_rect.max_y() - visible.max_y();
+                }
+
+                if new_scroll != current_scroll {
+                    self.scroll_states.set_scroll_position(scroll_node, new_scroll);
+                    scrolled = true;
+                }
+            }
+
+            current_parent = node_hierarchy.get(parent_id).and_then(|n| n.parent_id());
+        }
+
+        scrolled
+    }
+}
+
+/// Returns all nodes in `layout_results` that can be reached via keyboard (Tab)
+/// navigation, in tab order.
+///
+/// Nodes with an explicit `TabIndex::OverrideInParent(n > 0)` come first (sorted
+/// by `n`, ties are resolved in DOM order), followed by all other focusable nodes
+/// (`TabIndex::Auto`, `TabIndex::OverrideInParent(0)` or nodes that only have focus
+/// callbacks) in DOM order. Nodes with `TabIndex::NoKeyboardFocus` (disabled for
+/// keyboard focus) and nodes that are not visible (`display: none` or zero-sized)
+/// are skipped.
+pub(crate) fn get_tab_order(layout_results: &[LayoutResult]) -> Vec<DomNodeId> {
+    use azul_css::LayoutDisplay;
+
+    let mut focusable_nodes = Vec::new();
+
+    for layout_result in layout_results.iter() {
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        let layout_displays = layout_result.layout_displays.as_ref();
+        let rects = layout_result.rects.as_ref();
+
+        for node_id in node_data.linear_iter() {
+            let tab_index = match get_focusable_tab_index(&node_data[node_id]) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let is_hidden = layout_displays
+                .get(node_id)
+                .and_then(|d| d.get_property())
+                .map(|d| *d == LayoutDisplay::None)
+                .unwrap_or(false)
+                || rects
+                    .get(node_id)
+                    .map(|r| r.size.width <= 0.0 || r.size.height <= 0.0)
+                    .unwrap_or(true);
+
+            if is_hidden {
+                continue;
+            }
+
+            let dom_node_id = DomNodeId {
+                dom: layout_result.dom_id,
+                node: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
+            };
+
+            focusable_nodes.push((tab_index, dom_node_id));
+        }
+    }
+
+    sort_tab_order(focusable_nodes)
+}
+
+// Tab index of a focusable node: nodes that are only focusable
+// because of their focus callbacks are treated as `TabIndex::Auto`
+fn get_focusable_tab_index(node_data: &NodeData) -> Option<TabIndex> {
+    if !node_data.is_focusable() {
+        return None;
+    }
+    Some(node_data.get_tab_index().copied().unwrap_or(TabIndex::Auto))
+}
+
+// Sorts the keyboard-focusable nodes (given in DOM order) into tab order, see `get_tab_order`
+fn sort_tab_order(nodes: Vec<(TabIndex, DomNodeId)>) -> Vec<DomNodeId> {
+
+    let mut explicit = Vec::new();
+    let mut auto = Vec::new();
+
+    for (tab_index, dom_node_id) in nodes {
+        match tab_index {
+            TabIndex::NoKeyboardFocus => {}
+            TabIndex::OverrideInParent(n) if n > 0 => explicit.push((n, dom_node_id)),
+            TabIndex::Auto | TabIndex::OverrideInParent(_) => auto.push(dom_node_id),
+        }
+    }
+
+    // stable sort: nodes with the same tab index stay in DOM order
+    explicit.sort_by_key(|(n, _)| *n);
+
+    explicit
+        .into_iter()
+        .map(|(_, dom_node_id)| dom_node_id)
+        .chain(auto.into_iter())
+        .collect()
+}
+
+// Index of the node that receives the focus on Tab / Shift + Tab in a tab order
+// of `len` nodes (len > 0), wrapping around at the end, see `get_next_tab_focus`
+fn get_next_tab_index(current_index: Option<usize>, len: usize, reverse: bool) -> usize {
+    match (current_index, reverse) {
+        (None, false) => 0,
+        (None, true) => len - 1,
+        (Some(i), false) => (i + 1) % len,
+        (Some(i), true) => (i + len - 1) % len,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -914,6 +1198,8 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            retained_display_lists: RetainedDisplayLists::default(),
+            css_override: None,
         }
     }
 
@@ -929,17 +1215,12 @@ impl WindowInternal {
         callbacks: &RenderCallbacks,
         fc_cache_real: &mut FcFontCache,
         relayout_fn: RelayoutFn,
-        mut hit_test_func: F,
+        hit_test_func: F,
     ) where
         F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest,
     {
         use crate::callbacks::LayoutCallbackInfo;
-        use crate::display_list::SolvedLayout;
-        use crate::gl::gl_textures_remove_epochs_from_pipeline;
         use crate::styled_dom::DefaultCallbacksCfg;
-        use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
-
-        let id_namespace = self.id_namespace;
 
         let mut styled_dom = {
             let layout_callback = &mut self.current_window_state.layout_callback;
@@ -961,10 +1242,99 @@ impl WindowInternal {
         };
 
         styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
-            smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled,
+            smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled &&
+                !self.current_window_state.accessibility.prefers_reduced_motion,
             enable_autotab: self.current_window_state.flags.autotab_enabled,
         });
 
+        #[cfg(feature = "css_parser")] {
+            use azul_css_parser::CssApiWrapper;
+            if let Some(css) = self.css_override.as_ref() {
+                styled_dom.replace_css(CssApiWrapper { css: css.clone() });
+            }
+        }
+
+        self.relayout_styled_dom(
+            styled_dom,
+            image_cache,
+            gl_context,
+            all_resource_updates,
+            current_window_dpi,
+            callbacks,
+            fc_cache_real,
+            relayout_fn,
+            hit_test_func,
+        );
+    }
+
+    /// Replaces the stylesheet of the root DOM and re-layouts the window
+    /// without invoking the layout callback: the DOM structure, the scroll
+    /// positions and the focused node stay the same. Fonts and images that
+    /// are still in use after the swap keep their renderer resources, so
+    /// only glyphs of new font sizes / families have to be rasterized.
+    ///
+    /// The stylesheet stays active until it is replaced again, i.e. it is
+    /// also applied to DOMs returned by later calls to the layout callback.
+    #[cfg(all(feature = "multithreading", feature = "css_parser"))]
+    pub fn set_css<F>(
+        &mut self,
+        css: Css,
+        image_cache: &ImageCache,
+        gl_context: &OptionGlContextPtr,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+        current_window_dpi: DpiScaleFactor,
+        callbacks: &RenderCallbacks,
+        fc_cache_real: &mut FcFontCache,
+        relayout_fn: RelayoutFn,
+        hit_test_func: F,
+    ) where
+        F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest,
+    {
+        use azul_css_parser::CssApiWrapper;
+
+        let mut styled_dom = match self.layout_results.get(0) {
+            Some(s) => s.styled_dom.clone(),
+            None => return,
+        };
+
+        styled_dom.replace_css(CssApiWrapper { css: css.clone() });
+        self.css_override = Some(css);
+
+        self.relayout_styled_dom(
+            styled_dom,
+            image_cache,
+            gl_context,
+            all_resource_updates,
+            current_window_dpi,
+            callbacks,
+            fc_cache_real,
+            relayout_fn,
+            hit_test_func,
+        );
+    }
+
+    // Lays out a new root DOM (and its iframes), replaces the current layout results
+    #[cfg(all(feature = "multithreading"))]
+    fn relayout_styled_dom<F>(
+        &mut self,
+        styled_dom: StyledDom,
+        image_cache: &ImageCache,
+        gl_context: &OptionGlContextPtr,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+        current_window_dpi: DpiScaleFactor,
+        callbacks: &RenderCallbacks,
+        fc_cache_real: &mut FcFontCache,
+        relayout_fn: RelayoutFn,
+        mut hit_test_func: F,
+    ) where
+        F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest,
+    {
+        use crate::display_list::SolvedLayout;
+        use crate::gl::gl_textures_remove_epochs_from_pipeline;
+        use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
+
+        let id_namespace = self.id_namespace;
+
         let SolvedLayout { mut layout_results } = SolvedLayout::new(
             styled_dom,
             self.epoch,
@@ -1206,6 +1576,7 @@ impl WindowInternal {
             threads_removed: None,
             windows_created: Vec::new(),
             cursor_changed: false,
+            key_down_handled: false,
         };
 
         let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
@@ -1239,43 +1610,52 @@ impl WindowInternal {
             let cursor_relative_to_item = OptionLogicalPosition::None;
             let cursor_in_viewport = OptionLogicalPosition::None;
 
-            let callback_info = CallbackInfo::new(
-                &self.layout_results,
-                &self.renderer_resources,
-                &self.previous_window_state,
-                &self.current_window_state,
-                &mut ret_modified_window_state,
-                gl_context,
-                image_cache,
-                system_fonts,
-                &mut ret_timers,
-                &mut ret_threads,
-                &mut ret_timers_removed,
-                &mut ret_threads_removed,
-                current_window_handle,
-                &mut ret.windows_created,
-                system_callbacks,
-                &mut stop_propagation,
-                &mut new_focus_target,
-                &mut ret_words_changed,
-                &mut ret_images_changed,
-                &mut ret_image_masks_changed,
-                &mut ret_css_properties_changed,
-                &current_scroll_states,
-                &mut ret_nodes_scrolled_in_callbacks,
-                hit_dom_node,
-                cursor_relative_to_item,
-                cursor_in_viewport,
-            );
-
-            let tcr = timer.invoke(
-                callback_info,
-                frame_start.clone(),
-                system_callbacks.get_system_time_fn,
-            );
-
-            ret.callbacks_update_screen = tcr.should_update;
-            should_terminate = tcr.should_terminate;
+            // Depending on the missed tick policy, a timer that missed several intervals
+            // (because the event loop was blocked) is invoked once or once per interval
+            let invocations = timer.pending_invocations(&frame_start);
+
+            for _ in 0..invocations {
+                let callback_info = CallbackInfo::new(
+                    &self.layout_results,
+                    &self.renderer_resources,
+                    &self.previous_window_state,
+                    &self.current_window_state,
+                    &mut ret_modified_window_state,
+                    gl_context,
+                    image_cache,
+                    system_fonts,
+                    &mut ret_timers,
+                    &mut ret_threads,
+                    &mut ret_timers_removed,
+                    &mut ret_threads_removed,
+                    current_window_handle,
+                    &mut ret.windows_created,
+                    system_callbacks,
+                    &mut stop_propagation,
+                    &mut new_focus_target,
+                    &mut ret_words_changed,
+                    &mut ret_images_changed,
+                    &mut ret_image_masks_changed,
+                    &mut ret_css_properties_changed,
+                    &current_scroll_states,
+                    &mut ret_nodes_scrolled_in_callbacks,
+                    hit_dom_node,
+                    cursor_relative_to_item,
+                    cursor_in_viewport,
+                );
+
+                let tcr = timer.invoke(
+                    callback_info,
+                    frame_start.clone(),
+                    system_callbacks.get_system_time_fn,
+                );
+
+                ret.callbacks_update_screen.max_self(tcr.should_update);
+                if tcr.should_terminate == TerminateTimer::Terminate {
+                    should_terminate = TerminateTimer::Terminate;
+                    break;
+                }
+            }
 
             if !ret_timers.is_empty() {
                 ret.timers = Some(ret_timers);
@@ -1357,6 +1737,7 @@ impl WindowInternal {
             threads_removed: None,
             windows_created: Vec::new(),
             cursor_changed: false,
+            key_down_handled: false,
         };
 
         let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
@@ -1395,7 +1776,15 @@ impl WindowInternal {
             let _ = thread.sender_send(ThreadSendMsg::Tick);
             let update = thread.receiver_try_recv();
             let msg = match update {
-                OptionThreadReceiveMsg::None => continue,
+                OptionThreadReceiveMsg::None => {
+                    // thread exited after its last message (i.e. a one-shot loader)
+                    if thread.is_finished() {
+                        ret.threads_removed
+                            .get_or_insert_with(|| BTreeSet::default())
+                            .insert(*thread_id);
+                    }
+                    continue;
+                }
                 OptionThreadReceiveMsg::Some(s) => s,
             };
 
@@ -1524,6 +1913,7 @@ impl WindowInternal {
             threads_removed: None,
             windows_created: Vec::new(),
             cursor_changed: false,
+            key_down_handled: false,
         };
 
         let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
@@ -1645,6 +2035,7 @@ impl WindowInternal {
             threads_removed: None,
             windows_created: Vec::new(),
             cursor_changed: false,
+            key_down_handled: false,
         };
 
         let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
@@ -1849,6 +2240,13 @@ pub struct WindowState {
     /// Sets location of IME candidate box in client area coordinates
     /// relative to the top left of the window.
     pub ime_position: ImePosition,
+    /// Caret and selection rectangles of the focused text input in client area
+    /// coordinates, used to position the IME composition window and reported
+    /// to screen readers. Set by the text input when the caret moves.
+    pub text_caret: TextCaret,
+    /// Accessibility preferences of the user (high contrast, reduced motion), set by the
+    /// operating system. On change, it will emit a `WindowEventFilter::ThemeChanged` event
+    pub accessibility: AccessibilitySettings,
     /// Which monitor the window is currently residing on
     pub monitor: Monitor,
     /// Window options that can only be set on a certain platform
@@ -1856,6 +2254,9 @@ pub struct WindowState {
     pub platform_specific_options: PlatformSpecificOptions,
     /// Whether this window has SRGB / vsync / hardware acceleration
     pub renderer_options: RendererOptions,
+    /// Whether the window renders on demand (default) or continuously, independent
+    /// of the `EventLoopMode` of the app and of the other windows
+    pub redraw_policy: RedrawPolicy,
     /// Color of the window background (can be transparent if necessary)
     pub background_color: ColorU,
     /// The `layout()` function for this window, stored as a callback function pointer,
@@ -1905,6 +2306,31 @@ impl Default for ImePosition {
     }
 }
 
+/// Accessibility preferences of the user, set by the operating system
+/// (changing them in a callback has no effect on the OS settings)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct AccessibilitySettings {
+    /// Whether the high contrast mode is enabled: apps should switch to a
+    /// stylesheet using the system colors (Windows: `SPI_GETHIGHCONTRAST`)
+    pub high_contrast: bool,
+    /// Whether the user turned off animations (Windows: `SPI_GETCLIENTAREAANIMATION`):
+    /// animations started via `CallbackInfo::start_animation` and smooth scrolling are
+    /// skipped, apps should also skip their own animations
+    pub prefers_reduced_motion: bool,
+}
+
+/// Caret and selection of the currently focused text input,
+/// in logical coordinates relative to the top left of the window
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextCaret {
+    /// Bounding rectangle of the caret, `None` if no text input is focused
+    pub caret_rect: OptionLogicalRect,
+    /// Bounding rectangles of the selected text (one per line)
+    pub selection_rects: LogicalRectVec,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullWindowState {
     /// Theme of this window (dark or light) - can be set / overridden by the user
@@ -1933,11 +2359,20 @@ pub struct FullWindowState {
     /// Sets location of IME candidate box in client area coordinates
     /// relative to the top left of the window.
     pub ime_position: ImePosition,
+    /// Caret and selection rectangles of the focused text input in client area
+    /// coordinates, used to position the IME composition window and reported
+    /// to screen readers. Set by the text input when the caret moves.
+    pub text_caret: TextCaret,
+    /// Accessibility preferences of the user (high contrast, reduced motion), set by the
+    /// operating system. On change, it will emit a `WindowEventFilter::ThemeChanged` event
+    pub accessibility: AccessibilitySettings,
     /// Window options that can only be set on a certain platform
     /// (`WindowsWindowOptions` / `LinuxWindowOptions` / `MacWindowOptions`).
     pub platform_specific_options: PlatformSpecificOptions,
     /// Information about vsync and hardware acceleration
     pub renderer_options: RendererOptions,
+    /// Whether the window renders on demand or continuously
+    pub redraw_policy: RedrawPolicy,
     /// Background color of the window
     pub background_color: ColorU,
     /// The `layout()` function for this window, stored as a callback function pointer,
@@ -1983,11 +2418,14 @@ impl Default for FullWindowState {
             mouse_state: MouseState::default(),
             touch_state: TouchState::default(),
             ime_position: ImePosition::Uninitialized,
+            text_caret: TextCaret::default(),
+            accessibility: AccessibilitySettings::default(),
             platform_specific_options: PlatformSpecificOptions::default(),
             background_color: ColorU::WHITE,
             layout_callback: LayoutCallback::default(),
             close_callback: OptionCallback::None,
             renderer_options: RendererOptions::default(),
+            redraw_policy: RedrawPolicy::default(),
             monitor: Monitor::default(),
             // --
             hovered_file: None,
@@ -2050,11 +2488,14 @@ impl FullWindowState {
             mouse_state: window_state.mouse_state,
             touch_state: window_state.touch_state,
             ime_position: window_state.ime_position.into(),
+            text_caret: window_state.text_caret.clone(),
+            accessibility: window_state.accessibility,
             platform_specific_options: window_state.platform_specific_options.clone(),
             background_color: window_state.background_color,
             layout_callback: window_state.layout_callback.clone(),
             close_callback: window_state.close_callback,
             renderer_options: window_state.renderer_options,
+            redraw_policy: window_state.redraw_policy,
             dropped_file,
             hovered_file,
             focused_node,
@@ -2083,11 +2524,14 @@ impl From<FullWindowState> for WindowState {
             mouse_state: full_window_state.mouse_state,
             touch_state: full_window_state.touch_state,
             ime_position: full_window_state.ime_position.into(),
+            text_caret: full_window_state.text_caret,
+            accessibility: full_window_state.accessibility,
             platform_specific_options: full_window_state.platform_specific_options,
             background_color: full_window_state.background_color,
             layout_callback: full_window_state.layout_callback,
             close_callback: full_window_state.close_callback,
             renderer_options: full_window_state.renderer_options,
+            redraw_policy: full_window_state.redraw_policy,
         }
     }
 }
@@ -2129,6 +2573,9 @@ pub struct CallCallbacksResult {
     pub windows_created: Vec<WindowCreateOptions>,
     /// Whether the cursor changed in the callbacks
     pub cursor_changed: bool,
+    /// Whether a `VirtualKeyDown` callback of the focused node handled the key
+    /// (returned an update, changed the focus or stopped the event propagation)
+    pub key_down_handled: bool,
 }
 
 impl CallCallbacksResult {
@@ -2166,6 +2613,16 @@ pub struct WindowFlags {
     pub smooth_scroll_enabled: bool,
     /// Is automatic TAB switching supported?
     pub autotab_enabled: bool,
+    /// Do Ctrl + "=" / Ctrl + "-" / Ctrl + "0" change the content scale of this window?
+    pub zoom_shortcuts_enabled: bool,
+    /// Does Ctrl + mouse wheel change the content scale of this window
+    /// (zooming around the cursor) instead of scrolling?
+    pub ctrl_wheel_zoom_enabled: bool,
+    /// Is the window shown in the taskbar and the Alt+Tab switcher? (default: true)
+    /// Useful for tool windows and tray-only apps. Changing it while the window is
+    /// visible briefly hides and re-shows the window (required by Windows for the
+    /// change to take effect)
+    pub is_shown_in_taskbar: bool,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -2191,6 +2648,9 @@ impl Default for WindowFlags {
             has_blur_behind_window: false,
             smooth_scroll_enabled: true,
             autotab_enabled: true,
+            zoom_shortcuts_enabled: true,
+            ctrl_wheel_zoom_enabled: true,
+            is_shown_in_taskbar: true,
         }
     }
 }
@@ -2222,6 +2682,40 @@ pub struct WindowsWindowOptions {
     pub taskbar_icon: OptionTaskBarIcon,
     /// STARTUP ONLY: Pointer (casted to void pointer) to a HWND handle
     pub parent_window: OptionHwndHandle,
+    /// READWRITE: If set (0.0 - 1.0), clicks on pixels whose rendered alpha is below
+    /// this threshold are not handled by the window (default: None = disabled)
+    pub alpha_hit_test_threshold: OptionF32,
+    /// READWRITE: Whether clicks on transparent pixels pass through to the window
+    /// below (`HTTRANSPARENT`) instead of being ignored (`HTNOWHERE`)
+    pub alpha_hit_test_pass_through: bool,
+    /// READWRITE: Corner style of the window on Windows 11 (ignored on earlier versions)
+    pub corner_preference: WindowCornerPreference,
+    /// STARTUP ONLY: AppUserModelID of this window (`PKEY_AppUserModel_ID`), overrides
+    /// `AppConfig::app_user_model_id` for taskbar grouping. Only has an effect if
+    /// set before the window is shown for the first time.
+    pub app_user_model_id: OptionAzString,
+    /// READWRITE: Whether minimizing / maximizing / restoring the window by changing
+    /// `WindowFlags::frame` in a callback plays the OS animation (default: true).
+    /// Set to `false` for instant, scripted state changes.
+    pub animate_frame_changes: bool,
+    /// STARTUP ONLY: Pointer (casted to void pointer) to an existing HGLRC that the
+    /// OpenGL context of the window shares textures / buffers with (i.e. the context
+    /// of a game engine that embeds azul). Window creation fails if the contexts are
+    /// incompatible (different pixel format / device).
+    pub shared_gl_context: OptionHwndHandle,
+    /// STARTUP ONLY: Creates the window as a `WS_CHILD` window inside the client area of
+    /// the `parent_window` instead of a top-level window owned by it (default: false).
+    /// Input coordinates are relative to the child window, clicking the child focuses it
+    /// and Tab moves the focus between the azul windows of the parent and its children.
+    pub embed_in_parent: bool,
+    /// READWRITE: Skip rendering while the window is fully covered by other (opaque)
+    /// top-level windows (default: false). Conservative: a window that is only partially
+    /// covered, or covered by transparent windows, always renders.
+    pub skip_rendering_when_occluded: bool,
+    /// READWRITE: Whether a window without decorations (`WindowFlags::has_decorations
+    /// = false`) still casts the native drop shadow (default: true). Set to `false`
+    /// for fully flat or overlay windows.
+    pub borderless_shadow: bool,
 }
 
 impl Default for WindowsWindowOptions {
@@ -2232,10 +2726,39 @@ impl Default for WindowsWindowOptions {
             window_icon: OptionWindowIcon::None,
             taskbar_icon: OptionTaskBarIcon::None,
             parent_window: OptionHwndHandle::None,
+            alpha_hit_test_threshold: OptionF32::None,
+            alpha_hit_test_pass_through: true,
+            corner_preference: WindowCornerPreference::Default,
+            app_user_model_id: OptionAzString::None,
+            animate_frame_changes: true,
+            shared_gl_context: OptionHwndHandle::None,
+            embed_in_parent: false,
+            skip_rendering_when_occluded: false,
+            borderless_shadow: true,
         }
     }
 }
 
+/// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(C)]
+pub enum WindowCornerPreference {
+    /// Let the system decide whether or not to round the corners
+    Default,
+    /// Never round the corners
+    DoNotRound,
+    /// Round the corners if appropriate
+    Round,
+    /// Round the corners with a small radius if appropriate
+    RoundSmall,
+}
+
+impl Default for WindowCornerPreference {
+    fn default() -> Self {
+        WindowCornerPreference::Default
+    }
+}
+
 /// Note: this should be a *mut HWND
 type HwndHandle = *mut c_void;
 
@@ -2545,10 +3068,20 @@ pub struct WindowSize {
     pub dimensions: LogicalSize,
     /// Actual DPI value (default: 96)
     pub dpi: u32,
+    /// Per-window content zoom (accessibility zoom, default: 1.0),
+    /// multiplied with the DPI factor when converting to physical pixels
+    pub content_scale: f32,
     /// Minimum dimensions of the window
     pub min_dimensions: OptionLogicalSize,
     /// Maximum dimensions of the window
     pub max_dimensions: OptionLogicalSize,
+    /// If set, the window is always laid out at this logical size and the
+    /// rendered result is scaled to fit the window, centered between
+    /// letterbox / pillarbox bars (default: None)
+    pub fixed_logical_size: OptionLogicalSize,
+    /// Scale factor and bar rects of the fixed logical size, updated by the
+    /// windowing system on every resize (empty if `fixed_logical_size` is None)
+    pub letterbox: Letterbox,
 }
 
 impl WindowSize {
@@ -2568,19 +3101,176 @@ impl WindowSize {
         self.dimensions.to_physical(self.get_hidpi_factor())
     }
 
+    /// Returns the scale factor between logical and physical pixels
+    /// (DPI factor multiplied with the content scale)
     pub fn get_hidpi_factor(&self) -> f32 {
+        self.get_dpi_factor() * self.content_scale
+    }
+
+    /// Returns the scale factor of the monitor DPI only, ignoring the content scale
+    pub fn get_dpi_factor(&self) -> f32 {
         self.dpi as f32 / 96.0
     }
+
+    /// Updates the dimensions after the window was resized to the given
+    /// physical size. If a `fixed_logical_size` is set, the dimensions stay
+    /// at the fixed size and the content scale is set to the letterbox scale.
+    pub fn resize_to_physical(&mut self, physical_size: PhysicalSize<u32>) {
+        match self.fixed_logical_size.into_option() {
+            Some(fixed_size) => {
+                // keep the previous scale while the window is minimized
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    return;
+                }
+                let window_size = physical_size.to_logical(self.get_dpi_factor());
+                self.letterbox = Letterbox::new(fixed_size, window_size);
+                self.content_scale = self.letterbox.scale;
+                self.dimensions = fixed_size;
+            }
+            None => {
+                self.letterbox = Letterbox::none();
+                self.dimensions = physical_size.to_logical(self.get_hidpi_factor());
+            }
+        }
+    }
+
+    /// Returns the physical offset of the rendered content
+    /// from the top left corner of the window
+    pub fn get_physical_content_offset(&self) -> PhysicalPosition<i32> {
+        let dpi_factor = self.get_dpi_factor();
+        PhysicalPosition::new(
+            libm::roundf(self.letterbox.content.origin.x * dpi_factor) as i32,
+            libm::roundf(self.letterbox.content.origin.y * dpi_factor) as i32,
+        )
+    }
+
+    /// Converts a physical position relative to the window
+    /// into a logical position relative to the content
+    pub fn physical_to_logical_position(&self, x: i32, y: i32) -> LogicalPosition {
+        let offset = self.get_physical_content_offset();
+        let hidpi_factor = self.get_hidpi_factor();
+        LogicalPosition::new(
+            (x - offset.x) as f32 / hidpi_factor,
+            (y - offset.y) as f32 / hidpi_factor,
+        )
+    }
+
+    /// Returns the next larger content scale step (Ctrl + "=")
+    pub fn get_zoomed_in_scale(&self) -> f32 {
+        CONTENT_SCALE_STEPS.iter().copied()
+        .find(|s| *s > self.content_scale + 0.001)
+        .unwrap_or(CONTENT_SCALE_STEPS[CONTENT_SCALE_STEPS.len() - 1])
+    }
+
+    /// Returns the next smaller content scale step (Ctrl + "-")
+    pub fn get_zoomed_out_scale(&self) -> f32 {
+        CONTENT_SCALE_STEPS.iter().rev().copied()
+        .find(|s| *s < self.content_scale - 0.001)
+        .unwrap_or(CONTENT_SCALE_STEPS[0])
+    }
 }
 
+/// Placement of a window with a fixed logical size inside the actual window:
+/// all rects are in logical units of the window (physical pixels / DPI factor)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Letterbox {
+    /// Scale factor from the fixed logical size to the window size
+    pub scale: f32,
+    /// Area of the window that the content is rendered into
+    pub content: LogicalRect,
+    /// Bar left of (pillarbox) or above (letterbox) the content, may be empty
+    pub leading_bar: LogicalRect,
+    /// Bar right of (pillarbox) or below (letterbox) the content, may be empty
+    pub trailing_bar: LogicalRect,
+}
+
+impl Letterbox {
+    /// Letterbox without any scaling or bars
+    pub const fn none() -> Self {
+        Self {
+            scale: 1.0,
+            content: LogicalRect::zero(),
+            leading_bar: LogicalRect::zero(),
+            trailing_bar: LogicalRect::zero(),
+        }
+    }
+
+    /// Fits the `fixed_size` into the `window_size`, preserving the aspect ratio
+    pub fn new(fixed_size: LogicalSize, window_size: LogicalSize) -> Self {
+        if fixed_size.width <= 0.0 || fixed_size.height <= 0.0 {
+            return Self::none();
+        }
+
+        let scale = (window_size.width / fixed_size.width)
+            .min(window_size.height / fixed_size.height)
+            .max(0.0);
+
+        let content_size = LogicalSize::new(fixed_size.width * scale, fixed_size.height * scale);
+        let gap_x = (window_size.width - content_size.width).max(0.0);
+        let gap_y = (window_size.height - content_size.height).max(0.0);
+        let content_origin =
+            LogicalPosition::new(libm::floorf(gap_x / 2.0), libm::floorf(gap_y / 2.0));
+
+        let (leading_bar, trailing_bar) = if gap_x > gap_y {
+            // pillarbox: bars to the left and right
+            let trailing_x = content_origin.x + content_size.width;
+            (
+                LogicalRect::new(
+                    LogicalPosition::zero(),
+                    LogicalSize::new(content_origin.x, window_size.height),
+                ),
+                LogicalRect::new(
+                    LogicalPosition::new(trailing_x, 0.0),
+                    LogicalSize::new(window_size.width - trailing_x, window_size.height),
+                ),
+            )
+        } else {
+            // letterbox: bars above and below
+            let trailing_y = content_origin.y + content_size.height;
+            (
+                LogicalRect::new(
+                    LogicalPosition::zero(),
+                    LogicalSize::new(window_size.width, content_origin.y),
+                ),
+                LogicalRect::new(
+                    LogicalPosition::new(0.0, trailing_y),
+                    LogicalSize::new(window_size.width, window_size.height - trailing_y),
+                ),
+            )
+        };
+
+        Self {
+            scale,
+            content: LogicalRect::new(content_origin, content_size),
+            leading_bar,
+            trailing_bar,
+        }
+    }
+}
+
+impl Default for Letterbox {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// Content scale steps used by the zoom keyboard shortcuts
+pub const CONTENT_SCALE_STEPS: [f32;13] = [
+    0.25, 0.33, 0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 2.0, 3.0,
+];
+
 impl Default for WindowSize {
     fn default() -> Self {
         Self {
             #[cfg(not(feature = "glow"))]
             dimensions: LogicalSize::new(640.0, 480.0),
             dpi: 96,
+            content_scale: 1.0,
             min_dimensions: None.into(),
             max_dimensions: None.into(),
+            fixed_logical_size: None.into(),
+            letterbox: Letterbox::none(),
         }
     }
 }
@@ -2609,6 +3299,89 @@ pub struct WindowCreateOptions {
     /// If set to true, will hot-reload the UI every 200ms, useful in combination with `StyledDom::from_file()`
     /// to hot-reload the UI from a file while developing.
     pub hot_reload: bool,
+    /// If set, the window will be centered on the work area of the monitor it is
+    /// created on when it is first shown (instead of using the OS default position).
+    /// Ignored if the window starts maximized, minimized or in fullscreen mode.
+    pub center_on_show: bool,
+    /// Requested OpenGL version / profile of the window (default: 3.2 core)
+    pub gl_context: GlContextOptions,
+    /// If set, the window stays hidden until the first frame has been rendered,
+    /// which avoids showing a blank window on startup
+    pub show_after_first_frame: bool,
+    /// Optional callback to run once the first frame of the window has been presented
+    pub first_frame_callback: OptionCallback,
+    /// Node to focus after the first layout (`:focus` styles are applied and the
+    /// `FocusReceived` callbacks of the node run, so text inputs position the caret)
+    pub initial_focus: OptionFocusTarget,
+}
+
+/// OpenGL context version, profile and flags requested for a window
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GlContextOptions {
+    /// Requested major version, if the context can't be created, lower
+    /// versions are tried (down to 3.2, which webrender requires)
+    pub major_version: u8,
+    /// Requested minor version
+    pub minor_version: u8,
+    /// Core or compatibility profile
+    pub profile: GlContextProfile,
+    /// Whether to create a debug context (GL errors / warnings are logged)
+    pub debug: bool,
+    /// Double- or single-buffered surface and how frames are presented (default: double-buffered)
+    pub buffering: GlBuffering,
+}
+
+impl Default for GlContextOptions {
+    fn default() -> Self {
+        Self {
+            major_version: 3,
+            minor_version: 2,
+            profile: GlContextProfile::Core,
+            debug: false,
+            buffering: GlBuffering::DoubleBuffered,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum GlContextProfile {
+    Core,
+    Compatibility,
+}
+
+/// Buffering of the OpenGL surface of a window
+///
+/// A double-buffered surface renders into a back buffer which is presented
+/// atomically (`SwapBuffers`): no partially drawn frames are visible, but a frame
+/// may wait for the next vsync / compositor cycle. A single-buffered surface
+/// renders directly into the visible buffer, which removes that step (useful for
+/// overlays or low-latency drawing), at the cost of possible tearing and
+/// flickering while the frame is drawn.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum GlBuffering {
+    /// Render into the back buffer, present with `SwapBuffers` (default)
+    DoubleBuffered,
+    /// Render into the front buffer, present with `glFlush` (returns
+    /// immediately, the GPU may still be drawing when the next frame starts)
+    SingleBufferedFlush,
+    /// Render into the front buffer, present with `glFinish` (blocks until
+    /// the GPU is done drawing the frame)
+    SingleBufferedFinish,
+}
+
+impl Default for GlBuffering {
+    fn default() -> Self {
+        GlBuffering::DoubleBuffered
+    }
+}
+
+impl GlBuffering {
+    pub fn is_double_buffered(&self) -> bool {
+        *self == GlBuffering::DoubleBuffered
+    }
 }
 
 impl Default for WindowCreateOptions {
@@ -2620,6 +3393,11 @@ impl Default for WindowCreateOptions {
             theme: OptionWindowTheme::None,
             create_callback: OptionCallback::None,
             hot_reload: false,
+            center_on_show: false,
+            gl_context: GlContextOptions::default(),
+            show_after_first_frame: false,
+            first_frame_callback: OptionCallback::None,
+            initial_focus: OptionFocusTarget::None,
         }
     }
 }
@@ -2722,6 +3500,22 @@ impl LogicalRect {
         self.origin.y
     }
 
+    /// Returns the overlapping area of the two rects, `None` if they don't overlap
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min_x = self.min_x().max(other.min_x());
+        let min_y = self.min_y().max(other.min_y());
+        let max_x = self.max_x().min(other.max_x());
+        let max_y = self.max_y().min(other.max_y());
+        if max_x <= min_x || max_y <= min_y {
+            return None;
+        }
+        Some(Self::new(
+            LogicalPosition::new(min_x, min_y),
+            LogicalSize::new(max_x - min_x, max_y - min_y),
+        ))
+    }
+
     /// Faster union for a Vec<LayoutRect>
     #[inline]
     pub fn union<I: Iterator<Item = Self>>(mut rects: I) -> Option<Self> {
@@ -2770,6 +3564,15 @@ impl LogicalRect {
         }
     }
 
+    /// Returns the distance from the position to the nearest point of the
+    /// rect (0.0 if the position is inside of the rect)
+    #[inline]
+    pub fn distance_to(&self, other: &LogicalPosition) -> f32 {
+        let dx = (self.min_x() - other.x).max(other.x - self.max_x()).max(0.0);
+        let dy = (self.min_y() - other.y).max(other.y - self.max_y()).max(0.0);
+        libm::sqrtf(dx * dx + dy * dy)
+    }
+
     pub fn to_layout_rect(&self) -> LayoutRect {
         LayoutRect {
             origin: LayoutPoint::new(
@@ -2784,6 +3587,12 @@ impl LogicalRect {
     }
 }
 
+impl_option!(
+    LogicalRect,
+    OptionLogicalRect,
+    [Debug, Copy, Clone, PartialEq, PartialOrd]
+);
+
 impl_vec!(LogicalRect, LogicalRectVec, LogicalRectVecDestructor);
 impl_vec_clone!(LogicalRect, LogicalRectVec, LogicalRectVecDestructor);
 impl_vec_debug!(LogicalRect, LogicalRectVec);
@@ -3622,3 +4431,86 @@ pub enum MenuItemState {
     /// Menu item is disabled, but NOT greyed out
     Disabled,
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn node(id: usize) -> DomNodeId {
+        DomNodeId {
+            dom: DomId { inner: 0 },
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+        }
+    }
+
+    #[test]
+    fn test_tab_order_explicit_before_auto() {
+        let tab_order = sort_tab_order(vec![
+            (TabIndex::Auto, node(0)),
+            (TabIndex::OverrideInParent(2), node(1)),
+            (TabIndex::OverrideInParent(0), node(2)),
+            (TabIndex::OverrideInParent(1), node(3)),
+            (TabIndex::Auto, node(4)),
+        ]);
+        assert_eq!(tab_order, vec![node(3), node(1), node(0), node(2), node(4)]);
+    }
+
+    #[test]
+    fn test_tab_order_ties_stay_in_dom_order() {
+        let tab_order = sort_tab_order(vec![
+            (TabIndex::OverrideInParent(1), node(5)),
+            (TabIndex::OverrideInParent(1), node(2)),
+            (TabIndex::OverrideInParent(1), node(7)),
+        ]);
+        assert_eq!(tab_order, vec![node(5), node(2), node(7)]);
+    }
+
+    #[test]
+    fn test_tab_order_skips_no_keyboard_focus() {
+        let tab_order = sort_tab_order(vec![
+            (TabIndex::NoKeyboardFocus, node(0)),
+            (TabIndex::Auto, node(1)),
+            (TabIndex::NoKeyboardFocus, node(2)),
+        ]);
+        assert_eq!(tab_order, vec![node(1)]);
+    }
+
+    #[test]
+    fn test_next_tab_index_without_focus() {
+        assert_eq!(get_next_tab_index(None, 3, false), 0);
+        assert_eq!(get_next_tab_index(None, 3, true), 2);
+    }
+
+    #[test]
+    fn test_next_tab_index_wraps_around() {
+        assert_eq!(get_next_tab_index(Some(0), 3, false), 1);
+        assert_eq!(get_next_tab_index(Some(2), 3, false), 0);
+        assert_eq!(get_next_tab_index(Some(1), 3, true), 0);
+        assert_eq!(get_next_tab_index(Some(0), 3, true), 2);
+        assert_eq!(get_next_tab_index(Some(0), 1, false), 0);
+        assert_eq!(get_next_tab_index(Some(0), 1, true), 0);
+    }
+
+    extern "C" fn on_focus(_: &mut RefAny, _: &mut crate::callbacks::CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    #[test]
+    fn test_focus_callback_makes_node_focusable() {
+        use crate::dom::{EventFilter, FocusEventFilter};
+
+        assert_eq!(get_focusable_tab_index(&NodeData::div()), None);
+
+        let mut node_data = NodeData::div();
+        node_data.add_callback(
+            EventFilter::Focus(FocusEventFilter::FocusReceived),
+            RefAny::new(()),
+            on_focus,
+        );
+        assert_eq!(get_focusable_tab_index(&node_data), Some(TabIndex::Auto));
+
+        node_data.set_tab_index(TabIndex::OverrideInParent(2));
+        assert_eq!(get_focusable_tab_index(&node_data), Some(TabIndex::OverrideInParent(2)));
+    }
+}
@@ -66,7 +66,7 @@
 use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{ImageCache, RendererResources},
-    callbacks::{DocumentId, DomNodeId, HitTestItem, ScrollPosition, Update},
+    callbacks::{DocumentId, DomNodeId, FocusTarget, HitTestItem, ScrollPosition, Update},
     dom::{EventFilter, FocusEventFilter, HoverEventFilter, NotEventFilter, WindowEventFilter},
     id_tree::NodeId,
     styled_dom::{ChangedCssProperty, DomId, NodeHierarchyItemId},
@@ -143,7 +143,8 @@ impl Events {
             .unwrap_or_default();
 
         if let Some(prev_state) = previous_window_state.as_ref() {
-            if prev_state.theme != current_window_state.theme {
+            if prev_state.theme != current_window_state.theme ||
+               prev_state.accessibility != current_window_state.accessibility {
                 current_window_events.push(WindowEventFilter::ThemeChanged);
             }
             if current_window_state.last_hit_test.hovered_nodes
@@ -154,6 +155,17 @@ impl Events {
             }
         }
 
+        // text input is only delivered to the currently focused node, hovered nodes
+        // should not receive characters (they still receive the VirtualKeyDown /
+        // VirtualKeyUp events for shortcuts). Window-level callbacks still
+        // receive all text input, i.e. for global input handling.
+        if current_window_events.contains(&WindowEventFilter::TextInput) {
+            current_hover_events.retain(|e| *e != HoverEventFilter::TextInput);
+            if current_window_state.focused_node.is_none() {
+                current_focus_events.retain(|e| *e != FocusEventFilter::TextInput);
+            }
+        }
+
         // even if there are no window events, the focus node can changed
         if current_window_state.focused_node != old_focus_node {
             current_focus_events.push(FocusEventFilter::FocusReceived);
@@ -1033,6 +1045,7 @@ impl CallbacksOfHitTest {
             threads_removed: None,
             windows_created: Vec::new(),
             cursor_changed: false,
+            key_down_handled: false,
         };
         let mut new_focus_target = None;
 
@@ -1156,6 +1169,10 @@ impl CallbacksOfHitTest {
 
                             ret.callbacks_update_screen.max_self(callback_return);
 
+                            if key_down_was_handled(event_filter, callback_return, &new_focus, stop_propagation) {
+                                ret.key_down_handled = true;
+                            }
+
                             if let Some(new_focus) = new_focus.clone() {
                                 new_focus_target = Some(new_focus);
                             }
@@ -1251,6 +1268,10 @@ impl CallbacksOfHitTest {
 
                         ret.callbacks_update_screen.max_self(callback_return);
 
+                        if key_down_was_handled(event_filter, callback_return, &new_focus, stop_propagation) {
+                            ret.key_down_handled = true;
+                        }
+
                         if let Some(new_focus) = new_focus.clone() {
                             new_focus_target = Some(new_focus);
                         }
@@ -1517,7 +1538,8 @@ fn get_window_events(
         }
     }
 
-    if current_window_state.theme != previous_window_state.theme {
+    if current_window_state.theme != previous_window_state.theme ||
+       current_window_state.accessibility != previous_window_state.accessibility {
         events.push(WindowEventFilter::ThemeChanged);
     }
 
@@ -1531,9 +1553,90 @@ fn get_hover_events(input: &[WindowEventFilter]) -> Vec<HoverEventFilter> {
         .collect()
 }
 
+// Whether a callback handled a key press of the focused node, in which case
+// the key should not trigger the default action (i.e. Tab navigation)
+fn key_down_was_handled(
+    event_filter: &EventFilter,
+    callback_return: Update,
+    new_focus: &Option<FocusTarget>,
+    stop_propagation: bool,
+) -> bool {
+    *event_filter == EventFilter::Focus(FocusEventFilter::VirtualKeyDown)
+        && (callback_return != Update::DoNothing || new_focus.is_some() || stop_propagation)
+}
+
 fn get_focus_events(input: &[HoverEventFilter]) -> Vec<FocusEventFilter> {
     input
         .iter()
         .filter_map(|hover_event| hover_event.to_focus_event_filter())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::id_tree::NodeId;
+
+    fn focused_node() -> DomNodeId {
+        DomNodeId {
+            dom: DomId { inner: 0 },
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+        }
+    }
+
+    // window state before and after typing `c` while `focused` is focused
+    fn type_char(c: char, focused: Option<DomNodeId>) -> Events {
+        let mut previous_window_state = FullWindowState::default();
+        previous_window_state.focused_node = focused;
+        let mut current_window_state = previous_window_state.clone();
+        current_window_state.keyboard_state.current_char = Some(c as u32).into();
+        Events::new(&current_window_state, &Some(previous_window_state))
+    }
+
+    #[test]
+    fn test_text_input_is_routed_to_focused_node() {
+        let events = type_char('a', Some(focused_node()));
+        assert!(events.focus_events.contains(&FocusEventFilter::TextInput));
+        assert!(!events.hover_events.contains(&HoverEventFilter::TextInput));
+    }
+
+    #[test]
+    fn test_text_input_without_focused_node_is_dropped() {
+        let events = type_char('a', None);
+        assert!(!events.focus_events.contains(&FocusEventFilter::TextInput));
+        assert!(!events.hover_events.contains(&HoverEventFilter::TextInput));
+    }
+
+    #[test]
+    fn test_window_text_input_is_always_delivered() {
+        let events = type_char('a', Some(focused_node()));
+        assert!(events.window_events.contains(&WindowEventFilter::TextInput));
+        let events = type_char('a', None);
+        assert!(events.window_events.contains(&WindowEventFilter::TextInput));
+    }
+
+    #[test]
+    fn test_unchanged_char_is_no_text_input() {
+        let mut previous_window_state = FullWindowState::default();
+        previous_window_state.focused_node = Some(focused_node());
+        previous_window_state.keyboard_state.current_char = Some('a' as u32).into();
+        let current_window_state = previous_window_state.clone();
+        let events = Events::new(&current_window_state, &Some(previous_window_state));
+        assert!(!events.focus_events.contains(&FocusEventFilter::TextInput));
+        assert!(!events.window_events.contains(&WindowEventFilter::TextInput));
+    }
+
+    #[test]
+    fn test_key_down_was_handled() {
+        let key_down = EventFilter::Focus(FocusEventFilter::VirtualKeyDown);
+        assert!(!key_down_was_handled(&key_down, Update::DoNothing, &None, false));
+        assert!(key_down_was_handled(&key_down, Update::RefreshDom, &None, false));
+        assert!(key_down_was_handled(&key_down, Update::DoNothing, &Some(FocusTarget::Next), false));
+        assert!(key_down_was_handled(&key_down, Update::DoNothing, &None, true));
+
+        // other events never handle the key press
+        let hover_key_down = EventFilter::Hover(HoverEventFilter::VirtualKeyDown);
+        assert!(!key_down_was_handled(&hover_key_down, Update::RefreshDom, &None, true));
+    }
+}
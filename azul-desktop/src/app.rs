@@ -60,11 +60,22 @@ impl AzAppPtr {
             .unwrap_or(MonitorVec::from_const_slice(&[]))
     }
 
-    pub fn run(&self, root_window: WindowCreateOptions) {
-        if let Ok(mut l) = self.ptr.try_lock() {
-            let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
-            core::mem::swap(&mut *l, &mut app);
-            app.run(root_window)
+    pub fn pump_events(&self) -> bool {
+        self.ptr
+            .try_lock()
+            .map(|mut l| l.pump_events())
+            .unwrap_or(false)
+    }
+
+    /// Returns the exit code of the application, 1 if the app is already running
+    pub fn run(&self, root_window: WindowCreateOptions) -> i32 {
+        match self.ptr.try_lock() {
+            Ok(mut l) => {
+                let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
+                core::mem::swap(&mut *l, &mut app);
+                app.run(root_window)
+            },
+            Err(_) => 1,
         }
     }
 }
@@ -90,6 +101,9 @@ pub struct App {
     /// Font configuration cache - already start building the font cache
     /// while the app is starting
     pub fc_cache: LazyFcCache,
+    /// Event loop of the opened windows, only set once `.pump_events()` has been called
+    #[cfg(target_os = "windows")]
+    pub(crate) event_loop: Option<crate::shell::win32::EventLoop>,
 }
 
 impl App {
@@ -132,6 +146,8 @@ impl App {
             config: app_config,
             image_cache: ImageCache::new(),
             fc_cache,
+            #[cfg(target_os = "windows")]
+            event_loop: None,
         }
     }
 
@@ -165,21 +181,82 @@ impl App {
     /// Start the rendering loop for the currently added windows. The run() function
     /// takes one `WindowCreateOptions` as an argument, which is the "root" window, i.e.
     /// the main application window.
+    ///
+    /// Returns the exit code of the application once the event loop has stopped
+    /// (see `AppExit::exit_code` on Windows), 1 if the application failed to start.
     #[cfg(all(not(test), feature = "std"))]
-    pub fn run(mut self, root_window: WindowCreateOptions) {
+    pub fn run(mut self, root_window: WindowCreateOptions) -> i32 {
 
         #[cfg(target_os = "windows")]
-        let err = crate::shell::win32::run(self, root_window);
+        let result = crate::shell::win32::run(self, root_window).and_then(|exit| match exit {
+            crate::shell::win32::AppExit::Error(e) => Err(e),
+            other => Ok(other.exit_code()),
+        });
 
         #[cfg(target_os = "linux")]
-        let err = crate::shell::x11::run(self, root_window);
+        let result = crate::shell::x11::run(self, root_window).map(|code| code as i32);
 
         #[cfg(target_os = "macos")]
-        let err = crate::shell::appkit::run(self, root_window);
+        let result = crate::shell::appkit::run(self, root_window).map(|code| code as i32);
+
+        match result {
+            Ok(exit_code) => exit_code,
+            Err(e) => {
+                crate::dialogs::msg_box(&format!("{:?}", e));
+                println!("{:?}", e);
+                1
+            }
+        }
+    }
+
+    /// Processes all pending events of the application without blocking and returns
+    /// whether any window is still open. This is an alternative to `run()` for embedding
+    /// the application into an existing event loop, which has to call `pump_events()`
+    /// repeatedly. The first call opens the windows that were added with `add_window()`,
+    /// the first added window being the root window.
+    ///
+    /// Currently only implemented on Windows, returns `false` on other platforms.
+    #[cfg(all(not(test), feature = "std"))]
+    pub fn pump_events(&mut self) -> bool {
+
+        #[cfg(target_os = "windows")] {
+            if self.event_loop.is_none() {
+                if self.windows.is_empty() {
+                    return false;
+                }
+
+                let mut windows = core::mem::take(&mut self.windows);
+                let root_window = windows.remove(0);
+                let app = App {
+                    data: self.data.clone(),
+                    config: self.config.clone(),
+                    windows,
+                    image_cache: core::mem::replace(&mut self.image_cache, ImageCache::new()),
+                    fc_cache: core::mem::replace(
+                        &mut self.fc_cache,
+                        LazyFcCache::Resolved(FcFontCache::default()),
+                    ),
+                    event_loop: None,
+                };
+
+                match crate::shell::win32::create_event_loop(app, root_window) {
+                    Ok(o) => self.event_loop = Some(o),
+                    Err(e) => {
+                        crate::dialogs::msg_box(&format!("{:?}", e));
+                        println!("{:?}", e);
+                        return false;
+                    }
+                }
+            }
+
+            self.event_loop
+                .as_mut()
+                .map(|e| e.pump_events())
+                .unwrap_or(false)
+        }
 
-        if let Err(e) = err {
-            crate::dialogs::msg_box(&format!("{:?}", e));
-            println!("{:?}", e);
+        #[cfg(not(target_os = "windows"))] {
+            false
         }
     }
 }
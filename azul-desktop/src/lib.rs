@@ -29,7 +29,7 @@ extern crate azul_core;
 #[cfg(feature = "css_parser")]
 extern crate azul_css_parser;
 #[cfg(feature = "logging")]
-#[cfg_attr(feature = "logging", macro_use(error, warn))]
+#[cfg_attr(feature = "logging", macro_use(error, warn, info))]
 extern crate log;
 #[cfg(all(feature = "use_fern_logger"))]
 extern crate fern;
@@ -44,6 +44,7 @@ extern crate azul_text_layout;
 extern crate azulc_lib;
 extern crate raw_window_handle;
 extern crate webrender;
+extern crate bincode;
 extern crate tinyfiledialogs;
 extern crate clipboard2;
 
@@ -79,10 +80,12 @@ pub mod ui_solver {
         PositionInfoInner,
         ResolvedTextLayoutOptions,
         OptionResolvedTextLayoutOptions,
+        TextConstraints,
     };
 }
 
 mod compositor;
+mod program_cache;
 #[cfg(feature = "logging")]
 mod logging;
 mod wr_translate;
@@ -99,6 +102,52 @@ pub mod text_layout {
     pub use azul_text_layout::text_layout::*;
     pub use azul_text_layout::text_shaping::*;
     pub use azul_text_layout::InlineText;
+
+    use azul_core::callbacks::{CallbackInfo, LayoutCallbackInfo};
+    use azul_core::ui_solver::TextConstraints;
+    use azul_css::{StyleFontFamily, StyleFontSize};
+    use rust_fontconfig::FcFontCache;
+
+    /// Measures a text without building a DOM, with the same font loading,
+    /// shaping and line breaking as the layout engine (see `azul_layout::measure_text`)
+    pub fn measure_text(
+        fc_cache: &FcFontCache,
+        font: &[StyleFontFamily],
+        font_size: StyleFontSize,
+        text: &str,
+        constraints: &TextConstraints,
+    ) -> Option<azul_core::callbacks::InlineText> {
+        azul_layout::measure_text(
+            fc_cache,
+            font,
+            font_size,
+            text,
+            constraints,
+            crate::app::CALLBACKS.load_font_fn,
+        )
+    }
+
+    /// `measure_text` with the font cache of the app, for use in layout callbacks
+    pub fn layout_callback_info_measure_text(
+        info: &LayoutCallbackInfo,
+        font: &[StyleFontFamily],
+        font_size: StyleFontSize,
+        text: &str,
+        constraints: &TextConstraints,
+    ) -> Option<azul_core::callbacks::InlineText> {
+        measure_text(info.get_fc_cache(), font, font_size, text, constraints)
+    }
+
+    /// `measure_text` with the font cache of the app, for use in callbacks
+    pub fn callback_info_measure_text(
+        info: &CallbackInfo,
+        font: &[StyleFontFamily],
+        font_size: StyleFontSize,
+        text: &str,
+        constraints: &TextConstraints,
+    ) -> Option<azul_core::callbacks::InlineText> {
+        measure_text(info.get_fc_cache(), font, font_size, text, constraints)
+    }
 }
 
 /// SVG parsing + rendering
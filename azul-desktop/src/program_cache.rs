@@ -0,0 +1,115 @@
+//! On-disk cache for the compiled webrender shader programs
+//!
+//! Compiling the webrender shaders is the most expensive part of creating a
+//! renderer (several hundred milliseconds on cold start). If
+//! `AppConfig::shader_cache_dir` is set, the linked program binaries are
+//! written to disk after the first frame and loaded again on the next startup.
+//!
+//! The cache directory is versioned by the webrender version and the
+//! GL vendor / renderer / version strings: a cache written by a different
+//! webrender release or a different driver is never loaded. Binaries that
+//! the driver rejects anyway are deleted from the cache.
+//!
+//! NOTE: webrender does not expose its glyph or texture caches for
+//! serialization, those are still rebuilt on every startup.
+
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::path::PathBuf;
+use webrender::{
+    ProgramBinary as WrProgramBinary,
+    ProgramCache as WrProgramCache,
+    ProgramCacheObserver as WrProgramCacheObserver,
+    ProgramSourceDigest as WrProgramSourceDigest,
+};
+
+/// Version of the webrender crate, caches written by other versions are discarded
+const WEBRENDER_VERSION: &str = "azul-webrender-0.62.2";
+
+/// Creates the program cache for a new renderer. If `cache_dir` is `Some`, the
+/// program binaries are loaded from / saved to a subdirectory of `cache_dir` that
+/// is specific to the current webrender version and GL driver.
+pub(crate) fn create_program_cache(
+    cache_dir: Option<&str>,
+    gl_vendor: &str,
+    gl_renderer: &str,
+    gl_version: &str,
+) -> Rc<WrProgramCache> {
+
+    let observer = cache_dir.and_then(|dir| {
+        let mut hasher = DefaultHasher::new();
+        gl_vendor.hash(&mut hasher);
+        gl_renderer.hash(&mut hasher);
+        gl_version.hash(&mut hasher);
+
+        let mut path = PathBuf::from(dir);
+        path.push(format!("{}-{:016x}", WEBRENDER_VERSION, hasher.finish()));
+
+        // directory not writable: run without disk cache
+        fs::create_dir_all(&path).ok()?;
+
+        let observer: Box<dyn WrProgramCacheObserver> = Box::new(DiskProgramCache { dir: path });
+        Some(observer)
+    });
+
+    WrProgramCache::new(observer)
+}
+
+/// Stores one `ProgramBinary` per file, named by the digest of the shader source
+struct DiskProgramCache {
+    dir: PathBuf,
+}
+
+impl DiskProgramCache {
+
+    fn program_path(&self, digest: &WrProgramSourceDigest) -> PathBuf {
+        self.dir.join(format!("{}.bin", digest))
+    }
+
+    fn write_program(&self, program: &WrProgramBinary) -> bool {
+        let bytes = match bincode::serialize(program) {
+            Ok(o) => o,
+            Err(_) => return false,
+        };
+        fs::write(self.program_path(program.source_digest()), bytes).is_ok()
+    }
+
+    fn read_program(&self, digest: &WrProgramSourceDigest) -> Option<WrProgramBinary> {
+        let bytes = fs::read(self.program_path(digest)).ok()?;
+        bincode::deserialize(&bytes).ok()
+    }
+}
+
+impl WrProgramCacheObserver for DiskProgramCache {
+
+    fn save_shaders_to_disk(&self, entries: Vec<Arc<WrProgramBinary>>) {
+        for entry in entries.iter() {
+            if !self.write_program(entry) {
+                #[cfg(feature = "logging")] {
+                    warn!("failed to write shader program to {}", self.dir.display());
+                }
+            }
+        }
+    }
+
+    fn set_startup_shaders(&self, entries: Vec<Arc<WrProgramBinary>>) {
+        // programs are loaded lazily by digest, no separate startup list needed
+        self.save_shaders_to_disk(entries);
+    }
+
+    fn try_load_shader_from_disk(&self, digest: &WrProgramSourceDigest, program_cache: &Rc<WrProgramCache>) {
+        // missing or corrupt file: webrender compiles the program from source
+        if let Some(program) = self.read_program(digest) {
+            program_cache.load_program_binary(Arc::new(program));
+        }
+    }
+
+    fn notify_program_binary_failed(&self, program_binary: &Arc<WrProgramBinary>) {
+        // driver rejected the binary (driver update): compile it again next time
+        let _ = fs::remove_file(self.program_path(program_binary.source_digest()));
+    }
+}
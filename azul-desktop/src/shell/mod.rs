@@ -3,4 +3,59 @@ pub mod win32;
 #[cfg(target_os = "linux")]
 pub mod x11;
 #[cfg(target_os = "macos")]
-pub mod appkit;
\ No newline at end of file
+pub mod appkit;
+
+use azul_core::callbacks::CallbackInfo;
+use azul_core::window::RawWindowHandle;
+
+/// Runs all due timers, re-layouts and renders one frame of the window that
+/// the callback was invoked on, bypassing the message loop timing. Since the
+/// window is busy with the callback, the frame is rendered once the current
+/// event has been processed. Does nothing on platforms other than Windows.
+pub fn callback_info_force_frame(info: &CallbackInfo) {
+    match info.get_current_window_handle() {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(w) => win32::force_frame(w.hwnd as _),
+        _ => { },
+    }
+}
+
+/// Moves the window of the callback onto the monitor with the given `Monitor::id`
+/// and keeps it there (see `win32::confine_to_monitor`). Returns `false` if the
+/// monitor does not exist or the platform doesn't support it.
+pub fn callback_info_confine_to_monitor(info: &CallbackInfo, monitor_id: usize) -> bool {
+    match info.get_current_window_handle() {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(w) => win32::confine_to_monitor(w.hwnd as _, monitor_id),
+        _ => false,
+    }
+}
+
+/// Allows the window of the callback to be moved freely again
+pub fn callback_info_release_from_monitor(info: &CallbackInfo) {
+    match info.get_current_window_handle() {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(w) => win32::release_from_monitor(w.hwnd as _),
+        _ => { },
+    }
+}
+
+/// Locks the aspect ratio (width / height) of the client area of the window of
+/// the callback (see `win32::lock_aspect_ratio`). Returns `false` if the ratio
+/// is not a positive number or the platform doesn't support it.
+pub fn callback_info_lock_aspect_ratio(info: &CallbackInfo, ratio: f32) -> bool {
+    match info.get_current_window_handle() {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(w) => win32::lock_aspect_ratio(w.hwnd as _, ratio),
+        _ => false,
+    }
+}
+
+/// Allows the window of the callback to be resized freely again
+pub fn callback_info_unlock_aspect_ratio(info: &CallbackInfo) {
+    match info.get_current_window_handle() {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Windows(w) => win32::unlock_aspect_ratio(w.hwnd as _),
+        _ => { },
+    }
+}
@@ -0,0 +1,378 @@
+//! Native file open / save dialogs (`IFileOpenDialog` / `IFileSaveDialog`)
+//! and message boxes (`MessageBoxW`)
+//!
+//! The dialogs are modal over the given parent window and run their own
+//! modal message loop on the UI thread, so the parent window keeps
+//! receiving WM_PAINT / WM_TIMER messages while the dialog is open.
+
+use alloc::vec::Vec;
+use azul_css::{AzString, OptionAzString, StringVec};
+use core::ptr;
+use azul_core::window::RawWindowHandle;
+use crate::dialogs::{FileTypeList, MsgBoxIcon};
+use winapi::{
+    shared::{
+        minwindef::DWORD,
+        ntdef::LPWSTR,
+        windef::HWND,
+        winerror::{SUCCEEDED, RPC_E_CHANGED_MODE},
+        wtypesbase::CLSCTX_INPROC_SERVER,
+    },
+    um::{
+        combaseapi::{CoCreateInstance, CoInitializeEx, CoTaskMemFree, CoUninitialize},
+        objbase::COINIT_APARTMENTTHREADED,
+        shobjidl::{
+            IFileDialog, IFileOpenDialog, IFileSaveDialog,
+            FOS_ALLOWMULTISELECT, FOS_FORCEFILESYSTEM,
+            FOS_OVERWRITEPROMPT, FOS_PICKFOLDERS,
+        },
+        shobjidl_core::{
+            CLSID_FileOpenDialog, CLSID_FileSaveDialog,
+            IShellItem, IShellItemArray,
+            SHCreateItemFromParsingName, SIGDN_FILESYSPATH,
+        },
+        shtypes::COMDLG_FILTERSPEC,
+    },
+    Interface,
+};
+
+use super::encode_wide;
+
+/// Options for the `open_file_dialog` and `save_file_dialog` functions
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct FileDialogOptions {
+    /// Title of the dialog window
+    pub title: AzString,
+    /// Directory that the dialog shows when it is opened
+    pub default_directory: OptionAzString,
+    /// Pre-filled file name (only used by the save dialog)
+    pub default_file_name: OptionAzString,
+    /// File type filters, i.e. `("Word documents", ["doc", "docx"])`
+    pub filters: Vec<FileTypeList>,
+    /// Allow selecting multiple files (only used by the open dialog)
+    pub multi_select: bool,
+    /// Select directories instead of files (only used by the open dialog)
+    pub pick_folders: bool,
+}
+
+impl Default for FileDialogOptions {
+    fn default() -> Self {
+        Self {
+            title: AzString::from_const_str(""),
+            default_directory: None.into(),
+            default_file_name: None.into(),
+            filters: Vec::new(),
+            multi_select: false,
+            pick_folders: false,
+        }
+    }
+}
+
+/// Shows a native "open file" dialog, modal over the `parent` window.
+///
+/// Returns `None` if the user canceled the dialog, otherwise the list of
+/// selected paths (one path if `multi_select` is not set).
+pub fn open_file_dialog(parent: HWND, options: &FileDialogOptions) -> Option<StringVec> {
+
+    let _com = ComInitialized::new()?;
+
+    let mut dialog: *mut IFileOpenDialog = ptr::null_mut();
+    let hr = unsafe {
+        CoCreateInstance(
+            &CLSID_FileOpenDialog,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &IFileOpenDialog::uuidof(),
+            &mut dialog as *mut *mut IFileOpenDialog as *mut _,
+        )
+    };
+
+    if !SUCCEEDED(hr) || dialog.is_null() {
+        return None;
+    }
+
+    let dialog = unsafe { &*dialog };
+
+    let mut flags = FOS_FORCEFILESYSTEM;
+    if options.multi_select {
+        flags |= FOS_ALLOWMULTISELECT;
+    }
+    if options.pick_folders {
+        flags |= FOS_PICKFOLDERS;
+    }
+
+    let result = unsafe {
+        configure_dialog(dialog, options, flags);
+
+        if !SUCCEEDED(dialog.Show(parent)) {
+            None
+        } else {
+            let mut items: *mut IShellItemArray = ptr::null_mut();
+            if !SUCCEEDED(dialog.GetResults(&mut items)) || items.is_null() {
+                None
+            } else {
+                let items = &*items;
+                let mut count: DWORD = 0;
+                items.GetCount(&mut count);
+
+                let mut paths = Vec::new();
+                for i in 0..count {
+                    let mut item: *mut IShellItem = ptr::null_mut();
+                    if SUCCEEDED(items.GetItemAt(i, &mut item)) && !item.is_null() {
+                        if let Some(path) = get_shell_item_path(&*item) {
+                            paths.push(path);
+                        }
+                        (*item).Release();
+                    }
+                }
+                items.Release();
+
+                if paths.is_empty() { None } else { Some(paths.into()) }
+            }
+        }
+    };
+
+    unsafe { dialog.Release(); }
+
+    result
+}
+
+/// Shows a native "save file" dialog, modal over the `parent` window.
+///
+/// Returns `None` if the user canceled the dialog, otherwise the path to save to.
+/// The user is asked for confirmation before overwriting an existing file.
+pub fn save_file_dialog(parent: HWND, options: &FileDialogOptions) -> Option<AzString> {
+
+    let _com = ComInitialized::new()?;
+
+    let mut dialog: *mut IFileSaveDialog = ptr::null_mut();
+    let hr = unsafe {
+        CoCreateInstance(
+            &CLSID_FileSaveDialog,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &IFileSaveDialog::uuidof(),
+            &mut dialog as *mut *mut IFileSaveDialog as *mut _,
+        )
+    };
+
+    if !SUCCEEDED(hr) || dialog.is_null() {
+        return None;
+    }
+
+    let dialog = unsafe { &*dialog };
+
+    let result = unsafe {
+        configure_dialog(dialog, options, FOS_FORCEFILESYSTEM | FOS_OVERWRITEPROMPT);
+
+        if let Some(file_name) = options.default_file_name.as_ref() {
+            let mut file_name = encode_wide(file_name.as_str());
+            dialog.SetFileName(file_name.as_mut_ptr());
+        }
+
+        if !SUCCEEDED(dialog.Show(parent)) {
+            None
+        } else {
+            let mut item: *mut IShellItem = ptr::null_mut();
+            if !SUCCEEDED(dialog.GetResult(&mut item)) || item.is_null() {
+                None
+            } else {
+                let path = get_shell_item_path(&*item);
+                (*item).Release();
+                path
+            }
+        }
+    };
+
+    unsafe { dialog.Release(); }
+
+    result
+}
+
+// Sets the title, options, filters and default directory (shared by the open + save dialog)
+unsafe fn configure_dialog(dialog: &IFileDialog, options: &FileDialogOptions, flags: DWORD) {
+
+    let mut existing_flags: DWORD = 0;
+    dialog.GetOptions(&mut existing_flags);
+    dialog.SetOptions(existing_flags | flags);
+
+    if !options.title.as_str().is_empty() {
+        let mut title = encode_wide(options.title.as_str());
+        dialog.SetTitle(title.as_mut_ptr());
+    }
+
+    // "Word documents" -> "*.doc;*.docx"
+    // the strings have to outlive the SetFileTypes call
+    let filter_strings = options.filters.iter().map(|f| {
+        let pattern = f.document_types
+            .as_ref()
+            .iter()
+            .map(|ext| format!("*.{}", ext.as_str().trim_start_matches('.')))
+            .collect::<Vec<_>>()
+            .join(";");
+        (encode_wide(f.document_descriptor.as_str()), encode_wide(&pattern))
+    }).collect::<Vec<_>>();
+
+    if !filter_strings.is_empty() {
+        let filter_specs = filter_strings.iter().map(|(name, spec)| COMDLG_FILTERSPEC {
+            pszName: name.as_ptr(),
+            pszSpec: spec.as_ptr(),
+        }).collect::<Vec<_>>();
+        dialog.SetFileTypes(filter_specs.len() as u32, filter_specs.as_ptr());
+        dialog.SetFileTypeIndex(1); // 1-based
+    }
+
+    if let Some(dir) = options.default_directory.as_ref() {
+        let mut dir = encode_wide(dir.as_str());
+        let mut folder: *mut IShellItem = ptr::null_mut();
+        let hr = SHCreateItemFromParsingName(
+            dir.as_mut_ptr(),
+            ptr::null_mut(),
+            &IShellItem::uuidof(),
+            &mut folder as *mut *mut IShellItem as *mut _,
+        );
+        if SUCCEEDED(hr) && !folder.is_null() {
+            dialog.SetFolder(folder);
+            (*folder).Release();
+        }
+    }
+}
+
+unsafe fn get_shell_item_path(item: &IShellItem) -> Option<AzString> {
+
+    let mut path: LPWSTR = ptr::null_mut();
+    if !SUCCEEDED(item.GetDisplayName(SIGDN_FILESYSPATH, &mut path)) || path.is_null() {
+        return None;
+    }
+
+    let mut len = 0;
+    while *path.add(len) != 0 {
+        len += 1;
+    }
+
+    let s = String::from_utf16_lossy(core::slice::from_raw_parts(path, len));
+    CoTaskMemFree(path as *mut _);
+
+    Some(s.into())
+}
+
+// Initializes COM for the current thread (if necessary), un-initializes it on drop
+pub(crate) struct ComInitialized {
+    should_uninitialize: bool,
+}
+
+impl ComInitialized {
+    pub(crate) fn new() -> Option<Self> {
+        let hr = unsafe { CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED) };
+        if SUCCEEDED(hr) {
+            // S_OK or S_FALSE (already initialized): both have to be balanced
+            Some(Self { should_uninitialize: true })
+        } else if hr == RPC_E_CHANGED_MODE {
+            // already initialized with a different threading model, still usable
+            Some(Self { should_uninitialize: false })
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for ComInitialized {
+    fn drop(&mut self) {
+        if self.should_uninitialize {
+            unsafe { CoUninitialize(); }
+        }
+    }
+}
+
+/// Buttons to show in a `message_box`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageBoxButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+    RetryCancel,
+    AbortRetryIgnore,
+}
+
+/// Button that the user pressed to close a `message_box`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageBoxResult {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+    Retry,
+    Abort,
+    Ignore,
+}
+
+/// Shows a native message box, modal over the `parent` window
+/// (use `CallbackInfo::get_current_window_handle()` inside of a callback).
+///
+/// Blocks until the user closes the message box. Closing the message box
+/// without pressing a button returns `Cancel` (or `Ok` if there is no cancel button).
+pub fn message_box(
+    parent: &RawWindowHandle,
+    title: &str,
+    text: &str,
+    buttons: MessageBoxButtons,
+    icon: MsgBoxIcon,
+) -> MessageBoxResult {
+
+    use winapi::um::winuser::{
+        MessageBoxW,
+        MB_OK, MB_OKCANCEL, MB_YESNO, MB_YESNOCANCEL,
+        MB_RETRYCANCEL, MB_ABORTRETRYIGNORE,
+        MB_ICONINFORMATION, MB_ICONWARNING, MB_ICONERROR, MB_ICONQUESTION,
+        IDOK, IDCANCEL, IDYES, IDNO, IDRETRY, IDABORT, IDIGNORE,
+    };
+
+    let parent_hwnd = match parent {
+        RawWindowHandle::Windows(h) => h.hwnd as HWND,
+        _ => ptr::null_mut(),
+    };
+
+    let button_flags = match buttons {
+        MessageBoxButtons::Ok => MB_OK,
+        MessageBoxButtons::OkCancel => MB_OKCANCEL,
+        MessageBoxButtons::YesNo => MB_YESNO,
+        MessageBoxButtons::YesNoCancel => MB_YESNOCANCEL,
+        MessageBoxButtons::RetryCancel => MB_RETRYCANCEL,
+        MessageBoxButtons::AbortRetryIgnore => MB_ABORTRETRYIGNORE,
+    };
+
+    let icon_flags = match icon {
+        MsgBoxIcon::Info => MB_ICONINFORMATION,
+        MsgBoxIcon::Warning => MB_ICONWARNING,
+        MsgBoxIcon::Error => MB_ICONERROR,
+        MsgBoxIcon::Question => MB_ICONQUESTION,
+    };
+
+    let mut title = encode_wide(title);
+    let mut text = encode_wide(text);
+
+    let result = unsafe {
+        MessageBoxW(
+            parent_hwnd,
+            text.as_mut_ptr(),
+            title.as_mut_ptr(),
+            button_flags | icon_flags,
+        )
+    };
+
+    match result {
+        IDOK => MessageBoxResult::Ok,
+        IDYES => MessageBoxResult::Yes,
+        IDNO => MessageBoxResult::No,
+        IDRETRY => MessageBoxResult::Retry,
+        IDABORT => MessageBoxResult::Abort,
+        IDIGNORE => MessageBoxResult::Ignore,
+        IDCANCEL => MessageBoxResult::Cancel,
+        // MessageBoxW failed (0)
+        _ => match buttons {
+            MessageBoxButtons::Ok => MessageBoxResult::Ok,
+            _ => MessageBoxResult::Cancel,
+        },
+    }
+}
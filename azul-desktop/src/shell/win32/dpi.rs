@@ -54,6 +54,7 @@ pub type AdjustWindowRectExForDpi = unsafe extern "system" fn(
     dwExStyle: u32,
     dpi: u32,
 ) -> BOOL;
+pub type GetSystemMetricsForDpi = unsafe extern "system" fn(nIndex: i32, dpi: u32) -> i32;
 
 #[derive(Default, Debug)]
 pub struct DpiFunctions {
@@ -65,6 +66,7 @@ pub struct DpiFunctions {
     set_process_dpi_awareness_context: Option<SetProcessDpiAwarenessContext>,
     set_process_dpi_awareness: Option<SetProcessDpiAwareness>,
     set_process_dpi_aware: Option<SetProcessDPIAware>,
+    get_system_metrics_for_dpi: Option<GetSystemMetricsForDpi>,
 }
 
 
@@ -94,6 +96,7 @@ impl DpiFunctions {
                 set_process_dpi_awareness_context: Self::get_func(user32_dll, "SetProcessDpiAwarenessContext").map(|e| unsafe { mem::transmute(e) }),
                 set_process_dpi_awareness: Self::get_func(user32_dll, "SetProcessDpiAwareness").map(|e| unsafe { mem::transmute(e) }),
                 set_process_dpi_aware: Self::get_func(user32_dll, "SetProcessDPIAware").map(|e| unsafe { mem::transmute(e) }),
+                get_system_metrics_for_dpi: Self::get_func(user32_dll, "GetSystemMetricsForDpi").map(|e| unsafe { mem::transmute(e) }),
             }
         }
     }
@@ -153,6 +156,18 @@ impl DpiFunctions {
         None
     }
 
+    pub fn get_system_metrics(&self, index: i32, dpi: u32) -> i32 {
+        unsafe {
+            if let Some(GetSystemMetricsForDpi) = self.get_system_metrics_for_dpi.clone() {
+                // We are on Windows 10 Anniversary Update (1607) or later.
+                GetSystemMetricsForDpi(index, dpi)
+            } else {
+                // Scaled to the system DPI, not the DPI of the window
+                winapi::um::winuser::GetSystemMetrics(index)
+            }
+        }
+    }
+
     pub unsafe fn hwnd_dpi(&self, hwnd: HWND) -> u32 {
         let hdc = GetDC(hwnd);
         if hdc.is_null() {
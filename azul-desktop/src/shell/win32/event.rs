@@ -307,6 +307,30 @@ pub fn get_pressed_keys() -> impl Iterator<Item = i32> {
         .map(|(i, _)| i as i32)
 }
 
+// Keyboard layout of the input thread, updated on WM_INPUTLANGCHANGE
+static ACTIVE_KEYBOARD_LAYOUT: AtomicPtr<HKL__> = AtomicPtr::new(ptr::null_mut());
+
+/// Updates the cached keyboard layout, should be called
+/// with the new HKL (lparam) when WM_INPUTLANGCHANGE is received
+pub fn set_keyboard_layout(hkl: HKL) {
+    ACTIVE_KEYBOARD_LAYOUT.store(hkl, Ordering::SeqCst);
+}
+
+/// Returns the currently active keyboard layout of the input thread
+pub fn get_keyboard_layout() -> HKL {
+    let hkl = ACTIVE_KEYBOARD_LAYOUT.load(Ordering::SeqCst);
+    if !hkl.is_null() {
+        return hkl;
+    }
+    let hkl = unsafe { winuser::GetKeyboardLayout(0) };
+    ACTIVE_KEYBOARD_LAYOUT.store(hkl, Ordering::SeqCst);
+    hkl
+}
+
+// ToUnicodeEx flag: do not change the keyboard state of the kernel
+// (Windows 10 1607+), otherwise probing a key would consume a pending dead key
+const TO_UNICODE_NO_KEYBOARD_STATE_CHANGE: UINT = 0x4;
+
 unsafe fn get_char(keyboard_state: &[u8; 256], v_key: u32, hkl: HKL) -> Option<char> {
     let mut unicode_bytes = [0u16; 5];
     let len = winuser::ToUnicodeEx(
@@ -315,7 +339,7 @@ unsafe fn get_char(keyboard_state: &[u8; 256], v_key: u32, hkl: HKL) -> Option<c
         keyboard_state.as_ptr(),
         unicode_bytes.as_mut_ptr(),
         unicode_bytes.len() as _,
-        0,
+        TO_UNICODE_NO_KEYBOARD_STATE_CHANGE,
         hkl,
     );
     if len >= 1 {
@@ -341,7 +365,7 @@ fn layout_uses_altgr() -> bool {
         static ACTIVE_LAYOUT: AtomicPtr<HKL__> = AtomicPtr::new(ptr::null_mut());
         static USES_ALTGR: AtomicBool = AtomicBool::new(false);
 
-        let hkl = winuser::GetKeyboardLayout(0);
+        let hkl = get_keyboard_layout();
         let old_hkl = ACTIVE_LAYOUT.swap(hkl, Ordering::SeqCst);
 
         if hkl == old_hkl {
@@ -613,9 +637,14 @@ pub fn process_key_params(
 // This is needed as windows doesn't properly distinguish
 // some virtual key codes for different keyboard layouts
 fn map_text_keys(win_virtual_key: i32) -> Option<VirtualKeyCode> {
-    let char_key =
-        unsafe { winuser::MapVirtualKeyA(win_virtual_key as u32, winuser::MAPVK_VK_TO_CHAR) }
-            & 0x7FFF;
+    // bit 31 is set for dead keys, mask it out to get the base character
+    let char_key = unsafe {
+        winuser::MapVirtualKeyExW(
+            win_virtual_key as u32,
+            winuser::MAPVK_VK_TO_CHAR,
+            get_keyboard_layout(),
+        )
+    } & 0x7FFF;
     match char::from_u32(char_key) {
         Some(';') => Some(VirtualKeyCode::Semicolon),
         Some('/') => Some(VirtualKeyCode::Slash),
@@ -627,3 +656,46 @@ fn map_text_keys(win_virtual_key: i32) -> Option<VirtualKeyCode> {
         _ => None,
     }
 }
+
+/// Decodes the UTF-16 code unit of a WM_CHAR message: characters outside of the BMP
+/// (i.e. emojis) arrive as two WM_CHAR messages, the high surrogate is buffered in
+/// `high_surrogate` until the low surrogate arrives. Lone surrogates are dropped.
+pub fn decode_wm_char(high_surrogate: &mut Option<u16>, unit: u16) -> Option<char> {
+    match unit {
+        0xD800..=0xDBFF => {
+            // a previous high surrogate without a low surrogate is dropped
+            *high_surrogate = Some(unit);
+            None
+        },
+        0xDC00..=0xDFFF => {
+            let high = high_surrogate.take()?;
+            char::decode_utf16([high, unit].iter().copied()).next()?.ok()
+        },
+        _ => {
+            *high_surrogate = None;
+            char::from_u32(unit as u32)
+        },
+    }
+}
+
+#[test]
+fn test_decode_wm_char_surrogate_pairs() {
+
+    fn decode(units: &[u16]) -> Vec<char> {
+        let mut high_surrogate = None;
+        units.iter().filter_map(|u| decode_wm_char(&mut high_surrogate, *u)).collect()
+    }
+
+    // "a😀b": U+1F600 = D83D DE00
+    assert_eq!(decode(&[0x61, 0xD83D, 0xDE00, 0x62]), vec!['a', '😀', 'b']);
+    // two consecutive emojis (U+1F44D U+1F3FD, thumbs up + skin tone)
+    assert_eq!(decode(&[0xD83D, 0xDC4D, 0xD83C, 0xDFFD]), vec!['👍', '🏽']);
+    // lone low surrogate
+    assert_eq!(decode(&[0xDE00, 0x61]), vec!['a']);
+    // lone high surrogate, followed by a BMP character
+    assert_eq!(decode(&[0xD83D, 0x61]), vec!['a']);
+    // high surrogate replaced by a second high surrogate
+    assert_eq!(decode(&[0xD83D, 0xD83D, 0xDE00]), vec!['😀']);
+    // a low surrogate does not combine with an already consumed high surrogate
+    assert_eq!(decode(&[0xD83D, 0xDE00, 0xDE00]), vec!['😀']);
+}
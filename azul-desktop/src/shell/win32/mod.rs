@@ -6,6 +6,8 @@
 
 mod event;
 mod dpi;
+pub mod dialogs;
+pub mod notification;
 
 use crate::{
     app::{App, LazyFcCache},
@@ -15,11 +17,18 @@ use crate::{
         synchronize_gpu_values,
         scroll_all_nodes,
         wr_synchronize_updated_images,
+        warm_up_fonts,
+        set_async_scene_building,
+        wait_for_scene_builder,
+        report_render_error,
+        take_render_errors,
         AsyncHitTester,
     }
 };
+
+pub use crate::wr_translate::RenderError;
 use alloc::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     rc::Rc,
     sync::Arc
 };
@@ -27,31 +36,37 @@ use azul_core::{
     FastBTreeSet, FastHashMap,
     app_resources::{
         ImageMask, ImageRef, Epoch,
-        AppConfig, ImageCache, ResourceUpdate,
+        AppConfig, ImageCache, ResourceUpdate, EventLoopMode, GpuPreference,
         RendererResources, GlTextureCache, DpiScaleFactor,
+        RawImage, RawImageData, RawImageFormat, GpuMemoryBudget, HitTestTolerance,
     },
     callbacks::{
         RefAny, UpdateImageType,
-        DomNodeId, DocumentId
+        DomNodeId, DocumentId, FocusTarget,
+        LayoutCallback, Callback,
     },
-    gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
-    ui_solver::LayoutResult,
+    gl::{OptionGlContextPtr, GlContextPtr},
+    task::{Thread, ThreadId, Timer, TimerId, OptionDuration, Duration as AzDuration},
+    ui_solver::{LayoutResult, PositionInfo},
     styled_dom::DomId,
-    dom::NodeId,
-    display_list::RenderCallbacks,
+    dom::{NodeId, TitleBarRegion},
+    display_list::{RenderCallbacks, RetainedDisplayLists, DisplayListStats},
     window::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult, DebugState,
+        OptionLogicalPosition, LogicalPosition,
+        FullHitTest, WindowCornerPreference,
+        TextCaret, LogicalRect,
+        GlContextOptions, GlContextProfile, GlBuffering, PresentMode, WindowFrame,
+        AccessibilitySettings, PhysicalPositionI32, PhysicalSize, RedrawPolicy,
     },
     window_state::NodesToCheck,
 };
 use core::{
     fmt,
-    convert::TryInto,
-    cell::{BorrowError, BorrowMutError, RefCell},
+    cell::{BorrowError, BorrowMutError, Cell, RefCell},
     ffi::c_void,
     mem, ptr,
     sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
@@ -69,24 +84,31 @@ use webrender::{
         ApiHitTester as WrApiHitTester, DocumentId as WrDocumentId,
         RenderNotifier as WrRenderNotifier,
     },
-    render_api::RenderApi as WrRenderApi,
+    render_api::{RenderApi as WrRenderApi, RenderApiSender as WrRenderApiSender},
     PipelineInfo as WrPipelineInfo, Renderer as WrRenderer, RendererError as WrRendererError,
     RendererOptions as WrRendererOptions, ShaderPrecacheFlags as WrShaderPrecacheFlags,
-    Shaders as WrShaders, Transaction as WrTransaction,
+    Shaders as WrShaders, Transaction as WrTransaction, ProgramCache as WrProgramCache,
 };
 use winapi::{
     shared::{
         minwindef::{BOOL, HINSTANCE, LPARAM, LRESULT, TRUE, UINT, WPARAM},
         ntdef::HRESULT,
-        windef::{HDC, HGLRC, HMENU, HWND, RECT, POINT},
+        windef::{HBITMAP, HCURSOR, HDC, HGLRC, HHOOK, HMENU, HWND, RECT, POINT},
     },
+    um::winuser::{MSG, WINDOWPLACEMENT, WINDOWPOS},
     ctypes::wchar_t,
     um::dwmapi::{DWM_BB_ENABLE, DWM_BLURBEHIND},
     um::uxtheme::MARGINS,
     um::winuser::WM_APP,
 };
 use self::dpi::DpiFunctions;
-use azul_css::FloatValue;
+use azul_css::{AzString, ColorU, FloatValue};
+use rust_fontconfig::FcFontCache;
+use once_cell::sync::Lazy;
+use std::{
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
 
 type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 
@@ -94,13 +116,85 @@ type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 const AZ_TICK_REGENERATE_DOM: usize = 1;
 // ID sent by WM_TIMER to check the thread results
 const AZ_THREAD_TICK: usize = 2;
+// ID sent by WM_TIMER to check the GPU memory usage against the AppConfig::gpu_memory_budget
+const AZ_GPU_MEMORY_TICK: usize = 3;
+// ID sent by WM_TIMER to re-layout the spinner overlay of a busy window
+const AZ_BUSY_TICK: usize = 4;
+// ID sent by WM_TIMER to check whether an occluded window became visible again
+const AZ_OCCLUSION_TICK: usize = 5;
+// ID sent by WM_TIMER to repaint a window that skipped a frame due to PresentMode::FrameCapped
+const AZ_FRAME_CAP_TICK: usize = 6;
 
 const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
 const AZ_REDO_HIT_TEST: u32 = WM_APP + 3;
 const AZ_GPU_SCROLL_RENDER: u32 = WM_APP + 4;
+// Synchronously runs due timers, relayouts and renders one frame
+// (wparam = 1: for all windows of the application)
+const AZ_FORCE_FRAME: u32 = WM_APP + 5;
+// Recreates the OpenGL context and the renderer after the context was lost
+// (wparam = AZ_RENDERER_FAILED: the hardware renderer crashed or lost its device)
+const AZ_RECREATE_RENDERER: u32 = WM_APP + 6;
+const AZ_RENDERER_FAILED: usize = 1;
+// SendMessageW(hwnd, AZ_ADD_OVERLAY_LAYER, 0, *mut LayoutCallback): adds an overlay document
+const AZ_ADD_OVERLAY_LAYER: u32 = WM_APP + 7;
+// SendMessageW(hwnd, AZ_REMOVE_OVERLAY_LAYER, index, 0): removes an overlay document
+const AZ_REMOVE_OVERLAY_LAYER: u32 = WM_APP + 8;
+// SendMessageW(hwnd, AZ_SET_WINDOW_REGION, 0, *const Option<WindowRegion>): sets the window region
+const AZ_SET_WINDOW_REGION: u32 = WM_APP + 9;
+// Posted after the first frame of a window has been presented, invokes the `first_frame_callback`
+const AZ_FIRST_FRAME_PRESENTED: u32 = WM_APP + 10;
+// SendMessageW(hwnd, AZ_CAPTURE_NODE, 0, *mut NodeCapture): renders and reads back one DOM node
+const AZ_CAPTURE_NODE: u32 = WM_APP + 11;
+// SendMessageW(hwnd, AZ_QUERY_PENDING_WORK, 0, *mut WindowPendingWork): fills in the pending work
+const AZ_QUERY_PENDING_WORK: u32 = WM_APP + 12;
+// SendMessageW(hwnd, AZ_QUERY_DISPLAY_LIST_STATS, 0, *mut DisplayListStats): statistics of the last display list
+const AZ_QUERY_DISPLAY_LIST_STATS: u32 = WM_APP + 13;
+// SendMessageW(hwnd, AZ_CAPTURE_WINDOW, 0, *mut Option<RawImage>): renders and reads back the client area
+const AZ_CAPTURE_WINDOW: u32 = WM_APP + 14;
+// SendMessageW(hwnd, AZ_DUMP_LAYOUT_TREE, 0, *mut String): human-readable dump of the current layout
+const AZ_DUMP_LAYOUT_TREE: u32 = WM_APP + 15;
+// SendMessageW(hwnd, AZ_QUERY_PRESENT_MODE, 0, *mut Option<PresentModeStatus>): requested / active present mode
+const AZ_QUERY_PRESENT_MODE: u32 = WM_APP + 16;
+// SendMessageW(hwnd, AZ_QUERY_GPU_MEMORY, 0, *mut GpuMemoryUsage): GPU memory used by the renderer
+const AZ_QUERY_GPU_MEMORY: u32 = WM_APP + 17;
+// Sent by the shell to windows registered as an appbar (wparam = ABN_*)
+const AZ_APPBAR_NOTIFY: u32 = WM_APP + 18;
+// SendMessageW(hwnd, AZ_QUERY_RENDERER_INFO, 0, *mut Option<RendererInfo>): hardware / software renderer info
+const AZ_QUERY_RENDERER_INFO: u32 = WM_APP + 19;
+// PostMessageW(hwnd, AZ_NOTIFICATION_ACTION, notification_id, action): toast action button clicked
+// (or the toast was dismissed / failed, see notification::NOTIFICATION_CLOSED)
+const AZ_NOTIFICATION_ACTION: u32 = WM_APP + 20;
+// Sent by the shell to the tray icon of a balloon tip notification (wparam = notification id, lparam = NIN_*)
+const AZ_NOTIFICATION_TRAY_ICON: u32 = WM_APP + 21;
+// Posted when a message had to be deferred because the application data was borrowed
+const AZ_REPLAY_DEFERRED_MESSAGES: u32 = WM_APP + 22;
+// SendMessageW(hwnd, AZ_SAVE_FRAME_CAPTURE, 0, *const PathBuf): saves a webrender capture of the window
+#[cfg(feature = "capture")]
+const AZ_SAVE_FRAME_CAPTURE: u32 = WM_APP + 23;
+// SendMessageW(hwnd, AZ_SET_CUSTOM_CURSOR, 0, *const Option<(RawImage, LogicalPosition)>): sets / clears the custom cursor
+const AZ_SET_CUSTOM_CURSOR: u32 = WM_APP + 24;
+// SendMessageW(hwnd, AZ_SET_BUSY, busy, *const Option<LayoutCallback>): disables / re-enables the window
+const AZ_SET_BUSY: u32 = WM_APP + 25;
+// PostMessageW(hwnd, AZ_VISIBILITY_CHANGED, 0, 0): the window was cloaked / uncloaked
+const AZ_VISIBILITY_CHANGED: u32 = WM_APP + 26;
+// SendMessageW(hwnd, AZ_QUERY_FONT_WARMUP, 0, *mut Option<Duration>): duration of the font warm-up
+const AZ_QUERY_FONT_WARMUP: u32 = WM_APP + 27;
+// PostMessageW(hwnd, AZ_TAB_FOCUS_ENTER, reverse, 0): Tab moved the keyboard focus into this
+// window from another embedded window, focuses the first (last if reverse) node in tab order
+const AZ_TAB_FOCUS_ENTER: u32 = WM_APP + 28;
+// SendMessageW(hwnd, AZ_FLUSH_PENDING, 0, 0): blocks until webrender has processed all
+// queued transactions of the window and the hit tester is up to date
+const AZ_FLUSH_PENDING: u32 = WM_APP + 29;
+// SendMessageW(hwnd, AZ_SET_CSS, 0, *const Css): replaces the stylesheet and restyles the current DOM
+#[cfg(feature = "css_parser")]
+const AZ_SET_CSS: u32 = WM_APP + 30;
+// SendMessageW(hwnd, AZ_GET_PIXEL, 0, *mut PixelQuery): reads back one pixel of the last frame
+const AZ_GET_PIXEL: u32 = WM_APP + 31;
+
+// GL_CONTEXT_LOST (GL 4.5 / KHR_robustness), returned by glGetError after a GPU reset
+const GL_CONTEXT_LOST: u32 = 0x0507;
 
-const CLASS_NAME: &str = "AzulApplicationClass";
 
 // TODO: Cache compiled shaders between renderers
 const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
@@ -120,437 +214,3631 @@ impl RectTrait for RECT {
 }
 
 pub fn get_monitors(app: &App) -> MonitorVec {
-    MonitorVec::from_const_slice(&[]) // TODO
+    enumerate_monitors()
 }
 
-/// Main function that starts when app.run() is invoked
-pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsStartupError> {
+// Returns all monitors (in physical pixels), in the order of EnumDisplayMonitors
+fn enumerate_monitors() -> MonitorVec {
 
+    use azul_core::window::{Monitor, VideoMode};
+    use azul_css::{LayoutPoint, LayoutSize, OptionAzString};
     use winapi::{
-        shared::minwindef::FALSE,
+        shared::{minwindef::DWORD, windef::{HDC, HMONITOR, LPRECT}},
         um::{
-            libloaderapi::GetModuleHandleW,
-            wingdi::{wglMakeCurrent, CreateSolidBrush},
-            winbase::{INFINITE, WAIT_FAILED},
+            wingdi::DEVMODEW,
             winuser::{
-                DispatchMessageW, GetDC, GetMessageW,
-                RegisterClassW, ReleaseDC, SetProcessDPIAware,
-                TranslateMessage, MsgWaitForMultipleObjects,
-                PeekMessageW, GetForegroundWindow,
-                CS_HREDRAW, CS_OWNDC, QS_ALLEVENTS,
-                CS_VREDRAW, MSG, WNDCLASSW, PM_NOREMOVE, PM_NOYIELD
-            }
+                EnumDisplayMonitors, EnumDisplaySettingsW, GetMonitorInfoW,
+                MONITORINFO, MONITORINFOEXW, MONITORINFOF_PRIMARY,
+            },
         },
     };
 
-    let hinstance = unsafe { GetModuleHandleW(ptr::null_mut()) };
-    if hinstance.is_null() {
-        return Err(WindowsStartupError::NoAppInstance(get_last_error()));
+    unsafe extern "system" fn monitor_enum_proc(hmonitor: HMONITOR, _: HDC, _: LPRECT, data: LPARAM) -> BOOL {
+        let monitors = &mut *(data as *mut Vec<HMONITOR>);
+        monitors.push(hmonitor);
+        TRUE
     }
 
-    // Tell windows that this process is DPI-aware
-    let dpi = self::dpi::DpiFunctions::init();
-    dpi.become_dpi_aware();
-
-    // Register the application class (shared between windows)
-    let mut class_name = encode_wide(CLASS_NAME);
-    let mut wc: WNDCLASSW = unsafe { mem::zeroed() };
-    wc.style = CS_HREDRAW | CS_VREDRAW | CS_OWNDC;
-    wc.hInstance = hinstance;
-    wc.lpszClassName = class_name.as_mut_ptr();
-    wc.lpfnWndProc = Some(WindowProc);
-    wc.hCursor = ptr::null_mut();
-    wc.hbrBackground = unsafe { CreateSolidBrush(0x00000000) }; // transparent black
-
-    // RegisterClass can fail if the same class is
-    // registered twice, error can be ignored
-    unsafe { RegisterClassW(&wc) };
-
-    let dwm = DwmFunctions::initialize();
-    let gl = GlFunctions::initialize();
-
-    let mut active_hwnds = Rc::new(RefCell::new(BTreeSet::new()));
-
-    {
-        let App {
-            data,
-            config,
-            windows,
-            image_cache,
-            fc_cache,
-        } = app;
+    let mut hmonitors = Vec::<HMONITOR>::new();
+    unsafe {
+        EnumDisplayMonitors(
+            ptr::null_mut(),
+            ptr::null(),
+            Some(monitor_enum_proc),
+            &mut hmonitors as *mut Vec<HMONITOR> as LPARAM,
+        );
+    }
 
-        let app_data_inner = Rc::new(RefCell::new(ApplicationData {
-            hinstance,
-            data,
-            config,
-            image_cache,
-            fc_cache,
-            windows: BTreeMap::new(),
-            active_hwnds: active_hwnds.clone(),
-            dwm,
-            dpi,
-        }));
+    let dpi = DpiFunctions::init();
 
-        let w = Window::create(
-            hinstance,
-            root_window,
-            SharedApplicationData { inner: app_data_inner.clone() }
-        )?;
+    let monitors = hmonitors.iter().enumerate().filter_map(|(id, hmonitor)| {
 
-        active_hwnds.try_borrow_mut()?.insert(w.hwnd);
-        app_data_inner
-            .try_borrow_mut()?
-            .windows
-            .insert(w.get_id(), w);
+        let mut monitor_info: MONITORINFOEXW = unsafe { mem::zeroed() };
+        monitor_info.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
+        if unsafe { GetMonitorInfoW(*hmonitor, &mut monitor_info as *mut MONITORINFOEXW as *mut MONITORINFO) } == 0 {
+            return None;
+        }
 
-        for opts in windows {
-            if let Ok(w) = Window::create(hinstance, opts, SharedApplicationData { inner: app_data_inner.clone() }) {
-                active_hwnds.try_borrow_mut()?.insert(w.hwnd);
-                app_data_inner
-                    .try_borrow_mut()?
-                    .windows
-                    .insert(w.get_id(), w);
+        let device_name_len = monitor_info.szDevice.iter().position(|c| *c == 0).unwrap_or(monitor_info.szDevice.len());
+        let device_name = String::from_utf16_lossy(&monitor_info.szDevice[..device_name_len]);
+
+        // all display modes supported by the monitor (deduplicated, in driver order)
+        let mut video_modes = Vec::<VideoMode>::new();
+        let mut mode_num: DWORD = 0;
+        loop {
+            let mut dev_mode: DEVMODEW = unsafe { mem::zeroed() };
+            dev_mode.dmSize = mem::size_of::<DEVMODEW>() as u16;
+            if unsafe { EnumDisplaySettingsW(monitor_info.szDevice.as_ptr(), mode_num, &mut dev_mode) } == 0 {
+                break;
+            }
+            let video_mode = VideoMode {
+                size: LayoutSize::new(dev_mode.dmPelsWidth as isize, dev_mode.dmPelsHeight as isize),
+                bit_depth: dev_mode.dmBitsPerPel as u16,
+                refresh_rate: dev_mode.dmDisplayFrequency as u16,
+            };
+            if !video_modes.contains(&video_mode) {
+                video_modes.push(video_mode);
             }
+            mode_num += 1;
         }
-    }
 
-    // Process the window messages one after another
-    //
-    // Multiple windows will process messages in sequence
-    // to avoid complicated multithreading logic
-    let mut msg: MSG = unsafe { mem::zeroed() };
-    let mut results = Vec::new();
-    let mut hwnds = Vec::new();
+        let rect = monitor_info.rcMonitor;
+        let monitor_dpi = dpi.get_monitor_dpi(*hmonitor).unwrap_or(96);
+
+        Some(Monitor {
+            id,
+            name: OptionAzString::Some(device_name.into()),
+            size: LayoutSize::new(rect.width() as isize, rect.height() as isize),
+            position: LayoutPoint::new(rect.left as isize, rect.top as isize),
+            scale_factor: self::dpi::dpi_to_scale_factor(monitor_dpi) as f64,
+            video_modes: video_modes.into(),
+            is_primary_monitor: monitor_info.dwFlags & MONITORINFOF_PRIMARY != 0,
+        })
+    }).collect::<Vec<_>>();
 
-    'main: loop {
+    monitors.into()
+}
 
-        match active_hwnds.try_borrow().ok() {
-            Some(windows_vec) => {
-                hwnds = windows_vec.clone().into_iter().collect();
-            },
-            None => break 'main, // borrow error
-        }
+/// Handler that is invoked with the new list of monitors when a monitor is
+/// connected / disconnected, the monitor arrangement or resolution changes or the
+/// scale factor (DPI) of a monitor changes - independent of which window received
+/// the notification, the handler is only invoked once per change
+pub type MonitorsChangedCallback = fn(monitors: MonitorVec);
 
-        // For single-window apps, GetMessageW will block until
-        // the next event comes in. For multi-window apps we have
-        // to use PeekMessage in order to not block in case that
-        // there are no messages for that window
+thread_local! {
+    static MONITORS_CHANGED_HANDLER: Cell<Option<MonitorsChangedCallback>> = Cell::new(None);
+    static LAST_KNOWN_MONITORS: RefCell<Option<MonitorVec>> = RefCell::new(None);
+}
 
-        let is_multiwindow = match hwnds.len() {
-            0 | 1 => false,
-            _ => true,
-        };
+/// Sets (or removes) the handler that is invoked when the monitor configuration changes
+pub fn set_monitors_changed_handler(callback: Option<MonitorsChangedCallback>) {
+    LAST_KNOWN_MONITORS.with(|m| *m.borrow_mut() = callback.map(|_| enumerate_monitors()));
+    MONITORS_CHANGED_HANDLER.with(|h| h.set(callback));
+}
 
-        if is_multiwindow {
+// Re-enumerates the monitors and invokes the MonitorsChangedCallback if they
+// differ from the last known configuration (WM_DISPLAYCHANGE / WM_DPICHANGED
+// are sent to every top-level window, so this is called once per window)
+fn check_monitors_changed() {
 
-            for hwnd in hwnds.iter() {
-                unsafe {
-                    let r = PeekMessageW(&mut msg, *hwnd, 0, 0, PM_NOREMOVE);
-
-                    if r > 0 {
-                        // new message available
-                        let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                        results.push(r);
-                    }
-                }
-            }
+    let handler = match MONITORS_CHANGED_HANDLER.with(|h| h.get()) {
+        Some(s) => s,
+        None => return,
+    };
 
-            // It would be great if there was a function like
-            // MsgWaitForMultipleObjects([hwnd]), so that you could
-            // wait on one of many input events
-            //
-            // The best workaround is to get the foreground window
-            // (that the user is interacting with) and then
-            // wait until some event happens to that foreground window
-            let mut dump_msg: MSG = unsafe { mem::zeroed() };
-            while !hwnds.iter().any(|hwnd| unsafe { PeekMessageW(&mut dump_msg, *hwnd, 0, 0, PM_NOREMOVE) > 0 }) {
-                // reduce CPU load for multi-window apps
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
+    let monitors = enumerate_monitors();
+    let changed = LAST_KNOWN_MONITORS.with(|m| {
+        let mut last_known = m.borrow_mut();
+        if last_known.as_ref() == Some(&monitors) {
+            false
         } else {
-            for hwnd in hwnds.iter() {
-                unsafe {
-                    let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                    if r > 0 {
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                    }
-                    results.push(r);
-                }
-            }
-        }
-
-        for r in results.iter() {
-            if !(*r > 0) {
-                break 'main; // error occured
-            }
-        }
-
-        if hwnds.is_empty() {
-            break 'main;
+            *last_known = Some(monitors.clone());
+            true
         }
+    });
 
-        hwnds.clear();
-        results.clear();
+    if changed {
+        (handler)(monitors);
     }
-
-    Ok(msg.wParam as isize)
 }
 
-fn encode_wide(input: &str) -> Vec<u16> {
-    input
-        .encode_utf16()
-        .chain(Some(0).into_iter())
-        .collect::<Vec<_>>()
+/// Synchronously runs all due timers, re-layouts and renders exactly one frame
+/// of the given window, bypassing the message loop timing. Returns once the
+/// frame has been presented. Intended for automated UI tests.
+///
+/// Must be called from the thread that runs the event loop.
+pub fn force_frame(hwnd: HWND) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_FORCE_FRAME, 0, 0); }
 }
 
-fn encode_ascii(input: &str) -> Vec<i8> {
-    input
-        .chars()
-        .filter(|c| c.is_ascii())
-        .map(|c| c as i8)
-        .chain(Some(0).into_iter())
-        .collect::<Vec<_>>()
+/// Same as `force_frame`, but renders one frame for every window of the
+/// application that `hwnd` belongs to
+pub fn force_frame_all_windows(hwnd: HWND) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_FORCE_FRAME, 1, 0); }
 }
 
-fn get_last_error() -> u32 {
-    use winapi::um::errhandlingapi::GetLastError;
-    (unsafe { GetLastError() }) as u32
+/// Layout / paint work that is currently pending for a window, see `get_pending_work`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WindowPendingWork {
+    /// The DOM will be regenerated and laid out again
+    pub needs_relayout: bool,
+    /// A new frame will be rendered
+    pub needs_redraw: bool,
+    /// Reason: input events that changed the window state haven't been processed yet
+    pub state_changed: bool,
+    /// Reason: the set of hovered nodes changed since the last processed event
+    pub hover_changed: bool,
+    /// Reason: timers or threads are running, which can request new frames at any time
+    pub animation: bool,
+    /// Reason: a scroll position changed and the scrolled frame hasn't been rendered yet
+    pub scroll: bool,
 }
 
-pub fn load_dll(name: &'static str) -> Option<HINSTANCE> {
-    use winapi::um::libloaderapi::LoadLibraryW;
-    let mut dll_name = encode_wide(name);
-    let dll = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
-    if dll.is_null() {
-        None
-    } else {
-        Some(dll)
-    }
+/// Reports whether the window currently has pending layout or paint work (and why).
+/// This is a snapshot: new work may be queued right after this function returns.
+///
+/// Returns `None` if the window does not exist.
+pub fn get_pending_work(hwnd: HWND) -> Option<WindowPendingWork> {
+    use winapi::um::winuser::SendMessageW;
+    let mut pending_work = WindowPendingWork::default();
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_QUERY_PENDING_WORK, 0, &mut pending_work as *mut WindowPendingWork as LPARAM)
+    };
+    if result == 0 { Some(pending_work) } else { None }
 }
 
-#[derive(Debug)]
-pub enum WindowsWindowCreateError {
-    FailedToCreateHWND(u32),
-    NoHDC,
-    NoGlContext,
-    Extra(ExtraWglFunctionsLoadError),
-    Renderer(WrRendererError),
-    BorrowMut(BorrowMutError),
+/// Returns how many display list nodes were rebuilt / reused (unchanged iframes)
+/// in the last display list rebuild of the window and how long building the scene
+/// took (see `AppConfig::async_scene_building`), `None` if the window does not exist
+pub fn get_display_list_stats(hwnd: HWND) -> Option<DisplayListStats> {
+    use winapi::um::winuser::SendMessageW;
+    let mut stats = DisplayListStats::default();
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_QUERY_DISPLAY_LIST_STATS, 0, &mut stats as *mut DisplayListStats as LPARAM)
+    };
+    if result == 0 { Some(stats) } else { None }
 }
 
-impl From<ExtraWglFunctionsLoadError> for WindowsWindowCreateError {
-    fn from(e: ExtraWglFunctionsLoadError) -> Self {
-        WindowsWindowCreateError::Extra(e)
-    }
+/// Returns a human-readable dump of the current layout tree of the window
+/// (node ids, computed rects, display / position, margins, z-order), for debugging.
+/// In debug builds, the same dump is logged when pressing Ctrl + Shift + F12.
+///
+/// Returns `None` if the window does not exist.
+pub fn dump_layout_tree(hwnd: HWND) -> Option<String> {
+    use winapi::um::winuser::SendMessageW;
+    let mut dump = String::new();
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_DUMP_LAYOUT_TREE, 0, &mut dump as *mut String as LPARAM)
+    };
+    if result == 0 { Some(dump) } else { None }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub enum WindowsOpenGlError {
-    OpenGL32DllNotFound(u32),
-    FailedToGetDC(u32),
-    FailedToCreateHiddenHWND(u32),
-    FailedToGetPixelFormat(u32),
-    NoMatchingPixelFormat(u32),
-    OpenGLNotAvailable(u32),
-    FailedToStoreContext(u32),
+/// Saves a webrender capture of the current frame of the window (scene, frame,
+/// resource cache and external images) to `directory`, which can be attached
+/// to bug reports and replayed with wrench. In debug builds, a capture is saved
+/// to the temporary directory when pressing Ctrl + Shift + F11.
+///
+/// The capture is written asynchronously by the render backend. Returns `false`
+/// if the window does not exist. Requires the `capture` feature.
+#[cfg(feature = "capture")]
+pub fn save_frame_capture(hwnd: HWND, directory: &str) -> bool {
+    use winapi::um::winuser::SendMessageW;
+    let path = std::path::PathBuf::from(directory);
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_SAVE_FRAME_CAPTURE, 0, &path as *const std::path::PathBuf as LPARAM)
+    };
+    result == 0
 }
 
-#[derive(Debug)]
-pub enum WindowsStartupError {
-    NoAppInstance(u32),
-    WindowCreationFailed,
-    Borrow(BorrowError),
-    BorrowMut(BorrowMutError),
-    Create(WindowsWindowCreateError),
-    Gl(WindowsOpenGlError),
+/// Present mode of a window: the mode requested in the
+/// `RendererOptions` and the mode that is actually active
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PresentModeStatus {
+    pub requested: PresentMode,
+    /// Differs from `requested` if the driver doesn't support
+    /// setting the swap interval (the driver default is used)
+    pub active: PresentMode,
 }
 
-impl From<BorrowError> for WindowsStartupError {
-    fn from(e: BorrowError) -> Self {
-        WindowsStartupError::Borrow(e)
+impl PresentModeStatus {
+    pub fn is_supported(&self) -> bool {
+        self.requested == self.active
     }
 }
-impl From<BorrowMutError> for WindowsStartupError {
-    fn from(e: BorrowMutError) -> Self {
-        WindowsStartupError::BorrowMut(e)
+
+/// Returns the requested and active present mode of the window. Changes to the
+/// present mode are applied on the next frame, so this returns `None` if the window
+/// doesn't exist, uses the software renderer or hasn't presented a frame yet.
+pub fn get_present_mode(hwnd: HWND) -> Option<PresentModeStatus> {
+    use winapi::um::winuser::SendMessageW;
+    let mut status = None;
+    unsafe {
+        SendMessageW(hwnd, AZ_QUERY_PRESENT_MODE, 0, &mut status as *mut Option<PresentModeStatus> as LPARAM);
     }
+    status
 }
-impl From<WindowsWindowCreateError> for WindowsStartupError {
-    fn from(e: WindowsWindowCreateError) -> Self {
-        WindowsStartupError::Create(e)
-    }
+
+/// GPU memory used by the renderer of a window, in bytes
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct GpuMemoryUsage {
+    /// Texture cache (images, glyphs, cached render tasks)
+    pub texture_cache_bytes: usize,
+    /// Intermediate render targets and depth buffers
+    pub render_target_bytes: usize,
+    /// GPU cache and vertex data textures
+    pub gpu_cache_bytes: usize,
+    /// Uncompressed size of the images that azul registered in the renderer
+    /// (already included in `texture_cache_bytes` once the images are uploaded)
+    pub registered_image_bytes: usize,
+    /// Sum of the texture cache, render target and GPU cache memory -
+    /// this is the value that is compared against the `AppConfig::gpu_memory_budget`
+    pub total_bytes: usize,
 }
-impl From<WindowsOpenGlError> for WindowsStartupError {
-    fn from(e: WindowsOpenGlError) -> Self {
-        WindowsStartupError::Gl(e)
-    }
+
+/// Returns the GPU memory used by the renderer of the window,
+/// `None` if the window does not exist
+pub fn get_gpu_memory_usage(hwnd: HWND) -> Option<GpuMemoryUsage> {
+    use winapi::um::winuser::SendMessageW;
+    let mut usage = GpuMemoryUsage::default();
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_QUERY_GPU_MEMORY, 0, &mut usage as *mut GpuMemoryUsage as LPARAM)
+    };
+    if result == 0 { Some(usage) } else { None }
 }
 
-struct Notifier {}
+/// Which renderer a window actually uses - gathered right after the OpenGL
+/// context has been created (and again if the renderer had to be recreated)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RendererInfo {
+    /// `false` if the window fell back to software rendering (no OpenGL context
+    /// or the Microsoft "GDI Generic" software OpenGL 1.1 implementation)
+    pub hardware_accelerated: bool,
+    /// `GL_VENDOR` string of the driver ("software" if there is no OpenGL context)
+    pub vendor: AzString,
+    /// `GL_RENDERER` string of the driver ("software" if there is no OpenGL context)
+    pub renderer: AzString,
+    /// `GL_VERSION` string of the driver (empty if there is no OpenGL context)
+    pub version: AzString,
+}
 
-impl WrRenderNotifier for Notifier {
-    fn clone(&self) -> Box<dyn WrRenderNotifier> {
-        Box::new(Notifier {})
+impl RendererInfo {
+    fn software() -> Self {
+        Self {
+            hardware_accelerated: false,
+            vendor: "software".into(),
+            renderer: "software".into(),
+            version: AzString::from_const_str(""),
+        }
     }
-    fn wake_up(&self, composite_needed: bool) {}
-    fn new_frame_ready(
-        &self,
-        _: WrDocumentId,
-        _scrolled: bool,
-        composite_needed: bool,
-        _render_time: Option<u64>,
-    ) {
+
+    // OpenGL context has to be current
+    fn from_gl_context(gl_context: &GlContextPtr) -> Self {
+        let vendor = gl_context.get_string(gl_context_loader::gl::VENDOR);
+        let renderer = gl_context.get_string(gl_context_loader::gl::RENDERER);
+        let version = gl_context.get_string(gl_context_loader::gl::VERSION);
+        // opengl32.dll without an ICD from the GPU vendor
+        let is_software_driver = renderer.as_str() == "GDI Generic";
+        Self {
+            hardware_accelerated: !is_software_driver,
+            vendor,
+            renderer,
+            version,
+        }
     }
 }
 
-#[derive(Debug, Clone)]
-struct SharedApplicationData {
-    inner: Rc<RefCell<ApplicationData>>,
+/// OpenGL capabilities of the GPU / driver, see `probe_gl_capabilities`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlCapabilities {
+    /// Driver and renderer strings, same as for a window (see `RendererInfo`)
+    pub renderer_info: RendererInfo,
+    /// Highest OpenGL version for which a core profile context could be created
+    pub major_version: u8,
+    /// Highest OpenGL version for which a core profile context could be created
+    pub minor_version: u8,
+    /// `GL_MAX_SAMPLES`: highest supported MSAA sample count (0 = no multisampling)
+    pub max_samples: u32,
+    /// `GL_MAX_TEXTURE_SIZE`: maximum width / height of a texture in pixels
+    pub max_texture_size: u32,
 }
 
-// ApplicationData struct that is shared across windows
-#[derive(Debug)]
-struct ApplicationData {
-    hinstance: HINSTANCE,
-    data: RefAny,
-    config: AppConfig,
-    image_cache: ImageCache,
-    fc_cache: LazyFcCache,
-    windows: BTreeMap<usize, Window>,
-    // active HWNDS, tracked separately from the ApplicationData
-    active_hwnds: Rc<RefCell<BTreeSet<HWND>>>,
-    dwm: Option<DwmFunctions>,
-    dpi: DpiFunctions,
-}
+/// Pre-flight check of the OpenGL driver, to be called before the event loop is
+/// created: creates a hidden window with an OpenGL context of the highest available
+/// version, queries its capabilities and destroys the window and context again.
+///
+/// Apps can use the result to configure the `GlContextOptions` of their windows
+/// (i.e. only request a version or MSAA sample count the driver supports) instead
+/// of creating a window with settings the GPU can't provide. The `gpu_preference`
+/// should be the same as the `AppConfig::gpu_preference` the app is started with,
+/// otherwise a different GPU may be probed on hybrid graphics systems.
+pub fn probe_gl_capabilities(gpu_preference: GpuPreference) -> Result<GlCapabilities, WindowsOpenGlError> {
 
-// Extra functions from dwmapi.dll
-struct DwmFunctions {
-    _dwmapi_dll_handle: HINSTANCE,
-    DwmEnableBlurBehindWindow: Option<extern "system" fn(HWND, &DWM_BLURBEHIND) -> HRESULT>,
-    DwmExtendFrameIntoClientArea: Option<extern "system" fn(HWND, &MARGINS) -> HRESULT>,
-    DwmDefWindowProc: Option<extern "system" fn(HWND, u32, WPARAM, LPARAM, *mut LRESULT)>,
-}
+    use winapi::um::{
+        libloaderapi::GetModuleHandleW,
+        winuser::{CreateWindowExW, DestroyWindow, CW_USEDEFAULT},
+    };
+    use self::WindowsOpenGlError::*;
 
-impl fmt::Debug for DwmFunctions {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        (self._dwmapi_dll_handle as usize).fmt(f)?;
-        (self.DwmEnableBlurBehindWindow.map(|f| f as usize)).fmt(f)?;
-        (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
-        (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
-        Ok(())
+    // has to happen before the first OpenGL context is created
+    set_gpu_preference(gpu_preference);
+
+    // the application window class is not registered yet: use the system "STATIC" class
+    let extra = ExtraWglFunctions::load("STATIC")
+        .map_err(|_| OpenGLNotAvailable(get_last_error()))?;
+
+    let hinstance = unsafe { GetModuleHandleW(ptr::null_mut()) };
+    let mut class_name = encode_wide("STATIC");
+    let mut window_title = encode_wide("OpenGL Probe Window");
+
+    let hwnd = unsafe {
+        CreateWindowExW(
+            0,
+            class_name.as_mut_ptr(),
+            window_title.as_mut_ptr(),
+            0,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            hinstance,
+            ptr::null_mut(),
+        )
+    };
+
+    if hwnd.is_null() {
+        return Err(FailedToCreateHiddenHWND(get_last_error()));
     }
+
+    let capabilities = query_gl_capabilities(hwnd, hinstance, &extra);
+
+    unsafe { DestroyWindow(hwnd); }
+
+    capabilities
 }
 
-impl DwmFunctions {
-    fn initialize() -> Option<Self> {
-        use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW};
+// Creates an OpenGL context of the highest available version on the (hidden)
+// window, queries the capabilities and deletes the context again
+fn query_gl_capabilities(
+    hwnd: HWND,
+    hinstance: HINSTANCE,
+    extra: &ExtraWglFunctions,
+) -> Result<GlCapabilities, WindowsOpenGlError> {
 
-        let mut dll_name = encode_wide("dwmapi.dll");
-        let hDwmAPI_DLL = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
-        if hDwmAPI_DLL.is_null() {
-            return None; // dwmapi.dll not found
-        }
+    use winapi::um::{
+        wingdi::{wglDeleteContext, wglMakeCurrent},
+        winuser::{GetDC, ReleaseDC},
+    };
+    use self::WindowsOpenGlError::*;
 
-        let mut func_name = encode_ascii("DwmEnableBlurBehindWindow");
-        let DwmEnableBlurBehindWindow =
-            unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
-        let DwmEnableBlurBehindWindow = if DwmEnableBlurBehindWindow != ptr::null_mut() {
-            Some(unsafe { mem::transmute(DwmEnableBlurBehindWindow) })
-        } else {
-            None
-        };
+    let (major_version, minor_version) = GL_FALLBACK_VERSIONS[0];
+    let options = GlContextOptions {
+        major_version,
+        minor_version,
+        .. GlContextOptions::default()
+    };
 
-        let mut func_name = encode_ascii("DwmExtendFrameIntoClientArea");
-        let DwmExtendFrameIntoClientArea =
-            unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
-        let DwmExtendFrameIntoClientArea = if DwmExtendFrameIntoClientArea != ptr::null_mut() {
-            Some(unsafe { mem::transmute(DwmExtendFrameIntoClientArea) })
-        } else {
-            None
-        };
+    let (context, obtained) = create_gl_context(hwnd, hinstance, extra, &options, ptr::null_mut())?;
 
-        let mut func_name = encode_ascii("DwmDefWindowProc");
-        let DwmDefWindowProc = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
-        let DwmDefWindowProc = if DwmDefWindowProc != ptr::null_mut() {
-            Some(unsafe { mem::transmute(DwmDefWindowProc) })
+    let hdc = unsafe { GetDC(hwnd) };
+    if hdc.is_null() || unsafe { wglMakeCurrent(hdc, context) } != TRUE {
+        let error = get_last_error();
+        unsafe {
+            wglDeleteContext(context);
+            if !hdc.is_null() {
+                ReleaseDC(hwnd, hdc);
+            }
+        }
+        return Err(OpenGLNotAvailable(error));
+    }
+
+    let mut gl = GlFunctions::initialize();
+    gl.load();
+
+    let vendor = gl.functions.get_string(gl_context_loader::gl::VENDOR);
+    let renderer = gl.functions.get_string(gl_context_loader::gl::RENDERER);
+    let version = gl.functions.get_string(gl_context_loader::gl::VERSION);
+
+    let mut max_samples = [0_i32];
+    gl.functions.get_integer_v(gl_context_loader::gl::MAX_SAMPLES, (&mut max_samples[..]).into());
+    let mut max_texture_size = [0_i32];
+    gl.functions.get_integer_v(gl_context_loader::gl::MAX_TEXTURE_SIZE, (&mut max_texture_size[..]).into());
+
+    unsafe {
+        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+        wglDeleteContext(context);
+        ReleaseDC(hwnd, hdc);
+    }
+
+    Ok(GlCapabilities {
+        renderer_info: RendererInfo {
+            // opengl32.dll without an ICD from the GPU vendor
+            hardware_accelerated: renderer.as_str() != "GDI Generic",
+            vendor: vendor.into(),
+            renderer: renderer.into(),
+            version: version.into(),
+        },
+        major_version: obtained.major_version,
+        minor_version: obtained.minor_version,
+        max_samples: max_samples[0].max(0) as u32,
+        max_texture_size: max_texture_size[0].max(0) as u32,
+    })
+}
+
+/// Returns which renderer the window uses, `None` if the window does not exist
+pub fn get_renderer_info(hwnd: HWND) -> Option<RendererInfo> {
+    use winapi::um::winuser::SendMessageW;
+    let mut info = None;
+    unsafe {
+        SendMessageW(hwnd, AZ_QUERY_RENDERER_INFO, 0, &mut info as *mut Option<RendererInfo> as LPARAM);
+    }
+    info
+}
+
+/// Returns how long loading and rasterizing the fonts of the `AppConfig::font_warmup`
+/// list took when the window was created, `None` if there was no warm-up or the
+/// window does not exist
+pub fn get_font_warmup_time(hwnd: HWND) -> Option<Duration> {
+    use winapi::um::winuser::SendMessageW;
+    let mut warmup_time = None;
+    unsafe {
+        SendMessageW(hwnd, AZ_QUERY_FONT_WARMUP, 0, &mut warmup_time as *mut Option<Duration> as LPARAM);
+    }
+    warmup_time
+}
+
+/// Submits all queued webrender transactions of the window and blocks until
+/// webrender has built the scene and the hit tester reflects the current layout.
+///
+/// Call this before taking a screenshot or querying the layout in automated tests,
+/// to avoid reading stale results. Must be called from the thread that runs the event loop.
+pub fn flush_pending(hwnd: HWND) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_FLUSH_PENDING, 0, 0); }
+}
+
+/// Replaces the stylesheet of the window at runtime (i.e. to switch themes), see
+/// `WindowInternal::set_css`. The current DOM is restyled, re-layouted and repainted
+/// without invoking the layout callback: scroll positions and focus are preserved.
+/// The stylesheet also replaces the stylesheet of all DOMs returned by the layout
+/// callback afterwards. Iframes and overlay layers keep their own stylesheet.
+#[cfg(feature = "css_parser")]
+pub fn set_css(hwnd: HWND, css: azul_css::Css) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_SET_CSS, 0, &css as *const azul_css::Css as LPARAM); }
+}
+
+/// Returns the size of the default framebuffer of the window in physical pixels,
+/// which is the size of the whole client area. This is the size webrender renders
+/// into: unlike `WindowSize::get_physical_size`, it includes the letterbox bars of
+/// a `fixed_logical_size` window and is not rounded from the logical size.
+/// Returns a zero size if the window does not exist or is minimized.
+pub fn get_framebuffer_size(hwnd: HWND) -> PhysicalSize<u32> {
+    use winapi::um::winuser::GetClientRect;
+    let mut rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetClientRect(hwnd, &mut rect) } == 0 {
+        return PhysicalSize::zero();
+    }
+    PhysicalSize::new(rect.width().max(0) as u32, rect.height().max(0) as u32)
+}
+
+/// Returns whether the window renders with a hardware-accelerated OpenGL context
+/// (`false` if the window does not exist)
+pub fn is_hardware_accelerated(hwnd: HWND) -> bool {
+    get_renderer_info(hwnd).map(|i| i.hardware_accelerated).unwrap_or(false)
+}
+
+/// Reads the accessibility preferences of the user (high contrast mode, reduced motion)
+pub fn get_accessibility_settings() -> AccessibilitySettings {
+
+    use winapi::um::winuser::{
+        SystemParametersInfoW, HIGHCONTRASTW, HCF_HIGHCONTRASTON,
+        SPI_GETHIGHCONTRAST, SPI_GETCLIENTAREAANIMATION,
+    };
+
+    let mut high_contrast: HIGHCONTRASTW = unsafe { mem::zeroed() };
+    high_contrast.cbSize = mem::size_of::<HIGHCONTRASTW>() as u32;
+    let high_contrast_on = unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            high_contrast.cbSize,
+            &mut high_contrast as *mut HIGHCONTRASTW as *mut c_void,
+            0,
+        ) != 0 && (high_contrast.dwFlags & HCF_HIGHCONTRASTON) != 0
+    };
+
+    // "Show animations in Windows" (default: on)
+    let mut client_area_animation: BOOL = TRUE;
+    unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            &mut client_area_animation as *mut BOOL as *mut c_void,
+            0,
+        );
+    }
+
+    AccessibilitySettings {
+        high_contrast: high_contrast_on,
+        prefers_reduced_motion: client_area_animation == 0,
+    }
+}
+
+/// Standard system colors (`GetSysColor`), in high contrast mode these are the
+/// colors of the selected high contrast theme and should be used for the stylesheet
+///
+/// When the colors change (`WM_SYSCOLORCHANGE`, `WM_SETTINGCHANGE`,
+/// `WM_DWMCOLORIZATIONCOLORCHANGED`), the DOM of every window is regenerated,
+/// so layout callbacks that call `get_system_colors()` pick up the new colors
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SystemColors {
+    /// Accent color of the user (`DwmGetColorizationColor`), falls
+    /// back to `highlight` if the DWM is not available
+    pub accent: ColorU,
+    /// Background of windows (`COLOR_WINDOW`)
+    pub window: ColorU,
+    /// Text in windows (`COLOR_WINDOWTEXT`)
+    pub window_text: ColorU,
+    /// Background of selected items (`COLOR_HIGHLIGHT`)
+    pub highlight: ColorU,
+    /// Text of selected items (`COLOR_HIGHLIGHTTEXT`)
+    pub highlight_text: ColorU,
+    /// Background of buttons (`COLOR_BTNFACE`)
+    pub button_face: ColorU,
+    /// Text on buttons (`COLOR_BTNTEXT`)
+    pub button_text: ColorU,
+    /// Disabled text (`COLOR_GRAYTEXT`)
+    pub gray_text: ColorU,
+    /// Hyperlinks / hot-tracked items (`COLOR_HOTLIGHT`)
+    pub hotlight: ColorU,
+}
+
+/// Returns the current system colors
+pub fn get_system_colors() -> SystemColors {
+
+    use winapi::um::winuser::{
+        GetSysColor, COLOR_WINDOW, COLOR_WINDOWTEXT, COLOR_HIGHLIGHT, COLOR_HIGHLIGHTTEXT,
+        COLOR_BTNFACE, COLOR_BTNTEXT, COLOR_GRAYTEXT, COLOR_HOTLIGHT,
+    };
+
+    // COLORREF = 0x00BBGGRR
+    let get_color = |index: i32| -> ColorU {
+        let c = unsafe { GetSysColor(index) };
+        ColorU {
+            r: (c & 0xff) as u8,
+            g: ((c >> 8) & 0xff) as u8,
+            b: ((c >> 16) & 0xff) as u8,
+            a: ColorU::ALPHA_OPAQUE,
+        }
+    };
+
+    let highlight = get_color(COLOR_HIGHLIGHT);
+
+    SystemColors {
+        accent: get_accent_color().unwrap_or(highlight),
+        window: get_color(COLOR_WINDOW),
+        window_text: get_color(COLOR_WINDOWTEXT),
+        highlight,
+        highlight_text: get_color(COLOR_HIGHLIGHTTEXT),
+        button_face: get_color(COLOR_BTNFACE),
+        button_text: get_color(COLOR_BTNTEXT),
+        gray_text: get_color(COLOR_GRAYTEXT),
+        hotlight: get_color(COLOR_HOTLIGHT),
+    }
+}
+
+// DwmGetColorizationColor, loaded at runtime since dwmapi.dll is optional
+fn get_accent_color() -> Option<ColorU> {
+
+    use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW, FreeLibrary};
+    use winapi::shared::minwindef::{DWORD, FALSE};
+    use winapi::shared::winerror::S_OK;
+
+    type DwmGetColorizationColorFn = extern "system" fn(*mut DWORD, *mut BOOL) -> HRESULT;
+
+    let mut dll_name = encode_wide("dwmapi.dll");
+    let dwmapi_dll = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
+    if dwmapi_dll.is_null() {
+        return None;
+    }
+
+    let mut func_name = encode_ascii("DwmGetColorizationColor");
+    let func = unsafe { GetProcAddress(dwmapi_dll, func_name.as_mut_ptr()) };
+
+    let color = if func.is_null() {
+        None
+    } else {
+        let func: DwmGetColorizationColorFn = unsafe { mem::transmute(func) };
+        let mut argb: DWORD = 0;
+        let mut opaque_blend: BOOL = FALSE;
+        if func(&mut argb, &mut opaque_blend) == S_OK {
+            // 0xAARRGGBB
+            Some(ColorU {
+                r: ((argb >> 16) & 0xff) as u8,
+                g: ((argb >> 8) & 0xff) as u8,
+                b: (argb & 0xff) as u8,
+                a: ((argb >> 24) & 0xff) as u8,
+            })
         } else {
             None
-        };
+        }
+    };
 
-        Some(Self {
-            _dwmapi_dll_handle: hDwmAPI_DLL,
-            DwmEnableBlurBehindWindow,
-            DwmExtendFrameIntoClientArea,
-            DwmDefWindowProc,
+    unsafe { FreeLibrary(dwmapi_dll); }
+
+    color
+}
+
+/// Mouse button of a `SyntheticEvent`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SyntheticMouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// Input event that can be injected into a window via `inject_event`,
+/// also passed to the `InputEventFilter`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SyntheticEvent {
+    /// Moves the (virtual) cursor to the position, relative to the client area
+    MouseMove(LogicalPosition),
+    /// Presses the button at the last cursor position
+    MouseDown(SyntheticMouseButton),
+    /// Releases the button at the last cursor position
+    MouseUp(SyntheticMouseButton),
+    /// Scrolls the mouse wheel by the given number of lines (positive = up)
+    Scroll(f32),
+    /// Presses the key with the given Win32 virtual key code (`VK_*`)
+    KeyDown(i32),
+    /// Releases the key with the given Win32 virtual key code (`VK_*`)
+    KeyUp(i32),
+    /// Types a character (as if produced by `TranslateMessage`)
+    Char(char),
+}
+
+/// Injects a synthetic input event into the window, without going through the
+/// OS input queue (the real cursor and keyboard state are not modified).
+///
+/// The event is encoded as the Win32 message that the OS would send and handled
+/// by the same message handlers, so the window state is updated and callbacks
+/// are invoked exactly as for real input. Callbacks have run once this function
+/// returns - use `force_frame` afterwards to render the result.
+///
+/// Must be called from the thread that runs the event loop.
+pub fn inject_event(hwnd: HWND, event: SyntheticEvent) {
+
+    use winapi::um::winuser::{SendMessageW, PeekMessageW, DispatchMessageW, PM_REMOVE};
+
+    for (msg, wparam, lparam) in encode_synthetic_event(hwnd, event, None) {
+        unsafe { SendMessageW(hwnd, msg, wparam, lparam); }
+    }
+
+    // the handlers post AZ_REDO_HIT_TEST to invoke the callbacks:
+    // run them now, so that the callbacks have run when this function returns
+    let mut msg: MSG = unsafe { mem::zeroed() };
+    while unsafe { PeekMessageW(&mut msg, hwnd, AZ_REDO_HIT_TEST, AZ_REDO_HIT_TEST, PM_REMOVE) } > 0 {
+        unsafe { DispatchMessageW(&msg); }
+    }
+}
+
+// Encodes the event as the Win32 message(s) that the OS would send. Mouse button
+// and wheel events use `cursor_lparam` as the position if set, otherwise the
+// last cursor position set by inject_event
+fn encode_synthetic_event(
+    hwnd: HWND,
+    event: SyntheticEvent,
+    cursor_lparam: Option<LPARAM>,
+) -> Vec<(u32, WPARAM, LPARAM)> {
+
+    use winapi::um::winuser::{
+        MapVirtualKeyW, MAPVK_VK_TO_VSC, WHEEL_DELTA,
+        WM_MOUSEMOVE, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_RBUTTONDOWN, WM_RBUTTONUP,
+        WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEWHEEL, WM_KEYDOWN, WM_KEYUP, WM_CHAR,
+    };
+
+    let dpi = unsafe { self::dpi::DpiFunctions::init().hwnd_dpi(hwnd) };
+    let hidpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+
+    let make_lparam = |x: i32, y: i32| -> LPARAM {
+        ((x as u16 as u32) | ((y as u16 as u32) << 16)) as LPARAM
+    };
+
+    let key_lparam = |vk: i32, up: bool| -> LPARAM {
+        let scancode = unsafe { MapVirtualKeyW(vk as u32, MAPVK_VK_TO_VSC) };
+        // repeat count = 1, for key up: previous key state + transition state
+        let flags: u32 = if up { 0xC000_0001 } else { 0x0000_0001 };
+        (flags | ((scancode & 0xff) << 16)) as i32 as LPARAM
+    };
+
+    let cursor_lparam = || -> LPARAM {
+        cursor_lparam.unwrap_or_else(|| {
+            let pos = get_last_cursor_pos(hwnd);
+            make_lparam(
+                libm::roundf(pos.x * hidpi_factor) as i32,
+                libm::roundf(pos.y * hidpi_factor) as i32,
+            )
         })
+    };
+
+    let mut messages = Vec::new();
+
+    match event {
+        SyntheticEvent::MouseMove(pos) => {
+            let x = libm::roundf(pos.x * hidpi_factor) as i32;
+            let y = libm::roundf(pos.y * hidpi_factor) as i32;
+            INJECTED_CURSOR_POS.with(|c| c.set(Some((hwnd as usize, pos))));
+            messages.push((WM_MOUSEMOVE, 0, make_lparam(x, y)));
+        },
+        SyntheticEvent::MouseDown(button) => {
+            let msg = match button {
+                SyntheticMouseButton::Left => WM_LBUTTONDOWN,
+                SyntheticMouseButton::Right => WM_RBUTTONDOWN,
+                SyntheticMouseButton::Middle => WM_MBUTTONDOWN,
+            };
+            messages.push((msg, 0, cursor_lparam()));
+        },
+        SyntheticEvent::MouseUp(button) => {
+            let msg = match button {
+                SyntheticMouseButton::Left => WM_LBUTTONUP,
+                SyntheticMouseButton::Right => WM_RBUTTONUP,
+                SyntheticMouseButton::Middle => WM_MBUTTONUP,
+            };
+            messages.push((msg, 0, cursor_lparam()));
+        },
+        SyntheticEvent::Scroll(lines) => {
+            let delta = libm::roundf(lines * WHEEL_DELTA as f32) as i16;
+            messages.push((WM_MOUSEWHEEL, (delta as u16 as usize) << 16, cursor_lparam()));
+        },
+        SyntheticEvent::KeyDown(vk) => {
+            messages.push((WM_KEYDOWN, vk as usize, key_lparam(vk, false)));
+        },
+        SyntheticEvent::KeyUp(vk) => {
+            messages.push((WM_KEYUP, vk as usize, key_lparam(vk, true)));
+        },
+        SyntheticEvent::Char(c) => {
+            // characters outside of the BMP arrive as two WM_CHAR (surrogate pairs)
+            let mut utf16 = [0_u16; 2];
+            for unit in c.encode_utf16(&mut utf16).iter() {
+                messages.push((WM_CHAR, *unit as usize, 1));
+            }
+        },
     }
+
+    messages
 }
 
-impl Drop for DwmFunctions {
-    fn drop(&mut self) {
-        use winapi::um::libloaderapi::FreeLibrary;
-        unsafe { FreeLibrary(self._dwmapi_dll_handle); }
+// Inverse of encode_synthetic_event, `None` for messages that are not input
+// events (or half of a surrogate pair)
+fn decode_input_message(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> Option<SyntheticEvent> {
+
+    use winapi::um::winuser::{
+        WHEEL_DELTA,
+        WM_MOUSEMOVE, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_RBUTTONDOWN, WM_RBUTTONUP,
+        WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEWHEEL, WM_KEYDOWN, WM_KEYUP, WM_CHAR,
+    };
+    use winapi::shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM};
+
+    let event = match msg {
+        WM_MOUSEMOVE => {
+            let dpi = unsafe { self::dpi::DpiFunctions::init().hwnd_dpi(hwnd) };
+            let hidpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+            SyntheticEvent::MouseMove(LogicalPosition::new(
+                GET_X_LPARAM(lparam) as f32 / hidpi_factor,
+                GET_Y_LPARAM(lparam) as f32 / hidpi_factor,
+            ))
+        },
+        WM_LBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Left),
+        WM_RBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Right),
+        WM_MBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Middle),
+        WM_LBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Left),
+        WM_RBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Right),
+        WM_MBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Middle),
+        WM_MOUSEWHEEL => SyntheticEvent::Scroll((wparam >> 16) as i16 as f32 / WHEEL_DELTA as f32),
+        WM_KEYDOWN => SyntheticEvent::KeyDown(wparam as i32),
+        WM_KEYUP => SyntheticEvent::KeyUp(wparam as i32),
+        WM_CHAR => SyntheticEvent::Char(core::char::from_u32(wparam as u32)?),
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+/// Return value of an `InputEventFilter`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum InputFilterResult {
+    /// Dispatch the (possibly modified) event to the window
+    Continue,
+    /// Drop the event, it does not reach the window state / node callbacks
+    Consume,
+}
+
+/// App-level input event filter, invoked for every mouse / wheel / key / char
+/// event before it is dispatched to the window. The filter can observe the event,
+/// modify it in place (i.e. remap keys) or consume it.
+///
+/// Invoked without the application data being borrowed, the filter may call
+/// `inject_event` (injected events are filtered, too).
+pub type InputEventFilter = fn(HWND, &mut SyntheticEvent) -> InputFilterResult;
+
+thread_local! {
+    static INPUT_EVENT_FILTER: Cell<Option<InputEventFilter>> = Cell::new(None);
+    // set right before re-sending an already filtered message, consumed by the next WindowProc call
+    static BYPASS_INPUT_EVENT_FILTER: Cell<bool> = Cell::new(false);
+}
+
+/// Installs (or, with `None`, removes) the input event filter
+/// for all windows of the application
+pub fn set_input_event_filter(filter: Option<InputEventFilter>) {
+    INPUT_EVENT_FILTER.with(|f| f.set(filter));
+}
+
+// Runs the input event filter on the message, returns `true` if the message
+// must not be handled (because it was consumed or replaced by a modified event)
+fn apply_input_event_filter(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> bool {
+
+    use winapi::um::winuser::SendMessageW;
+
+    if BYPASS_INPUT_EVENT_FILTER.with(|b| b.replace(false)) {
+        return false;
+    }
+
+    // cheap exit if no filter is installed
+    let filter = match INPUT_EVENT_FILTER.with(|f| f.get()) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let original = match decode_input_message(hwnd, msg, wparam, lparam) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut event = original;
+    match (filter)(hwnd, &mut event) {
+        InputFilterResult::Consume => true,
+        InputFilterResult::Continue if event == original => false,
+        InputFilterResult::Continue => {
+            // modified: handle the re-encoded event instead, at the original cursor position
+            let cursor_lparam = match original {
+                SyntheticEvent::MouseDown(_) | SyntheticEvent::MouseUp(_) | SyntheticEvent::Scroll(_) => Some(lparam),
+                _ => None,
+            };
+            for (msg, wparam, lparam) in encode_synthetic_event(hwnd, event, cursor_lparam) {
+                BYPASS_INPUT_EVENT_FILTER.with(|b| b.set(true));
+                unsafe { SendMessageW(hwnd, msg, wparam, lparam); }
+            }
+            true
+        }
     }
 }
 
-// OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
-struct GlFunctions {
-    _opengl32_dll_handle: Option<HINSTANCE>,
-    functions: Rc<GenericGlContext>, // implements Rc<dyn gleam::Gl>!
+/// Input event captured by the input recorder, see `start_input_recording`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RecordedInputEvent {
+    /// Time since the recording was started
+    pub time: Duration,
+    /// Window that received the event: windows are numbered in the order in
+    /// which they were created (starting at 0), so that the ids stay the same
+    /// when the application is started again for the replay
+    pub window_id: usize,
+    pub event: SyntheticEvent,
 }
 
-impl fmt::Debug for GlFunctions {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self._opengl32_dll_handle.map(|f| f as usize).fmt(f)?;
-        Ok(())
+/// Error when parsing an input log, see `parse_input_log`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputLogError {
+    /// The first line is not an `azul-input-log <version>` header
+    MissingHeader,
+    /// The log was written by a newer version of the recorder
+    UnsupportedVersion(u32),
+    /// The line (1-based) is not a valid event
+    InvalidLine(usize),
+}
+
+// Version of the text format written by write_input_log
+const INPUT_LOG_VERSION: u32 = 1;
+
+/// Serializes the events into the stable text format of the input log:
+///
+/// ```text
+/// azul-input-log 1
+/// <microseconds> <window id> mousemove <x> <y>
+/// <microseconds> <window id> mousedown left|right|middle
+/// <microseconds> <window id> mouseup left|right|middle
+/// <microseconds> <window id> scroll <lines>
+/// <microseconds> <window id> keydown <virtual key code>
+/// <microseconds> <window id> keyup <virtual key code>
+/// <microseconds> <window id> char <unicode code point>
+/// ```
+pub fn write_input_log(events: &[RecordedInputEvent]) -> String {
+    let mut log = format!("azul-input-log {}\n", INPUT_LOG_VERSION);
+    for event in events {
+        log.push_str(&format_input_log_line(event));
     }
+    log
 }
 
-impl GlFunctions {
-    // Initializes the DLL, but does not load the functions yet
-    fn initialize() -> Self {
-        // zero-initialize all function pointers
-        let context: GenericGlContext = unsafe { mem::zeroed() };
+/// Parses an input log written by `write_input_log` (or streamed to a file
+/// by the recorder), empty lines are ignored
+pub fn parse_input_log(log: &str) -> Result<Vec<RecordedInputEvent>, InputLogError> {
 
-        let opengl32_dll = load_dll("opengl32.dll");
+    let mut lines = log.lines();
 
-        Self {
-            _opengl32_dll_handle: opengl32_dll,
-            functions: Rc::new(context),
-        }
+    let version = lines.next()
+        .and_then(|header| header.trim().strip_prefix("azul-input-log "))
+        .and_then(|version| version.trim().parse::<u32>().ok())
+        .ok_or(InputLogError::MissingHeader)?;
+
+    if version > INPUT_LOG_VERSION {
+        return Err(InputLogError::UnsupportedVersion(version));
     }
 
-    // Assuming the OpenGL context is current, loads the OpenGL function pointers
-    fn load(&mut self) {
-        fn get_func(s: &str, opengl32_dll: Option<HINSTANCE>) -> *mut gl_context_loader::c_void {
-            use winapi::um::{libloaderapi::GetProcAddress, wingdi::wglGetProcAddress};
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_input_log_line(line).ok_or(InputLogError::InvalidLine(i + 2)))
+        .collect()
+}
 
-            let mut func_name = encode_ascii(s);
-            let addr1 = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
-            (if addr1 != ptr::null_mut() {
-                addr1
-            } else {
-                if let Some(opengl32_dll) = opengl32_dll {
-                    unsafe { GetProcAddress(opengl32_dll, func_name.as_mut_ptr()) }
-                } else {
-                    addr1
+fn format_input_log_line(event: &RecordedInputEvent) -> String {
+
+    let button = |b: SyntheticMouseButton| match b {
+        SyntheticMouseButton::Left => "left",
+        SyntheticMouseButton::Right => "right",
+        SyntheticMouseButton::Middle => "middle",
+    };
+
+    let event_str = match event.event {
+        SyntheticEvent::MouseMove(pos) => format!("mousemove {} {}", pos.x, pos.y),
+        SyntheticEvent::MouseDown(b) => format!("mousedown {}", button(b)),
+        SyntheticEvent::MouseUp(b) => format!("mouseup {}", button(b)),
+        SyntheticEvent::Scroll(lines) => format!("scroll {}", lines),
+        SyntheticEvent::KeyDown(vk) => format!("keydown {}", vk),
+        SyntheticEvent::KeyUp(vk) => format!("keyup {}", vk),
+        SyntheticEvent::Char(c) => format!("char {}", c as u32),
+    };
+
+    format!("{} {} {}\n", event.time.as_micros(), event.window_id, event_str)
+}
+
+fn parse_input_log_line(line: &str) -> Option<RecordedInputEvent> {
+
+    let mut tokens = line.split_whitespace();
+
+    let time = Duration::from_micros(tokens.next()?.parse().ok()?);
+    let window_id = tokens.next()?.parse().ok()?;
+
+    let button = |token: Option<&str>| match token? {
+        "left" => Some(SyntheticMouseButton::Left),
+        "right" => Some(SyntheticMouseButton::Right),
+        "middle" => Some(SyntheticMouseButton::Middle),
+        _ => None,
+    };
+
+    let event = match tokens.next()? {
+        "mousemove" => {
+            let x = tokens.next()?.parse().ok()?;
+            let y = tokens.next()?.parse().ok()?;
+            SyntheticEvent::MouseMove(LogicalPosition::new(x, y))
+        },
+        "mousedown" => SyntheticEvent::MouseDown(button(tokens.next())?),
+        "mouseup" => SyntheticEvent::MouseUp(button(tokens.next())?),
+        "scroll" => SyntheticEvent::Scroll(tokens.next()?.parse().ok()?),
+        "keydown" => SyntheticEvent::KeyDown(tokens.next()?.parse().ok()?),
+        "keyup" => SyntheticEvent::KeyUp(tokens.next()?.parse().ok()?),
+        "char" => SyntheticEvent::Char(core::char::from_u32(tokens.next()?.parse().ok()?)?),
+        _ => return None,
+    };
+
+    Some(RecordedInputEvent { time, window_id, event })
+}
+
+// State of the input recorder, see start_input_recording
+struct InputRecorder {
+    start: std::time::Instant,
+    events: Vec<RecordedInputEvent>,
+    file: Option<std::fs::File>,
+}
+
+// State of the input replay, see replay_input_log
+struct InputReplay {
+    start: std::time::Instant,
+    events: VecDeque<RecordedInputEvent>,
+    timer_id: TIMERPTR,
+}
+
+thread_local! {
+    static INPUT_RECORDER: RefCell<Option<InputRecorder>> = RefCell::new(None);
+    // cheap check for the WindowProc, whether INPUT_RECORDER is set
+    static INPUT_RECORDING: Cell<bool> = Cell::new(false);
+    static INPUT_REPLAY: RefCell<Option<InputReplay>> = RefCell::new(None);
+    // HWNDs of all windows in the order of creation (index = window id of the input log)
+    static INPUT_LOG_WINDOWS: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+}
+
+// Assigns the next input log window id to a newly created window
+fn register_input_log_window(hwnd: HWND) {
+    INPUT_LOG_WINDOWS.with(|w| w.borrow_mut().push(hwnd as usize));
+}
+
+fn get_input_log_window_id(hwnd: HWND) -> Option<usize> {
+    // HWNDs can be re-used by the OS after a window is destroyed: newest window first
+    INPUT_LOG_WINDOWS.with(|w| w.borrow().iter().rposition(|h| *h == hwnd as usize))
+}
+
+fn get_input_log_window(window_id: usize) -> Option<HWND> {
+    INPUT_LOG_WINDOWS.with(|w| w.borrow().get(window_id).map(|h| *h as HWND))
+}
+
+/// Starts recording every input event (mouse, wheel, key and character events,
+/// after the `InputEventFilter`) of all windows of the application, together with
+/// the time since the start of the recording and the id of the window.
+///
+/// If `log_file` is set, the events are additionally streamed into the file in
+/// the format of `write_input_log` (so that the log survives a crash). A running
+/// recording is discarded. Must be called from the thread that runs the event loop.
+pub fn start_input_recording(log_file: Option<&std::path::Path>) -> std::io::Result<()> {
+
+    use std::io::Write;
+
+    let file = match log_file {
+        Some(path) => {
+            let mut file = std::fs::File::create(path)?;
+            file.write_all(format!("azul-input-log {}\n", INPUT_LOG_VERSION).as_bytes())?;
+            Some(file)
+        },
+        None => None,
+    };
+
+    INPUT_RECORDER.with(|r| *r.borrow_mut() = Some(InputRecorder {
+        start: std::time::Instant::now(),
+        events: Vec::new(),
+        file,
+    }));
+    INPUT_RECORDING.with(|r| r.set(true));
+
+    Ok(())
+}
+
+/// Stops the input recording, returns the recorded events
+/// (empty if no recording was running)
+pub fn stop_input_recording() -> Vec<RecordedInputEvent> {
+    INPUT_RECORDING.with(|r| r.set(false));
+    INPUT_RECORDER.with(|r| r.borrow_mut().take())
+        .map(|recorder| recorder.events)
+        .unwrap_or_default()
+}
+
+// Records the message if it is an input event and the recorder is running
+fn record_input_message(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) {
+
+    use std::io::Write;
+
+    if !INPUT_RECORDING.with(|r| r.get()) {
+        return;
+    }
+
+    let event = match decode_input_message(hwnd, msg, wparam, lparam) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let window_id = match get_input_log_window_id(hwnd) {
+        Some(s) => s,
+        None => return,
+    };
+
+    INPUT_RECORDER.with(|r| {
+        let mut recorder = r.borrow_mut();
+        let recorder = match recorder.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let event = RecordedInputEvent {
+            time: recorder.start.elapsed(),
+            window_id,
+            event,
+        };
+
+        if let Some(file) = recorder.file.as_mut() {
+            if let Err(_e) = file.write_all(format_input_log_line(&event).as_bytes()) {
+                #[cfg(feature = "logging")] {
+                    error!("failed to write input log: {}", _e);
                 }
-            }) as *mut gl_context_loader::c_void
+                recorder.file = None;
+            }
         }
 
-        self.functions = Rc::new(GenericGlContext {
-            glAccum: get_func("glAccum", self._opengl32_dll_handle),
-            glActiveTexture: get_func("glActiveTexture", self._opengl32_dll_handle),
-            glAlphaFunc: get_func("glAlphaFunc", self._opengl32_dll_handle),
-            glAreTexturesResident: get_func("glAreTexturesResident", self._opengl32_dll_handle),
-            glArrayElement: get_func("glArrayElement", self._opengl32_dll_handle),
-            glAttachShader: get_func("glAttachShader", self._opengl32_dll_handle),
-            glBegin: get_func("glBegin", self._opengl32_dll_handle),
-            glBeginConditionalRender: get_func(
+        recorder.events.push(event);
+    });
+}
+
+/// Replays recorded input events at their original timing (relative to the
+/// call of this function) via `inject_event`. Events for windows that don't
+/// exist (yet) are skipped: the windows have to be created in the same order
+/// as during the recording.
+///
+/// The replay runs on a thread timer of the event loop, this function returns
+/// immediately. A running replay is cancelled. Must be called from the thread
+/// that runs the event loop.
+pub fn replay_input_log(events: &[RecordedInputEvent]) {
+    stop_input_replay();
+    let mut events = events.to_vec();
+    events.sort_by_key(|e| e.time);
+    INPUT_REPLAY.with(|r| *r.borrow_mut() = Some(InputReplay {
+        start: std::time::Instant::now(),
+        events: events.into(),
+        timer_id: 0,
+    }));
+    schedule_input_replay();
+}
+
+/// Cancels a running replay, returns `false` if no replay was running
+pub fn stop_input_replay() -> bool {
+    use winapi::um::winuser::KillTimer;
+    match INPUT_REPLAY.with(|r| r.borrow_mut().take()) {
+        Some(replay) => {
+            if replay.timer_id != 0 {
+                unsafe { KillTimer(ptr::null_mut(), replay.timer_id); }
+            }
+            true
+        },
+        None => false,
+    }
+}
+
+/// Returns whether a replay started by `replay_input_log` is still running
+pub fn is_input_replay_running() -> bool {
+    INPUT_REPLAY.with(|r| r.borrow().is_some())
+}
+
+// (Re-)arms the replay timer for the next event, ends the replay if all events were injected
+fn schedule_input_replay() {
+
+    use winapi::um::winuser::{SetTimer, KillTimer, USER_TIMER_MINIMUM};
+
+    INPUT_REPLAY.with(|r| {
+        let mut replay_borrow = r.borrow_mut();
+        let replay = match replay_borrow.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let next_time = match replay.events.front() {
+            Some(s) => s.time,
+            None => {
+                if replay.timer_id != 0 {
+                    unsafe { KillTimer(ptr::null_mut(), replay.timer_id); }
+                }
+                *replay_borrow = None;
+                return;
+            },
+        };
+
+        let delay_ms = next_time
+            .saturating_sub(replay.start.elapsed())
+            .as_millis()
+            .max(USER_TIMER_MINIMUM as u128)
+            .min(u32::MAX as u128) as u32;
+
+        // thread timer: SetTimer with the same ID replaces the timeout
+        replay.timer_id = unsafe { SetTimer(ptr::null_mut(), replay.timer_id, delay_ms, Some(input_replay_timer_proc)) };
+    });
+}
+
+unsafe extern "system" fn input_replay_timer_proc(_: HWND, _: UINT, _: TIMERPTR, _: u32) {
+
+    use winapi::um::winuser::IsWindow;
+
+    // take the due events first: inject_event runs the callbacks,
+    // which may start / stop a replay
+    let due_events = INPUT_REPLAY.with(|r| {
+        let mut replay_borrow = r.borrow_mut();
+        let replay = match replay_borrow.as_mut() {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+        let elapsed = replay.start.elapsed();
+        let mut due_events = Vec::new();
+        while replay.events.front().map(|e| e.time <= elapsed).unwrap_or(false) {
+            due_events.extend(replay.events.pop_front());
+        }
+        due_events
+    });
+
+    for recorded in due_events {
+        match get_input_log_window(recorded.window_id) {
+            Some(hwnd) if IsWindow(hwnd) != 0 => inject_event(hwnd, recorded.event),
+            _ => {
+                #[cfg(feature = "logging")] {
+                    warn!("input replay: window {} does not exist, skipping event", recorded.window_id);
+                }
+            },
+        }
+    }
+
+    schedule_input_replay();
+}
+
+/// Native message handler that runs before azul's `WindowProc`, see
+/// `add_native_message_handler`. Returning `Some(result)` consumes the message
+/// (neither azul nor the subclasses installed before the handler see it),
+/// `None` passes it on unchanged.
+pub type NativeMessageHandler = fn(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT>;
+
+/// Registers an additional native message handler for the window, which runs
+/// before azul's `WindowProc`. Works for any `HWND` (i.e. a host window that azul
+/// subclasses or a window that the embedder owns), must be called from the thread
+/// that created the window.
+///
+/// The handler is installed via `SetWindowSubclass`, so it composes with other
+/// subclasses of the same window: handlers registered later run first. Registering
+/// the same handler twice has no effect, the handler is removed automatically when
+/// the window is destroyed. Returns `false` if the subclass could not be installed.
+pub fn add_native_message_handler(hwnd: HWND, handler: NativeMessageHandler) -> bool {
+    use winapi::um::commctrl::SetWindowSubclass;
+    unsafe {
+        SetWindowSubclass(
+            hwnd,
+            Some(native_message_handler_proc),
+            handler as usize,
+            handler as usize,
+        ) != 0
+    }
+}
+
+/// Removes a handler registered with `add_native_message_handler`,
+/// returns `false` if the handler was not registered for the window
+pub fn remove_native_message_handler(hwnd: HWND, handler: NativeMessageHandler) -> bool {
+    use winapi::um::commctrl::RemoveWindowSubclass;
+    unsafe { RemoveWindowSubclass(hwnd, Some(native_message_handler_proc), handler as usize) != 0 }
+}
+
+// Subclass procedure of add_native_message_handler: the handler
+// is stored in the subclass ID / reference data
+unsafe extern "system" fn native_message_handler_proc(
+    hwnd: HWND,
+    msg: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    id: usize,
+    ref_data: usize,
+) -> LRESULT {
+
+    use winapi::um::commctrl::{DefSubclassProc, RemoveWindowSubclass};
+    use winapi::um::winuser::WM_NCDESTROY;
+
+    let handler: NativeMessageHandler = mem::transmute(ref_data);
+
+    // last message of the window: the subclass has to be removed
+    // before the window is gone (the handler still sees the message)
+    if msg == WM_NCDESTROY {
+        RemoveWindowSubclass(hwnd, Some(native_message_handler_proc), id);
+    }
+
+    match (handler)(hwnd, msg, wparam, lparam) {
+        Some(result) => result,
+        None => DefSubclassProc(hwnd, msg, wparam, lparam),
+    }
+}
+
+thread_local! {
+    /// Last cursor position set by inject_event (hwnd, position), used as the
+    /// position of injected mouse button events
+    static INJECTED_CURSOR_POS: Cell<Option<(usize, LogicalPosition)>> = Cell::new(None);
+}
+
+fn get_last_cursor_pos(hwnd: HWND) -> LogicalPosition {
+    INJECTED_CURSOR_POS.with(|c| match c.get() {
+        Some((h, pos)) if h == hwnd as usize => pos,
+        _ => LogicalPosition::zero(),
+    })
+}
+
+/// Queries the current cursor position relative to the client area of the window
+/// (in logical coordinates at the DPI of the window), independent of the last
+/// mouse move event. Returns `None` if the cursor is outside of the client area.
+pub fn get_cursor_pos(hwnd: HWND) -> OptionLogicalPosition {
+
+    use azul_core::window::LogicalPosition;
+    use winapi::um::winuser::{GetCursorPos, GetClientRect, ScreenToClient};
+
+    let mut cursor_pos = POINT { x: 0, y: 0 };
+    let mut client_rect: RECT = unsafe { mem::zeroed() };
+
+    unsafe {
+        if GetCursorPos(&mut cursor_pos) == 0 ||
+           ScreenToClient(hwnd, &mut cursor_pos) == 0 ||
+           GetClientRect(hwnd, &mut client_rect) == 0 {
+            return None.into();
+        }
+    }
+
+    if cursor_pos.x < client_rect.left || cursor_pos.x >= client_rect.right ||
+       cursor_pos.y < client_rect.top || cursor_pos.y >= client_rect.bottom {
+        return None.into();
+    }
+
+    let dpi = unsafe { self::dpi::DpiFunctions::init().hwnd_dpi(hwnd) };
+    let hidpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+
+    Some(LogicalPosition::new(
+        cursor_pos.x as f32 / hidpi_factor,
+        cursor_pos.y as f32 / hidpi_factor,
+    )).into()
+}
+
+thread_local! {
+    /// (HWND, client x, client y) of the last `set_cursor_pos` call
+    /// whose resulting WM_MOUSEMOVE should not count as user motion
+    static SUPPRESSED_CURSOR_WARP: Cell<Option<(usize, i32, i32)>> = Cell::new(None);
+}
+
+/// Moves the OS cursor to the given position relative to the client area of the
+/// window (in logical coordinates at the DPI of the window). If `suppress_mouse_move`
+/// is set, the `WM_MOUSEMOVE` generated by the warp updates the cursor position
+/// and hover state, but doesn't fire any mouse move callbacks.
+///
+/// Returns `false` if the cursor could not be moved.
+pub fn set_cursor_pos(hwnd: HWND, position: LogicalPosition, suppress_mouse_move: bool) -> bool {
+
+    use winapi::um::winuser::{ClientToScreen, SetCursorPos};
+
+    let dpi = unsafe { self::dpi::DpiFunctions::init().hwnd_dpi(hwnd) };
+    let hidpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+
+    let client_x = (position.x * hidpi_factor).round() as i32;
+    let client_y = (position.y * hidpi_factor).round() as i32;
+
+    let mut point = POINT { x: client_x, y: client_y };
+    if unsafe { ClientToScreen(hwnd, &mut point) } == 0 {
+        return false;
+    }
+
+    let warp = if suppress_mouse_move { Some((hwnd as usize, client_x, client_y)) } else { None };
+    SUPPRESSED_CURSOR_WARP.with(|s| s.set(warp));
+
+    if unsafe { SetCursorPos(point.x, point.y) } == 0 {
+        SUPPRESSED_CURSOR_WARP.with(|s| s.set(None));
+        return false;
+    }
+
+    true
+}
+
+/// Shape of a non-rectangular window, see `set_window_region`
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowRegion {
+    /// Rectangle with rounded corners covering the entire window,
+    /// corner radius in logical pixels
+    RoundRect { radius: f32 },
+    /// Polygon in logical coordinates relative to the top left corner of the window
+    Polygon(Vec<LogicalPosition>),
+}
+
+/// Restricts the window to the given region (`SetWindowRgn`): nothing outside of the
+/// region is drawn and mouse input outside of it doesn't hit the window. The region
+/// is re-created when the window is resized or the DPI changes. `None` resets the
+/// window to its normal rectangular shape.
+///
+/// Must be called from the thread that runs the event loop.
+pub fn set_window_region(hwnd: HWND, region: Option<WindowRegion>) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe {
+        SendMessageW(hwnd, AZ_SET_WINDOW_REGION, 0, &region as *const Option<WindowRegion> as LPARAM);
+    }
+}
+
+/// Sets the cursor of the window to a custom image (RGBA8 or BGRA8), for example a
+/// paint brush. The image is specified in logical pixels and scaled with the DPI of the
+/// window, `hotspot` is the logical position of the click point inside of the image.
+/// The custom cursor replaces the `mouse_cursor_type` of the window until it is cleared
+/// with `clear_custom_cursor`, the CSS `cursor` of a hovered node still overrides it.
+///
+/// Must be called from the thread that runs the event loop. Returns `false` if the
+/// window does not exist or the cursor could not be created from the image.
+pub fn set_custom_cursor(hwnd: HWND, image: RawImage, hotspot: LogicalPosition) -> bool {
+    use winapi::um::winuser::SendMessageW;
+    let cursor = Some((image, hotspot));
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_SET_CUSTOM_CURSOR, 0, &cursor as *const Option<(RawImage, LogicalPosition)> as LPARAM)
+    };
+    result == 0
+}
+
+/// Removes the custom cursor set by `set_custom_cursor`, the window
+/// uses its `mouse_cursor_type` again
+pub fn clear_custom_cursor(hwnd: HWND) -> bool {
+    use winapi::um::winuser::SendMessageW;
+    let cursor: Option<(RawImage, LogicalPosition)> = None;
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_SET_CUSTOM_CURSOR, 0, &cursor as *const Option<(RawImage, LogicalPosition)> as LPARAM)
+    };
+    result == 0
+}
+
+static FRAME_REQUEST_ID_GENERATOR: AtomicUsize = AtomicUsize::new(1); // 0 = no request
+
+/// HWND -> ID of the last frame request that was presented on screen
+static PRESENTED_FRAME_REQUESTS: Lazy<(Mutex<BTreeMap<usize, usize>>, Condvar)> = Lazy::new(|| {
+    (Mutex::new(BTreeMap::new()), Condvar::new())
+});
+
+/// Returned by `invalidate_frame`, can be waited on until
+/// the frame that includes the invalidation is on screen
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FrameToken {
+    hwnd: usize,
+    request_id: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FrameWaitError {
+    /// The window does not exist (anymore)
+    InvalidWindow,
+    /// No frame was presented within the given timeout
+    Timeout,
+}
+
+/// Schedules a re-render of the window and returns a token that can be used to block
+/// until the corresponding frame has been presented (i.e. the `WM_PAINT` of the frame
+/// has finished). Intended for integration tests that assert on what is on screen
+/// after a state change.
+///
+/// Can be called from any thread, however `FrameToken::wait` must not be called
+/// from the thread that runs the event loop (it would block forever).
+pub fn invalidate_frame(hwnd: HWND) -> Result<FrameToken, FrameWaitError> {
+
+    use winapi::um::winuser::PostMessageW;
+
+    let request_id = FRAME_REQUEST_ID_GENERATOR.fetch_add(1, AtomicOrdering::SeqCst);
+
+    if unsafe { PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, request_id, 0) } == 0 {
+        return Err(FrameWaitError::InvalidWindow);
+    }
+
+    Ok(FrameToken { hwnd: hwnd as usize, request_id })
+}
+
+impl FrameToken {
+    /// Blocks the current thread until the frame has been presented,
+    /// returns `FrameWaitError::Timeout` if no frame was produced in time
+    pub fn wait(&self, timeout: Duration) -> Result<(), FrameWaitError> {
+
+        let (lock, condvar) = &*PRESENTED_FRAME_REQUESTS;
+        let presented = lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        let (_, wait_result) = condvar.wait_timeout_while(presented, timeout, |p| {
+            p.get(&self.hwnd).copied().unwrap_or(0) < self.request_id
+        }).unwrap_or_else(|e| e.into_inner());
+
+        if wait_result.timed_out() {
+            Err(FrameWaitError::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Called after a frame has been presented: wakes up all threads waiting on a FrameToken
+fn notify_frame_presented(hwnd: HWND, request_id: usize) {
+    let (lock, condvar) = &*PRESENTED_FRAME_REQUESTS;
+    let mut presented = lock.lock().unwrap_or_else(|e| e.into_inner());
+    presented.insert(hwnd as usize, request_id);
+    condvar.notify_all();
+}
+
+/// Adds an overlay layer (HUD, tooltips, etc.) on top of the content of the window.
+///
+/// Every layer is a separate webrender document with its own DOM (created by the
+/// `layout_callback`), display list and hit tester, which only gets re-layouted when
+/// the window is resized, not when the main content changes. Layers are composited
+/// in the order they were added, hit-testing consults them top-down: if a layer
+/// is hit, the content below it doesn't receive the mouse.
+///
+/// Returns the index of the new layer or `None` on failure. Must be called from
+/// the thread that runs the event loop.
+pub fn add_overlay_layer(hwnd: HWND, layout_callback: LayoutCallback) -> Option<usize> {
+    use winapi::um::winuser::SendMessageW;
+    let mut layout_callback = layout_callback;
+    let result = unsafe {
+        SendMessageW(hwnd, AZ_ADD_OVERLAY_LAYER, 0, &mut layout_callback as *mut LayoutCallback as LPARAM)
+    };
+    if result < 0 { None } else { Some(result as usize) }
+}
+
+/// Removes the overlay layer at the given index (see `add_overlay_layer`)
+pub fn remove_overlay_layer(hwnd: HWND, index: usize) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_REMOVE_OVERLAY_LAYER, index, 0); }
+}
+
+/// Puts the window into a "busy" state for a long operation: the window is disabled
+/// (`EnableWindow(FALSE)`) and rejects mouse and keyboard input, but still repaints.
+/// Mouse buttons that are held down are released and the wait cursor is shown.
+///
+/// If a `spinner` is given, its DOM is shown as an overlay layer (see `add_overlay_layer`)
+/// that is re-layouted every 50ms, so the layout callback can animate it. This needs
+/// the event loop to keep running: the operation has to be split up (i.e. into timer
+/// steps), a callback that blocks the UI thread also blocks the repaint.
+///
+/// Does nothing if the window is already busy.
+pub fn set_window_busy(hwnd: HWND, spinner: Option<LayoutCallback>) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_SET_BUSY, 1, &spinner as *const Option<LayoutCallback> as LPARAM); }
+}
+
+/// Re-enables a window disabled by `set_window_busy`, removes the spinner overlay and
+/// gives the keyboard focus back to the window if it had the focus before
+pub fn clear_window_busy(hwnd: HWND) {
+    use winapi::um::winuser::SendMessageW;
+    unsafe { SendMessageW(hwnd, AZ_SET_BUSY, 0, 0); }
+}
+
+// Request / response of a capture_node_screenshot() call, sent via AZ_CAPTURE_NODE
+struct NodeCapture {
+    node: DomNodeId,
+    result: Option<RawImage>,
+}
+
+/// Renders the current frame of the window (without presenting it) and reads
+/// back only the bounding box of the given DOM node as a top-down, RGBA8 image.
+/// Parts of the node outside of the window are cut off.
+///
+/// Returns `None` if the window or node does not exist, the node has no layout
+/// rect yet or the window has no OpenGL context.
+pub fn capture_node_screenshot(hwnd: HWND, node: DomNodeId) -> Option<RawImage> {
+    use winapi::um::winuser::SendMessageW;
+    let mut capture = NodeCapture { node, result: None };
+    unsafe { SendMessageW(hwnd, AZ_CAPTURE_NODE, 0, &mut capture as *mut NodeCapture as LPARAM); }
+    capture.result
+}
+
+/// Error returned by `get_pixel`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GetPixelError {
+    /// The window does not exist
+    InvalidWindow,
+    /// The window has not presented a frame yet
+    NoFrameRendered,
+    /// The window uses the software renderer, which can't be read back yet
+    NoOpenGlContext,
+    /// The position is outside of the client area
+    OutOfBounds,
+}
+
+// Request / response of a get_pixel() call, sent via AZ_GET_PIXEL
+struct PixelQuery {
+    position: LogicalPosition,
+    result: Result<ColorU, GetPixelError>,
+}
+
+/// Returns the (non-premultiplied) color of the pixel at the given logical
+/// position of the last presented frame, i.e. for an eyedropper tool.
+///
+/// The current scene is rendered again into the back buffer (without presenting it)
+/// and a single physical pixel is read back, so changes that haven't been presented
+/// yet may already be visible in the result.
+pub fn get_pixel(hwnd: HWND, position: LogicalPosition) -> Result<ColorU, GetPixelError> {
+    use winapi::um::winuser::SendMessageW;
+    let mut query = PixelQuery { position, result: Err(GetPixelError::InvalidWindow) };
+    unsafe { SendMessageW(hwnd, AZ_GET_PIXEL, 0, &mut query as *mut PixelQuery as LPARAM); }
+    query.result
+}
+
+/// Options for `print_window`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrintOptions {
+    /// Name of the print job, shown in the print queue
+    pub document_name: AzString,
+    /// Show the system print dialog (otherwise the default printer is used)
+    pub show_dialog: bool,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            document_name: AzString::from_const_str("azul"),
+            show_dialog: true,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PrintError {
+    /// The window does not exist or has no OpenGL context
+    InvalidWindow,
+    /// The user closed the print dialog
+    Canceled,
+    /// No (default) printer is installed
+    NoPrinter,
+    /// The spooler rejected the document, contains the result of `GetLastError`
+    PrintFailed(u32),
+}
+
+/// Prints the current contents of the window client area: the UI is rendered,
+/// composited onto white paper, scaled to the printable width of the page and
+/// split across multiple pages if it is taller than one page.
+///
+/// NOTE: The content is rasterized at the resolution of the window (not the
+/// printer), text is not printed as vector glyphs.
+pub fn print_window(hwnd: HWND, options: &PrintOptions) -> Result<(), PrintError> {
+
+    use winapi::um::{
+        commdlg::{PrintDlgW, PRINTDLGW, PD_RETURNDC, PD_RETURNDEFAULT, PD_NOPAGENUMS, PD_NOSELECTION},
+        wingdi::{
+            DeleteDC, EndDoc, EndPage, GetDeviceCaps, StartDocW, StartPage, StretchDIBits,
+            BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS, DOCINFOW,
+            HORZRES, VERTRES, SRCCOPY,
+        },
+        winbase::GlobalFree,
+        winuser::SendMessageW,
+    };
+
+    let mut image: Option<RawImage> = None;
+    unsafe { SendMessageW(hwnd, AZ_CAPTURE_WINDOW, 0, &mut image as *mut Option<RawImage> as LPARAM); }
+    let image = image.ok_or(PrintError::InvalidWindow)?;
+
+    let rgba = match &image.pixels {
+        RawImageData::U8(u) => u.as_ref(),
+        _ => return Err(PrintError::InvalidWindow),
+    };
+
+    // GDI expects BGRA, composite the premultiplied pixels onto white paper
+    let bgra = rgba.chunks_exact(4).flat_map(|px| {
+        let paper = 255 - px[3];
+        [px[2].saturating_add(paper), px[1].saturating_add(paper), px[0].saturating_add(paper), 255]
+    }).collect::<Vec<u8>>();
+
+    let image_width = image.width as i32;
+    let image_height = image.height as i32;
+
+    let mut print_dialog: PRINTDLGW = unsafe { mem::zeroed() };
+    print_dialog.lStructSize = mem::size_of::<PRINTDLGW>() as u32;
+    print_dialog.hwndOwner = hwnd;
+    print_dialog.Flags = PD_RETURNDC | PD_NOPAGENUMS | PD_NOSELECTION;
+    if !options.show_dialog {
+        print_dialog.Flags |= PD_RETURNDEFAULT;
+    }
+
+    if unsafe { PrintDlgW(&mut print_dialog) } == 0 {
+        return Err(if options.show_dialog { PrintError::Canceled } else { PrintError::NoPrinter });
+    }
+
+    unsafe {
+        GlobalFree(print_dialog.hDevMode);
+        GlobalFree(print_dialog.hDevNames);
+    }
+
+    let printer_dc = print_dialog.hDC;
+    if printer_dc.is_null() {
+        return Err(PrintError::NoPrinter);
+    }
+
+    let page_width = unsafe { GetDeviceCaps(printer_dc, HORZRES) };
+    let page_height = unsafe { GetDeviceCaps(printer_dc, VERTRES) };
+
+    // number of image rows that fit on one page when scaled to the page width
+    let scale = page_width as f32 / image_width as f32;
+    let rows_per_page = ((page_height as f32 / scale) as i32).max(1);
+
+    let mut document_name = encode_wide(options.document_name.as_str());
+    let mut doc_info: DOCINFOW = unsafe { mem::zeroed() };
+    doc_info.cbSize = mem::size_of::<DOCINFOW>() as i32;
+    doc_info.lpszDocName = document_name.as_mut_ptr();
+
+    let result = unsafe {
+        if StartDocW(printer_dc, &doc_info) <= 0 {
+            Err(PrintError::PrintFailed(get_last_error()))
+        } else {
+            let mut first_row = 0;
+            while first_row < image_height {
+                let rows = rows_per_page.min(image_height - first_row);
+
+                // top-down DIB of only the rows on this page
+                let mut bitmap_info: BITMAPINFO = mem::zeroed();
+                bitmap_info.bmiHeader.biSize = mem::size_of::<BITMAPINFOHEADER>() as u32;
+                bitmap_info.bmiHeader.biWidth = image_width;
+                bitmap_info.bmiHeader.biHeight = -rows;
+                bitmap_info.bmiHeader.biPlanes = 1;
+                bitmap_info.bmiHeader.biBitCount = 32;
+                bitmap_info.bmiHeader.biCompression = BI_RGB;
+
+                let offset = first_row as usize * image_width as usize * 4;
+
+                StartPage(printer_dc);
+                StretchDIBits(
+                    printer_dc,
+                    0, 0, page_width, libm::roundf(rows as f32 * scale) as i32,
+                    0, 0, image_width, rows,
+                    bgra[offset..].as_ptr() as *const c_void,
+                    &bitmap_info,
+                    DIB_RGB_COLORS,
+                    SRCCOPY,
+                );
+                EndPage(printer_dc);
+
+                first_row += rows;
+            }
+            EndDoc(printer_dc);
+            Ok(())
+        }
+    };
+
+    unsafe { DeleteDC(printer_dc); }
+
+    result
+}
+
+/// Handler for a message registered via `register_window_message`, receives the
+/// window and the raw `wparam` / `lparam`. Returning `None` forwards the message
+/// to `DefWindowProcW`, otherwise the value is returned from the `WindowProc`.
+pub type CustomMessageCallback = fn(hwnd: HWND, wparam: usize, lparam: isize) -> Option<isize>;
+
+thread_local! {
+    /// Message ID (returned by `RegisterWindowMessageW`) -> handler
+    static CUSTOM_MESSAGE_HANDLERS: RefCell<BTreeMap<u32, CustomMessageCallback>> = RefCell::new(BTreeMap::new());
+}
+
+/// Registers a system-wide unique message for the given name (so that external
+/// processes such as shell extensions can send it via `PostMessageW` /
+/// `SendMessageW`) and installs a handler that is invoked from the `WindowProc`
+/// of every azul window on this thread. Registering the same name again replaces
+/// the handler.
+///
+/// Returns the message ID or `None` if the registration failed.
+pub fn register_window_message(name: &str, callback: CustomMessageCallback) -> Option<u32> {
+
+    use winapi::um::winuser::RegisterWindowMessageW;
+
+    let name = encode_wide(name);
+    let msg = unsafe { RegisterWindowMessageW(name.as_ptr()) };
+    if msg == 0 {
+        return None;
+    }
+
+    CUSTOM_MESSAGE_HANDLERS.with(|h| { h.borrow_mut().insert(msg, callback); });
+    Some(msg)
+}
+
+/// Removes the handler for a message previously registered via `register_window_message`
+pub fn unregister_window_message(msg: u32) {
+    CUSTOM_MESSAGE_HANDLERS.with(|h| { h.borrow_mut().remove(&msg); });
+}
+
+fn get_custom_message_handler(msg: u32) -> Option<CustomMessageCallback> {
+    // registered messages are always in the range 0xC000 - 0xFFFF
+    if msg < 0xC000 || msg > 0xFFFF {
+        return None;
+    }
+    CUSTOM_MESSAGE_HANDLERS.with(|h| h.borrow().get(&msg).copied())
+}
+
+/// Command sent by media keys, multimedia keyboards and the back / forward
+/// buttons of a mouse (decoded from `WM_APPCOMMAND`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AppCommand {
+    BrowserBackward,
+    BrowserForward,
+    BrowserRefresh,
+    BrowserStop,
+    BrowserSearch,
+    BrowserFavorites,
+    BrowserHome,
+    VolumeMute,
+    VolumeDown,
+    VolumeUp,
+    MediaNextTrack,
+    MediaPreviousTrack,
+    MediaStop,
+    MediaPlayPause,
+    MediaPlay,
+    MediaPause,
+    MediaRecord,
+    MediaFastForward,
+    MediaRewind,
+    MediaChannelUp,
+    MediaChannelDown,
+    /// Any other `APPCOMMAND_*` value
+    Other(u16),
+}
+
+impl AppCommand {
+    // Decodes the command from the lparam of a WM_APPCOMMAND message (GET_APPCOMMAND_LPARAM)
+    fn from_lparam(lparam: LPARAM) -> Self {
+
+        use winapi::um::winuser::{
+            APPCOMMAND_BROWSER_BACKWARD, APPCOMMAND_BROWSER_FORWARD, APPCOMMAND_BROWSER_REFRESH,
+            APPCOMMAND_BROWSER_STOP, APPCOMMAND_BROWSER_SEARCH, APPCOMMAND_BROWSER_FAVORITES,
+            APPCOMMAND_BROWSER_HOME, APPCOMMAND_VOLUME_MUTE, APPCOMMAND_VOLUME_DOWN,
+            APPCOMMAND_VOLUME_UP, APPCOMMAND_MEDIA_NEXTTRACK, APPCOMMAND_MEDIA_PREVIOUSTRACK,
+            APPCOMMAND_MEDIA_STOP, APPCOMMAND_MEDIA_PLAY_PAUSE, APPCOMMAND_MEDIA_PLAY,
+            APPCOMMAND_MEDIA_PAUSE, APPCOMMAND_MEDIA_RECORD, APPCOMMAND_MEDIA_FAST_FORWARD,
+            APPCOMMAND_MEDIA_REWIND, APPCOMMAND_MEDIA_CHANNEL_UP, APPCOMMAND_MEDIA_CHANNEL_DOWN,
+        };
+
+        // the high word contains the device in the upper 4 bits (FAPPCOMMAND_MASK)
+        let cmd = ((lparam as usize >> 16) & 0x0FFF) as u16;
+        match cmd as i16 {
+            APPCOMMAND_BROWSER_BACKWARD => AppCommand::BrowserBackward,
+            APPCOMMAND_BROWSER_FORWARD => AppCommand::BrowserForward,
+            APPCOMMAND_BROWSER_REFRESH => AppCommand::BrowserRefresh,
+            APPCOMMAND_BROWSER_STOP => AppCommand::BrowserStop,
+            APPCOMMAND_BROWSER_SEARCH => AppCommand::BrowserSearch,
+            APPCOMMAND_BROWSER_FAVORITES => AppCommand::BrowserFavorites,
+            APPCOMMAND_BROWSER_HOME => AppCommand::BrowserHome,
+            APPCOMMAND_VOLUME_MUTE => AppCommand::VolumeMute,
+            APPCOMMAND_VOLUME_DOWN => AppCommand::VolumeDown,
+            APPCOMMAND_VOLUME_UP => AppCommand::VolumeUp,
+            APPCOMMAND_MEDIA_NEXTTRACK => AppCommand::MediaNextTrack,
+            APPCOMMAND_MEDIA_PREVIOUSTRACK => AppCommand::MediaPreviousTrack,
+            APPCOMMAND_MEDIA_STOP => AppCommand::MediaStop,
+            APPCOMMAND_MEDIA_PLAY_PAUSE => AppCommand::MediaPlayPause,
+            APPCOMMAND_MEDIA_PLAY => AppCommand::MediaPlay,
+            APPCOMMAND_MEDIA_PAUSE => AppCommand::MediaPause,
+            APPCOMMAND_MEDIA_RECORD => AppCommand::MediaRecord,
+            APPCOMMAND_MEDIA_FAST_FORWARD => AppCommand::MediaFastForward,
+            APPCOMMAND_MEDIA_REWIND => AppCommand::MediaRewind,
+            APPCOMMAND_MEDIA_CHANNEL_UP => AppCommand::MediaChannelUp,
+            APPCOMMAND_MEDIA_CHANNEL_DOWN => AppCommand::MediaChannelDown,
+            _ => AppCommand::Other(cmd),
+        }
+    }
+}
+
+/// Handler for `WM_APPCOMMAND`, returns `true` if the command was handled.
+/// Unhandled commands are forwarded to `DefWindowProcW` (which passes them
+/// on to the parent window / the shell, e.g. to change the system volume).
+pub type AppCommandCallback = fn(hwnd: HWND, command: AppCommand) -> bool;
+
+thread_local! {
+    static APP_COMMAND_HANDLER: Cell<Option<AppCommandCallback>> = Cell::new(None);
+}
+
+/// Sets (or removes) the handler that receives the media key / browser button
+/// commands of all azul windows on this thread
+pub fn set_app_command_handler(callback: Option<AppCommandCallback>) {
+    APP_COMMAND_HANDLER.with(|h| h.set(callback));
+}
+
+// How often the hardware renderer may fail (device lost / render errors) in one
+// session before all windows fall back to the software renderer for good
+const MAX_HARDWARE_RENDERER_FAILURES: usize = 3;
+
+/// Handler that is invoked when a window gives up on hardware rendering after
+/// repeated GPU / driver failures and continues with the software renderer.
+/// `failures` is the number of hardware renderer failures in this session.
+pub type SoftwareFallbackCallback = fn(hwnd: HWND, failures: usize);
+
+thread_local! {
+    static SOFTWARE_FALLBACK_HANDLER: Cell<Option<SoftwareFallbackCallback>> = Cell::new(None);
+    static HARDWARE_RENDERER_FAILURES: Cell<usize> = Cell::new(0);
+    static HARDWARE_RENDERING_DISABLED: Cell<bool> = Cell::new(false);
+}
+
+/// Sets (or removes) the handler that is notified when a window falls
+/// back to software rendering after repeated hardware renderer failures
+pub fn set_software_fallback_handler(callback: Option<SoftwareFallbackCallback>) {
+    SOFTWARE_FALLBACK_HANDLER.with(|h| h.set(callback));
+}
+
+/// Handler that is invoked when webrender rejects (parts of) a frame or the
+/// renderer fails. `document_id` identifies the window (or overlay layer of
+/// the window) whose frame was affected. Errors are always logged, the
+/// handler only gets called if one is set.
+pub type RenderErrorCallback = fn(hwnd: HWND, document_id: DocumentId, error: &RenderError);
+
+thread_local! {
+    static RENDER_ERROR_HANDLER: Cell<Option<RenderErrorCallback>> = Cell::new(None);
+}
+
+/// Sets (or removes) the handler that is notified about webrender
+/// transaction and renderer errors, see `RenderError`
+pub fn set_render_error_handler(callback: Option<RenderErrorCallback>) {
+    RENDER_ERROR_HANDLER.with(|h| h.set(callback));
+}
+
+// Passes the render errors reported while handling a message to the error
+// handler. Runs after the application data borrow of the WindowProc was released
+fn dispatch_render_errors(hwnd: HWND, app: &RefCell<ApplicationData>) {
+
+    let handler = match RENDER_ERROR_HANDLER.with(|h| h.get()) {
+        Some(s) => s,
+        None => {
+            // errors were already logged
+            let _ = take_render_errors();
+            return;
+        },
+    };
+
+    // nested dispatch: keep the errors queued for the outer WindowProc
+    let app = match app.try_borrow() {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    let errors = take_render_errors()
+        .into_iter()
+        .map(|(document_id, error)| {
+            let window_hwnd = app.windows.values()
+                .find(|w| {
+                    w.internal.document_id == document_id ||
+                    w.overlays.iter().any(|o| o.internal.document_id == document_id)
+                })
+                .map(|w| w.hwnd)
+                .unwrap_or(hwnd);
+            (window_hwnd, document_id, error)
+        })
+        .collect::<Vec<_>>();
+
+    mem::drop(app);
+
+    for (window_hwnd, document_id, error) in errors.iter() {
+        (handler)(*window_hwnd, *document_id, error);
+    }
+}
+
+/// Time that the last frame of a window took, by phase (see `AppConfig::frame_budget`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameTiming {
+    /// Handling the window messages since the last frame: callbacks, restyling and layout
+    pub layout: Duration,
+    /// Building the display lists and submitting them to webrender
+    pub display_list: Duration,
+    /// Waiting for the webrender scene builder and rendering the frame
+    pub webrender: Duration,
+    /// Presenting the frame (`SwapBuffers`, or `glFlush` / `glFinish` if single-buffered)
+    pub swap: Duration,
+}
+
+impl FrameTiming {
+    /// Sum of all phases
+    pub fn total(&self) -> Duration {
+        self.layout + self.display_list + self.webrender + self.swap
+    }
+
+    /// Name of the phase that took the longest ("layout", "display list", "webrender" or "swap")
+    pub fn slowest_phase(&self) -> &'static str {
+        [
+            (self.layout, "layout"),
+            (self.display_list, "display list"),
+            (self.webrender, "webrender"),
+            (self.swap, "swap"),
+        ].iter().max_by_key(|(d, _)| *d).map(|(_, name)| *name).unwrap_or("layout")
+    }
+}
+
+/// Handler that is invoked when a frame exceeded the `AppConfig::frame_budget`.
+/// The warning is logged whether or not a handler is set.
+pub type FrameBudgetCallback = fn(hwnd: HWND, budget: Duration, timing: &FrameTiming);
+
+thread_local! {
+    static FRAME_BUDGET: Cell<Option<Duration>> = Cell::new(None);
+    static FRAME_BUDGET_HANDLER: Cell<Option<FrameBudgetCallback>> = Cell::new(None);
+    // time spent handling the messages of a window since its last frame, see FrameMessageTimer
+    static FRAME_MESSAGE_TIME: RefCell<BTreeMap<usize, Duration>> = RefCell::new(BTreeMap::new());
+}
+
+/// Sets (or removes) the handler that is notified when a frame exceeds the frame budget
+pub fn set_frame_budget_handler(callback: Option<FrameBudgetCallback>) {
+    FRAME_BUDGET_HANDLER.with(|h| h.set(callback));
+}
+
+// Tick-based durations can't be compared to frame times: ignored
+fn set_frame_budget(budget: OptionDuration) {
+    let budget = match budget.into_option() {
+        Some(AzDuration::System(s)) => Some(s.into()),
+        _ => None,
+    };
+    FRAME_BUDGET.with(|b| b.set(budget));
+}
+
+// Measures how long a window message took (for all return paths of the WindowProc)
+// and adds it to the layout phase of the next frame. Messages that present a frame
+// are not measured, their phases are measured in render_and_present
+struct FrameMessageTimer {
+    hwnd: HWND,
+    start: Option<std::time::Instant>,
+}
+
+impl FrameMessageTimer {
+    fn start(hwnd: HWND, msg: u32) -> Self {
+        use winapi::um::winuser::WM_PAINT;
+        let measure = FRAME_BUDGET.with(|b| b.get()).is_some() && msg != WM_PAINT && msg != AZ_FORCE_FRAME;
+        Self {
+            hwnd,
+            start: if measure { Some(std::time::Instant::now()) } else { None },
+        }
+    }
+}
+
+impl Drop for FrameMessageTimer {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            FRAME_MESSAGE_TIME.with(|t| *t.borrow_mut().entry(self.hwnd as usize).or_default() += elapsed);
+        }
+    }
+}
+
+// Called after a frame was presented: warns if the frame took longer than the budget
+fn check_frame_budget(hwnd: HWND, timing: &FrameTiming) {
+
+    let budget = match FRAME_BUDGET.with(|b| b.get()) {
+        Some(s) => s,
+        None => return,
+    };
+
+    if timing.total() <= budget {
+        return;
+    }
+
+    #[cfg(feature = "logging")] {
+        warn!(
+            "frame of window {:?} took {:?} (budget: {:?}), slowest phase: {} - layout: {:?}, display list: {:?}, webrender: {:?}, swap: {:?}",
+            hwnd, timing.total(), budget, timing.slowest_phase(),
+            timing.layout, timing.display_list, timing.webrender, timing.swap,
+        );
+    }
+
+    if let Some(handler) = FRAME_BUDGET_HANDLER.with(|h| h.get()) {
+        (handler)(hwnd, budget, timing);
+    }
+}
+
+/// Returns whether hardware rendering was disabled for the rest of the
+/// session after repeated GPU / driver failures. New windows are then
+/// created with the software renderer.
+pub fn is_hardware_rendering_disabled() -> bool {
+    HARDWARE_RENDERING_DISABLED.with(|d| d.get())
+}
+
+// Counts a failure of the hardware renderer, disables hardware rendering
+// once the retry limit is reached. Returns whether hardware is now disabled
+fn record_hardware_renderer_failure() -> bool {
+    let failures = HARDWARE_RENDERER_FAILURES.with(|f| {
+        f.set(f.get() + 1);
+        f.get()
+    });
+
+    if failures >= MAX_HARDWARE_RENDERER_FAILURES && !is_hardware_rendering_disabled() {
+        #[cfg(feature = "logging")] {
+            error!(
+                "hardware renderer failed {} times, falling back to software rendering for the rest of the session",
+                failures
+            );
+        }
+        HARDWARE_RENDERING_DISABLED.with(|d| d.set(true));
+    }
+
+    is_hardware_rendering_disabled()
+}
+
+// COPYDATASTRUCT::dwData of a WM_COPYDATA message carrying an activation URL ("AZUR")
+const AZ_COPYDATA_ACTIVATION_URL: usize = 0x415A_5552;
+
+/// Handler for activation URLs (`myapp://...`) forwarded from a second
+/// instance of the app via `forward_to_running_instance`
+pub type ActivationUrlCallback = fn(hwnd: HWND, url: AzString);
+
+thread_local! {
+    static ACTIVATION_URL_HANDLER: Cell<Option<ActivationUrlCallback>> = Cell::new(None);
+}
+
+/// Sets (or removes) the handler for activation URLs received by the windows on this thread
+pub fn set_activation_url_handler(callback: Option<ActivationUrlCallback>) {
+    ACTIVATION_URL_HANDLER.with(|h| h.set(callback));
+}
+
+/// Returns the first command line argument that looks like a URL (`scheme://...`),
+/// which is how Windows passes the URL when launching a registered protocol handler
+pub fn get_activation_url() -> Option<AzString> {
+    std::env::args()
+        .skip(1)
+        .find(|arg| arg.contains("://"))
+        .map(|arg| arg.into())
+}
+
+/// Brings the window to the front and gives it the keyboard focus, restoring it
+/// first if it is minimized. Does nothing if the window already is the foreground window.
+///
+/// Works around the focus-stealing rules of `SetForegroundWindow` by temporarily
+/// attaching the input queue of this thread to the thread of the current foreground
+/// window. Returns `false` if the window does not exist or Windows refused the request.
+pub fn focus_window(hwnd: HWND) -> bool {
+
+    use winapi::shared::minwindef::FALSE;
+    use winapi::um::{
+        processthreadsapi::GetCurrentThreadId,
+        winuser::{
+            AttachThreadInput, BringWindowToTop, GetForegroundWindow, GetWindowThreadProcessId,
+            IsIconic, IsWindow, SetFocus, SetForegroundWindow, ShowWindow, SW_RESTORE,
+        },
+    };
+
+    unsafe {
+        if IsWindow(hwnd) == 0 {
+            return false;
+        }
+
+        let foreground_window = GetForegroundWindow();
+        if foreground_window == hwnd {
+            return true;
+        }
+
+        if IsIconic(hwnd) != 0 {
+            ShowWindow(hwnd, SW_RESTORE);
+        }
+
+        let current_thread = GetCurrentThreadId();
+        let foreground_thread = if foreground_window.is_null() {
+            0
+        } else {
+            GetWindowThreadProcessId(foreground_window, ptr::null_mut())
+        };
+
+        // only the thread that received the last input event may change the
+        // foreground window: share its input state for the duration of the call
+        let attach_input = foreground_thread != 0 && foreground_thread != current_thread;
+        if attach_input {
+            AttachThreadInput(current_thread, foreground_thread, TRUE);
+        }
+
+        BringWindowToTop(hwnd);
+        let success = SetForegroundWindow(hwnd) != 0;
+        SetFocus(hwnd);
+
+        if attach_input {
+            AttachThreadInput(current_thread, foreground_thread, FALSE);
+        }
+
+        success
+    }
+}
+
+/// Single-instance support: if a window of the given class (see
+/// `AppConfig::window_class_name`) already exists, the URL is sent to it via
+/// `WM_COPYDATA`, the existing window is brought to the front and `true` is
+/// returned - the second instance should then exit instead of calling `App::run`.
+pub fn forward_to_running_instance(window_class_name: &str, url: &str) -> bool {
+
+    use winapi::um::winuser::{FindWindowW, SendMessageW, COPYDATASTRUCT, WM_COPYDATA};
+
+    let class_name = encode_wide(window_class_name);
+    let existing_window = unsafe { FindWindowW(class_name.as_ptr(), ptr::null()) };
+    if existing_window.is_null() {
+        return false;
+    }
+
+    let payload = url.encode_utf16().collect::<Vec<u16>>();
+    let copy_data = COPYDATASTRUCT {
+        dwData: AZ_COPYDATA_ACTIVATION_URL,
+        cbData: (payload.len() * mem::size_of::<u16>()) as u32,
+        lpData: payload.as_ptr() as *mut c_void,
+    };
+
+    unsafe {
+        // only the foreground process may change the foreground window,
+        // so this has to be done here and not in the receiving process
+        focus_window(existing_window);
+        SendMessageW(existing_window, WM_COPYDATA, 0, &copy_data as *const COPYDATASTRUCT as LPARAM) != 0
+    }
+}
+
+/// Registers the current executable as the handler for `scheme://` URLs for the
+/// current user (`HKEY_CURRENT_USER\Software\Classes\<scheme>`). Windows then
+/// launches the app with the URL as a command line argument, see `get_activation_url`.
+pub fn register_url_protocol(scheme: &str, description: &str) -> bool {
+
+    use winapi::{
+        shared::winerror::ERROR_SUCCESS,
+        um::{
+            winnt::{KEY_WRITE, REG_OPTION_NON_VOLATILE, REG_SZ},
+            winreg::{RegCloseKey, RegCreateKeyExW, RegSetValueExW, HKEY_CURRENT_USER},
+        },
+    };
+
+    let exe_path = match std::env::current_exe() {
+        Ok(o) => o,
+        Err(_) => return false,
+    };
+
+    let set_value = |subkey: &str, name: Option<&str>, value: &str| -> bool {
+        let subkey = encode_wide(subkey);
+        let name = name.map(encode_wide);
+        let value = encode_wide(value);
+        unsafe {
+            let mut hkey = ptr::null_mut();
+            let created = RegCreateKeyExW(
+                HKEY_CURRENT_USER, subkey.as_ptr(), 0, ptr::null_mut(),
+                REG_OPTION_NON_VOLATILE, KEY_WRITE, ptr::null_mut(), &mut hkey, ptr::null_mut(),
+            );
+            if created as u32 != ERROR_SUCCESS {
+                return false;
+            }
+            let result = RegSetValueExW(
+                hkey,
+                name.as_ref().map(|n| n.as_ptr()).unwrap_or(ptr::null()),
+                0,
+                REG_SZ,
+                value.as_ptr() as *const u8,
+                (value.len() * mem::size_of::<u16>()) as u32,
+            );
+            RegCloseKey(hkey);
+            result as u32 == ERROR_SUCCESS
+        }
+    };
+
+    let key = format!("Software\\Classes\\{}", scheme);
+    let command = format!("\"{}\" \"%1\"", exe_path.display());
+
+    set_value(&key, None, &format!("URL:{}", description)) &&
+    set_value(&key, Some("URL Protocol"), "") &&
+    set_value(&format!("{}\\shell\\open\\command", key), None, &command)
+}
+
+// Read by the NVIDIA / AMD drivers when the first OpenGL context is created to
+// decide whether to run on the dedicated GPU (only works if exported from the
+// executable, i.e. when azul is linked statically). Set via AppConfig::gpu_preference
+#[no_mangle]
+#[used]
+pub static mut NvOptimusEnablement: u32 = 0;
+#[no_mangle]
+#[used]
+pub static mut AmdPowerXpressRequestHighPerformance: i32 = 0;
+
+fn set_gpu_preference(preference: GpuPreference) {
+    let high_performance = preference == GpuPreference::HighPerformance;
+    unsafe {
+        NvOptimusEnablement = if high_performance { 1 } else { 0 };
+        AmdPowerXpressRequestHighPerformance = if high_performance { 1 } else { 0 };
+    }
+}
+
+// Registers the window for WM_WTSSESSION_CHANGE notifications (lock / unlock,
+// local / remote connect and disconnect), wtsapi32.dll is loaded at runtime
+fn register_session_notification(hwnd: HWND) -> bool {
+
+    use winapi::um::libloaderapi::GetProcAddress;
+    use winapi::shared::minwindef::DWORD;
+
+    // only notify about changes of the session that the window belongs to
+    const NOTIFY_FOR_THIS_SESSION: DWORD = 0;
+
+    type WTSRegisterSessionNotificationFn = extern "system" fn(HWND, DWORD) -> BOOL;
+
+    let wtsapi32 = match load_dll("wtsapi32.dll") {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut func_name = encode_ascii("WTSRegisterSessionNotification");
+    let func = unsafe { GetProcAddress(wtsapi32, func_name.as_mut_ptr()) };
+    if func.is_null() {
+        return false;
+    }
+
+    let func: WTSRegisterSessionNotificationFn = unsafe { mem::transmute(func) };
+    func(hwnd, NOTIFY_FOR_THIS_SESSION) != 0
+}
+
+fn unregister_session_notification(hwnd: HWND) {
+
+    use winapi::um::libloaderapi::GetProcAddress;
+
+    type WTSUnRegisterSessionNotificationFn = extern "system" fn(HWND) -> BOOL;
+
+    let wtsapi32 = match load_dll("wtsapi32.dll") {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut func_name = encode_ascii("WTSUnRegisterSessionNotification");
+    let func = unsafe { GetProcAddress(wtsapi32, func_name.as_mut_ptr()) };
+    if !func.is_null() {
+        let func: WTSUnRegisterSessionNotificationFn = unsafe { mem::transmute(func) };
+        func(hwnd);
+    }
+}
+
+// Whether the application runs in a remote desktop (RDP) session
+fn is_remote_session() -> bool {
+    use winapi::um::winuser::{GetSystemMetrics, SM_REMOTESESSION};
+    unsafe { GetSystemMetrics(SM_REMOTESESSION) != 0 }
+}
+
+// Whether the window can't be seen at all: minimized or cloaked (see `Window::update_visibility`)
+//
+// NOTE: Occlusion by other windows can't be queried under DWM composition,
+// see `is_window_occluded` for windows that are fully covered by other windows
+fn is_window_hidden(hwnd: HWND, dwm: Option<&DwmFunctions>) -> bool {
+    use winapi::um::winuser::IsIconic;
+    unsafe { IsIconic(hwnd) != 0 } || dwm.map(|d| d.is_cloaked(hwnd)).unwrap_or(false)
+}
+
+// Whether the client area of the window is fully covered by the visible top-level windows
+// above it in the z-order (WindowsWindowOptions::skip_rendering_when_occluded).
+//
+// Conservative: layered / click-through windows may be transparent and never count as
+// covering, windows that aren't maximized are assumed to have rounded corners
+// (Windows 11) that let the window below shine through
+fn is_window_occluded(hwnd: HWND, dwm: Option<&DwmFunctions>) -> bool {
+
+    use winapi::um::{
+        wingdi::{CombineRgn, CreateRectRgn, CreateRoundRectRgn, DeleteObject, NULLREGION, RGN_DIFF},
+        winuser::{
+            ClientToScreen, GetAncestor, GetClientRect, GetWindow, GetWindowLongPtrW,
+            GetWindowRect, IsIconic, IsWindowVisible, IsZoomed, GA_ROOT, GWL_EXSTYLE,
+            GW_HWNDPREV, WS_EX_LAYERED, WS_EX_TRANSPARENT,
+        },
+    };
+
+    unsafe {
+
+        let mut client_rect: RECT = mem::zeroed();
+        if GetClientRect(hwnd, &mut client_rect) == 0 || client_rect.width() <= 0 || client_rect.height() <= 0 {
+            return false;
+        }
+
+        let mut top_left = POINT { x: 0, y: 0 };
+        ClientToScreen(hwnd, &mut top_left);
+
+        let visible = CreateRectRgn(
+            top_left.x,
+            top_left.y,
+            top_left.x + client_rect.width(),
+            top_left.y + client_rect.height(),
+        );
+        if visible.is_null() {
+            return false;
+        }
+
+        let corner_diameter = (16.0 * self::dpi::dpi_to_scale_factor(self::dpi::DpiFunctions::init().hwnd_dpi(hwnd))) as i32;
+
+        // embedded windows are covered by the windows above their top-level window
+        let mut current = GetAncestor(hwnd, GA_ROOT);
+        let mut region_type = !NULLREGION;
+
+        loop {
+            current = GetWindow(current, GW_HWNDPREV);
+            if current.is_null() {
+                break;
+            }
+
+            if IsWindowVisible(current) == 0 || IsIconic(current) != 0 ||
+               dwm.map(|d| d.is_cloaked(current)).unwrap_or(false) {
+                continue;
+            }
+
+            let ex_style = GetWindowLongPtrW(current, GWL_EXSTYLE) as u32;
+            if ex_style & (WS_EX_LAYERED | WS_EX_TRANSPARENT) != 0 {
+                continue;
+            }
+
+            // visible bounds, without the invisible resize borders
+            let bounds = match dwm.and_then(|d| d.get_extended_frame_bounds(current)) {
+                Some(s) => s,
+                None => {
+                    let mut r: RECT = mem::zeroed();
+                    GetWindowRect(current, &mut r);
+                    r
+                },
+            };
+
+            let covering = if IsZoomed(current) != 0 {
+                CreateRectRgn(bounds.left, bounds.top, bounds.right, bounds.bottom)
+            } else {
+                CreateRoundRectRgn(bounds.left, bounds.top, bounds.right + 1, bounds.bottom + 1, corner_diameter, corner_diameter)
+            };
+
+            if covering.is_null() {
+                continue;
+            }
+
+            region_type = CombineRgn(visible, visible, covering, RGN_DIFF);
+            DeleteObject(covering as *mut c_void);
+
+            if region_type == NULLREGION {
+                break;
+            }
+        }
+
+        DeleteObject(visible as *mut c_void);
+        region_type == NULLREGION
+    }
+}
+
+thread_local! {
+    static CLOAK_EVENT_HOOK_INSTALLED: Cell<bool> = Cell::new(false);
+}
+
+// Installs a WinEvent hook (once per thread) that sends AZ_VISIBILITY_CHANGED to the
+// windows of this process when they are cloaked / uncloaked, since there is no message for it
+fn install_cloak_event_hook() {
+
+    use winapi::um::{
+        processthreadsapi::GetCurrentProcessId,
+        winuser::{SetWinEventHook, WINEVENT_OUTOFCONTEXT},
+    };
+
+    // not yet defined in winapi
+    const EVENT_OBJECT_CLOAKED: u32 = 0x8017;
+    const EVENT_OBJECT_UNCLOAKED: u32 = 0x8018;
+
+    if CLOAK_EVENT_HOOK_INSTALLED.with(|i| i.get()) {
+        return;
+    }
+
+    let hook = unsafe {
+        SetWinEventHook(
+            EVENT_OBJECT_CLOAKED,
+            EVENT_OBJECT_UNCLOAKED,
+            ptr::null_mut(),
+            Some(cloak_event_hook),
+            GetCurrentProcessId(),
+            0,
+            WINEVENT_OUTOFCONTEXT,
+        )
+    };
+
+    CLOAK_EVENT_HOOK_INSTALLED.with(|i| i.set(!hook.is_null()));
+}
+
+unsafe extern "system" fn cloak_event_hook(
+    _hook: winapi::shared::windef::HWINEVENTHOOK,
+    _event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    _id_child: i32,
+    _event_thread: u32,
+    _event_time: u32,
+) {
+    use winapi::um::winuser::{GetWindowLongPtrW, PostMessageW, GWLP_WNDPROC, OBJID_WINDOW};
+
+    // only top-level azul windows, not their child objects or other windows of the process
+    if id_object == OBJID_WINDOW && !hwnd.is_null() &&
+       GetWindowLongPtrW(hwnd, GWLP_WNDPROC) == WindowProc as isize {
+        PostMessageW(hwnd, AZ_VISIBILITY_CHANGED, 0, 0);
+    }
+}
+
+// Loads a function from shell32.dll (not available on all Windows versions)
+fn load_shell32_function(name: &str) -> Option<*mut c_void> {
+    use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW};
+
+    let mut dll_name = encode_wide("shell32.dll");
+    let shell32 = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
+    if shell32.is_null() {
+        return None;
+    }
+
+    let mut func_name = encode_ascii(name);
+    let function = unsafe { GetProcAddress(shell32, func_name.as_mut_ptr()) };
+    if function.is_null() {
+        None
+    } else {
+        Some(function as *mut c_void)
+    }
+}
+
+/// Sets the explicit AppUserModelID of the current process, used by the taskbar
+/// to group windows, for jump lists and for toast notifications.
+///
+/// NOTE: Has to be called before the first window is shown, otherwise the
+/// taskbar button of that window keeps using the ID derived from the executable path.
+pub fn set_process_app_user_model_id(id: &str) -> bool {
+
+    use winapi::shared::winerror::SUCCEEDED;
+
+    type SetCurrentProcessExplicitAppUserModelIDFn = extern "system" fn(*const wchar_t) -> HRESULT;
+
+    let function = match load_shell32_function("SetCurrentProcessExplicitAppUserModelID") {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let function: SetCurrentProcessExplicitAppUserModelIDFn = unsafe { mem::transmute(function) };
+    let id = encode_wide(id);
+    SUCCEEDED((function)(id.as_ptr()))
+}
+
+// Binary layout of a PROPVARIANT holding a VT_LPWSTR
+#[repr(C)]
+struct PropVariantLpwstr {
+    vt: u16,
+    reserved1: u16,
+    reserved2: u16,
+    reserved3: u16,
+    pwsz_val: *const wchar_t,
+    padding: usize,
+}
+
+/// Sets the AppUserModelID of a single window (`PKEY_AppUserModel_ID`), so that it
+/// gets its own taskbar button instead of being grouped with the other windows
+/// of the process.
+///
+/// NOTE: Has to be called before the window is shown for the first time.
+pub fn set_window_app_user_model_id(hwnd: HWND, id: &str) -> bool {
+
+    use winapi::{
+        shared::{guiddef::{GUID, REFIID}, winerror::SUCCEEDED, wtypes::{PROPERTYKEY, VT_LPWSTR}},
+        um::{propidl::PROPVARIANT, propsys::IPropertyStore},
+        Interface,
+    };
+
+    type SHGetPropertyStoreForWindowFn = extern "system" fn(HWND, REFIID, *mut *mut c_void) -> HRESULT;
+
+    // {9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3}, 5
+    const PKEY_APP_USER_MODEL_ID: PROPERTYKEY = PROPERTYKEY {
+        fmtid: GUID {
+            Data1: 0x9F4C2855,
+            Data2: 0x9F79,
+            Data3: 0x4B39,
+            Data4: [0xA8, 0xD0, 0xE1, 0xD4, 0x2D, 0xE1, 0xD5, 0xF3],
+        },
+        pid: 5,
+    };
+
+    let function = match load_shell32_function("SHGetPropertyStoreForWindow") {
+        Some(s) => s,
+        None => return false,
+    };
+    let function: SHGetPropertyStoreForWindowFn = unsafe { mem::transmute(function) };
+
+    let _com = match self::dialogs::ComInitialized::new() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut property_store: *mut IPropertyStore = ptr::null_mut();
+    let hr = (function)(
+        hwnd,
+        &IPropertyStore::uuidof(),
+        &mut property_store as *mut *mut IPropertyStore as *mut _,
+    );
+
+    if !SUCCEEDED(hr) || property_store.is_null() {
+        return false;
+    }
+
+    // the string is copied by SetValue, no need to PropVariantClear() it
+    let id = encode_wide(id);
+    let value = PropVariantLpwstr {
+        vt: VT_LPWSTR as u16,
+        reserved1: 0,
+        reserved2: 0,
+        reserved3: 0,
+        pwsz_val: id.as_ptr(),
+        padding: 0,
+    };
+
+    unsafe {
+        let property_store = &*property_store;
+        let hr = property_store.SetValue(
+            &PKEY_APP_USER_MODEL_ID,
+            &value as *const PropVariantLpwstr as *const PROPVARIANT,
+        );
+        let success = SUCCEEDED(hr) && SUCCEEDED(property_store.Commit());
+        property_store.Release();
+        success
+    }
+}
+
+/// Main function that starts when app.run() is invoked
+pub fn run(app: App, root_window: WindowCreateOptions) -> Result<AppExit, WindowsStartupError> {
+
+    let mut event_loop = create_event_loop(app, root_window)?;
+
+    match event_loop.event_loop_mode {
+        EventLoopMode::Blocking => run_blocking_event_loop(&mut event_loop),
+        EventLoopMode::Polling(max_fps) => run_polling_event_loop(&mut event_loop, max_fps),
+    }
+
+    Ok(event_loop.exit.take().unwrap_or(AppExit::Normal))
+}
+
+/// Reason why the event loop of the application stopped, returned by `run()`
+#[derive(Debug)]
+pub enum AppExit {
+    /// The event loop stopped without a quit request or a closed window
+    Normal,
+    /// The last open window of the application was closed
+    LastWindowClosed,
+    /// `PostQuitMessage` was called with the given exit code
+    /// while windows were still open
+    UserQuit(i32),
+    /// The event loop could not continue after the application was started
+    Error(WindowsStartupError),
+}
+
+impl AppExit {
+    /// Exit code that should be returned from the process
+    /// (0 for a normal exit, 1 for an error)
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppExit::Normal | AppExit::LastWindowClosed => 0,
+            AppExit::UserQuit(code) => *code,
+            AppExit::Error(_) => 1,
+        }
+    }
+}
+
+/// Event loop of the windows of a started application, which processes
+/// the pending events one batch at a time (see `EventLoop::pump_events`)
+pub struct EventLoop {
+    active_hwnds: Rc<RefCell<BTreeSet<HWND>>>,
+    event_loop_mode: EventLoopMode,
+    msg: MSG,
+    exit: Option<AppExit>,
+}
+
+impl fmt::Debug for EventLoop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventLoop")
+            .field("event_loop_mode", &self.event_loop_mode)
+            .field("exit", &self.exit)
+            .finish()
+    }
+}
+
+impl EventLoop {
+
+    /// Processes all pending messages without blocking: dispatches input and
+    /// window messages, runs the timers that are due, polls the threads (WM_TIMER)
+    /// and applies the resulting state changes. Only windows that changed and are
+    /// visible render a frame (WM_PAINT), see `RedrawPolicy::Continuous`.
+    ///
+    /// Returns false once WM_QUIT was received or the last window was closed.
+    pub fn pump_events(&mut self) -> bool {
+
+        if self.exit.is_some() {
+            return false;
+        }
+
+        let quit_received = !dispatch_pending_messages(&mut self.msg);
+
+        let hwnds = match self.active_hwnds.try_borrow() {
+            Ok(o) => o.iter().copied().collect::<Vec<_>>(),
+            Err(e) => {
+                self.exit = Some(AppExit::Error(e.into()));
+                return false;
+            },
+        };
+
+        // WM_DESTROY posts WM_QUIT with an exit code of 0 after the last
+        // window was closed, any other WM_QUIT came from the user
+        if hwnds.is_empty() {
+            self.exit = Some(AppExit::LastWindowClosed);
+            return false;
+        }
+
+        if quit_received {
+            self.exit = Some(AppExit::UserQuit(self.msg.wParam as i32));
+            return false;
+        }
+
+        true
+    }
+
+    /// Reason why the event loop stopped, `None` while it is still running
+    pub fn exit_reason(&self) -> Option<&AppExit> {
+        self.exit.as_ref()
+    }
+}
+
+/// Registers the window class and creates the windows of the application,
+/// without processing any messages yet
+pub fn create_event_loop(app: App, root_window: WindowCreateOptions) -> Result<EventLoop, WindowsStartupError> {
+
+    use winapi::um::{
+        libloaderapi::GetModuleHandleW,
+        wingdi::{wglMakeCurrent, CreateSolidBrush},
+        winuser::{
+            GetDC, RegisterClassW, ReleaseDC, SetProcessDPIAware,
+            CS_HREDRAW, CS_OWNDC, CS_VREDRAW, WNDCLASSW,
+        }
+    };
+
+    let hinstance = unsafe { GetModuleHandleW(ptr::null_mut()) };
+    if hinstance.is_null() {
+        return Err(WindowsStartupError::NoAppInstance(get_last_error()));
+    }
+
+    // has to happen before the first OpenGL context is created
+    set_gpu_preference(app.config.gpu_preference);
+
+    // applies to the renderers of all windows
+    set_async_scene_building(app.config.async_scene_building);
+    set_frame_budget(app.config.frame_budget);
+
+    // has to happen before the first window is shown
+    if let Some(app_user_model_id) = app.config.app_user_model_id.as_ref() {
+        set_process_app_user_model_id(app_user_model_id.as_str());
+    }
+
+    // Tell windows that this process is DPI-aware
+    let dpi = self::dpi::DpiFunctions::init();
+    dpi.become_dpi_aware();
+
+    // Register the application class (shared between windows)
+    let mut class_name = encode_wide(app.config.window_class_name.as_str());
+    let mut wc: WNDCLASSW = unsafe { mem::zeroed() };
+    wc.style = CS_HREDRAW | CS_VREDRAW | CS_OWNDC;
+    wc.hInstance = hinstance;
+    wc.lpszClassName = class_name.as_mut_ptr();
+    wc.lpfnWndProc = Some(WindowProc);
+    wc.hCursor = ptr::null_mut();
+    wc.hbrBackground = unsafe { CreateSolidBrush(0x00000000) }; // transparent black
+
+    // RegisterClass can fail if the same class is
+    // registered twice, error can be ignored
+    unsafe { RegisterClassW(&wc) };
+
+    let dwm = DwmFunctions::initialize();
+    let gl = GlFunctions::initialize();
+
+    let mut active_hwnds = Rc::new(RefCell::new(BTreeSet::new()));
+    let event_loop_mode;
+
+    {
+        let App {
+            data,
+            config,
+            windows,
+            image_cache,
+            fc_cache,
+            ..
+        } = app;
+
+        event_loop_mode = config.event_loop_mode;
+
+        let app_data_inner = Rc::new(RefCell::new(ApplicationData {
+            hinstance,
+            data,
+            config,
+            image_cache,
+            fc_cache,
+            windows: BTreeMap::new(),
+            active_hwnds: active_hwnds.clone(),
+            dwm,
+            dpi,
+        }));
+
+        let w = Window::create(
+            hinstance,
+            root_window,
+            SharedApplicationData { inner: app_data_inner.clone() }
+        )?;
+
+        active_hwnds.try_borrow_mut()?.insert(w.hwnd);
+        app_data_inner
+            .try_borrow_mut()?
+            .windows
+            .insert(w.get_id(), w);
+
+        for opts in windows {
+            if let Ok(w) = Window::create(hinstance, opts, SharedApplicationData { inner: app_data_inner.clone() }) {
+                active_hwnds.try_borrow_mut()?.insert(w.hwnd);
+                app_data_inner
+                    .try_borrow_mut()?
+                    .windows
+                    .insert(w.get_id(), w);
+            }
+        }
+    }
+
+    // Process the window messages of all windows on this thread
+    // (multiple windows process messages in sequence to avoid
+    // complicated multithreading logic)
+    Ok(EventLoop {
+        active_hwnds,
+        event_loop_mode,
+        msg: unsafe { mem::zeroed() },
+        exit: None,
+    })
+}
+
+// Dispatches all pending messages, returns false if WM_QUIT was received
+fn dispatch_pending_messages(msg: &mut MSG) -> bool {
+
+    use winapi::um::winuser::{
+        DispatchMessageW, PeekMessageW, TranslateMessage, PM_REMOVE, WM_QUIT,
+    };
+
+    while unsafe { PeekMessageW(msg, ptr::null_mut(), 0, 0, PM_REMOVE) } > 0 {
+        if msg.message == WM_QUIT {
+            return false;
+        }
+        unsafe {
+            TranslateMessage(msg);
+            DispatchMessageW(msg);
+        }
+
+        // the dispatched message has returned, nothing is borrowed anymore
+        if has_deferred_messages() {
+            replay_deferred_messages();
+        }
+    }
+
+    true
+}
+
+// Message that arrived while the application data was already borrowed
+#[derive(Debug, Copy, Clone)]
+struct DeferredMessage {
+    hwnd: usize,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+}
+
+// Borrowing discipline for the ApplicationData: the WindowProc only holds the
+// borrow while handling a message and releases it before invoking anything that
+// can dispatch messages itself (DefWindowProcW, user callbacks that open modal
+// dialogs, SendMessageW to other windows). Messages that still arrive while the
+// borrow is held (nested message dispatch) are queued here instead of being
+// dropped, and replayed in order once the borrow has been released: either
+// via AZ_REPLAY_DEFERRED_MESSAGES (so that modal loops replay them, too) or
+// after each message dispatched by the main loop.
+thread_local! {
+    static DEFERRED_MESSAGES: RefCell<VecDeque<DeferredMessage>> = RefCell::new(VecDeque::new());
+    static REPLAY_PENDING: Cell<bool> = Cell::new(false);
+}
+
+// Only messages whose parameters are values (not pointers that are only valid
+// during the call) and whose result does not matter to the sender can be deferred
+fn is_deferrable_message(msg: u32) -> bool {
+
+    use winapi::um::winuser::{
+        WM_MOUSEMOVE, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_RBUTTONDOWN, WM_RBUTTONUP,
+        WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEWHEEL, WM_MOUSEHWHEEL, WM_MOUSELEAVE,
+        WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_CHAR, WM_SYSCHAR,
+        WM_SIZE, WM_TIMER, WM_SETFOCUS, WM_KILLFOCUS, WM_DISPLAYCHANGE,
+        WM_WTSSESSION_CHANGE,
+    };
+
+    match msg {
+        WM_MOUSEMOVE | WM_LBUTTONDOWN | WM_LBUTTONUP | WM_RBUTTONDOWN | WM_RBUTTONUP |
+        WM_MBUTTONDOWN | WM_MBUTTONUP | WM_MOUSEWHEEL | WM_MOUSEHWHEEL | WM_MOUSELEAVE |
+        WM_KEYDOWN | WM_KEYUP | WM_SYSKEYDOWN | WM_SYSKEYUP | WM_CHAR | WM_SYSCHAR |
+        WM_SIZE | WM_TIMER | WM_SETFOCUS | WM_KILLFOCUS | WM_DISPLAYCHANGE |
+        WM_WTSSESSION_CHANGE | AZ_VISIBILITY_CHANGED | AZ_REGENERATE_DOM | AZ_REGENERATE_DISPLAY_LIST | AZ_REDO_HIT_TEST |
+        AZ_GPU_SCROLL_RENDER | AZ_FIRST_FRAME_PRESENTED | AZ_APPBAR_NOTIFY |
+        AZ_NOTIFICATION_ACTION | AZ_NOTIFICATION_TRAY_ICON | AZ_FORCE_FRAME => true,
+        _ => false,
+    }
+}
+
+// Queues the message and makes sure that it is replayed, even if the
+// message loop that is currently running is a nested (modal) one
+fn defer_message(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) {
+
+    use winapi::um::winuser::PostMessageW;
+
+    DEFERRED_MESSAGES.with(|d| d.borrow_mut().push_back(DeferredMessage {
+        hwnd: hwnd as usize,
+        msg,
+        wparam,
+        lparam,
+    }));
+
+    if !REPLAY_PENDING.with(|r| r.replace(true)) {
+        unsafe { PostMessageW(hwnd, AZ_REPLAY_DEFERRED_MESSAGES, 0, 0); }
+    }
+}
+
+// Replays the deferred messages in order, must not be called while the
+// application data is borrowed (otherwise the messages are deferred again)
+fn replay_deferred_messages() {
+
+    use winapi::um::winuser::IsWindow;
+
+    REPLAY_PENDING.with(|r| r.set(false));
+
+    loop {
+        // the queue is not borrowed while the message is handled,
+        // the handler may defer new messages
+        let m = match pop_deferred_message() {
+            Some(s) => s,
+            None => break,
+        };
+
+        let hwnd = m.hwnd as HWND;
+        if unsafe { IsWindow(hwnd) } == 0 {
+            continue; // window was destroyed in the meantime
+        }
+
+        // the message already went through the input event filter when it was deferred
+        BYPASS_INPUT_EVENT_FILTER.with(|b| b.set(true));
+        unsafe { WindowProc(hwnd, m.msg, m.wparam, m.lparam); }
+    }
+}
+
+fn pop_deferred_message() -> Option<DeferredMessage> {
+    DEFERRED_MESSAGES.with(|d| d.borrow_mut().pop_front())
+}
+
+fn has_deferred_messages() -> bool {
+    DEFERRED_MESSAGES.with(|d| !d.borrow().is_empty())
+}
+
+// AZ_REPLAY_DEFERRED_MESSAGES arrived while the application data is still borrowed:
+// the replay request stays pending and is posted again from a timer. Posting it again
+// right away would starve the input of the nested message loop (posted messages are
+// retrieved before input), which has to run before the borrow can be released.
+fn postpone_deferred_message_replay() {
+    use winapi::um::winuser::{SetTimer, USER_TIMER_MINIMUM};
+    REPLAY_PENDING.with(|r| r.set(true));
+    unsafe { SetTimer(ptr::null_mut(), 0, USER_TIMER_MINIMUM, Some(replay_deferred_messages_timer_proc)); }
+}
+
+unsafe extern "system" fn replay_deferred_messages_timer_proc(_: HWND, _: UINT, timer_id: TIMERPTR, _: u32) {
+
+    use winapi::um::winuser::{KillTimer, PostMessageW};
+
+    KillTimer(ptr::null_mut(), timer_id);
+
+    let posted = match DEFERRED_MESSAGES.with(|d| d.borrow().front().map(|m| m.hwnd)) {
+        Some(hwnd) => PostMessageW(hwnd as HWND, AZ_REPLAY_DEFERRED_MESSAGES, 0, 0) != 0,
+        None => false,
+    };
+
+    // nothing to replay (or the window is gone): the next deferred message posts a new request
+    if !posted {
+        REPLAY_PENDING.with(|r| r.set(false));
+    }
+}
+
+// Returns the name of the window class that the window was created with
+fn get_window_class_name(hwnd: HWND) -> String {
+    use winapi::um::winuser::GetClassNameW;
+    let mut buf = [0_u16; 256];
+    let len = unsafe { GetClassNameW(hwnd, buf.as_mut_ptr(), buf.len() as i32) };
+    String::from_utf16_lossy(&buf[..len.max(0) as usize])
+}
+
+// Returns whether the window is an azul window (of any app in this process)
+fn is_azul_window(hwnd: HWND) -> bool {
+    use winapi::um::winuser::{GetWindowLongPtrW, GWLP_WNDPROC};
+    !hwnd.is_null() && unsafe { GetWindowLongPtrW(hwnd, GWLP_WNDPROC) } == WindowProc as isize
+}
+
+// Returns the visible, enabled azul windows inside the top-level window of `hwnd`:
+// the top-level window first, then the embedded child windows in z-order.
+// Tab moves the keyboard focus along this list.
+fn get_embedded_window_chain(hwnd: HWND) -> Vec<HWND> {
+
+    use winapi::um::winuser::{
+        EnumChildWindows, GetAncestor, IsWindowEnabled, IsWindowVisible, GA_ROOT,
+    };
+
+    unsafe extern "system" fn child_enum_proc(child: HWND, data: LPARAM) -> BOOL {
+        let windows = &mut *(data as *mut Vec<HWND>);
+        windows.push(child);
+        TRUE
+    }
+
+    let root = unsafe { GetAncestor(hwnd, GA_ROOT) };
+    let mut windows = vec![root];
+    unsafe {
+        EnumChildWindows(
+            root,
+            Some(child_enum_proc),
+            &mut windows as *mut Vec<HWND> as LPARAM,
+        );
+    }
+
+    windows.retain(|w| {
+        *w == hwnd || (
+            is_azul_window(*w) &&
+            unsafe { IsWindowVisible(*w) != 0 && IsWindowEnabled(*w) != 0 }
+        )
+    });
+
+    windows
+}
+
+// Returns the azul window that the keyboard focus is handed to when Tab
+// (Shift + Tab if reverse) wraps around in `hwnd`, None if no other azul
+// window is embedded in the same top-level window
+fn get_next_tab_focus_window(hwnd: HWND, reverse: bool) -> Option<HWND> {
+    let chain = get_embedded_window_chain(hwnd);
+    if chain.len() < 2 {
+        return None;
+    }
+    let index = chain.iter().position(|w| *w == hwnd)?;
+    let next_index = if reverse {
+        (index + chain.len() - 1) % chain.len()
+    } else {
+        (index + 1) % chain.len()
+    };
+    Some(chain[next_index])
+}
+
+// Returns the embedded azul window under the screen position if it is not `hwnd`
+// itself, but inside the same top-level window (i.e. the parent or a child of `hwnd`)
+fn get_embedded_window_at(hwnd: HWND, screen_position: POINT) -> Option<HWND> {
+    use winapi::um::winuser::{GetAncestor, WindowFromPoint, GA_ROOT};
+    let target = unsafe { WindowFromPoint(screen_position) };
+    let is_embedded = target != hwnd &&
+        is_azul_window(target) &&
+        unsafe { GetAncestor(target, GA_ROOT) == GetAncestor(hwnd, GA_ROOT) };
+    if is_embedded { Some(target) } else { None }
+}
+
+// Only top-level windows receive the keyboard focus from the OS when they are
+// clicked: clicking an embedded window (or its parent while an embedded window
+// has the focus) moves the focus to the clicked window
+fn focus_embedded_window_on_click(hwnd: HWND) {
+    use winapi::um::winuser::{GetAncestor, GetFocus, IsChild, SetFocus, GA_ROOT};
+    unsafe {
+        let focused_window = GetFocus();
+        if focused_window == hwnd {
+            return;
+        }
+        let is_embedded = GetAncestor(hwnd, GA_ROOT) != hwnd;
+        let embedded_window_has_focus = !focused_window.is_null() && IsChild(hwnd, focused_window) != 0;
+        if is_embedded || embedded_window_has_focus {
+            SetFocus(hwnd);
+        }
+    }
+}
+
+// EventLoopMode::Blocking: sleeps until the next message arrives,
+// windows only redraw on demand
+fn run_blocking_event_loop(event_loop: &mut EventLoop) {
+
+    use winapi::{
+        shared::minwindef::FALSE,
+        um::{
+            winbase::{INFINITE, WAIT_FAILED},
+            winuser::{MsgWaitForMultipleObjects, QS_ALLINPUT},
+        },
+    };
+
+    loop {
+        if !event_loop.pump_events() {
+            break;
+        }
+
+        // wakes up on input, WM_TIMER (timers / threads), posted and sent messages
+        let r = unsafe { MsgWaitForMultipleObjects(0, ptr::null(), FALSE, INFINITE, QS_ALLINPUT) };
+        if r == WAIT_FAILED {
+            break;
+        }
+    }
+}
+
+// EventLoopMode::Polling: processes all pending messages (dirty windows render
+// in WM_PAINT), sleeping for the rest of the frame if max_fps is set
+fn run_polling_event_loop(event_loop: &mut EventLoop, max_fps: u32) {
+
+    use std::time::Instant;
+
+    let frame_time = if max_fps == 0 {
+        None
+    } else {
+        Some(Duration::from_secs_f64(1.0 / max_fps as f64))
+    };
+
+    loop {
+        let frame_start = Instant::now();
+
+        if !event_loop.pump_events() {
+            break;
+        }
+
+        if let Some(frame_time) = frame_time {
+            let elapsed = frame_start.elapsed();
+            if elapsed < frame_time {
+                std::thread::sleep(frame_time - elapsed);
+            }
+        }
+    }
+}
+
+fn encode_wide(input: &str) -> Vec<u16> {
+    input
+        .encode_utf16()
+        .chain(Some(0).into_iter())
+        .collect::<Vec<_>>()
+}
+
+fn encode_ascii(input: &str) -> Vec<i8> {
+    input
+        .chars()
+        .filter(|c| c.is_ascii())
+        .map(|c| c as i8)
+        .chain(Some(0).into_iter())
+        .collect::<Vec<_>>()
+}
+
+fn get_last_error() -> u32 {
+    use winapi::um::errhandlingapi::GetLastError;
+    (unsafe { GetLastError() }) as u32
+}
+
+pub fn load_dll(name: &'static str) -> Option<HINSTANCE> {
+    use winapi::um::libloaderapi::LoadLibraryW;
+    let mut dll_name = encode_wide(name);
+    let dll = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
+    if dll.is_null() {
+        None
+    } else {
+        Some(dll)
+    }
+}
+
+#[derive(Debug)]
+pub enum WindowsWindowCreateError {
+    FailedToCreateHWND(u32),
+    NoHDC,
+    NoGlContext,
+    Extra(ExtraWglFunctionsLoadError),
+    Renderer(WrRendererError),
+    BorrowMut(BorrowMutError),
+    /// `WindowsWindowOptions::shared_gl_context` is set, but no
+    /// context could be created that shares objects with it
+    SharedGlContext(WindowsOpenGlError),
+}
+
+impl From<ExtraWglFunctionsLoadError> for WindowsWindowCreateError {
+    fn from(e: ExtraWglFunctionsLoadError) -> Self {
+        WindowsWindowCreateError::Extra(e)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum WindowsOpenGlError {
+    OpenGL32DllNotFound(u32),
+    FailedToGetDC(u32),
+    FailedToCreateHiddenHWND(u32),
+    FailedToGetPixelFormat(u32),
+    NoMatchingPixelFormat(u32),
+    OpenGLNotAvailable(u32),
+    FailedToStoreContext(u32),
+    /// The external context to share objects with uses an incompatible pixel
+    /// format or lives on a different device (`ERROR_INVALID_PIXEL_FORMAT` /
+    /// `ERROR_INVALID_OPERATION`)
+    IncompatibleSharedContext(u32),
+}
+
+#[derive(Debug)]
+pub enum WindowsStartupError {
+    NoAppInstance(u32),
+    WindowCreationFailed,
+    Borrow(BorrowError),
+    BorrowMut(BorrowMutError),
+    Create(WindowsWindowCreateError),
+    Gl(WindowsOpenGlError),
+}
+
+impl From<BorrowError> for WindowsStartupError {
+    fn from(e: BorrowError) -> Self {
+        WindowsStartupError::Borrow(e)
+    }
+}
+impl From<BorrowMutError> for WindowsStartupError {
+    fn from(e: BorrowMutError) -> Self {
+        WindowsStartupError::BorrowMut(e)
+    }
+}
+impl From<WindowsWindowCreateError> for WindowsStartupError {
+    fn from(e: WindowsWindowCreateError) -> Self {
+        WindowsStartupError::Create(e)
+    }
+}
+impl From<WindowsOpenGlError> for WindowsStartupError {
+    fn from(e: WindowsOpenGlError) -> Self {
+        WindowsStartupError::Gl(e)
+    }
+}
+
+struct Notifier {}
+
+impl WrRenderNotifier for Notifier {
+    fn clone(&self) -> Box<dyn WrRenderNotifier> {
+        Box::new(Notifier {})
+    }
+    fn wake_up(&self, composite_needed: bool) {}
+    fn new_frame_ready(
+        &self,
+        _: WrDocumentId,
+        _scrolled: bool,
+        composite_needed: bool,
+        _render_time: Option<u64>,
+    ) {
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SharedApplicationData {
+    inner: Rc<RefCell<ApplicationData>>,
+}
+
+// ApplicationData struct that is shared across windows
+#[derive(Debug)]
+struct ApplicationData {
+    hinstance: HINSTANCE,
+    data: RefAny,
+    config: AppConfig,
+    image_cache: ImageCache,
+    fc_cache: LazyFcCache,
+    windows: BTreeMap<usize, Window>,
+    // active HWNDS, tracked separately from the ApplicationData
+    active_hwnds: Rc<RefCell<BTreeSet<HWND>>>,
+    dwm: Option<DwmFunctions>,
+    dpi: DpiFunctions,
+}
+
+// Extra functions from dwmapi.dll
+struct DwmFunctions {
+    _dwmapi_dll_handle: HINSTANCE,
+    DwmEnableBlurBehindWindow: Option<extern "system" fn(HWND, &DWM_BLURBEHIND) -> HRESULT>,
+    DwmExtendFrameIntoClientArea: Option<extern "system" fn(HWND, &MARGINS) -> HRESULT>,
+    DwmDefWindowProc: Option<extern "system" fn(HWND, u32, WPARAM, LPARAM, *mut LRESULT)>,
+    DwmSetWindowAttribute: Option<extern "system" fn(HWND, u32, *const c_void, u32) -> HRESULT>,
+    DwmGetWindowAttribute: Option<extern "system" fn(HWND, u32, *mut c_void, u32) -> HRESULT>,
+    DwmSetIconicThumbnail: Option<extern "system" fn(HWND, HBITMAP, u32) -> HRESULT>,
+    DwmSetIconicLivePreviewBitmap: Option<extern "system" fn(HWND, HBITMAP, *mut POINT, u32) -> HRESULT>,
+}
+
+impl fmt::Debug for DwmFunctions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self._dwmapi_dll_handle as usize).fmt(f)?;
+        (self.DwmEnableBlurBehindWindow.map(|f| f as usize)).fmt(f)?;
+        (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
+        (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetWindowAttribute.map(|f| f as usize)).fmt(f)?;
+        (self.DwmGetWindowAttribute.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetIconicThumbnail.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetIconicLivePreviewBitmap.map(|f| f as usize)).fmt(f)?;
+        Ok(())
+    }
+}
+
+impl DwmFunctions {
+    fn initialize() -> Option<Self> {
+        use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW};
+
+        let mut dll_name = encode_wide("dwmapi.dll");
+        let hDwmAPI_DLL = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
+        if hDwmAPI_DLL.is_null() {
+            return None; // dwmapi.dll not found
+        }
+
+        let mut func_name = encode_ascii("DwmEnableBlurBehindWindow");
+        let DwmEnableBlurBehindWindow =
+            unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmEnableBlurBehindWindow = if DwmEnableBlurBehindWindow != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmEnableBlurBehindWindow) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmExtendFrameIntoClientArea");
+        let DwmExtendFrameIntoClientArea =
+            unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmExtendFrameIntoClientArea = if DwmExtendFrameIntoClientArea != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmExtendFrameIntoClientArea) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmDefWindowProc");
+        let DwmDefWindowProc = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmDefWindowProc = if DwmDefWindowProc != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmDefWindowProc) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetWindowAttribute");
+        let DwmSetWindowAttribute = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetWindowAttribute = if DwmSetWindowAttribute != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetWindowAttribute) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmGetWindowAttribute");
+        let DwmGetWindowAttribute = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmGetWindowAttribute = if DwmGetWindowAttribute != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmGetWindowAttribute) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetIconicThumbnail");
+        let DwmSetIconicThumbnail = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetIconicThumbnail = if DwmSetIconicThumbnail != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetIconicThumbnail) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetIconicLivePreviewBitmap");
+        let DwmSetIconicLivePreviewBitmap = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetIconicLivePreviewBitmap = if DwmSetIconicLivePreviewBitmap != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetIconicLivePreviewBitmap) })
+        } else {
+            None
+        };
+
+        Some(Self {
+            _dwmapi_dll_handle: hDwmAPI_DLL,
+            DwmEnableBlurBehindWindow,
+            DwmExtendFrameIntoClientArea,
+            DwmDefWindowProc,
+            DwmSetWindowAttribute,
+            DwmGetWindowAttribute,
+            DwmSetIconicThumbnail,
+            DwmSetIconicLivePreviewBitmap,
+        })
+    }
+
+    // Tells the DWM to request the taskbar thumbnail and the peek preview
+    // from the window (via WM_DWMSENDICONICTHUMBNAIL / WM_DWMSENDICONICLIVEPREVIEWBITMAP)
+    // instead of capturing the window surface
+    fn enable_iconic_representation(&self, hwnd: HWND) {
+
+        use winapi::um::dwmapi::{DWMWA_FORCE_ICONIC_REPRESENTATION, DWMWA_HAS_ICONIC_BITMAP};
+
+        let DwmSetWindowAttribute = match self.DwmSetWindowAttribute {
+            Some(s) => s,
+            None => return,
+        };
+
+        let enabled: BOOL = TRUE;
+        let enabled_ptr = &enabled as *const BOOL as *const c_void;
+        let size = mem::size_of::<BOOL>() as u32;
+
+        (DwmSetWindowAttribute)(hwnd, DWMWA_FORCE_ICONIC_REPRESENTATION, enabled_ptr, size);
+        (DwmSetWindowAttribute)(hwnd, DWMWA_HAS_ICONIC_BITMAP, enabled_ptr, size);
+    }
+
+    // Returns the visible bounds of the window (without the invisible
+    // resize borders and the drop shadow) in physical screen coordinates
+    fn get_extended_frame_bounds(&self, hwnd: HWND) -> Option<RECT> {
+
+        use winapi::{
+            shared::winerror::SUCCEEDED,
+            um::dwmapi::DWMWA_EXTENDED_FRAME_BOUNDS,
+        };
+
+        let DwmGetWindowAttribute = self.DwmGetWindowAttribute?;
+
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        let hr = (DwmGetWindowAttribute)(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut rect as *mut RECT as *mut c_void,
+            mem::size_of::<RECT>() as u32
+        );
+
+        if SUCCEEDED(hr) { Some(rect) } else { None }
+    }
+
+    // Whether the window is cloaked by DWM (i.e. on another virtual desktop
+    // or suspended UWP host), cloaked windows are not visible on any monitor
+    fn is_cloaked(&self, hwnd: HWND) -> bool {
+
+        use winapi::shared::winerror::SUCCEEDED;
+
+        const DWMWA_CLOAKED: u32 = 14;
+
+        let DwmGetWindowAttribute = match self.DwmGetWindowAttribute {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let mut cloaked: u32 = 0;
+        let hr = (DwmGetWindowAttribute)(
+            hwnd,
+            DWMWA_CLOAKED,
+            &mut cloaked as *mut u32 as *mut c_void,
+            mem::size_of::<u32>() as u32
+        );
+
+        SUCCEEDED(hr) && cloaked != 0
+    }
+
+    // Sets DWMWA_WINDOW_CORNER_PREFERENCE, no-op on Windows versions before
+    // Windows 11 (DwmSetWindowAttribute returns E_INVALIDARG there)
+    fn set_corner_preference(&self, hwnd: HWND, preference: WindowCornerPreference) {
+
+        // not yet defined in winapi
+        const DWMWA_WINDOW_CORNER_PREFERENCE: u32 = 33;
+
+        let DwmSetWindowAttribute = match self.DwmSetWindowAttribute {
+            Some(s) => s,
+            None => return,
+        };
+
+        // DWM_WINDOW_CORNER_PREFERENCE
+        let value: u32 = match preference {
+            WindowCornerPreference::Default => 0,
+            WindowCornerPreference::DoNotRound => 1,
+            WindowCornerPreference::Round => 2,
+            WindowCornerPreference::RoundSmall => 3,
+        };
+
+        (DwmSetWindowAttribute)(
+            hwnd,
+            DWMWA_WINDOW_CORNER_PREFERENCE,
+            &value as *const u32 as *const c_void,
+            mem::size_of::<u32>() as u32
+        );
+    }
+
+    // Sets DWMWA_TRANSITIONS_FORCEDISABLED (disables the minimize / maximize / restore animations)
+    fn set_transitions_disabled(&self, hwnd: HWND, disabled: bool) {
+
+        use winapi::um::dwmapi::DWMWA_TRANSITIONS_FORCEDISABLED;
+
+        let DwmSetWindowAttribute = match self.DwmSetWindowAttribute {
+            Some(s) => s,
+            None => return,
+        };
+
+        let value: BOOL = if disabled { TRUE } else { 0 };
+        (DwmSetWindowAttribute)(
+            hwnd,
+            DWMWA_TRANSITIONS_FORCEDISABLED,
+            &value as *const BOOL as *const c_void,
+            mem::size_of::<BOOL>() as u32
+        );
+    }
+
+    // Extends the DWM frame by `margin` pixels on every side into the client area:
+    // a 1px margin makes DWM draw the native drop shadow for windows whose
+    // non-client area was removed in WM_NCCALCSIZE, 0 removes it again
+    fn extend_frame_into_client_area(&self, hwnd: HWND, margin: i32) {
+
+        let DwmExtendFrameIntoClientArea = match self.DwmExtendFrameIntoClientArea {
+            Some(s) => s,
+            None => return,
+        };
+
+        let margins = MARGINS {
+            cxLeftWidth: margin,
+            cxRightWidth: margin,
+            cyTopHeight: margin,
+            cyBottomHeight: margin,
+        };
+
+        (DwmExtendFrameIntoClientArea)(hwnd, &margins);
+    }
+}
+
+impl Drop for DwmFunctions {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        unsafe { FreeLibrary(self._dwmapi_dll_handle); }
+    }
+}
+
+// OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
+struct GlFunctions {
+    _opengl32_dll_handle: Option<HINSTANCE>,
+    functions: Rc<GenericGlContext>, // implements Rc<dyn gleam::Gl>!
+}
+
+impl fmt::Debug for GlFunctions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self._opengl32_dll_handle.map(|f| f as usize).fmt(f)?;
+        Ok(())
+    }
+}
+
+// Receives the messages of a debug OpenGL context (see GlFunctions::install_debug_callback)
+extern "system" fn gl_debug_message_callback(
+    source: u32,
+    type_: u32,
+    id: u32,
+    severity: u32,
+    length: i32,
+    message: *const i8,
+    _user_param: *mut c_void,
+) {
+    const GL_DEBUG_SEVERITY_HIGH: u32 = 0x9146;
+    const GL_DEBUG_SEVERITY_MEDIUM: u32 = 0x9147;
+    const GL_DEBUG_SEVERITY_LOW: u32 = 0x9148;
+
+    if message.is_null() {
+        return;
+    }
+
+    let message = if length < 0 {
+        unsafe { std::ffi::CStr::from_ptr(message) }.to_string_lossy().into_owned()
+    } else {
+        let bytes = unsafe { core::slice::from_raw_parts(message as *const u8, length as usize) };
+        String::from_utf8_lossy(bytes).into_owned()
+    };
+
+    #[cfg(feature = "logging")] {
+        match severity {
+            GL_DEBUG_SEVERITY_HIGH => {
+                error!("OpenGL error (source 0x{:x}, type 0x{:x}, id {}): {}", source, type_, id, message);
+            },
+            GL_DEBUG_SEVERITY_MEDIUM | GL_DEBUG_SEVERITY_LOW => {
+                warn!("OpenGL warning (source 0x{:x}, type 0x{:x}, id {}): {}", source, type_, id, message);
+            },
+            _ => { }, // GL_DEBUG_SEVERITY_NOTIFICATION: too noisy
+        }
+    }
+}
+
+impl GlFunctions {
+    // Initializes the DLL, but does not load the functions yet
+    fn initialize() -> Self {
+        // zero-initialize all function pointers
+        let context: GenericGlContext = unsafe { mem::zeroed() };
+
+        let opengl32_dll = load_dll("opengl32.dll");
+
+        Self {
+            _opengl32_dll_handle: opengl32_dll,
+            functions: Rc::new(context),
+        }
+    }
+
+    // Assuming the (debug) OpenGL context is current, forwards all GL errors and
+    // warnings to the logger - only called for debug contexts, since synchronous
+    // debug output slows down every GL call
+    fn install_debug_callback(&self) {
+
+        const GL_DEBUG_OUTPUT: u32 = 0x92E0;
+        const GL_DEBUG_OUTPUT_SYNCHRONOUS: u32 = 0x8242;
+
+        type GlDebugProc = extern "system" fn(u32, u32, u32, u32, i32, *const i8, *mut c_void);
+        type GlDebugMessageCallback = extern "system" fn(GlDebugProc, *const c_void);
+
+        let glDebugMessageCallback = self.functions.glDebugMessageCallback;
+        if glDebugMessageCallback.is_null() {
+            return; // GL < 4.3 without KHR_debug
+        }
+
+        let glDebugMessageCallback: GlDebugMessageCallback = unsafe { mem::transmute(glDebugMessageCallback) };
+
+        self.functions.enable(GL_DEBUG_OUTPUT);
+        // report errors on the thread / call that caused them
+        self.functions.enable(GL_DEBUG_OUTPUT_SYNCHRONOUS);
+        (glDebugMessageCallback)(gl_debug_message_callback, ptr::null());
+    }
+
+    // Assuming the OpenGL context is current, loads the OpenGL function pointers
+    fn load(&mut self) {
+        fn get_func(s: &str, opengl32_dll: Option<HINSTANCE>) -> *mut gl_context_loader::c_void {
+            use winapi::um::{libloaderapi::GetProcAddress, wingdi::wglGetProcAddress};
+
+            let mut func_name = encode_ascii(s);
+            let addr1 = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+            (if addr1 != ptr::null_mut() {
+                addr1
+            } else {
+                if let Some(opengl32_dll) = opengl32_dll {
+                    unsafe { GetProcAddress(opengl32_dll, func_name.as_mut_ptr()) }
+                } else {
+                    addr1
+                }
+            }) as *mut gl_context_loader::c_void
+        }
+
+        self.functions = Rc::new(GenericGlContext {
+            glAccum: get_func("glAccum", self._opengl32_dll_handle),
+            glActiveTexture: get_func("glActiveTexture", self._opengl32_dll_handle),
+            glAlphaFunc: get_func("glAlphaFunc", self._opengl32_dll_handle),
+            glAreTexturesResident: get_func("glAreTexturesResident", self._opengl32_dll_handle),
+            glArrayElement: get_func("glArrayElement", self._opengl32_dll_handle),
+            glAttachShader: get_func("glAttachShader", self._opengl32_dll_handle),
+            glBegin: get_func("glBegin", self._opengl32_dll_handle),
+            glBeginConditionalRender: get_func(
                 "glBeginConditionalRender",
                 self._opengl32_dll_handle,
             ),
@@ -1431,1478 +4719,6063 @@ impl GlFunctions {
             glWindowPos3sv: get_func("glWindowPos3sv", self._opengl32_dll_handle),
         });
     }
-}
+}
+
+impl Drop for GlFunctions {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        if let Some(opengl32) = self._opengl32_dll_handle {
+            unsafe {
+                FreeLibrary(opengl32);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ExtraWglFunctions {
+    wglCreateContextAttribsARB: Option<extern "system" fn(HDC, HGLRC, *const i32) -> HGLRC>,
+    wglSwapIntervalEXT: Option<extern "system" fn(i32) -> i32>,
+    wglChoosePixelFormatARB: Option<extern "system" fn(HDC, *const i32, *const f32, u32, *mut i32, *mut u32) -> BOOL>,
+}
+
+impl fmt::Debug for ExtraWglFunctions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.wglCreateContextAttribsARB.map(|f| f as usize).fmt(f)?;
+        self.wglSwapIntervalEXT.map(|f| f as usize).fmt(f)?;
+        self.wglChoosePixelFormatARB.map(|f| f as usize).fmt(f)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum ExtraWglFunctionsLoadError {
+    FailedToCreateDummyWindow,
+    FailedToFindPixelFormat,
+    FailedToSetPixelFormat,
+    FailedToCreateDummyGlContext,
+    FailedToActivateDummyGlContext,
+}
+
+impl ExtraWglFunctions {
+    // class_name: window class of the application, used for a hidden dummy window
+    pub fn load(class_name: &str) -> Result<Self, ExtraWglFunctionsLoadError> {
+
+        use winapi::um::{
+            libloaderapi::GetModuleHandleW,
+            winuser::{
+                CreateWindowExW, GetDC,
+                ReleaseDC, DestroyWindow,
+                CW_USEDEFAULT
+            },
+            wingdi::{
+                wglGetProcAddress, ChoosePixelFormat,
+                SetPixelFormat, wglCreateContext,
+                wglMakeCurrent, wglDeleteContext,
+            },
+        };
+        use self::ExtraWglFunctionsLoadError::*;
+
+        unsafe {
+
+            let mut hidden_class_name = encode_wide(class_name);
+            let mut hidden_window_title = encode_wide("Dummy Window");
+
+            let dummy_window = CreateWindowExW(
+                0,
+                hidden_class_name.as_mut_ptr(),
+                hidden_window_title.as_mut_ptr(),
+                0,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                GetModuleHandleW(ptr::null_mut()),
+                ptr::null_mut(),
+            );
+
+            if dummy_window.is_null() {
+                return Err(FailedToCreateDummyWindow);
+            }
+
+            let dummy_dc = GetDC(dummy_window);
+
+            let mut pfd = get_default_pfd();
+
+            let pixel_format = ChoosePixelFormat(dummy_dc, &pfd);
+            if pixel_format == 0 {
+                return Err(FailedToFindPixelFormat);
+            }
+
+            if SetPixelFormat(dummy_dc, pixel_format, &pfd) != TRUE {
+                return Err(FailedToSetPixelFormat);
+            }
+
+            let dummy_context = wglCreateContext(dummy_dc);
+            if dummy_context.is_null() {
+                return Err(FailedToCreateDummyGlContext);
+            }
+
+            if wglMakeCurrent(dummy_dc, dummy_context) != TRUE {
+                return Err(FailedToActivateDummyGlContext);
+            }
+
+            let mut extra_functions = ExtraWglFunctions::default();
+
+            extra_functions.wglChoosePixelFormatARB = {
+                let mut func_name_1 = encode_ascii("wglChoosePixelFormatARB");
+                let mut func_name_2 = encode_ascii("wglChoosePixelFormatEXT");
+
+                let wgl1_result = unsafe { wglGetProcAddress(func_name_1.as_mut_ptr()) };
+                let wgl2_result = unsafe { wglGetProcAddress(func_name_2.as_mut_ptr()) };
+
+                if wgl1_result != ptr::null_mut() {
+                    Some(unsafe { mem::transmute(wgl1_result) })
+                } else if wgl2_result != ptr::null_mut() {
+                    Some(unsafe { mem::transmute(wgl2_result) })
+                } else {
+                    None
+                }
+            };
+
+            extra_functions.wglCreateContextAttribsARB = {
+                let mut func_name = encode_ascii("wglCreateContextAttribsARB");
+                let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+                if proc_address == ptr::null_mut() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(proc_address) })
+                }
+            };
+
+            extra_functions.wglSwapIntervalEXT = {
+                let mut func_name = encode_ascii("wglSwapIntervalEXT");
+                let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+                if proc_address == ptr::null_mut() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(proc_address) })
+                }
+            };
+
+            wglMakeCurrent(dummy_dc, ptr::null_mut());
+            wglDeleteContext(dummy_context);
+            ReleaseDC(dummy_window, dummy_dc);
+            DestroyWindow(dummy_window);
+
+            return Ok(extra_functions);
+        }
+    }
+}
+
+struct Window {
+    /// HWND handle of the plaform window
+    hwnd: HWND,
+    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
+    internal: WindowInternal,
+    /// OpenGL context handle - None if running in software mode
+    gl_context: Option<HGLRC>,
+    /// Version / profile of the OpenGL context that was actually created
+    gl_context_options: GlContextOptions,
+    /// OpenGL functions for faster rendering
+    gl_functions: GlFunctions,
+    /// OpenGL context pointer with compiled SVG and FXAA shaders
+    gl_context_ptr: OptionGlContextPtr,
+    /// Main render API that can be used to register and un-register fonts and images
+    render_api: WrRenderApi,
+    /// WebRender renderer implementation (software or hardware)
+    renderer: Option<WrRenderer>,
+    /// Hit-tester, lazily initialized and updated every time the display list changes layout
+    hit_tester: AsyncHitTester,
+    /// ID -> Callback map for the window menu (default: empty map)
+    menu_bar: Option<WindowsMenuBar>,
+    /// ID -> Context menu callbacks (cleared when the context menu closes)
+    context_menu: Option<CurrentContextMenu>,
+    /// Timer ID -> Win32 timer map
+    timers: BTreeMap<TimerId, TIMERPTR>,
+    /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
+    thread_timer_running: Option<TIMERPTR>,
+    /// characters are combined via two following wparam messages
+    high_surrogate: Option<u16>,
+    /// Whether the `VirtualKeyDown` callbacks of the focused node handled the last key press
+    key_down_handled: bool,
+    /// Overlay documents, composited on top of the main document (bottom to top)
+    overlays: Vec<OverlayLayer>,
+    /// ID of the last `invalidate_frame` request that hasn't been presented yet (0 = none)
+    pending_frame_request: usize,
+    /// Non-rectangular window shape, re-applied on every resize
+    window_region: Option<WindowRegion>,
+    /// ShowWindow() command to run after the first frame has been presented
+    /// (only set if the window was created with `show_after_first_frame`)
+    show_after_first_frame: Option<i32>,
+    /// Callback to invoke once the first frame has been presented
+    first_frame_callback: Option<Callback>,
+    /// wglSwapIntervalEXT of the current OpenGL context (None if the driver doesn't support swap control)
+    swap_interval_fn: Option<extern "system" fn(i32) -> i32>,
+    /// Present mode that is currently applied to the OpenGL context
+    /// (None = not applied yet, re-applied when `renderer_options.present_mode` changes)
+    present_mode: Option<PresentModeStatus>,
+    /// Time of the last SwapBuffers, used for pacing `PresentMode::FrameCapped`
+    last_present: Option<std::time::Instant>,
+    /// Set while a WM_PAINT was postponed until the AZ_FRAME_CAP_TICK timer fires
+    frame_cap_pending: bool,
+    /// Cursor of the hovered node (CSS `cursor` property), overrides
+    /// `mouse_state.mouse_cursor_type` while the node is hovered
+    node_cursor: Option<MouseCursorType>,
+    /// Cursor created from an image by `set_custom_cursor`, overrides
+    /// `mouse_state.mouse_cursor_type` (but not the `node_cursor`)
+    custom_cursor: Option<CustomCursor>,
+    /// Set while the window is disabled by `set_window_busy`
+    busy: Option<BusyState>,
+    /// `AppConfig::hit_test_tolerance`, the tolerance that is used
+    /// depends on the `pointer_input_type` of the last mouse message
+    hit_test_tolerance: HitTestTolerance,
+    /// Device that generated the last mouse message (touch and pen
+    /// input is promoted to mouse messages by Windows)
+    pointer_input_type: PointerInputType,
+    /// `WindowCreateOptions::initial_focus`, applied again after the DOM
+    /// regeneration that follows the creation of the window (which unsets the focus)
+    initial_focus: Option<FocusTarget>,
+    /// System colors when they were last read, compared against on
+    /// WM_SETTINGCHANGE / WM_SYSCOLORCHANGE to detect theme changes
+    system_colors: SystemColors,
+    /// Ctrl + mouse wheel notches that haven't been applied to the content scale
+    /// yet (high-resolution wheels / touchpads send fractions of a notch)
+    wheel_zoom_delta: f32,
+    /// GL vendor / renderer / version and whether the window is hardware-accelerated
+    renderer_info: RendererInfo,
+    /// Compiled shader programs, shared with the renderer (optionally backed by
+    /// `AppConfig::shader_cache_dir`)
+    program_cache: Rc<WrProgramCache>,
+    /// Whether the programs used for the first frame were already handed to the cache
+    program_cache_startup_complete: bool,
+    /// Whether the window was created in / last rendered to a remote desktop session,
+    /// compared against on WM_WTSSESSION_CHANGE to recreate the renderer
+    is_remote_session: bool,
+    /// Set while the session is locked or disconnected, rendering is paused
+    /// until the session is unlocked / reconnected
+    session_locked: bool,
+    session_disconnected: bool,
+    /// Set while the window is minimized or cloaked: timers that don't run
+    /// while hidden are stopped and WM_PAINT doesn't render
+    is_hidden: bool,
+    /// Set while WM_PAINT skips rendering because the window is fully covered by
+    /// other windows (WindowsWindowOptions::skip_rendering_when_occluded)
+    is_occluded: bool,
+    /// How long loading and rasterizing the `AppConfig::font_warmup`
+    /// fonts took (None if there was no warm-up)
+    font_warmup_time: Option<Duration>,
+    /// Native child windows attached to DOM nodes that were positioned in the last
+    /// frame, see `NodeData::set_native_child_window`
+    native_child_windows: BTreeSet<usize>,
+    /// Visible rects of the title bar nodes of the last frame, used in
+    /// WM_NCHITTEST, see `NodeData::set_title_bar_region`
+    title_bar_regions: Vec<(LogicalRect, TitleBarRegion)>,
+}
+
+/// Input device that generated a mouse message
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PointerInputType {
+    Mouse,
+    Pen,
+    Touch,
+}
+
+impl PointerInputType {
+    // Mouse messages that were promoted from pen / touch input carry a
+    // signature in their extra info, see "System Events and Mouse Messages"
+    fn of_current_message() -> Self {
+
+        use winapi::um::winuser::GetMessageExtraInfo;
+
+        const MI_WP_SIGNATURE: usize = 0xFF515700;
+        const SIGNATURE_MASK: usize = 0xFFFFFF00;
+        const TOUCH_FLAG: usize = 0x80;
+
+        let extra_info = unsafe { GetMessageExtraInfo() } as usize;
+        if extra_info & SIGNATURE_MASK != MI_WP_SIGNATURE {
+            PointerInputType::Mouse
+        } else if extra_info & TOUCH_FLAG != 0 {
+            PointerInputType::Touch
+        } else {
+            PointerInputType::Pen
+        }
+    }
+}
+
+/// State of a window disabled by `set_window_busy`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct BusyState {
+    /// Index of the spinner overlay layer
+    overlay: Option<usize>,
+    /// Whether the window had the keyboard focus before it was disabled
+    had_focus: bool,
+}
+
+/// Separate webrender document that is rendered on top of the window content
+struct OverlayLayer {
+    internal: WindowInternal,
+    hit_tester: AsyncHitTester,
+}
+
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.hwnd.fmt(f)?;
+        self.internal.fmt(f)?;
+        self.gl_context.fmt(f)?;
+        self.gl_context_ptr.fmt(f)?;
+        self.renderer.is_some().fmt(f)?;
+        self.menu_bar.fmt(f)?;
+        self.context_menu.fmt(f)?;
+        self.high_surrogate.fmt(f)?;
+        self.overlays.len().fmt(f)?;
+        Ok(())
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        use winapi::um::wingdi::{wglMakeCurrent, wglDeleteContext};
+
+        // drop the layout results first
+        self.internal.layout_results = Vec::new();
+        self.overlays.clear();
+
+        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+
+        if let Some(context) = self.gl_context.as_mut() {
+            unsafe { wglDeleteContext(*context); }
+        }
+
+        if let Some(renderer) = self.renderer.take() {
+            renderer.deinit();
+        }
+    }
+}
+
+// HCURSOR created with CreateIconIndirect, destroyed when the cursor is
+// replaced or the window is closed. The source image is kept so that the
+// cursor can be re-created at the new size when the DPI changes.
+#[derive(Debug)]
+struct CustomCursor {
+    image: RawImage,
+    hotspot: LogicalPosition,
+    hcursor: HCURSOR,
+}
+
+impl CustomCursor {
+
+    fn new(image: RawImage, hotspot: LogicalPosition, hidpi_factor: f32) -> Option<Self> {
+        let hcursor = create_cursor_from_image(&image, hotspot, hidpi_factor)?;
+        Some(Self { image, hotspot, hcursor })
+    }
+}
+
+impl Drop for CustomCursor {
+    fn drop(&mut self) {
+        use winapi::um::winuser::DestroyIcon;
+        // cursors created by CreateIconIndirect are destroyed with DestroyIcon
+        unsafe { DestroyIcon(self.hcursor as *mut _); }
+    }
+}
+
+#[derive(Debug)]
+struct CurrentContextMenu {
+    callbacks: BTreeMap<u16, MenuCallback>,
+    hit_dom_node: DomNodeId,
+}
+
+impl Window {
+
+    fn get_id(&self) -> usize {
+        self.hwnd as usize
+    }
+
+    // Size of the default framebuffer in physical pixels (= the client area), the
+    // single source of truth for rendering, glViewport and readbacks. Differs from
+    // the physical size of the logical window size if the content is letterboxed
+    // (`fixed_logical_size`) and is momentarily out of sync with it during a resize.
+    fn get_framebuffer_size(&self) -> WrDeviceIntSize {
+        let size = get_framebuffer_size(self.hwnd);
+        WrDeviceIntSize::new(size.width as i32, size.height as i32)
+    }
+
+    fn get_gpu_memory_usage(&self) -> GpuMemoryUsage {
+
+        let mut usage = GpuMemoryUsage {
+            registered_image_bytes: self.internal.renderer_resources.get_registered_image_bytes(),
+            .. GpuMemoryUsage::default()
+        };
+
+        if let Some(r) = self.renderer.as_ref() {
+            let report = r.report_memory(ptr::null_mut());
+            usage.texture_cache_bytes = report.texture_cache_textures;
+            usage.render_target_bytes = report.render_target_textures + report.depth_target_textures;
+            usage.gpu_cache_bytes = report.gpu_cache_textures + report.vertex_data_textures;
+        }
+
+        usage.total_bytes = usage.texture_cache_bytes + usage.render_target_bytes + usage.gpu_cache_bytes;
+        usage
+    }
+
+    // Asks the renderer to evict its caches (texture cache, glyph cache, GPU cache)
+    // if the GPU memory usage exceeds the budget - the evicted resources are
+    // re-uploaded on demand when the next frame needs them
+    fn enforce_gpu_memory_budget(&mut self, budget: GpuMemoryBudget) {
+
+        if budget.is_unlimited() {
+            return;
+        }
+
+        let usage = self.get_gpu_memory_usage();
+        if usage.total_bytes <= budget.max_bytes {
+            return;
+        }
+
+        #[cfg(feature = "logging")] {
+            warn!(
+                "GPU memory usage ({} bytes) exceeds the budget ({} bytes), evicting renderer caches",
+                usage.total_bytes, budget.max_bytes
+            );
+        }
+
+        self.render_api.notify_memory_pressure();
+    }
+
+    // Cursor over the client area: the cursor of the hovered node or, if the node
+    // doesn't specify one, the custom cursor or the cursor of the window
+    // (null = cursor is hidden)
+    fn get_current_cursor(&self) -> HCURSOR {
+        if self.busy.is_some() {
+            return load_cursor(Some(MouseCursorType::Progress));
+        }
+        match (self.node_cursor, self.custom_cursor.as_ref()) {
+            (Some(s), _) => load_cursor(Some(s)),
+            (None, Some(custom)) => custom.hcursor,
+            (None, None) => load_cursor(self.internal.current_window_state.mouse_state.mouse_cursor_type.into_option()),
+        }
+    }
+
+    // Replaces the custom cursor (destroying the previous HCURSOR) and applies it
+    fn set_custom_cursor(&mut self, cursor: Option<(RawImage, LogicalPosition)>) -> bool {
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let (new_cursor, success) = match cursor {
+            Some((image, hotspot)) => match CustomCursor::new(image, hotspot, hidpi_factor) {
+                Some(c) => (Some(c), true),
+                None => (None, false),
+            },
+            None => (None, true),
+        };
+        self.custom_cursor = new_cursor;
+        self.update_cursor();
+        success
+    }
+
+    // Ctrl + mouse wheel: changes the content scale by one step per wheel notch,
+    // keeping the content under the cursor at the same position
+    fn zoom_with_wheel(&mut self, notches: f32) {
+
+        // the content scale of a window with a fixed logical size follows the window size
+        if self.internal.current_window_state.size.fixed_logical_size.is_some() {
+            return;
+        }
+
+        self.wheel_zoom_delta += notches;
+        if self.wheel_zoom_delta.abs() < 1.0 {
+            return;
+        }
+
+        let size = &self.internal.current_window_state.size;
+        let new_content_scale = if self.wheel_zoom_delta > 0.0 {
+            size.get_zoomed_in_scale()
+        } else {
+            size.get_zoomed_out_scale()
+        };
+        self.wheel_zoom_delta = 0.0;
+
+        self.zoom_around_cursor(new_content_scale);
+    }
+
+    // Sets the content scale and scrolls the hovered scroll container so that
+    // the point under the cursor stays fixed
+    fn zoom_around_cursor(&mut self, new_content_scale: f32) {
+
+        use azul_core::window::CursorPosition;
+
+        let old_content_scale = self.internal.current_window_state.size.content_scale;
+        if new_content_scale == old_content_scale {
+            return;
+        }
+
+        self.internal.current_window_state.size.content_scale = new_content_scale;
+
+        if let CursorPosition::InWindow(old_pos) = self.internal.current_window_state.mouse_state.cursor_position {
+
+            // the physical cursor position doesn't change, but its logical position does
+            let ratio = old_content_scale / new_content_scale;
+            let new_pos = LogicalPosition::new(old_pos.x * ratio, old_pos.y * ratio);
+            self.internal.current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(new_pos);
+
+            // content point under the cursor = scroll position + cursor position,
+            // so the scroll position has to move by the difference
+            let scroll_node = self.internal.current_window_state.last_hit_test.hovered_nodes
+                .values()
+                .find_map(|ht| ht.scroll_hit_test_nodes.values().next())
+                .map(|item| item.scroll_node);
+
+            if let Some(scroll_node) = scroll_node {
+                self.internal.scroll_states.scroll_node(&scroll_node, old_pos.x - new_pos.x, old_pos.y - new_pos.y);
+            }
+        }
+
+        request_relayout(self.hwnd, self.internal.current_window_state.flags.frame);
+    }
+
+    // Re-creates the custom cursor at the size for the new DPI
+    fn rescale_custom_cursor(&mut self, hidpi_factor: f32) {
+        let (image, hotspot) = match self.custom_cursor.as_ref() {
+            Some(c) => (c.image.clone(), c.hotspot),
+            None => return,
+        };
+        // keep the old cursor if the new one can't be created
+        if let Some(c) = CustomCursor::new(image, hotspot, hidpi_factor) {
+            self.custom_cursor = Some(c);
+            self.update_cursor();
+        }
+    }
+
+    // Applies the current cursor immediately if the mouse is over the client area,
+    // otherwise it is applied by the next WM_SETCURSOR
+    fn update_cursor(&self) {
+        if self.internal.current_window_state.mouse_state.cursor_position.is_inside_window() {
+            unsafe { winapi::um::winuser::SetCursor(self.get_current_cursor()); }
+        }
+    }
+
+    // Returns the WM_GETDLGCODE flags for the currently focused node, so that
+    // a window hosted inside a dialog still receives the keys it needs:
+    // text inputs want all keys, nodes with key handlers want arrows + Tab,
+    // clickable nodes want Enter
+    fn get_dialog_code(&self, msg: Option<&MSG>) -> LRESULT {
+
+        use azul_core::dom::{EventFilter, FocusEventFilter, HoverEventFilter, WindowEventFilter};
+        use winapi::um::winuser::{
+            DLGC_WANTALLKEYS, DLGC_WANTARROWS, DLGC_WANTCHARS,
+            DLGC_WANTMESSAGE, DLGC_WANTTAB, VK_RETURN, WM_KEYDOWN,
+        };
+
+        let focused_node = match self.internal.current_window_state.focused_node {
+            Some(s) => s,
+            None => return 0,
+        };
+
+        let events = focused_node.node.into_crate_internal().and_then(|node_id| {
+            let layout_result = self.internal.layout_results.get(focused_node.dom.inner)?;
+            let node_data = layout_result.styled_dom.node_data.as_container();
+            Some(node_data.get(node_id)?.get_callbacks().iter().map(|c| c.event).collect::<Vec<_>>())
+        }).unwrap_or_default();
+
+        let wants_text_input = events.iter().any(|e| match e {
+            EventFilter::Focus(FocusEventFilter::TextInput) |
+            EventFilter::Hover(HoverEventFilter::TextInput) |
+            EventFilter::Window(WindowEventFilter::TextInput) => true,
+            _ => false,
+        });
+
+        if wants_text_input {
+            return (DLGC_WANTALLKEYS | DLGC_WANTCHARS | DLGC_WANTARROWS | DLGC_WANTTAB) as LRESULT;
+        }
+
+        let mut dialog_code = 0;
+
+        let wants_keys = events.iter().any(|e| match e {
+            EventFilter::Focus(FocusEventFilter::VirtualKeyDown) |
+            EventFilter::Focus(FocusEventFilter::VirtualKeyUp) |
+            EventFilter::Hover(HoverEventFilter::VirtualKeyDown) |
+            EventFilter::Hover(HoverEventFilter::VirtualKeyUp) => true,
+            _ => false,
+        });
+
+        if wants_keys {
+            dialog_code |= DLGC_WANTARROWS | DLGC_WANTTAB;
+        }
+
+        let is_clickable = events.iter().any(|e| match e {
+            EventFilter::Hover(HoverEventFilter::MouseUp) |
+            EventFilter::Hover(HoverEventFilter::LeftMouseUp) |
+            EventFilter::Focus(FocusEventFilter::MouseUp) |
+            EventFilter::Focus(FocusEventFilter::LeftMouseUp) => true,
+            _ => false,
+        });
+
+        let is_enter_key = msg
+            .map(|m| m.message == WM_KEYDOWN && m.wParam as i32 == VK_RETURN)
+            .unwrap_or(false);
+
+        if is_clickable && is_enter_key {
+            dialog_code |= DLGC_WANTMESSAGE;
+        }
+
+        dialog_code as LRESULT
+    }
+
+    // Re-reads the system colors and regenerates the DOM if they changed
+    fn update_system_colors(&mut self) {
+        use winapi::um::winuser::PostMessageW;
+        let system_colors = get_system_colors();
+        if system_colors != self.system_colors {
+            self.system_colors = system_colors;
+            unsafe { PostMessageW(self.hwnd, AZ_REGENERATE_DOM, 0, 0); }
+        }
+    }
+
+    // Returns the FocusReceived callbacks of the given node, wrapped as
+    // MenuCallbacks so that they can be invoked outside of a hit-test
+    fn get_focus_received_callbacks(&self, node: DomNodeId) -> Vec<MenuCallback> {
+
+        use azul_core::dom::{EventFilter, FocusEventFilter};
+
+        node.node.into_crate_internal().and_then(|node_id| {
+            let layout_result = self.internal.layout_results.get(node.dom.inner)?;
+            let node_data = layout_result.styled_dom.node_data.as_container();
+            Some(
+                node_data.get(node_id)?.get_callbacks().iter()
+                .filter(|c| c.event == EventFilter::Focus(FocusEventFilter::FocusReceived))
+                .map(|c| MenuCallback { callback: c.callback.clone(), data: c.data.clone() })
+                .collect::<Vec<_>>()
+            )
+        }).unwrap_or_default()
+    }
+
+    // Creates a new HWND according to the options
+    fn create(
+        hinstance: HINSTANCE,
+        mut options: WindowCreateOptions,
+        mut shared_application_data: SharedApplicationData,
+    ) -> Result<Self, WindowsWindowCreateError> {
+
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{
+                translate_document_id_wr,
+                translate_id_namespace_wr,
+                wr_translate_document_id,
+            },
+        };
+        use azul_core::{
+            callbacks::PipelineId,
+            gl::GlContextPtr,
+            window::{
+                CursorPosition, HwAcceleration,
+                LogicalPosition, ScrollResult,
+                PhysicalSize, RendererType,
+                WindowInternalInit, FullHitTest,
+                WindowFrame,
+            },
+        };
+        use winapi::{
+            shared::windef::POINT,
+            um::{
+                wingdi::{
+                    wglDeleteContext, wglMakeCurrent,
+                    SwapBuffers, GetDeviceCaps,
+                    LOGPIXELSX, LOGPIXELSY
+                },
+                winuser::{
+                    CreateWindowExW, DestroyWindow, GetClientRect, GetCursorPos, GetDC,
+                    GetWindowRect, ReleaseDC, ScreenToClient, SetMenu, CW_USEDEFAULT, WS_CAPTION,
+                    WS_EX_ACCEPTFILES, WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
+                    WS_OVERLAPPED, WS_POPUP, WS_SYSMENU, WS_TABSTOP, WS_THICKFRAME,
+                    WS_CHILD, WS_CLIPSIBLINGS,
+                    ShowWindow, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_NORMAL, SW_SHOWNORMAL,
+                },
+            },
+        };
+        use winapi::um::winuser::{
+            SetWindowPos, HWND_TOP, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOZORDER,
+        };
+        let parent_window = match options
+            .state
+            .platform_specific_options
+            .windows_options
+            .parent_window
+            .as_ref()
+        {
+            Some(hwnd) => (*hwnd) as HWND,
+            None => ptr::null_mut(),
+        };
+
+        let embed_in_parent = options
+            .state
+            .platform_specific_options
+            .windows_options
+            .embed_in_parent && !parent_window.is_null();
+
+        // embedded windows are drawn inside the client
+        // area of the parent, the parent must not draw over them
+        if embed_in_parent {
+            use winapi::um::winuser::{
+                GetWindowLongPtrW, SetWindowLongPtrW, GWL_STYLE, WS_CLIPCHILDREN,
+            };
+            unsafe {
+                let parent_style = GetWindowLongPtrW(parent_window, GWL_STYLE);
+                SetWindowLongPtrW(parent_window, GWL_STYLE, parent_style | WS_CLIPCHILDREN as isize);
+            }
+        }
+
+        let (window_style, window_ex_style) = if embed_in_parent {
+            (WS_CHILD | WS_CLIPSIBLINGS | WS_TABSTOP, WS_EX_ACCEPTFILES)
+        } else {
+            (
+                WS_OVERLAPPED
+                    | WS_CAPTION
+                    | WS_SYSMENU
+                    | WS_THICKFRAME
+                    | WS_MINIMIZEBOX
+                    | WS_MAXIMIZEBOX
+                    | WS_TABSTOP
+                    | WS_POPUP,
+                taskbar_ex_style(options.state.flags.is_shown_in_taskbar) | WS_EX_ACCEPTFILES,
+            )
+        };
+
+        let (window_class_name, shader_cache_dir) = {
+            let app_borrow = shared_application_data.inner
+                .try_borrow_mut()
+                .map_err(WindowsWindowCreateError::BorrowMut)?;
+            (
+                app_borrow.config.window_class_name.clone(),
+                app_borrow.config.shader_cache_dir.clone(),
+            )
+        };
+
+        let mut class_name = encode_wide(window_class_name.as_str());
+        let mut window_title = encode_wide(options.state.title.as_str());
+
+        let data_ptr = Box::into_raw(Box::new(shared_application_data.clone())) as *mut SharedApplicationData as *mut c_void;
+
+        // Create the window
+        let hwnd = unsafe {
+            CreateWindowExW(
+                window_ex_style,
+                class_name.as_mut_ptr(),
+                window_title.as_mut_ptr(),
+                window_style,
+                // Size and position: set later, after DPI factor has been queried
+                CW_USEDEFAULT, // x
+                CW_USEDEFAULT, // y
+                if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.width) as i32 }, // width
+                if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.height) as i32 }, // height
+                parent_window,
+                ptr::null_mut(), // Menu
+                hinstance,
+                data_ptr,
+            )
+        };
+
+        if hwnd.is_null() {
+            return Err(WindowsWindowCreateError::FailedToCreateHWND(
+                get_last_error(),
+            ));
+        }
+
+        register_input_log_window(hwnd);
+
+        // Get / store DPI
+        // NOTE: GetDpiForWindow would be easier, but it's Win10 only
+        let dpi = if let Ok(s) = shared_application_data.inner.try_borrow() {
+            unsafe { s.dpi.hwnd_dpi(hwnd) }
+        } else {
+            96
+        };
+
+        let dpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+
+        options.state.size.dpi = dpi;
+        options.state.accessibility = get_accessibility_settings();
+
+        // Window created, now try initializing OpenGL context
+        let renderer_types = if is_hardware_rendering_disabled() {
+            // the GPU / driver failed repeatedly earlier in this session
+            vec![RendererType::Software]
+        } else {
+            match options.renderer.into_option() {
+                Some(s) => match s.hw_accel {
+                    HwAcceleration::DontCare => vec![RendererType::Hardware, RendererType::Software],
+                    HwAcceleration::Enabled => vec![RendererType::Hardware],
+                    HwAcceleration::Disabled => vec![RendererType::Software],
+                },
+                None => vec![RendererType::Hardware, RendererType::Software],
+            }
+        };
+
+        let mut opengl_context: Option<HGLRC> = None;
+        let mut rt = RendererType::Software;
+        let mut extra = ExtraWglFunctions::load(window_class_name.as_str())?;
+        let mut gl = GlFunctions::initialize();
+        let mut gl_context_ptr: OptionGlContextPtr = None.into();
+        let mut gl_context_options = options.gl_context;
+
+        let shared_gl_context = options.state.platform_specific_options.windows_options.shared_gl_context
+            .into_option()
+            .unwrap_or(ptr::null_mut()) as HGLRC;
+
+        for r in renderer_types {
+            rt = r;
+            match r {
+                RendererType::Software => {}
+                RendererType::Hardware => {
+                    match create_gl_context(hwnd, hinstance, &extra, &options.gl_context, shared_gl_context) {
+                        Ok((o, version)) => {
+                            opengl_context = Some(o);
+                            gl_context_options = version;
+                            break;
+                        },
+                        // falling back to software rendering would silently break the
+                        // texture exchange with the external context: fail instead
+                        Err(e @ WindowsOpenGlError::IncompatibleSharedContext(_)) => {
+                            unsafe { DestroyWindow(hwnd); }
+                            return Err(WindowsWindowCreateError::SharedGlContext(e));
+                        },
+                        Err(_) => { },
+                    }
+                }
+            }
+        }
+
+        let mut renderer_info = RendererInfo::software();
+
+        gl_context_ptr = opengl_context
+            .map(|hrc| unsafe {
+                let hdc = GetDC(hwnd);
+                unsafe { wglMakeCurrent(hdc, hrc) };
+                gl.load();
+                if gl_context_options.debug {
+                    gl.install_debug_callback();
+                }
+                // compiles SVG and FXAA shader programs...
+                let ptr = GlContextPtr::new(rt, gl.functions.clone());
+                renderer_info = RendererInfo::from_gl_context(&ptr);
+
+                // NOTE: the swap interval (vsync) is applied on the
+                // first frame, see Window::apply_present_mode
+
+                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+                ReleaseDC(hwnd, hdc);
+                ptr
+            })
+            .into();
+
+
+        // WindowInternal::new() may dispatch OpenGL calls,
+        // need to make context current before invoking
+        let hdc = unsafe { GetDC(hwnd) };
+        if let Some(hrc) = opengl_context.as_mut() {
+            unsafe { wglMakeCurrent(hdc, *hrc) };
+        }
+
+        let program_cache = crate::program_cache::create_program_cache(
+            shader_cache_dir.as_ref().map(|s| s.as_str()),
+            renderer_info.vendor.as_str(),
+            renderer_info.renderer.as_str(),
+            renderer_info.version.as_str(),
+        );
+
+        // Invoke callback to initialize UI for the first time
+        let (mut renderer, sender) = match create_webrender_renderer(
+            gl.functions.clone(),
+            &options.state.debug_state,
+            program_cache.clone(),
+        ) {
+            Ok(o) => o,
+            Err(e) => unsafe {
+                if let Some(hrc) = opengl_context.as_mut() {
+                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                    wglDeleteContext(*hrc);
+                }
+                ReleaseDC(hwnd, hdc);
+                DestroyWindow(hwnd);
+                return Err(WindowsWindowCreateError::Renderer(e));
+            },
+        };
+
+
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        let mut render_api = sender.create_api();
+
+        // Query the current size of the window
+        let physical_size = if options.size_to_content {
+            PhysicalSize {
+                width: 0,
+                height: 0,
+            }
+        } else {
+            let mut rect: RECT = unsafe { mem::zeroed() };
+            let current_window_size = unsafe { GetClientRect(hwnd, &mut rect) }; // not DPI adjusted: physical pixels
+            PhysicalSize {
+                width: rect.width(),
+                height: rect.height(),
+            }
+        };
+
+        options.state.size.dimensions = physical_size.to_logical(dpi_factor);
+
+        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
+        let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+        let pipeline_id = PipelineId::new();
+        let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+
+        // hit tester will be empty on startup
+        let hit_tester = render_api
+            .request_hit_tester(wr_translate_document_id(document_id))
+            .resolve();
+        let hit_tester_ref = &*hit_tester;
+
+        // lock the SharedApplicationData in order to
+        // invoke the UI callback for the first time
+        let mut appdata_lock = match shared_application_data.inner.try_borrow_mut() {
+            Ok(o) => o,
+            Err(e) => unsafe {
+                if let Some(hrc) = opengl_context.as_mut() {
+                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                    wglDeleteContext(*hrc);
+                }
+                ReleaseDC(hwnd, hdc);
+                DestroyWindow(hwnd);
+                return Err(WindowsWindowCreateError::BorrowMut(e));
+            },
+        };
+
+        let hit_test_tolerance = appdata_lock.config.hit_test_tolerance;
+
+        let mut initial_resource_updates = Vec::new();
+        let mut internal = {
+
+            let appdata_lock = &mut *appdata_lock;
+            let fc_cache = &mut appdata_lock.fc_cache;
+            let image_cache = &appdata_lock.image_cache;
+            let data = &mut appdata_lock.data;
+
+            fc_cache.apply_closure(|fc_cache| {
+                WindowInternal::new(
+                    WindowInternalInit {
+                        window_create_options: options.clone(),
+                        document_id,
+                        id_namespace,
+                    },
+                    data,
+                    image_cache,
+                    &gl_context_ptr,
+                    &mut initial_resource_updates,
+                    &crate::app::CALLBACKS,
+                    fc_cache,
+                    azul_layout::do_the_relayout,
+                    |window_state, scroll_states, layout_results| {
+                        crate::wr_translate::fullhittest_new_webrender(
+                            hit_tester_ref,
+                            document_id,
+                            window_state.focused_node,
+                            layout_results,
+                            &window_state.mouse_state.cursor_position,
+                            window_state.size.get_hidpi_factor(),
+                            hit_test_tolerance.mouse.get(),
+                        )
+                    },
+                )
+            })
+        };
+
+        // Since the menu bar affects the window size, set it first,
+        // before querying the window size again
+        let mut menu_bar = None;
+        if let Some(m) = internal.get_menu_bar() {
+            let mb = WindowsMenuBar::new(m);
+            unsafe { SetMenu(hwnd, mb._native_ptr); }
+            menu_bar = Some(mb);
+        }
+
+        // If size_to_content is set, query the content size and adjust!
+        if options.size_to_content {
+            let content_size = internal.get_content_size();
+            unsafe {
+                SetWindowPos(
+                    hwnd,
+                    HWND_TOP,
+                    0,
+                    0,
+                    libm::roundf(content_size.width) as i32,
+                    libm::roundf(content_size.height) as i32,
+                    SWP_NOMOVE | SWP_NOZORDER | SWP_FRAMECHANGED,
+                );
+            }
+        }
+
+        // Center the window on its monitor now that the window size is final
+        if options.center_on_show && !embed_in_parent && internal.current_window_state.flags.frame == WindowFrame::Normal {
+            center_window_on_monitor(hwnd);
+        }
+
+        // custom frame: remove the non-client area before the window is shown
+        // (embedded windows don't have a non-client area)
+        if !internal.current_window_state.flags.has_decorations && !embed_in_parent {
+            set_window_decorations(hwnd, false);
+            set_borderless_shadow(hwnd, internal.current_window_state.platform_specific_options.windows_options.borderless_shadow);
+        }
+
+        // the per-window AppUserModelID is only picked up by the taskbar if set before the window is shown
+        if let Some(app_user_model_id) = internal.current_window_state.platform_specific_options.windows_options.app_user_model_id.as_ref() {
+            set_window_app_user_model_id(hwnd, app_user_model_id.as_str());
+        }
+
+        // If the window is maximized on startup, we have to call ShowWindow here
+        // before querying the client area
+        let mut sw_options = SW_HIDE; // 0 = default
+        let mut hidden_sw_options = SW_HIDE; // 0 = default
+        if internal.current_window_state.flags.is_visible {
+            sw_options |= SW_SHOWNORMAL;
+        }
+
+        match internal.current_window_state.flags.frame {
+            WindowFrame::Normal => { sw_options |= SW_NORMAL; hidden_sw_options |= SW_NORMAL; },
+            WindowFrame::Minimized => { sw_options |= SW_MINIMIZE; hidden_sw_options |= SW_MINIMIZE; },
+            WindowFrame::Maximized => { sw_options |= SW_MAXIMIZE; hidden_sw_options |= SW_MAXIMIZE; },
+            WindowFrame::Fullscreen => { sw_options |= SW_MAXIMIZE; hidden_sw_options |= SW_MAXIMIZE; },
+        }
+
+        // NOTE: SW_NORMAL would show the window immediately, a window that
+        // is shown after its first frame only needs this for maximized windows
+        let show_after_first_frame = options.show_after_first_frame;
+        if !show_after_first_frame || hidden_sw_options != SW_NORMAL {
+            unsafe { ShowWindow(hwnd, hidden_sw_options); }
+        }
+
+        // Query the client area from Win32 (not DPI adjusted) and adjust framebuffer
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        let current_window_size = unsafe { GetClientRect(hwnd, &mut rect) };
+        let physical_size = PhysicalSize {
+            width: rect.width(),
+            height: rect.height(),
+        };
+        // internal.previous_window_state = Some(internal.current_window_state.clone());
+        
+        internal.current_window_state.size.dimensions = physical_size.to_logical(dpi_factor);
+
+        let mut txn = WrTransaction::new();
+
+        // re-layout the window content for the first frame
+        // (since the width / height might have changed)
+        let initial_focus_node = {
+            let appdata_lock = &mut *appdata_lock;
+            let fc_cache = &mut appdata_lock.fc_cache;
+            let image_cache = &appdata_lock.image_cache;
+            let size = internal.current_window_state.size.clone();
+            let theme = internal.current_window_state.theme;
+            let resize_result = fc_cache.apply_closure(|fc_cache| {
+                internal.do_quick_resize(
+                    &image_cache,
+                    &crate::app::CALLBACKS,
+                    azul_layout::do_the_relayout,
+                    fc_cache,
+                    &gl_context_ptr,
+                    &size,
+                    theme,
+                )
+            });
+
+            wr_synchronize_updated_images(resize_result.updated_images, &document_id, &mut txn);
+
+            // focus the initial node before the first display list is built,
+            // so that the first frame already shows the :focus styles
+            options.initial_focus.as_ref().and_then(|initial_focus| {
+                apply_initial_focus(&mut internal, initial_focus, image_cache)
+            })
+        };
+
+        if let Some(hrc) = opengl_context.as_ref() {
+            unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+        }
+
+        unsafe { ReleaseDC(hwnd, hdc); }
+
+        txn.set_document_view(
+            WrDeviceIntRect::from_size(
+                WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32),
+            )
+        );
+        render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+
+        render_api.flush_scene_builder();
+
+        // Build the display list and send it to webrender for the first time
+        rebuild_display_list(
+            &mut internal,
+            &mut render_api,
+            &appdata_lock.image_cache,
+            initial_resource_updates,
+        );
+
+        render_api.flush_scene_builder();
+
+        generate_frame(
+            &mut internal,
+            &mut render_api,
+            true,
+        );
+
+        render_api.flush_scene_builder();
+
+        // Pre-load the fonts of the AppConfig::font_warmup list, now that
+        // the renderer is ready (skipped if the list is empty)
+        let font_warmup_time = if appdata_lock.config.font_warmup.is_empty() {
+            None
+        } else {
+            let appdata_lock = &mut *appdata_lock;
+            let font_warmup = appdata_lock.config.font_warmup.as_ref();
+            Some(appdata_lock.fc_cache.apply_closure(|fc_cache| {
+                warm_up_fonts(&mut internal, &mut render_api, fc_cache, font_warmup)
+            }))
+        };
+
+        // Get / store mouse cursor position, now that the window position is final
+        let mut cursor_pos: POINT = POINT { x: 0, y: 0 };
+        unsafe { GetCursorPos(&mut cursor_pos); }
+        unsafe { ScreenToClient(hwnd, &mut cursor_pos) };
+        let cursor_pos_logical = LogicalPosition {
+            x: cursor_pos.x as f32 / dpi_factor,
+            y: cursor_pos.y as f32 / dpi_factor,
+        };
+        internal.current_window_state.mouse_state.cursor_position = if cursor_pos.x <= 0 || cursor_pos.y <= 0 {
+            CursorPosition::Uninitialized
+        } else {
+            CursorPosition::InWindow(cursor_pos_logical)
+        };
+
+        // Update the hit-tester to account for the new hit-testing functionality
+        let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
+
+        // Done! Window is now created properly, display list has been built by
+        // WebRender (window is ready to render), menu bar is visible and hit-tester
+        // now contains the newest UI tree.
+
+        // Taskbar thumbnails / peek previews are rendered by azul
+        // (the DWM can't capture the contents of the OpenGL surface)
+        if opengl_context.is_some() && !embed_in_parent {
+            if let Some(dwm) = appdata_lock.dwm.as_ref() {
+                dwm.enable_iconic_representation(hwnd);
+            }
+        }
+
+        let corner_preference = options.state.platform_specific_options.windows_options.corner_preference;
+        if corner_preference != WindowCornerPreference::Default {
+            if let Some(dwm) = appdata_lock.dwm.as_ref() {
+                dwm.set_corner_preference(hwnd, corner_preference);
+            }
+        }
+
+        if options.hot_reload {
+            use winapi::um::winuser::SetTimer;
+            unsafe { SetTimer(hwnd, AZ_TICK_REGENERATE_DOM, 200, None); }
+        }
+
+        let gpu_memory_budget = appdata_lock.config.gpu_memory_budget;
+        if !gpu_memory_budget.is_unlimited() {
+            use winapi::um::winuser::SetTimer;
+            unsafe { SetTimer(hwnd, AZ_GPU_MEMORY_TICK, gpu_memory_budget.check_interval_ms.max(100), None); }
+        }
+
+        // pause / recreate the renderer on lock, unlock and remote desktop transitions
+        register_session_notification(hwnd);
+        // pause the timers of the window while it is cloaked
+        install_cloak_event_hook();
+
+        use winapi::um::winuser::PostMessageW;
+        unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0 ); }
+
+        let mut window = Window {
+            hwnd,
+            internal,
+            gl_context: opengl_context,
+            gl_context_options,
+            gl_functions: gl,
+            gl_context_ptr,
+            render_api,
+            renderer: Some(renderer),
+            hit_tester: AsyncHitTester::Requested(hit_tester),
+            menu_bar,
+            context_menu: None,
+            timers: BTreeMap::new(),
+            thread_timer_running: None,
+            high_surrogate: None,
+            key_down_handled: false,
+            overlays: Vec::new(),
+            pending_frame_request: 0,
+            window_region: None,
+            show_after_first_frame: if show_after_first_frame { Some(sw_options) } else { None },
+            first_frame_callback: options.first_frame_callback.clone().into_option(),
+            swap_interval_fn: extra.wglSwapIntervalEXT,
+            present_mode: None,
+            last_present: None,
+            frame_cap_pending: false,
+            node_cursor: None,
+            custom_cursor: None,
+            busy: None,
+            hit_test_tolerance,
+            pointer_input_type: PointerInputType::Mouse,
+            initial_focus: initial_focus_node.and(options.initial_focus.clone().into_option()),
+            system_colors: get_system_colors(),
+            wheel_zoom_delta: 0.0,
+            renderer_info,
+            program_cache,
+            program_cache_startup_complete: false,
+            is_remote_session: is_remote_session(),
+            session_locked: false,
+            session_disconnected: false,
+            is_hidden: false,
+            is_occluded: false,
+            font_warmup_time,
+            native_child_windows: BTreeSet::new(),
+            title_bar_regions: Vec::new(),
+        };
+
+        // invoke the FocusReceived callbacks of the initially focused node
+        // (text inputs position their caret and update the IME in there)
+        if let Some(focused_node) = initial_focus_node {
+
+            use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+            let hdc = unsafe { GetDC(hwnd) };
+            if let Some(hrc) = opengl_context.as_mut() {
+                unsafe { wglMakeCurrent(hdc, *hrc) };
+            }
+
+            let ab = &mut *appdata_lock;
+            let fc_cache = &mut ab.fc_cache;
+            let image_cache = &mut ab.image_cache;
+            let config = &ab.config;
+
+            let window_handle = RawWindowHandle::Windows(WindowsHandle {
+                hwnd: hwnd as *mut core::ffi::c_void,
+                hinstance: hinstance as *mut core::ffi::c_void,
+            });
+
+            let ntc = NodesToCheck::empty(
+                window.internal.current_window_state.mouse_state.mouse_down(),
+                Some(focused_node),
+            );
+
+            let mut new_windows = Vec::new();
+            let mut destroyed_windows = Vec::new();
+            let mut ret = ProcessEventResult::DoNothing;
+
+            for mut focus_callback in window.get_focus_received_callbacks(focused_node) {
+                let ccr = {
+                    let internal = &mut window.internal;
+                    let gl_context_ptr = &window.gl_context_ptr;
+                    fc_cache.apply_closure(|fc_cache| {
+                        internal.invoke_menu_callback(
+                            &mut focus_callback,
+                            focused_node,
+                            &window_handle,
+                            &gl_context_ptr,
+                            image_cache,
+                            fc_cache,
+                            &config.system_callbacks,
+                        )
+                    })
+                };
+
+                ret = ret.max_self(process_callback_results(
+                    ccr,
+                    &mut window,
+                    &ntc,
+                    image_cache,
+                    fc_cache,
+                    &mut new_windows,
+                    &mut destroyed_windows,
+                ));
+            }
+
+            if let Some(hrc) = opengl_context.as_mut() {
+                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+            }
+
+            unsafe { ReleaseDC(hwnd, hdc); }
+
+            // the window is not stored yet, the messages
+            // are handled once the event loop picks them up
+            match ret {
+                ProcessEventResult::DoNothing => { },
+                ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                    unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0); }
+                },
+                ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                    unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0); }
+                    for w in ab.windows.values() {
+                        unsafe { PostMessageW(w.hwnd, AZ_REGENERATE_DOM, 0, 0); }
+                    }
+                },
+                ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                    unsafe { PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0); }
+                },
+                ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                    unsafe { PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0); }
+                    unsafe { PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0); }
+                },
+                ProcessEventResult::ShouldReRenderCurrentWindow => {
+                    unsafe { PostMessageW(hwnd, AZ_GPU_SCROLL_RENDER, 0, 0); }
+                },
+            }
+
+            destroy_windows(ab, destroyed_windows);
+
+            if !new_windows.is_empty() {
+                mem::drop(appdata_lock);
+                create_windows(hinstance, &mut shared_application_data, new_windows);
+                appdata_lock = shared_application_data.inner.try_borrow_mut().unwrap();
+            }
+        }
+
+        // invoke the create callback, if there is any
+        if let Some(create_callback) = options.create_callback.as_mut() {
+
+            let hdc = unsafe { GetDC(hwnd) };
+            if let Some(hrc) = opengl_context.as_mut() {
+                unsafe { wglMakeCurrent(hdc, *hrc) };
+            }
+
+            let ab = &mut *appdata_lock;
+            let fc_cache = &mut ab.fc_cache;
+            let image_cache = &mut ab.image_cache;
+            let data = &mut ab.data;
+            let config = &ab.config;
+
+            let ccr = fc_cache.apply_closure(|fc_cache| {
+                use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+                window.internal.invoke_single_callback(
+                    create_callback,
+                    data,
+                    &RawWindowHandle::Windows(WindowsHandle {
+                        hwnd: hwnd as *mut core::ffi::c_void,
+                        hinstance: hinstance as *mut core::ffi::c_void,
+                    }),
+                    &window.gl_context_ptr,
+                    image_cache,
+                    fc_cache,
+                    &config.system_callbacks,
+                )
+            });
+
+            let ntc = NodesToCheck::empty(
+                window.internal.current_window_state.mouse_state.mouse_down(),
+                window.internal.current_window_state.focused_node,
+            );
+
+            let mut new_windows = Vec::new();
+            let mut destroyed_windows = Vec::new();
+
+            let ret = process_callback_results(
+                ccr,
+                &mut window,
+                &ntc,
+                image_cache,
+                fc_cache,
+                &mut new_windows,
+                &mut destroyed_windows,
+            );
+
+            if let Some(hrc) = opengl_context.as_mut() {
+                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+            }
+
+            mem::drop(ab);
+            mem::drop(appdata_lock);
+            create_windows(hinstance, &mut shared_application_data, new_windows, );
+            let mut appdata_lock = shared_application_data.inner.try_borrow_mut().unwrap();
+            let mut ab = &mut *appdata_lock;
+            destroy_windows(ab, destroyed_windows);
+
+            unsafe { ReleaseDC(hwnd, hdc); }
+        }
+
+        // otherwise the window is shown in render_and_present()
+        if !show_after_first_frame {
+            unsafe { ShowWindow(hwnd, sw_options); }
+        }
+
+        // NOTE: The window is NOT stored yet
+        Ok(window)
+    }
+
+    fn start_stop_timers(
+        &mut self,
+        added: FastHashMap<TimerId, Timer>,
+        removed: FastBTreeSet<TimerId>
+    ) {
+
+        use winapi::um::winuser::{SetTimer, KillTimer};
+
+        for (id, timer) in added {
+            // started by update_visibility() once the window is visible again
+            let res = if self.is_hidden && !timer.runs_while_hidden {
+                id.id
+            } else {
+                unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().min(u32::MAX as u64) as u32, None) }
+            };
+            self.internal.timers.insert(id, timer);
+            self.timers.insert(id, res);
+        }
+
+        for id in removed {
+            if let Some(_) = self.internal.timers.remove(&id) {
+                if let Some(handle) = self.timers.remove(&id) {
+                    unsafe { KillTimer(self.hwnd, handle) };
+                }
+            }
+        }
+    }
+
+    fn start_stop_threads(
+        &mut self,
+        mut added: FastHashMap<ThreadId, Thread>,
+        removed: FastBTreeSet<ThreadId>
+    ) {
+
+        use winapi::um::winuser::{SetTimer, KillTimer};
+
+        self.internal.threads.append(&mut added);
+        self.internal.threads.retain(|r, _| !removed.contains(r));
+
+        if self.internal.threads.is_empty() {
+            if let Some(thread_tick) = self.thread_timer_running {
+                unsafe { KillTimer(self.hwnd, thread_tick) };
+            }
+            self.thread_timer_running = None;
+        } else if !self.internal.threads.is_empty() && self.thread_timer_running.is_none() {
+            let res = unsafe { SetTimer(self.hwnd, AZ_THREAD_TICK, 16, None) }; // 16ms timer
+            self.thread_timer_running = Some(res);
+        }
+    }
+
+    // Moves the keyboard focus to the given node (None = no focused node), applies
+    // the :focus styles and scrolls the newly focused node into view
+    fn set_keyboard_focus(&mut self, image_cache: &ImageCache, new_focus: Option<DomNodeId>) {
+
+        use azul_core::window_state::StyleAndLayoutChanges;
+        use winapi::um::winuser::PostMessageW;
+
+        let old_focus = self.internal.current_window_state.focused_node;
+        if new_focus == old_focus {
+            return;
+        }
+
+        let nodes_to_check = NodesToCheck::empty(
+            self.internal.current_window_state.mouse_state.mouse_down(),
+            old_focus,
+        );
+
+        // apply the :focus styles to the old / new focused node
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut self.internal.layout_results,
+            image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            None,
+            None,
+            &Some(new_focus),
+            azul_layout::do_the_relayout,
+        );
+
+        if let Some(focus_change) = style_layout_changes.focus_change {
+            self.internal.current_window_state.focused_node = focus_change.new;
+        }
+
+        if let Some(focused_node) = new_focus {
+            self.internal.scroll_node_into_view(focused_node);
+        }
+
+        unsafe { PostMessageW(self.hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0); }
+    }
+
+    // Pauses the timers (except the ones that run while hidden) while the window is
+    // minimized or cloaked, restarts them and repaints once it is visible again.
+    // Missed intervals are handled by the missed tick policy of each timer.
+    fn update_visibility(&mut self, is_hidden: bool) {
+
+        use winapi::um::winuser::{InvalidateRect, KillTimer, SetTimer};
+
+        if self.is_hidden == is_hidden {
+            return;
+        }
+
+        self.is_hidden = is_hidden;
+
+        for (id, timer) in self.internal.timers.iter() {
+            if timer.runs_while_hidden {
+                continue;
+            }
+            if is_hidden {
+                if let Some(handle) = self.timers.get(id) {
+                    unsafe { KillTimer(self.hwnd, *handle) };
+                }
+            } else {
+                let res = unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().min(u32::MAX as u64) as u32, None) };
+                self.timers.insert(*id, res);
+            }
+        }
+
+        if !is_hidden {
+            unsafe { InvalidateRect(self.hwnd, ptr::null(), 0) };
+        }
+    }
+
+    // Stop all timers that have a NodeId attached to them because in the next
+    // frame the NodeId would be invalid, leading to crashes / panics
+    fn stop_timers_with_node_ids(&mut self) {
+        let timers_to_remove = self.internal.timers
+        .iter()
+        .filter_map(|(id, timer)| timer.node_id.as_ref().map(|_| *id))
+        .collect();
+
+        self.start_stop_timers(FastHashMap::default(), timers_to_remove);
+    }
+
+    // ScrollResult contains information about what nodes need to be scrolled,
+    // whether they were scrolled by the system or by the user and how far they
+    // need to be scrolled
+    fn do_system_scroll(&mut self, scroll: ScrollResult) {
+        // for scrolled_node in scroll {
+        //      self.render_api.scroll_node_with_id();
+        //      let scrolled_rect = LogicalRect { origin: scroll_offset, size: visible.size };
+        //      if !scrolled_node.scroll_bounds.contains(&scroll_rect) {
+        //
+        //      }
+        // }
+    }
+
+    // Moves the native child windows of the DOM nodes (NodeData::set_native_child_window)
+    // to the visible rect of their node, so that they follow layout changes, resizing and
+    // scrolling. Windows whose node is out of view or was removed from the DOM are hidden.
+    //
+    // Only nodes of the root DOM are handled (iframe content is laid out relative to the iframe)
+    fn update_native_child_windows(&mut self) {
+
+        use winapi::um::winuser::{
+            GetParent, GetWindowLongPtrW, IsWindow, SetParent, SetWindowLongPtrW, SetWindowPos,
+            ShowWindow, GWL_STYLE, SWP_NOACTIVATE, SWP_NOZORDER, SWP_SHOWWINDOW,
+            SW_HIDE, WS_CLIPCHILDREN,
+        };
+
+        let mut native_child_windows = BTreeSet::new();
+
+        if let Some(layout_result) = self.internal.layout_results.first() {
+
+            let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+            let scroll_states = &self.internal.scroll_states;
+
+            for (node_id, node_data) in layout_result.styled_dom.node_data.as_container().internal.iter().enumerate() {
+
+                let child = match node_data.get_native_child_window() {
+                    Some(s) => s.handle as HWND,
+                    None => continue,
+                };
+
+                if unsafe { IsWindow(child) } == 0 {
+                    continue;
+                }
+
+                native_child_windows.insert(child as usize);
+
+                // the OpenGL surface must not paint over the child windows
+                if unsafe { GetParent(child) } != self.hwnd {
+                    unsafe {
+                        let style = GetWindowLongPtrW(self.hwnd, GWL_STYLE);
+                        SetWindowLongPtrW(self.hwnd, GWL_STYLE, style | WS_CLIPCHILDREN as isize);
+                        SetParent(child, self.hwnd);
+                    }
+                }
+
+                match layout_result.get_visible_node_rect(NodeId::new(node_id), scroll_states) {
+                    Some(rect) => unsafe {
+                        SetWindowPos(
+                            child,
+                            ptr::null_mut(),
+                            (rect.origin.x * hidpi_factor).round() as i32,
+                            (rect.origin.y * hidpi_factor).round() as i32,
+                            (rect.size.width * hidpi_factor).round() as i32,
+                            (rect.size.height * hidpi_factor).round() as i32,
+                            SWP_NOZORDER | SWP_NOACTIVATE | SWP_SHOWWINDOW,
+                        );
+                    },
+                    None => unsafe { ShowWindow(child, SW_HIDE); },
+                }
+            }
+        }
+
+        // node removed from the DOM
+        for removed in self.native_child_windows.difference(&native_child_windows) {
+            unsafe {
+                if IsWindow(*removed as HWND) != 0 {
+                    ShowWindow(*removed as HWND, SW_HIDE);
+                }
+            }
+        }
+
+        self.native_child_windows = native_child_windows;
+    }
+
+    // Collects the visible rects of the nodes with a TitleBarRegion (NodeData::set_title_bar_region)
+    // for WM_NCHITTEST. Only nodes of the root DOM are handled, same as for native child windows
+    fn update_title_bar_regions(&mut self) {
+
+        self.title_bar_regions.clear();
+
+        let layout_result = match self.internal.layout_results.first() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let scroll_states = &self.internal.scroll_states;
+
+        for (node_id, node_data) in layout_result.styled_dom.node_data.as_container().internal.iter().enumerate() {
+
+            let region = match node_data.get_title_bar_region() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            if let Some(rect) = layout_result.get_visible_node_rect(NodeId::new(node_id), scroll_states) {
+                self.title_bar_regions.push((rect, region));
+            }
+        }
+    }
+
+    // Returns the window region at the given logical position:
+    // NoDrag regions (controls inside of the title bar) win over Drag regions
+    fn get_title_bar_region_at(&self, position: LogicalPosition) -> Option<TitleBarRegion> {
+
+        let mut result = None;
+
+        for (rect, region) in self.title_bar_regions.iter() {
+            let contains = position.x >= rect.min_x() && position.x < rect.max_x() &&
+                           position.y >= rect.min_y() && position.y < rect.max_y();
+            if !contains {
+                continue;
+            }
+            match region {
+                TitleBarRegion::NoDrag => return Some(TitleBarRegion::NoDrag),
+                TitleBarRegion::Drag => result = Some(TitleBarRegion::Drag),
+            }
+        }
+
+        result
+    }
+
+    // Blocks until all transactions sent to webrender (display lists, resource
+    // updates, generate_frame) have been processed by the scene builder, then
+    // requests and waits for a new hit tester, so that hit-testing and layout
+    // queries after this call never see a stale scene
+    fn flush_pending(&mut self) {
+        wait_for_scene_builder(&mut self.internal, &mut self.render_api);
+
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester = AsyncHitTester::Requested(
+            self.render_api.request_hit_tester(wr_document_id)
+        );
+        let _ = self.hit_tester.resolve();
+
+        for overlay in self.overlays.iter_mut() {
+            overlay.hit_tester = AsyncHitTester::Requested(
+                self.render_api.request_hit_tester(wr_translate_document_id(overlay.internal.document_id))
+            );
+            let _ = overlay.hit_tester.resolve();
+        }
+    }
+
+    // Reads the alpha value of the presented frame at the given physical position
+    // (relative to the client area), used by WM_NCHITTEST to let clicks on transparent
+    // pixels through. Only reads back a single pixel, so that the alpha hit test
+    // doesn't cost a full framebuffer read on every frame.
+    fn get_presented_alpha_at(&mut self, hdc: HDC, x: i32, y: i32) -> Option<u8> {
+
+        let gl_context = self.gl_context?;
+
+        let framebuffer_size = self.get_framebuffer_size();
+        if x < 0 || y < 0 || x >= framebuffer_size.width || y >= framebuffer_size.height {
+            return None;
+        }
+
+        if !make_gl_context_current(hdc, gl_context) {
+            return None;
+        }
+
+        let gl = &mut self.gl_functions.functions;
+
+        // both double- and single-buffered windows present in the front buffer
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.read_buffer(gl_context_loader::gl::FRONT);
+
+        // glReadPixels counts rows bottom-up
+        let pixel = gl.read_pixels(
+            x, framebuffer_size.height - 1 - y, 1, 1,
+            gl_context_loader::gl::RGBA,
+            gl_context_loader::gl::UNSIGNED_BYTE,
+        );
+
+        if self.gl_context_options.buffering == GlBuffering::DoubleBuffered {
+            gl.read_buffer(gl_context_loader::gl::BACK);
+        }
+
+        pixel.get(3).copied()
+    }
+
+    // PresentMode::FrameCapped: returns how long the next frame has to wait
+    // until it may be presented, None if it can be presented right away
+    fn get_frame_cap_delay(&self) -> Option<Duration> {
+        let present_mode = self.internal.current_window_state.renderer_options.present_mode;
+        let min_frame_time = Duration::from_secs_f32(present_mode.get_min_frame_time_ms()? / 1000.0);
+        let elapsed = self.last_present?.elapsed();
+        if elapsed < min_frame_time {
+            Some(min_frame_time - elapsed)
+        } else {
+            None
+        }
+    }
+
+    // Renders the current webrender scene to the back buffer and swaps
+    // the buffers - does nothing if the window has no OpenGL context
+    // Returns whether a frame was presented successfully
+    fn render_and_present(&mut self, hdc: HDC) -> bool {
+
+        use winapi::um::{
+            wingdi::{wglMakeCurrent, SwapBuffers},
+            winuser::{InvalidateRect, PostMessageW, ShowWindow},
+        };
+
+        let gl_context = match self.gl_context {
+            Some(s) => s,
+            None => return false,
+        };
+
+        // the session has no display: presenting would fail or render a black window
+        if self.session_locked || self.session_disconnected {
+            return false;
+        }
+
+        if !make_gl_context_current(hdc, gl_context) {
+            return false;
+        }
+
+        self.update_native_child_windows();
+        self.update_title_bar_regions();
+
+        let requested_present_mode = self.internal.current_window_state.renderer_options.present_mode;
+        if self.present_mode.map(|p| p.requested) != Some(requested_present_mode) {
+            self.apply_present_mode(requested_present_mode);
+        }
+
+        let framebuffer_size = self.get_framebuffer_size();
+        let webrender_start = std::time::Instant::now();
+
+        // Block until all transactions (display list build)
+        // have finished processing
+        //
+        // Usually this shouldn't take too long, since DL building
+        // happens asynchronously between WM_SIZE and WM_PAINT
+        wait_for_scene_builder(&mut self.internal, &mut self.render_api);
+
+        let mut gl = &mut self.gl_functions.functions;
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+        gl.disable(gl_context_loader::gl::MULTISAMPLE);
+        gl.viewport(0, 0, framebuffer_size.width, framebuffer_size.height);
+
+        let mut current_program = [0_i32];
+        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+
+        // Render
+        let mut render_failed = false;
+        if let Some(r) = self.renderer.as_mut() {
+            r.update();
+            let result = r.render(framebuffer_size, 0);
+            if let Err(e) = result.as_ref() {
+                report_render_error(
+                    self.internal.document_id,
+                    RenderError::RendererFailed(format!("{:?}", e)),
+                );
+            }
+            render_failed = result.is_err();
+        }
+
+        let webrender_time = webrender_start.elapsed();
+
+        // single-buffered surfaces were drawn into the front buffer,
+        // only wait for (glFinish) or kick off (glFlush) the GPU work
+        let swap_start = std::time::Instant::now();
+        match self.gl_context_options.buffering {
+            GlBuffering::DoubleBuffered => unsafe { SwapBuffers(hdc); },
+            GlBuffering::SingleBufferedFlush => gl.flush(),
+            GlBuffering::SingleBufferedFinish => gl.finish(),
+        }
+        self.last_present = Some(std::time::Instant::now());
+
+        // the display list is built while handling the messages: not part of the layout time
+        let display_list_time = mem::take(&mut self.internal.retained_display_lists.build_time_since_present);
+        let message_time = FRAME_MESSAGE_TIME.with(|t| t.borrow_mut().remove(&(self.hwnd as usize))).unwrap_or_default();
+        check_frame_budget(self.hwnd, &FrameTiming {
+            layout: message_time.saturating_sub(display_list_time),
+            display_list: display_list_time,
+            webrender: webrender_time,
+            swap: swap_start.elapsed(),
+        });
+
+        // driver update, remote desktop transition or GPU reset:
+        // the context is gone, recreate it on the next message
+        if gl.get_error() == GL_CONTEXT_LOST || render_failed {
+            unsafe { PostMessageW(self.hwnd, AZ_RECREATE_RENDERER, AZ_RENDERER_FAILED, 0) };
+            unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+            return false;
+        }
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        gl.use_program(current_program[0] as u32);
+
+        // the context stays current: the next frame of this window doesn't have
+        // to switch contexts again, other windows switch in make_gl_context_current
+
+        if self.pending_frame_request != 0 {
+            notify_frame_presented(self.hwnd, self.pending_frame_request);
+            self.pending_frame_request = 0;
+        }
+
+        // first frame is on the back buffer: show the window now,
+        // so that the user never sees a blank (white) window
+        if let Some(sw_options) = self.show_after_first_frame.take() {
+            unsafe { ShowWindow(self.hwnd, sw_options) };
+        }
+
+        if self.first_frame_callback.is_some() {
+            unsafe { PostMessageW(self.hwnd, AZ_FIRST_FRAME_PRESENTED, 0, 0) };
+        }
+
+        // all programs of the first frame are linked: write them to the disk cache
+        if !self.program_cache_startup_complete {
+            self.program_cache.startup_complete();
+            self.program_cache_startup_complete = true;
+        }
+
+        // RedrawPolicy::Continuous: schedule the next frame right away, the frame
+        // pacing comes from the present mode (vsync / frame cap) above. WM_PAINT has
+        // the lowest priority, so input and timers are still processed in between
+        if self.internal.current_window_state.redraw_policy == RedrawPolicy::Continuous {
+            unsafe { InvalidateRect(self.hwnd, ptr::null(), 0) };
+        }
+
+        true
+    }
+
+    // Sets the swap interval of the current OpenGL context according to the
+    // present mode - context has to be current. If the driver doesn't support
+    // swap control, the driver default (usually vsync) stays active
+    fn apply_present_mode(&mut self, requested: PresentMode) {
+
+        let swap_interval_set = match self.swap_interval_fn {
+            Some(wglSwapIntervalEXT) => (wglSwapIntervalEXT)(requested.get_swap_interval()) != 0,
+            None => false,
+        };
+
+        let active = if swap_interval_set { requested } else { PresentMode::Fifo };
+
+        #[cfg(feature = "logging")] {
+            if active != requested {
+                warn!("present mode {:?} is not supported, using {:?} instead", requested, active);
+            }
+        }
+
+        self.present_mode = Some(PresentModeStatus { requested, active });
+    }
+
+    // Reads back the pixel at the logical position, see get_pixel()
+    fn read_pixel(&mut self, hdc: HDC, position: LogicalPosition) -> Result<ColorU, GetPixelError> {
+
+        if self.gl_context.is_none() {
+            return Err(GetPixelError::NoOpenGlContext);
+        }
+
+        if self.last_present.is_none() {
+            return Err(GetPixelError::NoFrameRendered);
+        }
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let x = libm::floorf(position.x * hidpi_factor) as i32;
+        let y = libm::floorf(position.y * hidpi_factor) as i32;
+
+        let framebuffer_size = self.get_framebuffer_size();
+        if x < 0 || y < 0 || x >= framebuffer_size.width || y >= framebuffer_size.height {
+            return Err(GetPixelError::OutOfBounds);
+        }
+
+        let image = self
+            .render_region_to_image(hdc, x, y, x + 1, y + 1)
+            .ok_or(GetPixelError::NoOpenGlContext)?;
+
+        let (r, g, b, a) = match &image.pixels {
+            RawImageData::U8(u) if u.as_ref().len() >= 4 => {
+                let p = u.as_ref();
+                (p[0], p[1], p[2], p[3])
+            },
+            _ => return Err(GetPixelError::NoOpenGlContext),
+        };
+
+        // the framebuffer is premultiplied
+        let unpremultiply = |c: u8| -> u8 {
+            if a == 0 { 0 } else { ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8 }
+        };
+
+        Ok(ColorU { r: unpremultiply(r), g: unpremultiply(g), b: unpremultiply(b), a })
+    }
+
+    // Renders the current scene into the back buffer (without swapping) and
+    // reads back the physical pixels covered by the layout rect of the node
+    fn render_node_to_image(&mut self, hdc: HDC, node: DomNodeId) -> Option<RawImage> {
+
+        let (position, size) = {
+            let layout_result = self.internal.layout_results.get(node.dom.inner)?;
+            let nid = node.node.into_crate_internal()?;
+            let positioned_rect = layout_result.rects.as_ref().get(nid)?;
+            (positioned_rect.position, positioned_rect.size)
+        };
+
+        let (x, y) = match position {
+            PositionInfo::Static(p) | PositionInfo::Fixed(p) |
+            PositionInfo::Absolute(p) | PositionInfo::Relative(p) => (p.static_x_offset, p.static_y_offset),
+        };
+
+        // logical node rect -> physical pixels (clipped to the framebuffer when reading back)
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let x0 = libm::roundf(x * hidpi_factor) as i32;
+        let y0 = libm::roundf(y * hidpi_factor) as i32;
+        let x1 = libm::roundf((x + size.width) * hidpi_factor) as i32;
+        let y1 = libm::roundf((y + size.height) * hidpi_factor) as i32;
+
+        self.render_region_to_image(hdc, x0, y0, x1, y1)
+    }
+
+    // Renders the current scene into the back buffer (without swapping) and reads
+    // back the physical pixels (x0, y0) - (x1, y1) as a top-down RGBA8 image
+    fn render_region_to_image(&mut self, hdc: HDC, x0: i32, y0: i32, x1: i32, y1: i32) -> Option<RawImage> {
+
+        use winapi::um::wingdi::wglMakeCurrent;
+
+        let gl_context = self.gl_context?;
+
+        let framebuffer_size = self.get_framebuffer_size();
+        let framebuffer_width = framebuffer_size.width;
+        let framebuffer_height = framebuffer_size.height;
+
+        let x0 = x0.max(0);
+        let y0 = y0.max(0);
+        let x1 = x1.min(framebuffer_width);
+        let y1 = y1.min(framebuffer_height);
+
+        let width = x1 - x0;
+        let height = y1 - y0;
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        unsafe { wglMakeCurrent(hdc, gl_context) };
+
+        wait_for_scene_builder(&mut self.internal, &mut self.render_api);
+
+        let mut gl = &mut self.gl_functions.functions;
+
+        let mut current_program = [0_i32];
+        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.viewport(0, 0, framebuffer_width, framebuffer_height);
+
+        // render into the back buffer, but don't swap
+        if let Some(r) = self.renderer.as_mut() {
+            r.update();
+            let _ = r.render(framebuffer_size, 0);
+        }
+
+        // GL origin is bottom-left: only read back the rows / columns of the region
+        let pixels = gl.read_pixels(
+            x0, framebuffer_height - y1, width, height,
+            gl_context_loader::gl::RGBA,
+            gl_context_loader::gl::UNSIGNED_BYTE,
+        );
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        gl.use_program(current_program[0] as u32);
+
+        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+
+        let row_len = width as usize * 4;
+        if pixels.len() < row_len * height as usize {
+            return None;
+        }
+
+        // flip bottom-up rows to top-down
+        let top_down = pixels
+            .chunks_exact(row_len)
+            .take(height as usize)
+            .rev()
+            .flat_map(|row| row.iter().copied())
+            .collect::<Vec<u8>>();
+
+        Some(RawImage {
+            pixels: RawImageData::U8(top_down.into()),
+            width: width as usize,
+            height: height as usize,
+            premultiplied_alpha: true,
+            data_format: RawImageFormat::RGBA8,
+        })
+    }
+
+    // Renders the current scene and copies the result into a top-down, premultiplied
+    // 32-bit DIB of at most max_width x max_height pixels (preserving the aspect ratio),
+    // used for the DWM taskbar thumbnail / peek preview
+    fn render_to_dib(&mut self, hdc: HDC, max_width: i32, max_height: i32) -> Option<HBITMAP> {
+
+        use winapi::um::{
+            wingdi::{
+                wglMakeCurrent, CreateDIBSection,
+                BITMAPINFO, BITMAPINFOHEADER,
+                BI_RGB, DIB_RGB_COLORS,
+            },
+        };
+
+        let gl_context = self.gl_context?;
+
+        let framebuffer_size = self.get_framebuffer_size();
+        let width = framebuffer_size.width;
+        let height = framebuffer_size.height;
+
+        if width <= 0 || height <= 0 || max_width <= 0 || max_height <= 0 {
+            return None;
+        }
+
+        unsafe { wglMakeCurrent(hdc, gl_context) };
+
+        wait_for_scene_builder(&mut self.internal, &mut self.render_api);
+
+        let mut gl = &mut self.gl_functions.functions;
+
+        let mut current_program = [0_i32];
+        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.viewport(0, 0, width, height);
+
+        // render into the back buffer, but don't swap
+        if let Some(r) = self.renderer.as_mut() {
+            r.update();
+            let _ = r.render(framebuffer_size, 0);
+        }
+
+        let pixels = gl.read_pixels(
+            0, 0, width, height,
+            gl_context_loader::gl::BGRA,
+            gl_context_loader::gl::UNSIGNED_BYTE,
+        );
+
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        gl.use_program(current_program[0] as u32);
+
+        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+
+        if pixels.len() < (width * height * 4) as usize {
+            return None;
+        }
+
+        let scale = (max_width as f32 / width as f32)
+            .min(max_height as f32 / height as f32)
+            .min(1.0);
+        let dib_width = (libm::roundf(width as f32 * scale) as i32).max(1);
+        let dib_height = (libm::roundf(height as f32 * scale) as i32).max(1);
+
+        let mut bitmap_info: BITMAPINFO = unsafe { mem::zeroed() };
+        bitmap_info.bmiHeader.biSize = mem::size_of::<BITMAPINFOHEADER>() as u32;
+        bitmap_info.bmiHeader.biWidth = dib_width;
+        bitmap_info.bmiHeader.biHeight = -dib_height; // top-down DIB
+        bitmap_info.bmiHeader.biPlanes = 1;
+        bitmap_info.bmiHeader.biBitCount = 32;
+        bitmap_info.bmiHeader.biCompression = BI_RGB;
+
+        let mut bits: *mut c_void = ptr::null_mut();
+        let hbitmap = unsafe {
+            CreateDIBSection(hdc, &bitmap_info, DIB_RGB_COLORS, &mut bits, ptr::null_mut(), 0)
+        };
+
+        if hbitmap.is_null() || bits.is_null() {
+            return None;
+        }
+
+        let dst = unsafe {
+            core::slice::from_raw_parts_mut(bits as *mut u8, (dib_width * dib_height * 4) as usize)
+        };
+
+        // nearest-neighbour downscaling, OpenGL rows are stored bottom-up
+        for y in 0..dib_height {
+            let src_y = height - 1 - (y * height / dib_height);
+            for x in 0..dib_width {
+                let src_x = x * width / dib_width;
+                let src = ((src_y * width + src_x) * 4) as usize;
+                let dst_idx = ((y * dib_width + x) * 4) as usize;
+                dst[dst_idx..dst_idx + 4].copy_from_slice(&pixels[src..src + 4]);
+            }
+        }
+
+        Some(hbitmap)
+    }
+
+    // Saves a webrender capture (scene, frame, resource cache and
+    // external images) of all documents of this window to the directory
+    #[cfg(feature = "capture")]
+    fn save_frame_capture(&mut self, path: std::path::PathBuf) {
+        use webrender::api::CaptureBits as WrCaptureBits;
+        self.render_api.save_capture(path, WrCaptureBits::all());
+    }
+
+    // Destroys the (lost) OpenGL context and the renderer and creates new ones.
+    // If `software` is set, no new OpenGL context is created and the window
+    // continues with the software renderer.
+    //
+    // All resources registered in the old renderer are gone, so the
+    // RendererResources are reset: the next DOM regeneration will re-upload
+    // all fonts and images from the ImageCache to the new renderer
+    fn recreate_renderer(&mut self, hinstance: HINSTANCE, software: bool) -> Result<(), WindowsWindowCreateError> {
+
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{
+                translate_document_id_wr,
+                translate_id_namespace_wr,
+                wr_translate_document_id,
+            },
+        };
+        use azul_core::{
+            gl::GlContextPtr,
+            window::RendererType,
+        };
+        use winapi::um::{
+            wingdi::{wglDeleteContext, wglMakeCurrent},
+            winuser::{GetDC, ReleaseDC},
+        };
+
+        // tear down the old renderer and context
+        if let Some(renderer) = self.renderer.take() {
+            renderer.deinit();
+        }
+
+        // stays at software rendering if no new context can be created
+        self.renderer_info = RendererInfo::software();
+        self.is_remote_session = is_remote_session();
+
+        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+
+        if let Some(context) = self.gl_context.take() {
+            unsafe { wglDeleteContext(context); }
+        }
+
+        // the layout results may hold textures of the old context:
+        // drop them before a new context is made current
+        self.internal.layout_results = Vec::new();
+        self.internal.retained_display_lists = RetainedDisplayLists::default();
+        self.internal.gl_texture_cache = GlTextureCache::default();
+        self.internal.renderer_resources = RendererResources::default();
+        self.gl_context_ptr = None.into();
+
+        let hrc = if software {
+            None
+        } else {
+            let extra = ExtraWglFunctions::load(&get_window_class_name(self.hwnd))?;
+            let shared_gl_context = self.internal.current_window_state.platform_specific_options.windows_options.shared_gl_context
+                .into_option()
+                .unwrap_or(ptr::null_mut()) as HGLRC;
+            let (hrc, gl_context_options) = create_gl_context(self.hwnd, hinstance, &extra, &self.gl_context_options, shared_gl_context)
+                .map_err(|e| match e {
+                    WindowsOpenGlError::IncompatibleSharedContext(_) => WindowsWindowCreateError::SharedGlContext(e),
+                    _ => WindowsWindowCreateError::NoGlContext,
+                })?;
+
+            // the swap interval is per-context: re-apply it on the next frame
+            self.swap_interval_fn = extra.wglSwapIntervalEXT;
+            self.gl_context_options = gl_context_options;
+            Some(hrc)
+        };
+
+        self.present_mode = None;
+
+        let hdc = unsafe { GetDC(self.hwnd) };
+        if hdc.is_null() {
+            if let Some(hrc) = hrc {
+                unsafe { wglDeleteContext(hrc); }
+            }
+            return Err(WindowsWindowCreateError::NoHDC);
+        }
+
+        let mut gl = GlFunctions::initialize();
+        let mut gl_context_ptr: OptionGlContextPtr = None.into();
+
+        if let Some(hrc) = hrc {
+            unsafe { wglMakeCurrent(hdc, hrc) };
+            gl.load();
+            if self.gl_context_options.debug {
+                gl.install_debug_callback();
+            }
+            let ptr = GlContextPtr::new(RendererType::Hardware, gl.functions.clone());
+            self.renderer_info = RendererInfo::from_gl_context(&ptr);
+            gl_context_ptr = Some(ptr).into();
+        }
+
+        // the binaries of the old program cache were linked by the same
+        // driver, so they can be reused for the new context
+        let (mut renderer, sender) = match create_webrender_renderer(
+            gl.functions.clone(),
+            &self.internal.current_window_state.debug_state,
+            self.program_cache.clone(),
+        ) {
+            Ok(o) => o,
+            Err(e) => unsafe {
+                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                if let Some(hrc) = hrc {
+                    wglDeleteContext(hrc);
+                }
+                ReleaseDC(self.hwnd, hdc);
+                return Err(WindowsWindowCreateError::Renderer(e));
+            },
+        };
+
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        let mut render_api = sender.create_api();
+
+        let framebuffer_size = self.get_framebuffer_size();
+
+        let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+        let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+
+        self.hit_tester = AsyncHitTester::Requested(
+            render_api.request_hit_tester(wr_translate_document_id(document_id))
+        );
+
+        self.internal.document_id = document_id;
+        self.internal.id_namespace = id_namespace;
+        // overlay documents were registered in the old renderer
+        self.overlays.clear();
+        self.gl_context = hrc;
+        self.gl_functions = gl;
+        self.gl_context_ptr = gl_context_ptr;
+        self.render_api = render_api;
+        self.renderer = Some(renderer);
+
+        unsafe {
+            wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+            ReleaseDC(self.hwnd, hdc);
+        }
+
+        Ok(())
+    }
+
+    // Creates the GDI region for the current window size and hands it to the OS
+    fn apply_window_region(&self) {
+
+        use winapi::um::{
+            wingdi::{CreatePolygonRgn, CreateRoundRectRgn, DeleteObject, WINDING},
+            winuser::{GetWindowRect, SetWindowRgn},
+        };
+
+        let region = match self.window_region.as_ref() {
+            Some(s) => s,
+            None => {
+                // the OS frees the previous region
+                unsafe { SetWindowRgn(self.hwnd, ptr::null_mut(), TRUE) };
+                return;
+            }
+        };
+
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        if unsafe { GetWindowRect(self.hwnd, &mut rect) } == 0 {
+            return;
+        }
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+
+        let hrgn = match region {
+            WindowRegion::RoundRect { radius } => {
+                let diameter = (radius * 2.0 * hidpi_factor).round() as i32;
+                // right / bottom coordinates are exclusive
+                unsafe {
+                    CreateRoundRectRgn(0, 0, rect.width() as i32 + 1, rect.height() as i32 + 1, diameter, diameter)
+                }
+            },
+            WindowRegion::Polygon(points) => {
+                if points.len() < 3 {
+                    return;
+                }
+                let points = points.iter().map(|p| POINT {
+                    x: (p.x * hidpi_factor).round() as i32,
+                    y: (p.y * hidpi_factor).round() as i32,
+                }).collect::<Vec<_>>();
+                unsafe { CreatePolygonRgn(points.as_ptr(), points.len() as i32, WINDING) }
+            },
+        };
+
+        if hrgn.is_null() {
+            return;
+        }
+
+        // On success the OS owns the new region and deletes the previous one,
+        // only on failure the region has to be deleted here
+        if unsafe { SetWindowRgn(self.hwnd, hrgn, TRUE) } == 0 {
+            unsafe { DeleteObject(hrgn as *mut _) };
+        }
+    }
+
+    // Lays out the DOM of the layout_callback into a new document and
+    // pushes it on top of the overlay stack, returns the index of the layer
+    fn add_overlay_layer(
+        &mut self,
+        layout_callback: LayoutCallback,
+        data: &mut RefAny,
+        image_cache: &ImageCache,
+        fc_cache: &mut FcFontCache,
+    ) -> usize {
+
+        use azul_core::window::WindowInternalInit;
+        use crate::wr_translate::{
+            translate_document_id_wr,
+            translate_id_namespace_wr,
+            wr_translate_document_id,
+        };
+
+        let physical_size = self.internal.current_window_state.size.get_physical_size();
+        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
+        let document_id = translate_document_id_wr(self.render_api.add_document(framebuffer_size));
+        let id_namespace = translate_id_namespace_wr(self.render_api.get_namespace_id());
+
+        let mut state: WindowState = self.internal.current_window_state.clone().into();
+        state.layout_callback = layout_callback;
+
+        let hit_tester = self.render_api
+            .request_hit_tester(wr_translate_document_id(document_id))
+            .resolve();
+        let hit_tester_ref = &*hit_tester;
+
+        let mut resource_updates = Vec::new();
+        let mut internal = WindowInternal::new(
+            WindowInternalInit {
+                window_create_options: WindowCreateOptions { state, .. Default::default() },
+                document_id,
+                id_namespace,
+            },
+            data,
+            image_cache,
+            &self.gl_context_ptr,
+            &mut resource_updates,
+            &crate::app::CALLBACKS,
+            fc_cache,
+            azul_layout::do_the_relayout,
+            |_, _, _| FullHitTest::empty(None),
+        );
+
+        rebuild_display_list(&mut internal, &mut self.render_api, image_cache, resource_updates);
+        generate_frame(&mut internal, &mut self.render_api, true);
+
+        self.overlays.push(OverlayLayer {
+            internal,
+            hit_tester: AsyncHitTester::Requested(
+                self.render_api.request_hit_tester(wr_translate_document_id(document_id))
+            ),
+        });
+
+        self.overlays.len() - 1
+    }
+
+    fn remove_overlay_layer(&mut self, index: usize) {
+        use crate::wr_translate::wr_translate_document_id;
+        if index < self.overlays.len() {
+            let overlay = self.overlays.remove(index);
+            self.render_api.delete_document(wr_translate_document_id(overlay.internal.document_id));
+        }
+    }
+
+    // Re-invokes the layout callback of one overlay layer and sends the new display list
+    fn regenerate_overlay_layer(
+        &mut self,
+        index: usize,
+        data: &mut RefAny,
+        image_cache: &ImageCache,
+        fc_cache: &mut FcFontCache,
+    ) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        let overlay = match self.overlays.get_mut(index) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut resource_updates = Vec::new();
+        let dpi = overlay.internal.get_dpi_scale_factor();
+        overlay.internal.regenerate_styled_dom(
+            data,
+            image_cache,
+            &self.gl_context_ptr,
+            &mut resource_updates,
+            dpi,
+            &crate::app::CALLBACKS,
+            fc_cache,
+            azul_layout::do_the_relayout,
+            |_, _, _| FullHitTest::empty(None),
+        );
+
+        rebuild_display_list(&mut overlay.internal, &mut self.render_api, image_cache, resource_updates);
+
+        overlay.hit_tester = AsyncHitTester::Requested(
+            self.render_api.request_hit_tester(wr_translate_document_id(overlay.internal.document_id))
+        );
+
+        generate_frame(&mut overlay.internal, &mut self.render_api, true);
+    }
+
+    // Re-layouts all overlay layers to the new window size
+    fn resize_overlay_layers(&mut self, image_cache: &ImageCache, fc_cache: &FcFontCache) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        let size = self.internal.current_window_state.size.clone();
+        let theme = self.internal.current_window_state.theme;
+        let physical_size = size.get_physical_size();
+
+        for overlay in self.overlays.iter_mut() {
+
+            let resize_result = overlay.internal.do_quick_resize(
+                image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &self.gl_context_ptr,
+                &size,
+                theme,
+            );
+
+            overlay.internal.current_window_state.size = size.clone();
+
+            let mut txn = WrTransaction::new();
+            wr_synchronize_updated_images(
+                resize_result.updated_images,
+                &overlay.internal.document_id,
+                &mut txn
+            );
+            txn.set_document_view(
+                WrDeviceIntRect::from_size(
+                    WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32),
+                )
+            );
+            self.render_api.send_transaction(wr_translate_document_id(overlay.internal.document_id), txn);
+
+            rebuild_display_list(&mut overlay.internal, &mut self.render_api, image_cache, Vec::new());
+
+            overlay.hit_tester = AsyncHitTester::Requested(
+                self.render_api.request_hit_tester(wr_translate_document_id(overlay.internal.document_id))
+            );
+
+            generate_frame(&mut overlay.internal, &mut self.render_api, true);
+        }
+    }
+
+    // Tolerance (in logical pixels) for the device of the last mouse message
+    fn get_hit_test_tolerance(&self) -> f32 {
+        match self.pointer_input_type {
+            PointerInputType::Mouse => self.hit_test_tolerance.mouse.get(),
+            PointerInputType::Pen => self.hit_test_tolerance.pen.get(),
+            PointerInputType::Touch => self.hit_test_tolerance.touch.get(),
+        }
+    }
+
+    // Hit-tests the overlay layers top-down: if any layer is hit at the current
+    // cursor position, the main content is occluded and the hit test is empty
+    fn hit_test(&mut self) -> FullHitTest {
+
+        let cursor_position = self.internal.current_window_state.mouse_state.cursor_position;
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let focused_node = self.internal.current_window_state.focused_node;
+        let hit_test_tolerance = self.get_hit_test_tolerance();
+
+        for overlay in self.overlays.iter_mut().rev() {
+            let overlay_hit_test = crate::wr_translate::fullhittest_new_webrender(
+                &*overlay.hit_tester.resolve(),
+                overlay.internal.document_id,
+                None,
+                &overlay.internal.layout_results,
+                &cursor_position,
+                hidpi_factor,
+                hit_test_tolerance,
+            );
+            if overlay_hit_test.hovered_nodes.values().any(|ht| !ht.is_empty()) {
+                return FullHitTest::empty(focused_node);
+            }
+        }
+
+        crate::wr_translate::fullhittest_new_webrender(
+            &*self.hit_tester.resolve(),
+            self.internal.document_id,
+            focused_node,
+            &self.internal.layout_results,
+            &cursor_position,
+            hidpi_factor,
+            hit_test_tolerance,
+        )
+    }
+
+    fn set_menu_bar(hwnd: HWND, old: &mut Option<WindowsMenuBar>, menu_bar: Option<&Box<Menu>>) {
+
+        use winapi::um::winuser::SetMenu;
+
+        let hash = old.as_ref().map(|o| o.hash);
+
+        match (hash, menu_bar) {
+            (Some(_), None) => {
+                unsafe { SetMenu(hwnd, ptr::null_mut()); }
+                *old = None;
+            },
+            (None, Some(new)) => {
+                let new_menu_bar = WindowsMenuBar::new(new);
+                unsafe { SetMenu(hwnd, new_menu_bar._native_ptr); }
+                *old = Some(new_menu_bar);
+            }
+            (Some(hash), Some(new)) => {
+                if hash != new.get_hash() {
+                    let new_menu_bar = WindowsMenuBar::new(new);
+                    unsafe { SetMenu(hwnd, new_menu_bar._native_ptr); }
+                    *old = Some(new_menu_bar);
+                }
+            },
+            (None, None) => { }
+        }
+
+    }
+}
+
+// Window styles and placement before set_borderless_fullscreen(), restored by exit_fullscreen()
+struct WindowRestoreState {
+    style: isize,
+    ex_style: isize,
+    placement: WINDOWPLACEMENT,
+}
+
+thread_local! {
+    static FULLSCREEN_RESTORE_STATES: RefCell<BTreeMap<usize, WindowRestoreState>> = RefCell::new(BTreeMap::new());
+}
+
+/// Removes the decorations of the window and resizes it to cover the entire
+/// monitor that it is currently on (including the taskbar area). The previous
+/// styles and placement are restored by `exit_fullscreen`.
+///
+/// Returns `false` if the monitor could not be determined.
+pub fn set_borderless_fullscreen(hwnd: HWND) -> bool {
+
+    use winapi::um::winuser::{
+        GetMonitorInfoW, GetWindowLongPtrW, GetWindowPlacement, MonitorFromWindow,
+        SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE, GWL_STYLE, HWND_TOP, MONITORINFO,
+        MONITOR_DEFAULTTONEAREST, SWP_FRAMECHANGED, SWP_NOOWNERZORDER, SWP_SHOWWINDOW,
+        WS_CAPTION, WS_EX_CLIENTEDGE, WS_EX_DLGMODALFRAME, WS_EX_STATICEDGE,
+        WS_EX_WINDOWEDGE, WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_POPUP, WS_SYSMENU, WS_THICKFRAME,
+    };
+
+    let already_fullscreen = FULLSCREEN_RESTORE_STATES.with(|s| s.borrow().contains_key(&(hwnd as usize)));
+    if already_fullscreen {
+        return true;
+    }
+
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    if monitor.is_null() {
+        return false;
+    }
+
+    let mut monitor_info: MONITORINFO = unsafe { mem::zeroed() };
+    monitor_info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if unsafe { GetMonitorInfoW(monitor, &mut monitor_info) } == 0 {
+        return false;
+    }
+
+    let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    if unsafe { GetWindowPlacement(hwnd, &mut placement) } == 0 {
+        return false;
+    }
+
+    let style = unsafe { GetWindowLongPtrW(hwnd, GWL_STYLE) };
+    let ex_style = unsafe { GetWindowLongPtrW(hwnd, GWL_EXSTYLE) };
+
+    FULLSCREEN_RESTORE_STATES.with(|s| {
+        s.borrow_mut().insert(hwnd as usize, WindowRestoreState { style, ex_style, placement });
+    });
+
+    let decorations = (WS_CAPTION | WS_THICKFRAME | WS_SYSMENU | WS_MINIMIZEBOX | WS_MAXIMIZEBOX) as isize;
+    let ex_decorations = (WS_EX_DLGMODALFRAME | WS_EX_WINDOWEDGE | WS_EX_CLIENTEDGE | WS_EX_STATICEDGE) as isize;
+
+    // full monitor bounds, not the work area
+    let bounds = monitor_info.rcMonitor;
+
+    unsafe {
+        SetWindowLongPtrW(hwnd, GWL_STYLE, (style & !decorations) | WS_POPUP as isize);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style & !ex_decorations);
+        SetWindowPos(
+            hwnd,
+            HWND_TOP,
+            bounds.left,
+            bounds.top,
+            bounds.width() as i32,
+            bounds.height() as i32,
+            SWP_FRAMECHANGED | SWP_NOOWNERZORDER | SWP_SHOWWINDOW,
+        );
+    }
+
+    // the taskbar only gets out of the way on its own while the window is
+    // in the foreground, tell it explicitly that this window is fullscreen
+    mark_fullscreen_window(hwnd, true);
+
+    true
+}
+
+thread_local! {
+    // Windows without decorations (WindowFlags::has_decorations = false), kept outside
+    // of the application data so that WM_NCCALCSIZE can be answered while it is borrowed
+    static CUSTOM_FRAME_WINDOWS: RefCell<BTreeSet<usize>> = RefCell::new(BTreeSet::new());
+}
+
+/// Switches between the native title bar / border and a custom frame drawn by the
+/// application. Without decorations, the client area covers the entire window, but
+/// the window keeps its `WS_THICKFRAME` / `WS_CAPTION` styles, so that snapping and
+/// the minimize / maximize animations still work.
+pub fn set_window_decorations(hwnd: HWND, has_decorations: bool) {
+
+    use winapi::um::winuser::{
+        SetWindowPos, SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOMOVE,
+        SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    let changed = CUSTOM_FRAME_WINDOWS.with(|w| {
+        let mut w = w.borrow_mut();
+        if has_decorations { w.remove(&(hwnd as usize)) } else { w.insert(hwnd as usize) }
+    });
+
+    if !changed {
+        return;
+    }
+
+    // re-sends WM_NCCALCSIZE
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            0, 0, 0, 0,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOOWNERZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+/// Shows or hides the window in the taskbar and the Alt+Tab switcher by switching
+/// between the `WS_EX_APPWINDOW` and `WS_EX_TOOLWINDOW` extended styles.
+///
+/// The taskbar only picks up the new style when the window is shown, so a visible
+/// window is hidden and re-shown (without activating it).
+pub fn set_shown_in_taskbar(hwnd: HWND, shown: bool) {
+
+    use winapi::um::winuser::{
+        GetWindowLongPtrW, SetWindowLongPtrW, SetWindowPos, ShowWindow, IsWindowVisible,
+        GWL_EXSTYLE, WS_EX_APPWINDOW, WS_EX_TOOLWINDOW, SW_HIDE, SW_SHOWNA,
+        SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    unsafe {
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32;
+        let new_ex_style = (ex_style & !(WS_EX_APPWINDOW | WS_EX_TOOLWINDOW)) | taskbar_ex_style(shown);
+        if new_ex_style == ex_style {
+            return;
+        }
+
+        let is_visible = IsWindowVisible(hwnd) != 0;
+        if is_visible {
+            ShowWindow(hwnd, SW_HIDE);
+        }
+
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, new_ex_style as isize);
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            0, 0, 0, 0,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+
+        if is_visible {
+            ShowWindow(hwnd, SW_SHOWNA);
+        }
+    }
+}
+
+/// Enables or disables the native drop shadow of a window without decorations
+/// (see `set_window_decorations`), by extending the DWM frame 1px into the client
+/// area. Has no effect on windows with decorations, which always have a shadow.
+pub fn set_borderless_shadow(hwnd: HWND, shadow: bool) {
+
+    let dwm = match DwmFunctions::initialize() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let is_borderless = CUSTOM_FRAME_WINDOWS.with(|w| w.borrow().contains(&(hwnd as usize)));
+    dwm.extend_frame_into_client_area(hwnd, if shadow && is_borderless { 1 } else { 0 });
+}
+
+// WS_EX_APPWINDOW: taskbar button, WS_EX_TOOLWINDOW: neither taskbar button nor Alt+Tab entry
+fn taskbar_ex_style(shown_in_taskbar: bool) -> u32 {
+    use winapi::um::winuser::{WS_EX_APPWINDOW, WS_EX_TOOLWINDOW};
+    if shown_in_taskbar { WS_EX_APPWINDOW } else { WS_EX_TOOLWINDOW }
+}
+
+// WM_NCCALCSIZE for windows with a custom frame, returns None if the
+// window has decorations and the message needs the default handling
+unsafe fn calc_custom_frame_client_rect(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT> {
+
+    use winapi::um::winuser::{
+        IsZoomed, NCCALCSIZE_PARAMS, SM_CXPADDEDBORDER, SM_CXSIZEFRAME, SM_CYSIZEFRAME,
+    };
+
+    if wparam == 0 || !CUSTOM_FRAME_WINDOWS.with(|w| w.borrow().contains(&(hwnd as usize))) {
+        return None;
+    }
+
+    let params = (lparam as *mut NCCALCSIZE_PARAMS).as_mut()?;
+
+    // A maximized window is positioned so that its (invisible) resize border
+    // lies outside of the monitor: without a non-client area, the content would
+    // spill onto adjacent monitors / under the taskbar, so remove the border again
+    if IsZoomed(hwnd) != 0 {
+        let dpi_functions = self::dpi::DpiFunctions::init();
+        let dpi = dpi_functions.hwnd_dpi(hwnd);
+        let padding = dpi_functions.get_system_metrics(SM_CXPADDEDBORDER, dpi);
+        let frame_x = dpi_functions.get_system_metrics(SM_CXSIZEFRAME, dpi) + padding;
+        let frame_y = dpi_functions.get_system_metrics(SM_CYSIZEFRAME, dpi) + padding;
+
+        let client = &mut params.rgrc[0];
+        client.left += frame_x;
+        client.top += frame_y;
+        client.right -= frame_x;
+        client.bottom -= frame_y;
+    }
+
+    // client area = (adjusted) window rect
+    Some(0)
+}
+
+/// Minimizes, maximizes or restores the window. If `animate` is `false`, the
+/// minimize / maximize animation is suppressed, so that scripted state changes
+/// happen instantly, user-initiated changes should usually still animate.
+///
+/// `WindowFrame::Fullscreen` is ignored, use `set_borderless_fullscreen` instead.
+pub fn set_window_frame(hwnd: HWND, frame: WindowFrame, animate: bool) {
+
+    use winapi::um::winuser::{
+        GetWindowPlacement, IsIconic, IsZoomed, SetWindowPlacement, ShowWindow,
+        SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, SW_SHOWMAXIMIZED, SW_SHOWMINIMIZED, SW_SHOWNORMAL,
+    };
+
+    let (is_minimized, is_maximized) = unsafe { (IsIconic(hwnd) != 0, IsZoomed(hwnd) != 0) };
+
+    let (show_cmd, placement_show_cmd) = match frame {
+        WindowFrame::Minimized if !is_minimized => (SW_MINIMIZE, SW_SHOWMINIMIZED),
+        WindowFrame::Maximized if !is_maximized => (SW_MAXIMIZE, SW_SHOWMAXIMIZED),
+        WindowFrame::Normal if is_minimized || is_maximized => (SW_RESTORE, SW_SHOWNORMAL),
+        _ => return, // already in the requested state
+    };
+
+    if animate {
+        unsafe { ShowWindow(hwnd, show_cmd); }
+        return;
+    }
+
+    // SetWindowPlacement does not play the minimize / maximize animation,
+    // DWMWA_TRANSITIONS_FORCEDISABLED additionally suppresses the DWM transition
+    let dwm = DwmFunctions::initialize();
+    if let Some(dwm) = dwm.as_ref() {
+        dwm.set_transitions_disabled(hwnd, true);
+    }
+
+    let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    if unsafe { GetWindowPlacement(hwnd, &mut placement) } != 0 {
+        placement.showCmd = placement_show_cmd as u32;
+        unsafe { SetWindowPlacement(hwnd, &placement); }
+    } else {
+        unsafe { ShowWindow(hwnd, show_cmd); }
+    }
+
+    if let Some(dwm) = dwm.as_ref() {
+        dwm.set_transitions_disabled(hwnd, false);
+    }
+}
+
+/// Restores the styles and placement of a window that was made fullscreen by
+/// `set_borderless_fullscreen`. Returns `false` if the window wasn't fullscreen.
+pub fn exit_fullscreen(hwnd: HWND) -> bool {
+
+    use winapi::um::winuser::{
+        SetWindowLongPtrW, SetWindowPlacement, SetWindowPos, GWL_EXSTYLE, GWL_STYLE,
+        SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    let restore_state = match FULLSCREEN_RESTORE_STATES.with(|s| s.borrow_mut().remove(&(hwnd as usize))) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    mark_fullscreen_window(hwnd, false);
+
+    unsafe {
+        SetWindowLongPtrW(hwnd, GWL_STYLE, restore_state.style);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, restore_state.ex_style);
+        SetWindowPlacement(hwnd, &restore_state.placement);
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            0, 0, 0, 0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOOWNERZORDER | SWP_FRAMECHANGED,
+        );
+    }
+
+    true
+}
+
+/// Outer frame of a window in its normal (restored) state, in screen pixels
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RestoreBounds {
+    /// Top left corner of the outer frame
+    pub position: PhysicalPositionI32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// WINDOWPLACEMENT::rcNormalPosition is in workspace coordinates (relative to
+// the work area of the monitor, i.e. shifted by a taskbar on the left / top):
+// returns the offset that converts workspace to screen coordinates
+fn get_workspace_offset(hwnd: HWND) -> (i32, i32) {
+
+    use winapi::um::winuser::{
+        GetMonitorInfoW, GetWindowLongPtrW, MonitorFromWindow, GWL_EXSTYLE,
+        MONITORINFO, MONITOR_DEFAULTTONEAREST, WS_EX_TOOLWINDOW,
+    };
+
+    // tool windows use screen coordinates
+    let ex_style = unsafe { GetWindowLongPtrW(hwnd, GWL_EXSTYLE) } as u32;
+    if ex_style & WS_EX_TOOLWINDOW != 0 {
+        return (0, 0);
+    }
+
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    let mut monitor_info: MONITORINFO = unsafe { mem::zeroed() };
+    monitor_info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if monitor.is_null() || unsafe { GetMonitorInfoW(monitor, &mut monitor_info) } == 0 {
+        return (0, 0);
+    }
+
+    (
+        monitor_info.rcWork.left - monitor_info.rcMonitor.left,
+        monitor_info.rcWork.top - monitor_info.rcMonitor.top,
+    )
+}
+
+/// Returns the bounds that the window has in its normal state, independent of
+/// whether it is currently maximized, minimized or borderless fullscreen - i.e.
+/// the rectangle to save so that the layout can be restored on the next start.
+/// Returns `None` if the window does not exist.
+pub fn get_restore_bounds(hwnd: HWND) -> Option<RestoreBounds> {
+
+    use winapi::um::winuser::GetWindowPlacement;
+
+    // while fullscreen, the placement of the window is the monitor
+    let placement = match FULLSCREEN_RESTORE_STATES.with(|s| s.borrow().get(&(hwnd as usize)).map(|r| r.placement)) {
+        Some(s) => s,
+        None => {
+            let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+            placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+            if unsafe { GetWindowPlacement(hwnd, &mut placement) } == 0 {
+                return None;
+            }
+            placement
+        },
+    };
+
+    let (offset_x, offset_y) = get_workspace_offset(hwnd);
+    let rect = placement.rcNormalPosition;
+
+    Some(RestoreBounds {
+        position: PhysicalPositionI32::new(rect.left + offset_x, rect.top + offset_y),
+        width: rect.width().max(0) as u32,
+        height: rect.height().max(0) as u32,
+    })
+}
+
+/// Sets the bounds that the window has in its normal state, without changing the
+/// current state: a maximized window stays maximized and is restored to the new
+/// bounds later, a window in the normal state is moved immediately. Returns
+/// `false` if the window does not exist.
+pub fn set_restore_bounds(hwnd: HWND, bounds: RestoreBounds) -> bool {
+
+    use winapi::um::winuser::{
+        GetWindowPlacement, IsWindowVisible, SetWindowPlacement, SW_HIDE,
+    };
+
+    let (offset_x, offset_y) = get_workspace_offset(hwnd);
+    let rect = RECT {
+        left: bounds.position.x - offset_x,
+        top: bounds.position.y - offset_y,
+        right: bounds.position.x - offset_x + bounds.width as i32,
+        bottom: bounds.position.y - offset_y + bounds.height as i32,
+    };
+
+    // fullscreen: applied when the window exits fullscreen
+    let is_fullscreen = FULLSCREEN_RESTORE_STATES.with(|s| {
+        match s.borrow_mut().get_mut(&(hwnd as usize)) {
+            Some(restore_state) => {
+                restore_state.placement.rcNormalPosition = rect;
+                true
+            },
+            None => false,
+        }
+    });
+
+    if is_fullscreen {
+        return true;
+    }
+
+    let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    if unsafe { GetWindowPlacement(hwnd, &mut placement) } == 0 {
+        return false;
+    }
+
+    placement.rcNormalPosition = rect;
+
+    // GetWindowPlacement reports SW_SHOWNORMAL for hidden
+    // windows, which would show the window
+    if unsafe { IsWindowVisible(hwnd) } == 0 {
+        placement.showCmd = SW_HIDE as u32;
+    }
+
+    unsafe { SetWindowPlacement(hwnd, &placement) != 0 }
+}
+
+// ITaskbarList2::MarkFullscreenWindow
+fn mark_fullscreen_window(hwnd: HWND, fullscreen: bool) {
+
+    use winapi::{
+        shared::{winerror::SUCCEEDED, wtypesbase::CLSCTX_INPROC_SERVER},
+        um::{
+            combaseapi::CoCreateInstance,
+            shobjidl_core::{CLSID_TaskbarList, ITaskbarList2},
+        },
+        Interface,
+    };
+
+    let _com = match self::dialogs::ComInitialized::new() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut taskbar_list: *mut ITaskbarList2 = ptr::null_mut();
+    let hr = unsafe {
+        CoCreateInstance(
+            &CLSID_TaskbarList,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &ITaskbarList2::uuidof(),
+            &mut taskbar_list as *mut *mut ITaskbarList2 as *mut _,
+        )
+    };
+
+    if !SUCCEEDED(hr) || taskbar_list.is_null() {
+        return;
+    }
+
+    unsafe {
+        let taskbar_list = &*taskbar_list;
+        if SUCCEEDED(taskbar_list.HrInit()) {
+            taskbar_list.MarkFullscreenWindow(hwnd, if fullscreen { TRUE } else { 0 });
+        }
+        taskbar_list.Release();
+    }
+}
+
+// Windows hidden / minimized by minimize_all(): (hwnd, was maximized),
+// the window that was in the foreground comes first
+thread_local! {
+    static MINIMIZED_APP_WINDOWS: RefCell<Vec<(usize, bool)>> = RefCell::new(Vec::new());
+}
+
+// Returns all top-level azul windows of this thread (= of the application)
+fn get_app_windows() -> Vec<HWND> {
+
+    use winapi::um::{
+        processthreadsapi::GetCurrentThreadId,
+        winuser::{EnumThreadWindows, GetWindowLongPtrW, GWLP_WNDPROC},
+    };
+
+    unsafe extern "system" fn enum_windows_proc(hwnd: HWND, data: LPARAM) -> BOOL {
+        let windows = &mut *(data as *mut Vec<HWND>);
+        if GetWindowLongPtrW(hwnd, GWLP_WNDPROC) == WindowProc as isize {
+            windows.push(hwnd);
+        }
+        TRUE
+    }
+
+    let mut windows = Vec::new();
+    unsafe {
+        EnumThreadWindows(GetCurrentThreadId(), Some(enum_windows_proc), &mut windows as *mut Vec<HWND> as LPARAM);
+    }
+    windows
+}
+
+/// Minimizes (or, if `hide` is set, hides - e.g. for "minimize to tray") all visible
+/// windows of the application. The previous state of each window is remembered, so
+/// that `restore_all` returns maximized windows to the maximized state.
+pub fn minimize_all(hide: bool) {
+
+    use winapi::um::winuser::{
+        GetForegroundWindow, IsIconic, IsWindowVisible, IsZoomed,
+        ShowWindow, SW_HIDE, SW_MINIMIZE,
+    };
+
+    let foreground_window = unsafe { GetForegroundWindow() };
+
+    let mut minimized = get_app_windows()
+        .into_iter()
+        .filter(|hwnd| unsafe { IsWindowVisible(*hwnd) != 0 && (hide || IsIconic(*hwnd) == 0) })
+        .map(|hwnd| (hwnd as usize, unsafe { IsZoomed(hwnd) != 0 }))
+        .collect::<Vec<_>>();
+
+    minimized.sort_by_key(|(hwnd, _)| *hwnd != foreground_window as usize);
+
+    for (hwnd, _) in minimized.iter() {
+        unsafe { ShowWindow(*hwnd as HWND, if hide { SW_HIDE } else { SW_MINIMIZE }) };
+    }
+
+    MINIMIZED_APP_WINDOWS.with(|m| m.borrow_mut().extend(minimized));
+}
+
+/// Restores all windows that were minimized / hidden by `minimize_all` to their
+/// previous (normal or maximized) state and brings the previously active one to the front
+pub fn restore_all() {
+
+    use winapi::um::winuser::{IsWindow, ShowWindow, SW_SHOWMAXIMIZED, SW_SHOWNORMAL};
+
+    let minimized = MINIMIZED_APP_WINDOWS.with(|m| mem::replace(&mut *m.borrow_mut(), Vec::new()));
+
+    // restore in reverse order, so that the previously active window ends up on top
+    for (hwnd, was_maximized) in minimized.iter().rev() {
+        let hwnd = *hwnd as HWND;
+        if unsafe { IsWindow(hwnd) } == 0 {
+            continue;
+        }
+        unsafe { ShowWindow(hwnd, if *was_maximized { SW_SHOWMAXIMIZED } else { SW_SHOWNORMAL }) };
+    }
+
+    if let Some((hwnd, _)) = minimized.first() {
+        focus_window(*hwnd as HWND);
+    }
+}
+
+/// Stable identifier of a top-level window of the application (wraps the HWND),
+/// used to target window operations from callbacks of any window
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct WindowHandle {
+    pub id: usize,
+}
+
+impl WindowHandle {
+
+    pub fn from_hwnd(hwnd: HWND) -> Self {
+        Self { id: hwnd as usize }
+    }
+
+    pub fn hwnd(&self) -> HWND {
+        self.id as HWND
+    }
+
+    /// Whether the window still exists
+    pub fn is_valid(&self) -> bool {
+        use winapi::um::winuser::IsWindow;
+        unsafe { IsWindow(self.hwnd()) != 0 }
+    }
+
+    /// Returns a snapshot of the current state of the window,
+    /// `None` if the window does not exist anymore
+    pub fn get_info(&self) -> Option<WindowInfo> {
+        WindowInfo::query(self.hwnd())
+    }
+
+    /// Brings the window to the front and gives it the keyboard focus (see `focus_window`)
+    pub fn focus(&self) -> bool {
+        focus_window(self.hwnd())
+    }
+
+    /// Requests the window to close (posts a `WM_CLOSE`, so it is
+    /// safe to call from a callback of the window itself)
+    pub fn close(&self) -> bool {
+        use winapi::um::winuser::{PostMessageW, WM_CLOSE};
+        self.is_valid() && unsafe { PostMessageW(self.hwnd(), WM_CLOSE, 0, 0) != 0 }
+    }
+
+    /// Moves the outer frame of the window to the given position (in screen pixels),
+    /// without changing its size, z-order or activation
+    pub fn move_to(&self, position: PhysicalPositionI32) -> bool {
+        use winapi::um::winuser::{SetWindowPos, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER};
+        self.is_valid() && unsafe {
+            SetWindowPos(
+                self.hwnd(),
+                ptr::null_mut(),
+                position.x,
+                position.y,
+                0,
+                0,
+                SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            ) != 0
+        }
+    }
+}
+
+/// Immutable snapshot of a window, returned by `get_windows`
+///
+/// Read from Win32 (not from the application data), so it can also
+/// be queried from a callback while the application data is borrowed
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowInfo {
+    pub handle: WindowHandle,
+    /// Text of the title bar
+    pub title: AzString,
+    /// Top left corner of the outer frame, in screen pixels
+    pub position: PhysicalPositionI32,
+    /// Size of the client area, in logical pixels
+    pub size: LogicalSize,
+    /// DPI scale factor of the monitor that the window is on
+    pub hidpi_factor: f32,
+    /// Normal, minimized or maximized
+    pub frame: WindowFrame,
+    pub is_visible: bool,
+    /// Whether the window is the foreground window
+    pub has_focus: bool,
+    pub is_always_on_top: bool,
+    pub is_resizable: bool,
+    pub has_decorations: bool,
+    pub is_shown_in_taskbar: bool,
+}
+
+impl WindowInfo {
+    fn query(hwnd: HWND) -> Option<Self> {
+
+        use winapi::um::winuser::{
+            GetClientRect, GetForegroundWindow, GetWindowLongPtrW, GetWindowRect,
+            GetWindowTextLengthW, GetWindowTextW, IsIconic, IsWindow, IsWindowVisible,
+            IsZoomed, GWL_EXSTYLE, GWL_STYLE, WS_CAPTION, WS_EX_TOPMOST, WS_EX_TOOLWINDOW,
+            WS_THICKFRAME,
+        };
+
+        unsafe {
+
+            if IsWindow(hwnd) == 0 {
+                return None;
+            }
+
+            let title_len = GetWindowTextLengthW(hwnd).max(0) as usize;
+            let mut title = vec![0_u16; title_len + 1];
+            let title_len = GetWindowTextW(hwnd, title.as_mut_ptr(), title.len() as i32).max(0) as usize;
+            let title = String::from_utf16_lossy(&title[..title_len]);
+
+            let mut window_rect: RECT = mem::zeroed();
+            GetWindowRect(hwnd, &mut window_rect);
+            let mut client_rect: RECT = mem::zeroed();
+            GetClientRect(hwnd, &mut client_rect);
+
+            let hidpi_factor = self::dpi::dpi_to_scale_factor(self::dpi::DpiFunctions::init().hwnd_dpi(hwnd));
+
+            let style = GetWindowLongPtrW(hwnd, GWL_STYLE) as u32;
+            let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32;
+
+            let frame = if IsIconic(hwnd) != 0 {
+                WindowFrame::Minimized
+            } else if IsZoomed(hwnd) != 0 {
+                WindowFrame::Maximized
+            } else {
+                WindowFrame::Normal
+            };
+
+            Some(Self {
+                handle: WindowHandle::from_hwnd(hwnd),
+                title: title.into(),
+                position: PhysicalPositionI32::new(window_rect.left, window_rect.top),
+                size: LogicalSize::new(
+                    client_rect.width() as f32 / hidpi_factor,
+                    client_rect.height() as f32 / hidpi_factor,
+                ),
+                hidpi_factor,
+                frame,
+                is_visible: IsWindowVisible(hwnd) != 0,
+                has_focus: GetForegroundWindow() == hwnd,
+                is_always_on_top: (ex_style & WS_EX_TOPMOST) != 0,
+                is_resizable: (style & WS_THICKFRAME) != 0,
+                has_decorations: (style & WS_CAPTION) == WS_CAPTION,
+                is_shown_in_taskbar: (ex_style & WS_EX_TOOLWINDOW) == 0,
+            })
+        }
+    }
+}
+
+/// Returns a snapshot of all top-level windows of the application.
+/// Must be called from the thread that runs the event loop.
+pub fn get_windows() -> Vec<WindowInfo> {
+    get_app_windows().into_iter().filter_map(WindowInfo::query).collect()
+}
+
+thread_local! {
+    /// Device name of the monitor that a window is confined to, see `confine_to_monitor`
+    static CONFINED_WINDOWS: RefCell<BTreeMap<usize, String>> = RefCell::new(BTreeMap::new());
+}
+
+/// Moves the window onto the monitor with the given `Monitor::id` (see `get_monitors`),
+/// shrinking it if it is larger than the monitor, and keeps it there: the user can't
+/// drag or resize the window past the monitor bounds anymore (kiosk setups).
+/// If the monitor is disconnected, the window is moved to the primary monitor.
+///
+/// Returns `false` if the window or the monitor does not exist.
+pub fn confine_to_monitor(hwnd: HWND, monitor_id: usize) -> bool {
+
+    use winapi::um::winuser::IsWindow;
+
+    if unsafe { IsWindow(hwnd) } == 0 {
+        return false;
+    }
+
+    let device_name = match enumerate_monitors().iter().find(|m| m.id == monitor_id) {
+        Some(m) => match m.name.as_option() {
+            Some(s) => s.as_str().to_string(),
+            None => return false,
+        },
+        None => return false,
+    };
+
+    CONFINED_WINDOWS.with(|c| c.borrow_mut().insert(hwnd as usize, device_name));
+    apply_monitor_confinement(hwnd);
+    true
+}
+
+/// Allows the window to be moved freely again
+pub fn release_from_monitor(hwnd: HWND) {
+    CONFINED_WINDOWS.with(|c| c.borrow_mut().remove(&(hwnd as usize)));
+}
+
+// Returns the bounds of the monitor that the window is confined to, falls back to
+// (and from now on confines the window to) the primary monitor if it was disconnected
+fn get_confining_monitor_rect(hwnd: HWND) -> Option<RECT> {
+
+    let device_name = CONFINED_WINDOWS.with(|c| c.borrow().get(&(hwnd as usize)).cloned())?;
+
+    let monitors = enumerate_monitors();
+    let monitor = monitors.iter()
+        .find(|m| m.name.as_option().map(|n| n.as_str()) == Some(device_name.as_str()))
+        .or_else(|| monitors.iter().find(|m| m.is_primary_monitor))?;
+
+    if let Some(name) = monitor.name.as_option() {
+        if name.as_str() != device_name {
+            CONFINED_WINDOWS.with(|c| c.borrow_mut().insert(hwnd as usize, name.as_str().to_string()));
+        }
+    }
+
+    Some(RECT {
+        left: monitor.position.x as i32,
+        top: monitor.position.y as i32,
+        right: (monitor.position.x + monitor.size.width) as i32,
+        bottom: (monitor.position.y + monitor.size.height) as i32,
+    })
+}
+
+// Moves the rect inside of the bounds, shrinking it only if it doesn't fit
+fn move_rect_into(rect: &mut RECT, bounds: &RECT) {
+    let width = rect.width().min(bounds.width()) as i32;
+    let height = rect.height().min(bounds.height()) as i32;
+    rect.left = rect.left.max(bounds.left).min(bounds.right - width);
+    rect.top = rect.top.max(bounds.top).min(bounds.bottom - height);
+    rect.right = rect.left + width;
+    rect.bottom = rect.top + height;
+}
+
+// WM_MOVING / WM_SIZING: clamps the dragged rect to the monitor that the window is
+// confined to, returns `false` if the window is not confined to a monitor
+fn clamp_to_confining_monitor(hwnd: HWND, is_sizing: bool, rect: *mut RECT) -> bool {
+
+    let bounds = match get_confining_monitor_rect(hwnd) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let rect = match unsafe { rect.as_mut() } {
+        Some(s) => s,
+        None => return false,
+    };
+
+    if is_sizing {
+        // only the dragged edges move, so clamp them instead of moving the window
+        rect.left = rect.left.max(bounds.left);
+        rect.top = rect.top.max(bounds.top);
+        rect.right = rect.right.min(bounds.right);
+        rect.bottom = rect.bottom.min(bounds.bottom);
+    } else {
+        move_rect_into(rect, &bounds);
+    }
+
+    true
+}
+
+// Moves the window back onto the monitor that it is confined to
+// (after confining it or when the monitor configuration changed)
+fn apply_monitor_confinement(hwnd: HWND) {
+
+    use winapi::um::winuser::{GetWindowRect, SetWindowPos, SWP_NOACTIVATE, SWP_NOZORDER};
+
+    let bounds = match get_confining_monitor_rect(hwnd) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut window_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window_rect) } == 0 {
+        return;
+    }
+
+    let mut new_rect = window_rect;
+    move_rect_into(&mut new_rect, &bounds);
+    if new_rect.left == window_rect.left && new_rect.top == window_rect.top &&
+       new_rect.right == window_rect.right && new_rect.bottom == window_rect.bottom {
+        return;
+    }
+
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            new_rect.left,
+            new_rect.top,
+            new_rect.width() as i32,
+            new_rect.height() as i32,
+            SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+thread_local! {
+    /// Client area width / height that a window is locked to, see `lock_aspect_ratio`
+    static ASPECT_RATIO_WINDOWS: RefCell<BTreeMap<usize, f32>> = RefCell::new(BTreeMap::new());
+}
+
+/// Locks the aspect ratio (width / height) of the client area of the window: when
+/// the user resizes the window, the edge opposite to the dragged one follows so that
+/// the client area keeps the ratio (the window frame is not part of the ratio).
+/// The window is resized to the ratio right away, keeping its width.
+///
+/// Returns `false` if the window does not exist or the ratio is not a positive number.
+pub fn lock_aspect_ratio(hwnd: HWND, ratio: f32) -> bool {
+
+    use winapi::um::winuser::IsWindow;
+
+    if unsafe { IsWindow(hwnd) } == 0 || !ratio.is_finite() || ratio <= 0.0 {
+        return false;
+    }
+
+    ASPECT_RATIO_WINDOWS.with(|a| a.borrow_mut().insert(hwnd as usize, ratio));
+    apply_aspect_ratio(hwnd);
+    true
+}
+
+/// Allows the window to be resized freely again
+pub fn unlock_aspect_ratio(hwnd: HWND) {
+    ASPECT_RATIO_WINDOWS.with(|a| a.borrow_mut().remove(&(hwnd as usize)));
+}
+
+// Width and height of the non-client frame (window rect - client rect)
+fn get_frame_size(hwnd: HWND) -> Option<(i32, i32)> {
+
+    use winapi::um::winuser::{GetClientRect, GetWindowRect};
+
+    let mut window_rect: RECT = unsafe { mem::zeroed() };
+    let mut client_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window_rect) } == 0 ||
+       unsafe { GetClientRect(hwnd, &mut client_rect) } == 0 {
+        return None;
+    }
+
+    Some((
+        window_rect.width() as i32 - client_rect.width() as i32,
+        window_rect.height() as i32 - client_rect.height() as i32,
+    ))
+}
+
+// WM_SIZING: adjusts the dragged rect so that the client area keeps the locked
+// aspect ratio, returns `false` if the aspect ratio of the window is not locked
+fn constrain_to_aspect_ratio(hwnd: HWND, edge: WPARAM, rect: *mut RECT) -> bool {
+
+    use winapi::um::winuser::{
+        WMSZ_LEFT, WMSZ_RIGHT, WMSZ_TOP, WMSZ_BOTTOM,
+        WMSZ_TOPLEFT, WMSZ_TOPRIGHT, WMSZ_BOTTOMLEFT,
+    };
+
+    let ratio = match ASPECT_RATIO_WINDOWS.with(|a| a.borrow().get(&(hwnd as usize)).copied()) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let rect = match unsafe { rect.as_mut() } {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let (frame_width, frame_height) = match get_frame_size(hwnd) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let client_width = (rect.width() as i32 - frame_width).max(1);
+    let client_height = (rect.height() as i32 - frame_height).max(1);
+    let width_from_height = libm::roundf(client_height as f32 * ratio) as i32;
+    let height_from_width = libm::roundf(client_width as f32 / ratio) as i32;
+
+    let dragged_left = edge == WMSZ_LEFT || edge == WMSZ_TOPLEFT || edge == WMSZ_BOTTOMLEFT;
+    let dragged_top = edge == WMSZ_TOP || edge == WMSZ_TOPLEFT || edge == WMSZ_TOPRIGHT;
+
+    let width_follows_height = match edge {
+        WMSZ_TOP | WMSZ_BOTTOM => true,
+        WMSZ_LEFT | WMSZ_RIGHT => false,
+        // corners: the axis that was dragged further wins
+        _ => width_from_height > client_width,
+    };
+
+    // the edges opposite to the dragged edges stay in place
+    if width_follows_height {
+        let width = width_from_height + frame_width;
+        if dragged_left {
+            rect.left = rect.right - width;
+        } else {
+            rect.right = rect.left + width;
+        }
+    } else {
+        let height = height_from_width + frame_height;
+        if dragged_top {
+            rect.top = rect.bottom - height;
+        } else {
+            rect.bottom = rect.top + height;
+        }
+    }
+
+    true
+}
+
+// Resizes the window to its locked aspect ratio (after locking it), keeping the width
+fn apply_aspect_ratio(hwnd: HWND) {
+
+    use winapi::um::winuser::{
+        GetClientRect, SetWindowPos, IsZoomed,
+        SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOZORDER,
+    };
+
+    let ratio = match ASPECT_RATIO_WINDOWS.with(|a| a.borrow().get(&(hwnd as usize)).copied()) {
+        Some(s) => s,
+        None => return,
+    };
+
+    // maximized windows fill the monitor, the ratio applies once they are restored
+    if unsafe { IsZoomed(hwnd) } != 0 {
+        return;
+    }
+
+    let (frame_width, frame_height) = match get_frame_size(hwnd) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut client_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetClientRect(hwnd, &mut client_rect) } == 0 {
+        return;
+    }
+
+    let client_width = client_rect.width() as i32;
+    let client_height = libm::roundf(client_width as f32 / ratio) as i32;
+    if client_width <= 0 || client_height == client_rect.height() as i32 {
+        return;
+    }
+
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            0,
+            0,
+            client_width + frame_width,
+            client_height + frame_height,
+            SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+// WM_WINDOWPOSCHANGING: applies the locked aspect ratio and the monitor confinement
+// to moves / resizes that don't go through WM_MOVING / WM_SIZING (SetWindowPos,
+// snap, arrange), the monitor bounds win over the aspect ratio
+fn constrain_window_pos(hwnd: HWND, window_pos: *mut WINDOWPOS) {
+
+    use winapi::um::winuser::{GetWindowRect, IsIconic, IsZoomed, SWP_NOMOVE, SWP_NOSIZE};
+
+    let window_pos = match unsafe { window_pos.as_mut() } {
+        Some(s) => s,
+        None => return,
+    };
+
+    let no_move = window_pos.flags & SWP_NOMOVE != 0;
+    let no_size = window_pos.flags & SWP_NOSIZE != 0;
+    if no_move && no_size {
+        return;
+    }
+
+    // minimized / maximized windows are positioned by the OS
+    if unsafe { IsIconic(hwnd) } != 0 || unsafe { IsZoomed(hwnd) } != 0 {
+        return;
+    }
+
+    let mut window_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window_rect) } == 0 {
+        return;
+    }
+
+    // fill in the current position / size for the parts that don't change
+    let (x, y) = if no_move { (window_rect.left, window_rect.top) } else { (window_pos.x, window_pos.y) };
+    let (width, height) = if no_size {
+        (window_rect.width() as i32, window_rect.height() as i32)
+    } else {
+        (window_pos.cx, window_pos.cy)
+    };
+    let mut rect = RECT { left: x, top: y, right: x + width, bottom: y + height };
+
+    let ratio = ASPECT_RATIO_WINDOWS.with(|a| a.borrow().get(&(hwnd as usize)).copied());
+    if let (false, Some(ratio), Some((frame_width, frame_height))) = (no_size, ratio, get_frame_size(hwnd)) {
+        let client_width = (width - frame_width).max(1);
+        let client_height = (height - frame_height).max(1);
+        let height_from_width = libm::roundf(client_width as f32 / ratio) as i32;
+        // interactive resizes already keep the ratio (up to rounding), see WM_SIZING
+        if (height_from_width - client_height).abs() > 1 {
+            rect.bottom = rect.top + height_from_width + frame_height;
+        }
+    }
+
+    if let Some(bounds) = get_confining_monitor_rect(hwnd) {
+        move_rect_into(&mut rect, &bounds);
+    }
+
+    if rect.left != x || rect.top != y {
+        window_pos.x = rect.left;
+        window_pos.y = rect.top;
+        window_pos.flags &= !SWP_NOMOVE;
+    }
+
+    if rect.width() as i32 != width || rect.height() as i32 != height {
+        window_pos.cx = rect.width() as i32;
+        window_pos.cy = rect.height() as i32;
+        window_pos.flags &= !SWP_NOSIZE;
+    }
+}
+
+/// System shortcuts that are swallowed while the kiosk mode is enabled, see `enable_kiosk_mode`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KioskKeys {
+    /// Alt + Tab / Alt + Shift + Tab (task switcher)
+    pub alt_tab: bool,
+    /// Alt + Esc (cycles through the windows)
+    pub alt_esc: bool,
+    /// Alt + F4 (closes the window)
+    pub alt_f4: bool,
+    /// Ctrl + Esc (opens the start menu)
+    pub ctrl_esc: bool,
+    /// Left / right Windows key, including all Win + X shortcuts except Win + L
+    pub windows_key: bool,
+}
+
+impl KioskKeys {
+    /// Blocks every shortcut that can be blocked from userspace
+    pub const fn all() -> Self {
+        Self {
+            alt_tab: true,
+            alt_esc: true,
+            alt_f4: true,
+            ctrl_esc: true,
+            windows_key: true,
+        }
+    }
+}
+
+thread_local! {
+    /// Installed WH_KEYBOARD_LL hook and the keys it blocks
+    static KIOSK_MODE: Cell<Option<(usize, KioskKeys)>> = Cell::new(None);
+}
+
+/// Enables the kiosk mode: installs a low-level keyboard hook that swallows the
+/// given system shortcuts while a window of this process is the foreground window.
+/// Intended to be combined with a fullscreen window (and `confine_to_monitor`).
+///
+/// Must be called from the thread that runs the event loop (the hook is called
+/// from its message loop). Calling it again replaces the blocked keys.
+///
+/// NOTE: Ctrl + Alt + Del (the secure attention sequence) and Win + L can't be
+/// blocked from userspace at all, neither can the shortcuts of accessibility tools
+/// (i.e. Win + U). Locking those down requires administrator configuration: group
+/// policies (`DisableTaskMgr`, `DisableLockWorkstation`, `DisableChangePassword`),
+/// a custom shell (Shell Launcher) or Assigned Access. Since the hook is per-user,
+/// other processes with UIAccess (i.e. the on-screen keyboard) are not affected.
+///
+/// Returns `false` if the hook could not be installed.
+pub fn enable_kiosk_mode(keys: KioskKeys) -> bool {
+
+    use winapi::um::{
+        libloaderapi::GetModuleHandleW,
+        winuser::{SetWindowsHookExW, WH_KEYBOARD_LL},
+    };
+
+    if let Some((hook, _)) = KIOSK_MODE.with(|k| k.get()) {
+        KIOSK_MODE.with(|k| k.set(Some((hook, keys))));
+        return true;
+    }
+
+    let hook = unsafe {
+        SetWindowsHookExW(WH_KEYBOARD_LL, Some(kiosk_keyboard_hook), GetModuleHandleW(ptr::null()), 0)
+    };
+
+    if hook.is_null() {
+        return false;
+    }
+
+    KIOSK_MODE.with(|k| k.set(Some((hook as usize, keys))));
+    true
+}
+
+/// Disables the kiosk mode and releases the keyboard hook
+pub fn disable_kiosk_mode() {
+
+    use winapi::um::winuser::UnhookWindowsHookEx;
+
+    if let Some((hook, _)) = KIOSK_MODE.with(|k| k.take()) {
+        unsafe { UnhookWindowsHookEx(hook as HHOOK); }
+    }
+}
+
+/// Whether the kiosk mode is enabled (on the current thread)
+pub fn is_kiosk_mode_enabled() -> bool {
+    KIOSK_MODE.with(|k| k.get()).is_some()
+}
+
+// WH_KEYBOARD_LL hook of the kiosk mode: swallows the blocked shortcuts while
+// a window of this process is in the foreground, passes on everything else
+unsafe extern "system" fn kiosk_keyboard_hook(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+
+    use winapi::um::{
+        processthreadsapi::GetCurrentProcessId,
+        winuser::{
+            CallNextHookEx, GetAsyncKeyState, GetForegroundWindow, GetWindowThreadProcessId,
+            HC_ACTION, KBDLLHOOKSTRUCT, LLKHF_ALTDOWN, VK_CONTROL, VK_ESCAPE, VK_F4,
+            VK_LWIN, VK_RWIN, VK_TAB,
+        },
+    };
+
+    let (hook, keys) = match KIOSK_MODE.with(|k| k.get()) {
+        Some(s) => s,
+        None => return CallNextHookEx(ptr::null_mut(), code, wparam, lparam),
+    };
+
+    if code != HC_ACTION {
+        return CallNextHookEx(hook as HHOOK, code, wparam, lparam);
+    }
+
+    let mut foreground_process: u32 = 0;
+    GetWindowThreadProcessId(GetForegroundWindow(), &mut foreground_process);
+    if foreground_process != GetCurrentProcessId() {
+        return CallNextHookEx(hook as HHOOK, code, wparam, lparam);
+    }
+
+    let key = &*(lparam as *const KBDLLHOOKSTRUCT);
+    let vk = key.vkCode as i32;
+    let alt_down = key.flags & LLKHF_ALTDOWN != 0;
+    let ctrl_down = GetAsyncKeyState(VK_CONTROL) < 0;
+
+    let block = (keys.alt_tab && alt_down && vk == VK_TAB) ||
+                (keys.alt_esc && alt_down && vk == VK_ESCAPE) ||
+                (keys.alt_f4 && alt_down && vk == VK_F4) ||
+                (keys.ctrl_esc && ctrl_down && vk == VK_ESCAPE) ||
+                (keys.windows_key && (vk == VK_LWIN || vk == VK_RWIN));
+
+    if block {
+        1
+    } else {
+        CallNextHookEx(hook as HHOOK, code, wparam, lparam)
+    }
+}
+
+/// Screen edge that an appbar is docked to
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AppBarEdge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+impl AppBarEdge {
+    fn to_abe(&self) -> u32 {
+        use winapi::um::shellapi::{ABE_BOTTOM, ABE_LEFT, ABE_RIGHT, ABE_TOP};
+        match self {
+            AppBarEdge::Left => ABE_LEFT,
+            AppBarEdge::Top => ABE_TOP,
+            AppBarEdge::Right => ABE_RIGHT,
+            AppBarEdge::Bottom => ABE_BOTTOM,
+        }
+    }
+}
+
+// Edge, thickness (physical pixels) and auto-hide state of a registered appbar
+#[derive(Debug, Copy, Clone)]
+struct AppBarState {
+    edge: AppBarEdge,
+    thickness: i32,
+    autohide: bool,
+}
+
+thread_local! {
+    static APPBAR_STATES: RefCell<BTreeMap<usize, AppBarState>> = RefCell::new(BTreeMap::new());
+}
+
+fn new_appbar_data(hwnd: HWND) -> winapi::um::shellapi::APPBARDATA {
+    use winapi::um::shellapi::APPBARDATA;
+    let mut abd: APPBARDATA = unsafe { mem::zeroed() };
+    abd.cbSize = mem::size_of::<APPBARDATA>() as u32;
+    abd.hWnd = hwnd;
+    abd.uCallbackMessage = AZ_APPBAR_NOTIFY;
+    abd
+}
+
+/// Registers the window as an appbar (like the taskbar), docked to the given edge of
+/// the monitor that it is currently on. The shell reserves `thickness` logical pixels
+/// at that edge, so that maximized windows don't cover the appbar. If the shell moves
+/// the reserved area (other appbars, taskbar changes), the window is repositioned.
+///
+/// Returns `false` if the window is already registered or the shell refused the request.
+pub fn register_appbar(hwnd: HWND, edge: AppBarEdge, thickness: f32) -> bool {
+
+    use winapi::um::shellapi::{SHAppBarMessage, ABM_NEW};
+
+    if APPBAR_STATES.with(|s| s.borrow().contains_key(&(hwnd as usize))) {
+        return false;
+    }
+
+    let mut abd = new_appbar_data(hwnd);
+    if unsafe { SHAppBarMessage(ABM_NEW, &mut abd) } == 0 {
+        return false;
+    }
+
+    let dpi = unsafe { DpiFunctions::init().hwnd_dpi(hwnd) };
+    let state = AppBarState {
+        edge,
+        thickness: (thickness * self::dpi::dpi_to_scale_factor(dpi)).round() as i32,
+        autohide: false,
+    };
+
+    APPBAR_STATES.with(|s| s.borrow_mut().insert(hwnd as usize, state));
+    set_appbar_pos(hwnd, state);
+    true
+}
+
+/// Removes the appbar registration of the window and releases the reserved screen space
+pub fn unregister_appbar(hwnd: HWND) {
+
+    use winapi::um::shellapi::{SHAppBarMessage, ABM_REMOVE, ABM_SETAUTOHIDEBAREX};
+
+    let state = match APPBAR_STATES.with(|s| s.borrow_mut().remove(&(hwnd as usize))) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut abd = new_appbar_data(hwnd);
+    if state.autohide {
+        abd.uEdge = state.edge.to_abe();
+        abd.lParam = 0;
+        unsafe { SHAppBarMessage(ABM_SETAUTOHIDEBAREX, &mut abd) };
+    }
+    unsafe { SHAppBarMessage(ABM_REMOVE, &mut abd) };
+}
+
+/// Switches a registered appbar between reserving its screen edge and auto-hiding.
+/// An auto-hide appbar does not reserve any screen space: it is kept at the edge
+/// of the monitor and the app is responsible for collapsing / expanding it.
+///
+/// Returns `false` if the window is not registered as an appbar or if the edge
+/// already has an auto-hide appbar (only one per edge and monitor is allowed).
+pub fn set_appbar_autohide(hwnd: HWND, autohide: bool) -> bool {
+
+    use winapi::um::shellapi::{SHAppBarMessage, ABM_SETAUTOHIDEBAREX};
+
+    let mut state = match APPBAR_STATES.with(|s| s.borrow().get(&(hwnd as usize)).copied()) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    if state.autohide == autohide {
+        return true;
+    }
+
+    let mut abd = new_appbar_data(hwnd);
+    abd.uEdge = state.edge.to_abe();
+    abd.lParam = if autohide { TRUE as LPARAM } else { 0 };
+
+    if unsafe { SHAppBarMessage(ABM_SETAUTOHIDEBAREX, &mut abd) } == 0 {
+        return false;
+    }
+
+    state.autohide = autohide;
+    APPBAR_STATES.with(|s| s.borrow_mut().insert(hwnd as usize, state));
+    set_appbar_pos(hwnd, state);
+    true
+}
+
+/// Returns whether an auto-hide appbar (for example an auto-hiding taskbar) is docked
+/// to the given edge of the monitor that the window is on. Borderless fullscreen /
+/// maximized windows should then leave a 1px gap at that edge, so that the user can
+/// still reveal the appbar.
+pub fn has_autohide_appbar(hwnd: HWND, edge: AppBarEdge) -> bool {
+
+    use winapi::um::{
+        shellapi::{SHAppBarMessage, ABM_GETAUTOHIDEBAREX},
+        winuser::{GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST},
+    };
+
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    let mut monitor_info: MONITORINFO = unsafe { mem::zeroed() };
+    monitor_info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if monitor.is_null() || unsafe { GetMonitorInfoW(monitor, &mut monitor_info) } == 0 {
+        return false;
+    }
+
+    let mut abd = new_appbar_data(hwnd);
+    abd.uEdge = edge.to_abe();
+    abd.rc = monitor_info.rcMonitor;
+
+    let autohide_appbar = unsafe { SHAppBarMessage(ABM_GETAUTOHIDEBAREX, &mut abd) } as HWND;
+    !autohide_appbar.is_null() && autohide_appbar != hwnd
+}
+
+// Negotiates the position of the appbar with the shell (ABM_QUERYPOS / ABM_SETPOS)
+// and moves the window there. Auto-hide appbars don't reserve any space and are
+// only moved to the edge of the monitor.
+fn set_appbar_pos(hwnd: HWND, state: AppBarState) {
+
+    use winapi::um::{
+        shellapi::{SHAppBarMessage, ABM_QUERYPOS, ABM_SETPOS},
+        winuser::{
+            GetMonitorInfoW, MonitorFromWindow, MoveWindow,
+            MONITORINFO, MONITOR_DEFAULTTONEAREST,
+        },
+    };
+
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    let mut monitor_info: MONITORINFO = unsafe { mem::zeroed() };
+    monitor_info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if monitor.is_null() || unsafe { GetMonitorInfoW(monitor, &mut monitor_info) } == 0 {
+        return;
+    }
+
+    fn fit_to_edge(rc: &mut RECT, edge: AppBarEdge, thickness: i32) {
+        match edge {
+            AppBarEdge::Left => rc.right = rc.left + thickness,
+            AppBarEdge::Top => rc.bottom = rc.top + thickness,
+            AppBarEdge::Right => rc.left = rc.right - thickness,
+            AppBarEdge::Bottom => rc.top = rc.bottom - thickness,
+        }
+    }
+
+    let mut abd = new_appbar_data(hwnd);
+    abd.uEdge = state.edge.to_abe();
+    abd.rc = monitor_info.rcMonitor;
+
+    if state.autohide {
+        fit_to_edge(&mut abd.rc, state.edge, state.thickness);
+    } else {
+        // propose the entire edge, the shell shrinks the rect
+        // so that it doesn't overlap the taskbar / other appbars
+        fit_to_edge(&mut abd.rc, state.edge, state.thickness);
+        unsafe { SHAppBarMessage(ABM_QUERYPOS, &mut abd) };
+        fit_to_edge(&mut abd.rc, state.edge, state.thickness);
+        unsafe { SHAppBarMessage(ABM_SETPOS, &mut abd) };
+    }
+
+    unsafe {
+        MoveWindow(
+            hwnd,
+            abd.rc.left,
+            abd.rc.top,
+            abd.rc.width() as i32,
+            abd.rc.height() as i32,
+            TRUE,
+        );
+    }
+}
+
+// Moves the window to the center of the work area (screen without taskbar)
+// of the monitor that the window currently resides on
+fn center_window_on_monitor(hwnd: HWND) {
+
+    use winapi::um::winuser::{
+        GetMonitorInfoW, GetWindowRect, MonitorFromWindow, SetWindowPos,
+        MONITORINFO, MONITOR_DEFAULTTONEAREST, SWP_NOSIZE,
+        SWP_NOZORDER, SWP_NOACTIVATE,
+    };
+
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    if monitor.is_null() {
+        return;
+    }
+
+    let mut monitor_info: MONITORINFO = unsafe { mem::zeroed() };
+    monitor_info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    if unsafe { GetMonitorInfoW(monitor, &mut monitor_info) } == 0 {
+        return;
+    }
+
+    let mut window_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window_rect) } == 0 {
+        return;
+    }
+
+    let work_area = monitor_info.rcWork;
+    let x = work_area.left + (work_area.width() as i32 - window_rect.width() as i32) / 2;
+    let y = work_area.top + (work_area.height() as i32 - window_rect.height() as i32) / 2;
+
+    // if the window is larger than the work area, keep the title bar visible
+    let x = x.max(work_area.left);
+    let y = y.max(work_area.top);
+
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            ptr::null_mut(),
+            x,
+            y,
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
+/// Creates the webrender renderer (and the sender to create the `RenderApi`)
+/// for the currently active OpenGL context
+fn create_webrender_renderer(
+    gl: Rc<GenericGlContext>,
+    debug_state: &DebugState,
+    program_cache: Rc<WrProgramCache>,
+) -> Result<(WrRenderer, WrRenderApiSender), WrRendererError>
+{
+    use crate::wr_translate::wr_translate_debug_flags;
+    use webrender::api::ColorF as WrColorF;
+
+    WrRenderer::new(
+        gl,
+        Box::new(Notifier {}),
+        WrRendererOptions {
+            resource_override_path: None,
+            use_optimized_shaders: true,
+            enable_aa: true,
+            enable_subpixel_aa: true,
+            force_subpixel_aa: true,
+            clear_color: WrColorF {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            }, // transparent
+            panic_on_gl_error: false,
+            precache_flags: WrShaderPrecacheFlags::EMPTY,
+            cached_programs: Some(program_cache),
+            enable_multithreading: true,
+            debug_flags: wr_translate_debug_flags(debug_state),
+            ..WrRendererOptions::default()
+        },
+        WR_SHADER_CACHE,
+    )
+}
+
+// Makes the OpenGL context of a window current on the calling thread, unless it
+// already is (i.e. the same window rendered the last frame). Every window loads
+// its own GL function pointers for its own context, so the GlFunctions of a window
+// may only be used after its context was made current here. Returns false if the
+// context could not be made current.
+fn make_gl_context_current(hdc: HDC, context: HGLRC) -> bool {
+
+    use winapi::um::wingdi::{wglGetCurrentContext, wglGetCurrentDC, wglMakeCurrent};
+
+    unsafe {
+        if wglGetCurrentContext() == context && wglGetCurrentDC() == hdc {
+            return true;
+        }
+
+        wglMakeCurrent(hdc, context) == TRUE
+    }
+}
+
+/// Creates an OpenGL 3.2 context using wglCreateContextAttribsARB
+// Creates an OpenGL context with the requested version / profile (or the next lower
+// available version), returns the context and the version that was actually created.
+// If share_context is not null, the new context shares its objects with it.
+fn create_gl_context(
+    hwnd: HWND,
+    hinstance: HINSTANCE,
+    extra: &ExtraWglFunctions,
+    options: &GlContextOptions,
+    share_context: HGLRC,
+) -> Result<(HGLRC, GlContextOptions), WindowsOpenGlError>
+{
+    use winapi::um::{
+        wingdi::{
+            wglCreateContext, wglDeleteContext,
+            wglMakeCurrent, ChoosePixelFormat,
+            DescribePixelFormat, SetPixelFormat,
+        },
+        winuser::{GetDC, ReleaseDC}
+    };
+
+    use winapi::shared::winerror::{ERROR_INVALID_OPERATION, ERROR_INVALID_PIXEL_FORMAT};
+    use self::WindowsOpenGlError::*;
+
+    let wglCreateContextAttribsARB = extra.wglCreateContextAttribsARB
+    .ok_or(OpenGLNotAvailable(get_last_error()))?;
+
+    let wglChoosePixelFormatARB = extra.wglChoosePixelFormatARB
+    .ok_or(OpenGLNotAvailable(get_last_error()))?;
+
+    let opengl32_dll = load_dll("opengl32.dll")
+    .ok_or(OpenGL32DllNotFound(get_last_error()))?;
+
+    let hDC = unsafe { GetDC(hwnd) };
+    if hDC.is_null() {
+        return Err(FailedToGetDC(get_last_error()));
+    }
+
+    // https://www.khronos.org/registry/OpenGL/api/GL/wglext.h
+    const WGL_DRAW_TO_WINDOW_ARB: i32 = 0x2001;
+    const WGL_DOUBLE_BUFFER_ARB: i32 = 0x2011;
+    const WGL_SUPPORT_OPENGL_ARB: i32 = 0x2010;
+    const WGL_PIXEL_TYPE_ARB: i32 = 0x2013;
+    const WGL_TYPE_RGBA_ARB: i32 = 0x202B;
+    const WGL_TRANSPARENT_ARB: i32 = 0x200A;
+    const WGL_COLOR_BITS_ARB: i32 = 0x2014;
+    const WGL_RED_BITS_ARB: i32 = 0x2015;
+    const WGL_GREEN_BITS_ARB: i32 = 0x2017;
+    const WGL_BLUE_BITS_ARB: i32 = 0x2019;
+    const WGL_ALPHA_BITS_ARB: i32 = 0x201B;
+    const WGL_DEPTH_BITS_ARB: i32 = 0x2022;
+    const WGL_STENCIL_BITS_ARB: i32 = 0x2023;
+    const WGL_FULL_ACCELERATION_ARB: i32 =  0x2027;
+    const WGL_ACCELERATION_ARB: i32 = 0x2003;
+
+    const GL_TRUE: i32 = 1;
+    const GL_FALSE: i32 = 0;
+
+    let double_buffer = if options.buffering.is_double_buffered() { GL_TRUE } else { GL_FALSE };
+
+    let pixel_format_attribs = [
+        WGL_DRAW_TO_WINDOW_ARB,     GL_TRUE,
+        WGL_SUPPORT_OPENGL_ARB,     GL_TRUE,
+        WGL_DOUBLE_BUFFER_ARB,      double_buffer,
+        WGL_ACCELERATION_ARB,       WGL_FULL_ACCELERATION_ARB,
+        WGL_PIXEL_TYPE_ARB,         WGL_TYPE_RGBA_ARB,
+        WGL_COLOR_BITS_ARB,         32,
+        WGL_DEPTH_BITS_ARB,         24,
+        WGL_STENCIL_BITS_ARB,       8,
+        0
+    ];
+
+    let mut pixel_format = 0;
+    let mut num_formats = 0;
+    unsafe { (wglChoosePixelFormatARB)(hDC, pixel_format_attribs.as_ptr(), ptr::null_mut(), 1, &mut pixel_format, &mut num_formats) };
+    if num_formats == 0 {
+        unsafe { ReleaseDC(hwnd, hDC); }
+        return Err(NoMatchingPixelFormat(get_last_error()));
+    }
 
-impl Drop for GlFunctions {
-    fn drop(&mut self) {
-        use winapi::um::libloaderapi::FreeLibrary;
-        if let Some(opengl32) = self._opengl32_dll_handle {
-            unsafe {
-                FreeLibrary(opengl32);
+    let mut pfd: PIXELFORMATDESCRIPTOR = get_default_pfd();
+
+    unsafe {
+        DescribePixelFormat(hDC, pixel_format, mem::size_of::<PIXELFORMATDESCRIPTOR>() as u32, &mut pfd);
+        if SetPixelFormat(hDC, pixel_format, &mut pfd) != TRUE{
+            ReleaseDC(hwnd, hDC);
+            return Err(NoMatchingPixelFormat(get_last_error()));
+        }
+    }
+
+    // https://www.khronos.org/registry/OpenGL/extensions/ARB/WGL_ARB_create_context.txt
+    const WGL_CONTEXT_MAJOR_VERSION_ARB: i32 = 0x2091;
+    const WGL_CONTEXT_MINOR_VERSION_ARB: i32 = 0x2092;
+    const WGL_CONTEXT_FLAGS_ARB: i32 = 0x2094;
+    const WGL_CONTEXT_PROFILE_MASK_ARB: i32 = 0x9126;
+    const WGL_CONTEXT_DEBUG_BIT_ARB: i32 = 0x00000001;
+    const WGL_CONTEXT_CORE_PROFILE_BIT_ARB: i32 = 0x00000001;
+    const WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB: i32 = 0x00000002;
+
+    let profile_mask = match options.profile {
+        GlContextProfile::Core => WGL_CONTEXT_CORE_PROFILE_BIT_ARB,
+        GlContextProfile::Compatibility => WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB,
+    };
+
+    let context_flags = if options.debug { WGL_CONTEXT_DEBUG_BIT_ARB } else { 0 };
+
+    // Try the requested version first, then fall back to lower versions,
+    // down to OpenGL 3.2 core (#version 150 is required by webrender)
+    let requested = (options.major_version, options.minor_version);
+    let versions = Some(requested).into_iter().chain(
+        GL_FALLBACK_VERSIONS.iter().copied().filter(|v| *v < requested)
+    );
+
+    for (major, minor) in versions {
+
+        let context_attribs = [
+            WGL_CONTEXT_MAJOR_VERSION_ARB, major as i32,
+            WGL_CONTEXT_MINOR_VERSION_ARB, minor as i32,
+            WGL_CONTEXT_PROFILE_MASK_ARB,  profile_mask,
+            WGL_CONTEXT_FLAGS_ARB,         context_flags,
+            0,
+        ];
+
+        let context = unsafe { (wglCreateContextAttribsARB)(hDC, share_context, context_attribs.as_ptr()) };
+        if context.is_null() {
+            // the version is fine, but the context to share with is not
+            // compatible: lower versions won't help
+            let error = get_last_error();
+            if !share_context.is_null() && (error == ERROR_INVALID_OPERATION || error == ERROR_INVALID_PIXEL_FORMAT) {
+                unsafe { ReleaseDC(hwnd, hDC); }
+                return Err(IncompatibleSharedContext(error));
+            }
+            continue;
+        }
+
+        unsafe { ReleaseDC(hwnd, hDC); }
+
+        #[cfg(feature = "logging")] {
+            if (major, minor) != requested {
+                warn!(
+                    "OpenGL {}.{} context not available, created OpenGL {}.{} context instead",
+                    requested.0, requested.1, major, minor
+                );
             }
         }
+
+        let obtained = GlContextOptions {
+            major_version: major,
+            minor_version: minor,
+            .. *options
+        };
+
+        return Ok((context, obtained));
     }
-}
 
-#[derive(Default)]
-struct ExtraWglFunctions {
-    wglCreateContextAttribsARB: Option<extern "system" fn(HDC, HGLRC, *const i32) -> HGLRC>,
-    wglSwapIntervalEXT: Option<extern "system" fn(i32) -> i32>,
-    wglChoosePixelFormatARB: Option<extern "system" fn(HDC, *const i32, *const f32, u32, *mut i32, *mut u32) -> BOOL>,
+    unsafe { ReleaseDC(hwnd, hDC); }
+    Err(OpenGLNotAvailable(get_last_error()))
 }
 
-impl fmt::Debug for ExtraWglFunctions {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.wglCreateContextAttribsARB.map(|f| f as usize).fmt(f)?;
-        self.wglSwapIntervalEXT.map(|f| f as usize).fmt(f)?;
-        self.wglChoosePixelFormatARB.map(|f| f as usize).fmt(f)?;
-        Ok(())
+// Versions tried (in order) if the requested OpenGL version is not available
+const GL_FALLBACK_VERSIONS: &[(u8, u8)] = &[
+    (4, 6), (4, 5), (4, 4), (4, 3), (4, 2), (4, 1), (4, 0),
+    (3, 3), (3, 2),
+];
+
+
+use winapi::um::wingdi::PIXELFORMATDESCRIPTOR;
+
+fn get_default_pfd() -> PIXELFORMATDESCRIPTOR {
+
+    use winapi::um::wingdi::{
+        PFD_DRAW_TO_WINDOW,
+        PFD_SUPPORT_OPENGL,
+        PFD_GENERIC_ACCELERATED,
+        PFD_DOUBLEBUFFER,
+        PFD_MAIN_PLANE,
+        PFD_TYPE_RGBA,
+        PFD_SUPPORT_COMPOSITION,
+    };
+
+    PIXELFORMATDESCRIPTOR {
+        nSize: mem::size_of::<PIXELFORMATDESCRIPTOR> as u16,
+        nVersion: 1,
+        dwFlags: {
+            PFD_DRAW_TO_WINDOW |        // support window
+            PFD_SUPPORT_OPENGL |        // support OpenGL
+            PFD_DOUBLEBUFFER            // double buffered
+        },
+        iPixelType: PFD_TYPE_RGBA as u8,
+        cColorBits: 32,
+        cRedBits: 0,
+        cRedShift: 0,
+        cGreenBits: 0,
+        cGreenShift: 0,
+        cBlueBits: 0,
+        cBlueShift: 0,
+        cAlphaBits: 8, // request alpha
+        cAlphaShift: 0,
+        cAccumBits: 0,
+        cAccumRedBits: 0,
+        cAccumGreenBits: 0,
+        cAccumBlueBits: 0,
+        cAccumAlphaBits: 0,
+        cDepthBits: 24,                   // 16-bit z-buffer
+        cStencilBits: 8,                  // 8-bit stencil
+        cAuxBuffers: 0,                   // no auxiliary buffer
+        iLayerType: PFD_MAIN_PLANE as u8, // main layer
+        bReserved: 0,
+        dwLayerMask: 0,
+        dwVisibleMask: 0,
+        dwDamageMask: 0,
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub enum ExtraWglFunctionsLoadError {
-    FailedToCreateDummyWindow,
-    FailedToFindPixelFormat,
-    FailedToSetPixelFormat,
-    FailedToCreateDummyGlContext,
-    FailedToActivateDummyGlContext,
+#[derive(Debug)]
+struct WindowsMenuBar {
+    _native_ptr: HMENU,
+    /// Map from Command -> callback to call
+    callbacks: BTreeMap<u16, MenuCallback>,
+    hash: u64,
 }
 
-impl ExtraWglFunctions {
-    pub fn load() -> Result<Self, ExtraWglFunctionsLoadError> {
+static WINDOWS_UNIQUE_COMMAND_ID_GENERATOR: AtomicUsize = AtomicUsize::new(1); // 0 = no command
 
-        use winapi::um::{
-            libloaderapi::GetModuleHandleW,
-            winuser::{
-                CreateWindowExW, GetDC,
-                ReleaseDC, DestroyWindow,
-                CW_USEDEFAULT
-            },
-            wingdi::{
-                wglGetProcAddress, ChoosePixelFormat,
-                SetPixelFormat, wglCreateContext,
-                wglMakeCurrent, wglDeleteContext,
-            },
-        };
-        use self::ExtraWglFunctionsLoadError::*;
+impl WindowsMenuBar {
 
-        unsafe {
+    fn new(new: &Menu) -> Self {
+        use winapi::um::winuser::CreateMenu;
 
-            let mut hidden_class_name = encode_wide(CLASS_NAME);
-            let mut hidden_window_title = encode_wide("Dummy Window");
+        let hash = new.get_hash();
+        let mut root = unsafe { CreateMenu() };
+        let mut command_map = BTreeMap::new();
 
-            let dummy_window = CreateWindowExW(
-                0,
-                hidden_class_name.as_mut_ptr(),
-                hidden_window_title.as_mut_ptr(),
-                0,
-                CW_USEDEFAULT,
-                CW_USEDEFAULT,
-                CW_USEDEFAULT,
-                CW_USEDEFAULT,
-                ptr::null_mut(),
-                ptr::null_mut(),
-                GetModuleHandleW(ptr::null_mut()),
-                ptr::null_mut(),
-            );
+        Self::recursive_construct_menu(&mut root, new.items.as_ref(), &mut command_map);
+
+        Self {
+            _native_ptr: root,
+            callbacks: command_map,
+            hash,
+        }
+    }
+
+    fn get_new_command_id() -> usize {
+        WINDOWS_UNIQUE_COMMAND_ID_GENERATOR.fetch_add(1, AtomicOrdering::SeqCst)
+    }
+
+    fn recursive_construct_menu(
+        menu: &mut HMENU,
+        items: &[MenuItem],
+        command_map: &mut BTreeMap<u16, MenuCallback>,
+    ) {
+        fn convert_widestring(input: &str) -> Vec<u16> {
+            let mut v: Vec<u16> = input
+                .chars()
+                .filter_map(|s| {
+                    use std::convert::TryInto;
+                    (s as u32).try_into().ok()
+                })
+                .collect();
+            v.push(0);
+            v
+        }
+
+        use winapi::shared::basetsd::UINT_PTR;
+        use winapi::um::winuser::{AppendMenuW, CreateMenu};
+        use winapi::um::winuser::{MF_MENUBREAK, MF_POPUP, MF_SEPARATOR, MF_STRING};
+
+        for item in items.as_ref() {
+            match item {
+                MenuItem::String(mi) => {
+                    if mi.children.as_ref().is_empty() {
+                        // no children
+                        let command = match mi.callback.as_ref() {
+                            None => 0,
+                            Some(c) => {
+                                let new_command_id =
+                                    Self::get_new_command_id().min(core::u16::MAX as usize) as u16;
+                                command_map.insert(new_command_id, c.clone());
+                                new_command_id as usize
+                            }
+                        };
+                        unsafe {
+                            AppendMenuW(
+                                *menu,
+                                MF_STRING,
+                                command,
+                                convert_widestring(mi.label.as_str()).as_ptr(),
+                            )
+                        };
+                    } else {
+                        let mut root = unsafe { CreateMenu() };
+                        Self::recursive_construct_menu(
+                            &mut root,
+                            mi.children.as_ref(),
+                            command_map,
+                        );
+                        unsafe {
+                            AppendMenuW(
+                                *menu,
+                                MF_POPUP,
+                                root as UINT_PTR,
+                                convert_widestring(mi.label.as_str()).as_ptr(),
+                            )
+                        };
+                    }
+                }
+                MenuItem::Separator => unsafe {
+                    AppendMenuW(*menu, MF_SEPARATOR, 0, core::ptr::null_mut());
+                },
+                MenuItem::BreakLine => unsafe {
+                    AppendMenuW(*menu, MF_MENUBREAK, 0, core::ptr::null_mut());
+                },
+            }
+        }
+    }
+}
+
+unsafe extern "system" fn WindowProc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+
+    use winapi::um::winuser::{
+        DefWindowProcW, SetWindowLongPtrW,
+        GetWindowLongPtrW, PostQuitMessage, PostMessageW,
+        WM_NCCREATE, WM_TIMER, WM_COMMAND,
+        WM_CREATE, WM_NCMOUSELEAVE, WM_ERASEBKGND,
+        WM_MOUSEMOVE, WM_DESTROY, WM_PAINT, WM_ACTIVATE,
+        WM_MOUSEWHEEL, WM_SIZE, WM_NCHITTEST, WM_NCCALCSIZE,
+        WM_LBUTTONDOWN, WM_DPICHANGED, WM_RBUTTONDOWN,
+        WM_LBUTTONUP, WM_RBUTTONUP, WM_MBUTTONUP, WM_MBUTTONDOWN,
+        WM_MOUSELEAVE, WM_DISPLAYCHANGE, WM_SIZING, WM_MOVING,
+        WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_WINDOWPOSCHANGED, WM_WINDOWPOSCHANGING,
+        WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
+        WM_CHAR, WM_SYSCHAR, WHEEL_DELTA, WM_SETFOCUS, WM_KILLFOCUS,
+        WM_NCMOUSEMOVE, WM_NCLBUTTONDOWN, WM_NCLBUTTONUP,
+        WM_DWMSENDICONICTHUMBNAIL, WM_DWMSENDICONICLIVEPREVIEWBITMAP,
+        WM_INPUTLANGCHANGE, WM_DEADCHAR, WM_SYSDEADCHAR,
+        WM_APPCOMMAND, WM_COPYDATA, WM_GETDLGCODE, WM_SETCURSOR, WM_SETTINGCHANGE,
+        WM_SYSCOLORCHANGE, WM_DWMCOLORIZATIONCOLORCHANGED, WM_WTSSESSION_CHANGE,
+
+        VK_F4,
+        CREATESTRUCTW, GWLP_USERDATA,
+    };
+    use winapi::um::wingdi::wglMakeCurrent;
+    use crate::wr_translate::wr_translate_document_id;
 
-            if dummy_window.is_null() {
-                return Err(FailedToCreateDummyWindow);
-            }
 
-            let dummy_dc = GetDC(dummy_window);
+    return if msg == WM_NCCREATE {
+        let createstruct: *mut CREATESTRUCTW = mem::transmute(lparam);
+        let data_ptr = (*createstruct).lpCreateParams;
+        SetWindowLongPtrW(hwnd, GWLP_USERDATA, mem::transmute(data_ptr));
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    } else {
 
-            let mut pfd = get_default_pfd();
+        let shared_application_data: *mut SharedApplicationData = mem::transmute(GetWindowLongPtrW(hwnd, GWLP_USERDATA));
+        if shared_application_data == ptr::null_mut() {
+            // message fired before WM_NCCREATE: ignore
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+        let shared_application_data: &mut SharedApplicationData = &mut *shared_application_data;
 
-            let pixel_format = ChoosePixelFormat(dummy_dc, &pfd);
-            if pixel_format == 0 {
-                return Err(FailedToFindPixelFormat);
-            }
+        // app-level input event filter, runs before the application data is borrowed
+        if apply_input_event_filter(hwnd, msg, wparam, lparam) {
+            return 0;
+        }
 
-            if SetPixelFormat(dummy_dc, pixel_format, &pfd) != TRUE {
-                return Err(FailedToSetPixelFormat);
-            }
+        record_input_message(hwnd, msg, wparam, lparam);
 
-            let dummy_context = wglCreateContext(dummy_dc);
-            if dummy_context.is_null() {
-                return Err(FailedToCreateDummyGlContext);
+        if msg == WM_NCCALCSIZE {
+            if let Some(result) = calc_custom_frame_client_rect(hwnd, wparam, lparam) {
+                return result;
             }
+        }
 
-            if wglMakeCurrent(dummy_dc, dummy_context) != TRUE {
-                return Err(FailedToActivateDummyGlContext);
+        // windows that are confined to a monitor can't be dragged / resized off of it,
+        // the monitor bounds win over a locked aspect ratio
+        if msg == WM_MOVING || msg == WM_SIZING {
+            let aspect_ratio_kept = msg == WM_SIZING && constrain_to_aspect_ratio(hwnd, wparam, lparam as *mut RECT);
+            let clamped = clamp_to_confining_monitor(hwnd, msg == WM_SIZING, lparam as *mut RECT);
+            if aspect_ratio_kept || clamped {
+                return TRUE as LRESULT;
             }
+        }
 
-            let mut extra_functions = ExtraWglFunctions::default();
-
-            extra_functions.wglChoosePixelFormatARB = {
-                let mut func_name_1 = encode_ascii("wglChoosePixelFormatARB");
-                let mut func_name_2 = encode_ascii("wglChoosePixelFormatEXT");
-
-                let wgl1_result = unsafe { wglGetProcAddress(func_name_1.as_mut_ptr()) };
-                let wgl2_result = unsafe { wglGetProcAddress(func_name_2.as_mut_ptr()) };
+        // same constraints for SetWindowPos and OS-driven snap / arrange
+        if msg == WM_WINDOWPOSCHANGING {
+            constrain_window_pos(hwnd, lparam as *mut WINDOWPOS);
+        }
 
-                if wgl1_result != ptr::null_mut() {
-                    Some(unsafe { mem::transmute(wgl1_result) })
-                } else if wgl2_result != ptr::null_mut() {
-                    Some(unsafe { mem::transmute(wgl2_result) })
-                } else {
-                    None
+        let mut app_borrow = match shared_application_data.inner.try_borrow_mut() {
+            Ok(b) => b,
+            Err(e) => {
+                // nested message dispatch while the application data is borrowed:
+                // queue the message instead of dropping it, see DEFERRED_MESSAGES
+                if msg == AZ_REPLAY_DEFERRED_MESSAGES {
+                    postpone_deferred_message_replay();
+                    return 0;
+                } else if is_deferrable_message(msg) {
+                    defer_message(hwnd, msg, wparam, lparam);
+                    return 0;
                 }
-            };
 
-            extra_functions.wglCreateContextAttribsARB = {
-                let mut func_name = encode_ascii("wglCreateContextAttribsARB");
-                let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
-                if proc_address == ptr::null_mut() {
-                    None
-                } else {
-                    Some(unsafe { mem::transmute(proc_address) })
-                }
-            };
+                // custom messages don't need the application data
+                return match get_custom_message_handler(msg).and_then(|f| (f)(hwnd, wparam, lparam)) {
+                    Some(result) => result,
+                    None => DefWindowProcW(hwnd, msg, wparam, lparam),
+                };
+            },
+        };
 
-            extra_functions.wglSwapIntervalEXT = {
-                let mut func_name = encode_ascii("wglSwapIntervalEXT");
-                let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
-                if proc_address == ptr::null_mut() {
-                    None
-                } else {
-                    Some(unsafe { mem::transmute(proc_address) })
-                }
-            };
+        let hwnd_key = hwnd as usize;
 
-            wglMakeCurrent(dummy_dc, ptr::null_mut());
-            wglDeleteContext(dummy_context);
-            ReleaseDC(dummy_window, dummy_dc);
-            DestroyWindow(dummy_window);
+        let _frame_message_timer = FrameMessageTimer::start(hwnd, msg);
 
-            return Ok(extra_functions);
-        }
-    }
-}
+        let r = match msg {
+            AZ_REGENERATE_DOM => {
 
-struct Window {
-    /// HWND handle of the plaform window
-    hwnd: HWND,
-    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
-    internal: WindowInternal,
-    /// OpenGL context handle - None if running in software mode
-    gl_context: Option<HGLRC>,
-    /// OpenGL functions for faster rendering
-    gl_functions: GlFunctions,
-    /// OpenGL context pointer with compiled SVG and FXAA shaders
-    gl_context_ptr: OptionGlContextPtr,
-    /// Main render API that can be used to register and un-register fonts and images
-    render_api: WrRenderApi,
-    /// WebRender renderer implementation (software or hardware)
-    renderer: Option<WrRenderer>,
-    /// Hit-tester, lazily initialized and updated every time the display list changes layout
-    hit_tester: AsyncHitTester,
-    /// ID -> Callback map for the window menu (default: empty map)
-    menu_bar: Option<WindowsMenuBar>,
-    /// ID -> Context menu callbacks (cleared when the context menu closes)
-    context_menu: Option<CurrentContextMenu>,
-    /// Timer ID -> Win32 timer map
-    timers: BTreeMap<TimerId, TIMERPTR>,
-    /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
-    thread_timer_running: Option<TIMERPTR>,
-    /// characters are combined via two following wparam messages
-    high_surrogate: Option<u16>,
-}
+                use azul_core::window_state::{NodesToCheck, StyleAndLayoutChanges};
 
-impl fmt::Debug for Window {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.hwnd.fmt(f)?;
-        self.internal.fmt(f)?;
-        self.gl_context.fmt(f)?;
-        self.gl_context_ptr.fmt(f)?;
-        self.renderer.is_some().fmt(f)?;
-        self.menu_bar.fmt(f)?;
-        self.context_menu.fmt(f)?;
-        self.high_surrogate.fmt(f)?;
-        Ok(())
-    }
-}
+                let mut ret = ProcessEventResult::DoNothing;
 
-impl Drop for Window {
-    fn drop(&mut self) {
-        use winapi::um::wingdi::{wglMakeCurrent, wglDeleteContext};
+                // borrow checker :|
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let data = &mut ab.data;
+                let image_cache = &mut ab.image_cache;
 
-        // drop the layout results first
-        self.internal.layout_results = Vec::new();
+                if let Some(current_window) = windows.get_mut(&hwnd_key) {
 
-        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+                    use winapi::um::winuser::{GetDC, ReleaseDC};
 
-        if let Some(context) = self.gl_context.as_mut() {
-            unsafe { wglDeleteContext(*context); }
-        }
+                    let hDC = GetDC(hwnd);
 
-        if let Some(renderer) = self.renderer.take() {
-            renderer.deinit();
-        }
-    }
-}
+                    let gl_context = match current_window.gl_context {
+                        Some(c) => {
+                            if !hDC.is_null() {
+                                wglMakeCurrent(hDC, c);
+                            }
+                        },
+                        None => { },
+                    };
 
-#[derive(Debug)]
-struct CurrentContextMenu {
-    callbacks: BTreeMap<u16, MenuCallback>,
-    hit_dom_node: DomNodeId,
-}
+                    let mut current_program = [0_i32];
 
-impl Window {
+                    {
+                        let mut gl = &mut current_window.gl_functions.functions;
+                        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                    }
 
-    fn get_id(&self) -> usize {
-        self.hwnd as usize
-    }
+                    let document_id = current_window.internal.document_id;
+                    let hit_test_tolerance = current_window.get_hit_test_tolerance();
+                    let mut hit_tester = &mut current_window.hit_tester;
+                    let internal = &mut current_window.internal;
+                    let gl_context = &current_window.gl_context_ptr;
 
-    // Creates a new HWND according to the options
-    fn create(
-        hinstance: HINSTANCE,
-        mut options: WindowCreateOptions,
-        mut shared_application_data: SharedApplicationData,
-    ) -> Result<Self, WindowsWindowCreateError> {
+                    // unset the focus
+                    internal.current_window_state.focused_node = None;
 
-        use crate::{
-            compositor::Compositor,
-            wr_translate::{
-                translate_document_id_wr,
-                translate_id_namespace_wr,
-                wr_translate_debug_flags,
-                wr_translate_document_id,
-            },
-        };
-        use azul_core::{
-            callbacks::PipelineId,
-            gl::GlContextPtr,
-            window::{
-                CursorPosition, HwAcceleration,
-                LogicalPosition, ScrollResult,
-                PhysicalSize, RendererType,
-                WindowInternalInit, FullHitTest,
-                WindowFrame,
-            },
-        };
-        use webrender::api::ColorF as WrColorF;
-        use webrender::ProgramCache as WrProgramCache;
-        use winapi::{
-            shared::windef::POINT,
-            um::{
-                wingdi::{
-                    wglDeleteContext, wglMakeCurrent,
-                    SwapBuffers, GetDeviceCaps,
-                    LOGPIXELSX, LOGPIXELSY
-                },
-                winuser::{
-                    CreateWindowExW, DestroyWindow, GetClientRect, GetCursorPos, GetDC,
-                    GetWindowRect, ReleaseDC, ScreenToClient, SetMenu, CW_USEDEFAULT, WS_CAPTION,
-                    WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
-                    WS_OVERLAPPED, WS_POPUP, WS_SYSMENU, WS_TABSTOP, WS_THICKFRAME,
-                    ShowWindow, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_NORMAL, SW_SHOWNORMAL,
-                },
-            },
-        };
-        use winapi::um::winuser::{
-            SetWindowPos, HWND_TOP, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOZORDER,
-        };
-        let parent_window = match options
-            .state
-            .platform_specific_options
-            .windows_options
-            .parent_window
-            .as_ref()
-        {
-            Some(hwnd) => (*hwnd) as HWND,
-            None => ptr::null_mut(),
-        };
+                    let mut resource_updates = Vec::new();
+                    fc_cache.apply_closure(|fc_cache| {
+                        internal.regenerate_styled_dom(
+                            data,
+                            image_cache,
+                            gl_context,
+                            &mut resource_updates,
+                            internal.get_dpi_scale_factor(),
+                            &crate::app::CALLBACKS,
+                            fc_cache,
+                            azul_layout::do_the_relayout,
+                            |window_state, scroll_states, layout_results| {
+                                crate::wr_translate::fullhittest_new_webrender(
+                                     &*hit_tester.resolve(),
+                                     document_id,
+                                     window_state.focused_node,
+                                     layout_results,
+                                     &window_state.mouse_state.cursor_position,
+                                     window_state.size.get_hidpi_factor(),
+                                     hit_test_tolerance,
+                                )
+                            }
+                        );
+                    });
 
-        let mut class_name = encode_wide(CLASS_NAME);
-        let mut window_title = encode_wide(options.state.title.as_str());
+                    // stop timers that have a DomNodeId attached to them
+                    current_window.stop_timers_with_node_ids();
 
-        let data_ptr = Box::into_raw(Box::new(shared_application_data.clone())) as *mut SharedApplicationData as *mut c_void;
+                    let mut gl = &mut current_window.gl_functions.functions;
+                    gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                    gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                    gl.use_program(current_program[0] as u32);
 
-        // Create the window
-        let hwnd = unsafe {
-            CreateWindowExW(
-                WS_EX_APPWINDOW | WS_EX_ACCEPTFILES,
-                class_name.as_mut_ptr(),
-                window_title.as_mut_ptr(),
-                WS_OVERLAPPED
-                    | WS_CAPTION
-                    | WS_SYSMENU
-                    | WS_THICKFRAME
-                    | WS_MINIMIZEBOX
-                    | WS_MAXIMIZEBOX
-                    | WS_TABSTOP
-                    | WS_POPUP,
-                // Size and position: set later, after DPI factor has been queried
-                CW_USEDEFAULT, // x
-                CW_USEDEFAULT, // y
-                if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.width) as i32 }, // width
-                if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.height) as i32 }, // height
-                parent_window,
-                ptr::null_mut(), // Menu
-                hinstance,
-                data_ptr,
-            )
-        };
+                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                    if !hDC.is_null() {
+                        ReleaseDC(hwnd, hDC);
+                    }
 
-        if hwnd.is_null() {
-            return Err(WindowsWindowCreateError::FailedToCreateHWND(
-                get_last_error(),
-            ));
-        }
+                    // the first regeneration after the window was created
+                    // restores the initial focus (the node IDs are the same)
+                    if let Some(initial_focus) = current_window.initial_focus.take() {
+                        apply_initial_focus(&mut current_window.internal, &initial_focus, image_cache);
+                    }
 
-        // Get / store DPI
-        // NOTE: GetDpiForWindow would be easier, but it's Win10 only
-        let dpi = if let Ok(s) = shared_application_data.inner.try_borrow() {
-            unsafe { s.dpi.hwnd_dpi(hwnd) }
-        } else {
-            96
-        };
+                    current_window.context_menu = None;
+                    Window::set_menu_bar(
+                        hwnd,
+                        &mut current_window.menu_bar,
+                        current_window.internal.get_menu_bar()
+                    );
 
-        let dpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+                    // rebuild the display list and send it
+                    rebuild_display_list(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        image_cache,
+                        resource_updates,
+                    );
 
-        options.state.size.dpi = dpi;
+                    current_window.render_api.flush_scene_builder();
 
-        // Window created, now try initializing OpenGL context
-        let renderer_types = match options.renderer.into_option() {
-            Some(s) => match s.hw_accel {
-                HwAcceleration::DontCare => vec![RendererType::Hardware, RendererType::Software],
-                HwAcceleration::Enabled => vec![RendererType::Hardware],
-                HwAcceleration::Disabled => vec![RendererType::Software],
-            },
-            None => vec![RendererType::Hardware, RendererType::Software],
-        };
+                    let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                    current_window.hit_tester = AsyncHitTester::Requested(
+                        current_window.render_api.request_hit_tester(wr_document_id)
+                    );
 
-        let mut opengl_context: Option<HGLRC> = None;
-        let mut rt = RendererType::Software;
-        let mut extra = ExtraWglFunctions::load()?;
-        let mut gl = GlFunctions::initialize();
-        let mut gl_context_ptr: OptionGlContextPtr = None.into();
+                    let hit_test = current_window.hit_test();
 
-        for r in renderer_types {
-            rt = r;
-            match r {
-                RendererType::Software => {}
-                RendererType::Hardware => {
-                    if let Ok(o) = create_gl_context(hwnd, hinstance, &extra) {
-                        opengl_context = Some(o);
-                        break;
-                    }
-                }
-            }
-        }
+                    current_window.internal.previous_window_state = None;
+                    current_window.internal.current_window_state.last_hit_test = hit_test;
 
-        gl_context_ptr = opengl_context
-            .map(|hrc| unsafe {
-                let hdc = GetDC(hwnd);
-                unsafe { wglMakeCurrent(hdc, hrc) };
-                gl.load();
-                // compiles SVG and FXAA shader programs...
-                let ptr = GlContextPtr::new(rt, gl.functions.clone());
+                    let mut nodes_to_check = NodesToCheck::simulated_mouse_move(
+                        &current_window.internal.current_window_state.last_hit_test,
+                        current_window.internal.current_window_state.focused_node,
+                        current_window.internal.current_window_state.mouse_state.mouse_down()
+                    );
 
-                /*
-                match options.renderer.as_ref().map(|v| v.vsync) {
-                    Some(VSync::Enabled) => {
-                        if let Some(wglSwapIntervalEXT) = extra_functions.wglSwapIntervalEXT {
-                            unsafe { (wglSwapIntervalEXT)(1) };
-                        }
-                    },
-                    Some(VSync::Disabled) => {
-                        if let Some(wglSwapIntervalEXT) = extra_functions.wglSwapIntervalEXT {
-                            unsafe { (wglSwapIntervalEXT)(0) };
-                        }
-                    },
-                    _ => { },
+                    let mut style_layout_changes = StyleAndLayoutChanges::new(
+                        &nodes_to_check,
+                        &mut current_window.internal.layout_results,
+                        &image_cache,
+                        &mut current_window.internal.renderer_resources,
+                        current_window.internal.current_window_state.size.get_layout_size(),
+                        &current_window.internal.document_id,
+                        None,
+                        None,
+                        &None,
+                        azul_layout::do_the_relayout,
+                    );
+
+                    PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
                 }
-                */
 
-                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
-                ReleaseDC(hwnd, hdc);
-                ptr
-            })
-            .into();
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_REDO_HIT_TEST => {
 
+                let mut ret = ProcessEventResult::DoNothing;
 
-        // WindowInternal::new() may dispatch OpenGL calls,
-        // need to make context current before invoking
-        let hdc = unsafe { GetDC(hwnd) };
-        if let Some(hrc) = opengl_context.as_mut() {
-            unsafe { wglMakeCurrent(hdc, *hrc) };
-        }
+                let cur_hwnd;
 
-        // Invoke callback to initialize UI for the first time
-        let (mut renderer, sender) = match WrRenderer::new(
-            gl.functions.clone(),
-            Box::new(Notifier {}),
-            WrRendererOptions {
-                resource_override_path: None,
-                use_optimized_shaders: true,
-                enable_aa: true,
-                enable_subpixel_aa: true,
-                force_subpixel_aa: true,
-                clear_color: WrColorF {
-                    r: 0.0,
-                    g: 0.0,
-                    b: 0.0,
-                    a: 0.0,
-                }, // transparent
-                panic_on_gl_error: false,
-                precache_flags: WrShaderPrecacheFlags::EMPTY,
-                cached_programs: Some(WrProgramCache::new(None)),
-                enable_multithreading: true,
-                debug_flags: wr_translate_debug_flags(&options.state.debug_state),
-                ..WrRendererOptions::default()
-            },
-            WR_SHADER_CACHE,
-        ) {
-            Ok(o) => o,
-            Err(e) => unsafe {
-                if let Some(hrc) = opengl_context.as_mut() {
-                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                    wglDeleteContext(*hrc);
-                }
-                ReleaseDC(hwnd, hdc);
-                DestroyWindow(hwnd);
-                return Err(WindowsWindowCreateError::Renderer(e));
-            },
-        };
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let image_cache = &mut ab.image_cache;
+                let config = &ab.config;
+                let hinstance = ab.hinstance;
 
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
 
-        renderer.set_external_image_handler(Box::new(Compositor::default()));
+                match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
 
-        let mut render_api = sender.create_api();
+                        use winapi::um::winuser::{GetDC, ReleaseDC};
 
-        // Query the current size of the window
-        let physical_size = if options.size_to_content {
-            PhysicalSize {
-                width: 0,
-                height: 0,
-            }
-        } else {
-            let mut rect: RECT = unsafe { mem::zeroed() };
-            let current_window_size = unsafe { GetClientRect(hwnd, &mut rect) }; // not DPI adjusted: physical pixels
-            PhysicalSize {
-                width: rect.width(),
-                height: rect.height(),
-            }
-        };
+                        cur_hwnd = current_window.hwnd;
 
-        options.state.size.dimensions = physical_size.to_logical(dpi_factor);
+                        let hDC = GetDC(cur_hwnd);
 
-        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
-        let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
-        let pipeline_id = PipelineId::new();
-        let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+                        let gl_context = match current_window.gl_context {
+                            Some(c) => {
+                                if !hDC.is_null() {
+                                    wglMakeCurrent(hDC, c);
+                                }
+                            },
+                            None => { },
+                        };
 
-        // hit tester will be empty on startup
-        let hit_tester = render_api
-            .request_hit_tester(wr_translate_document_id(document_id))
-            .resolve();
-        let hit_tester_ref = &*hit_tester;
+                        let mut current_program = [0_i32];
 
-        // lock the SharedApplicationData in order to
-        // invoke the UI callback for the first time
-        let mut appdata_lock = match shared_application_data.inner.try_borrow_mut() {
-            Ok(o) => o,
-            Err(e) => unsafe {
-                if let Some(hrc) = opengl_context.as_mut() {
-                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                    wglDeleteContext(*hrc);
+                        {
+                            let mut gl = &mut current_window.gl_functions.functions;
+                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                        }
+
+                        ret = process_event(
+                            hinstance,
+                            current_window,
+                            fc_cache,
+                            image_cache,
+                            config,
+                            &mut new_windows,
+                            &mut destroyed_windows,
+                        );
+
+                        let mut gl = &mut current_window.gl_functions.functions;
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        gl.use_program(current_program[0] as u32);
+
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(cur_hwnd, hDC);
+                        }
+                        0
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                };
+
+                let hinstance = ab.hinstance;
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                create_windows(hinstance, shared_application_data, new_windows);
+                let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                let mut ab = &mut *app_borrow;
+                destroy_windows(ab, destroyed_windows);
+
+                mem::drop(ab);
+
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in app_borrow.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        if let Some(w) = app_borrow.windows.get_mut(&hwnd_key) {
+                            // TODO: submit display list, wait for new hit-tester and update hit-test results
+                            w.internal.previous_window_state = Some(w.internal.current_window_state.clone());
+                            PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                            PostMessageW(cur_hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
                 }
-                ReleaseDC(hwnd, hdc);
-                DestroyWindow(hwnd);
-                return Err(WindowsWindowCreateError::BorrowMut(e));
-            },
-        };
 
-        let mut initial_resource_updates = Vec::new();
-        let mut internal = {
+                mem::drop(app_borrow);
+                0
+            },
+            #[cfg(feature = "css_parser")]
+            AZ_SET_CSS => {
 
-            let appdata_lock = &mut *appdata_lock;
-            let fc_cache = &mut appdata_lock.fc_cache;
-            let image_cache = &appdata_lock.image_cache;
-            let data = &mut appdata_lock.data;
+                use azul_css::Css;
+                use winapi::um::winuser::{GetDC, ReleaseDC, InvalidateRect};
 
-            fc_cache.apply_closure(|fc_cache| {
-                WindowInternal::new(
-                    WindowInternalInit {
-                        window_create_options: options.clone(),
-                        document_id,
-                        id_namespace,
+                let css = match (lparam as *const Css).as_ref() {
+                    Some(s) => s.clone(),
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
                     },
-                    data,
-                    image_cache,
-                    &gl_context_ptr,
-                    &mut initial_resource_updates,
-                    &crate::app::CALLBACKS,
-                    fc_cache,
-                    azul_layout::do_the_relayout,
-                    |window_state, scroll_states, layout_results| {
-                        crate::wr_translate::fullhittest_new_webrender(
-                            hit_tester_ref,
-                            document_id,
-                            window_state.focused_node,
-                            layout_results,
-                            &window_state.mouse_state.cursor_position,
-                            window_state.size.get_hidpi_factor(),
-                        )
+                };
+
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let image_cache = &ab.image_cache;
+
+                let current_window = match windows.get_mut(&hwnd_key) {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
                     },
-                )
-            })
-        };
+                };
 
-        // Since the menu bar affects the window size, set it first,
-        // before querying the window size again
-        let mut menu_bar = None;
-        if let Some(m) = internal.get_menu_bar() {
-            let mb = WindowsMenuBar::new(m);
-            unsafe { SetMenu(hwnd, mb._native_ptr); }
-            menu_bar = Some(mb);
-        }
+                let hDC = GetDC(hwnd);
+                if let Some(c) = current_window.gl_context {
+                    if !hDC.is_null() {
+                        wglMakeCurrent(hDC, c);
+                    }
+                }
 
-        // If size_to_content is set, query the content size and adjust!
-        if options.size_to_content {
-            let content_size = internal.get_content_size();
-            unsafe {
-                SetWindowPos(
-                    hwnd,
-                    HWND_TOP,
-                    0,
-                    0,
-                    libm::roundf(content_size.width) as i32,
-                    libm::roundf(content_size.height) as i32,
-                    SWP_NOMOVE | SWP_NOZORDER | SWP_FRAMECHANGED,
-                );
-            }
-        }
+                let mut current_program = [0_i32];
 
-        // If the window is maximized on startup, we have to call ShowWindow here
-        // before querying the client area
-        let mut sw_options = SW_HIDE; // 0 = default
-        let mut hidden_sw_options = SW_HIDE; // 0 = default
-        if internal.current_window_state.flags.is_visible {
-            sw_options |= SW_SHOWNORMAL;
-        }
+                {
+                    let mut gl = &mut current_window.gl_functions.functions;
+                    gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                }
 
-        match internal.current_window_state.flags.frame {
-            WindowFrame::Normal => { sw_options |= SW_NORMAL; hidden_sw_options |= SW_NORMAL; },
-            WindowFrame::Minimized => { sw_options |= SW_MINIMIZE; hidden_sw_options |= SW_MINIMIZE; },
-            WindowFrame::Maximized => { sw_options |= SW_MAXIMIZE; hidden_sw_options |= SW_MAXIMIZE; },
-            WindowFrame::Fullscreen => { sw_options |= SW_MAXIMIZE; hidden_sw_options |= SW_MAXIMIZE; },
-        }
+                let document_id = current_window.internal.document_id;
+                let hit_test_tolerance = current_window.get_hit_test_tolerance();
+                let hit_tester = &mut current_window.hit_tester;
+                let internal = &mut current_window.internal;
+                let gl_context = &current_window.gl_context_ptr;
 
-        unsafe { ShowWindow(hwnd, hidden_sw_options); }
+                let mut resource_updates = Vec::new();
+                fc_cache.apply_closure(|fc_cache| {
+                    internal.set_css(
+                        css,
+                        image_cache,
+                        gl_context,
+                        &mut resource_updates,
+                        internal.get_dpi_scale_factor(),
+                        &crate::app::CALLBACKS,
+                        fc_cache,
+                        azul_layout::do_the_relayout,
+                        |window_state, scroll_states, layout_results| {
+                            crate::wr_translate::fullhittest_new_webrender(
+                                 &*hit_tester.resolve(),
+                                 document_id,
+                                 window_state.focused_node,
+                                 layout_results,
+                                 &window_state.mouse_state.cursor_position,
+                                 window_state.size.get_hidpi_factor(),
+                                 hit_test_tolerance,
+                            )
+                        }
+                    );
+                });
 
-        // Query the client area from Win32 (not DPI adjusted) and adjust framebuffer
-        let mut rect: RECT = unsafe { mem::zeroed() };
-        let current_window_size = unsafe { GetClientRect(hwnd, &mut rect) };
-        let physical_size = PhysicalSize {
-            width: rect.width(),
-            height: rect.height(),
-        };
-        // internal.previous_window_state = Some(internal.current_window_state.clone());
-        
-        internal.current_window_state.size.dimensions = physical_size.to_logical(dpi_factor);
+                let mut gl = &mut current_window.gl_functions.functions;
+                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                gl.use_program(current_program[0] as u32);
 
-        let mut txn = WrTransaction::new();
+                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                if !hDC.is_null() {
+                    ReleaseDC(hwnd, hDC);
+                }
 
-        // re-layout the window content for the first frame
-        // (since the width / height might have changed)
-        {
-            let appdata_lock = &mut *appdata_lock;
-            let fc_cache = &mut appdata_lock.fc_cache;
-            let image_cache = &appdata_lock.image_cache;
-            let size = internal.current_window_state.size.clone();
-            let theme = internal.current_window_state.theme;
-            let resize_result = fc_cache.apply_closure(|fc_cache| {
-                internal.do_quick_resize(
-                    &image_cache,
-                    &crate::app::CALLBACKS,
-                    azul_layout::do_the_relayout,
-                    fc_cache,
-                    &gl_context_ptr,
-                    &size,
-                    theme,
-                )
-            });
+                // fonts / images that are still used keep their keys, only
+                // the resources of the new styles are sent to webrender
+                rebuild_display_list(
+                    &mut current_window.internal,
+                    &mut current_window.render_api,
+                    image_cache,
+                    resource_updates,
+                );
 
-            wr_synchronize_updated_images(resize_result.updated_images, &document_id, &mut txn);
-        }
+                let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                current_window.hit_tester = AsyncHitTester::Requested(
+                    current_window.render_api.request_hit_tester(wr_document_id)
+                );
 
-        if let Some(hrc) = opengl_context.as_ref() {
-            unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
-        }
+                generate_frame(
+                    &mut current_window.internal,
+                    &mut current_window.render_api,
+                    true,
+                );
 
-        unsafe { ReleaseDC(hwnd, hdc); }
+                InvalidateRect(hwnd, ptr::null(), 0);
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_REGENERATE_DISPLAY_LIST => {
 
-        txn.set_document_view(
-            WrDeviceIntRect::from_size(
-                WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32),
-            )
-        );
-        render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+                use winapi::um::winuser::InvalidateRect;
 
-        render_api.flush_scene_builder();
+                let ab = &mut *app_borrow;
+                let image_cache = &ab.image_cache;
+                let windows = &mut ab.windows;
 
-        // Build the display list and send it to webrender for the first time
-        rebuild_display_list(
-            &mut internal,
-            &mut render_api,
-            &appdata_lock.image_cache,
-            initial_resource_updates,
-        );
+                if let Some(current_window) =  windows.get_mut(&hwnd_key) {
 
-        render_api.flush_scene_builder();
+                    // wparam = ID of an invalidate_frame() request, the next
+                    // frame presented after this rebuild satisfies the request
+                    if wparam != 0 {
+                        current_window.pending_frame_request = wparam;
+                    }
 
-        generate_frame(
-            &mut internal,
-            &mut render_api,
-            true,
-        );
+                    rebuild_display_list(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        image_cache,
+                        Vec::new(), // no resource updates
+                    );
 
-        render_api.flush_scene_builder();
+                    let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                    current_window.hit_tester = AsyncHitTester::Requested(
+                        current_window.render_api.request_hit_tester(wr_document_id)
+                    );
 
-        // Get / store mouse cursor position, now that the window position is final
-        let mut cursor_pos: POINT = POINT { x: 0, y: 0 };
-        unsafe { GetCursorPos(&mut cursor_pos); }
-        unsafe { ScreenToClient(hwnd, &mut cursor_pos) };
-        let cursor_pos_logical = LogicalPosition {
-            x: cursor_pos.x as f32 / dpi_factor,
-            y: cursor_pos.y as f32 / dpi_factor,
-        };
-        internal.current_window_state.mouse_state.cursor_position = if cursor_pos.x <= 0 || cursor_pos.y <= 0 {
-            CursorPosition::Uninitialized
-        } else {
-            CursorPosition::InWindow(cursor_pos_logical)
-        };
+                    generate_frame(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        true,
+                    );
 
-        // Update the hit-tester to account for the new hit-testing functionality
-        let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
+                    mem::drop(app_borrow);
+                    0
+                } else {
+                    mem::drop(app_borrow);
+                    -1
+                }
+            },
+            AZ_GPU_SCROLL_RENDER => {
 
-        // Done! Window is now created properly, display list has been built by
-        // WebRender (window is ready to render), menu bar is visible and hit-tester
-        // now contains the newest UI tree.
+                match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        generate_frame(
+                            &mut current_window.internal,
+                            &mut current_window.render_api,
+                            false,
+                        );
 
-        if options.hot_reload {
-            use winapi::um::winuser::SetTimer;
-            unsafe { SetTimer(hwnd, AZ_TICK_REGENERATE_DOM, 200, None); }
-        }
+                        PostMessageW(hwnd, WM_PAINT, 0, 0);
+                    },
+                    None => { },
+                }
 
-        use winapi::um::winuser::PostMessageW;
-        unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0 ); }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            AZ_QUERY_PENDING_WORK => {
 
-        let mut window = Window {
-            hwnd,
-            internal,
-            gl_context: opengl_context,
-            gl_functions: gl,
-            gl_context_ptr,
-            render_api,
-            renderer: Some(renderer),
-            hit_tester: AsyncHitTester::Requested(hit_tester),
-            menu_bar,
-            context_menu: None,
-            timers: BTreeMap::new(),
-            thread_timer_running: None,
-            high_surrogate: None,
-        };
+                use winapi::um::winuser::{PeekMessageW, PM_NOREMOVE, PM_NOYIELD};
 
-        // invoke the create callback, if there is any
-        if let Some(create_callback) = options.create_callback.as_mut() {
+                let pending_work = match (lparam as *mut WindowPendingWork).as_mut() {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return -1;
+                    },
+                };
 
-            let hdc = unsafe { GetDC(hwnd) };
-            if let Some(hrc) = opengl_context.as_mut() {
-                unsafe { wglMakeCurrent(hdc, *hrc) };
-            }
+                let current_window = match app_borrow.windows.get(&hwnd_key) {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return -1;
+                    },
+                };
 
-            let ab = &mut *appdata_lock;
-            let fc_cache = &mut ab.fc_cache;
-            let image_cache = &mut ab.image_cache;
-            let data = &mut ab.data;
-            let config = &ab.config;
+                // checks the message queue of this window without removing the message
+                let is_queued = |m: u32| {
+                    let mut queued_msg: MSG = mem::zeroed();
+                    PeekMessageW(&mut queued_msg, hwnd, m, m, PM_NOREMOVE | PM_NOYIELD) > 0
+                };
 
-            let ccr = fc_cache.apply_closure(|fc_cache| {
-                use azul_core::window::{RawWindowHandle, WindowsHandle};
+                let internal = &current_window.internal;
 
-                window.internal.invoke_single_callback(
-                    create_callback,
-                    data,
-                    &RawWindowHandle::Windows(WindowsHandle {
-                        hwnd: hwnd as *mut core::ffi::c_void,
-                        hinstance: hinstance as *mut core::ffi::c_void,
-                    }),
-                    &window.gl_context_ptr,
-                    image_cache,
-                    fc_cache,
-                    &config.system_callbacks,
-                )
-            });
+                let hovered_nodes = |hit_test: &FullHitTest| {
+                    hit_test.hovered_nodes.iter()
+                    .flat_map(|(dom_id, ht)| ht.regular_hit_test_nodes.keys().map(move |node_id| (*dom_id, *node_id)))
+                    .collect::<BTreeSet<_>>()
+                };
 
-            let ntc = NodesToCheck::empty(
-                window.internal.current_window_state.mouse_state.mouse_down(),
-                window.internal.current_window_state.focused_node,
-            );
+                let previous_hovered_nodes = internal.previous_window_state
+                    .as_ref()
+                    .map(|s| hovered_nodes(&s.last_hit_test))
+                    .unwrap_or_default();
+
+                pending_work.state_changed = is_queued(AZ_REDO_HIT_TEST);
+                pending_work.hover_changed = hovered_nodes(&internal.current_window_state.last_hit_test) != previous_hovered_nodes;
+                pending_work.animation = !internal.timers.is_empty() || !internal.threads.is_empty();
+                pending_work.scroll = is_queued(AZ_GPU_SCROLL_RENDER);
+                pending_work.needs_relayout = is_queued(AZ_REGENERATE_DOM);
+                pending_work.needs_redraw = pending_work.needs_relayout ||
+                    pending_work.scroll ||
+                    is_queued(AZ_REGENERATE_DISPLAY_LIST) ||
+                    is_queued(WM_PAINT);
 
-            let mut new_windows = Vec::new();
-            let mut destroyed_windows = Vec::new();
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_QUERY_DISPLAY_LIST_STATS => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut DisplayListStats).as_mut()) {
+                    (Some(current_window), Some(stats)) => {
+                        *stats = current_window.internal.retained_display_lists.stats;
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_QUERY_RENDERER_INFO => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut Option<RendererInfo>).as_mut()) {
+                    (Some(current_window), Some(info)) => {
+                        *info = Some(current_window.renderer_info.clone());
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_TAB_FOCUS_ENTER => {
+                use winapi::um::winuser::SetFocus;
+                let reverse = wparam != 0;
+                let ab = &mut *app_borrow;
+                let image_cache = &ab.image_cache;
+                match ab.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        let tab_order = current_window.internal.get_tab_order();
+                        let new_focus = if reverse { tab_order.last() } else { tab_order.first() };
+                        current_window.set_keyboard_focus(image_cache, new_focus.copied());
+                        mem::drop(app_borrow);
+                        // sends WM_KILLFOCUS to the previous / WM_SETFOCUS to this window
+                        SetFocus(hwnd);
+                        0
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        -1
+                    }
+                }
+            },
+            AZ_QUERY_FONT_WARMUP => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut Option<Duration>).as_mut()) {
+                    (Some(current_window), Some(warmup_time)) => {
+                        *warmup_time = current_window.font_warmup_time;
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_QUERY_GPU_MEMORY => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut GpuMemoryUsage).as_mut()) {
+                    (Some(current_window), Some(usage)) => {
+                        *usage = current_window.get_gpu_memory_usage();
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_QUERY_PRESENT_MODE => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut Option<PresentModeStatus>).as_mut()) {
+                    (Some(current_window), Some(status)) => {
+                        *status = current_window.present_mode;
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            #[cfg(feature = "capture")]
+            AZ_SAVE_FRAME_CAPTURE => {
+                let result = match (app_borrow.windows.get_mut(&hwnd_key), (lparam as *const std::path::PathBuf).as_ref()) {
+                    (Some(current_window), Some(path)) => {
+                        current_window.save_frame_capture(path.clone());
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_DUMP_LAYOUT_TREE => {
+                let result = match (app_borrow.windows.get(&hwnd_key), (lparam as *mut String).as_mut()) {
+                    (Some(current_window), Some(dump)) => {
+                        *dump = current_window.internal.get_layout_tree_string();
+                        0
+                    },
+                    _ => -1,
+                };
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_CAPTURE_WINDOW => {
 
-            let ret = process_callback_results(
-                ccr,
-                &mut window,
-                &ntc,
-                image_cache,
-                fc_cache,
-                &mut new_windows,
-                &mut destroyed_windows,
-            );
+                use winapi::um::winuser::{GetDC, ReleaseDC};
 
-            if let Some(hrc) = opengl_context.as_mut() {
-                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
-            }
+                if let (Some(current_window), Some(image)) = (app_borrow.windows.get_mut(&hwnd_key), (lparam as *mut Option<RawImage>).as_mut()) {
+                    let framebuffer_size = current_window.get_framebuffer_size();
+                    let hDC = GetDC(hwnd);
+                    if !hDC.is_null() {
+                        *image = current_window.render_region_to_image(hDC, 0, 0, framebuffer_size.width, framebuffer_size.height);
+                        ReleaseDC(hwnd, hDC);
+                    }
+                }
 
-            mem::drop(ab);
-            mem::drop(appdata_lock);
-            create_windows(hinstance, &mut shared_application_data, new_windows, );
-            let mut appdata_lock = shared_application_data.inner.try_borrow_mut().unwrap();
-            let mut ab = &mut *appdata_lock;
-            destroy_windows(ab, destroyed_windows);
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_CAPTURE_NODE => {
 
-            unsafe { ReleaseDC(hwnd, hdc); }
-        }
+                use winapi::um::winuser::{GetDC, ReleaseDC};
 
-        unsafe { ShowWindow(hwnd, sw_options); }
+                let capture = match (lparam as *mut NodeCapture).as_mut() {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                };
 
-        // NOTE: The window is NOT stored yet
-        Ok(window)
-    }
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let hDC = GetDC(hwnd);
+                    if !hDC.is_null() {
+                        capture.result = current_window.render_node_to_image(hDC, capture.node);
+                        ReleaseDC(hwnd, hDC);
+                    }
+                }
 
-    fn start_stop_timers(
-        &mut self,
-        added: FastHashMap<TimerId, Timer>,
-        removed: FastBTreeSet<TimerId>
-    ) {
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_GET_PIXEL => {
 
-        use winapi::um::winuser::{SetTimer, KillTimer};
+                use winapi::um::winuser::{GetDC, ReleaseDC};
 
-        for (id, timer) in added {
-            let res = unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().min(u32::MAX as u64) as u32, None) };
-            self.internal.timers.insert(id, timer);
-            self.timers.insert(id, res);
-        }
+                let query = match (lparam as *mut PixelQuery).as_mut() {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                };
 
-        for id in removed {
-            if let Some(_) = self.internal.timers.remove(&id) {
-                if let Some(handle) = self.timers.remove(&id) {
-                    unsafe { KillTimer(self.hwnd, handle) };
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let hDC = GetDC(hwnd);
+                    if !hDC.is_null() {
+                        query.result = current_window.read_pixel(hDC, query.position);
+                        ReleaseDC(hwnd, hDC);
+                    }
                 }
-            }
-        }
-    }
 
-    fn start_stop_threads(
-        &mut self,
-        mut added: FastHashMap<ThreadId, Thread>,
-        removed: FastBTreeSet<ThreadId>
-    ) {
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_FIRST_FRAME_PRESENTED => {
 
-        use winapi::um::winuser::{SetTimer, KillTimer};
+                use winapi::um::winuser::{GetDC, ReleaseDC};
 
-        self.internal.threads.append(&mut added);
-        self.internal.threads.retain(|r, _| !removed.contains(r));
+                let mut ab = &mut *app_borrow;
+                let hinstance = ab.hinstance;
+                let windows = &mut ab.windows;
+                let data = &mut ab.data;
+                let image_cache = &mut ab.image_cache;
+                let fc_cache = &mut ab.fc_cache;
+                let config = &ab.config;
 
-        if self.internal.threads.is_empty() {
-            if let Some(thread_tick) = self.thread_timer_running {
-                unsafe { KillTimer(self.hwnd, thread_tick) };
-            }
-            self.thread_timer_running = None;
-        } else if !self.internal.threads.is_empty() && self.thread_timer_running.is_none() {
-            let res = unsafe { SetTimer(self.hwnd, AZ_THREAD_TICK, 16, None) }; // 16ms timer
-            self.thread_timer_running = Some(res);
-        }
-    }
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
 
-    // Stop all timers that have a NodeId attached to them because in the next
-    // frame the NodeId would be invalid, leading to crashes / panics
-    fn stop_timers_with_node_ids(&mut self) {
-        let timers_to_remove = self.internal.timers
-        .iter()
-        .filter_map(|(id, timer)| timer.node_id.as_ref().map(|_| *id))
-        .collect();
+                let ret = match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
 
-        self.start_stop_timers(FastHashMap::default(), timers_to_remove);
-    }
+                        let hDC = GetDC(hwnd);
+                        if let Some(c) = current_window.gl_context {
+                            if !hDC.is_null() {
+                                wglMakeCurrent(hDC, c);
+                            }
+                        }
 
-    // ScrollResult contains information about what nodes need to be scrolled,
-    // whether they were scrolled by the system or by the user and how far they
-    // need to be scrolled
-    fn do_system_scroll(&mut self, scroll: ScrollResult) {
-        // for scrolled_node in scroll {
-        //      self.render_api.scroll_node_with_id();
-        //      let scrolled_rect = LogicalRect { origin: scroll_offset, size: visible.size };
-        //      if !scrolled_node.scroll_bounds.contains(&scroll_rect) {
-        //
-        //      }
-        // }
-    }
+                        let ret = process_first_frame_callback(
+                            hinstance,
+                            data,
+                            current_window,
+                            fc_cache,
+                            image_cache,
+                            config,
+                            &mut new_windows,
+                            &mut destroyed_windows,
+                        );
 
-    fn set_menu_bar(hwnd: HWND, old: &mut Option<WindowsMenuBar>, menu_bar: Option<&Box<Menu>>) {
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(hwnd, hDC);
+                        }
+
+                        ret
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                };
 
-        use winapi::um::winuser::SetMenu;
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                create_windows(hinstance, shared_application_data, new_windows);
+                let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                let mut ab = &mut *app_borrow;
+                destroy_windows(ab, destroyed_windows);
 
-        let hash = old.as_ref().map(|o| o.hash);
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in ab.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        if let Some(w) = ab.windows.get_mut(&hwnd_key) {
+                            w.internal.previous_window_state = Some(w.internal.current_window_state.clone());
+                            PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                            PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
+                }
 
-        match (hash, menu_bar) {
-            (Some(_), None) => {
-                unsafe { SetMenu(hwnd, ptr::null_mut()); }
-                *old = None;
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                0
             },
-            (None, Some(new)) => {
-                let new_menu_bar = WindowsMenuBar::new(new);
-                unsafe { SetMenu(hwnd, new_menu_bar._native_ptr); }
-                *old = Some(new_menu_bar);
-            }
-            (Some(hash), Some(new)) => {
-                if hash != new.get_hash() {
-                    let new_menu_bar = WindowsMenuBar::new(new);
-                    unsafe { SetMenu(hwnd, new_menu_bar._native_ptr); }
-                    *old = Some(new_menu_bar);
+            WM_CREATE => {
+                if let Ok(mut o) = app_borrow.active_hwnds.try_borrow_mut() {
+                    o.insert(hwnd);
                 }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            (None, None) => { }
-        }
-
-    }
-}
-
-/// Creates an OpenGL 3.2 context using wglCreateContextAttribsARB
-fn create_gl_context(hwnd: HWND, hinstance: HINSTANCE, extra: &ExtraWglFunctions)
--> Result<HGLRC, WindowsOpenGlError>
-{
-    use winapi::um::{
-        wingdi::{
-            wglCreateContext, wglDeleteContext,
-            wglMakeCurrent, ChoosePixelFormat,
-            DescribePixelFormat, SetPixelFormat,
-        },
-        winuser::{GetDC, ReleaseDC}
-    };
-
-    use self::WindowsOpenGlError::*;
-
-    let wglCreateContextAttribsARB = extra.wglCreateContextAttribsARB
-    .ok_or(OpenGLNotAvailable(get_last_error()))?;
-
-    let wglChoosePixelFormatARB = extra.wglChoosePixelFormatARB
-    .ok_or(OpenGLNotAvailable(get_last_error()))?;
+            WM_ACTIVATE => {
+                use winapi::um::shellapi::{SHAppBarMessage, ABM_ACTIVATE};
+                mem::drop(app_borrow);
+                // appbars have to notify the shell when they are activated
+                if APPBAR_STATES.with(|s| s.borrow().contains_key(&(hwnd as usize))) {
+                    let mut abd = new_appbar_data(hwnd);
+                    SHAppBarMessage(ABM_ACTIVATE, &mut abd);
+                }
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_WINDOWPOSCHANGED => {
 
-    let opengl32_dll = load_dll("opengl32.dll")
-    .ok_or(OpenGL32DllNotFound(get_last_error()))?;
+                use azul_core::window::{PhysicalPositionI32, WindowFrame, WindowPosition};
+                use winapi::shared::minwindef::MAKELONG;
+                use winapi::um::{
+                    shellapi::{SHAppBarMessage, ABM_WINDOWPOSCHANGED},
+                    winuser::{
+                        GetClientRect, GetWindowRect, IsIconic, IsZoomed, SendMessageW,
+                        SIZE_MAXIMIZED, SIZE_MINIMIZED, SIZE_RESTORED,
+                        SWP_HIDEWINDOW, SWP_NOSIZE, SWP_SHOWWINDOW,
+                    },
+                };
 
-    let hDC = unsafe { GetDC(hwnd) };
-    if hDC.is_null() {
-        return Err(FailedToGetDC(get_last_error()));
-    }
+                // authoritative source for the window position and size: OS-driven
+                // snap / arrange changes don't always produce a WM_SIZE, so the
+                // stored state is compared against the actual window instead
+                let flags = (lparam as *const WINDOWPOS).as_ref().map(|p| p.flags).unwrap_or(0);
 
-    // https://www.khronos.org/registry/OpenGL/api/GL/wglext.h
-    const WGL_DRAW_TO_WINDOW_ARB: i32 = 0x2001;
-    const WGL_DOUBLE_BUFFER_ARB: i32 = 0x2011;
-    const WGL_SUPPORT_OPENGL_ARB: i32 = 0x2010;
-    const WGL_PIXEL_TYPE_ARB: i32 = 0x2013;
-    const WGL_TYPE_RGBA_ARB: i32 = 0x202B;
-    const WGL_TRANSPARENT_ARB: i32 = 0x200A;
-    const WGL_COLOR_BITS_ARB: i32 = 0x2014;
-    const WGL_RED_BITS_ARB: i32 = 0x2015;
-    const WGL_GREEN_BITS_ARB: i32 = 0x2017;
-    const WGL_BLUE_BITS_ARB: i32 = 0x2019;
-    const WGL_ALPHA_BITS_ARB: i32 = 0x201B;
-    const WGL_DEPTH_BITS_ARB: i32 = 0x2022;
-    const WGL_STENCIL_BITS_ARB: i32 = 0x2023;
-    const WGL_FULL_ACCELERATION_ARB: i32 =  0x2027;
-    const WGL_ACCELERATION_ARB: i32 = 0x2003;
+                let frame = if IsIconic(hwnd) != 0 {
+                    WindowFrame::Minimized
+                } else if IsZoomed(hwnd) != 0 {
+                    WindowFrame::Maximized
+                } else {
+                    WindowFrame::Normal
+                };
 
-    const GL_TRUE: i32 = 1;
+                let mut client_rect: RECT = mem::zeroed();
+                GetClientRect(hwnd, &mut client_rect);
 
-    let pixel_format_attribs = [
-        WGL_DRAW_TO_WINDOW_ARB,     GL_TRUE,
-        WGL_SUPPORT_OPENGL_ARB,     GL_TRUE,
-        WGL_DOUBLE_BUFFER_ARB,      GL_TRUE,
-        WGL_ACCELERATION_ARB,       WGL_FULL_ACCELERATION_ARB,
-        WGL_PIXEL_TYPE_ARB,         WGL_TYPE_RGBA_ARB,
-        WGL_COLOR_BITS_ARB,         32,
-        WGL_DEPTH_BITS_ARB,         24,
-        WGL_STENCIL_BITS_ARB,       8,
-        0
-    ];
+                let mut needs_resize = false;
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let window_state = &mut current_window.internal.current_window_state;
 
-    let mut pixel_format = 0;
-    let mut num_formats = 0;
-    unsafe { (wglChoosePixelFormatARB)(hDC, pixel_format_attribs.as_ptr(), ptr::null_mut(), 1, &mut pixel_format, &mut num_formats) };
-    if num_formats == 0 {
-        unsafe { ReleaseDC(hwnd, hDC); }
-        return Err(NoMatchingPixelFormat(get_last_error()));
-    }
+                    // minimized windows are moved to (-32000, -32000)
+                    let mut window_rect: RECT = mem::zeroed();
+                    if frame != WindowFrame::Minimized && GetWindowRect(hwnd, &mut window_rect) != 0 {
+                        window_state.position = WindowPosition::Initialized(
+                            PhysicalPositionI32::new(window_rect.left, window_rect.top)
+                        );
+                    }
 
-    let mut pfd: PIXELFORMATDESCRIPTOR = get_default_pfd();
+                    let physical_size = window_state.size.get_physical_size();
+                    needs_resize = flags & (SWP_NOSIZE | SWP_SHOWWINDOW | SWP_HIDEWINDOW) != SWP_NOSIZE ||
+                        window_state.flags.frame != frame ||
+                        physical_size.width != client_rect.width() ||
+                        physical_size.height != client_rect.height();
+                }
 
-    unsafe {
-        DescribePixelFormat(hDC, pixel_format, mem::size_of::<PIXELFORMATDESCRIPTOR>() as u32, &mut pfd);
-        if SetPixelFormat(hDC, pixel_format, &mut pfd) != TRUE{
-            ReleaseDC(hwnd, hDC);
-            return Err(NoMatchingPixelFormat(get_last_error()));
-        }
-    }
+                mem::drop(app_borrow);
 
-    // https://www.khronos.org/registry/OpenGL/extensions/ARB/WGL_ARB_create_context.txt
-    const WGL_CONTEXT_MAJOR_VERSION_ARB: i32 = 0x2091;
-    const WGL_CONTEXT_MINOR_VERSION_ARB: i32 = 0x2092;
-    const WGL_CONTEXT_PROFILE_MASK_ARB: i32 = 0x9126;
-    const WGL_CONTEXT_CORE_PROFILE_BIT_ARB: i32 = 0x00000001;
+                if APPBAR_STATES.with(|s| s.borrow().contains_key(&(hwnd as usize))) {
+                    let mut abd = new_appbar_data(hwnd);
+                    SHAppBarMessage(ABM_WINDOWPOSCHANGED, &mut abd);
+                }
 
-    // Create OpenGL 3.2 core context - #version 150 required by WR!
-    // Specify that we want to create an OpenGL 3.3 core profile context
-    let gl32_attribs = [
-        WGL_CONTEXT_MAJOR_VERSION_ARB, 3,
-        WGL_CONTEXT_MINOR_VERSION_ARB, 2,
-        WGL_CONTEXT_PROFILE_MASK_ARB,  WGL_CONTEXT_CORE_PROFILE_BIT_ARB,
-        0,
-    ];
+                // not forwarded to DefWindowProcW: the WM_SIZE / WM_MOVE that it would
+                // generate are replaced by the single WM_SIZE below
+                if needs_resize {
+                    let size_type = match frame {
+                        WindowFrame::Minimized => SIZE_MINIMIZED,
+                        WindowFrame::Maximized => SIZE_MAXIMIZED,
+                        _ => SIZE_RESTORED,
+                    };
+                    let size = MAKELONG(client_rect.width() as u16, client_rect.height() as u16);
+                    SendMessageW(hwnd, WM_SIZE, size_type, size as LPARAM);
+                }
 
-    let gl32_context = unsafe { (wglCreateContextAttribsARB)(hDC, ptr::null_mut(), gl32_attribs.as_ptr()) };
-    if gl32_context.is_null() {
-        unsafe { ReleaseDC(hwnd, hDC); }
-        return Err(OpenGLNotAvailable(get_last_error()));
-    }
+                0
+            },
+            AZ_APPBAR_NOTIFY => {
 
-    unsafe { ReleaseDC(hwnd, hDC); }
+                use winapi::um::{
+                    shellapi::{ABN_FULLSCREENAPP, ABN_POSCHANGED},
+                    winuser::{
+                        SetWindowPos, HWND_BOTTOM, HWND_TOPMOST,
+                        SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
+                    },
+                };
 
-    return Ok(gl32_context);
-}
+                mem::drop(app_borrow);
 
+                let state = match APPBAR_STATES.with(|s| s.borrow().get(&(hwnd as usize)).copied()) {
+                    Some(s) => s,
+                    None => return 0,
+                };
 
-use winapi::um::wingdi::PIXELFORMATDESCRIPTOR;
+                match wparam as u32 {
+                    // taskbar / other appbar moved or resized: renegotiate the position
+                    ABN_POSCHANGED => set_appbar_pos(hwnd, state),
+                    // a fullscreen app opened / closed: get out of its way
+                    ABN_FULLSCREENAPP => {
+                        let insert_after = if lparam != 0 { HWND_BOTTOM } else { HWND_TOPMOST };
+                        SetWindowPos(hwnd, insert_after, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+                    },
+                    _ => { },
+                }
 
-fn get_default_pfd() -> PIXELFORMATDESCRIPTOR {
+                0
+            },
+            AZ_REPLAY_DEFERRED_MESSAGES => {
+                mem::drop(app_borrow);
+                replay_deferred_messages();
+                0
+            },
+            AZ_NOTIFICATION_ACTION | AZ_NOTIFICATION_TRAY_ICON => {
 
-    use winapi::um::wingdi::{
-        PFD_DRAW_TO_WINDOW,
-        PFD_SUPPORT_OPENGL,
-        PFD_GENERIC_ACCELERATED,
-        PFD_DOUBLEBUFFER,
-        PFD_MAIN_PLANE,
-        PFD_TYPE_RGBA,
-        PFD_SUPPORT_COMPOSITION,
-    };
+                use azul_core::window::{RawWindowHandle, WindowsHandle};
+                use azul_core::styled_dom::NodeHierarchyItemId;
 
-    PIXELFORMATDESCRIPTOR {
-        nSize: mem::size_of::<PIXELFORMATDESCRIPTOR> as u16,
-        nVersion: 1,
-        dwFlags: {
-            PFD_DRAW_TO_WINDOW |        // support window
-            PFD_SUPPORT_OPENGL |        // support OpenGL
-            PFD_DOUBLEBUFFER            // double buffered
-        },
-        iPixelType: PFD_TYPE_RGBA as u8,
-        cColorBits: 32,
-        cRedBits: 0,
-        cRedShift: 0,
-        cGreenBits: 0,
-        cGreenShift: 0,
-        cBlueBits: 0,
-        cBlueShift: 0,
-        cAlphaBits: 8, // request alpha
-        cAlphaShift: 0,
-        cAccumBits: 0,
-        cAccumRedBits: 0,
-        cAccumGreenBits: 0,
-        cAccumBlueBits: 0,
-        cAccumAlphaBits: 0,
-        cDepthBits: 24,                   // 16-bit z-buffer
-        cStencilBits: 8,                  // 8-bit stencil
-        cAuxBuffers: 0,                   // no auxiliary buffer
-        iLayerType: PFD_MAIN_PLANE as u8, // main layer
-        bReserved: 0,
-        dwLayerMask: 0,
-        dwVisibleMask: 0,
-        dwDamageMask: 0,
-    }
-}
+                let menu_callback = if msg == AZ_NOTIFICATION_ACTION {
+                    self::notification::take_notification_action(wparam, lparam as usize)
+                } else {
+                    self::notification::handle_tray_icon_message(hwnd, wparam, lparam)
+                };
 
-#[derive(Debug)]
-struct WindowsMenuBar {
-    _native_ptr: HMENU,
-    /// Map from Command -> callback to call
-    callbacks: BTreeMap<u16, MenuCallback>,
-    hash: u64,
-}
+                let mut menu_callback = match menu_callback {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
+                };
 
-static WINDOWS_UNIQUE_COMMAND_ID_GENERATOR: AtomicUsize = AtomicUsize::new(1); // 0 = no command
+                let mut ab = &mut *app_borrow;
+                let hinstance = ab.hinstance;
+                let windows = &mut ab.windows;
+                let image_cache = &mut ab.image_cache;
+                let fc_cache = &mut ab.fc_cache;
+                let config = &ab.config;
 
-impl WindowsMenuBar {
+                let current_window = match windows.get_mut(&hwnd_key) {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
+                };
 
-    fn new(new: &Menu) -> Self {
-        use winapi::um::winuser::CreateMenu;
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
 
-        let hash = new.get_hash();
-        let mut root = unsafe { CreateMenu() };
-        let mut command_map = BTreeMap::new();
+                let window_handle = RawWindowHandle::Windows(WindowsHandle {
+                    hwnd: hwnd as *mut _,
+                    hinstance: hinstance as *mut _,
+                });
 
-        Self::recursive_construct_menu(&mut root, new.items.as_ref(), &mut command_map);
+                let ntc = NodesToCheck::empty(
+                    current_window.internal.current_window_state.mouse_state.mouse_down(),
+                    current_window.internal.current_window_state.focused_node,
+                );
 
-        Self {
-            _native_ptr: root,
-            callbacks: command_map,
-            hash,
-        }
-    }
+                let ccr = {
+                    let internal = &mut current_window.internal;
+                    let gl_context_ptr = &current_window.gl_context_ptr;
+                    fc_cache.apply_closure(|fc_cache| {
+                        internal.invoke_menu_callback(
+                            &mut menu_callback,
+                            DomNodeId {
+                                dom: DomId::ROOT_ID,
+                                node: NodeHierarchyItemId::from_crate_internal(None),
+                            },
+                            &window_handle,
+                            &gl_context_ptr,
+                            image_cache,
+                            fc_cache,
+                            &config.system_callbacks,
+                        )
+                    })
+                };
 
-    fn get_new_command_id() -> usize {
-        WINDOWS_UNIQUE_COMMAND_ID_GENERATOR.fetch_add(1, AtomicOrdering::SeqCst)
-    }
+                let ret = process_callback_results(
+                    ccr,
+                    current_window,
+                    &ntc,
+                    image_cache,
+                    fc_cache,
+                    &mut new_windows,
+                    &mut destroyed_windows,
+                );
 
-    fn recursive_construct_menu(
-        menu: &mut HMENU,
-        items: &[MenuItem],
-        command_map: &mut BTreeMap<u16, MenuCallback>,
-    ) {
-        fn convert_widestring(input: &str) -> Vec<u16> {
-            let mut v: Vec<u16> = input
-                .chars()
-                .filter_map(|s| {
-                    use std::convert::TryInto;
-                    (s as u32).try_into().ok()
-                })
-                .collect();
-            v.push(0);
-            v
-        }
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                create_windows(hinstance, shared_application_data, new_windows);
+                let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                let mut ab = &mut *app_borrow;
+                destroy_windows(ab, destroyed_windows);
 
-        use winapi::shared::basetsd::UINT_PTR;
-        use winapi::um::winuser::{AppendMenuW, CreateMenu};
-        use winapi::um::winuser::{MF_MENUBREAK, MF_POPUP, MF_SEPARATOR, MF_STRING};
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in app_borrow.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        if let Some(w) = app_borrow.windows.get_mut(&hwnd_key) {
+                            w.internal.previous_window_state = Some(w.internal.current_window_state.clone());
+                            PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                            PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
+                }
 
-        for item in items.as_ref() {
-            match item {
-                MenuItem::String(mi) => {
-                    if mi.children.as_ref().is_empty() {
-                        // no children
-                        let command = match mi.callback.as_ref() {
-                            None => 0,
-                            Some(c) => {
-                                let new_command_id =
-                                    Self::get_new_command_id().min(core::u16::MAX as usize) as u16;
-                                command_map.insert(new_command_id, c.clone());
-                                new_command_id as usize
-                            }
-                        };
-                        unsafe {
-                            AppendMenuW(
-                                *menu,
-                                MF_STRING,
-                                command,
-                                convert_widestring(mi.label.as_str()).as_ptr(),
-                            )
-                        };
-                    } else {
-                        let mut root = unsafe { CreateMenu() };
-                        Self::recursive_construct_menu(
-                            &mut root,
-                            mi.children.as_ref(),
-                            command_map,
-                        );
-                        unsafe {
-                            AppendMenuW(
-                                *menu,
-                                MF_POPUP,
-                                root as UINT_PTR,
-                                convert_widestring(mi.label.as_str()).as_ptr(),
-                            )
-                        };
+                mem::drop(app_borrow);
+                0
+            },
+            WM_SETTINGCHANGE => {
+                // i.e. high contrast mode / animations toggled: the app is notified via a ThemeChanged event
+                let accessibility = get_accessibility_settings();
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous = current_window.internal.current_window_state.accessibility;
+                    if previous != accessibility {
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.accessibility = accessibility;
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        // smooth scrolling is set up when the DOM is built
+                        if previous.prefers_reduced_motion != accessibility.prefers_reduced_motion {
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
                     }
+                    current_window.update_system_colors();
                 }
-                MenuItem::Separator => unsafe {
-                    AppendMenuW(*menu, MF_SEPARATOR, 0, core::ptr::null_mut());
-                },
-                MenuItem::BreakLine => unsafe {
-                    AppendMenuW(*menu, MF_MENUBREAK, 0, core::ptr::null_mut());
-                },
-            }
-        }
-    }
-}
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_SYSCOLORCHANGE | WM_DWMCOLORIZATIONCOLORCHANGED => {
+                // system colors / accent color changed: rebuild the DOM
+                // so that the stylesheet can pick up the new colors
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.update_system_colors();
+                }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_ERASEBKGND => {
+                mem::drop(app_borrow);
+                1
+            },
+            WM_SETFOCUS => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                    current_window.internal.current_window_state.flags.has_focus = true;
+                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    mem::drop(app_borrow);
+                    0
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_KILLFOCUS => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                    current_window.internal.current_window_state.flags.has_focus = false;
+                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    mem::drop(app_borrow);
+                    0
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_MOUSEMOVE => {
 
-unsafe extern "system" fn WindowProc(
-    hwnd: HWND,
-    msg: u32,
-    wparam: WPARAM,
-    lparam: LPARAM,
-) -> LRESULT {
+                use winapi::{
+                    um::winuser::{
+                        TrackMouseEvent, TME_LEAVE, HOVER_DEFAULT, TRACKMOUSEEVENT,
+                    },
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
+                };
+                use azul_core::window::{
+                    CursorTypeHitTest,
+                    CursorPosition, FullHitTest,
+                };
 
-    use winapi::um::winuser::{
-        DefWindowProcW, SetWindowLongPtrW,
-        GetWindowLongPtrW, PostQuitMessage, PostMessageW,
-        WM_NCCREATE, WM_TIMER, WM_COMMAND,
-        WM_CREATE, WM_NCMOUSELEAVE, WM_ERASEBKGND,
-        WM_MOUSEMOVE, WM_DESTROY, WM_PAINT, WM_ACTIVATE,
-        WM_MOUSEWHEEL, WM_SIZE, WM_NCHITTEST,
-        WM_LBUTTONDOWN, WM_DPICHANGED, WM_RBUTTONDOWN,
-        WM_LBUTTONUP, WM_RBUTTONUP, WM_MBUTTONUP, WM_MBUTTONDOWN,
-        WM_MOUSELEAVE, WM_DISPLAYCHANGE, WM_SIZING,
-        WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_WINDOWPOSCHANGED,
-        WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
-        WM_CHAR, WM_SYSCHAR, WHEEL_DELTA, WM_SETFOCUS, WM_KILLFOCUS,
+                let x = GET_X_LPARAM(lparam);
+                let y = GET_Y_LPARAM(lparam);
 
-        VK_F4,
-        CREATESTRUCTW, GWLP_USERDATA,
-    };
-    use winapi::um::wingdi::wglMakeCurrent;
-    use crate::wr_translate::wr_translate_document_id;
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
+                    current_window.pointer_input_type = PointerInputType::of_current_message();
 
-    return if msg == WM_NCCREATE {
-        let createstruct: *mut CREATESTRUCTW = mem::transmute(lparam);
-        let data_ptr = (*createstruct).lpCreateParams;
-        SetWindowLongPtrW(hwnd, GWLP_USERDATA, mem::transmute(data_ptr));
-        DefWindowProcW(hwnd, msg, wparam, lparam)
-    } else {
+                    let pos = CursorPosition::InWindow(
+                        current_window.internal.current_window_state.size.physical_to_logical_position(x, y)
+                    );
 
-        let shared_application_data: *mut SharedApplicationData = mem::transmute(GetWindowLongPtrW(hwnd, GWLP_USERDATA));
-        if shared_application_data == ptr::null_mut() {
-            // message fired before WM_NCCREATE: ignore
-            return DefWindowProcW(hwnd, msg, wparam, lparam);
-        }
-        let shared_application_data: &mut SharedApplicationData = &mut *shared_application_data;
+                    // call SetCapture(hwnd) so that we can capture the WM_MOUSELEAVE event
+                    let cur_cursor_pos = current_window.internal.current_window_state.mouse_state.cursor_position;
+                    let prev_cursor_pos = current_window.internal.previous_window_state
+                        .as_ref().map(|m| m.mouse_state.cursor_position).unwrap_or_default();
 
-        let mut app_borrow = match shared_application_data.inner.try_borrow_mut() {
-            Ok(b) => b,
-            Err(e) => { return DefWindowProcW(hwnd, msg, wparam, lparam); },
-        };
+                    if !prev_cursor_pos.is_inside_window() && cur_cursor_pos.is_inside_window() {
+                        // cursor entered
+                        TrackMouseEvent(&mut TRACKMOUSEEVENT {
+                            cbSize: mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                            dwFlags: TME_LEAVE,
+                            hwndTrack: current_window.hwnd,
+                            dwHoverTime: HOVER_DEFAULT,
+                        });
+                    }
 
-        let hwnd_key = hwnd as usize;
+                    // mouse move caused by set_cursor_pos(): update the position in
+                    // both states, so that no mouse move callbacks are invoked
+                    let is_suppressed_warp = SUPPRESSED_CURSOR_WARP.with(|s| {
+                        if s.get() == Some((hwnd_key, x, y)) {
+                            s.set(None);
+                            true
+                        } else {
+                            false
+                        }
+                    });
+
+                    let mut previous_state = current_window.internal.current_window_state.clone();
+                    if is_suppressed_warp {
+                        previous_state.mouse_state.cursor_position = pos;
+                    }
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.cursor_position = pos;
+                    
+                    // mouse moved, so we need a new hit test
+                    let hit_test = current_window.hit_test();
+                    let cht = CursorTypeHitTest::new(&hit_test, &current_window.internal.layout_results);
+                    current_window.internal.current_window_state.last_hit_test = hit_test;
 
-        let msg_start = std::time::Instant::now();
+                    // the `cursor` of the hovered node overrides the window cursor
+                    let node_cursor = cht.cursor_node.map(|_| cht.cursor_icon);
+                    if node_cursor != current_window.node_cursor {
+                        current_window.node_cursor = node_cursor;
+                        current_window.update_cursor();
+                    }
 
-        let r = match msg {
-            AZ_REGENERATE_DOM => {
+                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                };
 
-                use azul_core::window_state::{NodesToCheck, StyleAndLayoutChanges};
+                mem::drop(app_borrow);
+                0
+            },
+            WM_NCMOUSEMOVE | WM_NCLBUTTONDOWN | WM_NCLBUTTONUP => {
 
-                let mut ret = ProcessEventResult::DoNothing;
+                use winapi::{
+                    um::winuser::{
+                        ScreenToClient, TrackMouseEvent, TRACKMOUSEEVENT,
+                        TME_LEAVE, TME_NONCLIENT, HOVER_DEFAULT, HTCAPTION,
+                        HTSIZEFIRST, HTSIZELAST, HTMINBUTTON, HTMAXBUTTON, HTCLOSE, HTSYSMENU,
+                    },
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
+                };
+                use azul_core::window::CursorPosition;
 
-                // borrow checker :|
-                let ab = &mut *app_borrow;
-                let windows = &mut ab.windows;
-                let fc_cache = &mut ab.fc_cache;
-                let data = &mut ab.data;
-                let image_cache = &mut ab.image_cache;
+                // the caption is handled by the OS (dragging, double-click to maximize)
+                if wparam as isize == HTCAPTION {
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
-                if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                    current_window.pointer_input_type = PointerInputType::of_current_message();
 
-                    use winapi::um::winuser::{GetDC, ReleaseDC};
+                    // non-client messages carry screen coordinates
+                    let mut point = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                    ScreenToClient(hwnd, &mut point);
 
-                    let hDC = GetDC(hwnd);
+                    let pos = CursorPosition::InWindow(
+                        current_window.internal.current_window_state.size.physical_to_logical_position(point.x, point.y)
+                    );
 
-                    let gl_context = match current_window.gl_context {
-                        Some(c) => {
-                            if !hDC.is_null() {
-                                wglMakeCurrent(hDC, c);
-                            }
-                        },
-                        None => { },
-                    };
+                    if msg == WM_NCMOUSEMOVE {
+                        // get notified with WM_NCMOUSELEAVE when the cursor leaves the frame
+                        TrackMouseEvent(&mut TRACKMOUSEEVENT {
+                            cbSize: mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                            dwFlags: TME_LEAVE | TME_NONCLIENT,
+                            hwndTrack: hwnd,
+                            dwHoverTime: HOVER_DEFAULT,
+                        });
+                    }
 
-                    let mut current_program = [0_i32];
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.cursor_position = pos;
 
-                    {
-                        let mut gl = &mut current_window.gl_functions.functions;
-                        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                    match msg {
+                        WM_NCLBUTTONDOWN => { current_window.internal.current_window_state.mouse_state.left_down = true; },
+                        WM_NCLBUTTONUP => { current_window.internal.current_window_state.mouse_state.left_down = false; },
+                        _ => { },
                     }
 
-                    let document_id = current_window.internal.document_id;
-                    let mut hit_tester = &mut current_window.hit_tester;
-                    let internal = &mut current_window.internal;
-                    let gl_context = &current_window.gl_context_ptr;
+                    let hit_test = current_window.hit_test();
+                    current_window.internal.current_window_state.last_hit_test = hit_test;
 
-                    // unset the focus
-                    internal.current_window_state.focused_node = None;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
 
-                    let mut resource_updates = Vec::new();
-                    fc_cache.apply_closure(|fc_cache| {
-                        internal.regenerate_styled_dom(
-                            data,
-                            image_cache,
-                            gl_context,
-                            &mut resource_updates,
-                            internal.get_dpi_scale_factor(),
-                            &crate::app::CALLBACKS,
-                            fc_cache,
-                            azul_layout::do_the_relayout,
-                            |window_state, scroll_states, layout_results| {
-                                crate::wr_translate::fullhittest_new_webrender(
-                                     &*hit_tester.resolve(),
-                                     document_id,
-                                     window_state.focused_node,
-                                     layout_results,
-                                     &window_state.mouse_state.cursor_position,
-                                     window_state.size.get_hidpi_factor(),
-                                )
+                // still let the OS handle resizing, min / max / close buttons, etc.
+                mem::drop(app_borrow);
+                let result = DefWindowProcW(hwnd, msg, wparam, lparam);
+
+                // resizing and the caption buttons run a modal loop that swallows
+                // the WM_NCLBUTTONUP: the button is released once DefWindowProcW returns
+                let hit = wparam as isize;
+                let ran_modal_loop = (hit >= HTSIZEFIRST && hit <= HTSIZELAST) ||
+                    hit == HTMINBUTTON || hit == HTMAXBUTTON || hit == HTCLOSE || hit == HTSYSMENU;
+
+                if msg == WM_NCLBUTTONDOWN && ran_modal_loop {
+                    if let Ok(mut app_borrow) = shared_application_data.inner.try_borrow_mut() {
+                        if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                            current_window.internal.current_window_state.mouse_state.left_down = false;
+                            PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    }
+                }
+
+                result
+            },
+            WM_KEYDOWN | WM_SYSKEYDOWN => {
+                if msg == WM_SYSKEYDOWN && wparam as i32 == VK_F4 {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                } else {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        if let Some((scancode, vk)) = event::process_key_params(wparam, lparam) {
+                            use winapi::um::winuser::SendMessageW;
+
+                            current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                            current_window.internal.current_window_state.keyboard_state.current_char = None.into();
+                            current_window.internal.current_window_state.keyboard_state.pressed_scancodes.insert_hm_item(scancode);
+                            if let Some(vk) = vk {
+                                current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
+                                current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
                             }
-                        );
-                    });
 
-                    // stop timers that have a DomNodeId attached to them
-                    current_window.stop_timers_with_node_ids();
+                            use azul_core::window::VirtualKeyCode;
 
-                    let mut gl = &mut current_window.gl_functions.functions;
-                    gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                    gl.use_program(current_program[0] as u32);
+                            // the delete key does not generate a WM_CHAR message:
+                            // deliver it as a control character to the focused text input
+                            if vk == Some(VirtualKeyCode::Delete) {
+                                current_window.internal.current_window_state.keyboard_state.current_char = Some(0x7f).into();
+                            }
 
-                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                    if !hDC.is_null() {
-                        ReleaseDC(hwnd, hDC);
-                    }
+                            // Ctrl + Shift + F12 (debug builds only): log the current layout tree
+                            #[cfg(all(debug_assertions, feature = "logging"))] {
+                                let keyboard_state = &current_window.internal.current_window_state.keyboard_state;
+                                if vk == Some(VirtualKeyCode::F12) && keyboard_state.ctrl_down() && keyboard_state.shift_down() {
+                                    info!("layout tree:\n{}", current_window.internal.get_layout_tree_string());
+                                }
+                            }
 
-                    current_window.context_menu = None;
-                    Window::set_menu_bar(
-                        hwnd,
-                        &mut current_window.menu_bar,
-                        current_window.internal.get_menu_bar()
-                    );
+                            // Ctrl + Shift + F11 (debug builds with the "capture" feature only):
+                            // save a webrender capture to the temp directory
+                            #[cfg(all(debug_assertions, feature = "capture"))] {
+                                let keyboard_state = &current_window.internal.current_window_state.keyboard_state;
+                                if vk == Some(VirtualKeyCode::F11) && keyboard_state.ctrl_down() && keyboard_state.shift_down() {
+                                    let timestamp = std::time::SystemTime::now()
+                                        .duration_since(std::time::UNIX_EPOCH)
+                                        .map(|d| d.as_secs())
+                                        .unwrap_or(0);
+                                    let path = std::env::temp_dir().join(format!("azul-capture-{}", timestamp));
+                                    #[cfg(feature = "logging")] {
+                                        info!("saving webrender capture to {}", path.display());
+                                    }
+                                    current_window.save_frame_capture(path);
+                                }
+                            }
 
-                    // rebuild the display list and send it
-                    rebuild_display_list(
-                        &mut current_window.internal,
-                        &mut current_window.render_api,
-                        image_cache,
-                        resource_updates,
-                    );
+                            // Ctrl + "=" / Ctrl + "-" / Ctrl + "0": zoom the content of this window
+                            let keyboard_state = &current_window.internal.current_window_state.keyboard_state;
+                            if current_window.internal.current_window_state.flags.zoom_shortcuts_enabled &&
+                               current_window.internal.current_window_state.size.fixed_logical_size.is_none() &&
+                               keyboard_state.ctrl_down() &&
+                               !keyboard_state.alt_down() {
+                                let size = &current_window.internal.current_window_state.size;
+                                let new_content_scale = match vk {
+                                    Some(VirtualKeyCode::Equals) | Some(VirtualKeyCode::NumpadAdd) => Some(size.get_zoomed_in_scale()),
+                                    Some(VirtualKeyCode::Minus) | Some(VirtualKeyCode::NumpadSubtract) => Some(size.get_zoomed_out_scale()),
+                                    Some(VirtualKeyCode::Key0) | Some(VirtualKeyCode::Numpad0) => Some(1.0),
+                                    _ => None,
+                                };
 
-                    current_window.render_api.flush_scene_builder();
+                                if let Some(new_content_scale) = new_content_scale {
+                                    if new_content_scale != size.content_scale {
+                                        current_window.internal.current_window_state.size.content_scale = new_content_scale;
+                                        request_relayout(hwnd, current_window.internal.current_window_state.flags.frame);
+                                    }
+                                }
+                            }
 
-                    let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
-                    current_window.hit_tester = AsyncHitTester::Requested(
-                        current_window.render_api.request_hit_tester(wr_document_id)
-                    );
+                            // Tab / Shift + Tab: move the focus to the next / previous
+                            // focusable node (in tab order), unless the key event callbacks
+                            // of the focused node handle the key themselves (i.e. DLGC_WANTTAB)
+                            let keyboard_state = &current_window.internal.current_window_state.keyboard_state;
+                            let is_tab_navigation = vk == Some(VirtualKeyCode::Tab) &&
+                                current_window.internal.current_window_state.flags.autotab_enabled &&
+                                !keyboard_state.ctrl_down() &&
+                                !keyboard_state.alt_down();
+                            let reverse = keyboard_state.shift_down();
 
-                    let hit_test = crate::wr_translate::fullhittest_new_webrender(
-                        &*current_window.hit_tester.resolve(),
-                        current_window.internal.document_id,
-                        current_window.internal.current_window_state.focused_node,
-                        &current_window.internal.layout_results,
-                        &current_window.internal.current_window_state.mouse_state.cursor_position,
-                        current_window.internal.current_window_state.size.get_hidpi_factor(),
-                    );
+                            current_window.key_down_handled = false;
 
-                    current_window.internal.previous_window_state = None;
-                    current_window.internal.current_window_state.last_hit_test = hit_test;
+                            mem::drop(app_borrow);
 
-                    let mut nodes_to_check = NodesToCheck::simulated_mouse_move(
-                        &current_window.internal.current_window_state.last_hit_test,
-                        current_window.internal.current_window_state.focused_node,
-                        current_window.internal.current_window_state.mouse_state.mouse_down()
-                    );
+                            // NOTE: due to a Win32 bug, the WM_CHAR message gets sent immediately after
+                            // the WM_KEYDOWN: this would mess with the event handling in the window state
+                            // code (the window state code expects events to arrive in logical order)
+                            //
+                            // So here we use SendMessage instead of PostMessage in order to immediately
+                            // call AZ_REDO_HIT_TEST (instead of posting to the windows message queue).
+                            SendMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
 
-                    let mut style_layout_changes = StyleAndLayoutChanges::new(
-                        &nodes_to_check,
-                        &mut current_window.internal.layout_results,
-                        &image_cache,
-                        &mut current_window.internal.renderer_resources,
-                        current_window.internal.current_window_state.size.get_layout_size(),
-                        &current_window.internal.document_id,
-                        None,
-                        None,
-                        &None,
-                        azul_layout::do_the_relayout,
-                    );
+                            if is_tab_navigation {
+                                if let Ok(mut app_borrow) = shared_application_data.inner.try_borrow_mut() {
 
-                    PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
-                }
+                                    let ab = &mut *app_borrow;
+                                    let image_cache = &ab.image_cache;
 
-                mem::drop(app_borrow);
-                0
-            },
-            AZ_REDO_HIT_TEST => {
+                                    let mut tab_focus_handoff = None;
 
-                let mut ret = ProcessEventResult::DoNothing;
+                                    if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
+                                        if !current_window.key_down_handled {
 
-                let cur_hwnd;
+                                            // at the last (first) node, Tab moves on to the next embedded
+                                            // azul window (parent / child) instead of wrapping around
+                                            tab_focus_handoff = if current_window.internal.tab_focus_wraps(reverse) {
+                                                get_next_tab_focus_window(hwnd, reverse).map(|w| (w, reverse))
+                                            } else {
+                                                None
+                                            };
 
-                let ab = &mut *app_borrow;
-                let windows = &mut ab.windows;
-                let fc_cache = &mut ab.fc_cache;
-                let image_cache = &mut ab.image_cache;
-                let config = &ab.config;
-                let hinstance = ab.hinstance;
+                                            let new_focus = match tab_focus_handoff {
+                                                Some(_) => None,
+                                                None => current_window.internal.get_next_tab_focus(reverse),
+                                            };
 
-                let mut new_windows = Vec::new();
-                let mut destroyed_windows = Vec::new();
+                                            if new_focus.is_some() || tab_focus_handoff.is_some() {
+                                                // deliver FocusLost / FocusReceived to the old / new focused node
+                                                current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                                                current_window.set_keyboard_focus(image_cache, new_focus);
+                                                PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                                            }
+                                        }
+                                    }
 
-                match windows.get_mut(&hwnd_key) {
-                    Some(current_window) => {
+                                    mem::drop(app_borrow);
 
-                        use winapi::um::winuser::{GetDC, ReleaseDC};
+                                    if let Some((next_window, reverse)) = tab_focus_handoff {
+                                        PostMessageW(next_window, AZ_TAB_FOCUS_ENTER, reverse as WPARAM, 0);
+                                    }
+                                }
+                            }
 
-                        cur_hwnd = current_window.hwnd;
+                            0
+                        } else {
+                            mem::drop(app_borrow);
+                            DefWindowProcW(hwnd, msg, wparam, lparam)
+                        }
+                    } else {
+                        mem::drop(app_borrow);
+                        DefWindowProcW(hwnd, msg, wparam, lparam)
+                    }
+                }
+            },
+            WM_INPUTLANGCHANGE => {
 
-                        let hDC = GetDC(cur_hwnd);
+                use winapi::shared::minwindef::HKL;
 
-                        let gl_context = match current_window.gl_context {
-                            Some(c) => {
-                                if !hDC.is_null() {
-                                    wglMakeCurrent(hDC, c);
-                                }
-                            },
-                            None => { },
-                        };
+                // keyboard layout changed: refresh the cached layout used
+                // to map virtual keys (and discard half-decoded characters)
+                self::event::set_keyboard_layout(lparam as HKL);
 
-                        let mut current_program = [0_i32];
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.high_surrogate = None;
+                }
 
-                        {
-                            let mut gl = &mut current_window.gl_functions.functions;
-                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
-                        }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_DEADCHAR | WM_SYSDEADCHAR => {
+                // Dead key (accent) pressed: TranslateMessage composes it with the
+                // next key, the composed character arrives as a regular WM_CHAR
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_CHAR | WM_SYSCHAR => {
 
-                        ret = process_event(
-                            hinstance,
-                            current_window,
-                            fc_cache,
-                            image_cache,
-                            config,
-                            &mut new_windows,
-                            &mut destroyed_windows,
-                        );
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
-                        let mut gl = &mut current_window.gl_functions.functions;
-                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                        gl.use_program(current_program[0] as u32);
+                    use self::event::decode_wm_char;
 
-                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                        if !hDC.is_null() {
-                            ReleaseDC(cur_hwnd, hDC);
+                    // characters outside of the BMP arrive as two WM_CHAR (surrogate pairs)
+                    let c = decode_wm_char(&mut current_window.high_surrogate, wparam as u16);
+
+                    if let Some(c) = c {
+                        // backspace, enter and ctrl + backspace are forwarded
+                        // to the focused text input, other control chars are ignored
+                        let is_editing_char = c == '\u{8}' || c == '\r' || c == '\u{7f}';
+                        if !c.is_control() || is_editing_char {
+                            current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                            current_window.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
+                            PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                            mem::drop(app_borrow);
+                            0
+                        } else {
+                            mem::drop(app_borrow);
+                            DefWindowProcW(hwnd, msg, wparam, lparam)
+                        }
+                    } else {
+                        mem::drop(app_borrow);
+                        DefWindowProcW(hwnd, msg, wparam, lparam)
+                    }
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_KEYUP | WM_SYSKEYUP => {
+                use self::event::process_key_params;
+                if let Some((scancode, vk)) = process_key_params(wparam, lparam) {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.keyboard_state.current_char = None.into();
+                        current_window.internal.current_window_state.keyboard_state.pressed_scancodes.remove_hm_item(&scancode);
+                        if let Some(vk) = vk {
+                            current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                            current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = None.into();
                         }
+                        PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        mem::drop(app_borrow);
                         0
-                    },
-                    None => {
+                    } else {
+                        mem::drop(app_borrow);
+                        DefWindowProcW(hwnd, msg, wparam, lparam)
+                    }
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_MOUSELEAVE | WM_NCMOUSELEAVE => {
+
+                use winapi::um::winuser::{GetCursorPos, GetClientRect, ScreenToClient};
+                use azul_core::window::{FullHitTest, CursorPosition, LogicalPosition};
+
+                // cursor moved from the frame into the client area:
+                // WM_MOUSEMOVE will update the cursor position
+                if msg == WM_NCMOUSELEAVE {
+                    let mut cursor_pos = POINT { x: 0, y: 0 };
+                    let mut client_rect: RECT = mem::zeroed();
+                    GetCursorPos(&mut cursor_pos);
+                    ScreenToClient(hwnd, &mut cursor_pos);
+                    GetClientRect(hwnd, &mut client_rect);
+                    if cursor_pos.x >= client_rect.left && cursor_pos.x < client_rect.right &&
+                       cursor_pos.y >= client_rect.top && cursor_pos.y < client_rect.bottom {
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam);
-                    },
-                };
+                    }
+                }
 
-                let hinstance = ab.hinstance;
-                mem::drop(ab);
-                mem::drop(app_borrow);
-                create_windows(hinstance, shared_application_data, new_windows);
-                let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
-                let mut ab = &mut *app_borrow;
-                destroy_windows(ab, destroyed_windows);
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
-                mem::drop(ab);
+                    let current_focus = current_window.internal.current_window_state.focused_node;
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    let last_seen = match current_window.internal.current_window_state.mouse_state.cursor_position {
+                        CursorPosition::InWindow(i) => i,
+                        _ => LogicalPosition::zero(),
+                    };
+                    current_window.internal.current_window_state.mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
+                    current_window.internal.current_window_state.last_hit_test = FullHitTest::empty(current_focus);
+                    current_window.node_cursor = None;
 
-                match ret {
-                    ProcessEventResult::DoNothing => { },
-                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
-                        PostMessageW(cur_hwnd, AZ_REGENERATE_DOM, 0, 0);
-                    },
-                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
-                        for window in app_borrow.windows.values() {
-                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
-                        }
-                    },
-                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
-                        PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
-                    },
-                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
-                        if let Some(w) = app_borrow.windows.get_mut(&hwnd_key) {
-                            // TODO: submit display list, wait for new hit-tester and update hit-test results
-                            w.internal.previous_window_state = Some(w.internal.current_window_state.clone());
-                            PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
-                            PostMessageW(cur_hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                        }
-                    },
-                    ProcessEventResult::ShouldReRenderCurrentWindow => {
-                        PostMessageW(cur_hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
-                    },
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    mem::drop(app_borrow);
+                    0
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_RBUTTONDOWN => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.pointer_input_type = PointerInputType::of_current_message();
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.right_down = true;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
-
                 mem::drop(app_borrow);
-                0
+                focus_embedded_window_on_click(hwnd);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            AZ_REGENERATE_DISPLAY_LIST => {
+            WM_RBUTTONUP => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
 
-                use winapi::um::winuser::InvalidateRect;
+                    // open context menu
+                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
 
-                let ab = &mut *app_borrow;
-                let image_cache = &ab.image_cache;
-                let windows = &mut ab.windows;
+                        use winapi::um::winuser::{
+                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
+                            GetClientRect, ClientToScreen,
+                            TPM_TOPALIGN, TPM_LEFTALIGN,
+                        };
 
-                if let Some(current_window) =  windows.get_mut(&hwnd_key) {
+                        let mut hPopupMenu = CreatePopupMenu();
+                        let mut callbacks = BTreeMap::new();
+                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
 
-                    rebuild_display_list(
-                        &mut current_window.internal,
-                        &mut current_window.render_api,
-                        image_cache,
-                        Vec::new(), // no resource updates
-                    );
+                        WindowsMenuBar::recursive_construct_menu(
+                            &mut hPopupMenu,
+                            &context_menu.items.as_ref(),
+                            &mut callbacks,
+                        );
 
-                    let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
-                    current_window.hit_tester = AsyncHitTester::Requested(
-                        current_window.render_api.request_hit_tester(wr_document_id)
-                    );
+                        let align = match context_menu.position {
+                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
+                        };
 
-                    generate_frame(
-                        &mut current_window.internal,
-                        &mut current_window.render_api,
-                        true,
-                    );
+                        // get the current top left edge of the window rect
+                        let mut rect: RECT = unsafe { mem::zeroed() };
+                        GetClientRect(hwnd, &mut rect);
 
-                    PostMessageW(hwnd, WM_PAINT, 0, 0);
-                    mem::drop(app_borrow);
-                    0
-                } else {
-                    mem::drop(app_borrow);
-                    -1
-                }
-            },
-            AZ_GPU_SCROLL_RENDER => {
+                        let mut top_left = POINT { x: rect.left, y: rect.top };
+                        ClientToScreen(hwnd, &mut top_left);
 
-                match app_borrow.windows.get_mut(&hwnd_key) {
-                    Some(current_window) => {
-                        generate_frame(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            false,
+                        let pos = match context_menu.position {
+                            _ => hit.point_in_viewport, // TODO
+                        };
+
+                        current_window.context_menu = Some(CurrentContextMenu {
+                            callbacks,
+                            hit_dom_node: node_id,
+                        });
+
+                        SetForegroundWindow(hwnd);
+                        TrackPopupMenu(
+                            hPopupMenu,
+                            align,
+                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
+                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
+                            0,
+                            hwnd,
+                            ptr::null_mut()
                         );
+                    }
 
-                        PostMessageW(hwnd, WM_PAINT, 0, 0);
-                    },
-                    None => { },
+                    current_window.internal.current_window_state.mouse_state.right_down = false;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
-
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_CREATE => {
-                if let Ok(mut o) = app_borrow.active_hwnds.try_borrow_mut() {
-                    o.insert(hwnd);
+            WM_MBUTTONDOWN => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.middle_down = true;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
+                focus_embedded_window_on_click(hwnd);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_ACTIVATE => {
+            WM_MBUTTONUP => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.middle_down = false;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_ERASEBKGND => {
+            WM_LBUTTONDOWN => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.pointer_input_type = PointerInputType::of_current_message();
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.left_down = true;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
                 mem::drop(app_borrow);
-                1
+                focus_embedded_window_on_click(hwnd);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_SETFOCUS => {
+            WM_LBUTTONUP => {
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                    current_window.internal.current_window_state.flags.has_focus = true;
-                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+
+                    // open context menu
+                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
+
+                        use winapi::um::winuser::{
+                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
+                            GetClientRect, ClientToScreen,
+                            TPM_TOPALIGN, TPM_LEFTALIGN,
+                        };
+
+                        let mut hPopupMenu = CreatePopupMenu();
+                        let mut callbacks = BTreeMap::new();
+                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+
+                        WindowsMenuBar::recursive_construct_menu(
+                            &mut hPopupMenu,
+                            &context_menu.items.as_ref(),
+                            &mut callbacks,
+                        );
+
+                        let align = match context_menu.position {
+                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
+                        };
+
+                        // get the current top left edge of the window rect
+                        let mut rect: RECT = unsafe { mem::zeroed() };
+                        GetClientRect(hwnd, &mut rect);
+
+                        let mut top_left = POINT { x: rect.left, y: rect.top };
+                        ClientToScreen(hwnd, &mut top_left);
+
+                        let pos = match context_menu.position {
+                            _ => hit.point_in_viewport, // TODO
+                        };
+
+                        current_window.context_menu = Some(CurrentContextMenu {
+                            callbacks,
+                            hit_dom_node: node_id,
+                        });
+
+                        SetForegroundWindow(hwnd);
+                        TrackPopupMenu(
+                            hPopupMenu,
+                            align,
+                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
+                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
+                            0,
+                            hwnd,
+                            ptr::null_mut()
+                        );
+                    }
+
+                    current_window.internal.current_window_state.mouse_state.left_down = false;
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_MOUSEWHEEL => {
+                use winapi::{
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM},
+                    um::winuser::{SendMessageW, MK_CONTROL},
+                };
+                // the OS sends wheel messages to the focused window: forward them
+                // to the embedded azul window (parent / child) under the cursor
+                let cursor_pos = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                if let Some(target) = get_embedded_window_at(hwnd, cursor_pos) {
+                    mem::drop(app_borrow);
+                    SendMessageW(target, msg, wparam, lparam)
+                } else if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let value = (wparam >> 16) as i16;
+                    let value = value as i32;
+                    let value = value as f32 / WHEEL_DELTA as f32;
+
+                    // Ctrl + wheel: zoom the content instead of scrolling
+                    if current_window.internal.current_window_state.flags.ctrl_wheel_zoom_enabled &&
+                       (wparam & 0xFFFF) & MK_CONTROL != 0 {
+                        current_window.zoom_with_wheel(value);
+                    } else {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.mouse_state.scroll_y = Some(value).into();
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
                     mem::drop(app_borrow);
                     0
                 } else {
@@ -2910,227 +10783,195 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
-            WM_KILLFOCUS => {
+            WM_DPICHANGED => {
+                use winapi::shared::minwindef::HIWORD;
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                    current_window.internal.current_window_state.flags.has_focus = false;
-                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                    mem::drop(app_borrow);
-                    0
-                } else {
-                    mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                    let new_dpi = HIWORD(wparam as u32) as u32;
+                    current_window.rescale_custom_cursor(self::dpi::dpi_to_scale_factor(new_dpi));
                 }
+                mem::drop(app_borrow);
+                // either the window moved to another monitor or the
+                // scale factor of the monitor changed, only the latter
+                // changes the monitor configuration
+                check_monitors_changed();
+                DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_MOUSEMOVE => {
+            WM_DISPLAYCHANGE => {
+                mem::drop(app_borrow);
+                check_monitors_changed();
+                // the monitor may have been disconnected or changed its resolution
+                apply_monitor_confinement(hwnd);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            AZ_VISIBILITY_CHANGED => {
+                let ab = &mut *app_borrow;
+                let is_hidden = is_window_hidden(hwnd, ab.dwm.as_ref());
+                if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
+                    current_window.update_visibility(is_hidden);
+                }
+                mem::drop(app_borrow);
+                0
+            },
+            WM_WTSSESSION_CHANGE => {
 
-                use winapi::{
-                    um::winuser::{
-                        SetClassLongPtrW, TrackMouseEvent,
-                        TME_LEAVE, HOVER_DEFAULT, TRACKMOUSEEVENT,
-                        GCLP_HCURSOR
-                    },
-                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
-                };
-                use azul_core::window::{
-                    CursorTypeHitTest, LogicalPosition,
-                    CursorPosition, OptionMouseCursorType,
-                    FullHitTest,
+                use winapi::um::winuser::{
+                    InvalidateRect, WTS_CONSOLE_CONNECT, WTS_CONSOLE_DISCONNECT,
+                    WTS_REMOTE_CONNECT, WTS_REMOTE_DISCONNECT,
+                    WTS_SESSION_LOCK, WTS_SESSION_UNLOCK,
                 };
 
-                let x = GET_X_LPARAM(lparam);
-                let y = GET_Y_LPARAM(lparam);
-
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
-                    let pos = CursorPosition::InWindow(LogicalPosition::new(
-                        x as f32 / current_window.internal.current_window_state.size.get_hidpi_factor(),
-                        y as f32 / current_window.internal.current_window_state.size.get_hidpi_factor(),
-                    ));
+                    let was_paused = current_window.session_locked || current_window.session_disconnected;
 
-                    // call SetCapture(hwnd) so that we can capture the WM_MOUSELEAVE event
-                    let cur_cursor_pos = current_window.internal.current_window_state.mouse_state.cursor_position;
-                    let prev_cursor_pos = current_window.internal.previous_window_state
-                        .as_ref().map(|m| m.mouse_state.cursor_position).unwrap_or_default();
-
-                    if !prev_cursor_pos.is_inside_window() && cur_cursor_pos.is_inside_window() {
-                        // cursor entered
-                        TrackMouseEvent(&mut TRACKMOUSEEVENT {
-                            cbSize: mem::size_of::<TRACKMOUSEEVENT>() as u32,
-                            dwFlags: TME_LEAVE,
-                            hwndTrack: current_window.hwnd,
-                            dwHoverTime: HOVER_DEFAULT,
-                        });
+                    match wparam {
+                        WTS_SESSION_LOCK => { current_window.session_locked = true; },
+                        WTS_SESSION_UNLOCK => { current_window.session_locked = false; },
+                        WTS_CONSOLE_DISCONNECT | WTS_REMOTE_DISCONNECT => { current_window.session_disconnected = true; },
+                        WTS_CONSOLE_CONNECT | WTS_REMOTE_CONNECT => { current_window.session_disconnected = false; },
+                        _ => { },
                     }
 
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.cursor_position = pos;
-                    
-                    // mouse moved, so we need a new hit test
-                    let hit_test = crate::wr_translate::fullhittest_new_webrender(
-                        &*current_window.hit_tester.resolve(),
-                        current_window.internal.document_id,
-                        current_window.internal.current_window_state.focused_node,
-                        &current_window.internal.layout_results,
-                        &current_window.internal.current_window_state.mouse_state.cursor_position,
-                        current_window.internal.current_window_state.size.get_hidpi_factor(),
-                    );
-                    let cht = CursorTypeHitTest::new(&hit_test, &current_window.internal.layout_results);
-                    current_window.internal.current_window_state.last_hit_test = hit_test;
+                    let is_paused = current_window.session_locked || current_window.session_disconnected;
 
-                    // update the cursor if necessary
-                    if current_window.internal.current_window_state.mouse_state.mouse_cursor_type != OptionMouseCursorType::Some(cht.cursor_icon) {
-                        // TODO: unset previous cursor?
-                        current_window.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
-                        SetClassLongPtrW(
-                                current_window.hwnd,
-                                GCLP_HCURSOR,
-                                (win32_translate_cursor(cht.cursor_icon) as isize)
-                                .try_into().unwrap_or(0)
-                        );
+                    if was_paused && !is_paused {
+                        if current_window.is_remote_session != is_remote_session() {
+                            // moved between a local and a remote session: the context
+                            // belongs to the old display driver (RDP may not offer
+                            // hardware acceleration at all)
+                            PostMessageW(hwnd, AZ_RECREATE_RENDERER, 0, 0);
+                        } else {
+                            InvalidateRect(hwnd, ptr::null(), 0);
+                        }
                     }
-
-                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                };
+                }
 
                 mem::drop(app_borrow);
                 0
             },
-            WM_KEYDOWN | WM_SYSKEYDOWN => {
-                if msg == WM_SYSKEYDOWN && wparam as i32 == VK_F4 {
-                    mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
-                } else {
-                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                        if let Some((scancode, vk)) = event::process_key_params(wparam, lparam) {
-                            use winapi::um::winuser::SendMessageW;
+            WM_SIZE => {
+                use azul_core::window::{WindowFrame, PhysicalSize};
+                use winapi::um::winuser::{
+                    WINDOWPOS, SWP_NOSIZE, SIZE_MAXIMIZED,
+                    SIZE_RESTORED, SIZE_MINIMIZED
+                };
+                use winapi::shared::minwindef::{LOWORD, HIWORD};
 
-                            current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                            current_window.internal.current_window_state.keyboard_state.current_char = None.into();
-                            current_window.internal.current_window_state.keyboard_state.pressed_scancodes.insert_hm_item(scancode);
-                            if let Some(vk) = vk {
-                                current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
-                                current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
-                            }
-                            mem::drop(app_borrow);
+                let new_width = LOWORD(lparam as u32);
+                let new_height = HIWORD(lparam as u32);
+                let new_size = PhysicalSize {
+                    width: new_width as u32,
+                    height: new_height as u32
+                };
 
-                            // NOTE: due to a Win32 bug, the WM_CHAR message gets sent immediately after
-                            // the WM_KEYDOWN: this would mess with the event handling in the window state
-                            // code (the window state code expects events to arrive in logical order)
-                            //
-                            // So here we use SendMessage instead of PostMessage in order to immediately
-                            // call AZ_REDO_HIT_TEST (instead of posting to the windows message queue).
-                            SendMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                let mut ab = &mut *app_borrow;
+                let is_hidden = is_window_hidden(hwnd, ab.dwm.as_ref());
+                let fc_cache = &mut ab.fc_cache;
+                let windows = &mut ab.windows;
+                let image_cache = &ab.image_cache;
 
-                            0
-                        } else {
-                            mem::drop(app_borrow);
-                            DefWindowProcW(hwnd, msg, wparam, lparam)
+                if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                    current_window.update_visibility(is_hidden);
+                    fc_cache.apply_closure(|fc_cache| {
+
+                        use winapi::um::winuser::{GetDC, ReleaseDC};
+
+                        let mut new_window_state = current_window.internal.current_window_state.clone();
+                        new_window_state.size.resize_to_physical(new_size);
+
+                        match wparam {
+                            SIZE_MAXIMIZED => {
+                                new_window_state.flags.frame = WindowFrame::Maximized;
+                            },
+                            SIZE_MINIMIZED => {
+                                new_window_state.flags.frame = WindowFrame::Minimized;
+                            },
+                            SIZE_RESTORED => {
+                                new_window_state.flags.frame = WindowFrame::Normal;
+                            },
+                            _ => { }
                         }
-                    } else {
-                        mem::drop(app_borrow);
-                        DefWindowProcW(hwnd, msg, wparam, lparam)
-                    }
-                }
-            },
-            WM_CHAR | WM_SYSCHAR => {
 
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        let hDC = GetDC(hwnd);
 
-                    use std::char;
+                        let gl_context = match current_window.gl_context {
+                            Some(c) => {
+                                if !hDC.is_null() {
+                                    wglMakeCurrent(hDC, c);
+                                }
+                            },
+                            None => { },
+                        };
 
-                    let is_high_surrogate = 0xD800 <= wparam && wparam <= 0xDBFF;
-                    let is_low_surrogate = 0xDC00 <= wparam && wparam <= 0xDFFF;
+                        let mut current_program = [0_i32];
 
-                    let mut c = None; // character
-                    if is_high_surrogate {
-                        current_window.high_surrogate = Some(wparam as u16);
-                    } else if is_low_surrogate {
-                        if let Some(high_surrogate) = current_window.high_surrogate {
-                            let pair = [high_surrogate, wparam as u16];
-                            if let Some(Ok(chr)) = char::decode_utf16(pair.iter().copied()).next() {
-                                c = Some(chr);
-                            }
-                        }
-                    } else {
-                        current_window.high_surrogate = None;
-                        if let Some(chr) = char::from_u32(wparam as u32) {
-                            c = Some(chr);
+                        {
+                            let mut gl = &mut current_window.gl_functions.functions;
+                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
                         }
-                    }
 
-                    if let Some(c) = c {
-                        if !c.is_control() {
-                            current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                            current_window.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
-                            PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                            mem::drop(app_borrow);
-                            0
-                        } else {
-                            mem::drop(app_borrow);
-                            DefWindowProcW(hwnd, msg, wparam, lparam)
+                        let resize_result = current_window.internal.do_quick_resize(
+                            &image_cache,
+                            &crate::app::CALLBACKS,
+                            azul_layout::do_the_relayout,
+                            fc_cache,
+                            &current_window.gl_context_ptr,
+                            &new_window_state.size,
+                            new_window_state.theme,
+                        );
+
+                        let mut txn = WrTransaction::new();
+                        wr_synchronize_updated_images(
+                            resize_result.updated_images,
+                            &current_window.internal.document_id,
+                            &mut txn
+                        );
+
+                        let mut gl = &mut current_window.gl_functions.functions;
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        gl.use_program(current_program[0] as u32);
+
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(hwnd, hDC);
                         }
-                    } else {
-                        mem::drop(app_borrow);
-                        DefWindowProcW(hwnd, msg, wparam, lparam)
-                    }
-                } else {
-                    mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
-                }
-            },
-            WM_KEYUP | WM_SYSKEYUP => {
-                use self::event::process_key_params;
-                if let Some((scancode, vk)) = process_key_params(wparam, lparam) {
-                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+
                         current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                        current_window.internal.current_window_state.keyboard_state.current_char = None.into();
-                        current_window.internal.current_window_state.keyboard_state.pressed_scancodes.remove_hm_item(&scancode);
-                        if let Some(vk) = vk {
-                            current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
-                            current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = None.into();
-                        }
-                        PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                        mem::drop(app_borrow);
-                        0
-                    } else {
-                        mem::drop(app_borrow);
-                        DefWindowProcW(hwnd, msg, wparam, lparam)
-                    }
-                } else {
-                    mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
-                }
-            },
-            WM_MOUSELEAVE => {
+                        current_window.internal.current_window_state = new_window_state;
 
-                use winapi::um::winuser::{SetClassLongPtrW, GCLP_HCURSOR};
-                use azul_core::window::{
-                    FullHitTest, OptionMouseCursorType,
-                    CursorPosition, LogicalPosition,
-                };
+                        txn.set_document_view(
+                            WrDeviceIntRect::from_size(
+                                WrDeviceIntSize::new(new_width as i32, new_height as i32),
+                            )
+                        );
+                        current_window.render_api.send_transaction(wr_translate_document_id(current_window.internal.document_id), txn);
 
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        rebuild_display_list(
+                            &mut current_window.internal,
+                            &mut current_window.render_api,
+                            image_cache,
+                            Vec::new(),
+                        );
 
-                    let current_focus = current_window.internal.current_window_state.focused_node;
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    let last_seen = match current_window.internal.current_window_state.mouse_state.cursor_position {
-                        CursorPosition::InWindow(i) => i,
-                        _ => LogicalPosition::zero(),
-                    };
-                    current_window.internal.current_window_state.mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
-                    current_window.internal.current_window_state.last_hit_test = FullHitTest::empty(current_focus);
-                    current_window.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
+                        let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                        current_window.hit_tester = AsyncHitTester::Requested(
+                            current_window.render_api.request_hit_tester(wr_document_id)
+                        );
+
+                        generate_frame(
+                            &mut current_window.internal,
+                            &mut current_window.render_api,
+                            true,
+                        );
+
+                        current_window.resize_overlay_layers(image_cache, fc_cache);
+                    });
+
+                    if current_window.window_region.is_some() {
+                        current_window.apply_window_region();
+                    }
 
-                    SetClassLongPtrW(
-                        hwnd,
-                        GCLP_HCURSOR,
-                        (win32_translate_cursor(MouseCursorType::Default) as isize)
-                        .try_into().unwrap_or(0)
-                    );
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                     mem::drop(app_borrow);
                     0
                 } else {
@@ -3138,327 +10979,371 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
-            WM_RBUTTONDOWN => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.right_down = true;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+            WM_NCHITTEST => {
+
+                use winapi::{
+                    um::winuser::{
+                        GetDC, ReleaseDC, ScreenToClient,
+                        HTCAPTION, HTCLIENT, HTNOWHERE, HTTRANSPARENT,
+                    },
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
+                };
+
+                let hit = DefWindowProcW(hwnd, msg, wparam, lparam);
+                if hit != HTCLIENT {
+                    mem::drop(app_borrow);
+                    return hit;
                 }
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_RBUTTONUP => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
 
-                    // open context menu
-                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
+                let current_window = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return hit;
+                    }
+                };
 
-                        use winapi::um::winuser::{
-                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
-                            GetClientRect, ClientToScreen,
-                            TPM_TOPALIGN, TPM_LEFTALIGN,
-                        };
+                // title bar drawn by the application: drag regions act as the caption
+                if !current_window.title_bar_regions.is_empty() {
+                    let mut point = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                    ScreenToClient(hwnd, &mut point);
+                    let position = current_window.internal.current_window_state.size.physical_to_logical_position(point.x, point.y);
+                    match current_window.get_title_bar_region_at(position) {
+                        Some(TitleBarRegion::Drag) => {
+                            mem::drop(app_borrow);
+                            return HTCAPTION;
+                        },
+                        Some(TitleBarRegion::NoDrag) => {
+                            mem::drop(app_borrow);
+                            return hit;
+                        },
+                        None => { },
+                    }
+                }
 
-                        let mut hPopupMenu = CreatePopupMenu();
-                        let mut callbacks = BTreeMap::new();
-                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                let windows_options = &current_window.internal.current_window_state
+                    .platform_specific_options
+                    .windows_options;
 
-                        WindowsMenuBar::recursive_construct_menu(
-                            &mut hPopupMenu,
-                            &context_menu.items.as_ref(),
-                            &mut callbacks,
-                        );
+                let threshold = match windows_options.alpha_hit_test_threshold.into_option() {
+                    Some(s) => s.max(0.0).min(1.0),
+                    None => {
+                        mem::drop(app_borrow);
+                        return hit;
+                    }
+                };
 
-                        let align = match context_menu.position {
-                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
-                        };
+                let pass_through = windows_options.alpha_hit_test_pass_through;
 
-                        // get the current top left edge of the window rect
-                        let mut rect: RECT = unsafe { mem::zeroed() };
-                        GetClientRect(hwnd, &mut rect);
+                let mut point = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                ScreenToClient(hwnd, &mut point);
 
-                        let mut top_left = POINT { x: rect.left, y: rect.top };
-                        ClientToScreen(hwnd, &mut top_left);
+                let hDC = GetDC(hwnd);
+                if hDC.is_null() {
+                    mem::drop(app_borrow);
+                    return hit;
+                }
 
-                        let pos = match context_menu.position {
-                            _ => hit.point_in_viewport, // TODO
-                        };
+                let pixel_alpha = current_window
+                    .get_presented_alpha_at(hDC, point.x, point.y)
+                    .unwrap_or(255);
 
-                        current_window.context_menu = Some(CurrentContextMenu {
-                            callbacks,
-                            hit_dom_node: node_id,
-                        });
+                ReleaseDC(hwnd, hDC);
 
-                        SetForegroundWindow(hwnd);
-                        TrackPopupMenu(
-                            hPopupMenu,
-                            align,
-                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
-                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
-                            0,
-                            hwnd,
-                            ptr::null_mut()
-                        );
+                let result = if (pixel_alpha as f32) < threshold * 255.0 {
+                    if pass_through {
+                        HTTRANSPARENT
+                    } else {
+                        HTNOWHERE
                     }
+                } else {
+                    hit
+                };
 
-                    current_window.internal.current_window_state.mouse_state.right_down = false;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                }
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                result
             },
-            WM_MBUTTONDOWN => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.middle_down = true;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+            WM_DWMSENDICONICTHUMBNAIL | WM_DWMSENDICONICLIVEPREVIEWBITMAP => {
+
+                use winapi::shared::minwindef::{HIWORD, LOWORD};
+                use winapi::um::{
+                    wingdi::DeleteObject,
+                    winuser::{GetDC, ReleaseDC},
+                };
+
+                let hDC = GetDC(hwnd);
+                if hDC.is_null() {
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
                 }
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_MBUTTONUP => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.middle_down = false;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+
+                let ab = &mut *app_borrow;
+
+                let (max_width, max_height) = if msg == WM_DWMSENDICONICTHUMBNAIL {
+                    // thumbnail: maximum size is given in the lparam
+                    (HIWORD(lparam as u32) as i32, LOWORD(lparam as u32) as i32)
+                } else {
+                    // live preview: full size of the framebuffer
+                    let framebuffer_size = get_framebuffer_size(hwnd);
+                    (framebuffer_size.width as i32, framebuffer_size.height as i32)
+                };
+
+                let hbitmap = match ab.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => current_window.render_to_dib(hDC, max_width, max_height),
+                    None => None,
+                };
+
+                ReleaseDC(hwnd, hDC);
+
+                if let (Some(hbitmap), Some(dwm)) = (hbitmap, ab.dwm.as_ref()) {
+                    if msg == WM_DWMSENDICONICTHUMBNAIL {
+                        if let Some(DwmSetIconicThumbnail) = dwm.DwmSetIconicThumbnail {
+                            (DwmSetIconicThumbnail)(hwnd, hbitmap, 0);
+                        }
+                    } else {
+                        if let Some(DwmSetIconicLivePreviewBitmap) = dwm.DwmSetIconicLivePreviewBitmap {
+                            (DwmSetIconicLivePreviewBitmap)(hwnd, hbitmap, ptr::null_mut(), 0);
+                        }
+                    }
+                    // the DWM keeps its own copy of the bitmap
+                    DeleteObject(hbitmap as *mut _);
                 }
+
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                0
             },
-            WM_LBUTTONDOWN => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.left_down = true;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                }
+            AZ_SET_CUSTOM_CURSOR => {
+                let cursor = (lparam as *const Option<(RawImage, LogicalPosition)>).as_ref().cloned().unwrap_or(None);
+                let result = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => if current_window.set_custom_cursor(cursor) { 0 } else { -1 },
+                    None => -1,
+                };
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                result
             },
-            WM_LBUTTONUP => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
+            AZ_SET_BUSY => {
 
-                    // open context menu
-                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
+                use winapi::um::winuser::{
+                    GetDC, ReleaseDC, SetTimer, KillTimer, EnableWindow,
+                    GetCapture, ReleaseCapture, GetFocus, SetFocus, GetForegroundWindow,
+                };
+                use winapi::shared::minwindef::FALSE;
 
-                        use winapi::um::winuser::{
-                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
-                            GetClientRect, ClientToScreen,
-                            TPM_TOPALIGN, TPM_LEFTALIGN,
-                        };
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let image_cache = &ab.image_cache;
+                let data = &mut ab.data;
 
-                        let mut hPopupMenu = CreatePopupMenu();
-                        let mut callbacks = BTreeMap::new();
-                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                let current_window = match windows.get_mut(&hwnd_key) {
+                    Some(s) => s,
+                    None => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                };
 
-                        WindowsMenuBar::recursive_construct_menu(
-                            &mut hPopupMenu,
-                            &context_menu.items.as_ref(),
-                            &mut callbacks,
-                        );
+                if wparam != 0 {
 
-                        let align = match context_menu.position {
-                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
-                        };
+                    if current_window.busy.is_some() {
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
 
-                        // get the current top left edge of the window rect
-                        let mut rect: RECT = unsafe { mem::zeroed() };
-                        GetClientRect(hwnd, &mut rect);
+                    let spinner = (lparam as *const Option<LayoutCallback>).as_ref().cloned().unwrap_or(None);
 
-                        let mut top_left = POINT { x: rect.left, y: rect.top };
-                        ClientToScreen(hwnd, &mut top_left);
+                    // the mouse up of a click / drag in progress will never arrive
+                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                    let mouse_state = &mut current_window.internal.current_window_state.mouse_state;
+                    mouse_state.left_down = false;
+                    mouse_state.right_down = false;
+                    mouse_state.middle_down = false;
 
-                        let pos = match context_menu.position {
-                            _ => hit.point_in_viewport, // TODO
-                        };
+                    let overlay = spinner.map(|layout_callback| {
 
-                        current_window.context_menu = Some(CurrentContextMenu {
-                            callbacks,
-                            hit_dom_node: node_id,
+                        let hDC = GetDC(hwnd);
+                        if let Some(c) = current_window.gl_context {
+                            if !hDC.is_null() {
+                                wglMakeCurrent(hDC, c);
+                            }
+                        }
+
+                        let index = fc_cache.apply_closure(|fc_cache| {
+                            current_window.add_overlay_layer(layout_callback, data, image_cache, fc_cache)
                         });
 
-                        SetForegroundWindow(hwnd);
-                        TrackPopupMenu(
-                            hPopupMenu,
-                            align,
-                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
-                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
-                            0,
-                            hwnd,
-                            ptr::null_mut()
-                        );
-                    }
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(hwnd, hDC);
+                        }
 
-                    current_window.internal.current_window_state.mouse_state.left_down = false;
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                }
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_MOUSEWHEEL => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let value = (wparam >> 16) as i16;
-                    let value = value as i32;
-                    let value = value as f32 / WHEEL_DELTA as f32;
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.scroll_y = Some(value).into();
-                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        SetTimer(hwnd, AZ_BUSY_TICK, 50, None);
+                        index
+                    });
+
+                    current_window.busy = Some(BusyState {
+                        overlay,
+                        had_focus: GetFocus() == hwnd,
+                    });
+                    current_window.update_cursor();
+
+                    // EnableWindow / ReleaseCapture send messages to the window
                     mem::drop(app_borrow);
+
+                    if GetCapture() == hwnd {
+                        ReleaseCapture();
+                    }
+                    EnableWindow(hwnd, FALSE);
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
                     0
                 } else {
+
+                    let busy = match current_window.busy.take() {
+                        Some(s) => s,
+                        None => {
+                            mem::drop(app_borrow);
+                            return 0;
+                        },
+                    };
+
+                    if let Some(index) = busy.overlay {
+                        KillTimer(hwnd, AZ_BUSY_TICK);
+                        current_window.remove_overlay_layer(index);
+                    }
+
+                    current_window.update_cursor();
                     mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
+
+                    EnableWindow(hwnd, TRUE);
+                    // a disabled window loses the keyboard focus, but stays
+                    // the foreground window: give the focus back to it
+                    if busy.had_focus && GetForegroundWindow() == hwnd {
+                        SetFocus(hwnd);
+                    }
+
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    0
                 }
             },
-            WM_DPICHANGED => {
+            AZ_SET_WINDOW_REGION => {
+                let region = (lparam as *const Option<WindowRegion>).as_ref().cloned().unwrap_or(None);
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.window_region = region;
+                    current_window.apply_window_region();
+                }
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                0
             },
-            WM_SIZE => {
-                use azul_core::window::{WindowFrame, PhysicalSize};
-                use winapi::um::winuser::{
-                    WINDOWPOS, SWP_NOSIZE, SIZE_MAXIMIZED,
-                    SIZE_RESTORED, SIZE_MINIMIZED
-                };
-                use winapi::shared::minwindef::{LOWORD, HIWORD};
+            AZ_ADD_OVERLAY_LAYER => {
 
-                let new_width = LOWORD(lparam as u32);
-                let new_height = HIWORD(lparam as u32);
-                let new_size = PhysicalSize {
-                    width: new_width as u32,
-                    height: new_height as u32
-                };
+                use winapi::um::winuser::{GetDC, ReleaseDC};
 
-                let mut ab = &mut *app_borrow;
-                let fc_cache = &mut ab.fc_cache;
+                let ab = &mut *app_borrow;
                 let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
                 let image_cache = &ab.image_cache;
+                let data = &mut ab.data;
 
-                if let Some(current_window) = windows.get_mut(&hwnd_key) {
-                    fc_cache.apply_closure(|fc_cache| {
-
-                        use winapi::um::winuser::{GetDC, ReleaseDC};
-
-                        let mut new_window_state = current_window.internal.current_window_state.clone();
-                        new_window_state.size.dimensions = new_size.to_logical(new_window_state.size.get_hidpi_factor());
+                let layout_callback = (lparam as *mut LayoutCallback).as_ref().cloned();
 
-                        match wparam {
-                            SIZE_MAXIMIZED => {
-                                new_window_state.flags.frame = WindowFrame::Maximized;
-                            },
-                            SIZE_MINIMIZED => {
-                                new_window_state.flags.frame = WindowFrame::Minimized;
-                            },
-                            SIZE_RESTORED => {
-                                new_window_state.flags.frame = WindowFrame::Normal;
-                            },
-                            _ => { }
-                        }
+                let result = match (windows.get_mut(&hwnd_key), layout_callback) {
+                    (Some(current_window), Some(layout_callback)) => {
 
                         let hDC = GetDC(hwnd);
-
-                        let gl_context = match current_window.gl_context {
-                            Some(c) => {
-                                if !hDC.is_null() {
-                                    wglMakeCurrent(hDC, c);
-                                }
-                            },
-                            None => { },
-                        };
-
-                        let mut current_program = [0_i32];
-
-                        {
-                            let mut gl = &mut current_window.gl_functions.functions;
-                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                        if let Some(c) = current_window.gl_context {
+                            if !hDC.is_null() {
+                                wglMakeCurrent(hDC, c);
+                            }
                         }
 
-                        let resize_result = current_window.internal.do_quick_resize(
-                            &image_cache,
-                            &crate::app::CALLBACKS,
-                            azul_layout::do_the_relayout,
-                            fc_cache,
-                            &current_window.gl_context_ptr,
-                            &new_window_state.size,
-                            new_window_state.theme,
-                        );
-
-                        let mut txn = WrTransaction::new();
-                        wr_synchronize_updated_images(
-                            resize_result.updated_images,
-                            &current_window.internal.document_id,
-                            &mut txn
-                        );
-
-                        let mut gl = &mut current_window.gl_functions.functions;
-                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                        gl.use_program(current_program[0] as u32);
+                        let index = fc_cache.apply_closure(|fc_cache| {
+                            current_window.add_overlay_layer(layout_callback, data, image_cache, fc_cache)
+                        });
 
                         wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
                         if !hDC.is_null() {
                             ReleaseDC(hwnd, hDC);
                         }
 
-                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                        current_window.internal.current_window_state = new_window_state;
+                        PostMessageW(hwnd, WM_PAINT, 0, 0);
+                        index as LRESULT
+                    },
+                    _ => -1,
+                };
 
-                        txn.set_document_view(
-                            WrDeviceIntRect::from_size(
-                                WrDeviceIntSize::new(new_width as i32, new_height as i32),
-                            )
-                        );
-                        current_window.render_api.send_transaction(wr_translate_document_id(current_window.internal.document_id), txn);
+                mem::drop(app_borrow);
+                result
+            },
+            AZ_REMOVE_OVERLAY_LAYER => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.remove_overlay_layer(wparam);
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
+                }
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_RECREATE_RENDERER => {
 
-                        rebuild_display_list(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            image_cache,
-                            Vec::new(),
-                        );
+                let hinstance = app_borrow.hinstance;
 
-                        let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
-                        current_window.hit_tester = AsyncHitTester::Requested(
-                            current_window.render_api.request_hit_tester(wr_document_id)
-                        );
+                let software = if wparam == AZ_RENDERER_FAILED {
+                    record_hardware_renderer_failure()
+                } else {
+                    is_hardware_rendering_disabled()
+                };
 
-                        generate_frame(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            true,
-                        );
-                    });
+                let (recreated, fell_back) = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        let was_hardware = current_window.gl_context.is_some();
+                        let mut result = current_window.recreate_renderer(hinstance, software);
+                        let mut software = software;
+
+                        // no new hardware context: continue in software
+                        // instead of leaving the window without a renderer
+                        if result.is_err() && !software {
+                            #[cfg(feature = "logging")] {
+                                if let Err(e) = result.as_ref() {
+                                    error!("could not recreate the hardware renderer: {:?}", e);
+                                }
+                            }
+                            HARDWARE_RENDERING_DISABLED.with(|d| d.set(true));
+                            software = true;
+                            result = current_window.recreate_renderer(hinstance, true);
+                        }
 
-                    mem::drop(app_borrow);
-                    0
-                } else {
-                    mem::drop(app_borrow);
-                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                        (result.is_ok(), was_hardware && software)
+                    },
+                    None => (false, false),
+                };
+
+                // re-layout and re-upload all fonts and images to the new renderer
+                if recreated {
+                    PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
                 }
-            },
-            WM_NCHITTEST => {
+
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+
+                if fell_back {
+                    if let Some(handler) = SOFTWARE_FALLBACK_HANDLER.with(|h| h.get()) {
+                        (handler)(hwnd, HARDWARE_RENDERER_FAILURES.with(|f| f.get()));
+                    }
+                }
+
+                0
             },
             WM_PAINT => {
 
-                use winapi::um::{
-                    wingdi::SwapBuffers,
-                    winuser::{GetDC, ReleaseDC, GetClientRect},
-                };
+                use winapi::um::winuser::{GetDC, ReleaseDC, SetTimer, ValidateRect};
 
                 // Assuming that the display list has been submitted and the
                 // scene on the background thread has been rebuilt, now tell
                 // webrender to pain the scene
 
+                // validate before rendering: InvalidateRect calls during the frame
+                // (RedrawPolicy::Continuous) schedule the next WM_PAINT
+                ValidateRect(hwnd, ptr::null());
+
                 let hDC = GetDC(hwnd);
                 if hDC.is_null() {
                     mem::drop(app_borrow);
@@ -3475,58 +11360,218 @@ unsafe extern "system" fn WindowProc(
                     },
                 };
 
-                let gl_context = match current_window.gl_context {
-                    Some(s) => s,
+                if current_window.gl_context.is_none() {
+                    // TODO: software rendering
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                // repainted by update_visibility() once the window is visible again
+                if current_window.is_hidden {
+                    ReleaseDC(hwnd, hDC);
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                // uncovering a window doesn't send WM_PAINT under DWM composition:
+                // poll until it is visible again, then repaint (AZ_OCCLUSION_TICK)
+                let skip_when_occluded = current_window.internal.current_window_state
+                    .platform_specific_options
+                    .windows_options
+                    .skip_rendering_when_occluded;
+
+                if skip_when_occluded && is_window_occluded(hwnd, app.dwm.as_ref()) {
+                    if !current_window.is_occluded {
+                        current_window.is_occluded = true;
+                        SetTimer(hwnd, AZ_OCCLUSION_TICK, 250, None);
+                    }
+                    ReleaseDC(hwnd, hDC);
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                // PresentMode::FrameCapped: don't block the message loop until the
+                // frame time is over, repaint once the AZ_FRAME_CAP_TICK timer fires
+                if current_window.frame_cap_pending {
+                    ReleaseDC(hwnd, hDC);
+                    mem::drop(app_borrow);
+                    return 0;
+                }
+
+                if let Some(delay) = current_window.get_frame_cap_delay() {
+                    let delay_ms = delay.as_millis().max(1).min(u32::MAX as u128) as u32;
+                    current_window.frame_cap_pending = true;
+                    SetTimer(hwnd, AZ_FRAME_CAP_TICK, delay_ms, None);
+                    ReleaseDC(hwnd, hDC);
+                    mem::drop(app_borrow);
+                    return 0;
+                }
+
+                // continuously redrawn windows composite a new frame each time instead of
+                // presenting the same scene again (rendered on the next WM_PAINT)
+                if current_window.internal.current_window_state.redraw_policy == RedrawPolicy::Continuous {
+                    generate_frame(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        false,
+                    );
+                }
+
+                current_window.render_and_present(hDC);
+
+                ReleaseDC(hwnd, hDC);
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_FORCE_FRAME => {
+
+                use winapi::um::winuser::{SendMessageW, GetDC, ReleaseDC};
+
+                // forward to all windows, one after another
+                if wparam == 1 {
+                    let hwnds = app_borrow.windows.values().map(|w| w.hwnd).collect::<Vec<_>>();
+                    mem::drop(app_borrow);
+                    for w in hwnds {
+                        SendMessageW(w, AZ_FORCE_FRAME, 0, 0);
+                    }
+                    return 0;
+                }
+
+                let ab = &mut *app_borrow;
+                let hinstance = ab.hinstance;
+                let windows = &mut ab.windows;
+                let image_cache = &mut ab.image_cache;
+                let fc_cache = &mut ab.fc_cache;
+                let config = &ab.config;
+
+                let mut ret = ProcessEventResult::DoNothing;
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
+
+                match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+
+                        let hDC = GetDC(hwnd);
+                        if let Some(c) = current_window.gl_context {
+                            if !hDC.is_null() {
+                                wglMakeCurrent(hDC, c);
+                            }
+                        }
+
+                        let mut current_program = [0_i32];
+
+                        {
+                            let mut gl = &mut current_window.gl_functions.functions;
+                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                        }
+
+                        // run all timers that are due, instead of waiting for WM_TIMER
+                        let now = (config.system_callbacks.get_system_time_fn.cb)();
+                        let due_timers = current_window.internal.timers
+                            .iter()
+                            .filter(|(_, timer)| timer.instant_of_next_run() <= now)
+                            .map(|(id, _)| id.id)
+                            .collect::<Vec<_>>();
+
+                        for timer_id in due_timers {
+                            ret = ret.max_self(process_timer(
+                                timer_id,
+                                hinstance,
+                                current_window,
+                                fc_cache,
+                                image_cache,
+                                config,
+                                &mut new_windows,
+                                &mut destroyed_windows,
+                            ));
+                        }
+
+                        let mut gl = &mut current_window.gl_functions.functions;
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        gl.use_program(current_program[0] as u32);
+
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(hwnd, hDC);
+                        }
+                    },
                     None => {
-                        // TODO: software rendering
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam);
                     },
-                };
+                }
 
-                wglMakeCurrent(hDC, gl_context);
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                create_windows(hinstance, shared_application_data, new_windows);
+                if let Ok(mut app_borrow) = shared_application_data.inner.try_borrow_mut() {
+                    destroy_windows(&mut *app_borrow, destroyed_windows);
+                }
 
-                let mut rect: RECT = mem::zeroed();
-                GetClientRect(hwnd, &mut rect);
+                // the DOM has to be regenerated synchronously (instead of via PostMessage)
+                match ret {
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow |
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        SendMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    _ => { },
+                }
 
-                // Block until all transactions (display list build)
-                // have finished processing
-                //
-                // Usually this shouldn't take too long, since DL building
-                // happens asynchronously between WM_SIZE and WM_PAINT
-                current_window.render_api.flush_scene_builder();
+                // still borrowed by an outer message: render the frame once that is done
+                let mut app_borrow = match shared_application_data.inner.try_borrow_mut() {
+                    Ok(o) => o,
+                    Err(_) => {
+                        defer_message(hwnd, AZ_FORCE_FRAME, 0, 0);
+                        return 0;
+                    },
+                };
 
-                let mut gl = &mut current_window.gl_functions.functions;
+                let ab = &mut *app_borrow;
+                let image_cache = &ab.image_cache;
 
-                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                gl.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                gl.disable(gl_context_loader::gl::MULTISAMPLE);
-                gl.viewport(0, 0, rect.width() as i32, rect.height() as i32);
+                if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
 
-                let mut current_program = [0_i32];
-                gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                    rebuild_display_list(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        image_cache,
+                        Vec::new(),
+                    );
 
-                let framebuffer_size = WrDeviceIntSize::new(
-                    rect.width() as i32,
-                    rect.height() as i32
-                );
+                    let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                    current_window.hit_tester = AsyncHitTester::Requested(
+                        current_window.render_api.request_hit_tester(wr_document_id)
+                    );
 
-                // Render
-                if let Some(r) = current_window.renderer.as_mut() {
-                    r.update();
-                    let _ = r.render(framebuffer_size, 0);
-                }
+                    generate_frame(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        true,
+                    );
 
-                SwapBuffers(hDC);
+                    let hDC = GetDC(hwnd);
+                    if !hDC.is_null() && current_window.render_and_present(hDC) {
+                        // block until the frame is actually on the screen
+                        current_window.gl_functions.functions.finish();
+                    }
+                    if !hDC.is_null() {
+                        ReleaseDC(hwnd, hDC);
+                    }
 
-                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                gl.use_program(current_program[0] as u32);
+                    // layout queries / hit-tests after force_frame must see this frame
+                    current_window.flush_pending();
+                }
 
-                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                ReleaseDC(hwnd, hDC);
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                0
+            },
+            AZ_FLUSH_PENDING => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.flush_pending();
+                }
+                mem::drop(app_borrow);
+                0
             },
             WM_TIMER => {
 
@@ -3602,6 +11647,68 @@ unsafe extern "system" fn WindowProc(
                             },
                         }
                     },
+                    AZ_BUSY_TICK => {
+                        // re-layout the spinner overlay so that it can animate
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            if let Some(index) = current_window.busy.and_then(|b| b.overlay) {
+
+                                let hDC = GetDC(hwnd);
+                                if let Some(c) = current_window.gl_context {
+                                    if !hDC.is_null() {
+                                        wglMakeCurrent(hDC, c);
+                                    }
+                                }
+
+                                fc_cache.apply_closure(|fc_cache| {
+                                    current_window.regenerate_overlay_layer(index, data, image_cache, fc_cache)
+                                });
+
+                                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                                if !hDC.is_null() {
+                                    ReleaseDC(hwnd, hDC);
+                                }
+
+                                PostMessageW(hwnd, WM_PAINT, 0, 0);
+                            }
+                        }
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_OCCLUSION_TICK => {
+                        use winapi::um::winuser::{InvalidateRect, KillTimer};
+                        let dwm = ab.dwm.as_ref();
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            let skip_when_occluded = current_window.internal.current_window_state
+                                .platform_specific_options
+                                .windows_options
+                                .skip_rendering_when_occluded;
+                            if !skip_when_occluded || !is_window_occluded(hwnd, dwm) {
+                                current_window.is_occluded = false;
+                                KillTimer(hwnd, AZ_OCCLUSION_TICK);
+                                InvalidateRect(hwnd, ptr::null(), 0);
+                            }
+                        }
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_FRAME_CAP_TICK => {
+                        use winapi::um::winuser::{InvalidateRect, KillTimer};
+                        KillTimer(hwnd, AZ_FRAME_CAP_TICK);
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            current_window.frame_cap_pending = false;
+                        }
+                        InvalidateRect(hwnd, ptr::null(), 0);
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_GPU_MEMORY_TICK => {
+                        let gpu_memory_budget = config.gpu_memory_budget;
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            current_window.enforce_gpu_memory_budget(gpu_memory_budget);
+                        }
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
                     id => { // run timer with ID "id"
                         match windows.get_mut(&hwnd_key) {
                             Some(current_window) => {
@@ -3841,6 +11948,14 @@ unsafe extern "system" fn WindowProc(
 
                 use winapi::um::winuser::{GetDC, ReleaseDC};
 
+                // release the reserved screen edge
+                unregister_appbar(hwnd);
+                unregister_session_notification(hwnd);
+                CONFINED_WINDOWS.with(|c| c.borrow_mut().remove(&(hwnd as usize)));
+                ASPECT_RATIO_WINDOWS.with(|a| a.borrow_mut().remove(&(hwnd as usize)));
+                self::notification::remove_window_notifications(hwnd);
+                CUSTOM_FRAME_WINDOWS.with(|w| w.borrow_mut().remove(&(hwnd as usize)));
+
                 // make OpenGL context current in case there are
                 // OpenGL objects stored in the windows' RefAny data
 
@@ -3903,12 +12018,88 @@ unsafe extern "system" fn WindowProc(
 
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            WM_COPYDATA => {
+
+                use winapi::um::winuser::{COPYDATASTRUCT, IsIconic, ShowWindow, SW_RESTORE};
+
+                mem::drop(app_borrow);
+
+                let copy_data = match (lparam as *const COPYDATASTRUCT).as_ref() {
+                    Some(s) if s.dwData == AZ_COPYDATA_ACTIVATION_URL && !s.lpData.is_null() => s,
+                    _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+                };
+
+                let payload = core::slice::from_raw_parts(
+                    copy_data.lpData as *const u16,
+                    copy_data.cbData as usize / mem::size_of::<u16>(),
+                );
+                let url: AzString = String::from_utf16_lossy(payload).into();
+
+                if IsIconic(hwnd) != 0 {
+                    ShowWindow(hwnd, SW_RESTORE);
+                }
+
+                match ACTIVATION_URL_HANDLER.with(|h| h.get()) {
+                    Some(f) => {
+                        (f)(hwnd, url);
+                        TRUE as LRESULT
+                    },
+                    None => 0,
+                }
+            },
+            WM_SETCURSOR => {
+                use winapi::um::winuser::{HTCLIENT, HTERROR};
+                // only set the cursor for the client area, the frame / resize borders
+                // use the default cursors (a disabled busy window reports HTERROR)
+                let hit_test = (lparam & 0xFFFF) as u16 as i16 as LRESULT;
+                let cursor = match app_borrow.windows.get(&hwnd_key) {
+                    Some(w) if hit_test == HTCLIENT || (hit_test == HTERROR && w.busy.is_some()) => {
+                        Some(w.get_current_cursor())
+                    },
+                    _ => None,
+                };
+                mem::drop(app_borrow);
+                match cursor {
+                    Some(c) => {
+                        winapi::um::winuser::SetCursor(c);
+                        TRUE as LRESULT
+                    },
+                    None => DefWindowProcW(hwnd, msg, wparam, lparam),
+                }
+            },
+            WM_GETDLGCODE => {
+                let dialog_code = match app_borrow.windows.get(&hwnd_key) {
+                    Some(current_window) => current_window.get_dialog_code((lparam as *const MSG).as_ref()),
+                    None => 0,
+                };
+                mem::drop(app_borrow);
+                dialog_code
+            },
+            WM_APPCOMMAND => {
+                mem::drop(app_borrow);
+                let command = AppCommand::from_lparam(lparam);
+                let handled = APP_COMMAND_HANDLER.with(|h| h.get())
+                    .map(|f| (f)(hwnd, command))
+                    .unwrap_or(false);
+                if handled {
+                    TRUE as LRESULT
+                } else {
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
             _ => {
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                // handler is invoked without holding the borrow
+                // so that it can freely send messages to the window
+                match get_custom_message_handler(msg).and_then(|f| (f)(hwnd, wparam, lparam)) {
+                    Some(result) => result,
+                    None => DefWindowProcW(hwnd, msg, wparam, lparam),
+                }
             }
         };
 
+        dispatch_render_errors(hwnd, &shared_application_data.inner);
+
         // TODO: performance benchmark here!
 
         r
@@ -4027,6 +12218,8 @@ fn process_event(
         )
     });
 
+    window.key_down_handled = callback_result.key_down_handled;
+
     return process_callback_results(
         callback_result,
         window,
@@ -4059,6 +12252,9 @@ fn process_timer(
             hinstance: hinstance as *mut _,
         });
 
+        // WM_TIMER messages are coalesced by Windows and may arrive late or early,
+        // so the number of elapsed intervals is computed from the monotonic clock
+        // (and the missed tick policy of the timer) instead of counting messages
         let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
         window.internal.run_single_timer(
             timer_id,
@@ -4085,6 +12281,57 @@ fn process_timer(
     );
 }
 
+#[must_use]
+fn process_first_frame_callback(
+    hinstance: HINSTANCE,
+    data: &mut RefAny,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    let mut callback = match window.first_frame_callback.take() {
+        Some(s) => s,
+        None => return ProcessEventResult::DoNothing,
+    };
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.invoke_single_callback(
+            &mut callback,
+            data,
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows
+    );
+}
+
 #[must_use]
 fn process_threads(
     hinstance: HINSTANCE,
@@ -4211,6 +12458,11 @@ fn process_callback_results(
         &window.internal.current_window_state
     );
 
+    let previous_cursor = window.internal.previous_window_state.as_ref().map(|p| p.mouse_state.mouse_cursor_type);
+    if previous_cursor != Some(window.internal.current_window_state.mouse_state.mouse_cursor_type) {
+        window.update_cursor();
+    }
+
     let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(
         &window.internal.previous_window_state
     );
@@ -4367,6 +12619,228 @@ fn synchronize_window_state_with_os(
     current_state: &FullWindowState
 ) {
     // TODO: window.set_title
+
+    let corner_preference = current_state.platform_specific_options.windows_options.corner_preference;
+    let previous_corner_preference = previous_state
+        .map(|p| p.platform_specific_options.windows_options.corner_preference)
+        .unwrap_or_default();
+
+    if corner_preference != previous_corner_preference {
+        if let Some(dwm) = DwmFunctions::initialize() {
+            dwm.set_corner_preference(window, corner_preference);
+        }
+    }
+
+    if previous_state.map(|p| &p.text_caret) != Some(&current_state.text_caret) {
+        update_text_caret(window, current_state);
+    }
+
+    // switched to RedrawPolicy::Continuous: start the render loop of the window
+    let previous_redraw_policy = previous_state.map(|p| p.redraw_policy).unwrap_or_default();
+    if current_state.redraw_policy == RedrawPolicy::Continuous && previous_redraw_policy != RedrawPolicy::Continuous {
+        use winapi::um::winuser::InvalidateRect;
+        unsafe { InvalidateRect(window, ptr::null(), 0) };
+    }
+
+    // minimized / maximized / restored from a callback
+    let previous_frame = previous_state.map(|p| p.flags.frame);
+    if previous_frame.is_some() && previous_frame != Some(current_state.flags.frame) {
+        set_window_frame(
+            window,
+            current_state.flags.frame,
+            current_state.platform_specific_options.windows_options.animate_frame_changes,
+        );
+    }
+
+    let previous_decorations = previous_state.map(|p| p.flags.has_decorations).unwrap_or(true);
+    if current_state.flags.has_decorations != previous_decorations {
+        set_window_decorations(window, current_state.flags.has_decorations);
+    }
+
+    let borderless_shadow = current_state.platform_specific_options.windows_options.borderless_shadow;
+    let previous_borderless_shadow = previous_state
+        .map(|p| p.platform_specific_options.windows_options.borderless_shadow)
+        .unwrap_or(true);
+    if current_state.flags.has_decorations != previous_decorations || borderless_shadow != previous_borderless_shadow {
+        set_borderless_shadow(window, borderless_shadow);
+    }
+
+    let previous_shown_in_taskbar = previous_state.map(|p| p.flags.is_shown_in_taskbar).unwrap_or(true);
+    if current_state.flags.is_shown_in_taskbar != previous_shown_in_taskbar {
+        set_shown_in_taskbar(window, current_state.flags.is_shown_in_taskbar);
+    }
+
+    let previous_content_scale = previous_state.map(|p| p.size.content_scale).unwrap_or(1.0);
+    let previous_fixed_logical_size = previous_state.map(|p| p.size.fixed_logical_size).unwrap_or_default();
+    if current_state.size.content_scale != previous_content_scale ||
+       current_state.size.fixed_logical_size != previous_fixed_logical_size {
+        request_relayout(window, current_state.flags.frame);
+    }
+}
+
+// Re-sends a WM_SIZE with the current client size, so that the window is
+// laid out and rasterized again (i.e. after the content scale changed)
+fn request_relayout(hwnd: HWND, frame: WindowFrame) {
+
+    use winapi::um::winuser::{GetClientRect, PostMessageW, WM_SIZE, SIZE_MAXIMIZED, SIZE_RESTORED};
+    use winapi::shared::minwindef::MAKELONG;
+
+    let mut rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetClientRect(hwnd, &mut rect) } == 0 {
+        return;
+    }
+
+    let size_type = match frame {
+        WindowFrame::Maximized => SIZE_MAXIMIZED,
+        _ => SIZE_RESTORED,
+    };
+
+    let lparam = MAKELONG(rect.width() as u16, rect.height() as u16);
+    unsafe { PostMessageW(hwnd, WM_SIZE, size_type, lparam as LPARAM); }
+}
+
+// Resolves the WindowCreateOptions::initial_focus against the first layout, applies
+// the :focus styles and scrolls the node into view, returns the newly focused node
+fn apply_initial_focus(
+    internal: &mut WindowInternal,
+    initial_focus: &FocusTarget,
+    image_cache: &ImageCache,
+) -> Option<DomNodeId> {
+
+    use azul_core::window_state::StyleAndLayoutChanges;
+
+    let new_focus = initial_focus.resolve(&internal.layout_results, None).ok()??;
+
+    let nodes_to_check = NodesToCheck::empty(
+        internal.current_window_state.mouse_state.mouse_down(),
+        None,
+    );
+
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut internal.layout_results,
+        image_cache,
+        &mut internal.renderer_resources,
+        internal.current_window_state.size.get_layout_size(),
+        &internal.document_id,
+        None,
+        None,
+        &Some(Some(new_focus)),
+        azul_layout::do_the_relayout,
+    );
+
+    if let Some(focus_change) = style_layout_changes.focus_change {
+        internal.current_window_state.focused_node = focus_change.new;
+    }
+
+    internal.scroll_node_into_view(new_focus);
+    internal.current_window_state.focused_node
+}
+
+/// Size of the invisible resize borders / drop shadow that Windows adds around
+/// the visible frame of a window, in logical units
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct WindowFrameInsets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+/// Computes the difference between the window rect (`GetWindowRect`) and the visible
+/// frame bounds (`DWMWA_EXTENDED_FRAME_BOUNDS`), so that custom window chrome can
+/// align its content with the visible edges of the window. Returns `None` if the
+/// DWM is not available (in which case there are no invisible borders).
+pub fn get_window_frame_insets(hwnd: HWND) -> Option<WindowFrameInsets> {
+
+    use winapi::um::winuser::GetWindowRect;
+
+    let dwm = DwmFunctions::initialize()?;
+    let frame_bounds = dwm.get_extended_frame_bounds(hwnd)?;
+
+    let mut window_rect: RECT = unsafe { mem::zeroed() };
+    if unsafe { GetWindowRect(hwnd, &mut window_rect) } == 0 {
+        return None;
+    }
+
+    let dpi = unsafe { self::dpi::DpiFunctions::init().hwnd_dpi(hwnd) };
+    let hidpi_factor = self::dpi::dpi_to_scale_factor(dpi);
+
+    Some(WindowFrameInsets {
+        left: (frame_bounds.left - window_rect.left).max(0) as f32 / hidpi_factor,
+        top: (frame_bounds.top - window_rect.top).max(0) as f32 / hidpi_factor,
+        right: (window_rect.right - frame_bounds.right).max(0) as f32 / hidpi_factor,
+        bottom: (window_rect.bottom - frame_bounds.bottom).max(0) as f32 / hidpi_factor,
+    })
+}
+
+/// Converts the caret and selection rectangles of a `TextCaret` (logical, relative to
+/// the client area) to physical screen coordinates, as required by UI Automation
+pub fn get_text_caret_screen_rects(hwnd: HWND, caret: &TextCaret, hidpi_factor: f32) -> (Option<RECT>, Vec<RECT>) {
+
+    use winapi::um::winuser::ClientToScreen;
+
+    let mut origin = POINT { x: 0, y: 0 };
+    unsafe { ClientToScreen(hwnd, &mut origin) };
+
+    let to_screen = |r: &LogicalRect| RECT {
+        left: origin.x + (r.origin.x * hidpi_factor).round() as i32,
+        top: origin.y + (r.origin.y * hidpi_factor).round() as i32,
+        right: origin.x + ((r.origin.x + r.size.width) * hidpi_factor).round() as i32,
+        bottom: origin.y + ((r.origin.y + r.size.height) * hidpi_factor).round() as i32,
+    };
+
+    let caret_rect = caret.caret_rect.as_option().map(|r| to_screen(r));
+    let selection_rects = caret.selection_rects.as_ref().iter().map(|r| to_screen(r)).collect();
+
+    (caret_rect, selection_rects)
+}
+
+// Moves the IME composition window and the (invisible) system caret to the caret of
+// the focused text input: screen readers and magnifiers follow the system caret
+fn update_text_caret(hwnd: HWND, state: &FullWindowState) {
+
+    use winapi::um::{
+        imm::{ImmGetContext, ImmReleaseContext, ImmSetCompositionWindow, COMPOSITIONFORM, CFS_RECT},
+        winuser::{
+            CreateCaret, DestroyCaret, SetCaretPos, NotifyWinEvent,
+            EVENT_OBJECT_LOCATIONCHANGE, OBJID_CARET, CHILDID_SELF,
+        },
+    };
+
+    let caret = match state.text_caret.caret_rect.into_option() {
+        Some(s) => s,
+        None => {
+            unsafe { DestroyCaret() };
+            return;
+        }
+    };
+
+    let hidpi_factor = state.size.get_hidpi_factor();
+    let area = RECT {
+        left: (caret.origin.x * hidpi_factor).round() as i32,
+        top: (caret.origin.y * hidpi_factor).round() as i32,
+        right: ((caret.origin.x + caret.size.width) * hidpi_factor).round() as i32,
+        bottom: ((caret.origin.y + caret.size.height) * hidpi_factor).round() as i32,
+    };
+
+    unsafe {
+        // the caret is never shown (ShowCaret), azul draws its own cursor
+        CreateCaret(hwnd, ptr::null_mut(), area.width().max(1) as i32, area.height().max(1) as i32);
+        SetCaretPos(area.left, area.top);
+        NotifyWinEvent(EVENT_OBJECT_LOCATIONCHANGE, hwnd, OBJID_CARET, CHILDID_SELF as i32);
+
+        let himc = ImmGetContext(hwnd);
+        if !himc.is_null() {
+            let mut composition_form = COMPOSITIONFORM {
+                dwStyle: CFS_RECT,
+                ptCurrentPos: POINT { x: area.left, y: area.top },
+                rcArea: area,
+            };
+            ImmSetCompositionWindow(himc, &mut composition_form);
+            ImmReleaseContext(hwnd, himc);
+        }
+    }
 }
 
 fn send_resource_updates(
@@ -4376,6 +12850,124 @@ fn send_resource_updates(
 
 }
 
+// Sets the cursor to one of the builtin IDC_* cursors (None = hide the cursor)
+fn load_cursor(cursor: Option<MouseCursorType>) -> HCURSOR {
+    use winapi::um::winuser::LoadCursorW;
+    match cursor {
+        Some(c) => unsafe { LoadCursorW(ptr::null_mut(), win32_translate_cursor(c)) },
+        None => ptr::null_mut(),
+    }
+}
+
+// Creates a cursor from an RGBA8 / BGRA8 image, scaled by the hidpi factor
+// (nearest-neighbour). The 32-bit color bitmap carries the (straight) alpha,
+// the monochrome mask is required by CreateIconIndirect but unused.
+fn create_cursor_from_image(image: &RawImage, hotspot: LogicalPosition, hidpi_factor: f32) -> Option<HCURSOR> {
+
+    use winapi::um::{
+        wingdi::{
+            CreateBitmap, CreateDIBSection, DeleteObject,
+            BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+        },
+        winuser::{CreateIconIndirect, ICONINFO},
+    };
+    use winapi::shared::minwindef::FALSE;
+
+    let is_bgra = match image.data_format {
+        RawImageFormat::BGRA8 => true,
+        RawImageFormat::RGBA8 => false,
+        _ => return None,
+    };
+
+    let pixels = match &image.pixels {
+        RawImageData::U8(u) => u.as_ref(),
+        _ => return None,
+    };
+
+    let (width, height) = (image.width as i32, image.height as i32);
+    if width == 0 || height == 0 || pixels.len() < image.width * image.height * 4 {
+        return None;
+    }
+
+    let cursor_width = (libm::roundf(width as f32 * hidpi_factor) as i32).max(1);
+    let cursor_height = (libm::roundf(height as f32 * hidpi_factor) as i32).max(1);
+
+    let mut bitmap_info: BITMAPINFO = unsafe { mem::zeroed() };
+    bitmap_info.bmiHeader.biSize = mem::size_of::<BITMAPINFOHEADER>() as u32;
+    bitmap_info.bmiHeader.biWidth = cursor_width;
+    bitmap_info.bmiHeader.biHeight = -cursor_height; // top-down DIB
+    bitmap_info.bmiHeader.biPlanes = 1;
+    bitmap_info.bmiHeader.biBitCount = 32;
+    bitmap_info.bmiHeader.biCompression = BI_RGB;
+
+    let mut bits: *mut c_void = ptr::null_mut();
+    let color_bitmap = unsafe {
+        CreateDIBSection(ptr::null_mut(), &bitmap_info, DIB_RGB_COLORS, &mut bits, ptr::null_mut(), 0)
+    };
+
+    if color_bitmap.is_null() || bits.is_null() {
+        return None;
+    }
+
+    let dst = unsafe {
+        core::slice::from_raw_parts_mut(bits as *mut u8, (cursor_width * cursor_height * 4) as usize)
+    };
+
+    for y in 0..cursor_height {
+        let src_y = y * height / cursor_height;
+        for x in 0..cursor_width {
+            let src_x = x * width / cursor_width;
+            let src = ((src_y * width + src_x) * 4) as usize;
+            let (mut b, mut g, mut r, a) = if is_bgra {
+                (pixels[src], pixels[src + 1], pixels[src + 2], pixels[src + 3])
+            } else {
+                (pixels[src + 2], pixels[src + 1], pixels[src], pixels[src + 3])
+            };
+            // icons expect straight alpha
+            if image.premultiplied_alpha && a != 0 && a != 255 {
+                let unpremultiply = |c: u8| ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8;
+                b = unpremultiply(b);
+                g = unpremultiply(g);
+                r = unpremultiply(r);
+            }
+            let dst_idx = ((y * cursor_width + x) * 4) as usize;
+            dst[dst_idx..dst_idx + 4].copy_from_slice(&[b, g, r, a]);
+        }
+    }
+
+    // monochrome rows are WORD-aligned, an all-zero AND mask leaves the color bitmap visible
+    let mask_stride = (((cursor_width + 15) / 16) * 2) as usize;
+    let mask_bits = vec![0_u8; mask_stride * cursor_height as usize];
+    let mask_bitmap = unsafe {
+        CreateBitmap(cursor_width, cursor_height, 1, 1, mask_bits.as_ptr() as *const c_void)
+    };
+
+    if mask_bitmap.is_null() {
+        unsafe { DeleteObject(color_bitmap as *mut _); }
+        return None;
+    }
+
+    let clamp_hotspot = |v: f32, max: i32| (libm::roundf(v * hidpi_factor) as i32).max(0).min(max - 1) as u32;
+
+    let mut icon_info = ICONINFO {
+        fIcon: FALSE,
+        xHotspot: clamp_hotspot(hotspot.x, cursor_width),
+        yHotspot: clamp_hotspot(hotspot.y, cursor_height),
+        hbmMask: mask_bitmap,
+        hbmColor: color_bitmap,
+    };
+
+    let hcursor = unsafe { CreateIconIndirect(&mut icon_info) };
+
+    // CreateIconIndirect copies the bitmaps
+    unsafe {
+        DeleteObject(mask_bitmap as *mut _);
+        DeleteObject(color_bitmap as *mut _);
+    }
+
+    if hcursor.is_null() { None } else { Some(hcursor) }
+}
+
 // translates MouseCursorType to a builtin IDC_* value
 // note: taken from https://github.com/rust-windowing/winit/blob/1c4d6e7613c3a3870cecb4cfa0eecc97409d45ff/src/platform_impl/windows/util.rs#L200
 const fn win32_translate_cursor(input: MouseCursorType) -> *const wchar_t {
@@ -4420,4 +13012,110 @@ const fn win32_translate_cursor(input: MouseCursorType) -> *const wchar_t {
         Help => winuser::IDC_HELP,
         _ => winuser::IDC_ARROW,
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const TEST_MESSAGE: u32 = WM_APP + 100;
+
+    fn clear_deferred_messages() {
+        DEFERRED_MESSAGES.with(|d| d.borrow_mut().clear());
+        REPLAY_PENDING.with(|r| r.set(false));
+    }
+
+    #[test]
+    fn test_deferred_messages_are_replayed_in_order() {
+        clear_deferred_messages();
+
+        defer_message(ptr::null_mut(), TEST_MESSAGE, 1, 0);
+        defer_message(ptr::null_mut(), TEST_MESSAGE, 2, 0);
+        assert!(REPLAY_PENDING.with(|r| r.get()));
+
+        // messages deferred during the replay are replayed after the ones already queued
+        assert_eq!(pop_deferred_message().map(|m| m.wparam), Some(1));
+        defer_message(ptr::null_mut(), TEST_MESSAGE, 3, 0);
+        assert_eq!(pop_deferred_message().map(|m| m.wparam), Some(2));
+        assert_eq!(pop_deferred_message().map(|m| m.wparam), Some(3));
+        assert!(pop_deferred_message().is_none());
+    }
+
+    #[test]
+    fn test_replay_stays_pending_while_borrowed() {
+        clear_deferred_messages();
+
+        defer_message(ptr::null_mut(), TEST_MESSAGE, 1, 0);
+        postpone_deferred_message_replay();
+
+        // neither the request nor the queued messages are dropped
+        assert!(REPLAY_PENDING.with(|r| r.get()));
+        assert!(has_deferred_messages());
+
+        // the timer re-posts the request for the first queued message
+        unsafe { replay_deferred_messages_timer_proc(ptr::null_mut(), 0, 0, 0); }
+        assert!(REPLAY_PENDING.with(|r| r.get()));
+
+        // nothing left to replay: the next deferred message posts a new request
+        clear_deferred_messages();
+        REPLAY_PENDING.with(|r| r.set(true));
+        unsafe { replay_deferred_messages_timer_proc(ptr::null_mut(), 0, 0, 0); }
+        assert!(!REPLAY_PENDING.with(|r| r.get()));
+    }
+
+    fn recorded(time_us: u64, window_id: usize, event: SyntheticEvent) -> RecordedInputEvent {
+        RecordedInputEvent { time: Duration::from_micros(time_us), window_id, event }
+    }
+
+    #[test]
+    fn test_input_log_round_trip() {
+        let events = vec![
+            recorded(0, 0, SyntheticEvent::MouseMove(LogicalPosition::new(10.5, -3.0))),
+            recorded(1_000, 0, SyntheticEvent::MouseDown(SyntheticMouseButton::Left)),
+            recorded(2_000, 0, SyntheticEvent::MouseUp(SyntheticMouseButton::Left)),
+            recorded(3_000, 1, SyntheticEvent::MouseDown(SyntheticMouseButton::Right)),
+            recorded(4_000, 1, SyntheticEvent::MouseUp(SyntheticMouseButton::Middle)),
+            recorded(5_000, 1, SyntheticEvent::Scroll(-1.5)),
+            recorded(6_000, 2, SyntheticEvent::KeyDown(0x41)),
+            recorded(7_000, 2, SyntheticEvent::KeyUp(0x41)),
+            recorded(8_000, 2, SyntheticEvent::Char('ä')),
+            recorded(9_000, 2, SyntheticEvent::Char('😀')),
+        ];
+
+        let log = write_input_log(&events);
+        assert!(log.starts_with("azul-input-log 1\n"));
+        assert_eq!(parse_input_log(&log), Ok(events));
+    }
+
+    #[test]
+    fn test_input_log_ignores_empty_lines() {
+        let log = "azul-input-log 1\n\n1000 0 keydown 65\n   \n";
+        assert_eq!(parse_input_log(log), Ok(vec![recorded(1_000, 0, SyntheticEvent::KeyDown(65))]));
+    }
+
+    #[test]
+    fn test_input_log_missing_header() {
+        assert_eq!(parse_input_log(""), Err(InputLogError::MissingHeader));
+        assert_eq!(parse_input_log("1000 0 keydown 65\n"), Err(InputLogError::MissingHeader));
+        assert_eq!(parse_input_log("azul-input-log one\n"), Err(InputLogError::MissingHeader));
+    }
+
+    #[test]
+    fn test_input_log_unsupported_version() {
+        let log = format!("azul-input-log {}\n", INPUT_LOG_VERSION + 1);
+        assert_eq!(parse_input_log(&log), Err(InputLogError::UnsupportedVersion(INPUT_LOG_VERSION + 1)));
+    }
+
+    #[test]
+    fn test_input_log_invalid_line_number() {
+        // line numbers are 1-based and count the header and empty lines
+        let log = "azul-input-log 1\n1000 0 keydown 65\n\n2000 0 mousedown left-ish\n";
+        assert_eq!(parse_input_log(log), Err(InputLogError::InvalidLine(4)));
+
+        let log = "azul-input-log 1\n1000 0 teleport 1 2\n";
+        assert_eq!(parse_input_log(log), Err(InputLogError::InvalidLine(2)));
+
+        let log = "azul-input-log 1\n1000 0 mousemove 1\n";
+        assert_eq!(parse_input_log(log), Err(InputLogError::InvalidLine(2)));
+    }
+}
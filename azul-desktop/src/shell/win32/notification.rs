@@ -0,0 +1,660 @@
+//! Native notifications: toasts via the WinRT `ToastNotificationManager`
+//! (Windows 10+), with a fallback to a balloon tip on a temporary tray icon
+//!
+//! Toasts require an explicit AppUserModelID (see `AppConfig::app_user_model_id`),
+//! otherwise the balloon fallback is used. Clicks on action buttons arrive on a
+//! WinRT worker thread and are posted to the window as `AZ_NOTIFICATION_ACTION`,
+//! so that the callbacks run on the UI thread. Dismissed (or expired) and failed
+//! toasts are posted the same way, with `NOTIFICATION_CLOSED` as the action.
+
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+use azul_core::window::MenuCallback;
+use azul_css::AzString;
+use core::{
+    cell::{Cell, RefCell},
+    ffi::c_void,
+    mem, ptr,
+    sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
+};
+use winapi::{
+    shared::{
+        guiddef::{GUID, REFIID, IsEqualGUID},
+        minwindef::{LPARAM, WPARAM},
+        ntdef::HRESULT,
+        windef::HWND,
+        winerror::{E_NOINTERFACE, E_POINTER, S_OK, SUCCEEDED},
+    },
+    um::combaseapi::CoTaskMemFree,
+};
+use super::{encode_wide, load_shell32_function, AZ_NOTIFICATION_ACTION, AZ_NOTIFICATION_TRAY_ICON};
+
+/// Button on a notification, the callback is invoked (on the window
+/// the notification was sent from) when the button is clicked
+#[derive(Debug, Clone)]
+pub struct NotificationAction {
+    pub label: AzString,
+    pub callback: MenuCallback,
+}
+
+/// How a notification was delivered
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NotificationKind {
+    /// Windows 10+ toast notification (with action buttons)
+    Toast,
+    /// Balloon tip on a temporary tray icon: there are no buttons,
+    /// clicking the balloon invokes the first action
+    BalloonTip,
+}
+
+// Pending notification: actions + (for toasts) the COM object, which has
+// to stay alive until the toast is activated, otherwise no event is fired
+struct PendingNotification {
+    hwnd: usize,
+    actions: Vec<MenuCallback>,
+    toast: *mut c_void,
+}
+
+impl Drop for PendingNotification {
+    fn drop(&mut self) {
+        if !self.toast.is_null() {
+            unsafe { com_release(self.toast); }
+        }
+    }
+}
+
+thread_local! {
+    static NEXT_NOTIFICATION_ID: Cell<usize> = Cell::new(1);
+    static PENDING_NOTIFICATIONS: RefCell<BTreeMap<usize, PendingNotification>> = RefCell::new(BTreeMap::new());
+}
+
+/// Shows a notification with the given title, body and action buttons,
+/// returns `None` if neither a toast nor a balloon tip could be shown
+pub fn show_notification(
+    hwnd: HWND,
+    title: &str,
+    body: &str,
+    actions: Vec<NotificationAction>,
+) -> Option<NotificationKind> {
+
+    let id = NEXT_NOTIFICATION_ID.with(|n| {
+        let id = n.get();
+        n.set(id + 1);
+        id
+    });
+
+    let labels = actions.iter().map(|a| a.label.as_str()).collect::<Vec<_>>();
+
+    let (kind, toast) = match show_toast(hwnd, id, title, body, &labels) {
+        Some(toast) => (NotificationKind::Toast, toast),
+        None => {
+            if !show_balloon_tip(hwnd, id, title, body) {
+                return None;
+            }
+            (NotificationKind::BalloonTip, ptr::null_mut())
+        }
+    };
+
+    PENDING_NOTIFICATIONS.with(|p| {
+        p.borrow_mut().insert(id, PendingNotification {
+            hwnd: hwnd as usize,
+            actions: actions.into_iter().map(|a| a.callback).collect(),
+            toast,
+        });
+    });
+
+    Some(kind)
+}
+
+// Action of the AZ_NOTIFICATION_ACTION message if the toast was dismissed,
+// expired or failed: only removes the notification, no callback is invoked
+const NOTIFICATION_CLOSED: usize = usize::MAX;
+
+// Removes the notification and returns the callback of the clicked action
+// (None for NOTIFICATION_CLOSED)
+pub(crate) fn take_notification_action(id: usize, action: usize) -> Option<MenuCallback> {
+    PENDING_NOTIFICATIONS.with(|p| {
+        p.borrow_mut().remove(&id).and_then(|mut n| {
+            if action < n.actions.len() {
+                Some(n.actions.swap_remove(action))
+            } else {
+                None
+            }
+        })
+    })
+}
+
+// Handles the callback message of a balloon tip tray icon (AZ_NOTIFICATION_TRAY_ICON):
+// returns the callback to invoke if the balloon was clicked
+pub(crate) fn handle_tray_icon_message(hwnd: HWND, wparam: WPARAM, lparam: LPARAM) -> Option<MenuCallback> {
+
+    use winapi::um::shellapi::{NIN_BALLOONHIDE, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK};
+
+    let id = wparam;
+    match lparam as u32 {
+        NIN_BALLOONUSERCLICK => {
+            remove_tray_icon(hwnd, id);
+            take_notification_action(id, 0)
+        },
+        NIN_BALLOONTIMEOUT | NIN_BALLOONHIDE => {
+            remove_tray_icon(hwnd, id);
+            PENDING_NOTIFICATIONS.with(|p| { p.borrow_mut().remove(&id); });
+            None
+        },
+        _ => None,
+    }
+}
+
+// Called on WM_DESTROY: the callbacks can't be invoked anymore
+pub(crate) fn remove_window_notifications(hwnd: HWND) {
+    let removed = PENDING_NOTIFICATIONS.with(|p| {
+        let mut p = p.borrow_mut();
+        let ids = p.iter()
+            .filter(|(_, n)| n.hwnd == hwnd as usize)
+            .map(|(id, n)| (*id, n.toast.is_null()))
+            .collect::<Vec<_>>();
+        for (id, _) in ids.iter() {
+            p.remove(id);
+        }
+        ids
+    });
+
+    for (id, is_balloon_tip) in removed {
+        if is_balloon_tip {
+            remove_tray_icon(hwnd, id);
+        }
+    }
+}
+
+// --- balloon tip fallback
+
+fn show_balloon_tip(hwnd: HWND, id: usize, title: &str, body: &str) -> bool {
+
+    use winapi::um::{
+        shellapi::{
+            Shell_NotifyIconW, NOTIFYICONDATAW, NIM_ADD,
+            NIF_ICON, NIF_INFO, NIF_MESSAGE, NIIF_INFO,
+        },
+        winuser::{LoadIconW, SendMessageW, ICON_SMALL, IDI_APPLICATION, WM_GETICON},
+    };
+
+    let mut data: NOTIFYICONDATAW = unsafe { mem::zeroed() };
+    data.cbSize = mem::size_of::<NOTIFYICONDATAW>() as u32;
+    data.hWnd = hwnd;
+    data.uID = id as u32;
+    data.uFlags = NIF_ICON | NIF_INFO | NIF_MESSAGE;
+    data.uCallbackMessage = AZ_NOTIFICATION_TRAY_ICON;
+    data.dwInfoFlags = NIIF_INFO;
+
+    let window_icon = unsafe { SendMessageW(hwnd, WM_GETICON, ICON_SMALL as usize, 0) };
+    data.hIcon = if window_icon != 0 {
+        window_icon as _
+    } else {
+        unsafe { LoadIconW(ptr::null_mut(), IDI_APPLICATION) }
+    };
+
+    copy_truncated(&mut data.szInfoTitle, title);
+    copy_truncated(&mut data.szInfo, body);
+
+    unsafe { Shell_NotifyIconW(NIM_ADD, &mut data) != 0 }
+}
+
+fn remove_tray_icon(hwnd: HWND, id: usize) {
+
+    use winapi::um::shellapi::{Shell_NotifyIconW, NOTIFYICONDATAW, NIM_DELETE};
+
+    let mut data: NOTIFYICONDATAW = unsafe { mem::zeroed() };
+    data.cbSize = mem::size_of::<NOTIFYICONDATAW>() as u32;
+    data.hWnd = hwnd;
+    data.uID = id as u32;
+    unsafe { Shell_NotifyIconW(NIM_DELETE, &mut data); }
+}
+
+// copies the string into a fixed-size, null-terminated UTF-16 buffer
+fn copy_truncated(target: &mut [u16], source: &str) {
+    let max_len = target.len().saturating_sub(1);
+    let mut len = 0;
+    for (t, s) in target.iter_mut().zip(source.encode_utf16().take(max_len)) {
+        *t = s;
+        len += 1;
+    }
+    if let Some(t) = target.get_mut(len) {
+        *t = 0;
+    }
+}
+
+// --- WinRT toast notifications
+
+type HSTRING = *mut c_void;
+
+const IID_IUNKNOWN: GUID = GUID { Data1: 0x00000000, Data2: 0x0000, Data3: 0x0000, Data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46] };
+const IID_IAGILE_OBJECT: GUID = GUID { Data1: 0x94EA2B94, Data2: 0xE9CC, Data3: 0x49E0, Data4: [0xC0, 0xFF, 0xEE, 0x64, 0xCA, 0x8F, 0x5B, 0x90] };
+const IID_IXML_DOCUMENT: GUID = GUID { Data1: 0xF7F3A506, Data2: 0x1E87, Data3: 0x42D6, Data4: [0xBC, 0xFB, 0xB8, 0xC8, 0x09, 0xFA, 0x54, 0x94] };
+const IID_IXML_DOCUMENT_IO: GUID = GUID { Data1: 0x6CD0E74E, Data2: 0xEE65, Data3: 0x4489, Data4: [0x9E, 0xBF, 0xCA, 0x43, 0xE8, 0x7B, 0xA6, 0x37] };
+const IID_ITOAST_NOTIFICATION_MANAGER_STATICS: GUID = GUID { Data1: 0x50AC103F, Data2: 0xD235, Data3: 0x4598, Data4: [0xBB, 0xEF, 0x98, 0xFE, 0x4D, 0x1A, 0x3A, 0xD4] };
+const IID_ITOAST_NOTIFICATION_FACTORY: GUID = GUID { Data1: 0x04124B20, Data2: 0x82C6, Data3: 0x4229, Data4: [0xB1, 0x09, 0xFD, 0x9E, 0xD4, 0x66, 0x2B, 0x53] };
+const IID_ITOAST_ACTIVATED_EVENT_ARGS: GUID = GUID { Data1: 0xE3BF92F3, Data2: 0xC197, Data3: 0x436F, Data4: [0x82, 0x65, 0x06, 0x25, 0x82, 0x4F, 0x8D, 0xAC] };
+// TypedEventHandler<ToastNotification, IInspectable>
+const IID_TOAST_ACTIVATED_HANDLER: GUID = GUID { Data1: 0xAB54DE2D, Data2: 0x97D9, Data3: 0x5528, Data4: [0xB6, 0xAD, 0x10, 0x5A, 0xFE, 0x15, 0x65, 0x30] };
+// TypedEventHandler<ToastNotification, ToastDismissedEventArgs>
+const IID_TOAST_DISMISSED_HANDLER: GUID = GUID { Data1: 0x61C2402F, Data2: 0x0ED0, Data3: 0x5A18, Data4: [0xAB, 0x69, 0x59, 0xF4, 0xAA, 0x99, 0xA3, 0x68] };
+// TypedEventHandler<ToastNotification, ToastFailedEventArgs>
+const IID_TOAST_FAILED_HANDLER: GUID = GUID { Data1: 0x95E3E803, Data2: 0xC969, Data3: 0x5E3A, Data4: [0x97, 0x53, 0xEA, 0x2A, 0xD2, 0x2A, 0x9A, 0x33] };
+
+// vtable indices (IUnknown: 0 - 2, IInspectable: 3 - 5)
+const QUERY_INTERFACE: usize = 0;
+const RELEASE: usize = 2;
+const XML_DOCUMENT_IO_LOAD_XML: usize = 6;
+const TOAST_NOTIFICATION_MANAGER_CREATE_TOAST_NOTIFIER_WITH_ID: usize = 7;
+const TOAST_NOTIFIER_SHOW: usize = 6;
+const TOAST_NOTIFICATION_FACTORY_CREATE_TOAST_NOTIFICATION: usize = 6;
+const TOAST_NOTIFICATION_ADD_DISMISSED: usize = 9;
+const TOAST_NOTIFICATION_ADD_ACTIVATED: usize = 11;
+const TOAST_NOTIFICATION_ADD_FAILED: usize = 13;
+const TOAST_ACTIVATED_EVENT_ARGS_GET_ARGUMENTS: usize = 6;
+
+type WindowsCreateStringFn = extern "system" fn(*const u16, u32, *mut HSTRING) -> HRESULT;
+type WindowsDeleteStringFn = extern "system" fn(HSTRING) -> HRESULT;
+type WindowsGetStringRawBufferFn = extern "system" fn(HSTRING, *mut u32) -> *const u16;
+type RoActivateInstanceFn = extern "system" fn(HSTRING, *mut *mut c_void) -> HRESULT;
+type RoGetActivationFactoryFn = extern "system" fn(HSTRING, REFIID, *mut *mut c_void) -> HRESULT;
+
+// WinRT functions from combase.dll (Windows 8+)
+#[derive(Copy, Clone)]
+struct WinRtFunctions {
+    WindowsCreateString: WindowsCreateStringFn,
+    WindowsDeleteString: WindowsDeleteStringFn,
+    WindowsGetStringRawBuffer: WindowsGetStringRawBufferFn,
+    RoActivateInstance: RoActivateInstanceFn,
+    RoGetActivationFactory: RoGetActivationFactoryFn,
+}
+
+impl WinRtFunctions {
+    fn load() -> Option<Self> {
+        use winapi::um::libloaderapi::{GetProcAddress, LoadLibraryW};
+
+        let mut dll_name = encode_wide("combase.dll");
+        let combase = unsafe { LoadLibraryW(dll_name.as_mut_ptr()) };
+        if combase.is_null() {
+            return None;
+        }
+
+        let load = |name: &str| {
+            let mut func_name = super::encode_ascii(name);
+            let f = unsafe { GetProcAddress(combase, func_name.as_mut_ptr()) };
+            if f.is_null() { None } else { Some(f as *const c_void) }
+        };
+
+        unsafe {
+            Some(Self {
+                WindowsCreateString: mem::transmute(load("WindowsCreateString")?),
+                WindowsDeleteString: mem::transmute(load("WindowsDeleteString")?),
+                WindowsGetStringRawBuffer: mem::transmute(load("WindowsGetStringRawBuffer")?),
+                RoActivateInstance: mem::transmute(load("RoActivateInstance")?),
+                RoGetActivationFactory: mem::transmute(load("RoGetActivationFactory")?),
+            })
+        }
+    }
+
+    fn create_string(&self, s: &str) -> Option<HString> {
+        let wide = s.encode_utf16().collect::<Vec<u16>>();
+        let mut hstring = ptr::null_mut();
+        if SUCCEEDED((self.WindowsCreateString)(wide.as_ptr(), wide.len() as u32, &mut hstring)) {
+            Some(HString { hstring, delete: self.WindowsDeleteString })
+        } else {
+            None
+        }
+    }
+}
+
+// HSTRING that is deleted on drop
+struct HString {
+    hstring: HSTRING,
+    delete: WindowsDeleteStringFn,
+}
+
+impl Drop for HString {
+    fn drop(&mut self) {
+        if !self.hstring.is_null() {
+            (self.delete)(self.hstring);
+        }
+    }
+}
+
+// COM pointer that is released on drop
+struct ComPtr(*mut c_void);
+
+impl ComPtr {
+    fn query_interface(&self, iid: &GUID) -> Option<ComPtr> {
+        unsafe { com_query_interface(self.0, iid) }
+    }
+
+    // gives up ownership without releasing the pointer
+    fn into_raw(self) -> *mut c_void {
+        let p = self.0;
+        mem::forget(self);
+        p
+    }
+}
+
+impl Drop for ComPtr {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { com_release(self.0); }
+        }
+    }
+}
+
+unsafe fn vtable_entry(object: *mut c_void, index: usize) -> *const c_void {
+    let vtable = *(object as *const *const *const c_void);
+    *vtable.add(index)
+}
+
+unsafe fn com_query_interface(object: *mut c_void, iid: &GUID) -> Option<ComPtr> {
+    type QueryInterfaceFn = unsafe extern "system" fn(*mut c_void, REFIID, *mut *mut c_void) -> HRESULT;
+    let query_interface: QueryInterfaceFn = mem::transmute(vtable_entry(object, QUERY_INTERFACE));
+    let mut out = ptr::null_mut();
+    let hr = query_interface(object, iid, &mut out);
+    if SUCCEEDED(hr) && !out.is_null() { Some(ComPtr(out)) } else { None }
+}
+
+unsafe fn com_release(object: *mut c_void) {
+    type ReleaseFn = unsafe extern "system" fn(*mut c_void) -> u32;
+    let release: ReleaseFn = mem::transmute(vtable_entry(object, RELEASE));
+    release(object);
+}
+
+// Returns the explicit AppUserModelID of the process (required for toasts)
+fn get_process_app_user_model_id() -> Option<String> {
+
+    type GetCurrentProcessExplicitAppUserModelIDFn = extern "system" fn(*mut *mut u16) -> HRESULT;
+
+    let function = load_shell32_function("GetCurrentProcessExplicitAppUserModelID")?;
+    let function: GetCurrentProcessExplicitAppUserModelIDFn = unsafe { mem::transmute(function) };
+
+    let mut id = ptr::null_mut();
+    if !SUCCEEDED((function)(&mut id)) || id.is_null() {
+        return None;
+    }
+
+    let id_string = unsafe {
+        let len = (0..).take_while(|i| *id.add(*i) != 0).count();
+        String::from_utf16_lossy(core::slice::from_raw_parts(id, len))
+    };
+    unsafe { CoTaskMemFree(id as *mut _); }
+
+    Some(id_string)
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// arguments of the action buttons: "azul-notification={id}:{action}"
+const ACTION_ARGUMENTS_PREFIX: &str = "azul-notification=";
+
+fn get_toast_xml(id: usize, title: &str, body: &str, actions: &[&str]) -> String {
+    let mut xml = format!(
+        "<toast launch=\"{}{}\"><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text></binding></visual>",
+        ACTION_ARGUMENTS_PREFIX, id, escape_xml(title), escape_xml(body),
+    );
+
+    if !actions.is_empty() {
+        xml.push_str("<actions>");
+        for (action_id, label) in actions.iter().enumerate() {
+            xml.push_str(&format!(
+                "<action content=\"{}\" arguments=\"{}{}:{}\" activationType=\"foreground\"/>",
+                escape_xml(label), ACTION_ARGUMENTS_PREFIX, id, action_id,
+            ));
+        }
+        xml.push_str("</actions>");
+    }
+
+    xml.push_str("</toast>");
+    xml
+}
+
+// returns the (unreleased) IToastNotification on success
+fn show_toast(hwnd: HWND, id: usize, title: &str, body: &str, actions: &[&str]) -> Option<*mut c_void> {
+
+    let winrt = WinRtFunctions::load()?;
+    let app_user_model_id = get_process_app_user_model_id()?;
+
+    let _com = super::dialogs::ComInitialized::new()?;
+
+    unsafe {
+
+        // XmlDocument.LoadXml(...)
+        let class_name = winrt.create_string("Windows.Data.Xml.Dom.XmlDocument")?;
+        let mut xml_document = ptr::null_mut();
+        if !SUCCEEDED((winrt.RoActivateInstance)(class_name.hstring, &mut xml_document)) || xml_document.is_null() {
+            return None;
+        }
+        let xml_document = ComPtr(xml_document);
+        let xml_document_io = xml_document.query_interface(&IID_IXML_DOCUMENT_IO)?;
+
+        let xml = winrt.create_string(&get_toast_xml(id, title, body, actions))?;
+        type LoadXmlFn = unsafe extern "system" fn(*mut c_void, HSTRING) -> HRESULT;
+        let load_xml: LoadXmlFn = mem::transmute(vtable_entry(xml_document_io.0, XML_DOCUMENT_IO_LOAD_XML));
+        if !SUCCEEDED(load_xml(xml_document_io.0, xml.hstring)) {
+            return None;
+        }
+        let xml_document = xml_document.query_interface(&IID_IXML_DOCUMENT)?;
+
+        // ToastNotificationManager.CreateToastNotifier(app_user_model_id)
+        let class_name = winrt.create_string("Windows.UI.Notifications.ToastNotificationManager")?;
+        let mut manager = ptr::null_mut();
+        if !SUCCEEDED((winrt.RoGetActivationFactory)(class_name.hstring, &IID_ITOAST_NOTIFICATION_MANAGER_STATICS, &mut manager)) || manager.is_null() {
+            return None;
+        }
+        let manager = ComPtr(manager);
+
+        let app_user_model_id = winrt.create_string(&app_user_model_id)?;
+        type CreateToastNotifierWithIdFn = unsafe extern "system" fn(*mut c_void, HSTRING, *mut *mut c_void) -> HRESULT;
+        let create_toast_notifier: CreateToastNotifierWithIdFn = mem::transmute(vtable_entry(manager.0, TOAST_NOTIFICATION_MANAGER_CREATE_TOAST_NOTIFIER_WITH_ID));
+        let mut notifier = ptr::null_mut();
+        if !SUCCEEDED(create_toast_notifier(manager.0, app_user_model_id.hstring, &mut notifier)) || notifier.is_null() {
+            return None;
+        }
+        let notifier = ComPtr(notifier);
+
+        // new ToastNotification(xml_document)
+        let class_name = winrt.create_string("Windows.UI.Notifications.ToastNotification")?;
+        let mut factory = ptr::null_mut();
+        if !SUCCEEDED((winrt.RoGetActivationFactory)(class_name.hstring, &IID_ITOAST_NOTIFICATION_FACTORY, &mut factory)) || factory.is_null() {
+            return None;
+        }
+        let factory = ComPtr(factory);
+
+        type CreateToastNotificationFn = unsafe extern "system" fn(*mut c_void, *mut c_void, *mut *mut c_void) -> HRESULT;
+        let create_toast_notification: CreateToastNotificationFn = mem::transmute(vtable_entry(factory.0, TOAST_NOTIFICATION_FACTORY_CREATE_TOAST_NOTIFICATION));
+        let mut toast = ptr::null_mut();
+        if !SUCCEEDED(create_toast_notification(factory.0, xml_document.0, &mut toast)) || toast.is_null() {
+            return None;
+        }
+        let toast = ComPtr(toast);
+
+        // toast.Activated += handler, toast.Dismissed += handler, toast.Failed += handler
+        for (event, add_event_index) in [
+            (ToastEvent::Activated, TOAST_NOTIFICATION_ADD_ACTIVATED),
+            (ToastEvent::Dismissed, TOAST_NOTIFICATION_ADD_DISMISSED),
+            (ToastEvent::Failed, TOAST_NOTIFICATION_ADD_FAILED),
+        ] {
+            let handler = ToastEventHandler::new(hwnd, id, event, winrt);
+            type AddEventFn = unsafe extern "system" fn(*mut c_void, *mut c_void, *mut i64) -> HRESULT;
+            let add_event: AddEventFn = mem::transmute(vtable_entry(toast.0, add_event_index));
+            let mut token = 0_i64;
+            let hr = add_event(toast.0, handler as *mut c_void, &mut token);
+            com_release(handler as *mut c_void);
+            if !SUCCEEDED(hr) {
+                return None;
+            }
+        }
+
+        // notifier.Show(toast)
+        type ShowFn = unsafe extern "system" fn(*mut c_void, *mut c_void) -> HRESULT;
+        let show: ShowFn = mem::transmute(vtable_entry(notifier.0, TOAST_NOTIFIER_SHOW));
+        if !SUCCEEDED(show(notifier.0, toast.0)) {
+            return None;
+        }
+
+        Some(toast.into_raw())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ToastEvent {
+    Activated,
+    Dismissed,
+    Failed,
+}
+
+impl ToastEvent {
+    fn handler_iid(&self) -> &'static GUID {
+        match self {
+            ToastEvent::Activated => &IID_TOAST_ACTIVATED_HANDLER,
+            ToastEvent::Dismissed => &IID_TOAST_DISMISSED_HANDLER,
+            ToastEvent::Failed => &IID_TOAST_FAILED_HANDLER,
+        }
+    }
+}
+
+// TypedEventHandler<ToastNotification, *> implemented in Rust: invoked on
+// a WinRT worker thread, posts AZ_NOTIFICATION_ACTION to the window
+#[repr(C)]
+struct ToastEventHandler {
+    vtable: *const ToastEventHandlerVtbl,
+    ref_count: AtomicUsize,
+    hwnd: usize,
+    notification_id: usize,
+    event: ToastEvent,
+    winrt: WinRtFunctions,
+}
+
+#[repr(C)]
+struct ToastEventHandlerVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut ToastEventHandler, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut ToastEventHandler) -> u32,
+    Release: unsafe extern "system" fn(*mut ToastEventHandler) -> u32,
+    Invoke: unsafe extern "system" fn(*mut ToastEventHandler, *mut c_void, *mut c_void) -> HRESULT,
+}
+
+static TOAST_EVENT_HANDLER_VTBL: ToastEventHandlerVtbl = ToastEventHandlerVtbl {
+    QueryInterface: ToastEventHandler::query_interface,
+    AddRef: ToastEventHandler::add_ref,
+    Release: ToastEventHandler::release,
+    Invoke: ToastEventHandler::invoke,
+};
+
+impl ToastEventHandler {
+
+    // returns a handler with a reference count of 1
+    fn new(hwnd: HWND, notification_id: usize, event: ToastEvent, winrt: WinRtFunctions) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            vtable: &TOAST_EVENT_HANDLER_VTBL,
+            ref_count: AtomicUsize::new(1),
+            hwnd: hwnd as usize,
+            notification_id,
+            event,
+            winrt,
+        }))
+    }
+
+    unsafe extern "system" fn query_interface(this: *mut Self, iid: REFIID, out: *mut *mut c_void) -> HRESULT {
+        if out.is_null() {
+            return E_POINTER;
+        }
+
+        let iid = &*iid;
+        if IsEqualGUID(iid, &IID_IUNKNOWN) ||
+           IsEqualGUID(iid, &IID_IAGILE_OBJECT) ||
+           IsEqualGUID(iid, (*this).event.handler_iid()) {
+            Self::add_ref(this);
+            *out = this as *mut c_void;
+            S_OK
+        } else {
+            *out = ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn add_ref(this: *mut Self) -> u32 {
+        ((*this).ref_count.fetch_add(1, AtomicOrdering::SeqCst) + 1) as u32
+    }
+
+    unsafe extern "system" fn release(this: *mut Self) -> u32 {
+        let remaining = (*this).ref_count.fetch_sub(1, AtomicOrdering::SeqCst) - 1;
+        if remaining == 0 {
+            mem::drop(Box::from_raw(this));
+        }
+        remaining as u32
+    }
+
+    unsafe extern "system" fn invoke(this: *mut Self, _sender: *mut c_void, args: *mut c_void) -> HRESULT {
+
+        use winapi::um::winuser::PostMessageW;
+
+        let hwnd = (*this).hwnd as HWND;
+
+        // dismissed (by the user or the application), expired or failed:
+        // the notification can't be activated anymore, remove it
+        if (*this).event != ToastEvent::Activated {
+            PostMessageW(hwnd, AZ_NOTIFICATION_ACTION, (*this).notification_id, NOTIFICATION_CLOSED as LPARAM);
+            return S_OK;
+        }
+
+        if args.is_null() {
+            return S_OK;
+        }
+
+        let winrt = (*this).winrt;
+
+        // the args are borrowed, only the interface returned by QueryInterface is released
+        let args = match com_query_interface(args, &IID_ITOAST_ACTIVATED_EVENT_ARGS) {
+            Some(s) => s,
+            None => return S_OK,
+        };
+
+        type GetArgumentsFn = unsafe extern "system" fn(*mut c_void, *mut HSTRING) -> HRESULT;
+        let get_arguments: GetArgumentsFn = mem::transmute(vtable_entry(args.0, TOAST_ACTIVATED_EVENT_ARGS_GET_ARGUMENTS));
+        let mut arguments = ptr::null_mut();
+        if !SUCCEEDED(get_arguments(args.0, &mut arguments)) {
+            return S_OK;
+        }
+        let arguments = HString { hstring: arguments, delete: winrt.WindowsDeleteString };
+
+        let mut len = 0;
+        let buffer = (winrt.WindowsGetStringRawBuffer)(arguments.hstring, &mut len);
+        if buffer.is_null() {
+            return S_OK;
+        }
+        let arguments = String::from_utf16_lossy(core::slice::from_raw_parts(buffer, len as usize));
+
+        // clicks on the toast body (without an action) are ignored
+        let (id, action) = match parse_action_arguments(&arguments) {
+            Some(s) => s,
+            None => return S_OK,
+        };
+
+        PostMessageW(hwnd, AZ_NOTIFICATION_ACTION, id, action as LPARAM);
+        S_OK
+    }
+}
+
+// "azul-notification={id}:{action}" => (id, action)
+fn parse_action_arguments(arguments: &str) -> Option<(usize, usize)> {
+    let mut parts = arguments.strip_prefix(ACTION_ARGUMENTS_PREFIX)?.split(':');
+    let id = parts.next()?.parse().ok()?;
+    let action = parts.next()?.parse().ok()?;
+    Some((id, action))
+}
@@ -966,6 +966,7 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         mut windows,
         image_cache,
         fc_cache,
+        ..
     } = app;
 
     let xlib = Rc::new(Xlib::new()?);
@@ -1662,6 +1663,7 @@ impl X11Window {
                         layout_results,
                         &window_state.mouse_state.cursor_position,
                         window_state.size.hidpi_factor,
+                        0.0,
                     )
                 },
             )
@@ -92,12 +92,13 @@ use azul_core::{
         ExternalImageType, ImageBufferKind, UpdateImage, ImageDirtyRect,
         Epoch, AddFontInstance, FontVariation, FontInstanceOptions,
         FontInstancePlatformOptions, SyntheticItalics, PrimitiveFlags,
-        TransformKey, UpdateImageResult,
+        TransformKey, UpdateImageResult, FontWarmup,
     },
     display_list::{
         CachedDisplayList, GlyphInstance, DisplayListScrollFrame,
         DisplayListFrame, LayoutRectContent, DisplayListMsg,
         AlphaType, ImageRendering, StyleBorderRadius, BoxShadow,
+        DisplayListStats,
     },
     dom::TagId,
     display_list::DisplayListImageMask,
@@ -130,7 +131,13 @@ use azul_css::{
 };
 use webrender::Renderer;
 use alloc::sync::Arc;
+use alloc::collections::BTreeMap;
+use core::cell::RefCell;
 use core::mem;
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use core::time::Duration;
+use rust_fontconfig::FcFontCache;
+use std::time::Instant;
 
 pub enum AsyncHitTester {
     Requested(WrHitTesterRequest),
@@ -155,6 +162,9 @@ impl AsyncHitTester {
 
 /// Same interface as azul-core: FullHitTest::new
 /// but uses webrender to compare the results of the two hit-testing implementations
+///
+/// `hit_test_tolerance` (logical pixels) expands the hit-testable area of the nodes
+/// of the root DOM, for imprecise (pen / touch) input, see `HitTestTolerance`
 pub(crate) fn fullhittest_new_webrender(
      wr_hittester: &dyn WrApiHitTester,
      document_id: DocumentId,
@@ -163,6 +173,7 @@ pub(crate) fn fullhittest_new_webrender(
      layout_results: &[LayoutResult],
      cursor_position: &CursorPosition,
      hidpi_factor: f32,
+     hit_test_tolerance: f32,
 ) -> FullHitTest {
 
     use alloc::collections::BTreeMap;
@@ -175,6 +186,13 @@ pub(crate) fn fullhittest_new_webrender(
         CursorPosition::InWindow(pos) => LogicalPosition::new(pos.x, pos.y),
     };
 
+    // pointer narrowly missed a (small) target: hit-test the nearest target instead
+    if let Some(adjusted) = layout_results.get(0).and_then(|lr| {
+        lr.get_hit_test_position_with_tolerance(cursor_location, hit_test_tolerance)
+    }) {
+        cursor_location = adjusted;
+    }
+
     // If there was no new focus found then the focus is set to none
     // NOTE: The following code should NOT use this field for updating,
     // but rather check if the event was a MouseUp event first
@@ -375,6 +393,7 @@ pub(crate) fn rebuild_display_list(
     use azul_core::styled_dom::DomId;
     use azul_core::ui_solver::LayoutResult;
 
+    let build_start = Instant::now();
     let mut txn = WrTransaction::new();
 
     // NOTE: Display list has to be rebuilt every frame, otherwise, the epochs get out of sync
@@ -393,6 +412,33 @@ pub(crate) fn rebuild_display_list(
     // Scale everything in the display list to the DPI of the window
     cached_display_list.scale_for_dpi(internal.current_window_state.size.get_hidpi_factor());
 
+    let resources = validate_resource_updates(internal.document_id, internal.id_namespace, resources);
+
+    // webrender can't build a scene from NaN / infinite rects: keep showing
+    // the last valid display list, but don't lose the resource updates
+    if cached_display_list.root.has_non_finite_geometry() {
+        report_render_error(internal.document_id, RenderError::NonFiniteGeometry);
+        txn.update_resources(
+            resources
+                .into_iter()
+                .filter_map(wr_translate_resource_update)
+                .collect(),
+        );
+        render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+        internal.retained_display_lists.build_time_since_present += build_start.elapsed();
+        return;
+    }
+
+    let total_nodes = cached_display_list.root.node_count();
+
+    // iframes that are identical to the last frame are not re-submitted,
+    // webrender keeps their display list (only the root is rebuilt every frame)
+    let mut iframe_cache = IFrameCache {
+        previous: mem::take(&mut internal.retained_display_lists.iframes),
+        current: BTreeMap::new(),
+        nodes_reused: 0,
+    };
+
     let root_pipeline_id = PipelineId(0, internal.document_id.id);
     let display_list = wr_translate_display_list(
         internal.document_id,
@@ -400,8 +446,16 @@ pub(crate) fn rebuild_display_list(
         cached_display_list,
         root_pipeline_id,
         internal.current_window_state.size.get_hidpi_factor(),
+        &mut iframe_cache,
     );
 
+    internal.retained_display_lists.iframes = iframe_cache.current;
+    internal.retained_display_lists.stats = DisplayListStats {
+        nodes_rebuilt: total_nodes.saturating_sub(iframe_cache.nodes_reused),
+        nodes_reused: iframe_cache.nodes_reused,
+        scene_build_time: internal.retained_display_lists.stats.scene_build_time,
+    };
+
     let physical_size = internal.current_window_state.size.get_physical_size();
     let physical_size = WrLayoutSize::new(physical_size.width as f32, physical_size.height as f32);
 
@@ -420,6 +474,93 @@ pub(crate) fn rebuild_display_list(
     );
 
     render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+    internal.retained_display_lists.build_time_since_present += build_start.elapsed();
+}
+
+/// Error of a webrender transaction or of the renderer of a window. Transactions
+/// are checked before they are sent, invalid parts are not sent to webrender
+/// (which would otherwise panic or silently drop the frame)
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderError {
+    /// The font was added more than once in the same transaction
+    DuplicateFontKey(FontKey),
+    /// The font instance was added more than once in the same transaction
+    DuplicateFontInstanceKey(FontInstanceKey),
+    /// The image was added more than once in the same transaction
+    DuplicateImageKey(ImageKey),
+    /// A resource key was created for the renderer of a different window
+    ForeignResourceNamespace { expected: IdNamespace, found: IdNamespace },
+    /// A frame of the display list has a NaN or infinite size or position,
+    /// the display list was not submitted
+    NonFiniteGeometry,
+    /// The renderer failed to render the frame (webrender error messages)
+    RendererFailed(String),
+}
+
+thread_local! {
+    // errors that were not yet passed to the error handler of the shell
+    static RENDER_ERRORS: RefCell<Vec<(DocumentId, RenderError)>> = RefCell::new(Vec::new());
+}
+
+/// Logs the error and queues it for the error handler of the shell, see `take_render_errors`
+pub(crate) fn report_render_error(document_id: DocumentId, error: RenderError) {
+    #[cfg(feature = "logging")] {
+        error!("render error in document {:?}: {:?}", document_id, error);
+    }
+    RENDER_ERRORS.with(|e| e.borrow_mut().push((document_id, error)));
+}
+
+/// Returns and clears the errors reported since the last call
+pub(crate) fn take_render_errors() -> Vec<(DocumentId, RenderError)> {
+    RENDER_ERRORS.with(|e| mem::take(&mut *e.borrow_mut()))
+}
+
+// Removes (and reports) resource updates that webrender would reject:
+// keys from a different namespace and keys added twice in one transaction
+fn validate_resource_updates(
+    document_id: DocumentId,
+    id_namespace: IdNamespace,
+    resources: Vec<ResourceUpdate>,
+) -> Vec<ResourceUpdate> {
+
+    use alloc::collections::BTreeSet;
+
+    let mut fonts = BTreeSet::new();
+    let mut font_instances = BTreeSet::new();
+    let mut images = BTreeSet::new();
+
+    resources.into_iter().filter(|update| {
+
+        let (namespace, error) = match update {
+            ResourceUpdate::AddFont(f) => (
+                f.key.namespace,
+                if fonts.insert(f.key) { None } else { Some(RenderError::DuplicateFontKey(f.key)) },
+            ),
+            ResourceUpdate::AddFontInstance(fi) => (
+                fi.key.namespace,
+                if font_instances.insert(fi.key) { None } else { Some(RenderError::DuplicateFontInstanceKey(fi.key)) },
+            ),
+            ResourceUpdate::AddImage(i) => (
+                i.key.namespace,
+                if images.insert(i.key) { None } else { Some(RenderError::DuplicateImageKey(i.key)) },
+            ),
+            _ => return true,
+        };
+
+        let error = if namespace != id_namespace {
+            Some(RenderError::ForeignResourceNamespace { expected: id_namespace, found: namespace })
+        } else {
+            error
+        };
+
+        match error {
+            Some(e) => {
+                report_render_error(document_id, e);
+                false
+            },
+            None => true,
+        }
+    }).collect()
 }
 
 /// Generates a new frame for webrender
@@ -448,7 +589,12 @@ pub(crate) fn generate_frame(
     }
 
     txn.set_root_pipeline(wr_translate_pipeline_id(PipelineId(0, internal.document_id.id)));
-    txn.set_document_view(WrDeviceIntRect::from_origin_and_size(WrDeviceIntPoint::new(0, 0), framebuffer_size));
+    // windows with a fixed logical size are centered between the letterbox bars
+    let content_offset = internal.current_window_state.size.get_physical_content_offset();
+    txn.set_document_view(WrDeviceIntRect::from_origin_and_size(
+        WrDeviceIntPoint::new(content_offset.x, content_offset.y),
+        framebuffer_size
+    ));
     scroll_all_nodes(&mut internal.scroll_states, &mut txn);
     synchronize_gpu_values(
         &internal.layout_results, 
@@ -463,6 +609,91 @@ pub(crate) fn generate_frame(
     txn.generate_frame(0);
 
     render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+
+    if !display_list_was_rebuilt {
+        return;
+    }
+
+    if ASYNC_SCENE_BUILDING.load(AtomicOrdering::SeqCst) {
+        // measured by the renderer once it waits for the scene
+        internal.retained_display_lists.scene_build_pending = true;
+    } else {
+        let scene_build_start = Instant::now();
+        render_api.flush_scene_builder();
+        internal.retained_display_lists.scene_build_pending = false;
+        internal.retained_display_lists.stats.scene_build_time = Some(scene_build_start.elapsed());
+    }
+}
+
+// Whether scenes are built on the scene builder thread (AppConfig::async_scene_building)
+static ASYNC_SCENE_BUILDING: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether `generate_frame` lets webrender build the scene asynchronously
+/// or blocks until the scene is built, applies to all windows
+pub(crate) fn set_async_scene_building(enabled: bool) {
+    ASYNC_SCENE_BUILDING.store(enabled, AtomicOrdering::SeqCst);
+}
+
+/// Blocks until the scene builder is done, if a scene build was started by
+/// `generate_frame` with async scene building, the time spent waiting is
+/// recorded as the scene build time
+pub(crate) fn wait_for_scene_builder(
+    internal: &mut WindowInternal,
+    render_api: &mut WrRenderApi,
+) {
+    let wait_start = Instant::now();
+    render_api.flush_scene_builder();
+    if mem::replace(&mut internal.retained_display_lists.scene_build_pending, false) {
+        internal.retained_display_lists.stats.scene_build_time = Some(wait_start.elapsed());
+    }
+}
+
+/// Loads the fonts of the `AppConfig::font_warmup` list into the renderer and
+/// rasterizes their characters, so that the first text using them doesn't stall.
+/// Returns how long the warm-up took.
+pub(crate) fn warm_up_fonts(
+    internal: &mut WindowInternal,
+    render_api: &mut WrRenderApi,
+    fc_cache: &FcFontCache,
+    font_warmup: &[FontWarmup],
+) -> Duration {
+
+    use azul_core::app_resources::add_font_warmup_resources;
+
+    let warmup_start = Instant::now();
+
+    let mut resource_updates = Vec::new();
+    let fonts = add_font_warmup_resources(
+        &mut internal.renderer_resources,
+        internal.get_dpi_scale_factor(),
+        fc_cache,
+        internal.id_namespace,
+        &mut resource_updates,
+        font_warmup,
+        crate::app::CALLBACKS.load_font_fn,
+        crate::app::CALLBACKS.parse_font_fn,
+    );
+
+    let mut txn = WrTransaction::new();
+    txn.update_resources(
+        resource_updates
+            .into_iter()
+            .filter_map(wr_translate_resource_update)
+            .collect(),
+    );
+    render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+
+    for (font_key, font_instance_key, characters) in fonts {
+        let glyph_indices = render_api
+            .get_glyph_indices(wr_translate_font_key(font_key), characters.as_str())
+            .into_iter()
+            .filter_map(|g| g)
+            .collect::<Vec<_>>();
+        // blocks until the glyphs are rasterized, the result is not needed
+        let _ = render_api.get_glyph_dimensions(wr_translate_font_instance_key(font_instance_key), glyph_indices);
+    }
+
+    warmup_start.elapsed()
 }
 
 
@@ -1108,17 +1339,25 @@ pub(crate) fn wr_translate_external_scroll_id(scroll_id: ExternalScrollId) -> Wr
     WrExternalScrollId(scroll_id.0, wr_translate_pipeline_id(scroll_id.1))
 }
 
+// Display lists of the iframes submitted in the last / current frame
+pub(crate) struct IFrameCache {
+    previous: BTreeMap<PipelineId, CachedDisplayList>,
+    current: BTreeMap<PipelineId, CachedDisplayList>,
+    nodes_reused: usize,
+}
+
 pub(crate) fn wr_translate_display_list(
     document_id: DocumentId,
     render_api: &mut WrRenderApi,
     input: CachedDisplayList,
     pipeline_id: PipelineId,
     current_hidpi_factor: f32,
+    iframe_cache: &mut IFrameCache,
 ) -> WrBuiltDisplayList {
     let root_space_and_clip = WrSpaceAndClipInfo::root_scroll(wr_translate_pipeline_id(pipeline_id));
     let mut positioned_items = Vec::new();
     let mut builder = WrDisplayListBuilder::new(wr_translate_pipeline_id(pipeline_id));
-    push_display_list_msg(document_id, render_api, &mut builder, input.root, root_space_and_clip.spatial_id, root_space_and_clip.clip_id, &mut positioned_items, current_hidpi_factor);
+    push_display_list_msg(document_id, render_api, &mut builder, input.root, root_space_and_clip.spatial_id, root_space_and_clip.clip_id, &mut positioned_items, current_hidpi_factor, iframe_cache);
     let (_pipeline_id, built_display_list) = builder.finalize();
     built_display_list
 }
@@ -1133,6 +1372,7 @@ fn push_display_list_msg(
     parent_clip_id: WrClipId,
     positioned_items: &mut Vec<(WrSpatialId, WrClipId)>,
     current_hidpi_factor: f32,
+    iframe_cache: &mut IFrameCache,
 ) {
     use azul_core::display_list::DisplayListMsg::*;
     use azul_core::ui_solver::PositionInfo::*;
@@ -1255,24 +1495,35 @@ fn push_display_list_msg(
 
             let iframe_root_size = cached_display_list.root_size;
 
-            let built_display_list = wr_translate_display_list(
-                document_id,
-                render_api,
-                *cached_display_list,
-                iframe_pipeline_id,
-                current_hidpi_factor,
-            );
+            let is_unchanged = iframe_cache.previous.remove(&iframe_pipeline_id).as_ref() == Some(&*cached_display_list);
+
+            if is_unchanged {
+                // webrender still has the display list of the last frame
+                iframe_cache.nodes_reused += cached_display_list.root.node_count();
+                iframe_cache.current.insert(iframe_pipeline_id, *cached_display_list);
+            } else {
+                iframe_cache.current.insert(iframe_pipeline_id, (*cached_display_list).clone());
+
+                let built_display_list = wr_translate_display_list(
+                    document_id,
+                    render_api,
+                    *cached_display_list,
+                    iframe_pipeline_id,
+                    current_hidpi_factor,
+                    iframe_cache,
+                );
 
-            let wr_pipeline_id = wr_translate_pipeline_id(iframe_pipeline_id);
-            let mut transaction = WrTransaction::new();
-            transaction.set_display_list(
-                wr_translate_epoch(epoch),
-                None, // background
-                wr_translate_logical_size(iframe_clip_size), // viewport size
-                (wr_pipeline_id, built_display_list),
-                true, // preserve frame scroll state
-            );
-            render_api.send_transaction(wr_translate_document_id(document_id), transaction);
+                let wr_pipeline_id = wr_translate_pipeline_id(iframe_pipeline_id);
+                let mut transaction = WrTransaction::new();
+                transaction.set_display_list(
+                    wr_translate_epoch(epoch),
+                    None, // background
+                    wr_translate_logical_size(iframe_clip_size), // viewport size
+                    (wr_pipeline_id, built_display_list),
+                    true, // preserve frame scroll state
+                );
+                render_api.send_transaction(wr_translate_document_id(document_id), transaction);
+            }
 
             builder.push_iframe(
                 WrLayoutRect::from_size(wr_translate_logical_size(iframe_root_size)), // bounds
@@ -1285,8 +1536,8 @@ fn push_display_list_msg(
                 false, // the iframe is already submitted into the render API
             );
         },
-        Frame(f) => push_frame(document_id, render_api, builder, f, rect_spatial_id, parent_clip_id, positioned_items, current_hidpi_factor),
-        ScrollFrame(sf) => push_scroll_frame(document_id, render_api, builder, sf, rect_spatial_id, parent_clip_id, positioned_items, current_hidpi_factor),
+        Frame(f) => push_frame(document_id, render_api, builder, f, rect_spatial_id, parent_clip_id, positioned_items, current_hidpi_factor, iframe_cache),
+        ScrollFrame(sf) => push_scroll_frame(document_id, render_api, builder, sf, rect_spatial_id, parent_clip_id, positioned_items, current_hidpi_factor, iframe_cache),
     }
 
     if msg_position.is_positioned() {
@@ -1310,6 +1561,7 @@ fn push_frame(
     parent_clip_id: WrClipId,
     positioned_items: &mut Vec<(WrSpatialId, WrClipId)>,
     current_hidpi_factor: f32,
+    iframe_cache: &mut IFrameCache,
 ) {
     let content_clip_id = push_display_list_content(
         builder,
@@ -1354,6 +1606,7 @@ fn push_frame(
             children_clip_id,
             positioned_items,
             current_hidpi_factor,
+            iframe_cache,
         );
     }
 }
@@ -1368,6 +1621,7 @@ fn push_scroll_frame(
     parent_clip_id: WrClipId,
     positioned_items: &mut Vec<(WrSpatialId, WrClipId)>,
     current_hidpi_factor: f32,
+    iframe_cache: &mut IFrameCache,
 ) {
     use azul_css::ColorU;
     use webrender::api::{
@@ -1447,6 +1701,7 @@ fn push_scroll_frame(
             scroll_frame_clip_info.clip_id,
             positioned_items,
             current_hidpi_factor,
+            iframe_cache,
         );
     }
 }
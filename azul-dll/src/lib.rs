@@ -29,8 +29,10 @@ pub use AzAppTT as AzApp;
 #[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { app.add_image(id, image) }
 /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
 #[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { app.get_monitors() }
-/// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
-#[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { app.run(window) }
+/// Processes all pending events of the application without blocking (for embedding it into an existing event loop, instead of calling `run()`) and returns whether any window is still open. The first call opens the windows added with `add_window()`, the first one being the root window. Currently only implemented on Windows.
+#[no_mangle] pub extern "C" fn AzApp_pumpEvents(app: &AzApp) -> bool { app.pump_events() }
+/// Runs the application until the event loop stops (the last window was closed or the application quit)
+#[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) -> i32 { app.run(window) }
 /// Destructor: Takes ownership of the `App` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzApp_delete(object: &mut AzApp) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
 /// Clones the object
@@ -41,6 +43,40 @@ pub use azul_impl::resources::AppConfig as AzAppConfigTT;
 pub use AzAppConfigTT as AzAppConfig;
 /// Constructs a default `AppConfig`, uses the layout solver currently available
 #[no_mangle] pub extern "C" fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { AzAppConfig::new(layout_solver) }
+/// Destructor: Takes ownership of the `AppConfig` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAppConfig_delete(object: &mut AzAppConfig) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+pub use azul_impl::resources::GpuPreference as AzGpuPreferenceTT;
+pub use AzGpuPreferenceTT as AzGpuPreference;
+
+/// Limits the GPU memory (texture cache, render targets, images) used by the renderer of a window: every `check_interval_ms`, the memory usage is compared against `max_bytes` and if it is exceeded, the renderer evicts its caches
+pub use azul_impl::resources::GpuMemoryBudget as AzGpuMemoryBudgetTT;
+pub use AzGpuMemoryBudgetTT as AzGpuMemoryBudget;
+/// No limit (default)
+#[no_mangle] pub extern "C" fn AzGpuMemoryBudget_unlimited() -> AzGpuMemoryBudget { AzGpuMemoryBudget::unlimited() }
+/// Limits the GPU memory to `max_bytes`, checked every 5 seconds
+#[no_mangle] pub extern "C" fn AzGpuMemoryBudget_new(max_bytes: usize) -> AzGpuMemoryBudget { AzGpuMemoryBudget::new(max_bytes) }
+
+/// Radius (in logical pixels) by which the hit-testable area of every node is expanded when hit-testing a pointer position, so that small targets can be hit with imprecise input devices: if the pointer misses all nodes, the nearest node within the radius is hit instead
+pub use azul_impl::resources::HitTestTolerance as AzHitTestToleranceTT;
+pub use AzHitTestToleranceTT as AzHitTestTolerance;
+/// Only exact hits, for all input devices
+#[no_mangle] pub extern "C" fn AzHitTestTolerance_none() -> AzHitTestTolerance { AzHitTestTolerance::none() }
+/// Creates a new `HitTestTolerance` from the tolerances (in logical pixels) for mouse, pen and touch input
+#[no_mangle] pub extern "C" fn AzHitTestTolerance_new(mouse: f32, pen: f32, touch: f32) -> AzHitTestTolerance { AzHitTestTolerance::new(mouse, pen, touch) }
+
+/// Font that is loaded at a given size when a window is created, together with the characters that are rasterized up front (see `AppConfig::font_warmup`)
+pub use azul_impl::resources::FontWarmup as AzFontWarmupTT;
+pub use AzFontWarmupTT as AzFontWarmup;
+/// Creates a new `FontWarmup` from the font families, the font size and the characters to rasterize
+#[no_mangle] pub extern "C" fn AzFontWarmup_new(font: AzStyleFontFamilyVec, size: AzStyleFontSize, characters: AzString) -> AzFontWarmup { AzFontWarmup::new(font, size, characters) }
+/// Destructor: Takes ownership of the `FontWarmup` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzFontWarmup_delete(object: &mut AzFontWarmup) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Strategy of the event loop
+pub use azul_impl::resources::EventLoopMode as AzEventLoopModeTT;
+pub use AzEventLoopModeTT as AzEventLoopMode;
 
 /// Configuration to set which messages should be logged.
 pub use azul_impl::resources::AppLogLevel as AzAppLogLevelTT;
@@ -64,14 +100,34 @@ pub use AzWindowCreateOptionsTT as AzWindowCreateOptions;
 /// Destructor: Takes ownership of the `WindowCreateOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowCreateOptions_delete(object: &mut AzWindowCreateOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// OpenGL context version, profile and flags requested for a window
+pub use azul_core::window::GlContextOptions as AzGlContextOptionsTT;
+pub use AzGlContextOptionsTT as AzGlContextOptions;
+
+/// OpenGL context profile
+pub use azul_core::window::GlContextProfile as AzGlContextProfileTT;
+pub use AzGlContextProfileTT as AzGlContextProfile;
+
+/// Buffering of the OpenGL surface of a window: double-buffered surfaces never show partially drawn frames, but may wait for the next vsync / compositor cycle; single-buffered surfaces render directly into the visible buffer (lower latency for overlays), at the cost of possible tearing and flickering
+pub use azul_core::window::GlBuffering as AzGlBufferingTT;
+pub use AzGlBufferingTT as AzGlBuffering;
+
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
 pub use azul_core::window::RendererOptions as AzRendererOptionsTT;
 pub use AzRendererOptionsTT as AzRendererOptions;
 
+/// How rendered frames are presented to the screen: controls vsync (swap interval), tearing and frame pacing
+pub use azul_core::window::PresentMode as AzPresentModeTT;
+pub use AzPresentModeTT as AzPresentMode;
+
 /// Whether the renderer has VSync enabled
 pub use azul_core::window::Vsync as AzVsyncTT;
 pub use AzVsyncTT as AzVsync;
 
+/// Whether a window only renders when its content changed or on every frame
+pub use azul_core::window::RedrawPolicy as AzRedrawPolicyTT;
+pub use AzRedrawPolicyTT as AzRedrawPolicy;
+
 /// Does the renderer render in SRGB color space? By default, azul tries to set it to `Enabled` and falls back to `Disabled` if the OpenGL context can't be initialized properly
 pub use azul_core::window::Srgb as AzSrgbTT;
 pub use AzSrgbTT as AzSrgb;
@@ -196,6 +252,10 @@ pub use AzVirtualKeyCodeTT as AzVirtualKeyCode;
 pub use azul_core::window::AcceleratorKey as AzAcceleratorKeyTT;
 pub use AzAcceleratorKeyTT as AzAcceleratorKey;
 
+/// Placement of a window with a fixed logical size inside the actual window: all rects are in logical units of the window (physical pixels / DPI factor)
+pub use azul_core::window::Letterbox as AzLetterboxTT;
+pub use AzLetterboxTT as AzLetterbox;
+
 /// Minimum / maximum / current size of the window in logical dimensions
 pub use azul_core::window::WindowSize as AzWindowSizeTT;
 pub use AzWindowSizeTT as AzWindowSize;
@@ -256,6 +316,10 @@ pub use AzWindowsWindowOptionsTT as AzWindowsWindowOptions;
 /// Destructor: Takes ownership of the `WindowsWindowOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowsWindowOptions_delete(object: &mut AzWindowsWindowOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+pub use azul_core::window::WindowCornerPreference as AzWindowCornerPreferenceTT;
+pub use AzWindowCornerPreferenceTT as AzWindowCornerPreference;
+
 /// CSD theme of the window title / button controls
 pub use azul_core::window::WaylandTheme as AzWaylandThemeTT;
 pub use AzWaylandThemeTT as AzWaylandTheme;
@@ -298,6 +362,16 @@ pub use AzWindowThemeTT as AzWindowTheme;
 pub use azul_core::window::WindowPosition as AzWindowPositionTT;
 pub use AzWindowPositionTT as AzWindowPosition;
 
+/// Caret and selection of the currently focused text input, in logical coordinates relative to the top left of the window
+pub use azul_core::window::TextCaret as AzTextCaretTT;
+pub use AzTextCaretTT as AzTextCaret;
+/// Destructor: Takes ownership of the `TextCaret` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextCaret_delete(object: &mut AzTextCaret) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Accessibility preferences of the user, set by the operating system
+pub use azul_core::window::AccessibilitySettings as AzAccessibilitySettingsTT;
+pub use AzAccessibilitySettingsTT as AzAccessibilitySettings;
+
 /// Position of the virtual keyboard necessary to insert CJK characters
 pub use azul_core::window::ImePosition as AzImePositionTT;
 pub use AzImePositionTT as AzImePosition;
@@ -396,6 +470,8 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getTextLayoutOptions(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionResolvedTextLayoutOptions { callbackinfo.get_text_layout_options(node_id).into() }
 /// Similar to `get_inline_text()`: If the node is a `Text` node, shape the `text` string with the same parameters as the current text and return the calculated InlineTextLayout. Necessary to calculate text cursor offsets and to detect when a line overflows content.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_shapeText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId, text: AzString) -> AzOptionInlineText { azul_impl::text_layout::callback_info_shape_text(callbackinfo, node_id, text).into() }
+/// Measures a text without building a DOM: loads the first available font of `font` and returns the lines, per-glyph positions and the total size of the text, exactly as the layout engine would lay out a text node with the same font, font size and constraints. Returns `None` if no font could be loaded.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_measureText(callbackinfo: &AzCallbackInfo, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: AzString, constraints: AzTextConstraints) -> AzOptionInlineText { azul_impl::text_layout::callback_info_measure_text(callbackinfo, font.as_ref(), font_size, text.as_str(), &constraints).into() }
 /// Returns the index of the node relative to the parent node.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getIndexInParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> usize { let mut t = 0; let mut n = node_id; while let Some(prev) = callbackinfo.get_previous_sibling(n) { n = prev; t += 1; } t }
 /// Returns the parent `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
@@ -436,8 +512,20 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { callbackinfo.delete_image(&id) }
 /// If the node has an `ImageMask`, exchanges the current mask for the new mask
 #[no_mangle] pub extern "C" fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { callbackinfo.update_image_mask(node_id, new_mask) }
+/// Decodes an image on a background thread. The node keeps showing its current (placeholder) image until the `loader` returns, then the decoded image - or the `fallback` if the loader returned `None` - is swapped in and the node is repainted. The layout is not recomputed, so the node should have a fixed size.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_loadImageAsync(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, data: AzRefAny, loader: AzImageLoaderCallbackType, fallback: AzImageRef) -> AzOptionThreadId { callbackinfo.load_image_async(node_id, data, loader, fallback).into() }
 /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { callbackinfo.stop_propagation(); }
+/// Runs all due timers, re-layouts and renders exactly one frame of the current window as soon as the callback returns, bypassing the message loop timing. Intended for automated UI tests. Only implemented on Windows.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_forceFrame(callbackinfo: &AzCallbackInfo) { azul_impl::shell::callback_info_force_frame(callbackinfo) }
+/// Moves the current window onto the monitor with the given `Monitor::id`, shrinking it if necessary, and keeps it there: the user can't drag or resize the window past the monitor bounds anymore. Returns `false` if the monitor does not exist. Only implemented on Windows.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_confineToMonitor(callbackinfo: &AzCallbackInfo, monitor_id: usize) -> bool { azul_impl::shell::callback_info_confine_to_monitor(callbackinfo, monitor_id) }
+/// Allows the current window to be moved freely again after `confine_to_monitor`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_releaseFromMonitor(callbackinfo: &AzCallbackInfo) { azul_impl::shell::callback_info_release_from_monitor(callbackinfo) }
+/// Locks the aspect ratio (width / height) of the client area of the current window: when the user resizes the window, the opposite edge follows so that the client area keeps the ratio. Returns `false` if the ratio is not a positive number. Only implemented on Windows.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_lockAspectRatio(callbackinfo: &AzCallbackInfo, ratio: f32) -> bool { azul_impl::shell::callback_info_lock_aspect_ratio(callbackinfo, ratio) }
+/// Allows the current window to be resized freely again after `lock_aspect_ratio`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_unlockAspectRatio(callbackinfo: &AzCallbackInfo) { azul_impl::shell::callback_info_unlock_aspect_ratio(callbackinfo) }
 /// Spawns a new window with the given `WindowCreateOptions`.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { callbackinfo.create_window(new_window); }
 /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
@@ -554,6 +642,13 @@ pub use AzResolvedTextLayoutOptionsTT as AzResolvedTextLayoutOptions;
 /// Destructor: Takes ownership of the `ResolvedTextLayoutOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzResolvedTextLayoutOptions_delete(object: &mut AzResolvedTextLayoutOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Constraints for measuring a text outside of the DOM (`measure_text`), the values are the resolved values of the CSS properties of a text node
+pub use azul_impl::ui_solver::TextConstraints as AzTextConstraintsTT;
+pub use AzTextConstraintsTT as AzTextConstraints;
+/// Creates a new `TextConstraints` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextConstraints::default()` constructor.
+#[no_mangle] pub extern "C" fn AzTextConstraints_default() -> AzTextConstraints { AzTextConstraints::default() }
+
 /// Animation struct to start a new animation
 pub use azul_impl::callbacks::Animation as AzAnimationTT;
 pub use AzAnimationTT as AzAnimation;
@@ -642,6 +737,11 @@ pub use AzWriteBackCallbackTT as AzWriteBackCallback;
 pub use azul_impl::callbacks::ThreadCallback as AzThreadCallbackTT;
 pub use AzThreadCallbackTT as AzThreadCallback;
 
+/// Re-export of rust-allocated (stack based) `ImageLoaderCallback` struct
+pub use azul_impl::callbacks::ImageLoaderCallback as AzImageLoaderCallbackTT;
+pub use AzImageLoaderCallbackTT as AzImageLoaderCallback;
+
+pub type AzImageLoaderCallbackType = extern "C" fn(&mut AzRefAny) -> AzOptionImageRef;
 pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 pub type AzRefAnyDestructorType = extern "C" fn(&mut c_void);
 /// Re-export of rust-allocated (stack based) `RefCount` struct
@@ -688,6 +788,8 @@ pub use AzLayoutCallbackInfoTT as AzLayoutCallbackInfo;
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { layoutcallbackinfo.get_system_fonts().into() }
 /// Returns an `ImageRef` referenced by a CSS ID
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { layoutcallbackinfo.get_image(&id).into() }
+/// Measures a text without building a DOM: loads the first available font of `font` and returns the lines, per-glyph positions and the total size of the text, exactly as the layout engine would lay out a text node with the same font, font size and constraints. Returns `None` if no font could be loaded.
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_measureText(layoutcallbackinfo: &AzLayoutCallbackInfo, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: AzString, constraints: AzTextConstraints) -> AzOptionInlineText { azul_impl::text_layout::layout_callback_info_measure_text(layoutcallbackinfo, font.as_ref(), font_size, text.as_str(), &constraints).into() }
 /// Destructor: Takes ownership of the `LayoutCallbackInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_delete(object: &mut AzLayoutCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -715,6 +817,8 @@ pub use AzDomTT as AzDom;
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::iframe()` constructor.
 #[no_mangle] pub extern "C" fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { AzDom::iframe(data, callback) }
+/// Creates a div that hosts a native child window (i.e. the `HWND` of a WebView2), see `NodeData::set_native_child_window`
+#[no_mangle] pub extern "C" fn AzDom_nativeChildWindow(window: AzNativeChildWindow) -> AzDom { AzDom::native_child_window(window) }
 /// Equivalent to the Rust `Dom::set_node_type()` function.
 #[no_mangle] pub extern "C" fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { dom.root.set_node_type(node_type) }
 /// Equivalent to the Rust `Dom::with_node_type()` function.
@@ -807,6 +911,10 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { dom.root.set_context_menu(context_menu) }
 /// Same as set_context_menu, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }
+/// Marks the DOM root node as part of the title bar of the window. See `NodeData::set_title_bar_region` for more information.
+#[no_mangle] pub extern "C" fn AzDom_setTitleBarRegion(dom: &mut AzDom, region: AzTitleBarRegion) { dom.root.set_title_bar_region(region) }
+/// Same as set_title_bar_region, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withTitleBarRegion(dom: &mut AzDom, region: AzTitleBarRegion) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_title_bar_region(region); dom }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { dom.root.calculate_node_data_hash().0 }
 /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -905,11 +1013,23 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { nodedata.set_menu_bar(menu_bar) }
 /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
 #[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { nodedata.set_context_menu(context_menu) }
+/// Attaches a native child window (i.e. the `HWND` of a WebView2 or a video player) to this node: the window is moved to the visible rect of the node every frame, clipped and scrolled with the layout, and hidden while the node is out of view
+#[no_mangle] pub extern "C" fn AzNodeData_setNativeChildWindow(nodedata: &mut AzNodeData, window: AzNativeChildWindow) { nodedata.set_native_child_window(window) }
+/// Marks the node as part of the title bar of the window: dragging a `TitleBarRegion::Drag` node moves the window, `TitleBarRegion::NoDrag` nodes inside of it stay interactive. Only nodes of the root DOM are taken into account
+#[no_mangle] pub extern "C" fn AzNodeData_setTitleBarRegion(nodedata: &mut AzNodeData, region: AzTitleBarRegion) { nodedata.set_title_bar_region(region) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { nodedata.calculate_node_data_hash().0 }
 /// Destructor: Takes ownership of the `NodeData` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNodeData_delete(object: &mut AzNodeData) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Handle of a native child window (`HWND` on Windows) that is embedded into the layout, see `NodeData::set_native_child_window`
+pub use azul_impl::dom::NativeChildWindow as AzNativeChildWindowTT;
+pub use AzNativeChildWindowTT as AzNativeChildWindow;
+
+/// Role of a node in a title bar that is drawn by the application (similar to the CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+pub use azul_impl::dom::TitleBarRegion as AzTitleBarRegionTT;
+pub use AzTitleBarRegionTT as AzTitleBarRegion;
+
 /// List of core DOM node types built-into by `azul`
 pub use azul_impl::dom::NodeType as AzNodeTypeTT;
 pub use AzNodeTypeTT as AzNodeType;
@@ -2041,6 +2161,14 @@ pub use AzTextInputTT as AzTextInput;
 #[no_mangle] pub extern "C" fn AzTextInput_setPlaceholder(textinput: &mut AzTextInput, text: AzString) { textinput.set_placeholder(text) }
 /// Equivalent to the Rust `TextInput::with_placeholder()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_withPlaceholder(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { textinput.with_placeholder(text) }
+/// Equivalent to the Rust `TextInput::set_read_only()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setReadOnly(textinput: &mut AzTextInput, read_only: bool) { textinput.set_read_only(read_only) }
+/// Equivalent to the Rust `TextInput::with_read_only()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withReadOnly(textinput: &mut AzTextInput, read_only: bool) -> AzTextInput { textinput.with_read_only(read_only) }
+/// Equivalent to the Rust `TextInput::set_disabled()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setDisabled(textinput: &mut AzTextInput, disabled: bool) { textinput.set_disabled(disabled) }
+/// Equivalent to the Rust `TextInput::with_disabled()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withDisabled(textinput: &mut AzTextInput, disabled: bool) -> AzTextInput { textinput.with_disabled(disabled) }
 /// Equivalent to the Rust `TextInput::set_on_text_input()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_setOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { textinput.set_on_text_input(data, callback) }
 /// Equivalent to the Rust `TextInput::with_on_text_input()` function.
@@ -3026,6 +3154,8 @@ pub use AzGlTT as AzGl;
 #[no_mangle] pub extern "C" fn AzGl_getShaderInfoLog(gl: &AzGl, shader: u32) -> AzString { gl.get_shader_info_log(shader) }
 /// Equivalent to the Rust `Gl::get_string()` function.
 #[no_mangle] pub extern "C" fn AzGl_getString(gl: &AzGl, which: u32) -> AzString { gl.get_string(which) }
+/// Returns the name of the GPU / driver the context was created on (`glGetString(GL_RENDERER)`)
+#[no_mangle] pub extern "C" fn AzGl_getRendererName(gl: &AzGl) -> AzString { gl.get_renderer_name() }
 /// Equivalent to the Rust `Gl::get_string_i()` function.
 #[no_mangle] pub extern "C" fn AzGl_getStringI(gl: &AzGl, which: u32, index: u32) -> AzString { gl.get_string_i(which, index) }
 /// Equivalent to the Rust `Gl::get_shader_iv()` function.
@@ -3292,6 +3422,8 @@ pub use AzImageRefTT as AzImageRef;
 #[no_mangle] pub extern "C" fn AzImageRef_rawImage(data: AzRawImage) -> AzOptionImageRef { AzImageRef::new_rawimage(data).into() }
 /// Creates an image reference from an OpenGL texture
 #[no_mangle] pub extern "C" fn AzImageRef_glTexture(texture: AzTexture) -> AzImageRef { AzImageRef::new_gltexture(texture) }
+/// Creates an image filled with a single color, i.e. a placeholder to show while the real image is still loading
+#[no_mangle] pub extern "C" fn AzImageRef_solidColor(width: usize, height: usize, color: AzColorU) -> AzImageRef { AzImageRef::solid_color(width, height, color) }
 /// Creates an image reference from a callback that is going to be rendered with the given nodes computed size
 #[no_mangle] pub extern "C" fn AzImageRef_callback(data: AzRefAny, callback: AzRenderImageCallbackType) -> AzImageRef { AzImageRef::callback(callback, data) }
 /// Creates a new copy of the image bytes instead of shallow-copying the reference
@@ -3970,9 +4102,17 @@ pub use AzTimerTT as AzTimer;
 #[no_mangle] pub extern "C" fn AzTimer_withInterval(timer: &AzTimer, interval: AzDuration) -> AzTimer { timer.clone().with_interval(interval) }
 /// Equivalent to the Rust `Timer::with_timeout()` function.
 #[no_mangle] pub extern "C" fn AzTimer_withTimeout(timer: &AzTimer, timeout: AzDuration) -> AzTimer { timer.clone().with_timeout(timeout) }
+/// Sets how intervals that were missed while the application was busy are handled, the default is to coalesce them into one run
+#[no_mangle] pub extern "C" fn AzTimer_withMissedTickPolicy(timer: &AzTimer, missed_tick_policy: AzTimerMissedTickPolicy) -> AzTimer { timer.clone().with_missed_tick_policy(missed_tick_policy) }
+/// Exempts the timer from being paused while its window is minimized or cloaked, i.e. for timers that do work that is not related to animations
+#[no_mangle] pub extern "C" fn AzTimer_withRunsWhileHidden(timer: &AzTimer, runs_while_hidden: bool) -> AzTimer { timer.clone().with_runs_while_hidden(runs_while_hidden) }
 /// Destructor: Takes ownership of the `Timer` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzTimer_delete(object: &mut AzTimer) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// How a timer with an interval should handle ticks that were missed because the application was busy or in the background
+pub use azul_impl::task::TimerMissedTickPolicy as AzTimerMissedTickPolicyTT;
+pub use AzTimerMissedTickPolicyTT as AzTimerMissedTickPolicy;
+
 /// Should a timer terminate or not - used to remove active timers
 pub use azul_impl::task::TerminateTimer as AzTerminateTimerTT;
 pub use AzTerminateTimerTT as AzTerminateTimer;
@@ -4451,6 +4591,12 @@ pub use AzStringPairVecTT as AzStringPairVec;
 /// Destructor: Takes ownership of the `StringPairVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStringPairVec_delete(object: &mut AzStringPairVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `FontWarmupVec`
+pub use azul_impl::resources::FontWarmupVec as AzFontWarmupVecTT;
+pub use AzFontWarmupVecTT as AzFontWarmupVec;
+/// Destructor: Takes ownership of the `FontWarmupVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzFontWarmupVec_delete(object: &mut AzFontWarmupVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
 pub use azul_impl::css::NormalizedLinearColorStopVec as AzNormalizedLinearColorStopVecTT;
 pub use AzNormalizedLinearColorStopVecTT as AzNormalizedLinearColorStopVec;
@@ -4784,6 +4930,11 @@ pub use azul_core::window::StringPairVecDestructor as AzStringPairVecDestructorT
 pub use AzStringPairVecDestructorTT as AzStringPairVecDestructor;
 
 pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
+/// Re-export of rust-allocated (stack based) `FontWarmupVecDestructor` struct
+pub use azul_impl::resources::FontWarmupVecDestructor as AzFontWarmupVecDestructorTT;
+pub use AzFontWarmupVecDestructorTT as AzFontWarmupVecDestructor;
+
+pub type AzFontWarmupVecDestructorType = extern "C" fn(&mut AzFontWarmupVec);
 /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
 pub use azul_impl::css::NormalizedLinearColorStopVecDestructor as AzNormalizedLinearColorStopVecDestructorTT;
 pub use AzNormalizedLinearColorStopVecDestructorTT as AzNormalizedLinearColorStopVecDestructor;
@@ -5130,6 +5281,12 @@ pub use AzOptionInlineTextTT as AzOptionInlineText;
 /// Destructor: Takes ownership of the `OptionInlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionInlineText_delete(object: &mut AzOptionInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionFocusTarget` struct
+pub use azul_impl::callbacks::OptionFocusTarget as AzOptionFocusTargetTT;
+pub use AzOptionFocusTargetTT as AzOptionFocusTarget;
+/// Destructor: Takes ownership of the `OptionFocusTarget` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionFocusTarget_delete(object: &mut AzOptionFocusTarget) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
 pub use azul_impl::css::OptionLayoutPoint as AzOptionLayoutPointTT;
 pub use AzOptionLayoutPointTT as AzOptionLayoutPoint;
@@ -5180,6 +5337,10 @@ pub use AzOptionTaskBarIconTT as AzOptionTaskBarIcon;
 pub use azul_core::window::OptionHwndHandle as AzOptionHwndHandleTT;
 pub use AzOptionHwndHandleTT as AzOptionHwndHandle;
 
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+pub use azul_core::window::OptionLogicalRect as AzOptionLogicalRectTT;
+pub use AzOptionLogicalRectTT as AzOptionLogicalRect;
+
 /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
 pub use azul_core::window::OptionLogicalPosition as AzOptionLogicalPositionTT;
 pub use AzOptionLogicalPositionTT as AzOptionLogicalPosition;
@@ -5497,6 +5658,28 @@ mod test_sizes {
         pub run_destructor: bool,
     }
 
+    /// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+    #[repr(C)]
+    pub enum AzGpuPreference {
+        Default,
+        HighPerformance,
+        PowerSaving,
+    }
+
+    /// Limits the GPU memory (texture cache, render targets, images) used by the renderer of a window: every `check_interval_ms`, the memory usage is compared against `max_bytes` and if it is exceeded, the renderer evicts its caches
+    #[repr(C)]
+    pub struct AzGpuMemoryBudget {
+        pub max_bytes: usize,
+        pub check_interval_ms: u32,
+    }
+
+    /// Strategy of the event loop
+    #[repr(C, u8)]
+    pub enum AzEventLoopMode {
+        Blocking,
+        Polling(u32),
+    }
+
     /// Configuration to set which messages should be logged.
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5516,6 +5699,30 @@ mod test_sizes {
         Default,
     }
 
+    /// OpenGL context profile
+    #[repr(C)]
+    pub enum AzGlContextProfile {
+        Core,
+        Compatibility,
+    }
+
+    /// Buffering of the OpenGL surface of a window: double-buffered surfaces never show partially drawn frames, but may wait for the next vsync / compositor cycle; single-buffered surfaces render directly into the visible buffer (lower latency for overlays), at the cost of possible tearing and flickering
+    #[repr(C)]
+    pub enum AzGlBuffering {
+        DoubleBuffered,
+        SingleBufferedFlush,
+        SingleBufferedFinish,
+    }
+
+    /// How rendered frames are presented to the screen: controls vsync (swap interval), tearing and frame pacing
+    #[repr(C, u8)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub enum AzPresentMode {
+        Immediate,
+        Fifo,
+        FrameCapped(u32),
+    }
+
     /// Whether the renderer has VSync enabled
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5525,6 +5732,14 @@ mod test_sizes {
         DontCare,
     }
 
+    /// Whether a window only renders when its content changed or on every frame
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub enum AzRedrawPolicy {
+        OnDemand,
+        Continuous,
+    }
+
     /// Does the renderer render in SRGB color space? By default, azul tries to set it to `Enabled` and falls back to `Disabled` if the OpenGL context can't be initialized properly
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5925,6 +6140,15 @@ mod test_sizes {
         RowResize,
     }
 
+    /// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+    #[repr(C)]
+    pub enum AzWindowCornerPreference {
+        Default,
+        DoNotRound,
+        Round,
+        RoundSmall,
+    }
+
     /// Renderer type of the current windows OpenGL context
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5962,6 +6186,14 @@ mod test_sizes {
         LightMode,
     }
 
+    /// Accessibility preferences of the user, set by the operating system
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzAccessibilitySettings {
+        pub high_contrast: bool,
+        pub prefers_reduced_motion: bool,
+    }
+
     /// Current state of touch devices / touch inputs
     #[repr(C)]
     pub struct AzTouchState {
@@ -6092,6 +6324,15 @@ mod test_sizes {
         pub cb: AzThreadCallbackType,
     }
 
+    /// Re-export of rust-allocated (stack based) `ImageLoaderCallback` struct
+    #[repr(C)]
+    pub struct AzImageLoaderCallback {
+        pub cb: AzImageLoaderCallbackType,
+    }
+
+    /// `AzImageLoaderCallbackType` struct
+    pub type AzImageLoaderCallbackType = extern "C" fn(&mut AzRefAny) -> AzOptionImageRef;
+
     /// `AzThreadCallbackType` struct
     pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
@@ -6105,6 +6346,19 @@ mod test_sizes {
         pub run_destructor: bool,
     }
 
+    /// Handle of a native child window (`HWND` on Windows) that is embedded into the layout, see `NodeData::set_native_child_window`
+    #[repr(C)]
+    pub struct AzNativeChildWindow {
+        pub handle: *mut c_void,
+    }
+
+    /// Role of a node in a title bar that is drawn by the application (similar to the CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+    #[repr(C)]
+    pub enum AzTitleBarRegion {
+        Drag,
+        NoDrag,
+    }
+
     /// When to call a callback action - `On::MouseOver`, `On::MouseOut`, etc.
     #[repr(C)]
     pub enum AzOn {
@@ -7519,6 +7773,13 @@ mod test_sizes {
         pub id: usize,
     }
 
+    /// How a timer with an interval should handle ticks that were missed because the application was busy or in the background
+    #[repr(C)]
+    pub enum AzTimerMissedTickPolicy {
+        Coalesce,
+        CatchUp,
+    }
+
     /// Should a timer terminate or not - used to remove active timers
     #[repr(C)]
     pub enum AzTerminateTimer {
@@ -8270,6 +8531,17 @@ mod test_sizes {
     /// `AzStringPairVecDestructorType` struct
     pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
 
+    /// Re-export of rust-allocated (stack based) `FontWarmupVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzFontWarmupVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzFontWarmupVecDestructorType),
+    }
+
+    /// `AzFontWarmupVecDestructorType` struct
+    pub type AzFontWarmupVecDestructorType = extern "C" fn(&mut AzFontWarmupVec);
+
     /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzNormalizedLinearColorStopVecDestructor {
@@ -8428,6 +8700,14 @@ mod test_sizes {
         pub col: u32,
     }
 
+    /// Radius (in logical pixels) by which the hit-testable area of every node is expanded when hit-testing a pointer position, so that small targets can be hit with imprecise input devices: if the pointer misses all nodes, the nearest node within the radius is hit instead
+    #[repr(C)]
+    pub struct AzHitTestTolerance {
+        pub mouse: AzFloatValue,
+        pub pen: AzFloatValue,
+        pub touch: AzFloatValue,
+    }
+
     /// External system callbacks to get the system time or create / manage threads
     #[repr(C)]
     pub struct AzSystemCallbacks {
@@ -8435,6 +8715,16 @@ mod test_sizes {
         pub get_system_time_fn: AzGetSystemTimeFn,
     }
 
+    /// OpenGL context version, profile and flags requested for a window
+    #[repr(C)]
+    pub struct AzGlContextOptions {
+        pub major_version: u8,
+        pub minor_version: u8,
+        pub profile: AzGlContextProfile,
+        pub debug: bool,
+        pub buffering: AzGlBuffering,
+    }
+
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8442,6 +8732,7 @@ mod test_sizes {
         pub vsync: AzVsync,
         pub srgb: AzSrgb,
         pub hw_accel: AzHwAcceleration,
+        pub present_mode: AzPresentMode,
     }
 
     /// Represents a rectangle in physical pixels (integer units)
@@ -8484,6 +8775,16 @@ mod test_sizes {
         Key(AzVirtualKeyCode),
     }
 
+    /// Placement of a window with a fixed logical size inside the actual window: all rects are in logical units of the window (physical pixels / DPI factor)
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzLetterbox {
+        pub scale: f32,
+        pub content: AzLogicalRect,
+        pub leading_bar: AzLogicalRect,
+        pub trailing_bar: AzLogicalRect,
+    }
+
     /// Boolean flags relating to the current window state
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8499,6 +8800,9 @@ mod test_sizes {
         pub has_blur_behind_window: bool,
         pub smooth_scroll_enabled: bool,
         pub autotab_enabled: bool,
+        pub zoom_shortcuts_enabled: bool,
+        pub ctrl_wheel_zoom_enabled: bool,
+        pub is_shown_in_taskbar: bool,
     }
 
     /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -8588,6 +8892,16 @@ mod test_sizes {
         pub char_index_relative_to_word: usize,
     }
 
+    /// Constraints for measuring a text outside of the DOM (`measure_text`), the values are the resolved values of the CSS properties of a text node
+    #[repr(C)]
+    pub struct AzTextConstraints {
+        pub max_width: AzOptionF32,
+        pub line_height: AzOptionF32,
+        pub letter_spacing: AzOptionF32,
+        pub word_spacing: AzOptionF32,
+        pub tab_width: AzOptionF32,
+    }
+
     /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
     #[repr(C)]
     pub struct AzIFrameCallbackInfo {
@@ -10712,6 +11026,13 @@ mod test_sizes {
         Some(AzSvgDashPattern),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionLogicalRect {
+        None,
+        Some(AzLogicalRect),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
     #[repr(C, u8)]
     pub enum AzOptionLogicalPosition {
@@ -10835,17 +11156,6 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
-    /// Configuration for optional features, such as whether to enable logging or panic hooks
-    #[repr(C)]
-    pub struct AzAppConfig {
-        pub layout_solver: AzLayoutSolver,
-        pub log_level: AzAppLogLevel,
-        pub enable_visual_panic_hook: bool,
-        pub enable_logging_on_panic: bool,
-        pub enable_tab_navigation: bool,
-        pub system_callbacks: AzSystemCallbacks,
-    }
-
     /// Small (16x16x4) window icon, usually shown in the window titlebar
     #[repr(C)]
     pub struct AzSmallWindowIconBytes {
@@ -10880,8 +11190,11 @@ mod test_sizes {
     pub struct AzWindowSize {
         pub dimensions: AzLogicalSize,
         pub dpi: u32,
+        pub content_scale: f32,
         pub min_dimensions: AzOptionLogicalSize,
         pub max_dimensions: AzOptionLogicalSize,
+        pub fixed_logical_size: AzOptionLogicalSize,
+        pub letterbox: AzLetterbox,
     }
 
     /// Current keyboard state, stores what keys / characters have been pressed
@@ -10906,6 +11219,13 @@ mod test_sizes {
         pub scroll_y: AzOptionF32,
     }
 
+    /// Caret and selection of the currently focused text input, in logical coordinates relative to the top left of the window
+    #[repr(C)]
+    pub struct AzTextCaret {
+        pub caret_rect: AzOptionLogicalRect,
+        pub selection_rects: AzLogicalRectVec,
+    }
+
     /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
     #[repr(C)]
     pub struct AzMarshaledLayoutCallback {
@@ -11529,6 +11849,15 @@ mod test_sizes {
         pub window_icon: AzOptionWindowIcon,
         pub taskbar_icon: AzOptionTaskBarIcon,
         pub parent_window: AzOptionHwndHandle,
+        pub alpha_hit_test_threshold: AzOptionF32,
+        pub alpha_hit_test_pass_through: bool,
+        pub corner_preference: AzWindowCornerPreference,
+        pub app_user_model_id: AzOptionString,
+        pub animate_frame_changes: bool,
+        pub shared_gl_context: AzOptionHwndHandle,
+        pub embed_in_parent: bool,
+        pub skip_rendering_when_occluded: bool,
+        pub borderless_shadow: bool,
     }
 
     /// CSD theme of the window title / button controls
@@ -11766,6 +12095,8 @@ mod test_sizes {
         pub max_len: usize,
         pub selection: AzOptionTextInputSelection,
         pub cursor_pos: usize,
+        pub read_only: bool,
+        pub disabled: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `TabHeader` struct
@@ -11944,6 +12275,8 @@ mod test_sizes {
         pub delay: AzOptionDuration,
         pub interval: AzOptionDuration,
         pub timeout: AzOptionDuration,
+        pub missed_tick_policy: AzTimerMissedTickPolicy,
+        pub runs_while_hidden: bool,
         pub callback: AzTimerCallback,
     }
 
@@ -12135,6 +12468,14 @@ mod test_sizes {
         InvalidCharacterData,
     }
 
+    /// Font that is loaded at a given size when a window is created, together with the characters that are rasterized up front (see `AppConfig::font_warmup`)
+    #[repr(C)]
+    pub struct AzFontWarmup {
+        pub font: AzStyleFontFamilyVec,
+        pub size: AzStyleFontSize,
+        pub characters: AzString,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     pub struct AzLinuxWindowOptions {
@@ -12457,6 +12798,15 @@ mod test_sizes {
         pub destructor: AzSvgSimpleNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `FontWarmupVec`
+    #[repr(C)]
+    pub struct AzFontWarmupVec {
+        pub(crate) ptr: *const AzFontWarmup,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzFontWarmupVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
     #[repr(C, u8)]
     pub enum AzOptionCssProperty {
@@ -12471,6 +12821,27 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Configuration for optional features, such as whether to enable logging or panic hooks
+    #[repr(C)]
+    pub struct AzAppConfig {
+        pub layout_solver: AzLayoutSolver,
+        pub log_level: AzAppLogLevel,
+        pub enable_visual_panic_hook: bool,
+        pub enable_logging_on_panic: bool,
+        pub enable_tab_navigation: bool,
+        pub system_callbacks: AzSystemCallbacks,
+        pub event_loop_mode: AzEventLoopMode,
+        pub gpu_preference: AzGpuPreference,
+        pub window_class_name: AzString,
+        pub gpu_memory_budget: AzGpuMemoryBudget,
+        pub app_user_model_id: AzOptionString,
+        pub shader_cache_dir: AzOptionString,
+        pub hit_test_tolerance: AzHitTestTolerance,
+        pub font_warmup: AzFontWarmupVec,
+        pub async_scene_building: bool,
+        pub frame_budget: AzOptionDuration,
+    }
+
     /// Platform-specific window configuration, i.e. WM options that are not cross-platform
     #[repr(C)]
     pub struct AzPlatformSpecificOptions {
@@ -12493,9 +12864,12 @@ mod test_sizes {
         pub mouse_state: AzMouseState,
         pub touch_state: AzTouchState,
         pub ime_position: AzImePosition,
+        pub text_caret: AzTextCaret,
+        pub accessibility: AzAccessibilitySettings,
         pub monitor: AzMonitor,
         pub platform_specific_options: AzPlatformSpecificOptions,
         pub renderer_options: AzRendererOptions,
+        pub redraw_policy: AzRedrawPolicy,
         pub background_color: AzColorU,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
@@ -12658,17 +13032,6 @@ mod test_sizes {
         UnknownToken(AzSvgParseErrorPosition),
     }
 
-    /// Options on how to initially create the window
-    #[repr(C)]
-    pub struct AzWindowCreateOptions {
-        pub state: AzWindowState,
-        pub size_to_content: bool,
-        pub renderer_type: AzOptionRendererOptions,
-        pub theme: AzOptionWindowTheme,
-        pub create_callback: AzOptionCallback,
-        pub hot_reload: bool,
-    }
-
     /// Defines the keyboard input focus target
     #[repr(C, u8)]
     pub enum AzFocusTarget {
@@ -12794,6 +13157,13 @@ mod test_sizes {
         pub destructor: AzNodeDataVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionFocusTarget` struct
+    #[repr(C, u8)]
+    pub enum AzOptionFocusTarget {
+        None,
+        Some(AzFocusTarget),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlError` struct
     #[repr(C, u8)]
     pub enum AzXmlError {
@@ -12816,6 +13186,22 @@ mod test_sizes {
         ParserError(AzXmlParseError),
     }
 
+    /// Options on how to initially create the window
+    #[repr(C)]
+    pub struct AzWindowCreateOptions {
+        pub state: AzWindowState,
+        pub size_to_content: bool,
+        pub renderer_type: AzOptionRendererOptions,
+        pub theme: AzOptionWindowTheme,
+        pub create_callback: AzOptionCallback,
+        pub hot_reload: bool,
+        pub center_on_show: bool,
+        pub gl_context: AzGlContextOptions,
+        pub show_after_first_frame: bool,
+        pub first_frame_callback: AzOptionCallback,
+        pub initial_focus: AzOptionFocusTarget,
+    }
+
     /// Re-export of rust-allocated (stack based) `Dom` struct
     #[repr(C)]
     pub struct AzDom {
@@ -12962,9 +13348,16 @@ mod test_sizes {
     fn test_size() {
          use core::alloc::Layout;
         assert_eq!((Layout::new::<azul_impl::app::AzAppPtr>(), "AzApp"), (Layout::new::<AzApp>(), "AzApp"));
+        assert_eq!((Layout::new::<azul_impl::resources::GpuPreference>(), "AzGpuPreference"), (Layout::new::<AzGpuPreference>(), "AzGpuPreference"));
+        assert_eq!((Layout::new::<azul_impl::resources::GpuMemoryBudget>(), "AzGpuMemoryBudget"), (Layout::new::<AzGpuMemoryBudget>(), "AzGpuMemoryBudget"));
+        assert_eq!((Layout::new::<azul_impl::resources::EventLoopMode>(), "AzEventLoopMode"), (Layout::new::<AzEventLoopMode>(), "AzEventLoopMode"));
         assert_eq!((Layout::new::<azul_impl::resources::AppLogLevel>(), "AzAppLogLevel"), (Layout::new::<AzAppLogLevel>(), "AzAppLogLevel"));
         assert_eq!((Layout::new::<azul_impl::resources::LayoutSolverVersion>(), "AzLayoutSolver"), (Layout::new::<AzLayoutSolver>(), "AzLayoutSolver"));
+        assert_eq!((Layout::new::<azul_core::window::GlContextProfile>(), "AzGlContextProfile"), (Layout::new::<AzGlContextProfile>(), "AzGlContextProfile"));
+        assert_eq!((Layout::new::<azul_core::window::GlBuffering>(), "AzGlBuffering"), (Layout::new::<AzGlBuffering>(), "AzGlBuffering"));
+        assert_eq!((Layout::new::<azul_core::window::PresentMode>(), "AzPresentMode"), (Layout::new::<AzPresentMode>(), "AzPresentMode"));
         assert_eq!((Layout::new::<azul_core::window::Vsync>(), "AzVsync"), (Layout::new::<AzVsync>(), "AzVsync"));
+        assert_eq!((Layout::new::<azul_core::window::RedrawPolicy>(), "AzRedrawPolicy"), (Layout::new::<AzRedrawPolicy>(), "AzRedrawPolicy"));
         assert_eq!((Layout::new::<azul_core::window::Srgb>(), "AzSrgb"), (Layout::new::<AzSrgb>(), "AzSrgb"));
         assert_eq!((Layout::new::<azul_core::window::HwAcceleration>(), "AzHwAcceleration"), (Layout::new::<AzHwAcceleration>(), "AzHwAcceleration"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutPoint>(), "AzLayoutPoint"), (Layout::new::<AzLayoutPoint>(), "AzLayoutPoint"));
@@ -12987,11 +13380,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowFrame>(), "AzWindowFrame"), (Layout::new::<AzWindowFrame>(), "AzWindowFrame"));
         assert_eq!((Layout::new::<azul_core::window::DebugState>(), "AzDebugState"), (Layout::new::<AzDebugState>(), "AzDebugState"));
         assert_eq!((Layout::new::<azul_core::window::MouseCursorType>(), "AzMouseCursorType"), (Layout::new::<AzMouseCursorType>(), "AzMouseCursorType"));
+        assert_eq!((Layout::new::<azul_core::window::WindowCornerPreference>(), "AzWindowCornerPreference"), (Layout::new::<AzWindowCornerPreference>(), "AzWindowCornerPreference"));
         assert_eq!((Layout::new::<azul_core::window::RendererType>(), "AzRendererType"), (Layout::new::<AzRendererType>(), "AzRendererType"));
         assert_eq!((Layout::new::<azul_core::window::MacWindowOptions>(), "AzMacWindowOptions"), (Layout::new::<AzMacWindowOptions>(), "AzMacWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WasmWindowOptions>(), "AzWasmWindowOptions"), (Layout::new::<AzWasmWindowOptions>(), "AzWasmWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::FullScreenMode>(), "AzFullScreenMode"), (Layout::new::<AzFullScreenMode>(), "AzFullScreenMode"));
         assert_eq!((Layout::new::<azul_core::window::WindowTheme>(), "AzWindowTheme"), (Layout::new::<AzWindowTheme>(), "AzWindowTheme"));
+        assert_eq!((Layout::new::<azul_core::window::AccessibilitySettings>(), "AzAccessibilitySettings"), (Layout::new::<AzAccessibilitySettings>(), "AzAccessibilitySettings"));
         assert_eq!((Layout::new::<azul_core::window::TouchState>(), "AzTouchState"), (Layout::new::<AzTouchState>(), "AzTouchState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"), (Layout::new::<AzMarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallbackInner>(), "AzLayoutCallbackInner"), (Layout::new::<AzLayoutCallbackInner>(), "AzLayoutCallbackInner"));
@@ -13008,7 +13403,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallback>(), "AzTimerCallback"), (Layout::new::<AzTimerCallback>(), "AzTimerCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::WriteBackCallback>(), "AzWriteBackCallback"), (Layout::new::<AzWriteBackCallback>(), "AzWriteBackCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::ThreadCallback>(), "AzThreadCallback"), (Layout::new::<AzThreadCallback>(), "AzThreadCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::ImageLoaderCallback>(), "AzImageLoaderCallback"), (Layout::new::<AzImageLoaderCallback>(), "AzImageLoaderCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RefCount>(), "AzRefCount"), (Layout::new::<AzRefCount>(), "AzRefCount"));
+        assert_eq!((Layout::new::<azul_impl::dom::NativeChildWindow>(), "AzNativeChildWindow"), (Layout::new::<AzNativeChildWindow>(), "AzNativeChildWindow"));
+        assert_eq!((Layout::new::<azul_impl::dom::TitleBarRegion>(), "AzTitleBarRegion"), (Layout::new::<AzTitleBarRegion>(), "AzTitleBarRegion"));
         assert_eq!((Layout::new::<azul_impl::dom::On>(), "AzOn"), (Layout::new::<AzOn>(), "AzOn"));
         assert_eq!((Layout::new::<azul_impl::dom::HoverEventFilter>(), "AzHoverEventFilter"), (Layout::new::<AzHoverEventFilter>(), "AzHoverEventFilter"));
         assert_eq!((Layout::new::<azul_impl::dom::FocusEventFilter>(), "AzFocusEventFilter"), (Layout::new::<AzFocusEventFilter>(), "AzFocusEventFilter"));
@@ -13146,6 +13544,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::SystemTimeDiff>(), "AzSystemTimeDiff"), (Layout::new::<AzSystemTimeDiff>(), "AzSystemTimeDiff"));
         assert_eq!((Layout::new::<azul_impl::task::SystemTickDiff>(), "AzSystemTickDiff"), (Layout::new::<AzSystemTickDiff>(), "AzSystemTickDiff"));
         assert_eq!((Layout::new::<azul_impl::task::TimerId>(), "AzTimerId"), (Layout::new::<AzTimerId>(), "AzTimerId"));
+        assert_eq!((Layout::new::<azul_impl::task::TimerMissedTickPolicy>(), "AzTimerMissedTickPolicy"), (Layout::new::<AzTimerMissedTickPolicy>(), "AzTimerMissedTickPolicy"));
         assert_eq!((Layout::new::<azul_impl::task::TerminateTimer>(), "AzTerminateTimer"), (Layout::new::<AzTerminateTimer>(), "AzTerminateTimer"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadId>(), "AzThreadId"), (Layout::new::<AzThreadId>(), "AzThreadId"));
         assert_eq!((Layout::new::<azul_impl::task::Thread>(), "AzThread"), (Layout::new::<AzThread>(), "AzThread"));
@@ -13218,6 +13617,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::gl::GLintVecDestructor>(), "AzGLintVecDestructor"), (Layout::new::<AzGLintVecDestructor>(), "AzGLintVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StringVecDestructor>(), "AzStringVecDestructor"), (Layout::new::<AzStringVecDestructor>(), "AzStringVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::StringPairVecDestructor>(), "AzStringPairVecDestructor"), (Layout::new::<AzStringPairVecDestructor>(), "AzStringPairVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::resources::FontWarmupVecDestructor>(), "AzFontWarmupVecDestructor"), (Layout::new::<AzFontWarmupVecDestructor>(), "AzFontWarmupVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::NormalizedLinearColorStopVecDestructor>(), "AzNormalizedLinearColorStopVecDestructor"), (Layout::new::<AzNormalizedLinearColorStopVecDestructor>(), "AzNormalizedLinearColorStopVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::NormalizedRadialColorStopVecDestructor>(), "AzNormalizedRadialColorStopVecDestructor"), (Layout::new::<AzNormalizedRadialColorStopVecDestructor>(), "AzNormalizedRadialColorStopVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeIdVecDestructor>(), "AzNodeIdVecDestructor"), (Layout::new::<AzNodeIdVecDestructor>(), "AzNodeIdVecDestructor"));
@@ -13236,12 +13636,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionChar>(), "AzOptionChar"), (Layout::new::<AzOptionChar>(), "AzOptionChar"));
         assert_eq!((Layout::new::<azul_impl::gl::OptionUsize>(), "AzOptionUsize"), (Layout::new::<AzOptionUsize>(), "AzOptionUsize"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextPos>(), "AzSvgParseErrorPosition"), (Layout::new::<AzSvgParseErrorPosition>(), "AzSvgParseErrorPosition"));
+        assert_eq!((Layout::new::<azul_impl::resources::HitTestTolerance>(), "AzHitTestTolerance"), (Layout::new::<AzHitTestTolerance>(), "AzHitTestTolerance"));
         assert_eq!((Layout::new::<azul_impl::task::ExternalSystemCallbacks>(), "AzSystemCallbacks"), (Layout::new::<AzSystemCallbacks>(), "AzSystemCallbacks"));
+        assert_eq!((Layout::new::<azul_core::window::GlContextOptions>(), "AzGlContextOptions"), (Layout::new::<AzGlContextOptions>(), "AzGlContextOptions"));
         assert_eq!((Layout::new::<azul_core::window::RendererOptions>(), "AzRendererOptions"), (Layout::new::<AzRendererOptions>(), "AzRendererOptions"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutRect>(), "AzLayoutRect"), (Layout::new::<AzLayoutRect>(), "AzLayoutRect"));
         assert_eq!((Layout::new::<azul_core::window::RawWindowHandle>(), "AzRawWindowHandle"), (Layout::new::<AzRawWindowHandle>(), "AzRawWindowHandle"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRect>(), "AzLogicalRect"), (Layout::new::<AzLogicalRect>(), "AzLogicalRect"));
         assert_eq!((Layout::new::<azul_core::window::AcceleratorKey>(), "AzAcceleratorKey"), (Layout::new::<AzAcceleratorKey>(), "AzAcceleratorKey"));
+        assert_eq!((Layout::new::<azul_core::window::Letterbox>(), "AzLetterbox"), (Layout::new::<AzLetterbox>(), "AzLetterbox"));
         assert_eq!((Layout::new::<azul_core::window::WindowFlags>(), "AzWindowFlags"), (Layout::new::<AzWindowFlags>(), "AzWindowFlags"));
         assert_eq!((Layout::new::<azul_core::window::CursorPosition>(), "AzCursorPosition"), (Layout::new::<AzCursorPosition>(), "AzCursorPosition"));
         assert_eq!((Layout::new::<azul_core::window::WindowPosition>(), "AzWindowPosition"), (Layout::new::<AzWindowPosition>(), "AzWindowPosition"));
@@ -13252,6 +13655,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::HidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"), (Layout::new::<AzHidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineGlyph>(), "AzInlineGlyph"), (Layout::new::<AzInlineGlyph>(), "AzInlineGlyph"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextHit>(), "AzInlineTextHit"), (Layout::new::<AzInlineTextHit>(), "AzInlineTextHit"));
+        assert_eq!((Layout::new::<azul_impl::ui_solver::TextConstraints>(), "AzTextConstraints"), (Layout::new::<AzTextConstraints>(), "AzTextConstraints"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallbackInfo>(), "AzIFrameCallbackInfo"), (Layout::new::<AzIFrameCallbackInfo>(), "AzIFrameCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackReturn>(), "AzTimerCallbackReturn"), (Layout::new::<AzTimerCallbackReturn>(), "AzTimerCallbackReturn"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RefAny>(), "AzRefAny"), (Layout::new::<AzRefAny>(), "AzRefAny"));
@@ -13509,6 +13913,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionDomNodeId>(), "AzOptionDomNodeId"), (Layout::new::<AzOptionDomNodeId>(), "AzOptionDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::css::OptionColorU>(), "AzOptionColorU"), (Layout::new::<AzOptionColorU>(), "AzOptionColorU"));
         assert_eq!((Layout::new::<azul_impl::svg::OptionSvgDashPattern>(), "AzOptionSvgDashPattern"), (Layout::new::<AzOptionSvgDashPattern>(), "AzOptionSvgDashPattern"));
+        assert_eq!((Layout::new::<azul_core::window::OptionLogicalRect>(), "AzOptionLogicalRect"), (Layout::new::<AzOptionLogicalRect>(), "AzOptionLogicalRect"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalPosition>(), "AzOptionLogicalPosition"), (Layout::new::<AzOptionLogicalPosition>(), "AzOptionLogicalPosition"));
         assert_eq!((Layout::new::<azul_core::window::OptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"), (Layout::new::<AzOptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseCursorType>(), "AzOptionMouseCursorType"), (Layout::new::<AzOptionMouseCursorType>(), "AzOptionMouseCursorType"));
@@ -13526,7 +13931,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::InvalidCharMultipleError>(), "AzInvalidCharMultipleError"), (Layout::new::<AzInvalidCharMultipleError>(), "AzInvalidCharMultipleError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidQuoteError>(), "AzInvalidQuoteError"), (Layout::new::<AzInvalidQuoteError>(), "AzInvalidQuoteError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidSpaceError>(), "AzInvalidSpaceError"), (Layout::new::<AzInvalidSpaceError>(), "AzInvalidSpaceError"));
-        assert_eq!((Layout::new::<azul_impl::resources::AppConfig>(), "AzAppConfig"), (Layout::new::<AzAppConfig>(), "AzAppConfig"));
         assert_eq!((Layout::new::<azul_core::window::SmallWindowIconBytes>(), "AzSmallWindowIconBytes"), (Layout::new::<AzSmallWindowIconBytes>(), "AzSmallWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::LargeWindowIconBytes>(), "AzLargeWindowIconBytes"), (Layout::new::<AzLargeWindowIconBytes>(), "AzLargeWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
@@ -13534,6 +13938,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
         assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
+        assert_eq!((Layout::new::<azul_core::window::TextCaret>(), "AzTextCaret"), (Layout::new::<AzTextCaret>(), "AzTextCaret"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::ResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"), (Layout::new::<AzResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"));
@@ -13664,6 +14069,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
+        assert_eq!((Layout::new::<azul_impl::resources::FontWarmup>(), "AzFontWarmup"), (Layout::new::<AzFontWarmup>(), "AzFontWarmup"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
@@ -13692,8 +14098,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVec>(), "AzCssPropertyVec"), (Layout::new::<AzCssPropertyVec>(), "AzCssPropertyVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"), (Layout::new::<AzSvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"));
+        assert_eq!((Layout::new::<azul_impl::resources::FontWarmupVec>(), "AzFontWarmupVec"), (Layout::new::<AzFontWarmupVec>(), "AzFontWarmupVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
+        assert_eq!((Layout::new::<azul_impl::resources::AppConfig>(), "AzAppConfig"), (Layout::new::<AzAppConfig>(), "AzAppConfig"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::CallbackInfo>(), "AzCallbackInfo"), (Layout::new::<AzCallbackInfo>(), "AzCallbackInfo"));
@@ -13710,7 +14118,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
-        assert_eq!((Layout::new::<azul_core::window::WindowCreateOptions>(), "AzWindowCreateOptions"), (Layout::new::<AzWindowCreateOptions>(), "AzWindowCreateOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::FocusTarget>(), "AzFocusTarget"), (Layout::new::<AzFocusTarget>(), "AzFocusTarget"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeData>(), "AzNodeData"), (Layout::new::<AzNodeData>(), "AzNodeData"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclaration>(), "AzCssDeclaration"), (Layout::new::<AzCssDeclaration>(), "AzCssDeclaration"));
@@ -13725,7 +14132,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"), (Layout::new::<AzNodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionFocusTarget>(), "AzOptionFocusTarget"), (Layout::new::<AzOptionFocusTarget>(), "AzOptionFocusTarget"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
+        assert_eq!((Layout::new::<azul_core::window::WindowCreateOptions>(), "AzWindowCreateOptions"), (Layout::new::<AzWindowCreateOptions>(), "AzWindowCreateOptions"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlock>(), "AzCssRuleBlock"), (Layout::new::<AzCssRuleBlock>(), "AzCssRuleBlock"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabContent>(), "AzTabContent"), (Layout::new::<AzTabContent>(), "AzTabContent"));
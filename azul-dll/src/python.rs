@@ -723,6 +723,28 @@ pub struct AzApp {
     pub run_destructor: bool,
 }
 
+/// GPU selection on systems with multiple GPUs (laptops with hybrid graphics)
+#[repr(C)]
+pub enum AzGpuPreference {
+    Default,
+    HighPerformance,
+    PowerSaving,
+}
+
+/// Limits the GPU memory (texture cache, render targets, images) used by the renderer of a window: every `check_interval_ms`, the memory usage is compared against `max_bytes` and if it is exceeded, the renderer evicts its caches
+#[repr(C)]
+pub struct AzGpuMemoryBudget {
+    pub max_bytes: usize,
+    pub check_interval_ms: u32,
+}
+
+/// Strategy of the event loop
+#[repr(C, u8)]
+pub enum AzEventLoopMode {
+    Blocking,
+    Polling(u32),
+}
+
 /// Configuration to set which messages should be logged.
 #[repr(C)]
 pub enum AzAppLogLevel {
@@ -740,6 +762,29 @@ pub enum AzLayoutSolver {
     Default,
 }
 
+/// OpenGL context profile
+#[repr(C)]
+pub enum AzGlContextProfile {
+    Core,
+    Compatibility,
+}
+
+/// Buffering of the OpenGL surface of a window: double-buffered surfaces never show partially drawn frames, but may wait for the next vsync / compositor cycle; single-buffered surfaces render directly into the visible buffer (lower latency for overlays), at the cost of possible tearing and flickering
+#[repr(C)]
+pub enum AzGlBuffering {
+    DoubleBuffered,
+    SingleBufferedFlush,
+    SingleBufferedFinish,
+}
+
+/// How rendered frames are presented to the screen: controls vsync (swap interval), tearing and frame pacing
+#[repr(C, u8)]
+pub enum AzPresentMode {
+    Immediate,
+    Fifo,
+    FrameCapped(u32),
+}
+
 /// Whether the renderer has VSync enabled
 #[repr(C)]
 pub enum AzVsync {
@@ -748,6 +793,13 @@ pub enum AzVsync {
     DontCare,
 }
 
+/// Whether a window only renders when its content changed or on every frame
+#[repr(C)]
+pub enum AzRedrawPolicy {
+    OnDemand,
+    Continuous,
+}
+
 /// Does the renderer render in SRGB color space? By default, azul tries to set it to `Enabled` and falls back to `Disabled` if the OpenGL context can't be initialized properly
 #[repr(C)]
 pub enum AzSrgb {
@@ -1135,6 +1187,15 @@ pub enum AzMouseCursorType {
     RowResize,
 }
 
+/// Corner style of a window, see `DWMWA_WINDOW_CORNER_PREFERENCE`
+#[repr(C)]
+pub enum AzWindowCornerPreference {
+    Default,
+    DoNotRound,
+    Round,
+    RoundSmall,
+}
+
 /// Renderer type of the current windows OpenGL context
 #[repr(C)]
 pub enum AzRendererType {
@@ -1170,6 +1231,13 @@ pub enum AzWindowTheme {
     LightMode,
 }
 
+/// Accessibility preferences of the user, set by the operating system
+#[repr(C)]
+pub struct AzAccessibilitySettings {
+    pub high_contrast: bool,
+    pub prefers_reduced_motion: bool,
+}
+
 /// Current state of touch devices / touch inputs
 #[repr(C)]
 pub struct AzTouchState {
@@ -1296,6 +1364,15 @@ pub struct AzThreadCallback {
     pub cb: AzThreadCallbackType,
 }
 
+/// Re-export of rust-allocated (stack based) `ImageLoaderCallback` struct
+#[repr(C)]
+pub struct AzImageLoaderCallback {
+    pub cb: AzImageLoaderCallbackType,
+}
+
+/// `AzImageLoaderCallbackType` struct
+pub type AzImageLoaderCallbackType = extern "C" fn(&mut AzRefAny) -> AzOptionImageRef;
+
 /// `AzThreadCallbackType` struct
 pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
@@ -1309,6 +1386,19 @@ pub struct AzRefCount {
     pub run_destructor: bool,
 }
 
+/// Handle of a native child window (`HWND` on Windows) that is embedded into the layout, see `NodeData::set_native_child_window`
+#[repr(C)]
+pub struct AzNativeChildWindow {
+    pub handle: *mut c_void,
+}
+
+/// Role of a node in a title bar that is drawn by the application (similar to the CSS `-webkit-app-region` property), see `NodeData::set_title_bar_region`
+#[repr(C)]
+pub enum AzTitleBarRegion {
+    Drag,
+    NoDrag,
+}
+
 /// When to call a callback action - `On::MouseOver`, `On::MouseOut`, etc.
 #[repr(C)]
 pub enum AzOn {
@@ -2699,6 +2789,13 @@ pub struct AzTimerId {
     pub id: usize,
 }
 
+/// How a timer with an interval should handle ticks that were missed because the application was busy or in the background
+#[repr(C)]
+pub enum AzTimerMissedTickPolicy {
+    Coalesce,
+    CatchUp,
+}
+
 /// Should a timer terminate or not - used to remove active timers
 #[repr(C)]
 pub enum AzTerminateTimer {
@@ -3450,6 +3547,17 @@ pub enum AzStringPairVecDestructor {
 /// `AzStringPairVecDestructorType` struct
 pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
 
+/// Re-export of rust-allocated (stack based) `FontWarmupVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzFontWarmupVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzFontWarmupVecDestructorType),
+}
+
+/// `AzFontWarmupVecDestructorType` struct
+pub type AzFontWarmupVecDestructorType = extern "C" fn(&mut AzFontWarmupVec);
+
 /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzNormalizedLinearColorStopVecDestructor {
@@ -3608,6 +3716,14 @@ pub struct AzSvgParseErrorPosition {
     pub col: u32,
 }
 
+/// Radius (in logical pixels) by which the hit-testable area of every node is expanded when hit-testing a pointer position, so that small targets can be hit with imprecise input devices: if the pointer misses all nodes, the nearest node within the radius is hit instead
+#[repr(C)]
+pub struct AzHitTestTolerance {
+    pub mouse: AzFloatValue,
+    pub pen: AzFloatValue,
+    pub touch: AzFloatValue,
+}
+
 /// External system callbacks to get the system time or create / manage threads
 #[repr(C)]
 pub struct AzSystemCallbacks {
@@ -3615,12 +3731,23 @@ pub struct AzSystemCallbacks {
     pub get_system_time_fn: AzGetSystemTimeFn,
 }
 
+/// OpenGL context version, profile and flags requested for a window
+#[repr(C)]
+pub struct AzGlContextOptions {
+    pub major_version: u8,
+    pub minor_version: u8,
+    pub profile: AzGlContextProfileEnumWrapper,
+    pub debug: bool,
+    pub buffering: AzGlBufferingEnumWrapper,
+}
+
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
 #[repr(C)]
 pub struct AzRendererOptions {
     pub vsync: AzVsyncEnumWrapper,
     pub srgb: AzSrgbEnumWrapper,
     pub hw_accel: AzHwAccelerationEnumWrapper,
+    pub present_mode: AzPresentModeEnumWrapper,
 }
 
 /// Represents a rectangle in physical pixels (integer units)
@@ -3660,6 +3787,15 @@ pub enum AzAcceleratorKey {
     Key(AzVirtualKeyCode),
 }
 
+/// Placement of a window with a fixed logical size inside the actual window: all rects are in logical units of the window (physical pixels / DPI factor)
+#[repr(C)]
+pub struct AzLetterbox {
+    pub scale: f32,
+    pub content: AzLogicalRect,
+    pub leading_bar: AzLogicalRect,
+    pub trailing_bar: AzLogicalRect,
+}
+
 /// Boolean flags relating to the current window state
 #[repr(C)]
 pub struct AzWindowFlags {
@@ -3674,6 +3810,9 @@ pub struct AzWindowFlags {
     pub has_blur_behind_window: bool,
     pub smooth_scroll_enabled: bool,
     pub autotab_enabled: bool,
+    pub zoom_shortcuts_enabled: bool,
+    pub ctrl_wheel_zoom_enabled: bool,
+    pub is_shown_in_taskbar: bool,
 }
 
 /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -3758,6 +3897,16 @@ pub struct AzInlineTextHit {
     pub char_index_relative_to_word: usize,
 }
 
+/// Constraints for measuring a text outside of the DOM (`measure_text`), the values are the resolved values of the CSS properties of a text node
+#[repr(C)]
+pub struct AzTextConstraints {
+    pub max_width: AzOptionF32EnumWrapper,
+    pub line_height: AzOptionF32EnumWrapper,
+    pub letter_spacing: AzOptionF32EnumWrapper,
+    pub word_spacing: AzOptionF32EnumWrapper,
+    pub tab_width: AzOptionF32EnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
 #[repr(C)]
 pub struct AzIFrameCallbackInfo {
@@ -5864,6 +6013,13 @@ pub enum AzOptionSvgDashPattern {
     Some(AzSvgDashPattern),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+#[repr(C, u8)]
+pub enum AzOptionLogicalRect {
+    None,
+    Some(AzLogicalRect),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
 #[repr(C, u8)]
 pub enum AzOptionLogicalPosition {
@@ -5985,17 +6141,6 @@ pub struct AzInvalidSpaceError {
     pub pos: AzSvgParseErrorPosition,
 }
 
-/// Configuration for optional features, such as whether to enable logging or panic hooks
-#[repr(C)]
-pub struct AzAppConfig {
-    pub layout_solver: AzLayoutSolverEnumWrapper,
-    pub log_level: AzAppLogLevelEnumWrapper,
-    pub enable_visual_panic_hook: bool,
-    pub enable_logging_on_panic: bool,
-    pub enable_tab_navigation: bool,
-    pub system_callbacks: AzSystemCallbacks,
-}
-
 /// Small (16x16x4) window icon, usually shown in the window titlebar
 #[repr(C)]
 pub struct AzSmallWindowIconBytes {
@@ -6029,8 +6174,11 @@ pub struct AzTaskBarIcon {
 pub struct AzWindowSize {
     pub dimensions: AzLogicalSize,
     pub dpi: u32,
+    pub content_scale: f32,
     pub min_dimensions: AzOptionLogicalSizeEnumWrapper,
     pub max_dimensions: AzOptionLogicalSizeEnumWrapper,
+    pub fixed_logical_size: AzOptionLogicalSizeEnumWrapper,
+    pub letterbox: AzLetterbox,
 }
 
 /// Current keyboard state, stores what keys / characters have been pressed
@@ -6055,6 +6203,13 @@ pub struct AzMouseState {
     pub scroll_y: AzOptionF32EnumWrapper,
 }
 
+/// Caret and selection of the currently focused text input, in logical coordinates relative to the top left of the window
+#[repr(C)]
+pub struct AzTextCaret {
+    pub caret_rect: AzOptionLogicalRectEnumWrapper,
+    pub selection_rects: AzLogicalRectVec,
+}
+
 /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
 #[repr(C)]
 pub struct AzMarshaledLayoutCallback {
@@ -6675,6 +6830,15 @@ pub struct AzWindowsWindowOptions {
     pub window_icon: AzOptionWindowIconEnumWrapper,
     pub taskbar_icon: AzOptionTaskBarIconEnumWrapper,
     pub parent_window: AzOptionHwndHandleEnumWrapper,
+    pub alpha_hit_test_threshold: AzOptionF32EnumWrapper,
+    pub alpha_hit_test_pass_through: bool,
+    pub corner_preference: AzWindowCornerPreferenceEnumWrapper,
+    pub app_user_model_id: AzOptionStringEnumWrapper,
+    pub animate_frame_changes: bool,
+    pub shared_gl_context: AzOptionHwndHandleEnumWrapper,
+    pub embed_in_parent: bool,
+    pub skip_rendering_when_occluded: bool,
+    pub borderless_shadow: bool,
 }
 
 /// CSD theme of the window title / button controls
@@ -6911,6 +7075,8 @@ pub struct AzTextInputState {
     pub max_len: usize,
     pub selection: AzOptionTextInputSelectionEnumWrapper,
     pub cursor_pos: usize,
+    pub read_only: bool,
+    pub disabled: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `TabHeader` struct
@@ -7085,6 +7251,8 @@ pub struct AzTimer {
     pub delay: AzOptionDurationEnumWrapper,
     pub interval: AzOptionDurationEnumWrapper,
     pub timeout: AzOptionDurationEnumWrapper,
+    pub missed_tick_policy: AzTimerMissedTickPolicyEnumWrapper,
+    pub runs_while_hidden: bool,
     pub callback: AzTimerCallback,
 }
 
@@ -7276,6 +7444,14 @@ pub enum AzXmlStreamError {
     InvalidCharacterData,
 }
 
+/// Font that is loaded at a given size when a window is created, together with the characters that are rasterized up front (see `AppConfig::font_warmup`)
+#[repr(C)]
+pub struct AzFontWarmup {
+    pub font: AzStyleFontFamilyVec,
+    pub size: AzStyleFontSize,
+    pub characters: AzString,
+}
+
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 #[repr(C)]
 pub struct AzLinuxWindowOptions {
@@ -7595,6 +7771,15 @@ pub struct AzSvgSimpleNodeVec {
     pub destructor: AzSvgSimpleNodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `FontWarmupVec`
+#[repr(C)]
+pub struct AzFontWarmupVec {
+    pub(crate) ptr: *const AzFontWarmup,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzFontWarmupVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 #[repr(C, u8)]
 pub enum AzOptionCssProperty {
@@ -7609,6 +7794,27 @@ pub struct AzXmlTextError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Configuration for optional features, such as whether to enable logging or panic hooks
+#[repr(C)]
+pub struct AzAppConfig {
+    pub layout_solver: AzLayoutSolverEnumWrapper,
+    pub log_level: AzAppLogLevelEnumWrapper,
+    pub enable_visual_panic_hook: bool,
+    pub enable_logging_on_panic: bool,
+    pub enable_tab_navigation: bool,
+    pub system_callbacks: AzSystemCallbacks,
+    pub event_loop_mode: AzEventLoopModeEnumWrapper,
+    pub gpu_preference: AzGpuPreferenceEnumWrapper,
+    pub window_class_name: AzString,
+    pub gpu_memory_budget: AzGpuMemoryBudget,
+    pub app_user_model_id: AzOptionStringEnumWrapper,
+    pub shader_cache_dir: AzOptionStringEnumWrapper,
+    pub hit_test_tolerance: AzHitTestTolerance,
+    pub font_warmup: AzFontWarmupVec,
+    pub async_scene_building: bool,
+    pub frame_budget: AzOptionDurationEnumWrapper,
+}
+
 /// Platform-specific window configuration, i.e. WM options that are not cross-platform
 #[repr(C)]
 pub struct AzPlatformSpecificOptions {
@@ -7631,9 +7837,12 @@ pub struct AzWindowState {
     pub mouse_state: AzMouseState,
     pub touch_state: AzTouchState,
     pub ime_position: AzImePositionEnumWrapper,
+    pub text_caret: AzTextCaret,
+    pub accessibility: AzAccessibilitySettings,
     pub monitor: AzMonitor,
     pub platform_specific_options: AzPlatformSpecificOptions,
     pub renderer_options: AzRendererOptions,
+    pub redraw_policy: AzRedrawPolicyEnumWrapper,
     pub background_color: AzColorU,
     pub layout_callback: AzLayoutCallbackEnumWrapper,
     pub close_callback: AzOptionCallbackEnumWrapper,
@@ -7796,17 +8005,6 @@ pub enum AzXmlParseError {
     UnknownToken(AzSvgParseErrorPosition),
 }
 
-/// Options on how to initially create the window
-#[repr(C)]
-pub struct AzWindowCreateOptions {
-    pub state: AzWindowState,
-    pub size_to_content: bool,
-    pub renderer_type: AzOptionRendererOptionsEnumWrapper,
-    pub theme: AzOptionWindowThemeEnumWrapper,
-    pub create_callback: AzOptionCallbackEnumWrapper,
-    pub hot_reload: bool,
-}
-
 /// Defines the keyboard input focus target
 #[repr(C, u8)]
 pub enum AzFocusTarget {
@@ -7932,6 +8130,13 @@ pub struct AzNodeDataVec {
     pub destructor: AzNodeDataVecDestructorEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `OptionFocusTarget` struct
+#[repr(C, u8)]
+pub enum AzOptionFocusTarget {
+    None,
+    Some(AzFocusTarget),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlError` struct
 #[repr(C, u8)]
 pub enum AzXmlError {
@@ -7954,6 +8159,22 @@ pub enum AzXmlError {
     ParserError(AzXmlParseError),
 }
 
+/// Options on how to initially create the window
+#[repr(C)]
+pub struct AzWindowCreateOptions {
+    pub state: AzWindowState,
+    pub size_to_content: bool,
+    pub renderer_type: AzOptionRendererOptionsEnumWrapper,
+    pub theme: AzOptionWindowThemeEnumWrapper,
+    pub create_callback: AzOptionCallbackEnumWrapper,
+    pub hot_reload: bool,
+    pub center_on_show: bool,
+    pub gl_context: AzGlContextOptions,
+    pub show_after_first_frame: bool,
+    pub first_frame_callback: AzOptionCallbackEnumWrapper,
+    pub initial_focus: AzOptionFocusTargetEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Dom` struct
 #[repr(C)]
 pub struct AzDom {
@@ -8093,6 +8314,18 @@ pub struct AzCss {
     pub stylesheets: AzStylesheetVec,
 }
 
+/// `AzGpuPreferenceEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGpuPreferenceEnumWrapper {
+    pub inner: AzGpuPreference,
+}
+
+/// `AzEventLoopModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzEventLoopModeEnumWrapper {
+    pub inner: AzEventLoopMode,
+}
+
 /// `AzAppLogLevelEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAppLogLevelEnumWrapper {
@@ -8105,12 +8338,36 @@ pub struct AzLayoutSolverEnumWrapper {
     pub inner: AzLayoutSolver,
 }
 
+/// `AzGlContextProfileEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGlContextProfileEnumWrapper {
+    pub inner: AzGlContextProfile,
+}
+
+/// `AzGlBufferingEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGlBufferingEnumWrapper {
+    pub inner: AzGlBuffering,
+}
+
+/// `AzPresentModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzPresentModeEnumWrapper {
+    pub inner: AzPresentMode,
+}
+
 /// `AzVsyncEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzVsyncEnumWrapper {
     pub inner: AzVsync,
 }
 
+/// `AzRedrawPolicyEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRedrawPolicyEnumWrapper {
+    pub inner: AzRedrawPolicy,
+}
+
 /// `AzSrgbEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzSrgbEnumWrapper {
@@ -8147,6 +8404,12 @@ pub struct AzMouseCursorTypeEnumWrapper {
     pub inner: AzMouseCursorType,
 }
 
+/// `AzWindowCornerPreferenceEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzWindowCornerPreferenceEnumWrapper {
+    pub inner: AzWindowCornerPreference,
+}
+
 /// `AzRendererTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzRendererTypeEnumWrapper {
@@ -8189,6 +8452,12 @@ pub struct AzAnimationRepeatCountEnumWrapper {
     pub inner: AzAnimationRepeatCount,
 }
 
+/// `AzTitleBarRegionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTitleBarRegionEnumWrapper {
+    pub inner: AzTitleBarRegion,
+}
+
 /// `AzOnEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOnEnumWrapper {
@@ -8531,6 +8800,12 @@ pub struct AzMsgBoxOkCancelEnumWrapper {
     pub inner: AzMsgBoxOkCancel,
 }
 
+/// `AzTimerMissedTickPolicyEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTimerMissedTickPolicyEnumWrapper {
+    pub inner: AzTimerMissedTickPolicy,
+}
+
 /// `AzTerminateTimerEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTerminateTimerEnumWrapper {
@@ -8879,6 +9154,12 @@ pub struct AzStringPairVecDestructorEnumWrapper {
     pub inner: AzStringPairVecDestructor,
 }
 
+/// `AzFontWarmupVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzFontWarmupVecDestructorEnumWrapper {
+    pub inner: AzFontWarmupVecDestructor,
+}
+
 /// `AzNormalizedLinearColorStopVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNormalizedLinearColorStopVecDestructorEnumWrapper {
@@ -9731,6 +10012,12 @@ pub struct AzOptionSvgDashPatternEnumWrapper {
     pub inner: AzOptionSvgDashPattern,
 }
 
+/// `AzOptionLogicalRectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionLogicalRectEnumWrapper {
+    pub inner: AzOptionLogicalRect,
+}
+
 /// `AzOptionLogicalPositionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionLogicalPositionEnumWrapper {
@@ -10157,6 +10444,12 @@ pub struct AzCssDeclarationEnumWrapper {
     pub inner: AzCssDeclaration,
 }
 
+/// `AzOptionFocusTargetEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionFocusTargetEnumWrapper {
+    pub inner: AzOptionFocusTarget,
+}
+
 /// `AzXmlErrorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzXmlErrorEnumWrapper {
@@ -10204,6 +10497,7 @@ unsafe impl Send for AzWaylandHandle { }
 unsafe impl Send for AzWindowsHandle { }
 unsafe impl Send for AzAndroidHandle { }
 unsafe impl Send for AzRefCount { }
+unsafe impl Send for AzNativeChildWindow { }
 unsafe impl Send for AzCssPropertyCache { }
 unsafe impl Send for AzGlVoidPtrConst { }
 unsafe impl Send for AzGlVoidPtrMut { }
@@ -10306,6 +10600,7 @@ unsafe impl Send for AzInlineLineVec { }
 unsafe impl Send for AzCssPropertyVec { }
 unsafe impl Send for AzSvgMultiPolygonVec { }
 unsafe impl Send for AzSvgSimpleNodeVec { }
+unsafe impl Send for AzFontWarmupVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
@@ -10319,9 +10614,16 @@ unsafe impl Send for AzStylesheetVec { }
 
 // Python objects must implement Clone at minimum
 impl Clone for AzApp { fn clone(&self) -> Self { let r: &azul_impl::app::AzAppPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGpuPreferenceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::GpuPreference = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGpuMemoryBudget { fn clone(&self) -> Self { let r: &azul_impl::resources::GpuMemoryBudget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzEventLoopModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::EventLoopMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAppLogLevelEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::AppLogLevel = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutSolverEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::LayoutSolverVersion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGlContextProfileEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::GlContextProfile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGlBufferingEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::GlBuffering = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPresentModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::PresentMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVsyncEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Vsync = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRedrawPolicyEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RedrawPolicy = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSrgbEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Srgb = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHwAccelerationEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::HwAcceleration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutPoint { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutPoint = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10344,11 +10646,13 @@ impl Clone for AzVirtualKeyCodeEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzWindowFrameEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowFrame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugState { fn clone(&self) -> Self { let r: &azul_core::window::DebugState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWindowCornerPreferenceEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowCornerPreference = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRendererTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RendererType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMacWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::MacWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWasmWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WasmWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFullScreenModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::FullScreenMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilitySettings { fn clone(&self) -> Self { let r: &azul_core::window::AccessibilitySettings = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTouchState { fn clone(&self) -> Self { let r: &azul_core::window::TouchState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10365,7 +10669,10 @@ impl Clone for AzRenderImageCallback { fn clone(&self) -> Self { let r: &azul_im
 impl Clone for AzTimerCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWriteBackCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::WriteBackCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ThreadCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImageLoaderCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ImageLoaderCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefCount { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RefCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNativeChildWindow { fn clone(&self) -> Self { let r: &azul_impl::dom::NativeChildWindow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTitleBarRegionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TitleBarRegion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOnEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::On = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHoverEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::HoverEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFocusEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::FocusEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10503,6 +10810,7 @@ impl Clone for AzSystemTick { fn clone(&self) -> Self { let r: &azul_impl::task:
 impl Clone for AzSystemTimeDiff { fn clone(&self) -> Self { let r: &azul_impl::task::SystemTimeDiff = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSystemTickDiff { fn clone(&self) -> Self { let r: &azul_impl::task::SystemTickDiff = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerId { fn clone(&self) -> Self { let r: &azul_impl::task::TimerId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTimerMissedTickPolicyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::TimerMissedTickPolicy = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTerminateTimerEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::TerminateTimer = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadId { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThread { fn clone(&self) -> Self { let r: &azul_impl::task::Thread = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10575,6 +10883,7 @@ impl Clone for AzGLuintVecDestructorEnumWrapper { fn clone(&self) -> Self { let
 impl Clone for AzGLintVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::GLintVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StringVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPairVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::StringPairVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFontWarmupVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::FontWarmupVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNormalizedLinearColorStopVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NormalizedLinearColorStopVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNormalizedRadialColorStopVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NormalizedRadialColorStopVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeIdVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10593,12 +10902,15 @@ impl Clone for AzOptionF32EnumWrapper { fn clone(&self) -> Self { let r: &azul_i
 impl Clone for AzOptionCharEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionChar = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionUsizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::OptionUsize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgParseErrorPosition { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextPos = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzHitTestTolerance { fn clone(&self) -> Self { let r: &azul_impl::resources::HitTestTolerance = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSystemCallbacks { fn clone(&self) -> Self { let r: &azul_impl::task::ExternalSystemCallbacks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGlContextOptions { fn clone(&self) -> Self { let r: &azul_core::window::GlContextOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRendererOptions { fn clone(&self) -> Self { let r: &azul_core::window::RendererOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutRect { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRawWindowHandleEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RawWindowHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRect { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAcceleratorKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorKey = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLetterbox { fn clone(&self) -> Self { let r: &azul_core::window::Letterbox = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowFlags { fn clone(&self) -> Self { let r: &azul_core::window::WindowFlags = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCursorPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::CursorPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10609,6 +10921,7 @@ impl Clone for AzPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzHidpiAdjustedBounds { fn clone(&self) -> Self { let r: &azul_impl::callbacks::HidpiAdjustedBounds = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyph { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineGlyph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHit { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextHit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextConstraints { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::TextConstraints = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallbackReturn { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefAny { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10866,6 +11179,7 @@ impl Clone for AzOptionNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzOptionDomNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionDomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionColorUEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgDashPatternEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::OptionSvgDashPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionLogicalRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPhysicalPositionI32EnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionPhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10883,7 +11197,6 @@ impl Clone for AzInvalidCharError { fn clone(&self) -> Self { let r: &azul_impl:
 impl Clone for AzInvalidCharMultipleError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidCharMultipleError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidQuoteError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidQuoteError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidSpaceError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidSpaceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzAppConfig { fn clone(&self) -> Self { let r: &azul_impl::resources::AppConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSmallWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::SmallWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::LargeWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10891,6 +11204,7 @@ impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::wind
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextCaret { fn clone(&self) -> Self { let r: &azul_core::window::TextCaret = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResolvedTextLayoutOptions { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::ResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11021,6 +11335,7 @@ impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFontWarmup { fn clone(&self) -> Self { let r: &azul_impl::resources::FontWarmup = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11049,8 +11364,10 @@ impl Clone for AzInlineLineVec { fn clone(&self) -> Self { let r: &azul_impl::ca
 impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgSimpleNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgSimpleNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFontWarmupVec { fn clone(&self) -> Self { let r: &azul_impl::resources::FontWarmupVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAppConfig { fn clone(&self) -> Self { let r: &azul_impl::resources::AppConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::CallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11067,7 +11384,6 @@ impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r:
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeData { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclaration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11082,7 +11398,9 @@ impl Clone for AzNodeIdNodeMap { fn clone(&self) -> Self { let r: &crate::widget
 impl Clone for AzNodeIdNodeMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionFocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlock { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlock = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabContent { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11180,6 +11498,7 @@ impl Drop for AzInlineLineVec { fn drop(&mut self) { crate::AzInlineLineVec_dele
 impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgSimpleNodeVec { fn drop(&mut self) { crate::AzSvgSimpleNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzFontWarmupVec { fn drop(&mut self) { crate::AzFontWarmupVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeIdNodeMapVec { fn drop(&mut self) { crate::AzNodeIdNodeMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11209,7 +11528,12 @@ impl AzApp {
             mem::transmute(self),
         )) }
     }
-    fn run(&self, window: AzWindowCreateOptions) -> () {
+    fn pump_events(&self) -> bool {
+        unsafe { mem::transmute(crate::AzApp_pumpEvents(
+            mem::transmute(self),
+        )) }
+    }
+    fn run(&self, window: AzWindowCreateOptions) -> i32 {
         unsafe { mem::transmute(crate::AzApp_run(
             mem::transmute(self),
             mem::transmute(window),
@@ -11259,6 +11583,138 @@ impl PyObjectProtocol for AzAppConfig {
     }
 }
 
+#[pymethods]
+impl AzGpuPreferenceEnumWrapper {
+    #[classattr]
+    fn Default() -> AzGpuPreferenceEnumWrapper { AzGpuPreferenceEnumWrapper { inner: AzGpuPreference::Default } }
+    #[classattr]
+    fn HighPerformance() -> AzGpuPreferenceEnumWrapper { AzGpuPreferenceEnumWrapper { inner: AzGpuPreference::HighPerformance } }
+    #[classattr]
+    fn PowerSaving() -> AzGpuPreferenceEnumWrapper { AzGpuPreferenceEnumWrapper { inner: AzGpuPreference::PowerSaving } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGpuPreferenceEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::GpuPreference = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::GpuPreference = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzGpuPreferenceEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzGpuMemoryBudget {
+    #[staticmethod]
+    fn unlimited() -> AzGpuMemoryBudget {
+        unsafe { mem::transmute(crate::AzGpuMemoryBudget_unlimited()) }
+    }
+    #[new]
+    fn new(max_bytes: usize) -> AzGpuMemoryBudget {
+        unsafe { mem::transmute(crate::AzGpuMemoryBudget_new(
+            mem::transmute(max_bytes),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGpuMemoryBudget {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::GpuMemoryBudget = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::GpuMemoryBudget = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzHitTestTolerance {
+    #[staticmethod]
+    fn none() -> AzHitTestTolerance {
+        unsafe { mem::transmute(crate::AzHitTestTolerance_none()) }
+    }
+    #[new]
+    fn new(mouse: f32, pen: f32, touch: f32) -> AzHitTestTolerance {
+        unsafe { mem::transmute(crate::AzHitTestTolerance_new(
+            mem::transmute(mouse),
+            mem::transmute(pen),
+            mem::transmute(touch),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzHitTestTolerance {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::HitTestTolerance = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::HitTestTolerance = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzFontWarmup {
+    #[new]
+    fn new(font: AzStyleFontFamilyVec, size: AzStyleFontSize, characters: String) -> AzFontWarmup {
+        let characters = pystring_to_azstring(&characters);
+        unsafe { mem::transmute(crate::AzFontWarmup_new(
+            mem::transmute(font),
+            mem::transmute(size),
+            mem::transmute(characters),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFontWarmup {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmup = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmup = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzEventLoopModeEnumWrapper {
+    #[classattr]
+    fn Blocking() -> AzEventLoopModeEnumWrapper { AzEventLoopModeEnumWrapper { inner: AzEventLoopMode::Blocking } }
+    #[staticmethod]
+    fn Polling(v: u32) -> AzEventLoopModeEnumWrapper { AzEventLoopModeEnumWrapper { inner: AzEventLoopMode::Polling(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzEventLoopMode;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzEventLoopMode::Blocking => Ok(vec!["Blocking".into_py(py), ().into_py(py)]),
+            AzEventLoopMode::Polling(v) => Ok(vec!["Polling".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzEventLoopModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::EventLoopMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::EventLoopMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzAppLogLevelEnumWrapper {
     #[classattr]
@@ -11362,14 +11818,98 @@ impl PyObjectProtocol for AzWindowCreateOptions {
     }
 }
 
+#[pymethods]
+impl AzGlContextOptions {
+    #[new]
+    fn __new__(major_version: u8, minor_version: u8, profile: AzGlContextProfileEnumWrapper, debug: bool, buffering: AzGlBufferingEnumWrapper) -> Self {
+        Self {
+            major_version,
+            minor_version,
+            profile,
+            debug,
+            buffering,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGlContextOptions {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlContextOptions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlContextOptions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGlContextProfileEnumWrapper {
+    #[classattr]
+    fn Core() -> AzGlContextProfileEnumWrapper { AzGlContextProfileEnumWrapper { inner: AzGlContextProfile::Core } }
+    #[classattr]
+    fn Compatibility() -> AzGlContextProfileEnumWrapper { AzGlContextProfileEnumWrapper { inner: AzGlContextProfile::Compatibility } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGlContextProfileEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlContextProfile = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlContextProfile = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzGlContextProfileEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzGlBufferingEnumWrapper {
+    #[classattr]
+    fn DoubleBuffered() -> AzGlBufferingEnumWrapper { AzGlBufferingEnumWrapper { inner: AzGlBuffering::DoubleBuffered } }
+    #[classattr]
+    fn SingleBufferedFlush() -> AzGlBufferingEnumWrapper { AzGlBufferingEnumWrapper { inner: AzGlBuffering::SingleBufferedFlush } }
+    #[classattr]
+    fn SingleBufferedFinish() -> AzGlBufferingEnumWrapper { AzGlBufferingEnumWrapper { inner: AzGlBuffering::SingleBufferedFinish } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGlBufferingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlBuffering = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GlBuffering = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzGlBufferingEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzRendererOptions {
     #[new]
-    fn __new__(vsync: AzVsyncEnumWrapper, srgb: AzSrgbEnumWrapper, hw_accel: AzHwAccelerationEnumWrapper) -> Self {
+    fn __new__(vsync: AzVsyncEnumWrapper, srgb: AzSrgbEnumWrapper, hw_accel: AzHwAccelerationEnumWrapper, present_mode: AzPresentModeEnumWrapper) -> Self {
         Self {
             vsync,
             srgb,
             hw_accel,
+            present_mode,
         }
     }
 
@@ -11385,6 +11925,38 @@ impl PyObjectProtocol for AzRendererOptions {
     }
 }
 
+#[pymethods]
+impl AzPresentModeEnumWrapper {
+    #[classattr]
+    fn Immediate() -> AzPresentModeEnumWrapper { AzPresentModeEnumWrapper { inner: AzPresentMode::Immediate } }
+    #[classattr]
+    fn Fifo() -> AzPresentModeEnumWrapper { AzPresentModeEnumWrapper { inner: AzPresentMode::Fifo } }
+    #[staticmethod]
+    fn FrameCapped(v: u32) -> AzPresentModeEnumWrapper { AzPresentModeEnumWrapper { inner: AzPresentMode::FrameCapped(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzPresentMode;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzPresentMode::Immediate => Ok(vec!["Immediate".into_py(py), ().into_py(py)]),
+            AzPresentMode::Fifo => Ok(vec!["Fifo".into_py(py), ().into_py(py)]),
+            AzPresentMode::FrameCapped(v) => Ok(vec!["FrameCapped".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPresentModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::PresentMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::PresentMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzVsyncEnumWrapper {
     #[classattr]
@@ -11415,6 +11987,34 @@ impl PyObjectProtocol for AzVsyncEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzRedrawPolicyEnumWrapper {
+    #[classattr]
+    fn OnDemand() -> AzRedrawPolicyEnumWrapper { AzRedrawPolicyEnumWrapper { inner: AzRedrawPolicy::OnDemand } }
+    #[classattr]
+    fn Continuous() -> AzRedrawPolicyEnumWrapper { AzRedrawPolicyEnumWrapper { inner: AzRedrawPolicy::Continuous } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRedrawPolicyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::RedrawPolicy = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::RedrawPolicy = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzRedrawPolicyEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzSrgbEnumWrapper {
     #[classattr]
@@ -12382,15 +12982,42 @@ impl PyObjectProtocol for AzAcceleratorKeyEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLetterbox {
+    #[new]
+    fn __new__(scale: f32, content: AzLogicalRect, leading_bar: AzLogicalRect, trailing_bar: AzLogicalRect) -> Self {
+        Self {
+            scale,
+            content,
+            leading_bar,
+            trailing_bar,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLetterbox {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Letterbox = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Letterbox = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzWindowSize {
     #[new]
-    fn __new__(dimensions: AzLogicalSize, dpi: u32, min_dimensions: AzOptionLogicalSizeEnumWrapper, max_dimensions: AzOptionLogicalSizeEnumWrapper) -> Self {
+    fn __new__(dimensions: AzLogicalSize, dpi: u32, content_scale: f32, min_dimensions: AzOptionLogicalSizeEnumWrapper, max_dimensions: AzOptionLogicalSizeEnumWrapper, fixed_logical_size: AzOptionLogicalSizeEnumWrapper, letterbox: AzLetterbox) -> Self {
         Self {
             dimensions,
             dpi,
+            content_scale,
             min_dimensions,
             max_dimensions,
+            fixed_logical_size,
+            letterbox,
         }
     }
 
@@ -12414,7 +13041,7 @@ impl PyObjectProtocol for AzWindowSize {
 #[pymethods]
 impl AzWindowFlags {
     #[new]
-    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, smooth_scroll_enabled: bool, autotab_enabled: bool) -> Self {
+    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, smooth_scroll_enabled: bool, autotab_enabled: bool, zoom_shortcuts_enabled: bool, ctrl_wheel_zoom_enabled: bool, is_shown_in_taskbar: bool) -> Self {
         Self {
             frame,
             is_about_to_close,
@@ -12427,6 +13054,9 @@ impl AzWindowFlags {
             has_blur_behind_window,
             smooth_scroll_enabled,
             autotab_enabled,
+            zoom_shortcuts_enabled,
+            ctrl_wheel_zoom_enabled,
+            is_shown_in_taskbar,
         }
     }
 
@@ -12752,13 +13382,22 @@ impl PyObjectProtocol for AzPlatformSpecificOptions {
 #[pymethods]
 impl AzWindowsWindowOptions {
     #[new]
-    fn __new__(allow_drag_drop: bool, no_redirection_bitmap: bool, window_icon: AzOptionWindowIconEnumWrapper, taskbar_icon: AzOptionTaskBarIconEnumWrapper, parent_window: AzOptionHwndHandleEnumWrapper) -> Self {
+    fn __new__(allow_drag_drop: bool, no_redirection_bitmap: bool, window_icon: AzOptionWindowIconEnumWrapper, taskbar_icon: AzOptionTaskBarIconEnumWrapper, parent_window: AzOptionHwndHandleEnumWrapper, alpha_hit_test_threshold: AzOptionF32EnumWrapper, alpha_hit_test_pass_through: bool, corner_preference: AzWindowCornerPreferenceEnumWrapper, app_user_model_id: AzOptionStringEnumWrapper, animate_frame_changes: bool, shared_gl_context: AzOptionHwndHandleEnumWrapper, embed_in_parent: bool, skip_rendering_when_occluded: bool, borderless_shadow: bool) -> Self {
         Self {
             allow_drag_drop,
             no_redirection_bitmap,
             window_icon,
             taskbar_icon,
             parent_window,
+            alpha_hit_test_threshold,
+            alpha_hit_test_pass_through,
+            corner_preference,
+            app_user_model_id,
+            animate_frame_changes,
+            shared_gl_context,
+            embed_in_parent,
+            skip_rendering_when_occluded,
+            borderless_shadow,
         }
     }
 
@@ -12774,6 +13413,38 @@ impl PyObjectProtocol for AzWindowsWindowOptions {
     }
 }
 
+#[pymethods]
+impl AzWindowCornerPreferenceEnumWrapper {
+    #[classattr]
+    fn Default() -> AzWindowCornerPreferenceEnumWrapper { AzWindowCornerPreferenceEnumWrapper { inner: AzWindowCornerPreference::Default } }
+    #[classattr]
+    fn DoNotRound() -> AzWindowCornerPreferenceEnumWrapper { AzWindowCornerPreferenceEnumWrapper { inner: AzWindowCornerPreference::DoNotRound } }
+    #[classattr]
+    fn Round() -> AzWindowCornerPreferenceEnumWrapper { AzWindowCornerPreferenceEnumWrapper { inner: AzWindowCornerPreference::Round } }
+    #[classattr]
+    fn RoundSmall() -> AzWindowCornerPreferenceEnumWrapper { AzWindowCornerPreferenceEnumWrapper { inner: AzWindowCornerPreference::RoundSmall } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzWindowCornerPreferenceEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowCornerPreference = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowCornerPreference = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzWindowCornerPreferenceEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzWaylandTheme {
 }
@@ -13001,6 +13672,50 @@ impl PyObjectProtocol for AzWindowPositionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextCaret {
+    #[new]
+    fn __new__(caret_rect: AzOptionLogicalRectEnumWrapper, selection_rects: AzLogicalRectVec) -> Self {
+        Self {
+            caret_rect,
+            selection_rects,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextCaret {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TextCaret = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TextCaret = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzAccessibilitySettings {
+    #[new]
+    fn __new__(high_contrast: bool, prefers_reduced_motion: bool) -> Self {
+        Self {
+            high_contrast,
+            prefers_reduced_motion,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilitySettings {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AccessibilitySettings = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AccessibilitySettings = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzImePositionEnumWrapper {
     #[classattr]
@@ -13414,6 +14129,21 @@ impl AzCallbackInfo {
             AzOptionInlineText::None => None,
         }
 
+    }
+    fn measure_text(&self, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: String, constraints: AzTextConstraints) -> Option<AzInlineText> {
+        let text = pystring_to_azstring(&text);
+        let m: AzOptionInlineText = unsafe { mem::transmute(crate::AzCallbackInfo_measureText(
+            mem::transmute(self),
+            mem::transmute(font),
+            mem::transmute(font_size),
+            mem::transmute(text),
+            mem::transmute(constraints),
+        )) };
+        match m {
+            AzOptionInlineText::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionInlineText::None => None,
+        }
+
     }
     fn get_index_in_parent(&mut self, node_id: AzDomNodeId) -> usize {
         unsafe { mem::transmute(crate::AzCallbackInfo_getIndexInParent(
@@ -13598,6 +14328,33 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
+    fn force_frame(&self) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_forceFrame(
+            mem::transmute(self),
+        )) }
+    }
+    fn confine_to_monitor(&self, monitor_id: usize) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_confineToMonitor(
+            mem::transmute(self),
+            mem::transmute(monitor_id),
+        )) }
+    }
+    fn release_from_monitor(&self) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_releaseFromMonitor(
+            mem::transmute(self),
+        )) }
+    }
+    fn lock_aspect_ratio(&self, ratio: f32) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_lockAspectRatio(
+            mem::transmute(self),
+            mem::transmute(ratio),
+        )) }
+    }
+    fn unlock_aspect_ratio(&self) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_unlockAspectRatio(
+            mem::transmute(self),
+        )) }
+    }
     fn create_window(&mut self, new_window: AzWindowCreateOptions) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_createWindow(
             mem::transmute(self),
@@ -14124,6 +14881,24 @@ impl PyObjectProtocol for AzResolvedTextLayoutOptions {
     }
 }
 
+#[pymethods]
+impl AzTextConstraints {
+    #[staticmethod]
+    fn default() -> AzTextConstraints {
+        unsafe { mem::transmute(crate::AzTextConstraints_default()) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextConstraints {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::TextConstraints = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::TextConstraints = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzAnimation {
     #[new]
@@ -14502,6 +15277,26 @@ impl PyObjectProtocol for AzThreadCallback {
     }
 }
 
+#[pymethods]
+impl AzImageLoaderCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImageLoaderCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ImageLoaderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ImageLoaderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzRefCount {
     fn can_be_shared(&self) -> bool {
@@ -14599,6 +15394,21 @@ impl AzLayoutCallbackInfo {
         }
 
     }
+    fn measure_text(&self, font: AzStyleFontFamilyVec, font_size: AzStyleFontSize, text: String, constraints: AzTextConstraints) -> Option<AzInlineText> {
+        let text = pystring_to_azstring(&text);
+        let m: AzOptionInlineText = unsafe { mem::transmute(crate::AzLayoutCallbackInfo_measureText(
+            mem::transmute(self),
+            mem::transmute(font),
+            mem::transmute(font_size),
+            mem::transmute(text),
+            mem::transmute(constraints),
+        )) };
+        match m {
+            AzOptionInlineText::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionInlineText::None => None,
+        }
+
+    }
 }
 
 #[pyproto]
@@ -14644,6 +15454,12 @@ impl AzDom {
             mem::transmute(image),
         )) }
     }
+    #[staticmethod]
+    fn native_child_window(window: AzNativeChildWindow) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_nativeChildWindow(
+            mem::transmute(window),
+        )) }
+    }
     fn set_node_type(&mut self, node_type: AzNodeTypeEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzDom_setNodeType(
             mem::transmute(self),
@@ -14908,6 +15724,18 @@ impl AzDom {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_title_bar_region(&mut self, region: AzTitleBarRegionEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDom_setTitleBarRegion(
+            mem::transmute(self),
+            mem::transmute(region),
+        )) }
+    }
+    fn with_title_bar_region(&mut self, region: AzTitleBarRegionEnumWrapper) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withTitleBarRegion(
+            mem::transmute(self),
+            mem::transmute(region),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzDom_hash(
             mem::transmute(self),
@@ -15240,6 +16068,18 @@ impl AzNodeData {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_native_child_window(&mut self, window: AzNativeChildWindow) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setNativeChildWindow(
+            mem::transmute(self),
+            mem::transmute(window),
+        )) }
+    }
+    fn set_title_bar_region(&mut self, region: AzTitleBarRegionEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setTitleBarRegion(
+            mem::transmute(self),
+            mem::transmute(region),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzNodeData_hash(
             mem::transmute(self),
@@ -15338,6 +16178,54 @@ impl PyObjectProtocol for AzNodeData {
     }
 }
 
+#[pymethods]
+impl AzNativeChildWindow {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNativeChildWindow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::NativeChildWindow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::NativeChildWindow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTitleBarRegionEnumWrapper {
+    #[classattr]
+    fn Drag() -> AzTitleBarRegionEnumWrapper { AzTitleBarRegionEnumWrapper { inner: AzTitleBarRegion::Drag } }
+    #[classattr]
+    fn NoDrag() -> AzTitleBarRegionEnumWrapper { AzTitleBarRegionEnumWrapper { inner: AzTitleBarRegion::NoDrag } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTitleBarRegionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TitleBarRegion = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TitleBarRegion = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTitleBarRegionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzNodeTypeEnumWrapper {
     #[classattr]
@@ -23078,6 +23966,30 @@ impl AzTextInput {
             mem::transmute(text),
         )) }
     }
+    fn set_read_only(&mut self, read_only: bool) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setReadOnly(
+            mem::transmute(self),
+            mem::transmute(read_only),
+        )) }
+    }
+    fn with_read_only(&mut self, read_only: bool) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withReadOnly(
+            mem::transmute(self),
+            mem::transmute(read_only),
+        )) }
+    }
+    fn set_disabled(&mut self, disabled: bool) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setDisabled(
+            mem::transmute(self),
+            mem::transmute(disabled),
+        )) }
+    }
+    fn with_disabled(&mut self, disabled: bool) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withDisabled(
+            mem::transmute(self),
+            mem::transmute(disabled),
+        )) }
+    }
     fn set_placeholder_style(&mut self, placeholder_style: AzNodeDataInlineCssPropertyVec) -> () {
         unsafe { mem::transmute(crate::AzTextInput_setPlaceholderStyle(
             mem::transmute(self),
@@ -23161,13 +24073,15 @@ impl PyObjectProtocol for AzTextInputStateWrapper {
 #[pymethods]
 impl AzTextInputState {
     #[new]
-    fn __new__(text: AzU32Vec, placeholder: AzOptionStringEnumWrapper, max_len: usize, selection: AzOptionTextInputSelectionEnumWrapper, cursor_pos: usize) -> Self {
+    fn __new__(text: AzU32Vec, placeholder: AzOptionStringEnumWrapper, max_len: usize, selection: AzOptionTextInputSelectionEnumWrapper, cursor_pos: usize, read_only: bool, disabled: bool) -> Self {
         Self {
             text,
             placeholder,
             max_len,
             selection,
             cursor_pos,
+            read_only,
+            disabled,
         }
     }
 
@@ -29588,6 +30502,11 @@ impl AzGl {
             mem::transmute(which),
         )) })
     }
+    fn get_renderer_name(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzGl_getRendererName(
+            mem::transmute(self),
+        )) })
+    }
     fn get_string_i(&self, which: u32, index: u32) -> String {
         az_string_to_py_string(unsafe { mem::transmute(crate::AzGl_getStringI(
             mem::transmute(self),
@@ -30574,6 +31493,14 @@ impl AzImageRef {
             mem::transmute(texture),
         )) }
     }
+    #[staticmethod]
+    fn solid_color(width: usize, height: usize, color: AzColorU) -> AzImageRef {
+        unsafe { mem::transmute(crate::AzImageRef_solidColor(
+            mem::transmute(width),
+            mem::transmute(height),
+            mem::transmute(color),
+        )) }
+    }
     fn clone_bytes(&self) -> AzImageRef {
         unsafe { mem::transmute(crate::AzImageRef_cloneBytes(
             mem::transmute(self),
@@ -33122,6 +34049,18 @@ impl AzTimer {
             mem::transmute(timeout),
         )) }
     }
+    fn with_missed_tick_policy(&self, missed_tick_policy: AzTimerMissedTickPolicyEnumWrapper) -> AzTimer {
+        unsafe { mem::transmute(crate::AzTimer_withMissedTickPolicy(
+            mem::transmute(self),
+            mem::transmute(missed_tick_policy),
+        )) }
+    }
+    fn with_runs_while_hidden(&self, runs_while_hidden: bool) -> AzTimer {
+        unsafe { mem::transmute(crate::AzTimer_withRunsWhileHidden(
+            mem::transmute(self),
+            mem::transmute(runs_while_hidden),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -33134,6 +34073,34 @@ impl PyObjectProtocol for AzTimer {
     }
 }
 
+#[pymethods]
+impl AzTimerMissedTickPolicyEnumWrapper {
+    #[classattr]
+    fn Coalesce() -> AzTimerMissedTickPolicyEnumWrapper { AzTimerMissedTickPolicyEnumWrapper { inner: AzTimerMissedTickPolicy::Coalesce } }
+    #[classattr]
+    fn CatchUp() -> AzTimerMissedTickPolicyEnumWrapper { AzTimerMissedTickPolicyEnumWrapper { inner: AzTimerMissedTickPolicy::CatchUp } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTimerMissedTickPolicyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::TimerMissedTickPolicy = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::TimerMissedTickPolicy = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTimerMissedTickPolicyEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzTerminateTimerEnumWrapper {
     #[classattr]
@@ -35000,6 +35967,31 @@ impl PyObjectProtocol for AzStringPairVec {
     }
 }
 
+#[pymethods]
+impl AzFontWarmupVec {
+    /// Creates a new `FontWarmupVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzFontWarmup>) -> Self {
+        let m: azul_impl::resources::FontWarmupVec = azul_impl::resources::FontWarmupVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the FontWarmup as a Python array
+    fn array(&self) -> Vec<AzFontWarmup> {
+        let m: &azul_impl::resources::FontWarmupVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFontWarmupVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmupVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmupVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNormalizedLinearColorStopVec {
     /// Creates a new `NormalizedLinearColorStopVec` from a Python array
@@ -36910,6 +37902,36 @@ impl PyObjectProtocol for AzStringPairVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzFontWarmupVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzFontWarmupVecDestructorEnumWrapper { AzFontWarmupVecDestructorEnumWrapper { inner: AzFontWarmupVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzFontWarmupVecDestructorEnumWrapper { AzFontWarmupVecDestructorEnumWrapper { inner: AzFontWarmupVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzFontWarmupVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzFontWarmupVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzFontWarmupVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzFontWarmupVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFontWarmupVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmupVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FontWarmupVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNormalizedLinearColorStopVecDestructorEnumWrapper {
     #[classattr]
@@ -38774,6 +39796,35 @@ impl PyObjectProtocol for AzOptionInlineTextEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionFocusTargetEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionFocusTargetEnumWrapper { AzOptionFocusTargetEnumWrapper { inner: AzOptionFocusTarget::None } }
+    #[staticmethod]
+    fn Some(v: AzFocusTargetEnumWrapper) -> AzOptionFocusTargetEnumWrapper { AzOptionFocusTargetEnumWrapper { inner: AzOptionFocusTarget::Some(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionFocusTarget;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionFocusTarget::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionFocusTarget::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzFocusTargetEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionFocusTargetEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionFocusTarget = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionFocusTarget = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionLayoutPointEnumWrapper {
     #[classattr]
@@ -39091,6 +40142,35 @@ impl PyObjectProtocol for AzOptionHwndHandleEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionLogicalRectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::None } }
+    #[staticmethod]
+    fn Some(v: AzLogicalRect) -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionLogicalRect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionLogicalRect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionLogicalRect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionLogicalRectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionLogicalPositionEnumWrapper {
     #[classattr]
@@ -40408,13 +41488,23 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<AzApp>()?;
     m.add_class::<AzAppConfig>()?;
+    m.add_class::<AzGpuPreferenceEnumWrapper>()?;
+    m.add_class::<AzGpuMemoryBudget>()?;
+    m.add_class::<AzHitTestTolerance>()?;
+    m.add_class::<AzFontWarmup>()?;
+    m.add_class::<AzEventLoopModeEnumWrapper>()?;
     m.add_class::<AzAppLogLevelEnumWrapper>()?;
     m.add_class::<AzLayoutSolverEnumWrapper>()?;
     m.add_class::<AzSystemCallbacks>()?;
 
     m.add_class::<AzWindowCreateOptions>()?;
+    m.add_class::<AzGlContextOptions>()?;
+    m.add_class::<AzGlContextProfileEnumWrapper>()?;
+    m.add_class::<AzGlBufferingEnumWrapper>()?;
     m.add_class::<AzRendererOptions>()?;
+    m.add_class::<AzPresentModeEnumWrapper>()?;
     m.add_class::<AzVsyncEnumWrapper>()?;
+    m.add_class::<AzRedrawPolicyEnumWrapper>()?;
     m.add_class::<AzSrgbEnumWrapper>()?;
     m.add_class::<AzHwAccelerationEnumWrapper>()?;
     m.add_class::<AzLayoutPoint>()?;
@@ -40442,6 +41532,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTaskBarIcon>()?;
     m.add_class::<AzVirtualKeyCodeEnumWrapper>()?;
     m.add_class::<AzAcceleratorKeyEnumWrapper>()?;
+    m.add_class::<AzLetterbox>()?;
     m.add_class::<AzWindowSize>()?;
     m.add_class::<AzWindowFlags>()?;
     m.add_class::<AzWindowFrameEnumWrapper>()?;
@@ -40452,6 +41543,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzMouseState>()?;
     m.add_class::<AzPlatformSpecificOptions>()?;
     m.add_class::<AzWindowsWindowOptions>()?;
+    m.add_class::<AzWindowCornerPreferenceEnumWrapper>()?;
     m.add_class::<AzWaylandTheme>()?;
     m.add_class::<AzRendererTypeEnumWrapper>()?;
     m.add_class::<AzStringPair>()?;
@@ -40461,6 +41553,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzFullScreenModeEnumWrapper>()?;
     m.add_class::<AzWindowThemeEnumWrapper>()?;
     m.add_class::<AzWindowPositionEnumWrapper>()?;
+    m.add_class::<AzTextCaret>()?;
+    m.add_class::<AzAccessibilitySettings>()?;
     m.add_class::<AzImePositionEnumWrapper>()?;
     m.add_class::<AzTouchState>()?;
     m.add_class::<AzMonitor>()?;
@@ -40490,6 +41584,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzFocusTargetEnumWrapper>()?;
     m.add_class::<AzFocusTargetPath>()?;
     m.add_class::<AzResolvedTextLayoutOptions>()?;
+    m.add_class::<AzTextConstraints>()?;
     m.add_class::<AzAnimation>()?;
     m.add_class::<AzAnimationRepeatEnumWrapper>()?;
     m.add_class::<AzAnimationRepeatCountEnumWrapper>()?;
@@ -40504,6 +41599,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTimerCallbackReturn>()?;
     m.add_class::<AzWriteBackCallback>()?;
     m.add_class::<AzThreadCallback>()?;
+    m.add_class::<AzImageLoaderCallback>()?;
     m.add_class::<AzRefCount>()?;
     m.add_class::<AzRefAny>()?;
     m.add_class::<AzLayoutCallbackInfo>()?;
@@ -40512,6 +41608,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzIFrameNode>()?;
     m.add_class::<AzCallbackData>()?;
     m.add_class::<AzNodeData>()?;
+    m.add_class::<AzNativeChildWindow>()?;
+    m.add_class::<AzTitleBarRegionEnumWrapper>()?;
     m.add_class::<AzNodeTypeEnumWrapper>()?;
     m.add_class::<AzOnEnumWrapper>()?;
     m.add_class::<AzEventFilterEnumWrapper>()?;
@@ -40943,6 +42041,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<AzTimerId>()?;
     m.add_class::<AzTimer>()?;
+    m.add_class::<AzTimerMissedTickPolicyEnumWrapper>()?;
     m.add_class::<AzTerminateTimerEnumWrapper>()?;
     m.add_class::<AzThreadId>()?;
     m.add_class::<AzThread>()?;
@@ -41023,6 +42122,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzGLintVec>()?;
     m.add_class::<AzStringVec>()?;
     m.add_class::<AzStringPairVec>()?;
+    m.add_class::<AzFontWarmupVec>()?;
     m.add_class::<AzNormalizedLinearColorStopVec>()?;
     m.add_class::<AzNormalizedRadialColorStopVec>()?;
     m.add_class::<AzNodeIdVec>()?;
@@ -41088,6 +42188,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzGLintVecDestructorEnumWrapper>()?;
     m.add_class::<AzStringVecDestructorEnumWrapper>()?;
     m.add_class::<AzStringPairVecDestructorEnumWrapper>()?;
+    m.add_class::<AzFontWarmupVecDestructorEnumWrapper>()?;
     m.add_class::<AzNormalizedLinearColorStopVecDestructorEnumWrapper>()?;
     m.add_class::<AzNormalizedRadialColorStopVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeIdVecDestructorEnumWrapper>()?;
@@ -41153,6 +42254,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionLayoutRectEnumWrapper>()?;
     m.add_class::<AzOptionRefAnyEnumWrapper>()?;
     m.add_class::<AzOptionInlineTextEnumWrapper>()?;
+    m.add_class::<AzOptionFocusTargetEnumWrapper>()?;
     m.add_class::<AzOptionLayoutPointEnumWrapper>()?;
     m.add_class::<AzOptionLayoutSizeEnumWrapper>()?;
     m.add_class::<AzOptionWindowThemeEnumWrapper>()?;
@@ -41164,6 +42266,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionWaylandThemeEnumWrapper>()?;
     m.add_class::<AzOptionTaskBarIconEnumWrapper>()?;
     m.add_class::<AzOptionHwndHandleEnumWrapper>()?;
+    m.add_class::<AzOptionLogicalRectEnumWrapper>()?;
     m.add_class::<AzOptionLogicalPositionEnumWrapper>()?;
     m.add_class::<AzOptionPhysicalPositionI32EnumWrapper>()?;
     m.add_class::<AzOptionWindowIconEnumWrapper>()?;
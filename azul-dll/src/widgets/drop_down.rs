@@ -436,6 +436,10 @@ fn on_dropdown_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
         theme: None.into(),
         create_callback: None.into(),
         hot_reload: false,
+        center_on_show: false,
+        gl_context: Default::default(),
+        show_after_first_frame: false,
+        first_frame_callback: None.into(),
     });
 
     println!("5!");
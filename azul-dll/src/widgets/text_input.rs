@@ -15,7 +15,8 @@ use azul_desktop::{
 use azul_core::{
     callbacks::{Animation, AnimationRepeatCount, InlineText, DomNodeId},
     task::SystemTimeDiff,
-    window::{KeyboardState, LogicalPosition, VirtualKeyCode},
+    ui_solver::PositionInfo,
+    window::{KeyboardState, LogicalPosition, LogicalRect, LogicalRectVec, TextCaret},
 };
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -308,6 +309,10 @@ pub struct TextInputState {
     pub max_len: usize,
     pub selection: OptionTextInputSelection,
     pub cursor_pos: usize,
+    /// If set, the text can be focused and selected, but not edited
+    pub read_only: bool,
+    /// If set, the text input ignores all keyboard input
+    pub disabled: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -383,6 +388,8 @@ impl Default for TextInputState {
             max_len: 50,
             selection: None.into(),
             cursor_pos: 0,
+            read_only: false,
+            disabled: false,
         }
     }
 }
@@ -443,6 +450,26 @@ impl TextInput {
         s
     }
 
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.state.inner.read_only = read_only;
+    }
+
+    pub fn with_read_only(&mut self, read_only: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_read_only(read_only);
+        s
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.state.inner.disabled = disabled;
+    }
+
+    pub fn with_disabled(&mut self, disabled: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_disabled(disabled);
+        s
+    }
+
     pub fn set_on_text_input(&mut self,  data: RefAny, callback: TextInputOnTextInputCallbackType) {
         self.state.on_text_input = Some(TextInputOnTextInput {
             callback: TextInputOnTextInputCallback { cb: callback },
@@ -584,6 +611,10 @@ fn default_on_focus_received(
 
     text_input.inner.cursor_pos = text_input.inner.text.len();
 
+    let cursor_node_id = info.get_next_sibling(placeholder_text_node_id)
+        .and_then(|label_node_id| info.get_first_child(label_node_id));
+    set_text_caret(info, cursor_node_id);
+
     Update::DoNothing
 }
 
@@ -613,6 +644,8 @@ fn default_on_focus_lost(
         );
     }
 
+    set_text_caret(info, None);
+
     let result = {
         // rustc doesn't understand the borrowing lifetime here
         let text_input = &mut *text_input;
@@ -651,6 +684,36 @@ fn default_on_text_input_inner(
     let cursor_node_id = info.get_first_child(label_node_id)?;
 
 
+    if text_input.inner.read_only || text_input.inner.disabled {
+        return None;
+    }
+
+    // control characters (backspace, ctrl + backspace, enter) edit
+    // the text instead of being appended to it
+    let new_text = {
+        let mut internal = text_input.inner.text.clone().into_library_owned_vec();
+        match core::char::from_u32(c) {
+            Some('\u{8}') => { internal.pop(); },
+            // ctrl + backspace: delete the word before the cursor
+            // (and the whitespace between the word and the cursor)
+            Some('\u{7f}') => {
+                let is_whitespace = |c: &u32| core::char::from_u32(*c).map(|c| c.is_whitespace()).unwrap_or(false);
+                while internal.last().map(is_whitespace).unwrap_or(false) { internal.pop(); }
+                while internal.last().map(|c| !is_whitespace(c)).unwrap_or(false) { internal.pop(); }
+            },
+            // single-line text input: ignore enter
+            Some('\r') | Some('\n') => return None,
+            Some(ch) if ch.is_control() => return None,
+            _ => {
+                if internal.len() >= text_input.inner.max_len {
+                    return None;
+                }
+                internal.push(c);
+            },
+        }
+        internal
+    };
+
     let result = {
         // rustc doesn't understand the borrowing lifetime here
         let text_input = &mut *text_input;
@@ -658,12 +721,8 @@ fn default_on_text_input_inner(
 
         // inner_clone has the new text
         let mut inner_clone = text_input.inner.clone();
-        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(1);
-        inner_clone.text = {
-            let mut internal = inner_clone.text.clone().into_library_owned_vec();
-            internal.push(c);
-            internal.into()
-        };
+        inner_clone.cursor_pos = new_text.len();
+        inner_clone.text = new_text.clone().into();
 
         match ontextinput.as_mut() {
             Some(TextInputOnTextInput { callback, data }) => (callback.cb)(data, info, &inner_clone),
@@ -675,21 +734,19 @@ fn default_on_text_input_inner(
     };
 
     if result.valid == TextInputValid::Yes {
-        // hide the placeholder text
+        // hide the placeholder text while there is text, show it
+        // again once the text has been deleted
+        let placeholder_opacity = if new_text.is_empty() { 100 } else { 0 };
         info.set_css_property(
             placeholder_node_id,
-            CssProperty::const_opacity(StyleOpacity::const_new(0))
+            CssProperty::const_opacity(StyleOpacity::const_new(placeholder_opacity))
         );
 
-        // append to the text
-        text_input.inner.text = {
-            let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-            internal.push(c);
-            internal.into()
-        };
-        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(1);
+        text_input.inner.cursor_pos = new_text.len();
+        text_input.inner.text = new_text.into();
 
         info.set_string_contents(label_node_id, text_input.inner.get_text().into());
+        set_text_caret(info, Some(cursor_node_id));
     }
 
     Some(result.update)
@@ -717,20 +774,52 @@ fn default_on_virtual_key_down_inner(
     let label_node_id = info.get_next_sibling(placeholder_node_id)?;
     let cursor_node_id = info.get_first_child(label_node_id)?;
 
-    if c != VirtualKeyCode::Back {
+    if text_input.inner.disabled {
         return None;
     }
 
-    text_input.inner.text = {
-        let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-        internal.pop();
-        internal.into()
+    // NOTE: backspace / delete are delivered as control characters
+    // via the text input callback, only forward the key to the user here
+    let result = {
+        // rustc doesn't understand the borrowing lifetime here
+        let text_input = &mut *text_input;
+        let onvirtualkeydown = &mut text_input.on_virtual_key_down;
+        let inner = &text_input.inner;
+
+        match onvirtualkeydown.as_mut() {
+            Some(TextInputOnVirtualKeyDown { callback, data }) => (callback.cb)(data, info, &inner),
+            None => return None,
+        }
     };
-    text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_sub(1);
 
-    info.set_string_contents(label_node_id, text_input.inner.get_text().into());
+    Some(result.update)
+}
+
+// Reports the position of the cursor node to the window, so that the
+// IME composition window and screen readers can follow the caret
+fn set_text_caret(info: &mut CallbackInfo, cursor_node_id: Option<DomNodeId>) {
 
-    None
+    let caret_rect = cursor_node_id.and_then(|node_id| {
+        let position = match info.get_node_position(node_id)? {
+            PositionInfo::Static(p) |
+            PositionInfo::Fixed(p) |
+            PositionInfo::Absolute(p) |
+            PositionInfo::Relative(p) => LogicalPosition::new(p.x_offset, p.y_offset),
+        };
+        let size = info.get_node_size(node_id)?;
+        Some(LogicalRect::new(position, size))
+    });
+
+    let text_caret = TextCaret {
+        caret_rect: caret_rect.into(),
+        selection_rects: LogicalRectVec::from_const_slice(&[]),
+    };
+
+    let mut window_state = info.get_current_window_state();
+    if window_state.text_caret != text_caret {
+        window_state.text_caret = text_caret;
+        info.set_window_state(window_state);
+    }
 }
 
 extern "C"
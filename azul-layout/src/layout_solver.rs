@@ -22,12 +22,13 @@ use azul_core::{
         WidthCalculatedRect, HeightCalculatedRect,
         HorizontalSolvedPosition, VerticalSolvedPosition,
         GpuValueCache, RelayoutChanges, PositionInfoInner,
-        StyleBoxShadowOffsets,
+        StyleBoxShadowOffsets, TextConstraints,
     },
     app_resources::{
         ResourceUpdate, IdNamespace, RendererResources,
         FontInstanceKey, Epoch, ShapedWords,
         WordPositions, Words, ImageCache, DpiScaleFactor,
+        LoadFontFn,
     },
     callbacks::DocumentId,
     display_list::RenderCallbacks,
@@ -2314,6 +2315,36 @@ fn create_word_cache<'a>(
     word_map.into_iter().filter_map(|a| a).collect()
 }
 
+/// Measures a text without a DOM: loads the first available font of `font` (the
+/// same way as the layout engine resolves a `font-family`) and shapes, breaks and
+/// positions the text exactly as the layout engine would lay out a text node with
+/// the same font, `font-size` and constraints.
+///
+/// Returns the lines, per-glyph positions and the total size of the text (use
+/// `InlineText::hit_test` for caret positions), or `None` if no font could be loaded.
+/// Note that system fonts are loaded and parsed on every call: to measure many
+/// texts, load the font once and pass it as a `StyleFontFamily::Ref`.
+#[cfg(feature = "text_layout")]
+pub fn measure_text(
+    fc_cache: &FcFontCache,
+    font: &[StyleFontFamily],
+    font_size: StyleFontSize,
+    text: &str,
+    constraints: &TextConstraints,
+    load_font_fn: LoadFontFn,
+) -> Option<InlineText> {
+
+    let font_ref = font.iter().find_map(|family| match family {
+        StyleFontFamily::Ref(r) => Some(r.clone()),
+        other => azul_text_layout::parse_font_fn((load_font_fn)(other, fc_cache)?),
+    })?;
+
+    let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
+    let text_layout_options = constraints.to_text_layout_options(font_size_px);
+
+    Some(azul_text_layout::text_layout::shape_text(&font_ref, text, &text_layout_options))
+}
+
 // same as get_inline_text(), but shapes a new word instead of using the internal one
 // - necessary to implement text cursor, so that we can calculate the x-offset of
 // the text cursor for the next frame (after the character has been pressed)
@@ -428,3 +428,74 @@ fn test_fill_out_preferred_width() {
         space_added: window_width - 200.0,
     });
 }
+
+// loads a font shipped with the examples, so that the test doesn't depend on the system fonts
+#[cfg(feature = "text_layout")]
+fn get_test_font() -> azul_css::StyleFontFamily {
+    use azul_core::app_resources::LoadedFontSource;
+    let font = azul_text_layout::parse_font_fn(LoadedFontSource {
+        data: include_bytes!("../../examples/assets/fonts/KoHo-Light.ttf").to_vec().into(),
+        index: 0,
+        load_outlines: false,
+    }).unwrap();
+    azul_css::StyleFontFamily::Ref(font)
+}
+
+#[cfg(feature = "text_layout")]
+fn no_system_fonts(
+    _: &azul_css::StyleFontFamily,
+    _: &rust_fontconfig::FcFontCache
+) -> Option<azul_core::app_resources::LoadedFontSource> {
+    None
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_measure_text_breaks_lines_at_max_width() {
+    use azul_core::ui_solver::TextConstraints;
+    use azul_css::{OptionF32, StyleFontSize};
+    use rust_fontconfig::FcFontCache;
+    use crate::layout_solver::measure_text;
+
+    let fc_cache = FcFontCache::default();
+    let font = [get_test_font()];
+    let text = "hello world hello world hello world";
+
+    let single_line = measure_text(
+        &fc_cache, &font, StyleFontSize::px(16.0), text,
+        &TextConstraints::default(), no_system_fonts,
+    ).unwrap();
+
+    assert_eq!(single_line.lines.len(), 1);
+    assert!(single_line.content_size.width > 0.0);
+    assert_eq!(single_line.font_size_px, 16.0);
+
+    let max_width = single_line.content_size.width / 2.0;
+    let wrapped = measure_text(
+        &fc_cache, &font, StyleFontSize::px(16.0), text,
+        &TextConstraints { max_width: OptionF32::Some(max_width), .. TextConstraints::default() },
+        no_system_fonts,
+    ).unwrap();
+
+    assert!(wrapped.lines.len() > 1);
+    assert!(wrapped.content_size.height > single_line.content_size.height);
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_measure_text_without_font() {
+    use azul_core::ui_solver::TextConstraints;
+    use azul_css::{StyleFontFamily, StyleFontSize};
+    use rust_fontconfig::FcFontCache;
+    use crate::layout_solver::measure_text;
+
+    let fc_cache = FcFontCache::default();
+    let font = [StyleFontFamily::System("does-not-exist".into())];
+
+    let result = measure_text(
+        &fc_cache, &font, StyleFontSize::px(16.0), "hello",
+        &TextConstraints::default(), no_system_fonts,
+    );
+
+    assert!(result.is_none());
+}
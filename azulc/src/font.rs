@@ -2,6 +2,8 @@
 
 use azul_css::{U8Vec, AzString};
 use rust_fontconfig::FcFontCache;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // serif
 #[cfg(target_os = "windows")]
@@ -236,6 +238,116 @@ pub fn load_system_font(id: &str, fc_cache: &FcFontCache) -> Option<(U8Vec, i32)
     None
 }
 
+/// Identifies a font that was registered with `register_font_bytes`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FontId(pub usize);
+
+static NEXT_FONT_ID: AtomicUsize = AtomicUsize::new(0);
+
+struct RegisteredFont {
+    id: FontId,
+    family_name: String,
+    bytes: Vec<u8>,
+    font_index: u32,
+}
+
+// Fonts loaded from memory, looked up by family name before the system fonts
+static REGISTERED_FONTS: Mutex<Vec<RegisteredFont>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FontRegistrationError {
+    /// The data is not a TrueType / OpenType font or font collection
+    UnknownFormat,
+    /// The face index is out of range for the font collection
+    FaceIndexOutOfRange { font_index: u32, face_count: u32 },
+    /// The font tables could not be parsed
+    InvalidFontData,
+}
+
+impl_display!(FontRegistrationError, {
+    UnknownFormat => format!("Not a TrueType / OpenType font or font collection"),
+    FaceIndexOutOfRange { font_index, face_count } => format!("Face index {} is out of range, the font collection has {} faces", font_index, face_count),
+    InvalidFontData => format!("Could not parse the font tables"),
+});
+
+// Number of faces in a TTF / OTF (1) or TTC font, None if the data is not a font
+fn get_face_count(bytes: &[u8]) -> Option<u32> {
+    let read_u32 = |offset: usize| -> Option<u32> {
+        let b = bytes.get(offset..offset + 4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    match bytes.get(0..4)? {
+        b"ttcf" => read_u32(8),
+        [0x00, 0x01, 0x00, 0x00] | b"OTTO" | b"true" => Some(1),
+        _ => None,
+    }
+}
+
+/// Registers a TTF / OTF font (or the face `font_index` of a TTC font collection)
+/// from memory under the given family name, so that it can be used in stylesheets
+/// (`font-family: <family_name>`) without being installed on the system. Registered
+/// fonts take precedence over system fonts with the same (case-insensitive) name,
+/// the last registration of a family name wins.
+///
+/// Like system fonts, the font is added to the renderer of each window the first
+/// time that the window lays out text with this font, so fonts should be registered
+/// before the first window that uses them is created.
+pub fn register_font_bytes(
+    family_name: &str,
+    bytes: &[u8],
+    font_index: u32,
+) -> Result<FontId, FontRegistrationError> {
+
+    let face_count = get_face_count(bytes).ok_or(FontRegistrationError::UnknownFormat)?;
+    if font_index >= face_count {
+        return Err(FontRegistrationError::FaceIndexOutOfRange { font_index, face_count });
+    }
+
+    #[cfg(feature = "text_layout")] {
+        use azul_core::app_resources::LoadedFontSource;
+        let source = LoadedFontSource {
+            data: bytes.to_vec().into(),
+            index: font_index,
+            load_outlines: false,
+        };
+        if azul_layout::parse_font_fn(source).is_none() {
+            return Err(FontRegistrationError::InvalidFontData);
+        }
+    }
+
+    let id = FontId(NEXT_FONT_ID.fetch_add(1, Ordering::SeqCst));
+    let mut registered_fonts = REGISTERED_FONTS.lock().unwrap_or_else(|e| e.into_inner());
+    registered_fonts.push(RegisteredFont {
+        id,
+        family_name: family_name.trim().to_string(),
+        bytes: bytes.to_vec(),
+        font_index,
+    });
+
+    Ok(id)
+}
+
+/// Removes a font registered with `register_font_bytes`, returns `false` if the
+/// font was not registered. Windows that already loaded the font keep using it
+/// until they are closed.
+pub fn unregister_font(id: FontId) -> bool {
+    let mut registered_fonts = REGISTERED_FONTS.lock().unwrap_or_else(|e| e.into_inner());
+    let len_before = registered_fonts.len();
+    registered_fonts.retain(|f| f.id != id);
+    registered_fonts.len() != len_before
+}
+
+/// Returns the bytes + font index of the font registered under the family name
+pub fn load_registered_font(family_name: &str) -> Option<(U8Vec, i32)> {
+    let registered_fonts = REGISTERED_FONTS.lock().unwrap_or_else(|e| e.into_inner());
+    registered_fonts
+        .iter()
+        .rev()
+        .find(|f| f.family_name.eq_ignore_ascii_case(family_name.trim()))
+        .map(|f| (f.bytes.clone().into(), f.font_index as i32))
+}
+
 #[cfg(all(target_os = "linux", feature = "std"))]
 fn linux_get_gsettings_font(font_name: &'static str) -> Option<String> {
     // Execute "gsettings get org.gnome.desktop.interface font-name" and parse the output
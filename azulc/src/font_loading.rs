@@ -55,8 +55,9 @@ pub fn font_source_get_bytes(font_family: &StyleFontFamily, fc_cache: &FcFontCac
     let (font_bytes, font_index) = match font_family {
         System(id) => {
             #[cfg(feature = "font_loading")] {
-                crate::font::load_system_font(id.as_str(), fc_cache)
-                .map(|(font_bytes, font_index)| (font_bytes, font_index))
+                // fonts registered from memory shadow the system fonts
+                crate::font::load_registered_font(id.as_str())
+                .or_else(|| crate::font::load_system_font(id.as_str(), fc_cache))
                 .ok_or(FontReloadError::FontNotFound(id.clone()))
             }
             #[cfg(not(feature = "font_loading"))] {
@@ -1314,6 +1314,7 @@ def generate_python_api(api_data, structs_map, functions_map):
 
         ("task", "Timer", "new"),
         ("callbacks", "CallbackInfo", "start_thread"),
+        ("callbacks", "CallbackInfo", "load_image_async"),
         ("callbacks", "CallbackInfo", "get_node_id_of_root_dataset"),
         ("image", "ImageRef", "callback"),
 